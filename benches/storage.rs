@@ -0,0 +1,41 @@
+//! # Storage Benchmarks
+//!
+//! Criterion benchmarks for walking and parsing, ID lookup, search, ID
+//! generation, and moving items with attachments in generated projects of
+//! increasing size. Run with `cargo bench`; set `QS_BENCH_ITEMS` (e.g.
+//! `1000,10000`) to choose the sizes.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use queuestack::{bench, storage};
+
+/// Project sizes used when `QS_BENCH_ITEMS` isn't set
+const DEFAULT_SIZES: &str = "100,1000,5000";
+/// Samples per case (criterion's minimum, large projects are slow to walk)
+const SAMPLES: usize = 10;
+
+fn storage_benches(c: &mut Criterion) {
+    let sizes = std::env::var("QS_BENCH_ITEMS").unwrap_or_else(|_| DEFAULT_SIZES.to_string());
+    for size in sizes.split(',') {
+        let count: usize = size.trim().parse().expect("QS_BENCH_ITEMS must be numbers");
+        let dir = tempfile::TempDir::new().expect("create temp dir");
+        let config = bench::generate(dir.path(), count).expect("generate project");
+        let items = storage::load_all_items(&config);
+
+        let mut group = c.benchmark_group(format!("{count}_items"));
+        group.sample_size(SAMPLES);
+        for (name, mut f) in bench::cases(&config, &items) {
+            group.bench_function(name, |b| b.iter(&mut f));
+        }
+        let mut moves = bench::attachment_moves(&config).expect("find attachments");
+        group.bench_function("attachment_move", |b| {
+            b.iter(|| moves().expect("move attachments"));
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, storage_benches);
+criterion_main!(benches);
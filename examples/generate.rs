@@ -0,0 +1,33 @@
+//! # Generate Example
+//!
+//! Creates a synthetic project for profiling queuestack on large repositories.
+//! Kept out of the `qs` binary since only contributors need it:
+//!
+//! ```sh
+//! cargo run --release --example generate -- 10000 [DIR]
+//! qs --project-root qs-bench-10000 bench
+//! ```
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use queuestack::bench;
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let count: usize = args
+        .next()
+        .context("Usage: generate <COUNT> [DIR]")?
+        .parse()
+        .context("COUNT must be a number")?;
+    let root = args
+        .next()
+        .map_or_else(|| PathBuf::from(format!("qs-bench-{count}")), PathBuf::from);
+
+    bench::generate(&root, count)?;
+    println!("Generated {count} items in {}", root.display());
+    Ok(())
+}
@@ -0,0 +1,367 @@
+//! # Benchmarks
+//!
+//! Synthetic projects and timings for the operations that get slow in large
+//! repositories: walking and parsing items, ID lookup, search, ID generation,
+//! and moving items with attachments. Used by `qs bench`, the benchmarks in
+//! `benches/`, and the `generate` example. Results can be saved as JSON and
+//! compared against a baseline to catch performance regressions.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+use chrono::{TimeDelta, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::{Config, ProjectConfig},
+    constants::PRIORITY_FIELD,
+    id::{self, base32},
+    item::{search, Frontmatter, Item, Status},
+    storage,
+};
+
+/// Categories the synthetic items are spread across (`None` is the stack root)
+const CATEGORIES: [Option<&str>; 5] = [
+    None,
+    Some("bugs"),
+    Some("features"),
+    Some("docs"),
+    Some("ops"),
+];
+/// Label pool for synthetic items
+const LABELS: [&str; 6] = ["bug", "ui", "backend", "urgent", "perf", "good-first-issue"];
+/// Title words for synthetic items
+const WORDS: [&str; 12] = [
+    "login",
+    "crash",
+    "export",
+    "sync",
+    "cache",
+    "report",
+    "upload",
+    "search",
+    "theme",
+    "parser",
+    "timeout",
+    "migration",
+];
+/// Priority values for synthetic items
+const PRIORITIES: [&str; 3] = ["high", "medium", "low"];
+/// Every n-th synthetic item is closed (and archived)
+const CLOSED_EVERY: usize = 5;
+/// Every n-th synthetic item has an attachment
+const ATTACHMENT_EVERY: usize = 10;
+/// Query used by the search benchmark
+const SEARCH_QUERY: &str = "timeout";
+/// IDs generated per run of the ID generation benchmark
+const IDS_PER_RUN: usize = 1000;
+
+/// Timing of one benchmark case
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub name: String,
+    /// Median duration in microseconds
+    pub median_us: u64,
+    pub runs: usize,
+}
+
+/// Creates a synthetic project with `count` items in `root`.
+///
+/// Items are spread over a few categories, about a fifth are closed and
+/// archived, and every tenth has an attachment. IDs and contents are
+/// deterministic, so projects of the same size are comparable. Returns a
+/// config for the project that ignores the user's global settings.
+pub fn generate(root: &Path, count: usize) -> Result<Config> {
+    if root.exists() && fs::read_dir(root)?.next().is_some() {
+        bail!("Directory is not empty: {}", root.display());
+    }
+    let config = Config::sandbox(root.to_path_buf());
+    for dir in [
+        config.stack_path(),
+        config.archive_path(),
+        config.template_path(),
+    ] {
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+    }
+    ProjectConfig::save_with_comments(root)?;
+
+    let start = Utc
+        .with_ymd_and_hms(2025, 1, 1, 9, 0, 0)
+        .single()
+        .context("Invalid start date")?;
+    for index in 0..count {
+        let created_at = start + TimeDelta::minutes(i64::try_from(index * 53).unwrap_or(i64::MAX));
+        let item = synthetic_item(index, created_at);
+        let category = CATEGORIES[index % CATEGORIES.len()];
+        let dir = storage::status_directory(&config, item.status(), category);
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+        let path = dir.join(item.filename());
+        item.save(&path)?;
+
+        if let Some(name) = item.attachments().first() {
+            let attachment_dir = storage::attachment_dir_for_item(&path);
+            fs::create_dir_all(&attachment_dir)?;
+            fs::write(
+                attachment_dir.join(name),
+                format!("log output of item {index}\n"),
+            )?;
+        }
+    }
+    Ok(config)
+}
+
+/// Builds the synthetic item with the given index.
+fn synthetic_item(index: usize, created_at: chrono::DateTime<Utc>) -> Item {
+    let word = |n: usize| WORDS[(index / n) % WORDS.len()];
+    let title = format!("Fix {} {} in {} ({index})", word(1), word(3), word(7));
+    let status = if index % CLOSED_EVERY == CLOSED_EVERY - 1 {
+        Status::Closed
+    } else {
+        Status::Open
+    };
+    let labels = vec![
+        LABELS[index % LABELS.len()].to_string(),
+        LABELS[(index / 2 + 1) % LABELS.len()].to_string(),
+    ];
+    let attachments = if index % ATTACHMENT_EVERY == 0 {
+        vec!["1-log.txt".to_string()]
+    } else {
+        Vec::new()
+    };
+    let mut extra = BTreeMap::new();
+    extra.insert(
+        PRIORITY_FIELD.to_string(),
+        serde_yml::Value::from(PRIORITIES[index % PRIORITIES.len()]),
+    );
+
+    let mut item = Item::new(Frontmatter {
+        id: format!(
+            "{}-{}",
+            created_at.format("%y%m%d"),
+            base32::encode(index as u64, 7)
+        ),
+        title,
+        author: "Bench".to_string(),
+        created_at,
+        status,
+        closed_at: (status == Status::Closed).then(|| created_at + TimeDelta::days(3)),
+        labels,
+        attachments,
+        extra,
+        ..Frontmatter::default()
+    });
+    item.body = format!(
+        "## Steps\n\n1. Open the {} screen\n2. Trigger a {}\n\n## Notes\n\n{}\n",
+        word(1),
+        word(5),
+        "The quick brown fox jumps over the lazy dog. ".repeat(8)
+    );
+    item
+}
+
+/// A named benchmark case
+pub type Case<'a> = (&'static str, Box<dyn FnMut() + 'a>);
+
+/// The read-only benchmark cases for a project and its loaded `items`.
+pub fn cases<'a>(config: &'a Config, items: &'a [Item]) -> Vec<Case<'a>> {
+    let last_id = items
+        .iter()
+        .map(Item::id)
+        .max()
+        .unwrap_or_default()
+        .to_string();
+
+    vec![
+        (
+            "walk",
+            Box::new(|| {
+                storage::walk_all(config).count();
+            }),
+        ),
+        (
+            "parse",
+            Box::new(|| {
+                storage::load_all_items(config);
+            }),
+        ),
+        (
+            "parse_headers",
+            Box::new(|| {
+                let mut buf = String::new();
+                for path in storage::walk_all(config) {
+                    let _ = Item::load_frontmatter(&path, &mut buf);
+                }
+            }),
+        ),
+        (
+            "find_by_id",
+            Box::new(move || {
+                let _ = storage::find_by_id(config, &last_id);
+            }),
+        ),
+        (
+            "search",
+            Box::new(|| {
+                items
+                    .iter()
+                    .filter(|item| search::matches_query(item, SEARCH_QUERY, true))
+                    .count();
+            }),
+        ),
+        (
+            "id_generate",
+            Box::new(|| {
+                for _ in 0..IDS_PER_RUN {
+                    id::generate(config.id_pattern());
+                }
+            }),
+        ),
+    ]
+}
+
+/// Times the read-only benchmark cases against a project.
+pub fn run(config: &Config, runs: usize) -> Vec<Measurement> {
+    let items = storage::load_all_items(config);
+    cases(config, &items)
+        .into_iter()
+        .map(|(name, f)| measure(name, runs, f))
+        .collect()
+}
+
+/// Returns a case that moves up to 100 items with attachments to another
+/// category and back.
+///
+/// Modifies the project, so only use it on generated projects.
+pub fn attachment_moves(config: &Config) -> Result<impl FnMut() -> Result<()> + '_> {
+    let paths: Vec<PathBuf> = storage::walk_items(config)
+        .filter(|path| storage::attachment_dir_for_item(path).is_dir())
+        .take(100)
+        .collect();
+    if paths.is_empty() {
+        bail!("No items with attachments to move");
+    }
+
+    Ok(move || {
+        for path in &paths {
+            let category = storage::derive_category(config, path);
+            let (moved, _) = storage::move_to_category(config, path, Status::Open, Some("moved"))?;
+            storage::move_to_category(config, &moved, Status::Open, category.as_deref())?;
+        }
+        Ok(())
+    })
+}
+
+/// Times [`attachment_moves`] against a generated project.
+pub fn run_attachment_moves(config: &Config, runs: usize) -> Result<Measurement> {
+    let mut moves = attachment_moves(config)?;
+    let mut result = Ok(());
+    let measurement = measure("attachment_move", runs, || {
+        if let Err(e) = moves() {
+            result = Err(e);
+        }
+    });
+    result.map(|()| measurement)
+}
+
+/// Runs `f` the given number of times and records the median duration.
+pub fn measure(name: &str, runs: usize, mut f: impl FnMut()) -> Measurement {
+    let runs = runs.max(1);
+    let mut durations: Vec<Duration> = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+    durations.sort();
+    Measurement {
+        name: name.to_string(),
+        median_us: u64::try_from(durations[runs / 2].as_micros()).unwrap_or(u64::MAX),
+        runs,
+    }
+}
+
+/// Compares measurements against a baseline.
+///
+/// Returns a message for every case that got more than `threshold_percent`
+/// slower. Cases missing from either side are ignored.
+pub fn regressions(
+    baseline: &[Measurement],
+    current: &[Measurement],
+    threshold_percent: u32,
+) -> Vec<String> {
+    current
+        .iter()
+        .filter_map(|now| {
+            let before = baseline.iter().find(|b| b.name == now.name)?;
+            let limit = before.median_us + before.median_us * u64::from(threshold_percent) / 100;
+            (now.median_us > limit).then(|| {
+                format!(
+                    "{}: {} -> {} (limit {})",
+                    now.name,
+                    format_duration(before.median_us),
+                    format_duration(now.median_us),
+                    format_duration(limit)
+                )
+            })
+        })
+        .collect()
+}
+
+/// Formats a duration in microseconds for display (e.g. `850µs`, `12.3ms`, `1.20s`).
+pub fn format_duration(us: u64) -> String {
+    #[allow(clippy::cast_precision_loss)]
+    let value = us as f64;
+    if us < 1_000 {
+        format!("{us}µs")
+    } else if us < 1_000_000 {
+        format!("{:.1}ms", value / 1_000.0)
+    } else {
+        format!("{:.2}s", value / 1_000_000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(name: &str, median_us: u64) -> Measurement {
+        Measurement {
+            name: name.to_string(),
+            median_us,
+            runs: 5,
+        }
+    }
+
+    #[test]
+    fn test_regressions_over_threshold() {
+        let baseline = [measurement("walk", 1000), measurement("parse", 1000)];
+        let current = [
+            measurement("walk", 1200),
+            measurement("parse", 1201),
+            measurement("search", 9999),
+        ];
+
+        assert_eq!(
+            regressions(&baseline, &current, 20),
+            vec!["parse: 1.0ms -> 1.2ms (limit 1.2ms)"]
+        );
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(850), "850µs");
+        assert_eq!(format_duration(12_345), "12.3ms");
+        assert_eq!(format_duration(1_200_000), "1.20s");
+    }
+}
@@ -0,0 +1,154 @@
+//! # Clipboard
+//!
+//! Copies an item's ID, path, link, or Markdown link to the system clipboard
+//! (`qs copy` and the item action menu). The platform's tool does the copying:
+//! `pbcopy` on macOS, `clip` on Windows, and `wl-copy`, `xclip`, or `xsel`
+//! elsewhere. Without one, a terminal supporting OSC 52 is asked to set the
+//! clipboard, which also works over SSH.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    io::{self, IsTerminal, Write},
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{config::Config, item::Item, permalink};
+
+/// What to copy for an item
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum CopyWhat {
+    /// Item ID
+    #[default]
+    Id,
+    /// Item file path, relative to the working directory
+    Path,
+    /// Shareable link (as printed by `qs url`)
+    Url,
+    /// Markdown link to the item, titled with the item's title
+    MarkdownLink,
+}
+
+impl CopyWhat {
+    /// Describes the copied text in messages ("Copied link: ...").
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Id => "ID",
+            Self::Path => "path",
+            Self::Url => "link",
+            Self::MarkdownLink => "Markdown link",
+        }
+    }
+}
+
+/// Returns the text copied for an item stored at `path`.
+pub fn item_text(config: &Config, item: &Item, path: &Path, what: CopyWhat) -> String {
+    match what {
+        CopyWhat::Id => item.qualified_id(),
+        CopyWhat::Path => config.relative_path(path).display().to_string(),
+        CopyWhat::Url => permalink::link(config, item.id(), path),
+        CopyWhat::MarkdownLink => {
+            let title = item.title().replace('[', "\\[").replace(']', "\\]");
+            format!("[{title}]({})", permalink::link(config, item.id(), path))
+        }
+    }
+}
+
+/// Copies text to the system clipboard.
+pub fn copy(text: &str) -> Result<()> {
+    for (program, args) in tools() {
+        match run_tool(program, args, text) {
+            Ok(()) => return Ok(()),
+            // Not installed: try the next tool
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e).with_context(|| format!("Failed to copy with {program}")),
+        }
+    }
+
+    let mut stderr = io::stderr();
+    if stderr.is_terminal() {
+        write!(stderr, "\x1b]52;c;{}\x07", base64(text.as_bytes()))?;
+        stderr.flush()?;
+        return Ok(());
+    }
+
+    bail!("No clipboard tool found (install wl-copy, xclip, or xsel)")
+}
+
+/// Clipboard tools to try, in order
+fn tools() -> Vec<(&'static str, &'static [&'static str])> {
+    if cfg!(target_os = "macos") {
+        vec![("pbcopy", &[])]
+    } else if cfg!(windows) {
+        vec![("clip", &[])]
+    } else {
+        let mut tools: Vec<(&'static str, &'static [&'static str])> = Vec::new();
+        if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            tools.push(("wl-copy", &[]));
+        }
+        tools.push(("xclip", &["-selection", "clipboard"]));
+        tools.push(("xsel", &["--clipboard", "--input"]));
+        tools
+    }
+}
+
+/// Pipes the text into a clipboard tool.
+fn run_tool(program: &str, args: &[&str], text: &str) -> io::Result<()> {
+    // xclip and xsel keep running to serve the selection: don't let them hold
+    // on to our output
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(io::Error::other(format!("{program} exited with {status}")));
+    }
+    Ok(())
+}
+
+/// Standard base64 with padding, for the OSC 52 escape sequence.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = u32::from(b[0]) << 16 | u32::from(b[1]) << 8 | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(char::from(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize]));
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64("260109-ABC ü".as_bytes()), "MjYwMTA5LUFCQyDDvA==");
+    }
+}
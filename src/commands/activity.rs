@@ -0,0 +1,266 @@
+//! # Activity Command
+//!
+//! Prints a chronological feed of what happened in the project: items
+//! created, updated, closed or reopened, and files attached.
+//!
+//! Creation comes from the items' `created_at` and author, so it shows up even
+//! before anything is committed. Everything else is read from the git history
+//! of the stack directory: a commit moving an item's file into the archive
+//! closes it, one moving it back reopens it, any other commit touching it is an
+//! update, and one adding a file below its attachments directory attaches it.
+//! Items are matched by ID, so renames and category moves are followed. Actors
+//! are unified through the configured identities.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+use anyhow::Result;
+use chrono::{DateTime, Local, Utc};
+use owo_colors::OwoColorize;
+
+use crate::{
+    config::Config,
+    constants::ATTACHMENTS_DIR_SUFFIX,
+    item::{identity::Identities, Item, Status},
+    storage::{self, vcs},
+};
+
+/// Seconds between checks for new activity with `--follow`
+const FOLLOW_INTERVAL_SECS: u64 = 2;
+
+/// Arguments for the activity command
+pub struct ActivityArgs {
+    /// Only show activity at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Most events shown (the latest ones), 0 for all
+    pub limit: usize,
+    /// Keep running and print new activity as it happens
+    pub follow: bool,
+}
+
+/// What happened to an item
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Action {
+    Created,
+    Updated,
+    Closed,
+    Reopened,
+    Attached(String),
+}
+
+/// One entry of the feed
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Event {
+    date: DateTime<Utc>,
+    actor: String,
+    action: Action,
+    id: String,
+    title: String,
+}
+
+impl Event {
+    /// Formats the event as one line: time, actor, what happened, and the item.
+    fn format(&self, color: bool) -> String {
+        let time = DateTime::<Local>::from(self.date)
+            .format("%Y-%m-%d %H:%M")
+            .to_string();
+        let verb = match self.action {
+            Action::Created => "created".to_string(),
+            Action::Updated => "updated".to_string(),
+            Action::Closed => "closed".to_string(),
+            Action::Reopened => "reopened".to_string(),
+            Action::Attached(ref file) => format!("attached {file} to"),
+        };
+        if color {
+            format!(
+                "{}  {} {verb} {}  {}",
+                time.dimmed(),
+                self.actor.bold(),
+                self.id.cyan(),
+                self.title
+            )
+        } else {
+            format!("{time}  {} {verb} {}  {}", self.actor, self.id, self.title)
+        }
+    }
+}
+
+/// Executes the activity command.
+pub fn execute(args: &ActivityArgs) -> Result<()> {
+    let config = Config::load()?;
+    let color = std::io::IsTerminal::is_terminal(&std::io::stdout());
+
+    let mut events = collect(&config, args.since)?;
+    if events.is_empty() && !args.follow {
+        println!("No activity found.");
+        return Ok(());
+    }
+    let mut seen: HashSet<Event> = events.iter().cloned().collect();
+    if args.limit > 0 && events.len() > args.limit {
+        events.drain(..events.len() - args.limit);
+    }
+    for event in &events {
+        println!("{}", event.format(color));
+    }
+
+    if !args.follow {
+        return Ok(());
+    }
+    loop {
+        thread::sleep(Duration::from_secs(FOLLOW_INTERVAL_SECS));
+        for event in collect(&config, args.since)? {
+            if seen.insert(event.clone()) {
+                println!("{}", event.format(color));
+            }
+        }
+    }
+}
+
+/// Builds the feed, oldest first.
+fn collect(config: &Config, since: Option<DateTime<Utc>>) -> Result<Vec<Event>> {
+    let items = storage::load_all_items(config);
+    let history = vcs::history(&config.stack_path())?;
+    let identities = config.identities();
+
+    let mut events: Vec<Event> = items
+        .iter()
+        .filter(|item| item.status() != Status::Template)
+        .map(|item| {
+            event(
+                item,
+                item.created_at(),
+                item.author(),
+                Action::Created,
+                &identities,
+            )
+        })
+        .collect();
+    events.extend(from_history(config, &items, &history, &identities));
+
+    events.retain(|event| since.map_or(true, |since| event.date >= since));
+    events.sort_by_key(|event| event.date);
+    Ok(events)
+}
+
+/// Derives updates, closes, reopens, and attachments from the commits.
+///
+/// The commit first adding an item's file is its creation (already in the
+/// feed from `created_at`) and is skipped. A commit moving the file into the
+/// archive closes the item and one moving it out reopens it; any other commit
+/// touching it is one update.
+fn from_history(
+    config: &Config,
+    items: &[Item],
+    history: &[vcs::Commit],
+    identities: &Identities,
+) -> Vec<Event> {
+    let by_id: HashMap<&str, &Item> = items.iter().map(|item| (item.id(), item)).collect();
+    let mut committed: HashSet<&str> = HashSet::new();
+    let mut archived: HashSet<&str> = HashSet::new();
+    let mut attached: HashSet<(&str, String)> = HashSet::new();
+
+    let mut events = Vec::new();
+    for commit in history {
+        // Per item: (touched in the archive, touched outside it), in commit order
+        let mut touched: Vec<(&Item, bool, bool)> = Vec::new();
+        for file in &commit.files {
+            let path = Path::new(file);
+            let Some(item) = path
+                .iter()
+                .find_map(|component| item_of(&component.to_string_lossy(), &by_id))
+            else {
+                continue;
+            };
+
+            let attachment_dir = path.iter().any(|component| {
+                component
+                    .to_string_lossy()
+                    .ends_with(ATTACHMENTS_DIR_SUFFIX)
+            });
+            if attachment_dir {
+                let name = path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                if attached.insert((item.id(), name.clone())) {
+                    events.push(event(
+                        item,
+                        commit.date,
+                        &commit.author,
+                        Action::Attached(name),
+                        identities,
+                    ));
+                }
+                continue;
+            }
+
+            let in_archive = path
+                .iter()
+                .any(|component| component == config.archive_dir());
+            match touched
+                .iter_mut()
+                .find(|(other, ..)| other.id() == item.id())
+            {
+                Some(entry) if in_archive => entry.1 = true,
+                Some(entry) => entry.2 = true,
+                None => touched.push((item, in_archive, !in_archive)),
+            }
+        }
+
+        for (item, in_archive, outside) in touched {
+            let id = item.id();
+            let first = committed.insert(id);
+            let was_archived = archived.contains(id);
+            let action = match (in_archive, outside) {
+                // Moved between the stack and the archive
+                (true, true) if was_archived => Action::Reopened,
+                (true, true) => Action::Closed,
+                // Added (or first seen) in the archive
+                (true, false) if !was_archived => Action::Closed,
+                _ if first => continue,
+                _ => Action::Updated,
+            };
+            match action {
+                Action::Closed => archived.insert(id),
+                Action::Reopened => archived.remove(id),
+                _ => false,
+            };
+            events.push(event(item, commit.date, &commit.author, action, identities));
+        }
+    }
+    events
+}
+
+/// Finds the item a path component belongs to: its file (`<id>-<slug>.md` or
+/// `<id>.md`) or attachments directory.
+fn item_of<'a>(name: &str, by_id: &HashMap<&str, &'a Item>) -> Option<&'a Item> {
+    let stem = name.strip_suffix(".md").unwrap_or(name);
+    if let Some(item) = by_id.get(stem) {
+        return Some(item);
+    }
+    stem.match_indices('-')
+        .find_map(|(index, _)| by_id.get(&stem[..index]).copied())
+}
+
+fn event(
+    item: &Item,
+    date: DateTime<Utc>,
+    actor: &str,
+    action: Action,
+    identities: &Identities,
+) -> Event {
+    Event {
+        date,
+        actor: identities.canonical(actor).to_string(),
+        action,
+        id: item.id().to_string(),
+        title: item.title().to_string(),
+    }
+}
@@ -0,0 +1,177 @@
+//! # Agent Command
+//!
+//! Experimental: watches the queue and hands matching items to an agent.
+//!
+//! For each open item with all of the given labels (`auto` by default), the
+//! configured `agent_command` runs through the platform shell from the project
+//! root, oldest item first. Each item is claimed like `qs pop` claims it
+//! (marked in progress under the project lock) before the agent runs, and the
+//! agent's output is appended to the item as a comment. On exit status 0 the
+//! item is closed, otherwise it is reopened and labeled `agent-failed` so it is
+//! not picked up again until someone removes the label.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{fmt::Write as _, path::Path, thread, time::Duration};
+
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use owo_colors::OwoColorize;
+
+use super::{
+    close,
+    pop::{self, Order},
+};
+use crate::{
+    config::Config,
+    item::{action, comment, matches_all_labels, Item, Status},
+    storage::{self, LoadedItem},
+    ui,
+};
+
+/// Label added to items the agent failed on
+pub const FAILED_LABEL: &str = "agent-failed";
+
+/// Lines of agent output kept in the comment
+const MAX_OUTPUT_LINES: usize = 100;
+
+/// Arguments for the agent command
+pub struct AgentArgs {
+    /// Labels an item needs to be picked up
+    pub labels: Vec<String>,
+    /// Only pick up items in this category
+    pub category: Option<String>,
+    /// Process the current queue once and exit
+    pub once: bool,
+    /// Seconds between scans of the queue
+    pub interval: u64,
+}
+
+/// Executes the agent command.
+pub fn execute(args: &AgentArgs) -> Result<()> {
+    let config = Config::load()?;
+    let Some(command) = config.agent_command() else {
+        bail!("No agent command configured. Set agent_command in .queuestack or the global config");
+    };
+
+    if !args.once {
+        println!(
+            "Watching for open items labeled {} every {}s (Ctrl-C to stop)",
+            args.labels.join(", "),
+            args.interval
+        );
+    }
+
+    loop {
+        while let Some(LoadedItem { path, item }) =
+            pop::claim_next(&config, Order::Fifo, |item, path| {
+                wanted(&config, args, item, path)
+            })?
+        {
+            run_agent(&config, &command, &path, &item)?;
+        }
+        if args.once {
+            return Ok(());
+        }
+        thread::sleep(Duration::from_secs(args.interval));
+    }
+}
+
+/// Returns true if the agent should pick up the open item at `path`.
+fn wanted(config: &Config, args: &AgentArgs, item: &Item, path: &Path) -> bool {
+    matches_all_labels(item.labels(), &args.labels)
+        && !item.labels().iter().any(|label| label == FAILED_LABEL)
+        && args.category.as_ref().map_or(true, |category| {
+            storage::derive_category(config, path).as_ref() == Some(category)
+        })
+}
+
+/// Runs the agent on a claimed item and records the outcome.
+fn run_agent(config: &Config, command: &str, path: &Path, item: &Item) -> Result<()> {
+    println!("{} {} {}", "→".cyan(), item.id(), item.title());
+
+    let category = storage::derive_category(config, path).unwrap_or_default();
+    let command = action::expand(command, item.id(), path);
+    let output = action::shell(&command)
+        .current_dir(config.project_root())
+        .env("QS_ID", item.id())
+        .env("QS_TITLE", item.title())
+        .env("QS_FILE", path)
+        .env("QS_LABELS", item.labels().join(","))
+        .env("QS_CATEGORY", category)
+        .output()
+        .with_context(|| format!("Failed to run agent command: {command}"))?;
+
+    let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+    text.push_str(&String::from_utf8_lossy(&output.stderr));
+    let exit = output
+        .status
+        .code()
+        .map_or_else(|| "signal".to_string(), |code| code.to_string());
+
+    // The agent may have edited the item; record on top of its changes
+    if !path.exists() {
+        ui::print_warnings(&[format!(
+            "{} was moved or deleted by the agent; nothing recorded",
+            config.relative_path(path).display()
+        )]);
+        return Ok(());
+    }
+    let mut item = Item::load(path)?;
+    append_comment(&mut item, &exit, &text);
+
+    if output.status.success() {
+        match close::close_item(config, path, &mut item) {
+            Ok((new_path, warnings)) => {
+                ui::print_warnings(&warnings);
+                ui::print_success("Closed", config, &new_path);
+                return Ok(());
+            }
+            Err(err) => ui::print_warnings(&[format!("{}: {err:#}", item.id())]),
+        }
+    }
+
+    item.set_status(Status::Open);
+    item.add_label(FAILED_LABEL);
+    item.save(path)?;
+    let (_, warnings) = storage::move_to_status(config, path, Status::Open)?;
+    ui::print_warnings(&warnings);
+    println!(
+        "{} Agent failed on {} (exit {exit}), labeled {FAILED_LABEL}",
+        "✗".red(),
+        item.id()
+    );
+    Ok(())
+}
+
+/// Appends the agent's output to the item body as a comment.
+fn append_comment(item: &mut Item, exit: &str, output: &str) {
+    let lines: Vec<&str> = output.lines().collect();
+    let kept = &lines[lines.len().saturating_sub(MAX_OUTPUT_LINES)..];
+
+    let mut section = format!(
+        "### Agent run ({}, exit {exit})\n\n",
+        Utc::now().format("%Y-%m-%d %H:%M UTC")
+    );
+    if lines.len() > kept.len() {
+        let _ = write!(
+            section,
+            "(last {} of {} lines)\n\n",
+            kept.len(),
+            lines.len()
+        );
+    }
+    if kept.is_empty() {
+        section.push_str("(no output)\n");
+    } else {
+        section.push_str("```text\n");
+        for line in kept {
+            section.push_str(line);
+            section.push('\n');
+        }
+        section.push_str("```\n");
+    }
+
+    comment::append_section(item, &section);
+}
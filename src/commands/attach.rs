@@ -1,22 +1,33 @@
 //! # Attach Command
 //!
-//! Add or remove attachments from items.
+//! Add, remove, and open attachments, and find files attached to several items.
 //!
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
-use std::path::PathBuf;
+use std::{collections::BTreeMap, path::PathBuf, process::Command};
 
 use anyhow::{bail, Result};
 use owo_colors::OwoColorize;
 
-use crate::{config::Config, item::is_url, storage, ui};
+use crate::{
+    config::Config,
+    item::{is_object_url, is_url, Item},
+    policy::{self, Destructive, Edit},
+    storage::{self, checksum},
+    ui,
+};
+
+/// Length of the checksum prefix shown for duplicate groups
+const CHECKSUM_DISPLAY_LEN: usize = 12;
 
 /// Arguments for the attach add subcommand
 pub struct AttachAddArgs {
     pub id: Option<String>,
     pub file: Option<PathBuf>,
     pub sources: Vec<String>,
+    /// Attach to archived items too
+    pub force: bool,
 }
 
 /// Arguments for the attach remove subcommand
@@ -24,6 +35,34 @@ pub struct AttachRemoveArgs {
     pub id: Option<String>,
     pub file: Option<PathBuf>,
     pub indices: Vec<usize>,
+    /// Remove even if the item is protected
+    pub force: bool,
+}
+
+/// Arguments for the attach open subcommand
+pub struct AttachOpenArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+    /// 1-based attachment index
+    pub index: usize,
+    /// Print the resolved path or URL instead of opening it
+    pub print: bool,
+}
+
+/// Arguments for the attach duplicates subcommand
+pub struct AttachDuplicatesArgs {
+    /// Hard-link local duplicates into the shared store
+    pub link: bool,
+}
+
+/// One attachment in a group of identical files
+struct AttachmentCopy {
+    id: String,
+    item_path: PathBuf,
+    attachment: String,
+    /// Local file, if the attachment is stored on disk
+    file: Option<PathBuf>,
+    recorded: bool,
 }
 
 /// Executes the attach add command.
@@ -38,18 +77,17 @@ pub fn execute_add(args: &AttachAddArgs) -> Result<()> {
     let item_ref = storage::ItemRef::from_options(args.id.clone(), args.file.clone())?;
     let storage::LoadedItem { path, mut item } = item_ref.resolve(&config)?;
 
-    // Check item is not closed
-    if item.status() == crate::item::Status::Closed {
-        bail!("Cannot attach to a closed item. Use 'qs reopen' first.");
-    }
+    policy::check_editable(&config, Edit::Attach, &item, &path, args.force)?;
 
     // Process attachments
-    let added_count = ui::process_and_save_attachments(&mut item, &path, &args.sources)?;
+    let added_count = ui::process_and_save_attachments(&config, &mut item, &path, &args.sources)?;
 
     if added_count == 0 {
-        bail!("No attachments were added (all files not found)");
+        bail!("No attachments were added (all files missing or rejected)");
     }
 
+    storage::vault::sync(&config)?;
+
     println!(
         "\n{} Added {} attachment(s) to {}",
         "✓".green(),
@@ -77,9 +115,18 @@ pub fn execute_remove(args: &AttachRemoveArgs) -> Result<()> {
         bail!("Item has no attachments");
     }
 
+    let category = storage::derive_category(&config, &path);
+    policy::check_protected(
+        &config,
+        Destructive::RemoveAttachment,
+        &item,
+        category.as_deref(),
+        args.force,
+    )?;
+
     // Get attachment directory
     let attachment_dir = item
-        .attachment_dir()
+        .attachment_dir(&config)
         .ok_or_else(|| anyhow::anyhow!("Invalid item path"))?;
 
     // Validate all indices first (1-based from user)
@@ -104,8 +151,18 @@ pub fn execute_remove(args: &AttachRemoveArgs) -> Result<()> {
         let idx_0 = idx - 1;
 
         if let Some(removed) = item.remove_attachment(idx_0) {
-            // If it's a file (not URL), delete from disk
-            if !is_url(&removed) {
+            // Objects we uploaded are deleted from storage; other URLs are references
+            if is_object_url(&removed) {
+                if let Err(e) = storage::delete_object_attachment(&config, &removed) {
+                    eprintln!(
+                        "  {} Failed to delete object {}: {}",
+                        "!".yellow(),
+                        removed,
+                        e
+                    );
+                }
+            } else if !is_url(&removed) {
+                // File attachment: delete from disk
                 if let Err(e) = storage::delete_attachment(&attachment_dir, &removed) {
                     eprintln!(
                         "  {} Failed to delete file {}: {}",
@@ -122,6 +179,7 @@ pub fn execute_remove(args: &AttachRemoveArgs) -> Result<()> {
 
     // Save updated item
     item.save(&path)?;
+    storage::vault::sync(&config)?;
 
     println!(
         "\n{} Removed {} attachment(s) from {}",
@@ -132,3 +190,140 @@ pub fn execute_remove(args: &AttachRemoveArgs) -> Result<()> {
 
     Ok(())
 }
+
+/// Executes the attach open subcommand.
+///
+/// Files open from the attachment directory, URLs as-is, and object storage
+/// attachments through a presigned URL.
+pub fn execute_open(args: &AttachOpenArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let item_ref = storage::ItemRef::from_options(args.id.clone(), args.file.clone())?;
+    let storage::LoadedItem { path, item } = item_ref.resolve(&config)?;
+
+    let attachment_count = item.attachments().len();
+    let Some(attachment) = args
+        .index
+        .checked_sub(1)
+        .and_then(|idx| item.attachments().get(idx))
+    else {
+        bail!(
+            "Invalid attachment index: {}. Item has {attachment_count} attachment(s). Use 'qs list --attachments --id <ID>' to see the list.",
+            args.index
+        );
+    };
+
+    let target = if is_object_url(attachment) {
+        storage::presign_object_attachment(attachment)?
+    } else if is_url(attachment) {
+        attachment.clone()
+    } else {
+        let file = storage::attachment_dir(&config, item.id(), &path).join(attachment);
+        if !file.exists() {
+            bail!("Attachment file not found: {}", file.display());
+        }
+        file.display().to_string()
+    };
+
+    if args.print {
+        println!("{target}");
+        return Ok(());
+    }
+
+    open_with_system(&target)
+}
+
+/// Executes the attach duplicates subcommand.
+///
+/// Groups file attachments by checksum. Attachments added before checksums
+/// were recorded are hashed from disk. With `link`, local duplicates are
+/// hard-linked into the shared store and missing checksums are recorded.
+pub fn execute_duplicates(args: &AttachDuplicatesArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let mut groups: BTreeMap<String, Vec<AttachmentCopy>> = BTreeMap::new();
+    for item_path in storage::walk_all(&config) {
+        let Ok(item) = Item::load(&item_path) else {
+            continue;
+        };
+        let dir = storage::attachment_dir(&config, item.id(), &item_path);
+        for attachment in item.attachments() {
+            let file = Some(dir.join(attachment)).filter(|f| !is_url(attachment) && f.is_file());
+            let (checksum, recorded) = match (item.checksum(attachment), &file) {
+                (Some(checksum), _) => (checksum.to_string(), true),
+                (None, Some(file)) => (checksum::sha256_file(file)?, false),
+                (None, None) => continue,
+            };
+            groups.entry(checksum).or_default().push(AttachmentCopy {
+                id: item.id().to_string(),
+                item_path: item_path.clone(),
+                attachment: attachment.clone(),
+                file,
+                recorded,
+            });
+        }
+    }
+    groups.retain(|_, copies| copies.len() > 1);
+
+    if groups.is_empty() {
+        println!("No duplicate attachments.");
+        return Ok(());
+    }
+
+    let mut linked = 0;
+    for (checksum, copies) in &groups {
+        let short = checksum.get(..CHECKSUM_DISPLAY_LEN).unwrap_or(checksum);
+        println!("{short}  {} copies", copies.len());
+        for copy in copies {
+            println!("  {}  {}", copy.id, copy.attachment);
+        }
+
+        if args.link {
+            for copy in copies {
+                if let Some(file) = &copy.file {
+                    storage::link_into_store(&config, file, checksum)?;
+                    linked += 1;
+                }
+                if !copy.recorded {
+                    let mut item = Item::load(&copy.item_path)?;
+                    item.set_checksum(copy.attachment.clone(), checksum.clone());
+                    item.save(&copy.item_path)?;
+                }
+            }
+        }
+    }
+
+    if args.link {
+        println!(
+            "Linked {linked} file(s) into {}",
+            config
+                .relative_path(&storage::attachment_store(&config))
+                .display()
+        );
+    }
+
+    Ok(())
+}
+
+/// Opens a path or URL with the platform's default handler.
+fn open_with_system(target: &str) -> Result<()> {
+    let mut cmd = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", "start", ""]);
+        cmd
+    } else {
+        Command::new("xdg-open")
+    };
+
+    let status = cmd
+        .arg(target)
+        .status()
+        .map_err(|e| anyhow::anyhow!("Failed to open {target}: {e}"))?;
+    if !status.success() {
+        bail!("Failed to open {target}");
+    }
+
+    Ok(())
+}
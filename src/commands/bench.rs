@@ -0,0 +1,71 @@
+//! # Bench Command
+//!
+//! Times the core operations (walking, parsing, ID lookup, search, ID
+//! generation) against the current project. Timings can be saved and compared against a baseline, failing when
+//! an operation got slower than the allowed threshold.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+
+use crate::{
+    bench::{self, Measurement},
+    config::Config,
+    storage,
+};
+
+/// Arguments for the bench command
+pub struct BenchArgs {
+    /// Runs per case (the median is reported)
+    pub runs: usize,
+    /// Write the timings to this JSON file
+    pub save: Option<PathBuf>,
+    /// Compare the timings against this JSON file
+    pub compare: Option<PathBuf>,
+    /// Allowed slowdown against the baseline, in percent
+    pub threshold: u32,
+}
+
+/// Executes the bench command.
+pub fn execute(args: &BenchArgs) -> Result<()> {
+    let config = Config::load()?;
+    let count = storage::walk_all(&config).count();
+    let measurements = bench::run(&config, args.runs);
+
+    println!("{count} items, median of {} runs:", args.runs.max(1));
+    for measurement in &measurements {
+        println!(
+            "  {:<14} {:>10}",
+            measurement.name,
+            bench::format_duration(measurement.median_us)
+        );
+    }
+
+    if let Some(ref path) = args.save {
+        let json = serde_json::to_string_pretty(&measurements)?;
+        std::fs::write(path, json + "\n")
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        println!("{} Saved timings to {}", "✓".green(), path.display());
+    }
+
+    if let Some(ref path) = args.compare {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read baseline {}", path.display()))?;
+        let baseline: Vec<Measurement> = serde_json::from_str(&content)
+            .with_context(|| format!("Invalid baseline {}", path.display()))?;
+        let regressions = bench::regressions(&baseline, &measurements, args.threshold);
+        if !regressions.is_empty() {
+            bail!(
+                "Slower than the baseline by more than {}%:\n  {}",
+                args.threshold,
+                regressions.join("\n  ")
+            );
+        }
+        println!("{} Within {}% of the baseline", "✓".green(), args.threshold);
+    }
+    Ok(())
+}
@@ -0,0 +1,308 @@
+//! # Bulk Command
+//!
+//! Exports the frontmatter of many items to one YAML file and applies the
+//! edited file back, for mass edits in an editor.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    io::IsTerminal,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+
+use super::list::{collect_items, sort_items, SortBy, StatusFilter};
+use crate::{
+    config::Config,
+    item::{FilterCriteria, Frontmatter, Item, Status},
+    policy::{self, Destructive, Edit},
+    storage::{self, plan::Plan},
+    ui::{self, OutputFormat},
+};
+
+/// Header written above the exported items
+const EXPORT_HEADER: &str = "\
+# Edit the items below, then apply the changes with 'qs bulk apply <file>'.
+# Items are matched by id; removed entries are left unchanged. created_at,
+# closed_at, attachments, checksums, and link_titles are read-only, and status
+# can only switch between open and in-progress.
+";
+
+/// Arguments for the bulk export subcommand
+pub struct BulkExportArgs {
+    pub status: StatusFilter,
+    pub labels: Vec<String>,
+    pub any_labels: Vec<String>,
+    pub not_labels: Vec<String>,
+    pub author: Option<String>,
+    pub category: Option<String>,
+    /// Write to a file instead of stdout
+    pub output: Option<PathBuf>,
+    /// Export protected items too
+    pub force: bool,
+}
+
+/// Arguments for the bulk apply subcommand
+pub struct BulkApplyArgs {
+    /// File written by `qs bulk export`
+    pub file: PathBuf,
+    /// Show the changes without applying them
+    pub dry_run: bool,
+    /// Apply changes to archived items too
+    pub force: bool,
+}
+
+/// Executes the bulk export subcommand.
+pub fn execute_export(args: &BulkExportArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let filter = FilterCriteria {
+        labels: args.labels.clone(),
+        any_labels: args.any_labels.clone(),
+        not_labels: args.not_labels.clone(),
+        author: args.author.clone(),
+        identities: config.identities(),
+        category: args.category.clone(),
+        ..FilterCriteria::default()
+    };
+    let mut items = match args.status {
+        StatusFilter::Open => collect_items(&config, false, &filter),
+        StatusFilter::Closed => collect_items(&config, true, &filter),
+        StatusFilter::All => {
+            let mut items = collect_items(&config, false, &filter);
+            items.extend(collect_items(&config, true, &filter));
+            items
+        }
+    };
+    sort_items(&mut items, SortBy::Id);
+
+    for item in &items {
+        let category = item
+            .path
+            .as_ref()
+            .and_then(|p| storage::derive_category(&config, p));
+        policy::check_protected(
+            &config,
+            Destructive::Export,
+            item,
+            category.as_deref(),
+            args.force,
+        )?;
+    }
+
+    let frontmatter: Vec<&Frontmatter> = items.iter().map(|item| &item.frontmatter).collect();
+    let yaml = if frontmatter.is_empty() {
+        "[]\n".to_string()
+    } else {
+        serde_yml::to_string(&frontmatter).context("Failed to serialize items")?
+    };
+    let content = format!("{EXPORT_HEADER}{yaml}");
+
+    if let Some(ref path) = args.output {
+        fs::write(path, content)
+            .with_context(|| format!("Failed to write export: {}", path.display()))?;
+        eprintln!(
+            "{} Exported {} items to {}",
+            "✓".green(),
+            items.len(),
+            path.display()
+        );
+    } else {
+        print!("{content}");
+    }
+
+    Ok(())
+}
+
+/// An edited item, validated and ready to save.
+struct BulkChange {
+    path: PathBuf,
+    before: Item,
+    after: Item,
+}
+
+/// Executes the bulk apply subcommand.
+pub fn execute_apply(args: &BulkApplyArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let content = fs::read_to_string(&args.file)
+        .with_context(|| format!("Failed to read {}", args.file.display()))?;
+    let entries: Vec<Frontmatter> = serde_yml::from_str(&content)
+        .with_context(|| format!("Failed to parse {}", args.file.display()))?;
+
+    let changes = prepare(&config, entries, args.force)?;
+    if changes.is_empty() {
+        println!("{}", "No changes to apply.".dimmed());
+        return Ok(());
+    }
+
+    let plan = plan(&config, &changes)?;
+    plan.print(OutputFormat::Text)?;
+    if args.dry_run {
+        return Ok(());
+    }
+
+    if config.interactive()
+        && std::io::stdout().is_terminal()
+        && ui::confirm(&format!("Apply changes to {} items?", changes.len()))? != Some(true)
+    {
+        println!("{}", "Cancelled.".dimmed());
+        return Ok(());
+    }
+
+    for change in &changes {
+        change.after.save(&change.path)?;
+
+        // Keep the filename in sync with the title
+        let path = storage::rename_item(&change.path, &change.after.filename())?;
+
+        // Move between status directories
+        if change.after.status() != change.before.status() {
+            let (_, warnings) = storage::move_to_status(&config, &path, change.after.status())?;
+            ui::print_warnings(&warnings);
+        }
+    }
+
+    storage::vault::sync(&config)?;
+
+    println!("{} Updated {} items", "✓".green(), changes.len());
+    Ok(())
+}
+
+/// Matches the entries to stored items and validates the edits.
+///
+/// All problems are collected so a single run reports every invalid entry.
+/// Returns only the items whose frontmatter actually changed.
+fn prepare(config: &Config, entries: Vec<Frontmatter>, force: bool) -> Result<Vec<BulkChange>> {
+    let mut stored: HashMap<String, Item> = storage::load_all_items(config)
+        .into_iter()
+        .map(|item| (item.id().to_string(), item))
+        .collect();
+
+    let mut seen = HashSet::new();
+    let mut problems = Vec::new();
+    let mut changes = Vec::new();
+
+    for frontmatter in entries {
+        let id = frontmatter.id.clone();
+        if !seen.insert(id.clone()) {
+            problems.push(format!("{id}: listed more than once"));
+            continue;
+        }
+        let Some(before) = stored.remove(&id) else {
+            problems.push(format!("{id}: no item with this id"));
+            continue;
+        };
+        let Some(path) = before.path.clone() else {
+            continue;
+        };
+
+        let mut after = before.clone();
+        after.frontmatter = frontmatter;
+        if let Err(problem) = validate(&before, &mut after) {
+            problems.push(format!("{id}: {problem}"));
+            continue;
+        }
+        if after.labels() != before.labels() {
+            if let Err(e) = policy::check_labels(config, &mut after, before.labels()) {
+                problems.push(format!("{id}: {e}"));
+                continue;
+            }
+        }
+        if after.frontmatter == before.frontmatter {
+            continue;
+        }
+        if let Err(e) = policy::check_editable(config, Edit::Bulk, &before, &path, force) {
+            problems.push(e.to_string());
+            continue;
+        }
+        changes.push(BulkChange {
+            path,
+            before,
+            after,
+        });
+    }
+
+    if !problems.is_empty() {
+        bail!(
+            "Invalid bulk edit, nothing was changed:\n  {}",
+            problems.join("\n  ")
+        );
+    }
+    Ok(changes)
+}
+
+/// Checks an edited item against the stored one.
+///
+/// Read-only fields are compared in their serialized form, since timestamps
+/// are written with the configured precision, and then restored from the
+/// stored item so unchanged values keep their full precision.
+fn validate(before: &Item, after: &mut Item) -> Result<(), String> {
+    let old = serde_yml::to_value(&before.frontmatter).map_err(|e| e.to_string())?;
+    let new = serde_yml::to_value(&after.frontmatter).map_err(|e| e.to_string())?;
+    for field in [
+        "created_at",
+        "closed_at",
+        "attachments",
+        "checksums",
+        "link_titles",
+    ] {
+        if old.get(field) != new.get(field) {
+            return Err(format!("{field} can't be changed"));
+        }
+    }
+    after.frontmatter.created_at = before.frontmatter.created_at;
+    after.frontmatter.closed_at = before.frontmatter.closed_at;
+
+    let (from, to) = (before.status(), after.status());
+    if from != to {
+        if from == Status::Closed {
+            return Err("use 'qs reopen' to reopen closed items".to_string());
+        }
+        if to == Status::Closed {
+            return Err("use 'qs close' to close items".to_string());
+        }
+        if to == Status::Template || from == Status::Template {
+            return Err("status can't change to or from template".to_string());
+        }
+    }
+
+    if after.title().trim().is_empty() {
+        return Err("title can't be empty".to_string());
+    }
+    if after.labels().iter().any(|label| label.trim().is_empty()) {
+        return Err("labels can't be empty".to_string());
+    }
+    Ok(())
+}
+
+/// Describes the file operations for the changes.
+fn plan(config: &Config, changes: &[BulkChange]) -> Result<Plan> {
+    let mut plan = Plan::new();
+    for change in changes {
+        plan.write(config, &change.path, &change.before, &change.after)?;
+
+        // Rename and status change combine into a single move
+        let dest_dir = if change.after.status() == change.before.status() {
+            change
+                .path
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default()
+        } else {
+            let category = storage::derive_category(config, &change.path);
+            storage::status_directory(config, change.after.status(), category.as_deref())
+        };
+        plan.move_item(
+            config,
+            &change.path,
+            &dest_dir.join(change.after.filename()),
+        );
+    }
+    Ok(plan)
+}
@@ -0,0 +1,56 @@
+//! # Clean Command
+//!
+//! Sweeps the stack for empty category, archive, attachment, and trash
+//! directories left behind by hand-deleted items or older versions, and
+//! removes them. Git doesn't track empty directories, so this only tidies the
+//! working copy.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use crate::{config::Config, storage};
+
+/// Arguments for the clean command
+pub struct CleanArgs {
+    /// List the directories without removing them
+    pub dry_run: bool,
+}
+
+/// Executes the clean command.
+pub fn execute(args: &CleanArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let dirs = if args.dry_run {
+        storage::find_empty_dirs(&config)
+    } else {
+        storage::remove_empty_dirs(&config)?
+    };
+    if dirs.is_empty() {
+        println!("No empty directories.");
+        return Ok(());
+    }
+
+    let mut paths: Vec<String> = dirs
+        .iter()
+        .map(|dir| config.relative_path(dir).display().to_string())
+        .collect();
+    paths.sort();
+    for path in &paths {
+        println!("  {path}");
+    }
+
+    let noun = if paths.len() == 1 {
+        "directory"
+    } else {
+        "directories"
+    };
+    if args.dry_run {
+        println!("Would remove {} empty {noun}", paths.len());
+    } else {
+        println!("{} Removed {} empty {noun}", "✓".green(), paths.len());
+    }
+    Ok(())
+}
@@ -1,24 +1,65 @@
 //! # Close/Reopen Commands
 //!
 //! Closes or reopens queuestack items, moving them to/from the archive.
+//! `close --category` files the item under a different archive category and
+//! `reopen --to-category` restores it into a different category, each in a
+//! single move.
 //!
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 
-use crate::{config::Config, item::Status, storage, ui};
+use crate::{
+    config::Config,
+    item::{normalize_identifier, Item, Status},
+    policy::{self, Hook},
+    storage::{self, plan::Plan},
+    ui,
+};
 
 /// Executes the close command.
-pub fn execute_close(id: Option<String>, file: Option<PathBuf>) -> Result<()> {
-    execute_status_change(id, file, StatusChange::Close)
+///
+/// `category` files the item under that archive category (`done/2026`)
+/// instead of its current one.
+pub fn execute_close(
+    id: Option<String>,
+    file: Option<PathBuf>,
+    category: Option<&str>,
+) -> Result<()> {
+    execute_status_change(id, file, StatusChange::Close, category)
 }
 
 /// Executes the reopen command.
-pub fn execute_reopen(id: Option<String>, file: Option<PathBuf>) -> Result<()> {
-    execute_status_change(id, file, StatusChange::Reopen)
+///
+/// `category` restores the item into that category instead of the one it was
+/// archived under.
+pub fn execute_reopen(
+    id: Option<String>,
+    file: Option<PathBuf>,
+    category: Option<&str>,
+) -> Result<()> {
+    execute_status_change(id, file, StatusChange::Reopen, category)
+}
+
+/// Computes what closing an item would do without executing it (`--dry-run`).
+pub fn plan_close(
+    id: Option<String>,
+    file: Option<PathBuf>,
+    category: Option<&str>,
+) -> Result<Plan> {
+    plan_status_change(id, file, StatusChange::Close, category)
+}
+
+/// Computes what reopening an item would do without executing it (`--dry-run`).
+pub fn plan_reopen(
+    id: Option<String>,
+    file: Option<PathBuf>,
+    category: Option<&str>,
+) -> Result<Plan> {
+    plan_status_change(id, file, StatusChange::Reopen, category)
 }
 
 /// Specifies the type of status change operation.
@@ -33,6 +74,7 @@ fn execute_status_change(
     id: Option<String>,
     file: Option<PathBuf>,
     operation: StatusChange,
+    category: Option<&str>,
 ) -> Result<()> {
     let config = Config::load()?;
 
@@ -40,10 +82,96 @@ fn execute_status_change(
     let item_ref = storage::ItemRef::from_options(id, file)?;
     let storage::LoadedItem { path, mut item } = item_ref.resolve(&config)?;
 
+    let category = category.map(normalize_category);
+    let (new_path, warnings) =
+        apply_status_change(&config, &path, &mut item, operation, category.as_deref())?;
+
+    // Print any attachment move warnings
+    ui::print_warnings(&warnings);
+
+    // Print success message
+    let verb = match operation {
+        StatusChange::Close => "Closed",
+        StatusChange::Reopen => "Reopened",
+    };
+    ui::print_success(verb, &config, &new_path);
+
+    Ok(())
+}
+
+/// Plans the status update and archive move of a close/reopen operation.
+fn plan_status_change(
+    id: Option<String>,
+    file: Option<PathBuf>,
+    operation: StatusChange,
+    category: Option<&str>,
+) -> Result<Plan> {
+    let config = Config::load()?;
+
+    let item_ref = storage::ItemRef::from_options(id, file)?;
+    let storage::LoadedItem { path, item } = item_ref.resolve(&config)?;
+
+    let category = category.map(normalize_category);
+    let target_status = match operation {
+        StatusChange::Close => Status::Closed,
+        StatusChange::Reopen => Status::Open,
+    };
+    let dest_dir = match (operation, category.as_deref()) {
+        (StatusChange::Close, None) => storage::archive_directory(&config, &path),
+        (StatusChange::Close, Some(cat)) => storage::archive_category_path(&config, cat)?,
+        (StatusChange::Reopen, None) => storage::unarchive_directory(&config, &path),
+        (StatusChange::Reopen, Some(cat)) => {
+            storage::check_stack_category(&config, Some(cat))?;
+            storage::status_directory(&config, Status::Open, Some(cat))
+        }
+    };
+
+    let mut plan = Plan::new();
+    if item.status() == target_status {
+        return Ok(plan);
+    }
+
+    let mut updated = item.clone();
+    updated.set_status(target_status);
+    plan.write(&config, &path, &item, &updated)?;
+    plan.move_item(
+        &config,
+        &path,
+        &dest_dir.join(path.file_name().unwrap_or_default()),
+    );
+
+    Ok(plan)
+}
+
+/// Closes an item and moves it into the archive.
+///
+/// Returns the new path and any attachment move warnings.
+pub fn close_item(config: &Config, path: &Path, item: &mut Item) -> Result<(PathBuf, Vec<String>)> {
+    apply_status_change(config, path, item, StatusChange::Close, None)
+}
+
+/// Normalizes each `/`-separated segment of a category like `--category` does
+/// for `new` and `update`.
+fn normalize_category(category: &str) -> String {
+    category
+        .split('/')
+        .map(normalize_identifier)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Sets the new status, saves the item, and moves it to/from the archive.
+fn apply_status_change(
+    config: &Config,
+    path: &Path,
+    item: &mut Item,
+    operation: StatusChange,
+    category: Option<&str>,
+) -> Result<(PathBuf, Vec<String>)> {
     // Determine operation parameters
-    let (target_status, verb, state_name) = match operation {
-        StatusChange::Close => (Status::Closed, "Closed", "closed"),
-        StatusChange::Reopen => (Status::Open, "Reopened", "open"),
+    let (target_status, state_name) = match operation {
+        StatusChange::Close => (Status::Closed, "closed"),
+        StatusChange::Reopen => (Status::Open, "open"),
     };
 
     // Check if already in target state
@@ -51,21 +179,41 @@ fn execute_status_change(
         anyhow::bail!("Item '{}' is already {}", item.id(), state_name);
     }
 
+    // Validate the destination before touching the item
+    match (operation, category) {
+        (StatusChange::Close, Some(cat)) => {
+            storage::archive_category_path(config, cat)?;
+        }
+        (StatusChange::Reopen, Some(cat)) => storage::check_stack_category(config, Some(cat))?,
+        (_, None) => {}
+    }
+
+    if matches!(operation, StatusChange::Close) {
+        let category = storage::derive_category(config, path);
+        policy::check(
+            config,
+            Hook::PreClose,
+            item,
+            category.as_deref(),
+            Some(path),
+        )?;
+    }
+
     // Update status and save
     item.set_status(target_status);
-    item.save(&path)?;
+    item.save(path)?;
 
     // Move to/from archive
-    let (new_path, warnings) = match operation {
-        StatusChange::Close => storage::archive_item(&config, &path)?,
-        StatusChange::Reopen => storage::unarchive_item(&config, &path)?,
-    };
+    let moved = match (operation, category) {
+        (StatusChange::Close, None) => storage::archive_item(config, path),
+        (StatusChange::Close, Some(cat)) => storage::archive_item_to(config, path, cat),
+        (StatusChange::Reopen, None) => storage::unarchive_item(config, path),
+        (StatusChange::Reopen, Some(cat)) => {
+            storage::move_to_category(config, path, Status::Open, Some(cat))
+        }
+    }?;
 
-    // Print any attachment move warnings
-    ui::print_warnings(&warnings);
+    storage::vault::sync(config)?;
 
-    // Print success message
-    ui::print_success(verb, &config, &new_path);
-
-    Ok(())
+    Ok(moved)
 }
@@ -0,0 +1,98 @@
+//! # Comment Command
+//!
+//! Adds comments to an item and lists them. Comments are timestamped,
+//! authored sections at the end of the item body (see `item::comment`).
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use chrono::Utc;
+use owo_colors::OwoColorize;
+
+use crate::{
+    config::Config,
+    item::comment,
+    policy::{self, Edit},
+    storage,
+    ui::OutputFormat,
+};
+
+/// Arguments for the comment command
+pub struct CommentArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+    pub text: String,
+    /// Comment on archived items too
+    pub force: bool,
+}
+
+/// Arguments for the comments command
+pub struct CommentsArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+    pub format: OutputFormat,
+}
+
+/// Executes the comment command.
+pub fn execute_add(args: CommentArgs) -> Result<()> {
+    if args.text.trim().is_empty() {
+        bail!("Comment text cannot be empty");
+    }
+
+    let mut config = Config::load()?;
+
+    let item_ref = storage::ItemRef::from_options(args.id, args.file)?;
+    let storage::LoadedItem { path, mut item } = item_ref.resolve(&config)?;
+
+    policy::check_editable(&config, Edit::Comment, &item, &path, args.force)?;
+
+    let author = config.user_name_or_prompt()?;
+    comment::add(&mut item, &author, Utc::now(), &args.text);
+    item.save(&path)?;
+    storage::vault::sync(&config)?;
+
+    println!(
+        "{} Commented on {}",
+        "✓".green(),
+        config.relative_path(&path).display()
+    );
+    Ok(())
+}
+
+/// Executes the comments command.
+pub fn execute_list(args: CommentsArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let item_ref = storage::ItemRef::from_options(args.id, args.file)?;
+    let storage::LoadedItem { item, .. } = item_ref.resolve(&config)?;
+
+    let comments = comment::list(&item.body);
+
+    if args.format == OutputFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&comments)?);
+        return Ok(());
+    }
+
+    if comments.is_empty() {
+        println!("{}", "No comments.".dimmed());
+        return Ok(());
+    }
+
+    for (index, comment) in comments.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        let date = comment
+            .created_at
+            .map(|date| date.format("%Y-%m-%d %H:%M UTC").to_string())
+            .unwrap_or_default();
+        println!("{} {}", comment.author.bold(), date.dimmed());
+        for line in comment.text.lines() {
+            println!("  {line}");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! # Commit Template Command
+//!
+//! Prints a commit message prefix referencing the item being worked on.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+
+use crate::{
+    config::Config,
+    item::{Item, Status},
+    storage,
+};
+
+/// Arguments for the commit-template command
+pub struct CommitTemplateArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+}
+
+/// Executes the commit-template command.
+pub fn execute(args: &CommitTemplateArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let item = if args.id.is_some() || args.file.is_some() {
+        let item_ref = storage::ItemRef::from_options(args.id.clone(), args.file.clone())?;
+        item_ref.resolve(&config)?.item
+    } else {
+        in_progress_item(&config)?
+    };
+
+    println!("{}", commit_prefix(item.id()));
+
+    Ok(())
+}
+
+/// Formats the commit message prefix for an item ID: `[ID] `.
+pub fn commit_prefix(id: &str) -> String {
+    format!("[{id}] ")
+}
+
+/// Finds the single in-progress item.
+///
+/// Errors when no item or more than one item is in progress.
+pub fn in_progress_item(config: &Config) -> Result<Item> {
+    let mut items: Vec<Item> = storage::walk_items(config)
+        .filter_map(|path| Item::load(&path).ok())
+        .filter(|item| item.status() == Status::InProgress)
+        .collect();
+
+    match items.len() {
+        0 => bail!(
+            "No item is in progress. Claim one with 'qs patch --id <ID> --set status=in-progress' or pass --id"
+        ),
+        1 => Ok(items.remove(0)),
+        _ => {
+            items.sort_by(|a, b| a.id().cmp(b.id()));
+            let ids: Vec<_> = items.iter().map(Item::id).collect();
+            bail!(
+                "Multiple items are in progress:\n  {}\nPass --id to choose one",
+                ids.join("\n  ")
+            );
+        }
+    }
+}
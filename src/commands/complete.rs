@@ -0,0 +1,172 @@
+//! # Complete Command
+//!
+//! Fast prefix lookup of item references for editor and shell completion.
+//!
+//! Exposed as the hidden `qs __complete` command family and the daemon's
+//! `complete` method. Output lines are `value<TAB>description`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::Config,
+    id,
+    item::{fold, Item},
+    storage,
+};
+
+/// Default maximum number of completions returned
+pub const DEFAULT_LIMIT: usize = 50;
+
+/// What to complete
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CompleteKind {
+    /// Item IDs (matched by ID prefix or title word prefix)
+    #[default]
+    Ids,
+    /// Labels in use
+    Labels,
+    /// Categories in use
+    Categories,
+}
+
+/// A single completion candidate
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Completion {
+    pub value: String,
+    pub description: String,
+}
+
+/// Arguments for the hidden complete command
+pub struct CompleteArgs {
+    pub kind: CompleteKind,
+    pub prefix: String,
+    pub closed: bool,
+    pub limit: usize,
+}
+
+/// Executes the complete command.
+pub fn execute(args: &CompleteArgs) -> Result<()> {
+    let config = Config::load()?;
+    for completion in candidates(&config, args.kind, &args.prefix, args.closed, args.limit) {
+        println!("{}\t{}", completion.value, completion.description);
+    }
+    Ok(())
+}
+
+/// Returns completion candidates for a prefix.
+///
+/// For IDs, items whose ID starts with the prefix come first, followed by
+/// items with a title word starting with the prefix. Matching uses filenames
+/// only, so just the matched items are parsed.
+pub fn candidates(
+    config: &Config,
+    kind: CompleteKind,
+    prefix: &str,
+    include_archived: bool,
+    limit: usize,
+) -> Vec<Completion> {
+    match kind {
+        CompleteKind::Ids => complete_ids(config, prefix, include_archived, limit),
+        CompleteKind::Labels | CompleteKind::Categories => {
+            complete_metadata(config, kind, prefix, include_archived, limit)
+        }
+    }
+}
+
+fn complete_ids(
+    config: &Config,
+    prefix: &str,
+    include_archived: bool,
+    limit: usize,
+) -> Vec<Completion> {
+    let prefix = prefix.to_lowercase();
+    let paths: Vec<_> = if include_archived {
+        storage::walk_all(config).collect()
+    } else {
+        storage::walk_items(config).collect()
+    };
+
+    // (rank, id) -> path; rank 0 = ID prefix, rank 1 = title word prefix
+    let mut matches = BTreeMap::new();
+    for path in paths {
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let Some(item_id) = id::extract_from_filename(stem) else {
+            continue;
+        };
+
+        let rank = if item_id.to_lowercase().starts_with(&prefix) {
+            0
+        } else if stem[item_id.len()..]
+            .split('-')
+            .any(|word| !word.is_empty() && word.starts_with(&prefix))
+        {
+            1
+        } else {
+            continue;
+        };
+        matches.insert((rank, item_id.to_string()), path.clone());
+    }
+
+    matches
+        .into_iter()
+        .take(limit)
+        .map(|((_, item_id), path)| Completion {
+            description: Item::load(&path)
+                .map(|item| item.title().to_string())
+                .unwrap_or_default(),
+            value: item_id,
+        })
+        .collect()
+}
+
+fn complete_metadata(
+    config: &Config,
+    kind: CompleteKind,
+    prefix: &str,
+    include_archived: bool,
+    limit: usize,
+) -> Vec<Completion> {
+    let paths: Vec<_> = if include_archived {
+        storage::walk_all(config).collect()
+    } else {
+        storage::walk_items(config).collect()
+    };
+
+    // value -> number of items using it
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for path in paths {
+        let values = if kind == CompleteKind::Labels {
+            Item::load(&path)
+                .map(|item| item.labels().to_vec())
+                .unwrap_or_default()
+        } else {
+            storage::derive_category(config, &path)
+                .into_iter()
+                .collect()
+        };
+        for value in values {
+            if fold::starts_with(&value, prefix) {
+                *counts.entry(value).or_default() += 1;
+            }
+        }
+    }
+
+    counts
+        .into_iter()
+        .take(limit)
+        .map(|(value, count)| Completion {
+            value,
+            description: format!("{count} item(s)"),
+        })
+        .collect()
+}
@@ -0,0 +1,43 @@
+//! # Copy Command
+//!
+//! Copies an item's ID, path, link, or Markdown link to the system clipboard,
+//! e.g. to paste the ID into a commit message.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use crate::{
+    clipboard::{self, CopyWhat},
+    config::Config,
+    item::Item,
+    storage,
+};
+
+/// Arguments for the copy command
+pub struct CopyArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+    pub what: CopyWhat,
+}
+
+/// Executes the copy command.
+pub fn execute(args: &CopyArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let item_ref = storage::ItemRef::from_options(args.id.clone(), args.file.clone())?;
+    let storage::LoadedItem { item, path } = item_ref.resolve(&config)?;
+    copy_item(&config, &item, &path, args.what)
+}
+
+/// Copies the text for an item to the clipboard and reports what was copied.
+pub fn copy_item(config: &Config, item: &Item, path: &Path, what: CopyWhat) -> Result<()> {
+    let text = clipboard::item_text(config, item, path, what);
+    clipboard::copy(&text)?;
+    println!("{} Copied {}: {text}", "✓".green(), what.name());
+    Ok(())
+}
@@ -0,0 +1,320 @@
+//! # Daemon Command
+//!
+//! Long-running JSON-RPC server for editor integrations.
+//!
+//! The daemon listens on a Unix socket and speaks JSON-RPC 2.0 with one
+//! request per line and one response per line. Editor extensions keep a
+//! connection open and avoid spawning `qs` for every lookup.
+//!
+//! Methods:
+//! - `list` - `{closed?, labels?, category?, author?}` → array of items
+//! - `search` - `{query, full_text?, closed?}` → array of items
+//! - `resolve-id` - `{id}` → item
+//! - `create` - `{title, labels?, category?}` → item
+//! - `close` - `{id}` → item (with its archived path)
+//! - `complete` - `{prefix, kind?, closed?, limit?}` → array of `{value, description}`
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Result};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::{
+    close,
+    complete::{self, CompleteKind},
+    list::{collect_items, sort_items, SortBy},
+    new,
+};
+use crate::{
+    config::Config,
+    item::{record::ItemRecord, search::rank_matches, FilterCriteria, Item, Status},
+    storage::{self, suggest::LookupError},
+};
+
+/// JSON-RPC error code for unknown methods
+const METHOD_NOT_FOUND: i64 = -32601;
+/// JSON-RPC error code for malformed requests
+const INVALID_REQUEST: i64 = -32600;
+/// JSON-RPC error code for failed method calls
+const SERVER_ERROR: i64 = -32000;
+
+/// Arguments for the daemon command
+pub struct DaemonArgs {
+    /// Socket path (defaults to a per-project path in the temp directory)
+    pub socket: Option<PathBuf>,
+}
+
+/// Executes the daemon command.
+pub fn execute(args: &DaemonArgs) -> Result<()> {
+    let config = Config::load()?;
+    let socket = args
+        .socket
+        .clone()
+        .unwrap_or_else(|| default_socket_path(&config));
+    serve(config, &socket)
+}
+
+/// Returns the default socket path for a project.
+///
+/// The path is stable per project root so editors can find a running daemon.
+pub fn default_socket_path(config: &Config) -> PathBuf {
+    std::env::temp_dir().join(format!("queuestack-{}.sock", config.project_key()))
+}
+
+#[cfg(unix)]
+fn serve(config: Config, socket: &Path) -> Result<()> {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        os::unix::net::UnixListener,
+        sync::Arc,
+        thread,
+    };
+
+    use anyhow::Context;
+
+    // A leftover socket from a previous run would make bind fail
+    if socket.exists() {
+        std::fs::remove_file(socket)
+            .with_context(|| format!("Failed to remove stale socket: {}", socket.display()))?;
+    }
+
+    let listener = UnixListener::bind(socket)
+        .with_context(|| format!("Failed to bind socket: {}", socket.display()))?;
+    eprintln!("Listening on {}", socket.display());
+
+    let config = Arc::new(config);
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else { continue };
+        let config = Arc::clone(&config);
+
+        // One thread per connection: editors keep their connection open
+        thread::spawn(move || {
+            // Settings like the frontmatter format are per thread
+            if config.apply_globals().is_err() {
+                return;
+            }
+            let Ok(mut writer) = stream.try_clone() else {
+                return;
+            };
+            for line in BufReader::new(stream).lines() {
+                let Ok(line) = line else { break };
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let response = handle_request(&config, &line);
+                if writeln!(writer, "{response}").is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn serve(_config: Config, _socket: &Path) -> Result<()> {
+    bail!("qs daemon requires Unix domain sockets, which are not available on this platform")
+}
+
+/// A JSON-RPC request
+#[derive(Deserialize)]
+struct Request {
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// Handles a single JSON-RPC request line and returns the response line.
+pub fn handle_request(config: &Config, line: &str) -> String {
+    let request: Request = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(e) => return error_response(&Value::Null, INVALID_REQUEST, &e.to_string()),
+    };
+
+    let result = match request.method.as_str() {
+        "list" => list(config, &request.params),
+        "search" => search(config, &request.params),
+        "resolve-id" => resolve_id(config, &request.params),
+        "create" => create(config, &request.params),
+        "close" => close(config, &request.params),
+        "complete" => complete(config, &request.params),
+        other => {
+            return error_response(
+                &request.id,
+                METHOD_NOT_FOUND,
+                &format!("Unknown method '{other}'"),
+            )
+        }
+    };
+
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": request.id, "result": result }).to_string(),
+        Err(e) => {
+            let mut response = error_value(&request.id, SERVER_ERROR, &format!("{e:#}"));
+            // Failed ID lookups carry their candidates as structured data
+            if let Some(lookup) = e.downcast_ref::<LookupError>() {
+                response["error"]["data"] = json!(lookup);
+            }
+            response.to_string()
+        }
+    }
+}
+
+fn error_response(id: &Value, code: i64, message: &str) -> String {
+    error_value(id, code, message).to_string()
+}
+
+fn error_value(id: &Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
+
+// =============================================================================
+// Methods
+// =============================================================================
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct ListParams {
+    closed: bool,
+    labels: Vec<String>,
+    any_labels: Vec<String>,
+    not_labels: Vec<String>,
+    category: Option<String>,
+    author: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SearchParams {
+    query: String,
+    #[serde(default)]
+    full_text: bool,
+    #[serde(default)]
+    closed: bool,
+}
+
+#[derive(Deserialize)]
+struct IdParams {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct CreateParams {
+    title: String,
+    #[serde(default)]
+    labels: Vec<String>,
+    category: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CompleteParams {
+    #[serde(default)]
+    prefix: String,
+    #[serde(default)]
+    kind: CompleteKind,
+    #[serde(default)]
+    closed: bool,
+    limit: Option<usize>,
+}
+
+fn parse_params<T: for<'de> Deserialize<'de>>(params: &Value) -> Result<T> {
+    // Missing params are treated as an empty object so optional fields default
+    let params = if params.is_null() { &json!({}) } else { params };
+    serde_json::from_value(params.clone()).map_err(|e| anyhow!("Invalid params: {e}"))
+}
+
+fn list(config: &Config, params: &Value) -> Result<Value> {
+    let params: ListParams = parse_params(params)?;
+    let filter = FilterCriteria {
+        labels: params.labels,
+        any_labels: params.any_labels,
+        not_labels: params.not_labels,
+        category: params.category,
+        author: params.author,
+        identities: config.identities(),
+        ..FilterCriteria::default()
+    };
+    let mut items = collect_items(config, params.closed, &filter);
+    sort_items(&mut items, SortBy::Id);
+    records(config, &items)
+}
+
+fn search(config: &Config, params: &Value) -> Result<Value> {
+    let params: SearchParams = parse_params(params)?;
+    let items = collect_items(config, params.closed, &FilterCriteria::default());
+    let items = rank_matches(items, &params.query, params.full_text);
+    records(config, &items)
+}
+
+fn resolve_id(config: &Config, params: &Value) -> Result<Value> {
+    let params: IdParams = parse_params(params)?;
+    let storage::LoadedItem { path, item } = storage::find_and_load_read_only(config, &params.id)?;
+    to_value(&ItemRecord::at(config, &item, &path))
+}
+
+fn create(config: &Config, params: &Value) -> Result<Value> {
+    let params: CreateParams = parse_params(params)?;
+    if params.title.trim().is_empty() {
+        bail!("Title cannot be empty");
+    }
+    // The daemon cannot prompt, so the author must be configured
+    let author = config.user_name().ok_or_else(|| {
+        anyhow!("No user name available. Set user_name in ~/.config/queuestack/config")
+    })?;
+
+    let (path, item) = new::create(
+        config,
+        params.title,
+        author,
+        &params.labels,
+        params.category.as_deref(),
+        Status::Open,
+    )?;
+    storage::vault::sync(config)?;
+    to_value(&ItemRecord::at(config, &item, &path))
+}
+
+fn close(config: &Config, params: &Value) -> Result<Value> {
+    let params: IdParams = parse_params(params)?;
+    let storage::LoadedItem { path, mut item } = storage::find_and_load(config, &params.id)?;
+    let (new_path, _warnings) = close::close_item(config, &path, &mut item)?;
+    to_value(&ItemRecord::at(config, &item, &new_path))
+}
+
+fn complete(config: &Config, params: &Value) -> Result<Value> {
+    let params: CompleteParams = parse_params(params)?;
+    let completions = complete::candidates(
+        config,
+        params.kind,
+        &params.prefix,
+        params.closed,
+        params.limit.unwrap_or(complete::DEFAULT_LIMIT),
+    );
+    to_value(&completions)
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+fn records(config: &Config, items: &[Item]) -> Result<Value> {
+    let records: Vec<ItemRecord> = items
+        .iter()
+        .filter_map(|item| item.path.as_ref().map(|p| ItemRecord::at(config, item, p)))
+        .collect();
+    to_value(&records)
+}
+
+fn to_value<T: serde::Serialize>(value: &T) -> Result<Value> {
+    serde_json::to_value(value).map_err(Into::into)
+}
@@ -0,0 +1,103 @@
+//! # Delete Command
+//!
+//! Deletes an item by moving it and its attachments into the `.trash/`
+//! directory (through `git mv` in git repositories), after a confirmation
+//! prompt (`--force` skips it for scripts and overrides `[protected]`). Emptied
+//! category directories are removed. Deleted items can be brought back with
+//! `qs trash restore`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    io::IsTerminal,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use crate::{
+    config::Config,
+    item::Item,
+    policy::{self, Destructive},
+    storage::{self, plan::Plan},
+    ui,
+};
+
+/// Arguments for the delete command
+pub struct DeleteArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+    /// Skip the confirmation and delete protected items too
+    pub force: bool,
+}
+
+/// Executes the delete command.
+pub fn execute(args: DeleteArgs) -> Result<()> {
+    let (config, storage::LoadedItem { path, item }) = resolve(args.id, args.file, args.force)?;
+
+    if !args.force {
+        if !config.interactive() || !std::io::stdout().is_terminal() {
+            bail!(
+                "Refusing to delete without confirmation; pass --force to delete non-interactively"
+            );
+        }
+        if ui::confirm(&format!("Delete '{}'?", item.title()))? != Some(true) {
+            println!("{}", "Cancelled.".dimmed());
+            return Ok(());
+        }
+    }
+
+    trash(&config, &path, &item)?;
+    storage::vault::sync(&config)
+}
+
+/// Computes the deletion without executing it (`--dry-run`).
+pub fn plan(args: DeleteArgs) -> Result<Plan> {
+    let (config, storage::LoadedItem { path, .. }) = resolve(args.id, args.file, args.force)?;
+
+    let mut plan = Plan::new();
+    let dest_dir = storage::trash::deletion_dir(&config, &path)?;
+    plan.move_item(
+        &config,
+        &path,
+        &dest_dir.join(path.file_name().unwrap_or_default()),
+    );
+    Ok(plan)
+}
+
+/// Loads the item to delete and checks that it isn't protected.
+fn resolve(
+    id: Option<String>,
+    file: Option<PathBuf>,
+    force: bool,
+) -> Result<(Config, storage::LoadedItem)> {
+    let config = Config::load()?;
+
+    let item_ref = storage::ItemRef::from_options(id, file)?;
+    let loaded = item_ref.resolve(&config)?;
+
+    let category = storage::derive_category(&config, &loaded.path);
+    policy::check_protected(
+        &config,
+        Destructive::Delete,
+        &loaded.item,
+        category.as_deref(),
+        force,
+    )?;
+    Ok((config, loaded))
+}
+
+/// Moves a confirmed item into the trash and reports how to restore it.
+pub fn trash(config: &Config, path: &Path, item: &Item) -> Result<()> {
+    let (_, warnings) = storage::delete_item(config, path)?;
+    ui::print_warnings(&warnings);
+    println!(
+        "{} Moved to trash: {} (restore with 'qs trash restore {}')",
+        "✓".green(),
+        config.relative_path(path).display(),
+        item.id()
+    );
+    Ok(())
+}
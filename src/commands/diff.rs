@@ -0,0 +1,101 @@
+//! # Diff Command
+//!
+//! Shows how an item changed since a git revision: a field-level frontmatter
+//! diff followed by a line diff of the body.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+
+use crate::{
+    config::Config,
+    item::{diff, parser},
+    storage::{self, git},
+};
+
+/// Lines of unchanged body context shown around each change
+const BODY_CONTEXT: usize = 2;
+
+/// Arguments for the diff command
+pub struct DiffArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+    /// Git revision to compare against (defaults to `HEAD`)
+    pub since: Option<String>,
+}
+
+/// Executes the diff command.
+pub fn execute(args: &DiffArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    if !git::is_git_repo() {
+        bail!("'qs diff' requires a git repository");
+    }
+
+    let item_ref = storage::ItemRef::from_options(args.id.clone(), args.file.clone())?;
+    let storage::LoadedItem { path, item } = item_ref.resolve(&config)?;
+    let rev = args.since.as_deref().unwrap_or("HEAD");
+
+    // Items move on close/recategorize, so locate the old version by ID
+    let Some(old_path) = find_at_revision(&config, rev, item.id())? else {
+        println!("{} did not exist at {rev}", item.id());
+        return Ok(());
+    };
+    let (old_frontmatter, old_body) = parser::parse(&git::file_at_revision(rev, &old_path)?)?;
+
+    let fields = diff::fields(&old_frontmatter, &item.frontmatter)?;
+    let body = diff::hunks(&old_body, &item.body, BODY_CONTEXT);
+    let moved = !same_file(&old_path, &path);
+
+    if fields.is_empty() && body.is_empty() && !moved {
+        println!("No changes since {rev}.");
+        return Ok(());
+    }
+
+    println!("{} {}", item.id(), item.title());
+    if moved {
+        println!(
+            "moved: {} -> {}",
+            config.relative_path(&old_path).display(),
+            config.relative_path(&path).display()
+        );
+    }
+    for change in &fields {
+        println!("{change}");
+    }
+    if !body.is_empty() {
+        println!("body:");
+        for line in &body {
+            println!("  {line}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Finds the item file with the given ID in the queuestack directory at `rev`.
+fn find_at_revision(config: &Config, rev: &str, id: &str) -> Result<Option<PathBuf>> {
+    let prefix = format!("{id}-");
+    let exact = format!("{id}.md");
+
+    Ok(git::files_at_revision(rev, &config.stack_path())?
+        .into_iter()
+        .find(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| {
+                    name == exact
+                        || (name.starts_with(&prefix)
+                            && Path::new(name).extension().is_some_and(|ext| ext == "md"))
+                })
+        }))
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    let a = a.canonicalize().unwrap_or_else(|_| a.to_path_buf());
+    let b = b.canonicalize().unwrap_or_else(|_| b.to_path_buf());
+    a == b
+}
@@ -0,0 +1,130 @@
+//! # Draft Command
+//!
+//! Edits an item's body in a draft kept outside the project and applies it
+//! only on `qs draft apply`, so an abandoned edit session never touches the
+//! tracked file.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Local};
+use owo_colors::OwoColorize;
+
+use crate::{
+    config::Config,
+    editor,
+    storage::{self, draft, draft::Draft},
+    ui,
+};
+
+/// Arguments for starting or continuing a draft
+pub struct DraftArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+}
+
+/// Arguments for the draft apply subcommand
+pub struct DraftApplyArgs {
+    /// Draft ID (partial match supported)
+    pub id: String,
+    /// Overwrite the body even if it changed since the draft was started
+    pub force: bool,
+}
+
+/// Arguments for the draft discard subcommand
+pub struct DraftDiscardArgs {
+    /// Draft ID (partial match supported)
+    pub id: String,
+}
+
+/// Starts (or continues) a draft of an item's body and opens it in the editor.
+pub fn execute_edit(args: &DraftArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let item_ref = storage::ItemRef::from_options(args.id.clone(), args.file.clone())?;
+    let storage::LoadedItem { item, .. } = item_ref.resolve(&config)?;
+
+    let (path, created) = draft::start(&config, &item)?;
+    if created {
+        println!("{} Started draft: {}", "✓".green(), path.display());
+    } else {
+        println!("Continuing draft: {}", path.display());
+    }
+    editor::open(&path, &config)?;
+
+    println!(
+        "Apply with 'qs draft apply --id {id}' or discard with 'qs draft discard --id {id}'",
+        id = item.id()
+    );
+    Ok(())
+}
+
+/// Applies a draft to its item and removes the draft.
+pub fn execute_apply(args: &DraftApplyArgs) -> Result<()> {
+    let config = Config::load()?;
+    let found = find(&config, &args.id)?;
+
+    let item_ref = storage::ItemRef::from_options(Some(found.id), None)?;
+    let storage::LoadedItem { path, mut item } = item_ref.resolve(&config)?;
+    draft::apply(&config, &path, &mut item, args.force)?;
+    storage::vault::sync(&config)?;
+
+    ui::print_success("Applied draft to", &config, &path);
+    ui::print_size_warning(&config, &path);
+    Ok(())
+}
+
+/// Deletes a draft without applying it.
+pub fn execute_discard(args: &DraftDiscardArgs) -> Result<()> {
+    let config = Config::load()?;
+    let found = find(&config, &args.id)?;
+    draft::discard(&config, &found.id)?;
+
+    println!("{} Discarded draft of {}", "✓".green(), found.id);
+    Ok(())
+}
+
+/// Lists the project's drafts.
+pub fn execute_list() -> Result<()> {
+    let config = Config::load()?;
+    let drafts = draft::list(&config)?;
+    if drafts.is_empty() {
+        println!("No drafts.");
+        return Ok(());
+    }
+
+    for found in drafts {
+        let modified = found.modified.map_or_else(String::new, |time| {
+            DateTime::<Local>::from(time)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        });
+        let title = storage::ItemRef::from_options(Some(found.id.clone()), None)
+            .and_then(|item_ref| item_ref.resolve(&config))
+            .map_or_else(
+                |_| "(item not found)".to_string(),
+                |loaded| loaded.item.title().to_string(),
+            );
+        println!("{}  {modified}  {title}", found.id);
+    }
+    Ok(())
+}
+
+/// Finds the draft whose ID starts with `id` (ignoring case).
+fn find(config: &Config, id: &str) -> Result<Draft> {
+    let mut matches: Vec<Draft> = draft::list(config)?
+        .into_iter()
+        .filter(|found| found.id.to_lowercase().starts_with(&id.to_lowercase()))
+        .collect();
+    match matches.len() {
+        0 => bail!("No draft matches '{id}'"),
+        1 => Ok(matches.remove(0)),
+        _ => {
+            let ids: Vec<&str> = matches.iter().map(|found| found.id.as_str()).collect();
+            bail!("'{id}' matches several drafts: {}", ids.join(", "))
+        }
+    }
+}
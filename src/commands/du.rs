@@ -0,0 +1,230 @@
+//! # Disk Usage Command
+//!
+//! Summarizes the disk space taken by each item (its file plus its attachment
+//! directory) and by each category, largest first, to decide what to prune or
+//! move to external storage. Items at or above a threshold are highlighted.
+//!
+//! Sizes are apparent file sizes: attachments deduplicated into the shared
+//! store (hard links) count for every item that links them, and URL and
+//! object-storage attachments take no space.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use walkdir::WalkDir;
+
+use crate::{
+    config::Config,
+    item::{Item, Status},
+    storage,
+    ui::{self, pad_to_width, OutputFormat},
+};
+
+/// Default size at which items are highlighted
+pub const DEFAULT_THRESHOLD: &str = "1MB";
+
+/// Arguments for the du command
+pub struct DuArgs {
+    /// Size at or above which items are highlighted (e.g. `500KB`, `10MB`)
+    pub threshold: String,
+    /// Number of items to list (0 for all)
+    pub limit: usize,
+    /// Leave out closed items
+    pub open: bool,
+    pub format: OutputFormat,
+}
+
+/// Disk usage of one item
+#[derive(Debug, Serialize)]
+struct ItemUsage {
+    id: String,
+    title: String,
+    category: Option<String>,
+    status: Status,
+    path: String,
+    /// Size of the item file
+    body_bytes: u64,
+    /// Size of the files in the attachment directory
+    attachment_bytes: u64,
+    total_bytes: u64,
+    /// Whether the total is at or above the threshold
+    over_threshold: bool,
+}
+
+/// Disk usage of one category (`None` for the stack root)
+#[derive(Debug, Serialize)]
+struct CategoryUsage {
+    category: Option<String>,
+    items: usize,
+    total_bytes: u64,
+}
+
+/// Full report
+#[derive(Debug, Serialize)]
+struct Report {
+    threshold_bytes: u64,
+    total_bytes: u64,
+    categories: Vec<CategoryUsage>,
+    items: Vec<ItemUsage>,
+}
+
+/// Executes the du command.
+pub fn execute(args: &DuArgs) -> Result<()> {
+    let config = Config::load()?;
+    let threshold = ui::parse_size(&args.threshold).with_context(|| {
+        format!(
+            "Invalid threshold '{}' (expected e.g. 500KB, 10MB)",
+            args.threshold
+        )
+    })?;
+
+    let paths: Vec<_> = if args.open {
+        storage::walk_items(&config).collect()
+    } else {
+        storage::walk_all(&config).collect()
+    };
+    let mut items: Vec<ItemUsage> = paths
+        .iter()
+        .filter_map(|path| Some((path, Item::load(path).ok()?)))
+        .map(|(path, item)| item_usage(&config, path, &item, threshold))
+        .collect();
+    items.sort_by(|a, b| b.total_bytes.cmp(&a.total_bytes).then(a.id.cmp(&b.id)));
+
+    let mut categories: BTreeMap<Option<String>, CategoryUsage> = BTreeMap::new();
+    for usage in &items {
+        let entry = categories
+            .entry(usage.category.clone())
+            .or_insert_with(|| CategoryUsage {
+                category: usage.category.clone(),
+                items: 0,
+                total_bytes: 0,
+            });
+        entry.items += 1;
+        entry.total_bytes += usage.total_bytes;
+    }
+    let mut categories: Vec<CategoryUsage> = categories.into_values().collect();
+    categories.sort_by_key(|usage| std::cmp::Reverse(usage.total_bytes));
+
+    let total_bytes = items.iter().map(|usage| usage.total_bytes).sum();
+    if args.limit > 0 {
+        items.truncate(args.limit);
+    }
+    let report = Report {
+        threshold_bytes: threshold,
+        total_bytes,
+        categories,
+        items,
+    };
+
+    if args.format == OutputFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_report(&report);
+    }
+    Ok(())
+}
+
+/// Measures an item's file and attachment directory.
+fn item_usage(config: &Config, path: &Path, item: &Item, threshold: u64) -> ItemUsage {
+    let body_bytes = path.metadata().map_or(0, |meta| meta.len());
+    let attachment_bytes = item
+        .attachment_dir(config)
+        .map_or(0, |dir| directory_size(&dir));
+    let total_bytes = body_bytes + attachment_bytes;
+    ItemUsage {
+        id: item.id().to_string(),
+        title: item.title().to_string(),
+        category: storage::derive_category(config, path),
+        status: item.status(),
+        path: config.relative_path(path).display().to_string(),
+        body_bytes,
+        attachment_bytes,
+        total_bytes,
+        over_threshold: total_bytes >= threshold,
+    }
+}
+
+/// Total size of the files below a directory (0 if it doesn't exist).
+fn directory_size(dir: &Path) -> u64 {
+    WalkDir::new(dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|meta| meta.len())
+        .sum()
+}
+
+/// Prints the report as aligned text tables.
+fn print_report(report: &Report) {
+    if report.items.is_empty() {
+        println!("No items found.");
+        return;
+    }
+
+    println!("Categories:");
+    let names: Vec<&str> = report
+        .categories
+        .iter()
+        .map(|usage| usage.category.as_deref().unwrap_or("(root)"))
+        .collect();
+    let width = names
+        .iter()
+        .map(|name| unicode_width::UnicodeWidthStr::width(*name))
+        .max()
+        .unwrap_or(0);
+    for (usage, name) in report.categories.iter().zip(names) {
+        println!(
+            "  {}  {:>9}  {} item(s)",
+            pad_to_width(name, width),
+            ui::format_size(usage.total_bytes),
+            usage.items
+        );
+    }
+
+    println!("Items:");
+    println!("  {:>9}  {:>9}  {:>9}  Item", "Total", "File", "Attached");
+    for usage in &report.items {
+        let total = format!("{:>9}", ui::format_size(usage.total_bytes));
+        let total = if usage.over_threshold {
+            total.red().bold().to_string()
+        } else {
+            total
+        };
+        let closed = if usage.status == Status::Closed {
+            format!(" {}", "(closed)".dimmed())
+        } else {
+            String::new()
+        };
+        println!(
+            "  {total}  {:>9}  {:>9}  {} {}{closed}",
+            ui::format_size(usage.body_bytes),
+            ui::format_size(usage.attachment_bytes),
+            usage.id.cyan(),
+            ui::truncate(&usage.title, 50)
+        );
+    }
+
+    let over = report
+        .items
+        .iter()
+        .filter(|usage| usage.over_threshold)
+        .count();
+    println!(
+        "Total: {}{}",
+        ui::format_size(report.total_bytes),
+        if over > 0 {
+            format!(
+                " ({over} listed item(s) at or above {})",
+                ui::format_size(report.threshold_bytes)
+            )
+        } else {
+            String::new()
+        }
+    );
+}
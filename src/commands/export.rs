@@ -0,0 +1,206 @@
+//! # Export Command
+//!
+//! Exports items to other plain-text task formats (Org-mode, `TaskPaper`).
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{fmt::Write as _, fs, path::PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+
+use super::list::{collect_items, sort_items, SortBy, StatusFilter};
+use crate::{
+    config::Config,
+    item::{FilterCriteria, Item, Status},
+    policy::{self, Destructive},
+    storage,
+};
+
+/// Export format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+    /// Emacs Org-mode outline
+    Org,
+    /// `TaskPaper` plain-text format
+    Taskpaper,
+}
+
+/// Arguments for the export command
+pub struct ExportArgs {
+    pub format: ExportFormat,
+    pub status: StatusFilter,
+    /// Write to a file instead of stdout
+    pub output: Option<PathBuf>,
+    /// Export protected items too
+    pub force: bool,
+}
+
+/// An item prepared for export, with its derived category.
+struct ExportItem {
+    item: Item,
+    category: Option<String>,
+}
+
+/// Executes the export command.
+pub fn execute(args: &ExportArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let filter = FilterCriteria::default();
+    let mut items = match args.status {
+        StatusFilter::Open => collect_items(&config, false, &filter),
+        StatusFilter::Closed => collect_items(&config, true, &filter),
+        StatusFilter::All => {
+            let mut items = collect_items(&config, false, &filter);
+            items.extend(collect_items(&config, true, &filter));
+            items
+        }
+    };
+    sort_items(&mut items, SortBy::Id);
+
+    let items: Vec<ExportItem> = items
+        .into_iter()
+        .map(|item| ExportItem {
+            category: item
+                .path
+                .as_ref()
+                .and_then(|p| storage::derive_category(&config, p)),
+            item,
+        })
+        .collect();
+
+    for export in &items {
+        policy::check_protected(
+            &config,
+            Destructive::Export,
+            &export.item,
+            export.category.as_deref(),
+            args.force,
+        )?;
+    }
+
+    let content = match args.format {
+        ExportFormat::Org => render_org(&items),
+        ExportFormat::Taskpaper => render_taskpaper(&items),
+    };
+
+    if let Some(ref path) = args.output {
+        fs::write(path, content)
+            .with_context(|| format!("Failed to write export: {}", path.display()))?;
+    } else {
+        print!("{content}");
+    }
+
+    Ok(())
+}
+
+// =============================================================================
+// Org-mode
+// =============================================================================
+
+/// Renders items as an Org-mode outline, one top-level heading per item.
+///
+/// Markdown headings in the body become nested Org headings, and Markdown
+/// `* ` bullets become `- ` so they are not mistaken for headings.
+fn render_org(items: &[ExportItem]) -> String {
+    let mut out = String::new();
+    out.push_str("#+TITLE: queuestack\n");
+    out.push_str("#+TODO: TODO STARTED | DONE\n");
+    let _ = writeln!(out, "#+DATE: {}\n", Utc::now().format("[%Y-%m-%d %a]"));
+
+    for ExportItem { item, category } in items {
+        let keyword = match item.status() {
+            Status::InProgress => "STARTED",
+            Status::Closed => "DONE",
+            Status::Open | Status::Template => "TODO",
+        };
+        let _ = write!(out, "* {keyword} {}", item.title());
+
+        // Org tags only allow letters, digits, '_', '@', '#' and '%'
+        if !item.labels().is_empty() {
+            let tags: Vec<String> = item.labels().iter().map(|l| l.replace('-', "_")).collect();
+            let _ = write!(out, " :{}:", tags.join(":"));
+        }
+        out.push('\n');
+
+        if let Some(due) = item.due() {
+            let _ = writeln!(out, "DEADLINE: <{}>", due.format("%Y-%m-%d %a"));
+        }
+
+        out.push_str(":PROPERTIES:\n");
+        let _ = writeln!(out, ":ID: {}", item.id());
+        let _ = writeln!(out, ":AUTHOR: {}", item.author());
+        let _ = writeln!(
+            out,
+            ":CREATED: {}",
+            item.created_at().format("[%Y-%m-%d %a %H:%M]")
+        );
+        if let Some(category) = category {
+            let _ = writeln!(out, ":CATEGORY: {category}");
+        }
+        out.push_str(":END:\n");
+
+        for line in item.body.trim_end().lines() {
+            out.push_str(&org_body_line(line));
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+/// Converts a Markdown body line so it nests under the item's Org heading.
+fn org_body_line(line: &str) -> String {
+    let hashes = line.chars().take_while(|&c| c == '#').count();
+    if hashes > 0 && line[hashes..].starts_with(' ') {
+        return format!("{}{}", "*".repeat(hashes + 1), &line[hashes..]);
+    }
+    if let Some(rest) = line.strip_prefix("* ") {
+        return format!("- {rest}");
+    }
+    line.to_string()
+}
+
+// =============================================================================
+// TaskPaper
+// =============================================================================
+
+/// Renders items as `TaskPaper`, grouped into one project per category.
+fn render_taskpaper(items: &[ExportItem]) -> String {
+    let mut out = String::new();
+
+    let mut categories: Vec<Option<&str>> = items.iter().map(|e| e.category.as_deref()).collect();
+    categories.sort_unstable();
+    categories.dedup();
+
+    for category in categories {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        let _ = writeln!(out, "{}:", category.unwrap_or("Uncategorized"));
+
+        for ExportItem { item, .. } in items.iter().filter(|e| e.category.as_deref() == category) {
+            let _ = write!(out, "\t- {}", item.title());
+            for label in item.labels() {
+                let _ = write!(out, " @{label}");
+            }
+            if let Some(due) = item.due() {
+                let _ = write!(out, " @due({})", due.format("%Y-%m-%d"));
+            }
+            match item.status() {
+                Status::InProgress => out.push_str(" @started"),
+                Status::Closed => out.push_str(" @done"),
+                Status::Open | Status::Template => {}
+            }
+            let _ = writeln!(out, " @id({})", item.id());
+
+            // Notes: every non-empty body line, indented below the task
+            for line in item.body.lines().filter(|l| !l.trim().is_empty()) {
+                let _ = writeln!(out, "\t\t{}", line.trim_end());
+            }
+        }
+    }
+
+    out
+}
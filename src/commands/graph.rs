@@ -0,0 +1,283 @@
+//! # Graph Command
+//!
+//! Renders the dependency graph of open and in-progress items as a mermaid
+//! flowchart or a Graphviz DOT digraph, for pasting into docs or rendering
+//! with `dot`.
+//!
+//! Dependencies are read from the `blocks` and `depends_on` frontmatter
+//! fields (set with `qs link`) and the `parent` field, each holding one item
+//! ID or a list of IDs. Only items with a dependency on another listed item
+//! are drawn.
+//!
+//! With `--tui`, the graph is browsed on the terminal instead, most blocking
+//! items first, and Enter opens the selected item's action menu.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{collections::BTreeSet, fmt::Write as _, fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+use super::list::{collect_items, handle_item_action, sort_items, SortBy};
+use crate::{
+    config::Config,
+    constants::{MILESTONE_FIELD, PARENT_FIELD},
+    item::{FilterCriteria, Item, Status},
+    policy::{self, Destructive},
+    storage,
+    tui::{
+        self,
+        screens::{browse_graph, GraphNode},
+    },
+};
+
+/// Graph format
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum GraphFormat {
+    /// Mermaid flowchart
+    #[default]
+    Mermaid,
+    /// Graphviz DOT digraph
+    Dot,
+}
+
+/// Arguments for the graph command
+pub struct GraphArgs {
+    pub format: GraphFormat,
+    /// Only items of this milestone
+    pub milestone: Option<String>,
+    /// Only items of this category
+    pub category: Option<String>,
+    /// Write to a file instead of stdout
+    pub output: Option<PathBuf>,
+    /// Include protected items too
+    pub force: bool,
+    /// Browse the graph on the terminal instead of rendering it
+    pub tui: bool,
+}
+
+/// Kind of dependency between two items
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum EdgeKind {
+    /// `from` blocks `to`
+    Blocks,
+    /// `from` is the parent of `to`
+    Parent,
+}
+
+impl EdgeKind {
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Blocks => "blocks",
+            Self::Parent => "parent of",
+        }
+    }
+}
+
+/// A dependency edge, by item ID
+type Edge = (String, String, EdgeKind);
+
+/// Executes the graph command.
+pub fn execute(args: &GraphArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let filter = FilterCriteria {
+        category: args.category.clone(),
+        ..FilterCriteria::default()
+    };
+    let mut items = collect_items(&config, false, &filter);
+    items.retain(|item| item.status().is_active());
+    if let Some(ref milestone) = args.milestone {
+        items.retain(|item| {
+            item.custom_field(MILESTONE_FIELD)
+                .is_some_and(|m| m.eq_ignore_ascii_case(milestone))
+        });
+    }
+    sort_items(&mut items, SortBy::Id);
+
+    let edges = edges(&items);
+    items.retain(|item| {
+        edges
+            .iter()
+            .any(|(from, to, _)| from == item.id() || to == item.id())
+    });
+
+    if args.tui {
+        if !tui::terminal::is_available() {
+            bail!("The graph view requires a terminal");
+        }
+        return browse(&config, &items, &edges);
+    }
+
+    for item in &items {
+        let category = item
+            .path
+            .as_ref()
+            .and_then(|p| storage::derive_category(&config, p));
+        policy::check_protected(
+            &config,
+            Destructive::Export,
+            item,
+            category.as_deref(),
+            args.force,
+        )?;
+    }
+
+    let content = match args.format {
+        GraphFormat::Mermaid => render_mermaid(&items, &edges),
+        GraphFormat::Dot => render_dot(&items, &edges),
+    };
+
+    if let Some(ref path) = args.output {
+        fs::write(path, content)
+            .with_context(|| format!("Failed to write graph: {}", path.display()))?;
+    } else {
+        print!("{content}");
+    }
+
+    Ok(())
+}
+
+/// Collects the dependencies between the items, ignoring references to
+/// items that are not listed.
+fn edges(items: &[Item]) -> BTreeSet<Edge> {
+    let listed: BTreeSet<&str> = items.iter().map(Item::id).collect();
+    let mut edges = BTreeSet::new();
+    for item in items {
+        let id = item.id().to_string();
+        for other in item.blocks() {
+            edges.insert((id.clone(), other.clone(), EdgeKind::Blocks));
+        }
+        for other in item.depends_on() {
+            edges.insert((other.clone(), id.clone(), EdgeKind::Blocks));
+        }
+        for parent in item.id_list(PARENT_FIELD) {
+            edges.insert((parent, id.clone(), EdgeKind::Parent));
+        }
+    }
+    edges.retain(|(from, to, _)| {
+        from != to && listed.contains(from.as_str()) && listed.contains(to.as_str())
+    });
+    edges
+}
+
+// =============================================================================
+// Terminal view
+// =============================================================================
+
+/// Browses the graph on the terminal and runs the action chosen for an item.
+fn browse(config: &Config, items: &[Item], edges: &BTreeSet<Edge>) -> Result<()> {
+    let index = |id: &str| items.iter().position(|item| item.id() == id);
+
+    let mut nodes: Vec<GraphNode> = items
+        .iter()
+        .map(|item| GraphNode {
+            id: item.id().to_string(),
+            title: item.title().to_string(),
+            status: item.status(),
+            path: item.path.clone().unwrap_or_default(),
+            actions: item
+                .actions()
+                .iter()
+                .map(|(name, command)| (name.clone(), command.clone()))
+                .collect(),
+            ..GraphNode::default()
+        })
+        .collect();
+    for (from, to, kind) in edges {
+        let (Some(from), Some(to)) = (index(from), index(to)) else {
+            continue;
+        };
+        match kind {
+            EdgeKind::Blocks => nodes[from].blocks.push(to),
+            EdgeKind::Parent => nodes[from].children.push(to),
+        }
+    }
+
+    let Some(action) = browse_graph(nodes)? else {
+        return Ok(()); // User cancelled
+    };
+    handle_item_action(action, config)
+}
+
+// =============================================================================
+// Mermaid
+// =============================================================================
+
+/// Renders a mermaid flowchart; blocking edges are solid, parent edges dotted,
+/// and in-progress items have a thick border.
+fn render_mermaid(items: &[Item], edges: &BTreeSet<Edge>) -> String {
+    // Item IDs contain '-', which mermaid reads as an edge, so nodes are numbered
+    let node = |id: &str| {
+        items
+            .iter()
+            .position(|item| item.id() == id)
+            .map(|index| format!("n{index}"))
+            .unwrap_or_default()
+    };
+
+    let mut out = String::from("flowchart LR\n");
+    for (index, item) in items.iter().enumerate() {
+        let label = format!("{}: {}", item.id(), item.title()).replace('"', "#quot;");
+        let class = if item.status() == Status::InProgress {
+            ":::inProgress"
+        } else {
+            ""
+        };
+        let _ = writeln!(out, "    n{index}[\"{label}\"]{class}");
+    }
+    for (from, to, kind) in edges {
+        let arrow = match kind {
+            EdgeKind::Blocks => "-->",
+            EdgeKind::Parent => "-.->",
+        };
+        let _ = writeln!(
+            out,
+            "    {} {arrow}|{}| {}",
+            node(from),
+            kind.label(),
+            node(to)
+        );
+    }
+    if items.iter().any(|item| item.status() == Status::InProgress) {
+        out.push_str("    classDef inProgress stroke-width:3px\n");
+    }
+    out
+}
+
+// =============================================================================
+// DOT
+// =============================================================================
+
+/// Renders a Graphviz digraph; parent edges are dashed, and in-progress items
+/// are drawn bold.
+fn render_dot(items: &[Item], edges: &BTreeSet<Edge>) -> String {
+    let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+
+    let mut out = String::from("digraph queuestack {\n    rankdir=LR;\n    node [shape=box];\n");
+    for item in items {
+        let label = quote(&format!("{}\n{}", item.id(), item.title())).replace('\n', "\\n");
+        let style = if item.status() == Status::InProgress {
+            ", style=bold"
+        } else {
+            ""
+        };
+        let _ = writeln!(out, "    {} [label={label}{style}];", quote(item.id()));
+    }
+    for (from, to, kind) in edges {
+        let style = match kind {
+            EdgeKind::Blocks => "",
+            EdgeKind::Parent => ", style=dashed",
+        };
+        let _ = writeln!(
+            out,
+            "    {} -> {} [label={}{style}];",
+            quote(from),
+            quote(to),
+            quote(kind.label())
+        );
+    }
+    out.push_str("}\n");
+    out
+}
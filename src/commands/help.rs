@@ -0,0 +1,86 @@
+//! # Help Command
+//!
+//! Prints the built-in help topics (`qs help ids`), searches them
+//! (`qs help --search <text>`), or lists them. Help for a command
+//! (`qs help new`) is handled by clap in `main.rs`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::io::IsTerminal;
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use crate::{help, ui};
+
+/// Arguments for the help command
+pub struct HelpArgs {
+    /// Topic to print
+    pub topic: Option<String>,
+    /// Text to search the topics for
+    pub search: Option<String>,
+}
+
+/// Executes the help command.
+pub fn execute(args: &HelpArgs) -> Result<()> {
+    let color = std::io::stdout().is_terminal();
+
+    if let Some(ref query) = args.search {
+        let results = help::search(query);
+        if results.is_empty() {
+            println!("No help topics match '{query}'.");
+        }
+        for (i, (topic, lines)) in results.iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+            if color {
+                println!("{}", topic.name.yellow().bold());
+            } else {
+                println!("{}", topic.name);
+            }
+            for line in lines {
+                println!("  {}", ui::highlight(line, query));
+            }
+        }
+        return Ok(());
+    }
+
+    let Some(ref name) = args.topic else {
+        print_topics(color);
+        return Ok(());
+    };
+    let Some(topic) = help::find(name) else {
+        bail!(
+            "Unknown help topic or command '{name}'. Topics: {}",
+            topic_names()
+        );
+    };
+    print!("{}", help::render(topic.content, color));
+    Ok(())
+}
+
+/// Lists the topics with their summaries.
+fn print_topics(color: bool) {
+    if color {
+        println!("{}", "Help topics:".yellow().bold());
+    } else {
+        println!("Help topics:");
+    }
+    for topic in &help::TOPICS {
+        println!("  {:<10} {}", topic.name, topic.summary);
+    }
+    println!();
+    println!("Run 'qs help <topic>' to read one, 'qs help --search <text>' to search them,");
+    println!("or 'qs help <command>' for a command's options.");
+}
+
+/// Topic names for error messages (`ids, config, ...`).
+pub fn topic_names() -> String {
+    help::TOPICS
+        .iter()
+        .map(|topic| topic.name)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,88 @@
+//! # Hooks Command
+//!
+//! Installs git hooks that integrate queuestack into the commit workflow.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::fs;
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+
+use crate::{config::Config, storage::git};
+
+/// Marker line identifying hooks written by queuestack
+const HOOK_MARKER: &str = "# Installed by `qs hooks install`";
+
+/// `prepare-commit-msg` hook: prefixes the message with the in-progress item ID.
+///
+/// Skips merges, squashes and amends, and messages that already mention the item.
+/// Never blocks a commit: any failure of `qs` leaves the message untouched.
+const PREPARE_COMMIT_MSG: &str = r#"#!/bin/sh
+# Installed by `qs hooks install`
+# Prefixes commit messages with the in-progress queuestack item: [ID] message
+case "$2" in
+    merge|squash|commit) exit 0 ;;
+esac
+prefix=$(qs commit-template 2>/dev/null) || exit 0
+[ -n "$prefix" ] || exit 0
+ref=$(printf '%s' "$prefix" | sed 's/ *$//')
+grep -qF "$ref" "$1" && exit 0
+{ printf '%s' "$prefix"; cat "$1"; } > "$1.qs" && mv "$1.qs" "$1"
+"#;
+
+/// Arguments for the hooks install subcommand
+pub struct HooksInstallArgs {
+    /// Overwrite existing hooks not installed by queuestack
+    pub force: bool,
+}
+
+/// Executes the hooks install subcommand.
+pub fn execute_install(args: &HooksInstallArgs) -> Result<()> {
+    // Hooks only make sense inside a queuestack project
+    Config::load()?;
+
+    let Some(hooks_dir) = git::hooks_dir() else {
+        bail!("Not in a git repository");
+    };
+    fs::create_dir_all(&hooks_dir)
+        .with_context(|| format!("Failed to create directory: {}", hooks_dir.display()))?;
+
+    let path = hooks_dir.join("prepare-commit-msg");
+    if path.exists() && !args.force {
+        let existing = fs::read_to_string(&path).unwrap_or_default();
+        if !existing.contains(HOOK_MARKER) {
+            bail!(
+                "A prepare-commit-msg hook already exists at {}. Use --force to replace it.",
+                path.display()
+            );
+        }
+    }
+
+    fs::write(&path, PREPARE_COMMIT_MSG)
+        .with_context(|| format!("Failed to write hook: {}", path.display()))?;
+    make_executable(&path)?;
+
+    println!(
+        "{} Installed prepare-commit-msg hook: {}",
+        "✓".green(),
+        path.display()
+    );
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn make_executable(path: &std::path::Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+        .with_context(|| format!("Failed to make hook executable: {}", path.display()))
+}
+
+#[cfg(not(unix))]
+#[allow(clippy::unnecessary_wraps)]
+fn make_executable(_path: &std::path::Path) -> Result<()> {
+    Ok(())
+}
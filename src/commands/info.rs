@@ -0,0 +1,265 @@
+//! # Info Command
+//!
+//! One-look overview of a project for support and debugging: resolved settings,
+//! paths, item counts, git status, the attachment store, and detected problems.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+
+use crate::{
+    config::{project::PROJECT_CONFIG_FILE, Config, GlobalConfig},
+    item::{is_url, Item, Status},
+    storage::{self, git, vcs},
+    ui,
+};
+
+/// Executes the info command.
+pub fn execute() -> Result<()> {
+    let config = Config::load()?;
+    let mut problems = Vec::new();
+
+    println!("Project: {}", config.project_root().display());
+
+    println!("Config:");
+    let field = |name: &str, value: &dyn std::fmt::Display| println!("  {name:<16} {value}");
+    match GlobalConfig::path() {
+        Some(path) if path.exists() => field("global", &path.display()),
+        Some(path) => field("global", &format!("{} (missing)", path.display())),
+        None => field("global", &"(no home directory)"),
+    }
+    field(
+        "project",
+        &config.project_root().join(PROJECT_CONFIG_FILE).display(),
+    );
+    field(
+        "profile",
+        &config.profile_name().as_deref().unwrap_or("none"),
+    );
+    field("id_pattern", &config.id_pattern());
+    field(
+        "user_name",
+        &config.user_name().as_deref().unwrap_or("not set"),
+    );
+    field("editor", &config.editor().as_deref().unwrap_or("not set"));
+    field("interactive", &config.interactive());
+    field("picker", &config.picker());
+    field("obsidian", &config.obsidian());
+    field("attachment_dedup", &config.attachment_dedup());
+    field("trash_days", &config.trash_days());
+    field("date_format", &config.date_format());
+    field("frontmatter_format", &config.frontmatter_format());
+    field("fold_accents", &config.fold_accents());
+    field("usage_log", &config.usage_log());
+    if let Some(path) = config.attachment_path() {
+        field("attachment_path", &path);
+    }
+    if let Some(url) = config.s3_url() {
+        field("s3_url", &url);
+    }
+    if let Ok(validation) = GlobalConfig::validate() {
+        problems.extend(
+            validation
+                .invalid
+                .iter()
+                .map(|key| format!("Unknown key '{key}' in global config")),
+        );
+        problems.extend(validation.migrated.iter().map(|(old, new)| {
+            format!("Legacy key '{old}' in global config (now '{new}'; run qs setup)")
+        }));
+    }
+    if config.s3_url().is_some() && !cfg!(feature = "s3") {
+        problems.push("s3_url is set, but qs was built without the 's3' feature".to_string());
+    }
+
+    println!("Paths:");
+    for (name, path) in [
+        ("stack", config.stack_path()),
+        ("archive", config.archive_path()),
+        ("templates", config.template_path()),
+    ] {
+        let marker = if path.is_dir() { "" } else { " (missing)" };
+        println!("  {name:<16} {}{marker}", path.display());
+    }
+    if !config.stack_path().is_dir() {
+        problems.push("Stack directory is missing (run qs init)".to_string());
+    }
+
+    let items = load_items(&config, &mut problems);
+    let count = |status: Status| items.iter().filter(|i| i.status() == status).count();
+    println!(
+        "Items: {} open, {} in progress, {} closed, {} templates",
+        count(Status::Open),
+        count(Status::InProgress),
+        count(Status::Closed),
+        count(Status::Template)
+    );
+    check_items(&config, &items, &mut problems);
+    check_empty_dirs(&config, &mut problems);
+
+    print_storage(&config);
+
+    let store = storage::attachment_store(&config);
+    match store_usage(&store) {
+        Some((blobs, bytes)) => println!(
+            "Attachment store: {blobs} file(s), {} in {}",
+            ui::format_size(bytes),
+            config.relative_path(&store).display()
+        ),
+        None => println!("Attachment store: not created"),
+    }
+
+    if problems.is_empty() {
+        println!("Problems: none");
+    } else {
+        println!("Problems:");
+        for problem in &problems {
+            println!("  {problem}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Loads open, archived, and template items, recording files that fail to parse.
+fn load_items(config: &Config, problems: &mut Vec<String>) -> Vec<Item> {
+    storage::walk_all(config)
+        .chain(storage::walk_templates(config))
+        .filter_map(|path| match Item::load(&path) {
+            Ok(item) => Some(item),
+            Err(e) => {
+                problems.push(format!(
+                    "Cannot parse {}: {e}",
+                    config.relative_path(&path).display()
+                ));
+                None
+            }
+        })
+        .collect()
+}
+
+/// Records duplicate IDs, items in the wrong place for their status,
+/// attachments missing from disk, and frontmatter that needed fixing.
+fn check_items(config: &Config, items: &[Item], problems: &mut Vec<String>) {
+    let mut by_id: BTreeMap<&str, Vec<&Item>> = BTreeMap::new();
+    for item in items {
+        by_id.entry(item.id()).or_default().push(item);
+    }
+    for (id, items) in by_id.iter().filter(|(_, items)| items.len() > 1) {
+        let paths: Vec<String> = items.iter().map(|item| relative(config, item)).collect();
+        problems.push(format!("Duplicate ID {id}: {}", paths.join(", ")));
+    }
+
+    let archive = config.archive_path();
+    for item in items {
+        let Some(path) = item.path.as_deref() else {
+            continue;
+        };
+        if !item.fixes.is_empty() {
+            problems.push(format!(
+                "{} has fixable frontmatter: {} (run qs update --id {} to save)",
+                relative(config, item),
+                item.fixes.join(", "),
+                item.id()
+            ));
+        }
+        let archived = storage::paths::relative_to(path, &archive).is_some();
+        match item.status() {
+            Status::Closed if !archived => problems.push(format!(
+                "{} is closed but not archived",
+                relative(config, item)
+            )),
+            status if status.is_active() && archived => problems.push(format!(
+                "{} is archived but {status}",
+                relative(config, item)
+            )),
+            status if status.is_active() => check_status_dir(config, item, path, problems),
+            _ => {}
+        }
+
+        let Some(dir) = item.attachment_dir(config) else {
+            continue;
+        };
+        for attachment in item.attachments().iter().filter(|a| !is_url(a)) {
+            if !dir.join(attachment).exists() {
+                problems.push(format!(
+                    "{} lists missing attachment {attachment}",
+                    relative(config, item)
+                ));
+            }
+        }
+    }
+}
+
+/// Records an open or in-progress item outside the directory of its status
+/// (`[status_dirs]`), e.g. after a status edit by hand.
+fn check_status_dir(config: &Config, item: &Item, path: &Path, problems: &mut Vec<String>) {
+    let status = item.status();
+    let expected = config.status_dirs().get(&status.to_string()).cloned();
+    if storage::status_dir_of(config, path) == expected {
+        return;
+    }
+    let dir = storage::status_directory(config, status, None);
+    problems.push(format!(
+        "{} is {status} but not in {}/",
+        relative(config, item),
+        config.relative_path(&dir).display()
+    ));
+}
+
+/// Records empty directories left in the stack (removed by `qs clean`).
+fn check_empty_dirs(config: &Config, problems: &mut Vec<String>) {
+    let count = storage::find_empty_dirs(config).len();
+    if count > 0 {
+        let noun = if count == 1 {
+            "directory"
+        } else {
+            "directories"
+        };
+        problems.push(format!("{count} empty {noun} in the stack (run qs clean)"));
+    }
+}
+
+/// Prints the storage backend and, in a git repository, the stack's git status.
+fn print_storage(config: &Config) {
+    let backend = vcs::current().backend(config.project_root());
+    println!("Storage: {backend}");
+    if matches!(backend, vcs::Backend::Vcs(_)) {
+        if let Some(status) = git::status(&config.stack_path()) {
+            println!(
+                "Git: on {}, {} uncommitted change(s) in the stack",
+                status.branch.as_deref().unwrap_or("detached HEAD"),
+                status.changes
+            );
+        }
+    }
+}
+
+/// Returns an item's path relative to the project root.
+fn relative(config: &Config, item: &Item) -> String {
+    item.path.as_deref().map_or_else(String::new, |path| {
+        config.relative_path(path).display().to_string()
+    })
+}
+
+/// Returns the number of blobs and their total size, or `None` if the store doesn't exist.
+fn store_usage(store: &Path) -> Option<(usize, u64)> {
+    let entries = std::fs::read_dir(store).ok()?;
+    let blobs: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.file_name().is_some_and(|n| n != ".gitignore"))
+        .collect();
+    let bytes = blobs
+        .iter()
+        .filter_map(|path| path.metadata().ok())
+        .map(|meta| meta.len())
+        .sum();
+    Some((blobs.len(), bytes))
+}
@@ -1,6 +1,7 @@
 //! # Init Command
 //!
-//! Initializes a new queuestack project in the current directory.
+//! Initializes a new queuestack project in the current directory, optionally
+//! adding `.gitattributes` and `.gitignore` entries for the stack directory.
 //!
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
@@ -10,15 +11,46 @@ use std::fs;
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 
-use crate::config::{project::PROJECT_CONFIG_FILE, Config, ProjectConfig};
+use crate::{
+    config::{project::PROJECT_CONFIG_FILE, Config, ProjectConfig},
+    storage::git_files,
+};
 
-/// Executes the init command.
+/// Arguments for the init command
+#[derive(Debug, Default)]
+pub struct InitArgs {
+    /// Route item files through the queuestack merge driver
+    pub gitattributes: bool,
+    /// Ignore files qs keeps out of version control
+    pub gitignore: bool,
+    /// Store attachments matching these patterns with Git LFS (empty: defaults)
+    pub lfs: Option<Vec<String>>,
+}
+
+impl InitArgs {
+    /// Returns true if any git file should be written
+    const fn writes_git_files(&self) -> bool {
+        self.gitattributes || self.gitignore || self.lfs.is_some()
+    }
+}
+
+/// Executes the init command without touching git files.
 pub fn execute() -> Result<()> {
+    execute_with(&InitArgs::default())
+}
+
+/// Executes the init command.
+///
+/// In an initialized project, only the requested git files are updated.
+pub fn execute_with(args: &InitArgs) -> Result<()> {
     let config = Config::for_init()?;
 
     // Check if already initialized
     let config_path = config.project_root().join(PROJECT_CONFIG_FILE);
     if config_path.exists() {
+        if args.writes_git_files() {
+            return write_git_files(&config, args);
+        }
         anyhow::bail!(
             "Project already initialized (found {})",
             config_path.display()
@@ -64,5 +96,40 @@ pub fn execute() -> Result<()> {
     println!("  {} {}", "Config:".dimmed(), config_path.display());
     println!("  {} {}", "Items:".dimmed(), stack_path.display());
 
+    write_git_files(&config, args)
+}
+
+/// Adds the requested `.gitattributes` and `.gitignore` entries.
+fn write_git_files(config: &Config, args: &InitArgs) -> Result<()> {
+    let mut attributes = Vec::new();
+    if args.gitattributes {
+        attributes.extend(git_files::merge_attributes(config));
+    }
+    if let Some(ref patterns) = args.lfs {
+        let patterns = if patterns.is_empty() {
+            git_files::DEFAULT_LFS_PATTERNS.map(String::from).to_vec()
+        } else {
+            patterns.clone()
+        };
+        attributes.extend(git_files::lfs_attributes(config, &patterns));
+    }
+    let ignores = if args.gitignore {
+        git_files::ignores(config)
+    } else {
+        Vec::new()
+    };
+
+    for (name, entries) in [(".gitattributes", attributes), (".gitignore", ignores)] {
+        if entries.is_empty() {
+            continue;
+        }
+        let path = config.project_root().join(name);
+        let added = git_files::append_missing(&path, &entries)?;
+        if added == 0 {
+            println!("{} {name} is up to date", "✓".green());
+        } else {
+            println!("{} Added {added} entries to {name}", "✓".green());
+        }
+    }
     Ok(())
 }
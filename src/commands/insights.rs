@@ -0,0 +1,155 @@
+//! # Insights Command
+//!
+//! Summarizes the local usage log: which commands you use most and at what
+//! times of day and days of the week. Reads only the log on this machine.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use anyhow::{bail, Context, Result};
+use chrono::{Local, TimeDelta};
+use owo_colors::OwoColorize;
+
+use crate::{
+    config::GlobalConfig,
+    insights::{self, Summary, DAY_PERIODS},
+};
+
+/// Weekday names, Monday first
+const WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// Width of the longest bar
+const BAR_WIDTH: usize = 20;
+
+/// Arguments for the insights command
+pub struct InsightsArgs {
+    /// Only include the last N days (0 = everything)
+    pub days: u32,
+    /// Delete the usage log instead of summarizing it
+    pub clear: bool,
+}
+
+/// Executes the insights command.
+pub fn execute(args: &InsightsArgs) -> Result<()> {
+    let Some(path) = insights::log_path() else {
+        bail!("Could not determine home directory");
+    };
+
+    if args.clear {
+        if path.exists() {
+            std::fs::remove_file(&path)
+                .with_context(|| format!("Failed to remove {}", path.display()))?;
+        }
+        println!("{} Cleared the usage log", "✓".green());
+        return Ok(());
+    }
+
+    let enabled = GlobalConfig::load()?.usage_log;
+    let entries = insights::load(&path)?;
+    if entries.is_empty() {
+        if enabled {
+            println!("No commands recorded yet.");
+        } else {
+            println!("Usage logging is off.");
+            println!(
+                "Set usage_log = true in {} to record the commands you run.",
+                GlobalConfig::path().unwrap_or_default().display()
+            );
+            println!("Only command names and times are recorded, and only on this machine.");
+        }
+        return Ok(());
+    }
+
+    let since = (args.days > 0)
+        .then(|| Local::now().fixed_offset() - TimeDelta::days(i64::from(args.days)));
+    let summary = insights::summarize(&entries, since);
+    let range = if args.days > 0 {
+        format!("last {} days", args.days)
+    } else {
+        "all time".to_string()
+    };
+    println!(
+        "{} commands on {} {} ({range})",
+        summary.total,
+        summary.active_days,
+        if summary.active_days == 1 {
+            "day"
+        } else {
+            "days"
+        }
+    );
+    if summary.total > 0 {
+        print_summary(&summary);
+    }
+    if !enabled {
+        println!();
+        println!("Usage logging is off; no new commands are recorded.");
+    }
+    Ok(())
+}
+
+/// Prints the command, time-of-day, and weekday breakdowns.
+fn print_summary(summary: &Summary) {
+    let max = summary.commands.first().map_or(1, |(_, count)| *count);
+    let width = summary
+        .commands
+        .iter()
+        .map(|(command, _)| command.len())
+        .max()
+        .unwrap_or(0);
+
+    println!();
+    println!("Commands:");
+    for (command, count) in &summary.commands {
+        let line = format!(
+            "  {command:<width$}  {count:>5}  {:>3}%  {}",
+            percent(*count, summary.total),
+            bar(*count, max)
+        );
+        println!("{}", line.trim_end());
+    }
+
+    println!();
+    println!("Time of day:");
+    let periods = summary.periods();
+    let max = periods.iter().copied().max().unwrap_or(1);
+    for ((name, start), count) in DAY_PERIODS.iter().zip(periods) {
+        let label = format!("{name} ({start:02}-{:02})", start + 6);
+        let line = format!("  {label:<17}  {count:>5}  {}", bar(count, max));
+        println!("{}", line.trim_end());
+    }
+
+    println!();
+    if let Some((hour, count)) = summary.busiest_hour() {
+        println!(
+            "Busiest hour:    {hour:02}:00-{:02}:00 ({count})",
+            (hour + 1) % 24
+        );
+    }
+    if let Some((day, count)) = summary.busiest_weekday() {
+        println!("Busiest weekday: {} ({count})", WEEKDAYS[day]);
+    }
+}
+
+/// Share of `count` in `total`, rounded to whole percent.
+const fn percent(count: usize, total: usize) -> usize {
+    (count * 100 + total / 2) / total
+}
+
+/// A bar of up to `BAR_WIDTH` characters proportional to `count / max`.
+fn bar(count: usize, max: usize) -> String {
+    let len = if count == 0 {
+        0
+    } else {
+        (count * BAR_WIDTH).div_ceil(max.max(1))
+    };
+    "█".repeat(len)
+}
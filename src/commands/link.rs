@@ -0,0 +1,108 @@
+//! # Link Command
+//!
+//! Records blocking links between items: `qs link --id A --blocks B` stores
+//! `blocks: [B]` on A, `--depends-on` stores `depends_on`. Referenced IDs
+//! must name existing items (see `item::deps`).
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use crate::{
+    config::Config,
+    item::deps::LinkKind,
+    policy::{self, Edit},
+    storage,
+};
+
+/// Arguments for the link command
+pub struct LinkArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+    /// Items this item blocks
+    pub blocks: Vec<String>,
+    /// Items this item depends on
+    pub depends_on: Vec<String>,
+    /// Remove the links instead of adding them
+    pub remove: bool,
+    /// Link archived items too
+    pub force: bool,
+}
+
+/// Executes the link command.
+pub fn execute(args: LinkArgs) -> Result<()> {
+    if args.blocks.is_empty() && args.depends_on.is_empty() {
+        bail!("Nothing to link: use --blocks or --depends-on");
+    }
+
+    let config = Config::load()?;
+
+    let item_ref = storage::ItemRef::from_options(args.id, args.file)?;
+    let storage::LoadedItem { path, mut item } = item_ref.resolve(&config)?;
+
+    policy::check_editable(&config, Edit::Link, &item, &path, args.force)?;
+
+    let mut changes = Vec::new();
+    for (kind, references) in [
+        (LinkKind::Blocks, &args.blocks),
+        (LinkKind::DependsOn, &args.depends_on),
+    ] {
+        for reference in references {
+            let other = if args.remove {
+                linked_id(&config, kind.ids(&item), reference)?
+            } else {
+                resolve(&config, reference)?
+            };
+            if other.eq_ignore_ascii_case(item.id()) {
+                bail!("Item '{other}' can't be linked to itself");
+            }
+
+            let ids = kind.ids_mut(&mut item);
+            let present = ids.iter().any(|id| id.eq_ignore_ascii_case(&other));
+            if args.remove && present {
+                ids.retain(|id| !id.eq_ignore_ascii_case(&other));
+            } else if !args.remove && !present {
+                ids.push(other.clone());
+            } else {
+                continue;
+            }
+            changes.push(format!("{} {other}", kind.field()));
+        }
+    }
+
+    if changes.is_empty() {
+        println!("{}", "No links changed.".dimmed());
+        return Ok(());
+    }
+
+    item.save(&path)?;
+    storage::vault::sync(&config)?;
+
+    let verb = if args.remove { "Unlinked" } else { "Linked" };
+    println!(
+        "{} {verb} {}: {}",
+        "✓".green(),
+        config.relative_path(&path).display(),
+        changes.join(", ")
+    );
+    Ok(())
+}
+
+/// Resolves a (partial) reference to the full ID of an existing item.
+fn resolve(config: &Config, reference: &str) -> Result<String> {
+    let loaded = storage::find_and_load(config, reference)?;
+    Ok(loaded.item.id().to_string())
+}
+
+/// Finds the linked ID to remove; links to deleted items can still be
+/// removed by their full ID.
+fn linked_id(config: &Config, linked: &[String], reference: &str) -> Result<String> {
+    if let Some(id) = linked.iter().find(|id| id.eq_ignore_ascii_case(reference)) {
+        return Ok(id.clone());
+    }
+    resolve(config, reference)
+}
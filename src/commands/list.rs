@@ -1,25 +1,40 @@
 //! # List Command
 //!
-//! Lists queuestack items with filtering and sorting options.
-//! Also supports listing labels, categories, attachments, and item metadata.
+//! Lists queuestack items with filtering, sorting, and grouping options, as
+//! paths, as a directory tree, or as JSON. Also supports listing labels,
+//! categories, attachments, and item metadata.
 //!
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
 use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::io::IsTerminal;
+use std::io::Write as _;
 use std::path::PathBuf;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
 use owo_colors::OwoColorize;
 
 use crate::{
     commands,
     config::Config,
-    item::{matches_filter, FilterCriteria, Item},
-    storage,
+    constants::{PRIORITY_FIELD, PRIORITY_ORDER},
+    item::{
+        dates::DateRange,
+        deps::Blockers,
+        fold,
+        group::{self, Group, GroupBy},
+        labels, matches_filter,
+        record::ItemRecord,
+        FilterCriteria, Item, Status,
+    },
+    policy::{self, Destructive},
+    storage::{self, mirror::MirroredItems},
     tui::screens::ItemAction,
-    ui,
-    ui::InteractiveArgs,
+    ui::{self, InteractiveArgs, OutputFormat, Selection},
 };
 
 /// Sort order for listing
@@ -43,6 +58,46 @@ pub enum StatusFilter {
     All,
 }
 
+/// Corpus a listing or search covers (`--scope`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Scope {
+    /// Open and in-progress items (default)
+    #[default]
+    Open,
+    /// Closed items in the archive
+    Archive,
+    /// Templates
+    Templates,
+    /// Open items, the archive, and templates
+    All,
+}
+
+impl Scope {
+    /// Walks the item files of the scope: open items, then the archive, then templates.
+    pub fn walk(self, config: &Config) -> impl Iterator<Item = PathBuf> {
+        let open = matches!(self, Self::Open | Self::All).then(|| storage::walk_items(config));
+        let archive =
+            matches!(self, Self::Archive | Self::All).then(|| storage::walk_archived(config));
+        let templates =
+            matches!(self, Self::Templates | Self::All).then(|| storage::walk_templates(config));
+        open.into_iter()
+            .flatten()
+            .chain(archive.into_iter().flatten())
+            .chain(templates.into_iter().flatten())
+    }
+
+    /// Which stacks of read-only mirrors the scope covers (`true` for the
+    /// archive); mirrored templates are never included.
+    pub const fn mirrored(self) -> &'static [bool] {
+        match self {
+            Self::Open => &[false],
+            Self::Archive => &[true],
+            Self::Templates => &[],
+            Self::All => &[false, true],
+        }
+    }
+}
+
 /// Special list modes
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum ListMode {
@@ -59,39 +114,111 @@ pub enum ListMode {
     Meta,
     /// List templates
     Templates,
+    /// Standard item listing, printed as a directory tree
+    Tree,
 }
 
 /// Filter options for listing
+#[allow(clippy::struct_excessive_bools)] // Each flag is an independent CLI option
 pub struct ListOptions {
     pub mode: ListMode,
-    pub status: StatusFilter,
+    pub scope: Scope,
+    /// Labels the item must all have
     pub labels: Vec<String>,
+    /// Labels of which the item must have at least one
+    pub any_labels: Vec<String>,
+    /// Labels the item must not have
+    pub not_labels: Vec<String>,
     pub author: Option<String>,
+    /// Only items assigned to this person
+    pub assignee: Option<String>,
+    /// Only items assigned to the current user
+    pub mine: bool,
     pub category: Option<String>,
+    /// Earliest creation date (close date with `Scope::Archive`)
+    pub since: Option<DateTime<Utc>>,
+    /// Latest creation date (close date with `Scope::Archive`)
+    pub until: Option<DateTime<Utc>>,
+    /// Only items due on or after this date
+    pub due_after: Option<NaiveDate>,
+    /// Only items due on or before this date
+    pub due_before: Option<NaiveDate>,
+    /// Only open or in-progress items past their due date
+    pub overdue: bool,
+    /// Only items blocked (`true`) or not blocked (`false`) by open items
+    pub blocked: Option<bool>,
     pub sort: SortBy,
+    /// Show items in groups
+    pub group_by: Option<GroupBy>,
+    /// Append a footer with counts by status and priority (implies non-interactive)
+    pub summary: bool,
+    /// Print summary cards instead of paths (implies non-interactive)
+    pub verbose: bool,
+    /// Pick an item on the terminal and print it instead of opening it
+    pub print_selection: Option<Selection>,
     pub interactive: InteractiveArgs,
     /// Item ID (required for --attachments and --meta modes)
     pub id: Option<String>,
     /// Item file path (alternative to id)
     pub file: Option<PathBuf>,
+    /// Append items of the configured read-only mirrors to the path listing
+    pub mirrors: bool,
+    /// Print items (or the `--meta` item) as JSON (implies non-interactive)
+    pub format: OutputFormat,
 }
 
 impl Default for ListOptions {
     fn default() -> Self {
         Self {
             mode: ListMode::default(),
-            status: StatusFilter::default(),
+            scope: Scope::default(),
             labels: Vec::new(),
+            any_labels: Vec::new(),
+            not_labels: Vec::new(),
             author: None,
+            assignee: None,
+            mine: false,
             category: None,
+            since: None,
+            until: None,
+            due_after: None,
+            due_before: None,
+            overdue: false,
+            blocked: None,
             sort: SortBy::Id,
+            group_by: None,
+            summary: false,
+            verbose: false,
+            print_selection: None,
             interactive: InteractiveArgs::default(),
             id: None,
             file: None,
+            mirrors: true,
+            format: OutputFormat::Text,
         }
     }
 }
 
+/// An item reduced to what the plain path listing needs to sort and print
+struct ListRow {
+    path: PathBuf,
+    id: String,
+    title: String,
+    created_at: DateTime<Utc>,
+}
+
+impl ListRow {
+    /// Reduces a loaded item to a row (`None` if it has no path).
+    fn new(item: Item) -> Option<Self> {
+        Some(Self {
+            path: item.path?,
+            id: item.frontmatter.id,
+            title: item.frontmatter.title,
+            created_at: item.frontmatter.created_at,
+        })
+    }
+}
+
 /// Collects and filters items from storage.
 ///
 /// If `include_archived` is true, collects from archive directory,
@@ -101,23 +228,65 @@ pub fn collect_items(
     include_archived: bool,
     filter: &FilterCriteria,
 ) -> Vec<Item> {
-    let paths: Vec<_> = if include_archived {
-        storage::walk_archived(config).collect()
+    let mut items = Vec::new();
+    for_each_item(config, include_archived, filter, false, |item| {
+        items.push(item);
+    });
+    items
+}
+
+/// Collects and filters the items of a scope.
+pub fn collect_in_scope(config: &Config, scope: Scope, filter: &FilterCriteria) -> Vec<Item> {
+    let mut items = Vec::new();
+    for_each_in_scope(config, scope, filter, false, |item| items.push(item));
+    items
+}
+
+/// Walks items and calls `f` with each item that matches the filter.
+fn for_each_item(
+    config: &Config,
+    include_archived: bool,
+    filter: &FilterCriteria,
+    headers_only: bool,
+    f: impl FnMut(Item),
+) {
+    let scope = if include_archived {
+        Scope::Archive
     } else {
-        storage::walk_items(config).collect()
+        Scope::Open
     };
+    for_each_in_scope(config, scope, filter, headers_only, f);
+}
 
-    paths
-        .into_iter()
-        .filter_map(|path| Item::load(&path).ok())
-        .filter(|item| {
-            let category = item
-                .path
-                .as_ref()
-                .and_then(|p| storage::derive_category(config, p));
-            matches_filter(item, filter, category.as_deref())
-        })
-        .collect()
+/// Walks the items of a scope and calls `f` with each item that matches the
+/// filter.
+///
+/// With `headers_only`, only the frontmatter is read (bodies stay empty) into
+/// one reused buffer, so memory doesn't grow with the size of the items.
+fn for_each_in_scope(
+    config: &Config,
+    scope: Scope,
+    filter: &FilterCriteria,
+    headers_only: bool,
+    mut f: impl FnMut(Item),
+) {
+    let mut buf = String::new();
+    let mut visit = |path: PathBuf| {
+        let item = if headers_only {
+            Item::load_frontmatter(&path, &mut buf)
+        } else {
+            Item::load(&path)
+        };
+        let Ok(item) = item else {
+            return;
+        };
+        let category = storage::derive_category(config, &path);
+        if matches_filter(&item, filter, category.as_deref()) {
+            f(item);
+        }
+    };
+
+    scope.walk(config).for_each(&mut visit);
 }
 
 /// Sorts items in place by the given sort order.
@@ -129,40 +298,326 @@ pub fn sort_items(items: &mut [Item], sort: SortBy) {
     }
 }
 
-/// Collect unique labels from items, sorted alphabetically.
+/// Collect unique labels from items (spellings merged), sorted alphabetically.
 fn collect_unique_labels(items: &[Item]) -> Vec<String> {
-    let mut labels: Vec<String> = items
-        .iter()
-        .flat_map(|item| item.labels().to_vec())
-        .collect::<std::collections::HashSet<_>>()
-        .into_iter()
-        .collect();
+    let mut labels: Vec<String> = fold::spellings(
+        items
+            .iter()
+            .flat_map(|item| item.labels().iter().map(String::as_str)),
+    )
+    .into_values()
+    .collect();
     labels.sort();
     labels
 }
 
-/// Collect unique categories from items, sorted alphabetically.
+/// Collect unique categories from items (spellings merged), sorted alphabetically.
 fn collect_unique_categories(items: &[Item], config: &Config) -> Vec<String> {
-    let mut categories: Vec<String> = items
+    let categories: Vec<String> = items
         .iter()
         .filter_map(|item| {
             item.path
                 .as_ref()
                 .and_then(|p| storage::derive_category(config, p))
         })
-        .collect::<std::collections::HashSet<_>>()
-        .into_iter()
+        .collect();
+    let mut categories: Vec<String> = fold::spellings(categories.iter().map(String::as_str))
+        .into_values()
         .collect();
     categories.sort();
     categories
 }
 
+/// Folded keys of an item's labels, each once.
+fn label_keys(item: &Item) -> BTreeSet<String> {
+    item.labels().iter().map(|label| fold::key(label)).collect()
+}
+
+/// Items and subdirectories below one directory of the tree
+#[derive(Default)]
+struct TreeNode<'a> {
+    dirs: BTreeMap<String, Self>,
+    items: Vec<&'a Item>,
+}
+
+/// Renders items as a tree of the stack directory (categories, subdirectories,
+/// and the archive), keeping the given item order within each directory.
+///
+/// Colors are only used when stdout is a terminal; piped output is plain text.
+fn format_tree(config: &Config, items: &[Item]) -> String {
+    let stack_path = config.stack_path();
+    let mut root = TreeNode::default();
+    for item in items {
+        let Some(relative) = item
+            .path
+            .as_ref()
+            .and_then(|path| storage::paths::relative_to(path, &stack_path))
+        else {
+            continue;
+        };
+        let mut node = &mut root;
+        if let Some(parent) = relative.parent() {
+            for dir in parent {
+                node = node
+                    .dirs
+                    .entry(dir.to_string_lossy().to_string())
+                    .or_default();
+            }
+        }
+        node.items.push(item);
+    }
+
+    let color = std::io::stdout().is_terminal();
+    let name = format!("{}/", config.relative_path(&stack_path).display());
+    let mut out = if color {
+        format!("{}\n", name.blue().bold())
+    } else {
+        format!("{name}\n")
+    };
+    push_tree_children(&mut out, &root, "", color);
+    out
+}
+
+/// Appends the subdirectories (first) and items of a tree node.
+fn push_tree_children(out: &mut String, node: &TreeNode, prefix: &str, color: bool) {
+    let count = node.dirs.len() + node.items.len();
+    let mut entries = 0;
+    let mut connector = || {
+        entries += 1;
+        if entries == count {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        }
+    };
+
+    for (name, child) in &node.dirs {
+        let (branch, indent) = connector();
+        let name = format!("{name}/");
+        if color {
+            let _ = writeln!(out, "{prefix}{branch}{}", name.blue().bold());
+        } else {
+            let _ = writeln!(out, "{prefix}{branch}{name}");
+        }
+        push_tree_children(out, child, &format!("{prefix}{indent}"), color);
+    }
+
+    for item in &node.items {
+        let (branch, _) = connector();
+        if color {
+            let _ = writeln!(
+                out,
+                "{prefix}{branch}{}  {}",
+                item.id().cyan(),
+                item.title().bold()
+            );
+        } else {
+            let _ = writeln!(out, "{prefix}{branch}{}  {}", item.id(), item.title());
+        }
+    }
+}
+
+/// Prints the paths of the matching items (non-interactive default).
+///
+/// Streams the items' frontmatter and keeps only a [`ListRow`] per match, so
+/// listing very large projects doesn't hold every item in memory.
+fn print_paths(config: &Config, filter: &ListOptions, item_filter: &FilterCriteria) -> Result<()> {
+    let mut rows = Vec::new();
+    for_each_in_scope(config, filter.scope, item_filter, true, |item| {
+        if let Some(row) = ListRow::new(item) {
+            rows.push(row);
+        }
+    });
+    sort_rows(&mut rows, filter.sort);
+
+    // Mirrored items follow the project's own, one mirror at a time
+    let mirrored = load_mirrored(config, filter, item_filter)?;
+
+    if rows.is_empty() && mirrored.iter().all(|(_, items)| items.is_empty()) {
+        println!("No items found.");
+        return Ok(());
+    }
+    let mut out = std::io::stdout().lock();
+    for row in rows {
+        let _ = writeln!(out, "{}", config.relative_path(&row.path).display());
+    }
+    for (mirror, items) in mirrored {
+        let mut rows: Vec<ListRow> = items.into_iter().filter_map(ListRow::new).collect();
+        sort_rows(&mut rows, filter.sort);
+        for row in rows {
+            let _ = writeln!(out, "{}", mirror.display_path(&row.path));
+        }
+    }
+    Ok(())
+}
+
+/// Prints the matching items as a JSON array, mirrored items after the
+/// project's own.
+fn print_json(config: &Config, filter: &ListOptions, item_filter: &FilterCriteria) -> Result<()> {
+    let mut items = Vec::new();
+    for_each_in_scope(config, filter.scope, item_filter, true, |item| {
+        items.push(item);
+    });
+    sort_items(&mut items, filter.sort);
+    let mut records = item_records(config, &items);
+
+    for (mirror, mut mirrored) in load_mirrored(config, filter, item_filter)? {
+        sort_items(&mut mirrored, filter.sort);
+        records.extend(item_records(
+            &Config::mirror(mirror.root.clone()),
+            &mirrored,
+        ));
+    }
+
+    println!("{}", serde_json::to_string_pretty(&records)?);
+    Ok(())
+}
+
+/// Builds the JSON records of items loaded from the project of `config`.
+fn item_records(config: &Config, items: &[Item]) -> Vec<ItemRecord> {
+    items
+        .iter()
+        .filter_map(|item| Some(ItemRecord::at(config, item, item.path.as_ref()?)))
+        .collect()
+}
+
+/// Loads the items of the configured mirrors matching the filter (none with
+/// `--no-mirrors`), warning about mirrors that can't be read.
+fn load_mirrored(
+    config: &Config,
+    filter: &ListOptions,
+    item_filter: &FilterCriteria,
+) -> Result<Vec<MirroredItems>> {
+    let mut mirrored = Vec::new();
+    if !filter.mirrors {
+        return Ok(mirrored);
+    }
+    let mut warnings = Vec::new();
+    for &archived in filter.scope.mirrored() {
+        let (loaded, skipped) = storage::mirror::load_all(config, archived, item_filter)?;
+        mirrored.extend(loaded);
+        warnings.extend(skipped);
+    }
+    warnings.dedup();
+    ui::print_warnings(&warnings);
+    Ok(mirrored)
+}
+
+/// Prints a summary card per item, mirrored items after the project's own.
+fn print_cards(
+    config: &Config,
+    filter: &ListOptions,
+    items: &[Item],
+    item_filter: &FilterCriteria,
+) -> Result<()> {
+    let mut cards: Vec<String> = items
+        .iter()
+        .map(|item| ui::format_card(&ui::item_card(item, config)))
+        .collect();
+    for (mirror, mut mirrored) in load_mirrored(config, filter, item_filter)? {
+        sort_items(&mut mirrored, filter.sort);
+        let config = Config::mirror(mirror.root.clone());
+        cards.extend(
+            mirrored
+                .iter()
+                .map(|item| ui::format_card(&ui::item_card(item, &config))),
+        );
+    }
+    if cards.is_empty() {
+        println!("No items found.");
+    } else {
+        println!("{}", cards.join("\n\n"));
+    }
+    print_summary(filter, items);
+    Ok(())
+}
+
+/// Sorts path listing rows by the given sort order.
+fn sort_rows(rows: &mut [ListRow], sort: SortBy) {
+    match sort {
+        SortBy::Id => rows.sort_by(|a, b| a.id.cmp(&b.id)),
+        SortBy::Date => rows.sort_by_key(|row| Reverse(row.created_at)),
+        SortBy::Title => rows.sort_by_cached_key(|row| row.title.to_lowercase()),
+    }
+}
+
+/// Formats grouped items as sections with a `name (count)` header and the
+/// indented item paths, separated by blank lines.
+fn format_groups(config: &Config, items: &[Item], groups: &[Group]) -> String {
+    let color = std::io::stdout().is_terminal();
+    let mut out = String::new();
+    for (index, group) in groups.iter().enumerate() {
+        if index > 0 {
+            out.push('\n');
+        }
+        let header = format!("{} ({})", group.name, group.members.len());
+        if color {
+            let _ = writeln!(out, "{}", header.bold());
+        } else {
+            let _ = writeln!(out, "{header}");
+        }
+        for &member in &group.members {
+            if let Some(ref path) = items[member].path {
+                let _ = writeln!(out, "  {}", config.relative_path(path).display());
+            }
+        }
+    }
+    out
+}
+
+/// Prints the `--summary` footer after a blank line, if requested.
+fn print_summary(filter: &ListOptions, items: &[Item]) {
+    if filter.summary {
+        println!();
+        println!("{}", format_summary(items));
+    }
+}
+
+/// Formats the one-line summary footer: total, counts by status, and counts by
+/// priority (omitted when no item has one).
+fn format_summary(items: &[Item]) -> String {
+    let total = items.len();
+    let mut out = format!("{total} {}", if total == 1 { "item" } else { "items" });
+
+    let by_status = ui::count_by(items, Item::status);
+    let statuses: Vec<String> = [
+        Status::Open,
+        Status::InProgress,
+        Status::Closed,
+        Status::Template,
+    ]
+    .into_iter()
+    .filter_map(|status| Some(format!("{} {status}", by_status.get(&status)?)))
+    .collect();
+    let _ = write!(out, ": {}", statuses.join(", "));
+
+    let by_priority = ui::count_by(items, |item| item.custom_field(PRIORITY_FIELD));
+    if by_priority.keys().any(Option::is_some) {
+        let mut priorities: Vec<(&Option<String>, &usize)> = by_priority.iter().collect();
+        priorities.sort_by_key(|(priority, _)| {
+            let rank = priority.as_deref().map_or(PRIORITY_ORDER.len() + 1, |p| {
+                PRIORITY_ORDER
+                    .iter()
+                    .position(|known| p.eq_ignore_ascii_case(known))
+                    .unwrap_or(PRIORITY_ORDER.len())
+            });
+            (rank, priority.as_deref().map(str::to_lowercase))
+        });
+        let priorities: Vec<String> = priorities
+            .into_iter()
+            .map(|(priority, count)| format!("{count} {}", priority.as_deref().unwrap_or("unset")))
+            .collect();
+        let _ = write!(out, "; priority: {}", priorities.join(", "));
+    }
+    out
+}
+
 /// Executes the list command.
 pub fn execute(filter: &ListOptions) -> Result<()> {
     let config = Config::load()?;
 
     match filter.mode {
-        ListMode::Items => execute_items(filter, &config),
+        ListMode::Items | ListMode::Tree => execute_items(filter, &config),
         ListMode::Labels => execute_labels(filter, &config),
         ListMode::Categories => execute_categories(filter, &config),
         ListMode::Attachments => execute_attachments(filter, &config),
@@ -171,47 +626,143 @@ pub fn execute(filter: &ListOptions) -> Result<()> {
     }
 }
 
-/// Lists items (default mode).
-fn execute_items(filter: &ListOptions, config: &Config) -> Result<()> {
-    // Collect items based on status filter
-    let item_filter = FilterCriteria {
+/// Builds the item filter of the listing options; closed listings filter by
+/// close date, and `--mine` resolves to the configured user name.
+fn item_filter(filter: &ListOptions, config: &Config) -> Result<FilterCriteria> {
+    let dates = DateRange {
+        since: filter.since,
+        until: filter.until,
+    };
+    let (created, closed) = if filter.scope == Scope::Archive {
+        (DateRange::default(), dates)
+    } else {
+        (dates, DateRange::default())
+    };
+    let assignee = if filter.mine {
+        let Some(name) = config.user_name() else {
+            bail!("--mine needs your name: set user_name in the config or git user.name");
+        };
+        Some(name)
+    } else {
+        filter.assignee.clone()
+    };
+    // Closed items never block, so the open stack holds every blocker
+    let blockers = if filter.blocked.is_some() {
+        let items: Vec<_> = storage::walk_items(config)
+            .filter_map(|path| Item::load(&path).ok())
+            .collect();
+        Blockers::new(&items)
+    } else {
+        Blockers::default()
+    };
+    Ok(FilterCriteria {
         labels: filter.labels.clone(),
+        any_labels: filter.any_labels.clone(),
+        not_labels: filter.not_labels.clone(),
         author: filter.author.clone(),
+        assignee,
+        identities: config.identities(),
         category: filter.category.clone(),
+        created,
+        closed,
+        due_after: filter.due_after,
+        due_before: filter.due_before,
+        overdue: filter.overdue,
+        blocked: filter.blocked,
+        blockers,
         ..FilterCriteria::default()
-    };
+    })
+}
 
-    let mut items = match filter.status {
-        StatusFilter::Open => collect_items(config, false, &item_filter),
-        StatusFilter::Closed => collect_items(config, true, &item_filter),
-        StatusFilter::All => {
-            let mut open = collect_items(config, false, &item_filter);
-            let closed = collect_items(config, true, &item_filter);
-            open.extend(closed);
-            open
-        }
-    };
+/// Lists items (default mode).
+fn execute_items(filter: &ListOptions, config: &Config) -> Result<()> {
+    let item_filter = item_filter(filter, config)?;
+
+    if let Some(selection) = filter.print_selection {
+        return select_and_print(config, filter, &item_filter, selection);
+    }
+    if filter.format == OutputFormat::Json {
+        return print_json(config, filter, &item_filter);
+    }
+
+    // Only the selector and cards need item bodies (search filter, preview)
+    let interactive = filter.mode == ListMode::Items
+        && !filter.summary
+        && !filter.verbose
+        && filter.interactive.should_run(config);
+
+    // Plain path listing: keep just a compact row per item
+    if !interactive
+        && filter.mode == ListMode::Items
+        && filter.group_by.is_none()
+        && !filter.summary
+        && !filter.verbose
+    {
+        return print_paths(config, filter, &item_filter);
+    }
+
+    let mut items = Vec::new();
+    let headers_only = !interactive && !filter.verbose;
+    for_each_in_scope(config, filter.scope, &item_filter, headers_only, |item| {
+        items.push(item);
+    });
 
     // Sort items
     sort_items(&mut items, filter.sort);
 
+    if filter.verbose {
+        return print_cards(config, filter, &items, &item_filter);
+    }
+
     // Display
     if items.is_empty() {
         println!("No items found.");
         return Ok(());
     }
 
+    if filter.mode == ListMode::Tree {
+        print!("{}", format_tree(config, &items));
+        print_summary(filter, &items);
+        return Ok(());
+    }
+
+    let groups = filter.group_by.map_or_else(Vec::new, |by| {
+        group::group(
+            by,
+            items.iter().map(|item| {
+                let category = item
+                    .path
+                    .as_ref()
+                    .and_then(|p| storage::derive_category(config, p));
+                by.keys(item, category.as_deref(), &item_filter.identities)
+            }),
+        )
+    });
+
     // Check interactive mode
-    if !filter.interactive.should_run(config) {
-        // Non-interactive: print file paths
-        for item in &items {
-            if let Some(ref path) = item.path {
-                println!("{}", config.relative_path(path).display());
+    if !interactive {
+        if filter.group_by.is_some() {
+            print!("{}", format_groups(config, &items, &groups));
+        } else {
+            // Non-interactive: print file paths
+            for item in &items {
+                if let Some(ref path) = item.path {
+                    println!("{}", config.relative_path(path).display());
+                }
             }
         }
+        print_summary(filter, &items);
         return Ok(());
     }
 
+    // External picker: choose an item and open it
+    if !config.picker().is_builtin() {
+        let Some(index) = ui::select_item("Select an item", &items, config)? else {
+            return Ok(()); // User cancelled
+        };
+        return ui::open_item_in_editor(&items[index], config);
+    }
+
     // Collect available labels and categories for filter overlay
     let available_labels = collect_unique_labels(&items);
     let available_categories = collect_unique_categories(&items, config);
@@ -223,6 +774,7 @@ fn execute_items(filter: &ListOptions, config: &Config) -> Result<()> {
         config,
         available_labels,
         available_categories,
+        groups,
     )?
     else {
         return Ok(()); // User cancelled
@@ -233,8 +785,39 @@ fn execute_items(filter: &ListOptions, config: &Config) -> Result<()> {
     Ok(())
 }
 
+/// Lets the user pick an item on the terminal and prints it to stdout
+/// (`--print-selection`), for use in shell pipelines.
+///
+/// Fails if nothing matches or the selection is cancelled, so scripts can
+/// stop on a non-zero exit status.
+fn select_and_print(
+    config: &Config,
+    filter: &ListOptions,
+    item_filter: &FilterCriteria,
+    selection: Selection,
+) -> Result<()> {
+    // External pickers draw on the terminal themselves
+    if config.picker().is_builtin() {
+        crate::tui::terminal::draw_on_tty()?;
+    }
+
+    let mut items = Vec::new();
+    for_each_in_scope(config, filter.scope, item_filter, true, |item| {
+        items.push(item);
+    });
+    if items.is_empty() {
+        bail!("No items found");
+    }
+    sort_items(&mut items, filter.sort);
+
+    let Some(index) = ui::select_item("Select an item", &items, config)? else {
+        bail!("Selection cancelled");
+    };
+    ui::print_selection(&items[index], config, selection)
+}
+
 /// Handle an action selected from the item action popup.
-fn handle_item_action(action: ItemAction, config: &Config) -> Result<()> {
+pub fn handle_item_action(action: ItemAction, config: &Config) -> Result<()> {
     match action {
         ItemAction::View(path) => {
             // Open in editor
@@ -246,22 +829,33 @@ fn handle_item_action(action: ItemAction, config: &Config) -> Result<()> {
             execute_edit_wizard(&path, config)?;
         }
         ItemAction::Close(path) => {
-            commands::execute_close(None, Some(path))?;
+            commands::execute_close(None, Some(path), None)?;
         }
         ItemAction::Reopen(path) => {
-            commands::execute_reopen(None, Some(path))?;
+            commands::execute_reopen(None, Some(path), None)?;
+        }
+        ItemAction::Run(path, name) => {
+            let item = Item::load(&path)?;
+            commands::run::run_action(config, &item, &path, &name)?;
+        }
+        ItemAction::Copy(path, what) => {
+            let item = Item::load(&path)?;
+            commands::copy::copy_item(config, &item, &path, what)?;
         }
         ItemAction::Delete(path) => {
             // Show confirmation dialog
             let item = Item::load(&path)?;
+            let category = storage::derive_category(config, &path);
+            policy::check_protected(
+                config,
+                Destructive::Delete,
+                &item,
+                category.as_deref(),
+                false,
+            )?;
             let message = format!("Delete '{}'?", item.title());
             if ui::confirm(&message)? == Some(true) {
-                storage::delete_item(&path)?;
-                println!(
-                    "{} Deleted: {}",
-                    "✓".green(),
-                    config.relative_path(&path).display()
-                );
+                commands::delete::trash(config, &path, &item)?;
             }
         }
     }
@@ -271,23 +865,28 @@ fn handle_item_action(action: ItemAction, config: &Config) -> Result<()> {
 
 /// Execute the edit wizard for an existing item.
 fn execute_edit_wizard(path: &std::path::Path, config: &Config) -> Result<()> {
-    use crate::tui::{self, screens::NewItemWizard};
+    use crate::tui;
 
     // Load the item
     let item = Item::load(path)?;
 
     // Collect existing metadata
-    let (existing_categories, existing_labels) = commands::new::collect_existing_metadata(config);
+    let metadata = commands::new::collect_existing_metadata(config);
 
     // Get current category from path
     let current_category = storage::derive_category(config, path);
 
     // Create pre-populated wizard
-    let wizard = NewItemWizard::new(existing_categories, existing_labels)
+    let wizard = metadata
+        .wizard()
         .with_title(item.title())
         .with_attachments(item.attachments().to_vec())
         .with_category(current_category.clone())
         .with_labels(item.labels())
+        .with_due(item.due().map(|d| d.to_string()).as_deref())
+        .with_priority(item.custom_field(PRIORITY_FIELD))
+        .with_assignee(item.assignee())
+        .with_body(&item.body)
         .with_item_id(item.id())
         .for_editing();
 
@@ -297,31 +896,50 @@ fn execute_edit_wizard(path: &std::path::Path, config: &Config) -> Result<()> {
         return Ok(());
     };
 
-    // Apply changes (body is edited in external editor, not in wizard)
+    // Apply changes
+    let labels = item.labels().to_vec();
     let mut updated = item;
+    commands::new::apply_wizard_fields(config, &mut updated, &output);
     updated.set_title(output.title);
     updated.frontmatter.labels = output.labels;
+    updated.body = output.body;
+    policy::check_labels(config, &mut updated, &labels)?;
 
     // Handle new attachments
     if !output.attachments.is_empty() {
-        // For new attachments, we need to process them
-        for source in &output.attachments {
-            // Skip existing attachments
-            if updated.attachments().contains(source) {
-                continue;
-            }
+        // For new attachments, we need to process them (skipping existing ones)
+        let new_sources: Vec<String> = output
+            .attachments
+            .iter()
+            .filter(|source| !updated.attachments().contains(source))
+            .cloned()
+            .collect();
+        let expanded = storage::sources::expand(
+            &std::env::current_dir()?,
+            &new_sources,
+            config.attachment_directories(),
+        )?;
+        for source in &expanded.sources {
             // Process new attachment
-            if let Ok(result) = storage::process_attachment(source, &mut updated, path) {
+            if let Ok(result) = storage::process_attachment(config, source, &mut updated, path) {
                 match result {
-                    storage::AttachmentResult::UrlAdded(url) => {
-                        println!("  {} {}", "+".green(), url);
+                    storage::AttachmentResult::UrlAdded { url, title } => {
+                        ui::print_added_url(&url, title.as_deref());
                     }
-                    storage::AttachmentResult::FileCopied { original, new_name } => {
+                    storage::AttachmentResult::FileCopied {
+                        original,
+                        new_name,
+                        duplicates,
+                    } => {
                         println!("  {} {} -> {}", "+".green(), original, new_name);
+                        ui::print_duplicate_attachment(&duplicates);
                     }
                     storage::AttachmentResult::FileNotFound(p) => {
                         eprintln!("  {} File not found: {}", "!".yellow(), p);
                     }
+                    storage::AttachmentResult::Rejected { file, reason } => {
+                        eprintln!("  {} Rejected {}: {}", "!".yellow(), file, reason);
+                    }
                 }
             }
         }
@@ -337,14 +955,17 @@ fn execute_edit_wizard(path: &std::path::Path, config: &Config) -> Result<()> {
     } else {
         // Move to new category
         let (new_path, warnings) =
-            storage::move_to_category(config, path, output.category.as_deref())?;
+            storage::move_to_category(config, path, updated.status(), output.category.as_deref())?;
         ui::print_warnings(&warnings);
         ui::print_success("Updated", config, &new_path);
         new_path
     };
 
-    // Open editor for content editing
-    crate::editor::open(&final_path, config).context("Failed to open editor")?;
+    // Open editor for content editing (unless the body was edited in the wizard)
+    if !output.skip_editor {
+        crate::editor::open(&final_path, config).context("Failed to open editor")?;
+    }
+    ui::print_size_warning(config, &final_path);
 
     Ok(())
 }
@@ -355,25 +976,44 @@ fn execute_labels(filter: &ListOptions, config: &Config) -> Result<()> {
 
     // Load all items to get complete label vocabulary
     let all_items = storage::load_all_items(config);
-    let all_label_counts = ui::count_by_many(&all_items, |item: &Item| item.labels().to_vec());
+    // "Bug" and "bug" are counted as one label, shown in its most common spelling
+    let spellings = fold::spellings(
+        all_items
+            .iter()
+            .flat_map(|item| item.labels().iter().map(String::as_str)),
+    );
+    let all_label_counts = ui::count_by_many(&all_items, label_keys);
+    let defined = &config.label_policy().defined;
 
-    if all_label_counts.is_empty() {
+    if all_label_counts.is_empty() && defined.is_empty() {
         println!("No labels found.");
         return Ok(());
     }
 
     // Count only open items per label (for display and selectability)
     let open_items = collect_items(config, false, &item_filter);
-    let open_label_counts = ui::count_by_many(&open_items, |item: &Item| item.labels().to_vec());
+    let open_label_counts = ui::count_by_many(&open_items, label_keys);
 
     // Build label list: all labels with their open counts
     let mut labels: Vec<(String, usize)> = all_label_counts
         .keys()
-        .map(|label| {
-            let open_count = open_label_counts.get(label).copied().unwrap_or(0);
-            (label.clone(), open_count)
+        .map(|key| {
+            let open_count = open_label_counts.get(key).copied().unwrap_or(0);
+            (spellings[key].clone(), open_count)
         })
         .collect();
+    // Defined labels are listed even before they are used
+    for def in defined {
+        if !all_label_counts.contains_key(&fold::key(&def.name)) {
+            labels.push((def.name.clone(), 0));
+        }
+    }
+    let description = |label: &str| {
+        labels::find(defined, label)
+            .and_then(|def| def.description.as_deref())
+            .map(|description| format!("  {description}"))
+            .unwrap_or_default()
+    };
 
     // Sort by open count (descending), then alphabetically
     labels.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
@@ -381,8 +1021,14 @@ fn execute_labels(filter: &ListOptions, config: &Config) -> Result<()> {
     // Check interactive mode
     if !filter.interactive.should_run(config) {
         // Non-interactive: print labels with open count, one per line
+        let color = std::io::stdout().is_terminal();
         for (label, count) in &labels {
-            println!("{label} ({count})");
+            if color {
+                let description = description(label);
+                println!("{} ({count}){}", labels::paint(label), description.dimmed());
+            } else {
+                println!("{label} ({count}){}", description(label));
+            }
         }
         return Ok(());
     }
@@ -398,7 +1044,7 @@ fn execute_labels(filter: &ListOptions, config: &Config) -> Result<()> {
     // Build display options
     let options: Vec<String> = labels
         .iter()
-        .map(|(label, count)| format!("{label} ({count})"))
+        .map(|(label, count)| format!("{label} ({count}){}", description(label)))
         .collect();
 
     // Show TUI even if all items are disabled (user can view and ESC to exit)
@@ -439,11 +1085,16 @@ fn execute_categories(filter: &ListOptions, config: &Config) -> Result<()> {
 
     // Load all items to get complete category vocabulary
     let all_items = storage::load_all_items(config);
-    let all_category_counts = ui::count_by(&all_items, |item: &Item| {
+    let category = |item: &Item| {
         item.path
             .as_ref()
             .and_then(|p| storage::derive_category(config, p))
-    });
+    };
+    // Categories differing only in case are counted as one
+    let all_categories: Vec<String> = all_items.iter().filter_map(category).collect();
+    let spellings = fold::spellings(all_categories.iter().map(String::as_str));
+    let category_key = |item: &Item| category(item).map(|c| fold::key(&c));
+    let all_category_counts = ui::count_by(&all_items, category_key);
 
     if all_category_counts.is_empty() {
         println!("No categories found.");
@@ -452,18 +1103,14 @@ fn execute_categories(filter: &ListOptions, config: &Config) -> Result<()> {
 
     // Count only open items per category (for display and selectability)
     let open_items = collect_items(config, false, &item_filter);
-    let open_category_counts = ui::count_by(&open_items, |item: &Item| {
-        item.path
-            .as_ref()
-            .and_then(|p| storage::derive_category(config, p))
-    });
+    let open_category_counts = ui::count_by(&open_items, category_key);
 
     // Build category list: all categories with their open counts
     let mut categories: Vec<(Option<String>, usize)> = all_category_counts
         .keys()
-        .map(|category| {
-            let open_count = open_category_counts.get(category).copied().unwrap_or(0);
-            (category.clone(), open_count)
+        .map(|key| {
+            let open_count = open_category_counts.get(key).copied().unwrap_or(0);
+            (key.as_ref().map(|key| spellings[key].clone()), open_count)
         })
         .collect();
 
@@ -547,7 +1194,7 @@ fn execute_attachments(filter: &ListOptions, config: &Config) -> Result<()> {
     let item_ref = storage::ItemRef::from_options(filter.id.clone(), filter.file.clone())?;
 
     // Find and load the item
-    let storage::LoadedItem { item, .. } = item_ref.resolve(config)?;
+    let storage::LoadedItem { item, .. } = item_ref.resolve_read_only(config)?;
 
     let attachments = item.attachments();
 
@@ -556,9 +1203,12 @@ fn execute_attachments(filter: &ListOptions, config: &Config) -> Result<()> {
         return Ok(());
     }
 
-    // Print attachments one per line
+    // Print attachments one per line, URLs with their page title
     for attachment in attachments {
-        println!("{attachment}");
+        match item.link_title(attachment) {
+            Some(title) => println!("{attachment}  ({title})"),
+            None => println!("{attachment}"),
+        }
     }
 
     Ok(())
@@ -569,7 +1219,13 @@ fn execute_meta(filter: &ListOptions, config: &Config) -> Result<()> {
     let item_ref = storage::ItemRef::from_options(filter.id.clone(), filter.file.clone())?;
 
     // Find and load the item
-    let storage::LoadedItem { path, item } = item_ref.resolve(config)?;
+    let storage::LoadedItem { path, item } = item_ref.resolve_read_only(config)?;
+
+    if filter.format == OutputFormat::Json {
+        let record = ItemRecord::at(config, &item, &path);
+        println!("{}", serde_json::to_string_pretty(&record)?);
+        return Ok(());
+    }
 
     // Print frontmatter fields
     println!("id: {}", item.id());
@@ -0,0 +1,90 @@
+//! # Merge Driver Command
+//!
+//! Git merge driver for item files. Git calls it with the common ancestor,
+//! our version, and their version (`qs merge-driver %O %A %B`); the merged
+//! item is written over our version. Frontmatter is merged field by field, so
+//! only conflicting body lines are left for a human.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+
+use crate::{
+    config::Config,
+    item::merge,
+    storage::{git, git_files::MERGE_DRIVER},
+    ui,
+};
+
+/// Arguments for the merge-driver command
+pub struct MergeDriverArgs {
+    /// Common ancestor (`%O`)
+    pub base: Option<PathBuf>,
+    /// Our version (`%A`), replaced by the merge result
+    pub ours: Option<PathBuf>,
+    /// Their version (`%B`)
+    pub theirs: Option<PathBuf>,
+    /// Register the driver in the repository's git config instead of merging
+    pub install: bool,
+}
+
+/// Executes the merge-driver command.
+///
+/// Fails (so git reports a conflict) when the body has conflicting changes.
+pub fn execute(args: &MergeDriverArgs) -> Result<()> {
+    if args.install {
+        return execute_install();
+    }
+    let (Some(base), Some(ours), Some(theirs)) = (&args.base, &args.ours, &args.theirs) else {
+        bail!("Expected the base, ours, and theirs files (%O %A %B)");
+    };
+
+    // Only for the date format and frontmatter syntax; merging works without a project
+    let _ = Config::load();
+
+    let read = |path: &PathBuf| {
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+    };
+    let merged = merge::merge(&read(base)?, &read(ours)?, &read(theirs)?)?;
+    fs::write(ours, &merged.content)
+        .with_context(|| format!("Failed to write {}", ours.display()))?;
+
+    ui::print_warnings(&merged.notes);
+    if merged.conflicts > 0 {
+        bail!(
+            "{} conflicting {} in the item body",
+            merged.conflicts,
+            if merged.conflicts == 1 {
+                "change"
+            } else {
+                "changes"
+            }
+        );
+    }
+    Ok(())
+}
+
+/// Registers the driver in the repository's git config.
+///
+/// `qs init --gitattributes` routes item files to it.
+fn execute_install() -> Result<()> {
+    if !git::is_git_repo() {
+        bail!("Not in a git repository");
+    }
+    git::set_config(
+        &format!("merge.{MERGE_DRIVER}.name"),
+        "queuestack item merge",
+    )?;
+    git::set_config(
+        &format!("merge.{MERGE_DRIVER}.driver"),
+        "qs merge-driver %O %A %B",
+    )?;
+
+    println!("{} Registered the {MERGE_DRIVER} merge driver", "✓".green());
+    println!("  Route item files to it with: qs init --gitattributes");
+    Ok(())
+}
@@ -0,0 +1,78 @@
+//! # Mirror Command
+//!
+//! Lists the read-only mirrors configured in `[mirrors]` and fetches the ones
+//! that come from git URLs.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use crate::{config::Config, item::FilterCriteria, storage::mirror};
+
+/// Arguments for the mirror fetch subcommand
+pub struct MirrorFetchArgs {
+    /// Mirror to fetch (all git mirrors if not given)
+    pub name: Option<String>,
+}
+
+/// Executes the mirror list subcommand.
+pub fn execute_list() -> Result<()> {
+    let config = Config::load()?;
+    let mirrors = mirror::mirrors(&config)?;
+    if mirrors.is_empty() {
+        println!("No mirrors configured.");
+        return Ok(());
+    }
+
+    for mirror in mirrors {
+        let state = match (mirror.is_remote(), mirror.is_available()) {
+            (_, true) => {
+                let count = mirror.items(false, &FilterCriteria::default()).len();
+                format!("{count} open")
+            }
+            (true, false) => "not fetched".to_string(),
+            (false, false) => "not found".to_string(),
+        };
+        println!("{}  {}  {state}", mirror.name, mirror.source);
+    }
+    Ok(())
+}
+
+/// Executes the mirror fetch subcommand.
+pub fn execute_fetch(args: &MirrorFetchArgs) -> Result<()> {
+    let config = Config::load()?;
+    let mirrors = mirror::mirrors(&config)?;
+
+    let selected: Vec<_> = match args.name {
+        Some(ref name) => {
+            let Some(found) = mirrors.into_iter().find(|mirror| &mirror.name == name) else {
+                bail!("No mirror named '{name}'");
+            };
+            if !found.is_remote() {
+                bail!("Mirror '{name}' is a local path and is read in place");
+            }
+            vec![found]
+        }
+        None => mirrors
+            .into_iter()
+            .filter(mirror::Mirror::is_remote)
+            .collect(),
+    };
+    if selected.is_empty() {
+        println!("No git mirrors to fetch.");
+        return Ok(());
+    }
+
+    for found in selected {
+        mirror::fetch(&found)?;
+        println!(
+            "{} Fetched {} from {}",
+            "✓".green(),
+            found.name,
+            found.source
+        );
+    }
+    Ok(())
+}
@@ -5,27 +5,108 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+pub mod activity;
+pub mod agent;
 pub mod attach;
+pub mod bench;
+pub mod bulk;
+pub mod clean;
 pub mod close;
+pub mod comment;
+pub mod commit_template;
+pub mod complete;
 pub mod completions;
+pub mod copy;
+pub mod daemon;
+pub mod delete;
+pub mod diff;
+pub mod draft;
+pub mod du;
+pub mod export;
+pub mod graph;
+pub mod help;
+pub mod hooks;
+pub mod info;
 pub mod init;
+pub mod insights;
+pub mod link;
 pub mod list;
+pub mod merge_driver;
+pub mod mirror;
 pub mod new;
+pub mod patch;
+pub mod pop;
+pub mod resolve;
+pub mod rules;
+pub mod run;
 pub mod search;
 pub mod setup;
+pub mod show;
+pub mod stats;
+pub mod sync;
+pub mod tour;
+pub mod trash;
+pub mod triage;
 pub mod update;
+pub mod url;
+pub mod validate;
+pub mod which;
 
 pub use self::{
+    activity::{execute as activity, ActivityArgs},
+    agent::{execute as agent, AgentArgs},
     attach::{
-        execute_add as attach_add, execute_remove as attach_remove, AttachAddArgs, AttachRemoveArgs,
+        execute_add as attach_add, execute_duplicates as attach_duplicates,
+        execute_open as attach_open, execute_remove as attach_remove, AttachAddArgs,
+        AttachDuplicatesArgs, AttachOpenArgs, AttachRemoveArgs,
     },
-    close::{execute_close, execute_reopen},
+    bench::{execute as bench, BenchArgs},
+    bulk::{
+        execute_apply as bulk_apply, execute_export as bulk_export, BulkApplyArgs, BulkExportArgs,
+    },
+    clean::{execute as clean, CleanArgs},
+    close::{execute_close, execute_reopen, plan_close, plan_reopen},
+    comment::{execute_add as comment, execute_list as comments, CommentArgs, CommentsArgs},
+    commit_template::{execute as commit_template, CommitTemplateArgs},
+    complete::{execute as complete, CompleteArgs, CompleteKind},
     completions::execute as completions,
-    init::execute as init,
-    list::{execute as list, ListMode, ListOptions, SortBy, StatusFilter},
+    copy::{execute as copy, CopyArgs},
+    daemon::{execute as daemon, DaemonArgs},
+    delete::{execute as delete, plan as plan_delete, DeleteArgs},
+    diff::{execute as diff, DiffArgs},
+    draft::{
+        execute_apply as draft_apply, execute_discard as draft_discard, execute_edit as draft,
+        execute_list as draft_list, DraftApplyArgs, DraftArgs, DraftDiscardArgs,
+    },
+    du::{execute as du, DuArgs, DEFAULT_THRESHOLD as DU_DEFAULT_THRESHOLD},
+    export::{execute as export, ExportArgs, ExportFormat},
+    graph::{execute as graph, GraphArgs, GraphFormat},
+    help::{execute as help, HelpArgs},
+    hooks::{execute_install as hooks_install, HooksInstallArgs},
+    info::execute as info,
+    init::{execute as init, execute_with as init_with, InitArgs},
+    insights::{execute as insights, InsightsArgs},
+    link::{execute as link, LinkArgs},
+    list::{execute as list, ListMode, ListOptions, Scope, SortBy, StatusFilter},
+    merge_driver::{execute as merge_driver, MergeDriverArgs},
+    mirror::{execute_fetch as mirror_fetch, execute_list as mirror_list, MirrorFetchArgs},
     new::{execute as new, NewArgs},
+    patch::{execute as patch, PatchArgs},
+    pop::{execute as pop, Order as PopOrder, PopArgs},
+    resolve::{execute as resolve, ResolveArgs},
+    rules::{execute_apply as rules_apply, RulesApplyArgs},
+    run::{execute as run, RunArgs},
     search::{execute as search, SearchArgs},
-    setup::execute as setup,
-    update::{execute as update, UpdateArgs},
+    setup::{execute as setup, print_paths as setup_print_paths, SetupShell},
+    show::{execute as show, ShowArgs},
+    stats::{execute as stats, StatsArgs, StatsFormat},
+    sync::{execute_github as sync_github, SyncGithubArgs},
+    tour::execute as tour,
+    trash::{execute_list as trash_list, execute_restore as trash_restore, TrashRestoreArgs},
+    triage::{execute as triage, TriageArgs},
+    update::{execute as update, plan as plan_update, UpdateArgs},
+    url::{execute as url, UrlArgs},
+    validate::{execute as validate, ValidateArgs},
+    which::execute as which,
 };
-pub use crate::ui::InteractiveArgs;
+pub use crate::ui::{InteractiveArgs, OutputFormat, Selection};
@@ -7,17 +7,22 @@
 
 use std::collections::HashSet;
 use std::io::IsTerminal;
+use std::path::PathBuf;
 
 use anyhow::{Context, Result};
-use chrono::Utc;
+use chrono::{NaiveDate, Utc};
 use owo_colors::OwoColorize;
 
 use crate::{
     config::Config,
+    constants::PRIORITY_FIELD,
     editor, id,
-    item::{is_url, normalize_identifier, Frontmatter, Item, Status},
+    item::{fold, is_url, normalize_identifier, variables, Frontmatter, Item, Status},
     storage,
-    tui::{self, screens::NewItemWizard},
+    tui::{
+        self,
+        screens::{NewItemWizard, WizardOutput},
+    },
     ui::{self, InteractiveArgs},
 };
 
@@ -26,11 +31,17 @@ pub struct NewArgs {
     pub title: Option<String>,
     pub labels: Vec<String>,
     pub category: Option<String>,
+    /// Due date (`due` field)
+    pub due: Option<NaiveDate>,
+    /// Assignee (`assignee` field)
+    pub assignee: Option<String>,
     pub attachments: Vec<String>,
     pub interactive: InteractiveArgs,
     pub as_template: bool,
     #[allow(clippy::option_option)]
     pub from_template: Option<Option<String>>,
+    /// Answers to the template's `{{prompt:...}}` placeholders (`--var`)
+    pub vars: Vec<(String, String)>,
 }
 
 /// Executes the new command.
@@ -39,17 +50,21 @@ pub fn execute(args: NewArgs) -> Result<()> {
 
     // Handle --from-template
     if let Some(ref template_ref) = args.from_template {
-        return execute_from_template(&mut config, &args, template_ref.as_deref());
+        execute_from_template(&mut config, &args, template_ref.as_deref())?;
+        return storage::vault::sync(&config);
     }
 
-    // If no title provided and we're in a terminal, launch the wizard
-    if args.title.is_none() {
+    // Without a title, or with -i in a terminal, launch the wizard pre-filled
+    // with the values given on the command line
+    if args.title.is_none() || wants_wizard(&args) {
         if !std::io::stdout().is_terminal() {
             anyhow::bail!("Title is required in non-interactive mode");
         }
-        return execute_wizard(&config, args.as_template);
+        execute_wizard(&config, &args)?;
+        return storage::vault::sync(&config);
     }
 
+    let assignee = assignee(&config, &args);
     let title = args.title.unwrap();
 
     // Validate title is not empty
@@ -74,17 +89,6 @@ pub fn execute(args: NewArgs) -> Result<()> {
     // Get author name (prompts if not available)
     let author = config.user_name_or_prompt()?;
 
-    // Generate ID
-    let id = id::generate(config.id_pattern());
-
-    // Normalize labels and category (spaces -> hyphens)
-    let labels: Vec<String> = args
-        .labels
-        .iter()
-        .map(|l| normalize_identifier(l))
-        .collect();
-    let category = args.category.as_deref().map(normalize_identifier);
-
     // Determine status based on --as-template flag
     let status = if args.as_template {
         Status::Template
@@ -92,30 +96,24 @@ pub fn execute(args: NewArgs) -> Result<()> {
         Status::Open
     };
 
-    // Create frontmatter
-    let frontmatter = Frontmatter {
-        id,
+    let (path, mut item) = create(
+        &config,
         title,
         author,
-        created_at: Utc::now(),
+        &args.labels,
+        args.category.as_deref(),
         status,
-        labels,
-        attachments: vec![],
-    };
+    )?;
 
-    // Create item
-    let mut item = Item::new(frontmatter);
-
-    // Save to disk (category determines folder placement)
-    let path = if args.as_template {
-        storage::create_template(&config, &item, category.as_deref())?
-    } else {
-        storage::create_item(&config, &item, category.as_deref())?
-    };
+    if args.due.is_some() || args.assignee.is_some() {
+        item.set_due(args.due);
+        item.set_assignee(assignee);
+        item.save(&path)?;
+    }
 
     // Process attachments if any
     if !args.attachments.is_empty() {
-        ui::process_and_save_attachments(&mut item, &path, &args.attachments)?;
+        ui::process_and_save_attachments(&config, &mut item, &path, &args.attachments)?;
     }
 
     // Resolve interactive mode (editor doesn't require terminal check)
@@ -125,6 +123,9 @@ pub fn execute(args: NewArgs) -> Result<()> {
     if interactive {
         editor::open(&path, &config).context("Failed to open editor")?;
     }
+    ui::print_size_warning(&config, &path);
+
+    storage::vault::sync(&config)?;
 
     // Output the path (for scripting)
     println!("{}", config.relative_path(&path).display());
@@ -132,10 +133,70 @@ pub fn execute(args: NewArgs) -> Result<()> {
     Ok(())
 }
 
-/// Collect existing categories and labels from all items.
-pub fn collect_existing_metadata(config: &Config) -> (Vec<String>, Vec<String>) {
+/// Creates an item (or template) on disk without opening an editor.
+///
+/// Generates the ID and normalizes labels and category. Returns the path of
+/// the created file together with the saved item.
+pub fn create(
+    config: &Config,
+    title: String,
+    author: String,
+    labels: &[String],
+    category: Option<&str>,
+    status: Status,
+) -> Result<(PathBuf, Item)> {
+    // Generate ID
+    let id = id::generate(config.id_pattern());
+
+    // Normalize labels and category (spaces -> hyphens)
+    let labels: Vec<String> = labels.iter().map(|l| normalize_identifier(l)).collect();
+    let category = category.map(normalize_identifier);
+
+    // Create frontmatter
+    let frontmatter = Frontmatter {
+        id,
+        title,
+        author,
+        created_at: Utc::now(),
+        status,
+        labels,
+        ..Frontmatter::default()
+    };
+
+    // Create item
+    let mut item = Item::new(frontmatter);
+
+    // Save to disk (category determines folder placement)
+    let path = if status == Status::Template {
+        storage::create_template(config, &mut item, category.as_deref())?
+    } else {
+        storage::create_item(config, &mut item, category.as_deref())?
+    };
+
+    Ok((path, item))
+}
+
+/// Categories, labels, and people found in existing items, offered by the wizard.
+pub struct ExistingMetadata {
+    pub categories: Vec<String>,
+    pub labels: Vec<String>,
+    /// Authors and assignees, mapped to their canonical identities
+    pub people: Vec<String>,
+}
+
+impl ExistingMetadata {
+    /// Creates a wizard offering the existing categories, labels, and people.
+    pub fn wizard(self) -> NewItemWizard {
+        NewItemWizard::new(self.categories, self.labels).with_people(self.people)
+    }
+}
+
+/// Collect existing categories, labels, and people from all items.
+pub fn collect_existing_metadata(config: &Config) -> ExistingMetadata {
     let mut categories: HashSet<String> = HashSet::new();
     let mut labels: HashSet<String> = HashSet::new();
+    let mut people: HashSet<String> = HashSet::new();
+    let identities = config.identities();
 
     let paths: Vec<_> = storage::walk_all(config).collect();
 
@@ -148,24 +209,79 @@ pub fn collect_existing_metadata(config: &Config) -> (Vec<String>, Vec<String>)
             for label in item.labels() {
                 labels.insert(label.clone());
             }
+            let assignee = item.assignee();
+            for person in std::iter::once(item.author()).chain(assignee.as_deref()) {
+                if !person.trim().is_empty() {
+                    people.insert(identities.canonical(person).to_string());
+                }
+            }
         }
     }
 
     let mut categories: Vec<_> = categories.into_iter().collect();
-    let mut labels: Vec<_> = labels.into_iter().collect();
+    // Offer one spelling of labels that differ only in case
+    let mut labels: Vec<_> = fold::spellings(labels.iter().map(String::as_str))
+        .into_values()
+        .collect();
+    let mut people: Vec<_> = people.into_iter().collect();
     categories.sort();
     labels.sort();
+    people.sort_by_key(|p| p.to_lowercase());
+
+    ExistingMetadata {
+        categories,
+        labels,
+        people,
+    }
+}
+
+/// Stores the wizard's due date, priority, and assignee in the item's
+/// frontmatter, removing the ones left empty. Assignees are recorded under
+/// their canonical identity.
+pub fn apply_wizard_fields(config: &Config, item: &mut Item, output: &WizardOutput) {
+    let assignee = output
+        .assignee
+        .as_deref()
+        .map(|a| config.identities().canonical(a).to_string());
+    item.set_due(output.due);
+    item.set_custom_field(PRIORITY_FIELD, output.priority.clone());
+    item.set_assignee(assignee);
+}
+
+/// Returns the `--assignee` under its canonical identity.
+fn assignee(config: &Config, args: &NewArgs) -> Option<String> {
+    args.assignee
+        .as_deref()
+        .map(str::trim)
+        .filter(|a| !a.is_empty())
+        .map(|a| config.identities().canonical(a).to_string())
+}
 
-    (categories, labels)
+/// Returns true if `-i` asks for the wizard along with a title.
+fn wants_wizard(args: &NewArgs) -> bool {
+    args.title.is_some() && args.interactive.interactive && tui::terminal::is_available()
 }
 
-/// Execute the wizard flow for creating a new item.
-fn execute_wizard(config: &Config, as_template: bool) -> Result<()> {
+/// Execute the wizard flow for creating a new item, starting from the title,
+/// labels, category, and attachments given on the command line.
+fn execute_wizard(config: &Config, args: &NewArgs) -> Result<()> {
     // Collect existing metadata
-    let (existing_categories, existing_labels) = collect_existing_metadata(config);
+    let metadata = collect_existing_metadata(config);
 
     // Run the wizard
-    let wizard = NewItemWizard::new(existing_categories, existing_labels);
+    let labels: Vec<String> = args
+        .labels
+        .iter()
+        .map(|l| normalize_identifier(l))
+        .collect();
+    let wizard = metadata
+        .wizard()
+        .with_title(args.title.as_deref().unwrap_or_default())
+        .with_attachments(args.attachments.clone())
+        .with_category(args.category.as_deref().map(normalize_identifier))
+        .with_labels(&labels)
+        .with_due(args.due.map(|d| d.to_string()).as_deref())
+        .with_assignee(assignee(config, args));
     let Some(output) = tui::run(wizard)? else {
         println!("{}", "Cancelled.".dimmed());
         return Ok(());
@@ -187,7 +303,7 @@ fn execute_wizard(config: &Config, as_template: bool) -> Result<()> {
     let category = output.category.as_deref().map(normalize_identifier);
 
     // Determine status based on --as-template flag
-    let status = if as_template {
+    let status = if args.as_template {
         Status::Template
     } else {
         Status::Open
@@ -196,33 +312,37 @@ fn execute_wizard(config: &Config, as_template: bool) -> Result<()> {
     // Create frontmatter from wizard output
     let frontmatter = Frontmatter {
         id,
-        title: output.title,
+        title: output.title.clone(),
         author,
         created_at: Utc::now(),
         status,
         labels,
-        attachments: vec![],
+        ..Frontmatter::default()
     };
 
-    // Create item
+    // Create item with the body and fields set in the wizard
     let mut item = Item::new(frontmatter);
+    apply_wizard_fields(&config, &mut item, &output);
+    item.body = output.body;
 
     // Save to disk (category determines folder placement)
-    let path = if as_template {
-        storage::create_template(&config, &item, category.as_deref())?
+    let path = if args.as_template {
+        storage::create_template(&config, &mut item, category.as_deref())?
     } else {
-        storage::create_item(&config, &item, category.as_deref())?
+        storage::create_item(&config, &mut item, category.as_deref())?
     };
 
     // Process attachments
     if !output.attachments.is_empty() {
-        ui::process_and_save_attachments(&mut item, &path, &output.attachments)?;
+        ui::process_and_save_attachments(&config, &mut item, &path, &output.attachments)?;
     }
 
-    // Open editor for content (unless skip_editor was set via Ctrl+Shift+S)
+    // Open editor for content (unless the body was written in the wizard or
+    // skipped with Ctrl+Alt+S)
     if !output.skip_editor {
         editor::open(&path, &config).context("Failed to open editor")?;
     }
+    ui::print_size_warning(&config, &path);
 
     // Output the path
     println!("{}", config.relative_path(&path).display());
@@ -278,12 +398,13 @@ fn execute_from_template(
         }
     }
 
-    // If no title provided, launch wizard with template data pre-filled
-    if args.title.is_none() {
+    // Without a title, or with -i in a terminal, launch the wizard with the
+    // template data and the command line values pre-filled
+    if args.title.is_none() || wants_wizard(args) {
         if !std::io::stdout().is_terminal() {
             anyhow::bail!("Title is required in non-interactive mode");
         }
-        return execute_wizard_from_template(config, &template, category.as_deref(), &labels);
+        return execute_wizard_from_template(config, &template, args, category.as_deref(), &labels);
     }
 
     let title = args.title.clone().unwrap();
@@ -296,6 +417,13 @@ fn execute_from_template(
     // Get author name
     let author = config.user_name_or_prompt()?;
 
+    // Fill prompts from --var, asking for the rest in a terminal
+    let can_prompt = std::io::stdout().is_terminal() && !args.interactive.no_interactive;
+    let Some(body) = fill_prompts(&template, &template.body, &args.vars, can_prompt)? else {
+        println!("{}", "Cancelled.".dimmed());
+        return Ok(());
+    };
+
     // Generate new ID
     let id = id::generate(config.id_pattern());
 
@@ -307,22 +435,29 @@ fn execute_from_template(
         created_at: Utc::now(),
         status: Status::Open,
         labels,
-        attachments: vec![],
+        extra: template.frontmatter.extra.clone(),
+        ..Frontmatter::default()
     };
 
-    // Create item with template's body content
+    // Create item with template's body content, expanding {{variables}}
     let mut item = Item::new(frontmatter);
-    item.body.clone_from(&template.body);
+    if args.due.is_some() {
+        item.set_due(args.due);
+    }
+    if args.assignee.is_some() {
+        item.set_assignee(assignee(config, args));
+    }
+    item.body = variables::expand(&body, &item, category.as_deref());
 
     // Save to disk
-    let path = storage::create_item(config, &item, category.as_deref())?;
+    let path = storage::create_item(config, &mut item, category.as_deref())?;
 
     // Copy template attachments (files are copied from template dir, URLs are added directly)
-    copy_template_attachments(&template, &mut item, &path)?;
+    copy_template_attachments(config, &template, &mut item, &path)?;
 
     // Process CLI attachments (if any)
     if !args.attachments.is_empty() {
-        ui::process_and_save_attachments(&mut item, &path, &args.attachments)?;
+        ui::process_and_save_attachments(config, &mut item, &path, &args.attachments)?;
     }
 
     // Resolve interactive mode
@@ -332,6 +467,7 @@ fn execute_from_template(
     if interactive {
         editor::open(&path, config).context("Failed to open editor")?;
     }
+    ui::print_size_warning(config, &path);
 
     // Output the path
     println!("{}", config.relative_path(&path).display());
@@ -339,26 +475,40 @@ fn execute_from_template(
     Ok(())
 }
 
-/// Execute wizard flow with template data pre-filled.
+/// Execute wizard flow with template data pre-filled; a title and attachments
+/// given on the command line replace the template's title and are added to its
+/// attachments.
 fn execute_wizard_from_template(
     config: &Config,
     template: &Item,
+    args: &NewArgs,
     category: Option<&str>,
     labels: &[String],
 ) -> Result<()> {
     // Collect existing metadata for autocomplete
-    let (existing_categories, existing_labels) = collect_existing_metadata(config);
+    let metadata = collect_existing_metadata(config);
 
     // Resolve template attachments to full paths for pre-population
     // URLs are kept as-is, file attachments are converted to full paths
-    let template_attachments: Vec<String> = resolve_template_attachments(template);
+    let mut attachments: Vec<String> = resolve_template_attachments(template);
+    attachments.extend(args.attachments.iter().cloned());
 
     // Create pre-populated wizard
-    let wizard = NewItemWizard::new(existing_categories, existing_labels)
-        .with_title(template.title())
-        .with_attachments(template_attachments)
+    let wizard = metadata
+        .wizard()
+        .with_title(args.title.as_deref().unwrap_or_else(|| template.title()))
+        .with_attachments(attachments)
         .with_category(category.map(String::from))
-        .with_labels(labels);
+        .with_labels(labels)
+        .with_due(
+            args.due
+                .or_else(|| template.due())
+                .map(|d| d.to_string())
+                .as_deref(),
+        )
+        .with_priority(template.custom_field(PRIORITY_FIELD))
+        .with_assignee(assignee(config, args).or_else(|| template.assignee()))
+        .with_body(&template.body);
 
     let Some(output) = tui::run(wizard)? else {
         println!("{}", "Cancelled.".dimmed());
@@ -369,6 +519,11 @@ fn execute_wizard_from_template(
     let mut config = Config::load()?;
     let author = config.user_name_or_prompt()?;
 
+    let Some(body) = fill_prompts(template, &output.body, &args.vars, true)? else {
+        println!("{}", "Cancelled.".dimmed());
+        return Ok(());
+    };
+
     // Generate ID
     let id = id::generate(config.id_pattern());
 
@@ -378,30 +533,34 @@ fn execute_wizard_from_template(
     // Create frontmatter
     let frontmatter = Frontmatter {
         id,
-        title: output.title,
+        title: output.title.clone(),
         author,
         created_at: Utc::now(),
         status: Status::Open,
-        labels: output.labels,
-        attachments: vec![],
+        labels: output.labels.clone(),
+        extra: template.frontmatter.extra.clone(),
+        ..Frontmatter::default()
     };
 
-    // Create item with template's body content
+    // Create item with template's body content, expanding {{variables}}
     let mut item = Item::new(frontmatter);
-    item.body.clone_from(&template.body);
+    apply_wizard_fields(&config, &mut item, &output);
+    item.body = variables::expand(&body, &item, category.as_deref());
 
     // Save to disk
-    let path = storage::create_item(&config, &item, category.as_deref())?;
+    let path = storage::create_item(&config, &mut item, category.as_deref())?;
 
     // Process attachments
     if !output.attachments.is_empty() {
-        ui::process_and_save_attachments(&mut item, &path, &output.attachments)?;
+        ui::process_and_save_attachments(&config, &mut item, &path, &output.attachments)?;
     }
 
-    // Open editor for content (unless skip_editor was set via Ctrl+Shift+S)
+    // Open editor for content (unless the body was written in the wizard or
+    // skipped with Ctrl+Alt+S)
     if !output.skip_editor {
         editor::open(&path, &config).context("Failed to open editor")?;
     }
+    ui::print_size_warning(&config, &path);
 
     // Output the path
     println!("{}", config.relative_path(&path).display());
@@ -409,6 +568,39 @@ fn execute_wizard_from_template(
     Ok(())
 }
 
+/// Fills the `{{prompt:...}}` placeholders of a body from the template.
+///
+/// Values come from `--var`; missing ones are asked for when `can_prompt` is
+/// set and are an error otherwise. Returns `None` if a prompt was cancelled.
+fn fill_prompts(
+    template: &Item,
+    body: &str,
+    vars: &[(String, String)],
+    can_prompt: bool,
+) -> Result<Option<String>> {
+    let prompts = variables::prompts(body);
+    let mut answers = variables::match_vars(&prompts, vars)?;
+    let missing: Vec<String> = prompts
+        .into_iter()
+        .filter(|prompt| !answers.contains_key(prompt))
+        .collect();
+    if !missing.is_empty() && !can_prompt {
+        anyhow::bail!(
+            "Missing values for template prompts: {} (pass --var \"<prompt>=<value>\")",
+            missing.join(", ")
+        );
+    }
+
+    let heading = format!("Template: {}", template.title());
+    for prompt in missing {
+        let Some(answer) = tui::screens::prompt_text(&heading, &prompt)? else {
+            return Ok(None);
+        };
+        answers.insert(prompt, answer);
+    }
+    Ok(Some(variables::fill_prompts(body, &answers)))
+}
+
 /// Resolves template attachments to full paths.
 ///
 /// URLs are kept as-is. File attachments are converted to full paths
@@ -443,13 +635,14 @@ fn resolve_template_attachments(template: &Item) -> Vec<String> {
 /// - URL attachments are added directly to the item's frontmatter
 /// - File attachments are copied from the template's directory to the item's directory
 fn copy_template_attachments(
+    config: &Config,
     template: &Item,
     item: &mut Item,
     item_path: &std::path::Path,
 ) -> Result<()> {
-    let template_attachment_dir = template.attachment_dir();
+    let template_attachment_dir = template.attachment_dir(config);
 
-    let item_attachment_dir = storage::attachment_dir_for_item(item_path);
+    let item_attachment_dir = storage::attachment_dir(config, item.id(), item_path);
 
     for attachment in template.attachments() {
         if is_url(attachment) {
@@ -466,7 +659,16 @@ fn copy_template_attachments(
                 let counter = item.next_attachment_counter();
                 let new_filename =
                     storage::copy_attachment(&source_path, &item_attachment_dir, counter)?;
+                let checksum = storage::checksum::sha256_file(&source_path)?;
+                if config.attachment_dedup() {
+                    storage::link_into_store(
+                        config,
+                        &item_attachment_dir.join(&new_filename),
+                        &checksum,
+                    )?;
+                }
                 item.add_attachment(new_filename.clone());
+                item.set_checksum(new_filename.clone(), checksum);
                 println!("  {} {} -> {}", "+".green(), attachment, new_filename);
             } else {
                 eprintln!(
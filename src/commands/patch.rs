@@ -0,0 +1,91 @@
+//! # Patch Command
+//!
+//! Applies field-level patch operations to an item.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+
+use crate::{
+    config::Config,
+    item::patch::{self, PatchOp},
+    policy::{self, Edit},
+    storage, ui,
+};
+
+/// Arguments for the patch command
+pub struct PatchArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+    /// Operations from `--set` flags
+    pub set: Vec<String>,
+    /// Patch file with one operation per line
+    pub patch_file: Option<PathBuf>,
+    /// Patch archived items too
+    pub force: bool,
+}
+
+/// Executes the patch command.
+pub fn execute(args: &PatchArgs) -> Result<()> {
+    let mut ops = Vec::new();
+    if let Some(ref patch_file) = args.patch_file {
+        let content = fs::read_to_string(patch_file)
+            .with_context(|| format!("Failed to read patch file: {}", patch_file.display()))?;
+        ops.extend(PatchOp::parse_lines(&content)?);
+    }
+    for spec in &args.set {
+        ops.push(PatchOp::parse(spec)?);
+    }
+    if ops.is_empty() {
+        bail!("No patch operations specified");
+    }
+
+    let config = Config::load()?;
+
+    // Record assignees under their canonical identity
+    let identities = config.identities();
+    for op in ops.iter_mut().filter(|op| op.field == "assignee") {
+        op.value = identities.canonical(&op.value).to_string();
+    }
+
+    // Resolve item from --id or --file
+    let item_ref = storage::ItemRef::from_options(args.id.clone(), args.file.clone())?;
+    let storage::LoadedItem { mut path, mut item } = item_ref.resolve(&config)?;
+    policy::check_editable(&config, Edit::Patch, &item, &path, args.force)?;
+
+    let old_filename = item.filename();
+    let old_status = item.status();
+    let old_labels = item.labels().to_vec();
+    if !patch::apply(&mut item, &ops)? {
+        println!("{}", "No changes to apply.".dimmed());
+        return Ok(());
+    }
+    if item.labels() != old_labels {
+        policy::check_labels(&config, &mut item, &old_labels)?;
+    }
+
+    item.save(&path)?;
+
+    // Keep the filename in sync with the title
+    let new_filename = item.filename();
+    if old_filename != new_filename {
+        path = storage::rename_item(&path, &new_filename)?;
+    }
+
+    // Move between status directories
+    if item.status() != old_status {
+        let warnings;
+        (path, warnings) = storage::move_to_status(&config, &path, item.status())?;
+        ui::print_warnings(&warnings);
+    }
+
+    storage::vault::sync(&config)?;
+
+    ui::print_success("Patched", &config, &path);
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+//! # Pop Command
+//!
+//! Takes the next item off the queue: picks the oldest (`--fifo`, the
+//! default) or newest (`--lifo`) open item matching the filters, marks it in
+//! progress, and prints it.
+//!
+//! Picking and claiming happen under the project lock, so concurrent `pop`
+//! calls never return the same item.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::Path;
+
+use anyhow::{bail, Result};
+
+use crate::{
+    config::Config,
+    item::{matches_all_labels, record::ItemRecord, Item, Status},
+    storage::{self, lock::ProjectLock, LoadedItem},
+    ui::{self, OutputFormat},
+};
+
+/// End of the queue items are taken from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Order {
+    /// Oldest item first
+    #[default]
+    Fifo,
+    /// Newest item first
+    Lifo,
+}
+
+/// Arguments for the pop command
+pub struct PopArgs {
+    pub order: Order,
+    /// Labels the item must have (all of them)
+    pub labels: Vec<String>,
+    /// Category the item must be in
+    pub category: Option<String>,
+    pub format: OutputFormat,
+}
+
+/// Executes the pop command.
+pub fn execute(args: &PopArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let claimed = claim_next(&config, args.order, |item, path| {
+        matches_all_labels(item.labels(), &args.labels)
+            && args.category.as_ref().map_or(true, |category| {
+                storage::derive_category(&config, path).as_ref() == Some(category)
+            })
+    })?;
+    let Some(LoadedItem { path, item }) = claimed else {
+        bail!("No open items to pop");
+    };
+
+    if args.format == OutputFormat::Json {
+        let record = ItemRecord::at(&config, &item, &path);
+        println!("{}", serde_json::to_string_pretty(&record)?);
+        return Ok(());
+    }
+
+    ui::print_success("Claimed", &config, &path);
+    println!();
+    println!(
+        "{}",
+        ui::format_card(&ui::item_card(&item, &config).without_summary())
+    );
+    let body = item.body.trim();
+    if !body.is_empty() {
+        println!("\n{body}");
+    }
+    Ok(())
+}
+
+/// Claims the next open item accepted by `filter` and marks it in progress.
+///
+/// Items are ordered by creation time (then ID). Returns `None` when no open
+/// item matches. Holds the project lock while picking and saving, so no two
+/// callers claim the same item.
+pub fn claim_next(
+    config: &Config,
+    order: Order,
+    filter: impl Fn(&Item, &Path) -> bool,
+) -> Result<Option<LoadedItem>> {
+    let _lock = ProjectLock::acquire(config)?;
+
+    let mut candidates: Vec<LoadedItem> = storage::walk_items(config)
+        .filter_map(|path| {
+            let item = Item::load(&path).ok()?;
+            (item.status() == Status::Open && filter(&item, &path))
+                .then_some(LoadedItem { path, item })
+        })
+        .collect();
+    candidates.sort_by(|a, b| {
+        (a.item.created_at(), a.item.id()).cmp(&(b.item.created_at(), b.item.id()))
+    });
+
+    let next = match order {
+        Order::Fifo => candidates.into_iter().next(),
+        Order::Lifo => candidates.pop(),
+    };
+    let Some(mut next) = next else {
+        return Ok(None);
+    };
+    next.item.set_status(Status::InProgress);
+    next.item.save(&next.path)?;
+    let (path, warnings) = storage::move_to_status(config, &next.path, Status::InProgress)?;
+    ui::print_warnings(&warnings);
+    next.path = path;
+    Ok(Some(next))
+}
@@ -0,0 +1,46 @@
+//! # Resolve Command
+//!
+//! Finds the item a link from `qs url` points to. Prints its path, and opens
+//! it in the editor in interactive mode.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use anyhow::{bail, Result};
+
+use crate::{
+    config::Config,
+    item::Item,
+    permalink, storage,
+    ui::{self, InteractiveArgs},
+};
+
+/// Arguments for the resolve command
+pub struct ResolveArgs {
+    /// Link to resolve
+    pub url: String,
+    pub interactive: InteractiveArgs,
+}
+
+/// Executes the resolve command.
+pub fn execute(args: &ResolveArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let items = storage::load_all_items(&config);
+    let ids: Vec<&str> = items.iter().map(Item::id).collect();
+    let Some(id) = permalink::find(&args.url, &ids) else {
+        bail!("No item found for link: {}", args.url);
+    };
+    let Some(item) = items.iter().find(|item| item.id() == id) else {
+        bail!("No item found for link: {}", args.url);
+    };
+
+    if args.interactive.should_run(&config) {
+        ui::open_item_in_editor(item, &config)
+    } else {
+        if let Some(ref path) = item.path {
+            println!("{}", config.relative_path(path).display());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+//! # Rules Command
+//!
+//! Applies the configured auto-labeling rules to existing items. Archived
+//! items are left alone: `--all` skips them, and a single archived item
+//! needs `--force`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use crate::{
+    config::Config,
+    item::{rules, Item},
+    policy::{self, Edit},
+    storage,
+};
+
+/// Arguments for the rules apply subcommand
+pub struct RulesApplyArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+    /// Apply to every open item
+    pub all: bool,
+    /// Apply to archived items too
+    pub force: bool,
+}
+
+/// Executes the rules apply subcommand.
+pub fn execute_apply(args: &RulesApplyArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    if config.rules().is_empty() {
+        bail!("No rules configured. Add [[rules]] to .queuestack or the global config");
+    }
+
+    let targets: Vec<PathBuf> = if args.all {
+        storage::walk_items(&config).collect()
+    } else {
+        let item_ref = storage::ItemRef::from_options(args.id.clone(), args.file.clone())?;
+        vec![item_ref.resolve(&config)?.path]
+    };
+
+    let mut updated = 0;
+    for path in targets {
+        let Ok(mut item) = Item::load(&path) else {
+            continue;
+        };
+        if let Err(e) = policy::check_editable(&config, Edit::Rules, &item, &path, args.force) {
+            if args.all {
+                continue;
+            }
+            return Err(e);
+        }
+        let labels = item.labels().to_vec();
+        let changes = rules::apply(config.rules(), &mut item)?;
+        if changes.is_empty() {
+            continue;
+        }
+        policy::check_labels(&config, &mut item, &labels)?;
+
+        item.save(&path)?;
+        updated += 1;
+        println!(
+            "  {} {} {}",
+            "+".green(),
+            item.id(),
+            changes.join(", ").dimmed()
+        );
+    }
+
+    if updated == 0 {
+        println!("{}", "No changes to apply.".dimmed());
+        return Ok(());
+    }
+
+    storage::vault::sync(&config)?;
+
+    println!("\n{} Applied rules to {} item(s)", "✓".green(), updated);
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+//! # Run Command
+//!
+//! Runs one of an item's own actions: shell commands declared under `actions`
+//! in its frontmatter. Without an action name, lists the available actions.
+//!
+//! Actions run through the platform shell from the project root, with the item
+//! in `QS_ID` and `QS_FILE` in addition to the `{id}` and `{path}` placeholders.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{
+    config::Config,
+    item::{action, Item},
+    storage,
+};
+
+/// Arguments for the run command
+pub struct RunArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+    /// Action to run; lists the actions when omitted
+    pub action: Option<String>,
+}
+
+/// Executes the run command.
+pub fn execute(args: &RunArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let item_ref = storage::ItemRef::from_options(args.id.clone(), args.file.clone())?;
+    let storage::LoadedItem { path, item } = item_ref.resolve(&config)?;
+
+    let Some(ref name) = args.action else {
+        for (name, command) in item.actions() {
+            println!("{name}: {command}");
+        }
+        return Ok(());
+    };
+
+    run_action(&config, &item, &path, name)
+}
+
+/// Runs the item's action `name`, failing if it exits non-zero.
+pub fn run_action(config: &Config, item: &Item, path: &Path, name: &str) -> Result<()> {
+    let Some(command) = item.actions().get(name) else {
+        if item.actions().is_empty() {
+            bail!("Item '{}' has no actions", item.id());
+        }
+        let names: Vec<&str> = item.actions().keys().map(String::as_str).collect();
+        bail!(
+            "Item '{}' has no action '{name}' (available: {})",
+            item.id(),
+            names.join(", ")
+        );
+    };
+
+    let command = action::expand(command, item.id(), path);
+    let status = action::shell(&command)
+        .current_dir(config.project_root())
+        .env("QS_ID", item.id())
+        .env("QS_FILE", path)
+        .status()
+        .with_context(|| format!("Failed to run action '{name}': {command}"))?;
+
+    if !status.success() {
+        bail!("Action '{name}' failed ({status})");
+    }
+    Ok(())
+}
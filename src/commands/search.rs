@@ -2,6 +2,9 @@
 //!
 //! Search for items and interactively select one to open.
 //!
+//! Results are ranked by match quality (title, then ID, then body), boosted
+//! by recency, and the matched text is highlighted in colored output.
+//!
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
@@ -9,16 +12,39 @@ use std::io::IsTerminal;
 
 use anyhow::Result;
 
-use super::list::{collect_items, sort_items, SortBy};
+use super::list::{collect_in_scope, Scope};
 use crate::item::FilterCriteria;
-use crate::{config::Config, item::search::matches_query, ui, ui::InteractiveArgs};
+use crate::{
+    config::Config,
+    item::{record::ItemRecord, search::rank_matches_within, Item},
+    storage::{self, mirror::Mirror},
+    ui::{self, InteractiveArgs, OutputFormat, Selection},
+};
 
 /// Arguments for the search command
+#[allow(clippy::struct_excessive_bools)] // Each flag is an independent CLI option
 pub struct SearchArgs {
     pub query: String,
     pub full_text: bool,
+    /// Search bodies over `max_body_words` too (with `full_text`)
+    pub include_large: bool,
     pub interactive: InteractiveArgs,
-    pub closed: bool,
+    /// Which items are searched: open, archived, templates, or all
+    pub scope: Scope,
+    /// Labels the item must all have
+    pub labels: Vec<String>,
+    /// Labels of which the item must have at least one
+    pub any_labels: Vec<String>,
+    /// Labels the item must not have
+    pub not_labels: Vec<String>,
+    /// Print summary cards instead of paths (implies listing)
+    pub verbose: bool,
+    /// Include items of the configured read-only mirrors when listing
+    pub mirrors: bool,
+    /// Pick a match on the terminal and print it instead of opening it
+    pub print_selection: Option<Selection>,
+    /// Print the matches as JSON, best first (implies listing)
+    pub format: OutputFormat,
 }
 
 /// Executes the search command.
@@ -30,27 +56,95 @@ pub fn execute(args: &SearchArgs) -> Result<()> {
 
     let config = Config::load()?;
 
-    // Collect all items (no pre-filtering, search applied after)
-    let mut items = collect_items(&config, args.closed, &FilterCriteria::default());
+    // Collect label-filtered items (search applied after)
+    let filter = FilterCriteria {
+        labels: args.labels.clone(),
+        any_labels: args.any_labels.clone(),
+        not_labels: args.not_labels.clone(),
+        ..FilterCriteria::default()
+    };
+    let mut items = collect_in_scope(&config, args.scope, &filter);
 
-    // Filter by search query
-    items.retain(|item| matches_query(item, &args.query, args.full_text));
+    // Resolve interactive mode (without terminal check - handled separately)
+    let interactive = args.print_selection.is_some()
+        || (!args.verbose
+            && args.format == OutputFormat::Text
+            && args.interactive.is_enabled(&config));
+
+    // Mirrored items are listed, but never offered for opening
+    let mut mirrors = Vec::new();
+    if args.mirrors && !interactive {
+        let mut warnings = Vec::new();
+        for &archived in args.scope.mirrored() {
+            let (loaded, skipped) = storage::mirror::load_all(&config, archived, &filter)?;
+            warnings.extend(skipped);
+            for (mirror, mirrored) in loaded {
+                items.extend(mirrored);
+                if !mirrors
+                    .iter()
+                    .any(|known: &Mirror| known.name == mirror.name)
+                {
+                    mirrors.push(mirror);
+                }
+            }
+        }
+        warnings.dedup();
+        ui::print_warnings(&warnings);
+    }
 
-    // Sort by ID for consistent ordering
-    sort_items(&mut items, SortBy::Id);
+    // Keep matches, best first; oversized bodies only when asked for
+    let max_body_words = if args.include_large {
+        None
+    } else {
+        config.max_body_words()
+    };
+    let items = rank_matches_within(items, &args.query, args.full_text, max_body_words);
 
     if items.is_empty() {
         anyhow::bail!("No items found matching \"{}\"", args.query);
     }
 
-    // Resolve interactive mode (without terminal check - handled separately)
-    let interactive = args.interactive.is_enabled(&config);
+    // Scripted selection: a single match is printed right away
+    if let Some(selection) = args.print_selection {
+        if items.len() > 1 && config.picker().is_builtin() {
+            crate::tui::terminal::draw_on_tty()?;
+        }
+        let index = if items.len() == 1 {
+            0
+        } else if let Some(index) = ui::select_item("Select an item", &items, &config)? {
+            index
+        } else {
+            anyhow::bail!("Selection cancelled");
+        };
+        return ui::print_selection(&items[index], &config, selection);
+    }
+
+    if args.format == OutputFormat::Json {
+        return print_json(&config, &items, &mirrors);
+    }
+
+    if args.verbose {
+        let cards: Vec<String> = items
+            .iter()
+            .map(|item| ui::format_card(&ui::item_card(item, &config).highlight(&args.query)))
+            .collect();
+        println!("{}", cards.join("\n\n"));
+        return Ok(());
+    }
 
     // Non-interactive mode: just print the list
     if !interactive {
         for item in &items {
             if let Some(ref path) = item.path {
-                println!("{}", config.relative_path(path).display());
+                let mirror = item
+                    .source
+                    .as_ref()
+                    .and_then(|source| mirrors.iter().find(|mirror| &mirror.name == source));
+                let path = mirror.map_or_else(
+                    || config.relative_path(path).display().to_string(),
+                    |mirror| mirror.display_path(path),
+                );
+                println!("{}", ui::highlight(&path, &args.query));
             }
         }
         return Ok(());
@@ -77,3 +171,24 @@ pub fn execute(args: &SearchArgs) -> Result<()> {
 
     Ok(())
 }
+
+/// Prints the matches as a JSON array in rank order; mirrored items get their
+/// category and path from the mirror's project.
+fn print_json(config: &Config, items: &[Item], mirrors: &[Mirror]) -> Result<()> {
+    let records: Vec<ItemRecord> = items
+        .iter()
+        .filter_map(|item| {
+            let path = item.path.as_ref()?;
+            let mirror = item
+                .source
+                .as_ref()
+                .and_then(|source| mirrors.iter().find(|mirror| &mirror.name == source));
+            Some(mirror.map_or_else(
+                || ItemRecord::at(config, item, path),
+                |mirror| ItemRecord::at(&Config::mirror(mirror.root.clone()), item, path),
+            ))
+        })
+        .collect();
+    println!("{}", serde_json::to_string_pretty(&records)?);
+    Ok(())
+}
@@ -1,6 +1,8 @@
 //! # Setup Command
 //!
-//! One-time setup for queuestack: creates global config and installs shell completions.
+//! One-time setup for queuestack: creates global config and installs shell completions,
+//! for one shell or every shell found on the PATH, per user or system-wide.
+//! `--print-paths` lists the paths without writing, for package install scripts.
 //!
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
@@ -8,13 +10,13 @@
 use std::{
     env,
     fs::{self, File},
-    io::Write,
+    io::{self, Write},
     path::{Path, PathBuf},
 };
 
 use crate::ui::select_from_list;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use clap_complete::Shell;
 use owo_colors::OwoColorize;
 
@@ -24,32 +26,91 @@ use crate::{
     config::GlobalConfig,
     constants::{
         BASHRC_FILE, BASH_COMPLETIONS_DIR, BASH_COMPLETION_FILE, BASH_PROFILE_FILE,
-        ELVISH_COMPLETIONS_DIR, ELVISH_COMPLETION_FILE, FISH_COMPLETIONS_DIR, FISH_COMPLETION_FILE,
-        POWERSHELL_CONFIG_DIR_UNIX, POWERSHELL_CONFIG_DIR_WINDOWS, POWERSHELL_PROFILE_FILE,
-        ZSHRC_FILE, ZSH_COMPLETIONS_DIR, ZSH_COMPLETION_FILE,
+        BASH_SYSTEM_COMPLETIONS_DIR, ELVISH_COMPLETIONS_DIR, ELVISH_COMPLETION_FILE,
+        ELVISH_SYSTEM_COMPLETIONS_DIR, FISH_COMPLETIONS_DIR, FISH_COMPLETION_FILE,
+        FISH_SYSTEM_COMPLETIONS_DIR, POWERSHELL_CONFIG_DIR_UNIX, POWERSHELL_CONFIG_DIR_WINDOWS,
+        POWERSHELL_PROFILE_FILE, ZSHRC_FILE, ZSH_COMPLETIONS_DIR, ZSH_COMPLETION_FILE,
+        ZSH_SYSTEM_COMPLETIONS_DIR,
     },
 };
 
 use super::completions::generate_to_string;
 
+/// Shell selection for `qs setup --shell`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum SetupShell {
+    Zsh,
+    Bash,
+    Fish,
+    Elvish,
+    Powershell,
+    /// Every supported shell found on the PATH
+    All,
+}
+
+impl SetupShell {
+    /// Returns the single shell, or `None` for `all`.
+    const fn shell(self) -> Option<Shell> {
+        match self {
+            Self::Zsh => Some(Shell::Zsh),
+            Self::Bash => Some(Shell::Bash),
+            Self::Fish => Some(Shell::Fish),
+            Self::Elvish => Some(Shell::Elvish),
+            Self::Powershell => Some(Shell::PowerShell),
+            Self::All => None,
+        }
+    }
+}
+
 /// Executes the setup command.
 ///
 /// The `cmd` parameter should be a clone of the CLI command for generating completions.
 /// The `shell_override` parameter allows explicit shell specification, bypassing detection.
-pub fn execute(cmd: &mut Command, shell_override: Option<Shell>) -> Result<()> {
+/// With `system`, completions go to system-wide locations.
+pub fn execute(cmd: &mut Command, shell_override: Option<SetupShell>, system: bool) -> Result<()> {
+    if system && cfg!(windows) {
+        bail!("--system is not supported on Windows");
+    }
+
     eprintln!("{}\n", "Setting up queuestack...".bold());
 
     // Step 1: Ensure global config exists
     setup_global_config()?;
 
-    // Step 2: Install shell completions for detected shell
-    setup_completions(cmd, shell_override)?;
+    // Step 2: Install shell completions for the selected or detected shells
+    setup_completions(cmd, shell_override, system)?;
 
     eprintln!("\n{} Setup complete!", "✓".green().bold());
 
     Ok(())
 }
 
+/// Prints the paths setup uses as `name<TAB>path` lines, without writing
+/// anything: `config` for the global config, then one line per shell with an
+/// install location (all shells unless one is given).
+pub fn print_paths(shell_override: Option<SetupShell>, system: bool) -> Result<()> {
+    let config = GlobalConfig::path()
+        .ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
+    println!("config\t{}", config.display());
+
+    let shells = shell_override.and_then(SetupShell::shell).map_or_else(
+        || vec![Shell::Zsh, Shell::Bash, Shell::Fish, Shell::Elvish],
+        |shell| vec![shell],
+    );
+    for shell in shells {
+        let path = if system {
+            get_system_completion_path(shell)
+        } else {
+            get_completion_path(shell)
+        };
+        if let Some(path) = path {
+            println!("{}\t{}", shell_name(shell), path.display());
+        }
+    }
+
+    Ok(())
+}
+
 /// Creates or updates the global config file
 fn setup_global_config() -> Result<()> {
     let path = GlobalConfig::path()
@@ -130,6 +191,47 @@ fn prompt_shell_selection() -> Result<Option<Shell>> {
     }))
 }
 
+/// Returns the supported shells found on the PATH.
+fn detect_installed_shells() -> Vec<Shell> {
+    let candidates: [(Shell, &[&str]); 5] = [
+        (Shell::Zsh, &["zsh"]),
+        (Shell::Bash, &["bash"]),
+        (Shell::Fish, &["fish"]),
+        (Shell::Elvish, &["elvish"]),
+        (Shell::PowerShell, &["pwsh", "powershell"]),
+    ];
+    candidates
+        .into_iter()
+        .filter(|(_, programs)| programs.iter().any(|p| is_on_path(p)))
+        .map(|(shell, _)| shell)
+        .collect()
+}
+
+/// Checks if a program exists in one of the PATH directories.
+fn is_on_path(program: &str) -> bool {
+    let Some(path) = env::var_os("PATH") else {
+        return false;
+    };
+    env::split_paths(&path).any(|dir| {
+        dir.join(program).is_file()
+            || (cfg!(windows) && dir.join(format!("{program}.exe")).is_file())
+    })
+}
+
+/// Returns the system-wide completion file path for each shell
+fn get_system_completion_path(shell: Shell) -> Option<PathBuf> {
+    match shell {
+        Shell::Zsh => Some(Path::new(ZSH_SYSTEM_COMPLETIONS_DIR).join(ZSH_COMPLETION_FILE)),
+        Shell::Bash => Some(Path::new(BASH_SYSTEM_COMPLETIONS_DIR).join(BASH_COMPLETION_FILE)),
+        Shell::Fish => Some(Path::new(FISH_SYSTEM_COMPLETIONS_DIR).join(FISH_COMPLETION_FILE)),
+        Shell::Elvish => {
+            Some(Path::new(ELVISH_SYSTEM_COMPLETIONS_DIR).join(ELVISH_COMPLETION_FILE))
+        }
+        // PowerShell doesn't have a standard auto-load directory
+        _ => None,
+    }
+}
+
 /// Returns the completion file path for each shell
 fn get_completion_path(shell: Shell) -> Option<PathBuf> {
     let home = dirs::home_dir()?;
@@ -179,58 +281,129 @@ fn get_rc_file_path(shell: Shell) -> Option<PathBuf> {
     }
 }
 
-/// Sets up shell completions for the specified or detected shell.
-fn setup_completions(cmd: &mut Command, shell_override: Option<Shell>) -> Result<()> {
-    let shell = if let Some(s) = shell_override {
-        s
-    } else {
-        let Some(s) = detect_or_prompt_shell()? else {
-            return Ok(()); // User cancelled
-        };
-        s
+/// Sets up shell completions for the specified, detected, or all installed shells.
+fn setup_completions(
+    cmd: &mut Command,
+    shell_override: Option<SetupShell>,
+    system: bool,
+) -> Result<()> {
+    let shells = match shell_override.map(SetupShell::shell) {
+        Some(Some(shell)) => vec![shell],
+        Some(None) => {
+            let shells = detect_installed_shells();
+            if shells.is_empty() {
+                eprintln!("{} No supported shells found on the PATH", "!".yellow());
+                return Ok(());
+            }
+            shells
+        }
+        None => {
+            let Some(shell) = detect_or_prompt_shell()? else {
+                return Ok(()); // User cancelled
+            };
+            vec![shell]
+        }
     };
 
+    let mut installed = Vec::new();
+    for shell in shells {
+        if let Some(path) = install_completions(cmd, shell, system)? {
+            installed.push((shell, path));
+        }
+    }
+
+    // Summarize what went where when installing for several shells
+    if installed.len() > 1 {
+        eprintln!("\n{}", "Installed completions:".bold());
+        for (shell, path) in &installed {
+            eprintln!("  {:<10} {}", shell_name(*shell), path.display());
+        }
+    }
+
+    Ok(())
+}
+
+/// Installs completions for one shell; returns where they were written, or
+/// `None` for shells that need manual setup.
+///
+/// A system-wide install without permission falls back to the user location.
+fn install_completions(cmd: &mut Command, shell: Shell, system: bool) -> Result<Option<PathBuf>> {
     // Generate completions
     let completions = generate_to_string(shell, cmd);
 
     // Get installation path
-    let Some(install_path) = get_completion_path(shell) else {
+    let path = if system {
+        get_system_completion_path(shell)
+    } else {
+        get_completion_path(shell)
+    };
+    let Some(mut install_path) = path else {
         // No auto-install path, just print instructions
         print_manual_instructions(shell);
-        return Ok(());
+        return Ok(None);
     };
 
-    // Create parent directories
-    if let Some(parent) = install_path.parent() {
-        fs::create_dir_all(parent)
-            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    let mut system = system;
+    match write_completions(&install_path, &completions) {
+        Ok(()) => {}
+        Err(e) if system && e.kind() == io::ErrorKind::PermissionDenied => {
+            eprintln!(
+                "{} No permission to write {} (run 'sudo qs setup --system' to install \
+                 system-wide); installing for the current user instead",
+                "!".yellow(),
+                install_path.display()
+            );
+            install_path = get_completion_path(shell)
+                .ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
+            system = false;
+            write_completions(&install_path, &completions).with_context(|| {
+                format!("Failed to write completions: {}", install_path.display())
+            })?;
+        }
+        Err(e) => {
+            return Err(e).with_context(|| {
+                format!("Failed to write completions: {}", install_path.display())
+            })
+        }
     }
 
-    // Write completions file
-    let mut file = File::create(&install_path).with_context(|| {
-        format!(
-            "Failed to create completions file: {}",
-            install_path.display()
-        )
-    })?;
-    file.write_all(completions.as_bytes())
-        .with_context(|| format!("Failed to write completions: {}", install_path.display()))?;
-
     eprintln!(
         "{} Installed {} completions: {}",
         "✓".green(),
-        format!("{shell:?}").to_lowercase(),
+        shell_name(shell),
         install_path.display()
     );
 
     // Print any additional setup instructions
-    print_activation_instructions(shell, &install_path);
+    print_activation_instructions(shell, &install_path, system);
 
-    Ok(())
+    Ok(Some(install_path))
+}
+
+/// Writes a completions file, creating its parent directories.
+fn write_completions(path: &Path, completions: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    File::create(path)?.write_all(completions.as_bytes())
+}
+
+/// Returns the lowercase shell name used in messages.
+fn shell_name(shell: Shell) -> String {
+    format!("{shell:?}").to_lowercase()
 }
 
 /// Prints instructions for activating completions
-fn print_activation_instructions(shell: Shell, install_path: &Path) {
+fn print_activation_instructions(shell: Shell, install_path: &Path, system: bool) {
+    // System-wide locations are on the shells' default search paths
+    if system && shell != Shell::Elvish {
+        eprintln!(
+            "  {} Completions will be loaded automatically on next shell start.",
+            "→".cyan(),
+        );
+        return;
+    }
+
     match shell {
         Shell::Zsh => {
             let rc_path = get_rc_file_path(shell);
@@ -269,7 +442,7 @@ fn print_activation_instructions(shell: Shell, install_path: &Path) {
             );
         }
         Shell::Elvish => {
-            eprintln!("\n  {} Add to ~/.config/elvish/rc.elv:", "→".cyan(),);
+            eprintln!("\n  {} Add to ~/.config/elvish/rc.elv:", "→".cyan());
             eprintln!("    {}", "use qs".dimmed());
         }
         _ => {}
@@ -0,0 +1,95 @@
+//! # Show Command
+//!
+//! Prints a single item: its card followed by the body, only the card with
+//! `--brief`, the file as it is on disk with `--raw`, or all fields and the
+//! body as JSON with `--format json`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{collections::BTreeMap, path::PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::{
+    config::Config,
+    item::{record::ItemRecord, Item},
+    storage,
+    ui::{self, OutputFormat},
+};
+
+/// Arguments for the show command
+pub struct ShowArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+    /// Print only the compact card
+    pub brief: bool,
+    /// Print the file content unchanged
+    pub raw: bool,
+    pub format: OutputFormat,
+}
+
+/// Full item for JSON output
+#[derive(Debug, Serialize)]
+struct ShowRecord {
+    #[serde(flatten)]
+    record: ItemRecord,
+    /// Custom frontmatter fields
+    fields: BTreeMap<String, serde_yml::Value>,
+    /// Frontmatter mistakes fixed while loading
+    fixes: Vec<String>,
+    body: String,
+}
+
+impl ShowRecord {
+    fn new(config: &Config, path: &std::path::Path, item: Item) -> Self {
+        Self {
+            record: ItemRecord::at(config, &item, path),
+            fields: item.frontmatter.extra,
+            fixes: item.fixes,
+            body: item.body,
+        }
+    }
+}
+
+/// Executes the show command.
+pub fn execute(args: &ShowArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let item_ref = storage::ItemRef::from_options(args.id.clone(), args.file.clone())?;
+    let storage::LoadedItem { item, path } = item_ref.resolve_read_only(&config)?;
+
+    if args.raw {
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read item: {}", path.display()))?;
+        print!("{content}");
+        return Ok(());
+    }
+    if args.format == OutputFormat::Json {
+        let record = ShowRecord::new(&config, &path, item);
+        println!("{}", serde_json::to_string_pretty(&record)?);
+        return Ok(());
+    }
+
+    let fixes: Vec<String> = item
+        .fixes
+        .iter()
+        .map(|fix| format!("Fixed frontmatter: {fix} (run qs update to save)"))
+        .collect();
+    ui::print_warnings(&fixes);
+
+    let card = ui::item_card(&item, &config);
+    if args.brief {
+        println!("{}", ui::format_card(&card));
+        return Ok(());
+    }
+
+    println!("{}", ui::format_card(&card.without_summary()));
+    let body = item.body.trim();
+    if !body.is_empty() {
+        println!("\n{body}");
+    }
+
+    Ok(())
+}
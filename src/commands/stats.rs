@@ -0,0 +1,413 @@
+//! # Stats Command
+//!
+//! Summarizes the queue: item counts by status and first-response time, or
+//! with `--author` a per-author breakdown of created, closed, and assigned
+//! items and cycle time.
+//!
+//! First response is the time from an item's creation to the first commit that
+//! touches it by someone other than its author, taken from git history. Items
+//! are matched by ID, so renames, category moves, and archiving are followed.
+//! Commit authors are unified with item authors through the configured identities.
+//!
+//! A closed item counts for its assignee, or for its author if it was never
+//! assigned; cycle time is the time from creation to `closed_at`.
+//!
+//! `--format markdown` prints the same numbers as Markdown tables with mermaid
+//! pie charts, ready to paste into a README, wiki page, or pull request.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{collections::BTreeMap, fmt::Write as _, path::Path};
+
+use anyhow::Result;
+use chrono::TimeDelta;
+use serde::Serialize;
+
+use crate::{
+    config::Config,
+    item::{identity::Identities, Item, Status},
+    storage::{self, vcs},
+    ui::pad_to_width,
+};
+
+/// Stats output format
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum StatsFormat {
+    /// Plain text
+    #[default]
+    Text,
+    /// JSON
+    Json,
+    /// Markdown tables and mermaid charts
+    Markdown,
+}
+
+/// Arguments for the stats command
+pub struct StatsArgs {
+    /// Break the numbers down by author
+    pub by_author: bool,
+    pub format: StatsFormat,
+}
+
+/// Queue summary
+#[derive(Debug, Serialize)]
+struct Summary {
+    open: usize,
+    in_progress: usize,
+    closed: usize,
+    /// `None` without git history
+    first_response: Option<FirstResponse>,
+}
+
+/// First-response metrics
+#[derive(Debug, Serialize)]
+struct FirstResponse {
+    responded: usize,
+    total: usize,
+    median_minutes: Option<i64>,
+    average_minutes: Option<i64>,
+    /// Active items nobody but their author has touched yet
+    awaiting: usize,
+}
+
+/// One author's row of the `--author` breakdown
+#[derive(Debug, Serialize)]
+struct AuthorStats {
+    author: String,
+    /// Items the author created
+    created: usize,
+    /// Closed items assigned to the author (or created by them, if unassigned)
+    closed: usize,
+    /// Open and in-progress items assigned to the author
+    assigned: usize,
+    /// Average time from creation to close of the closed items
+    average_cycle_minutes: Option<i64>,
+    #[serde(skip)]
+    cycle_times: Vec<TimeDelta>,
+}
+
+/// Executes the stats command.
+pub fn execute(args: &StatsArgs) -> Result<()> {
+    let config = Config::load()?;
+    let items = storage::load_all_items(&config);
+
+    if args.by_author {
+        let authors = author_stats(&items, &config.identities());
+        match args.format {
+            StatsFormat::Text => print_authors(&authors),
+            StatsFormat::Json => println!("{}", serde_json::to_string_pretty(&authors)?),
+            StatsFormat::Markdown => print!("{}", markdown_authors(&authors)),
+        }
+        return Ok(());
+    }
+
+    let history = vcs::history(&config.stack_path())?;
+    let count = |status: Status| items.iter().filter(|i| i.status() == status).count();
+    let summary = Summary {
+        open: count(Status::Open),
+        in_progress: count(Status::InProgress),
+        closed: count(Status::Closed),
+        first_response: (!history.is_empty())
+            .then(|| first_responses(&items, &history, &config.identities())),
+    };
+    match args.format {
+        StatsFormat::Text => print_summary(&summary),
+        StatsFormat::Json => println!("{}", serde_json::to_string_pretty(&summary)?),
+        StatsFormat::Markdown => print!("{}", markdown_summary(&summary)),
+    }
+    Ok(())
+}
+
+/// Prints the queue summary as text.
+fn print_summary(summary: &Summary) {
+    println!(
+        "Items: {} open, {} in progress, {} closed",
+        summary.open, summary.in_progress, summary.closed
+    );
+
+    let Some(ref response) = summary.first_response else {
+        println!("First response: no git history");
+        return;
+    };
+    println!("First response:");
+    println!(
+        "  Responded: {} of {} items",
+        response.responded, response.total
+    );
+    if let (Some(median), Some(average)) = (response.median_minutes, response.average_minutes) {
+        println!(
+            "  Median:    {}",
+            format_duration(TimeDelta::minutes(median))
+        );
+        println!(
+            "  Average:   {}",
+            format_duration(TimeDelta::minutes(average))
+        );
+    }
+    println!("  Awaiting:  {} open item(s)", response.awaiting);
+}
+
+/// Renders the queue summary as Markdown: a status table and pie chart, then
+/// the first-response metrics.
+fn markdown_summary(summary: &Summary) -> String {
+    let statuses = [
+        ("Open", summary.open),
+        ("In progress", summary.in_progress),
+        ("Closed", summary.closed),
+    ];
+    let mut out = String::from("## Items\n\n| Status | Items |\n| --- | ---: |\n");
+    for (status, count) in statuses {
+        let _ = writeln!(out, "| {status} | {count} |");
+    }
+    out.push_str(&mermaid_pie("Items by status", &statuses));
+
+    out.push_str("\n## First response\n\n");
+    let Some(ref response) = summary.first_response else {
+        out.push_str("No git history.\n");
+        return out;
+    };
+    out.push_str("| Metric | Value |\n| --- | --- |\n");
+    let _ = writeln!(
+        out,
+        "| Responded | {} of {} items |",
+        response.responded, response.total
+    );
+    if let (Some(median), Some(average)) = (response.median_minutes, response.average_minutes) {
+        let _ = writeln!(
+            out,
+            "| Median | {} |",
+            format_duration(TimeDelta::minutes(median))
+        );
+        let _ = writeln!(
+            out,
+            "| Average | {} |",
+            format_duration(TimeDelta::minutes(average))
+        );
+    }
+    let _ = writeln!(out, "| Awaiting | {} open item(s) |", response.awaiting);
+    out
+}
+
+/// Computes first-response metrics from git history.
+fn first_responses(
+    items: &[Item],
+    history: &[vcs::Commit],
+    identities: &Identities,
+) -> FirstResponse {
+    let mut response_times = Vec::new();
+    let mut awaiting = 0;
+    for item in items {
+        match first_response(item, history, identities) {
+            Some(delta) => response_times.push(delta),
+            None if item.status().is_active() => awaiting += 1,
+            None => {}
+        }
+    }
+    response_times.sort();
+
+    FirstResponse {
+        responded: response_times.len(),
+        total: items.len(),
+        median_minutes: response_times
+            .get(response_times.len() / 2)
+            .map(TimeDelta::num_minutes),
+        average_minutes: average(&response_times).map(|delta| delta.num_minutes()),
+        awaiting,
+    }
+}
+
+/// Builds the per-author breakdown, busiest first (most assigned, then most
+/// created, then by name).
+///
+/// Author and assignee spellings are unified through the configured identities.
+fn author_stats(items: &[Item], identities: &Identities) -> Vec<AuthorStats> {
+    let mut authors: BTreeMap<String, AuthorStats> = BTreeMap::new();
+    for item in items
+        .iter()
+        .filter(|item| item.status() != Status::Template)
+    {
+        author_entry(&mut authors, identities, item.author()).created += 1;
+
+        let assignee = item.assignee();
+        if item.status().is_active() {
+            if let Some(ref assignee) = assignee {
+                author_entry(&mut authors, identities, assignee).assigned += 1;
+            }
+            continue;
+        }
+        let owner = author_entry(
+            &mut authors,
+            identities,
+            assignee.as_deref().unwrap_or_else(|| item.author()),
+        );
+        owner.closed += 1;
+        if let Some(closed_at) = item.closed_at() {
+            owner.cycle_times.push(closed_at - item.created_at());
+        }
+    }
+
+    let mut authors: Vec<AuthorStats> = authors
+        .into_values()
+        .filter(|stats| !stats.author.is_empty())
+        .map(|mut stats| {
+            stats.average_cycle_minutes =
+                average(&stats.cycle_times).map(|delta| delta.num_minutes());
+            stats
+        })
+        .collect();
+    authors.sort_by(|a, b| {
+        b.assigned
+            .cmp(&a.assigned)
+            .then_with(|| b.created.cmp(&a.created))
+            .then_with(|| a.author.to_lowercase().cmp(&b.author.to_lowercase()))
+    });
+    authors
+}
+
+/// Returns the row of an author (by canonical name), adding it if needed.
+fn author_entry<'a>(
+    authors: &'a mut BTreeMap<String, AuthorStats>,
+    identities: &Identities,
+    name: &str,
+) -> &'a mut AuthorStats {
+    let name = identities.canonical(name).to_string();
+    authors
+        .entry(name.to_lowercase())
+        .or_insert_with(|| AuthorStats {
+            author: name,
+            created: 0,
+            closed: 0,
+            assigned: 0,
+            average_cycle_minutes: None,
+            cycle_times: Vec::new(),
+        })
+}
+
+/// Prints the per-author breakdown as an aligned table.
+fn print_authors(authors: &[AuthorStats]) {
+    if authors.is_empty() {
+        println!("No items found.");
+        return;
+    }
+    let width = authors
+        .iter()
+        .map(|stats| unicode_width::UnicodeWidthStr::width(stats.author.as_str()))
+        .max()
+        .unwrap_or(0)
+        .max("Author".len());
+    println!(
+        "{}  Created  Closed  Assigned  Avg cycle",
+        pad_to_width("Author", width)
+    );
+    for stats in authors {
+        let cycle = stats.average_cycle_minutes.map_or_else(
+            || "-".to_string(),
+            |m| format_duration(TimeDelta::minutes(m)),
+        );
+        println!(
+            "{}  {:>7}  {:>6}  {:>8}  {cycle}",
+            pad_to_width(&stats.author, width),
+            stats.created,
+            stats.closed,
+            stats.assigned
+        );
+    }
+}
+
+/// Renders the per-author breakdown as a Markdown table, followed by a pie
+/// chart of the open items assigned to each author.
+fn markdown_authors(authors: &[AuthorStats]) -> String {
+    if authors.is_empty() {
+        return "## Authors\n\nNo items found.\n".to_string();
+    }
+    let mut out = String::from(
+        "## Authors\n\n\
+         | Author | Created | Closed | Assigned | Avg cycle |\n\
+         | --- | ---: | ---: | ---: | ---: |\n",
+    );
+    for stats in authors {
+        let cycle = stats.average_cycle_minutes.map_or_else(
+            || "-".to_string(),
+            |m| format_duration(TimeDelta::minutes(m)),
+        );
+        let _ = writeln!(
+            out,
+            "| {} | {} | {} | {} | {cycle} |",
+            stats.author.replace('|', "\\|"),
+            stats.created,
+            stats.closed,
+            stats.assigned
+        );
+    }
+    let assigned: Vec<(&str, usize)> = authors
+        .iter()
+        .map(|stats| (stats.author.as_str(), stats.assigned))
+        .collect();
+    out.push_str(&mermaid_pie("Assigned open items", &assigned));
+    out
+}
+
+/// Renders a fenced mermaid pie chart, preceded by a blank line; empty slices
+/// are left out, and nothing is rendered if all are empty.
+fn mermaid_pie(title: &str, slices: &[(&str, usize)]) -> String {
+    if slices.iter().all(|&(_, count)| count == 0) {
+        return String::new();
+    }
+    let mut out = format!("\n```mermaid\npie title {title}\n");
+    for &(label, count) in slices.iter().filter(|&&(_, count)| count > 0) {
+        let _ = writeln!(out, "    \"{}\" : {count}", label.replace('"', "#quot;"));
+    }
+    out.push_str("```\n");
+    out
+}
+
+/// Average of durations, `None` if there are none.
+fn average(deltas: &[TimeDelta]) -> Option<TimeDelta> {
+    let count = i32::try_from(deltas.len()).unwrap_or(i32::MAX);
+    (count > 0).then(|| deltas.iter().copied().sum::<TimeDelta>() / count)
+}
+
+/// Time from creation to the first commit touching the item by someone else.
+///
+/// Commit authors are matched against the item author by name and email,
+/// through the configured identities.
+fn first_response(
+    item: &Item,
+    history: &[vcs::Commit],
+    identities: &Identities,
+) -> Option<TimeDelta> {
+    let created = item.created_at();
+    history
+        .iter()
+        .filter(|commit| commit.date >= created)
+        .filter(|commit| {
+            !identities.same(&commit.author, item.author())
+                && !identities.same(&commit.email, item.author())
+        })
+        .find(|commit| commit.files.iter().any(|f| touches(f, item.id())))
+        .map(|commit| commit.date - created)
+}
+
+/// Returns true if a path belongs to the item (its file or attachments).
+fn touches(path: &str, id: &str) -> bool {
+    let prefix = format!("{id}-");
+    let exact = format!("{id}.md");
+    Path::new(path).components().any(|component| {
+        let name = component.as_os_str().to_string_lossy();
+        name == exact || name.starts_with(&prefix)
+    })
+}
+
+/// Formats a duration compactly: `3d 4h`, `5h 12m`, `12m`.
+fn format_duration(delta: TimeDelta) -> String {
+    let minutes = delta.num_minutes().max(0);
+    let (days, hours, minutes) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+    if days > 0 {
+        format!("{days}d {hours}h")
+    } else if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else {
+        format!("{minutes}m")
+    }
+}
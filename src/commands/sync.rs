@@ -0,0 +1,285 @@
+//! # Sync Command
+//!
+//! Syncs items with GitHub Issues (`qs sync github`). Active items without an
+//! issue get one, and its number is stored in their `github_issue` field.
+//! Titles, bodies, and labels of linked items are pushed to their issues, and
+//! closing either side closes the other: a closed item closes its issue, a
+//! closed issue closes (archives) its item. Protected items are skipped
+//! unless `--force` is given, as they would be published.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::Path,
+};
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use super::close;
+use crate::{
+    config::{Config, GithubSync},
+    item::{fold, labels, Item},
+    policy::{self, Destructive},
+    storage::{
+        self,
+        github::{Issue, IssueEdit, Repo},
+        vault, LoadedItem,
+    },
+    ui,
+};
+
+/// Arguments for the sync github subcommand
+pub struct SyncGithubArgs {
+    /// Print what would change without touching GitHub or items
+    pub dry_run: bool,
+    /// Sync protected items too
+    pub force: bool,
+}
+
+/// Counts of the changes made by a sync
+#[derive(Default)]
+struct Summary {
+    created: usize,
+    updated: usize,
+    closed_issues: usize,
+    closed_items: usize,
+}
+
+/// Executes the sync github subcommand.
+pub fn execute_github(args: &SyncGithubArgs) -> Result<()> {
+    let config = Config::load()?;
+    let settings = config.github();
+    let repo = Repo {
+        name: settings.repo.as_deref(),
+        root: config.project_root(),
+    };
+
+    let issues: HashMap<u64, Issue> = repo
+        .issues()?
+        .into_iter()
+        .map(|issue| (issue.number, issue))
+        .collect();
+    let mut warnings = Vec::new();
+    let items = load_items(&config, args.force, &mut warnings);
+
+    // Labels of any item are managed by the sync; others set on GitHub stay
+    let managed: BTreeSet<String> = items
+        .iter()
+        .flat_map(|loaded| remote_labels(&settings, &loaded.item))
+        .collect();
+    let pushed: BTreeSet<String> = items
+        .iter()
+        .filter(|loaded| loaded.item.status().is_active())
+        .flat_map(|loaded| remote_labels(&settings, &loaded.item))
+        .collect();
+    create_missing_labels(&config, &repo, &pushed, args.dry_run)?;
+
+    let mut summary = Summary::default();
+    let mut closed_any = false;
+    for LoadedItem { path, mut item } in items {
+        let wanted = remote_labels(&settings, &item);
+        let body = body_of(&item);
+
+        let Some(number) = item.github_issue() else {
+            if !item.status().is_active() {
+                continue;
+            }
+            create_issue(&repo, &path, &mut item, &body, &wanted, args.dry_run)?;
+            summary.created += 1;
+            continue;
+        };
+
+        let Some(issue) = issues.get(&number) else {
+            warnings.push(format!(
+                "Issue #{number} of {} not found on GitHub",
+                item.id()
+            ));
+            continue;
+        };
+
+        match (item.status().is_active(), issue.is_closed()) {
+            (false, true) => {}
+            (false, false) => {
+                if args.dry_run {
+                    println!("Would close #{number} ({} is closed)", item.id());
+                } else {
+                    repo.close_issue(number)?;
+                    println!("{} Closed #{number} ({} is closed)", "✓".green(), item.id());
+                }
+                summary.closed_issues += 1;
+            }
+            (true, true) => {
+                if args.dry_run {
+                    println!("Would close {} (#{number} is closed)", item.id());
+                } else {
+                    let (new_path, moved) = close::close_item(&config, &path, &mut item)?;
+                    warnings.extend(moved);
+                    closed_any = true;
+                    println!(
+                        "{} Closed {} (#{number} is closed): {}",
+                        "✓".green(),
+                        item.id(),
+                        config.relative_path(&new_path).display()
+                    );
+                }
+                summary.closed_items += 1;
+            }
+            (true, false) => {
+                let edit = issue_edit(&item, &body, &wanted, &managed, issue);
+                if edit.is_empty() {
+                    continue;
+                }
+                if args.dry_run {
+                    println!("Would update #{number} from {}", item.id());
+                } else {
+                    repo.edit_issue(number, &edit)?;
+                    println!("{} Updated #{number} from {}", "✓".green(), item.id());
+                }
+                summary.updated += 1;
+            }
+        }
+    }
+
+    if closed_any {
+        vault::sync(&config)?;
+    }
+    ui::print_warnings(&warnings);
+    print_summary(&summary, args.dry_run);
+    Ok(())
+}
+
+/// Loads all items sorted by ID, leaving out protected items unless `force`.
+fn load_items(config: &Config, force: bool, warnings: &mut Vec<String>) -> Vec<LoadedItem> {
+    let mut items: Vec<LoadedItem> = storage::walk_all(config)
+        .filter_map(|path| Item::load(&path).ok().map(|item| LoadedItem { path, item }))
+        .filter(|loaded| {
+            let category = storage::derive_category(config, &loaded.path);
+            let check = policy::check_protected(
+                config,
+                Destructive::Export,
+                &loaded.item,
+                category.as_deref(),
+                force,
+            );
+            check
+                .map_err(|e| warnings.push(format!("Skipped: {e}")))
+                .is_ok()
+        })
+        .collect();
+    items.sort_by(|a, b| a.item.id().cmp(b.item.id()));
+    items
+}
+
+/// Opens an issue for an item and records its number in the item.
+fn create_issue(
+    repo: &Repo<'_>,
+    path: &Path,
+    item: &mut Item,
+    body: &str,
+    labels: &[String],
+    dry_run: bool,
+) -> Result<()> {
+    if dry_run {
+        println!("Would create an issue for {}: {}", item.id(), item.title());
+        return Ok(());
+    }
+    let number = repo.create_issue(item.title(), body, labels)?;
+    item.set_github_issue(number);
+    item.save(path)?;
+    println!("{} Created #{number} for {}", "✓".green(), item.id());
+    Ok(())
+}
+
+/// Returns the GitHub labels of an item, mapped through `[github.labels]`.
+fn remote_labels(settings: &GithubSync, item: &Item) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    item.labels()
+        .iter()
+        .filter_map(|label| settings.remote_label(label))
+        .filter(|label| seen.insert(fold::key(label)))
+        .collect()
+}
+
+/// Returns the item body as sent to GitHub, without a generated vault footer.
+fn body_of(item: &Item) -> String {
+    vault::apply_footer(&item.body, None).trim().to_string()
+}
+
+/// Creates the labels GitHub doesn't have yet, with the color and description
+/// of their local definition.
+fn create_missing_labels(
+    config: &Config,
+    repo: &Repo<'_>,
+    wanted: &BTreeSet<String>,
+    dry_run: bool,
+) -> Result<()> {
+    if wanted.is_empty() {
+        return Ok(());
+    }
+    let existing = repo.labels()?;
+    let defined = &config.label_policy().defined;
+
+    for name in wanted {
+        if existing.iter().any(|label| fold::eq(label, name)) {
+            continue;
+        }
+        if dry_run {
+            println!("Would create label '{name}'");
+            continue;
+        }
+        let def = labels::find(defined, name);
+        let color = def
+            .and_then(|def| def.color.as_deref())
+            .and_then(labels::LabelColor::parse)
+            .map(labels::LabelColor::hex);
+        let description = def.and_then(|def| def.description.as_deref());
+        repo.create_label(name, color.as_deref(), description)?;
+        println!("{} Created label '{name}'", "✓".green());
+    }
+    Ok(())
+}
+
+/// Computes the changes that bring an issue in line with its item.
+fn issue_edit<'a>(
+    item: &'a Item,
+    body: &'a str,
+    wanted: &[String],
+    managed: &BTreeSet<String>,
+    issue: &Issue,
+) -> IssueEdit<'a> {
+    let current = issue.label_names();
+    let has = |labels: &[String], name: &str| labels.iter().any(|label| fold::eq(label, name));
+
+    IssueEdit {
+        title: (issue.title != item.title()).then(|| item.title()),
+        body: (issue.body.trim() != body).then_some(body),
+        add_labels: wanted
+            .iter()
+            .filter(|label| !has(&current, label))
+            .cloned()
+            .collect(),
+        remove_labels: current
+            .iter()
+            .filter(|label| !has(wanted, label))
+            .filter(|label| managed.iter().any(|name| fold::eq(name, label)))
+            .cloned()
+            .collect(),
+    }
+}
+
+/// Prints how many issues and items changed.
+fn print_summary(summary: &Summary, dry_run: bool) {
+    let total = summary.created + summary.updated + summary.closed_issues + summary.closed_items;
+    if total == 0 {
+        println!("Everything is in sync.");
+        return;
+    }
+    let verb = if dry_run { "Would sync" } else { "Synced" };
+    println!(
+        "{verb}: {} created, {} updated, {} issue(s) closed, {} item(s) closed",
+        summary.created, summary.updated, summary.closed_issues, summary.closed_items
+    );
+}
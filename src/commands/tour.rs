@@ -0,0 +1,283 @@
+//! # Tour Command
+//!
+//! Interactive first-run walkthrough. Creates a throwaway project in a temporary
+//! directory and walks through creating, listing, searching, attaching to, and
+//! closing an item there, then removes it again. The real project is never touched.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    io::IsTerminal,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{
+    commands::{close, new},
+    config::{Config, ProjectConfig},
+    item::{search, Item, Status},
+    storage::{self, AttachmentResult},
+    tui::screens::{run_tour, TourPage, Walkthrough},
+};
+
+/// Title of the item created in the sandbox.
+const SANDBOX_TITLE: &str = "Try out queuestack";
+
+/// Executes the tour command.
+pub fn execute() -> Result<()> {
+    if !std::io::stdout().is_terminal() {
+        bail!("The tour needs an interactive terminal");
+    }
+
+    let author = Config::load()
+        .ok()
+        .and_then(|config| config.user_name())
+        .unwrap_or_else(|| "You".to_string());
+
+    let sandbox = Sandbox::create(author)?;
+    let finished = run_tour(&mut Tour {
+        sandbox: &sandbox,
+        item: None,
+    });
+    let root = sandbox.config.project_root().to_path_buf();
+    drop(sandbox);
+
+    if finished? {
+        println!(
+            "Tour complete. The sandbox at {} was removed.",
+            root.display()
+        );
+        println!("Next: run `qs init` in your project, then `qs new` to create your first item.");
+    } else {
+        println!(
+            "Tour ended early. The sandbox at {} was removed.",
+            root.display()
+        );
+    }
+    Ok(())
+}
+
+/// A throwaway project in the temp directory, removed when dropped.
+struct Sandbox {
+    config: Config,
+    author: String,
+}
+
+impl Sandbox {
+    fn create(author: String) -> Result<Self> {
+        let root = std::env::temp_dir().join(format!("qs-tour-{}", std::process::id()));
+        let config = Config::sandbox(root.clone());
+
+        std::fs::create_dir_all(config.stack_path())
+            .with_context(|| format!("Failed to create sandbox: {}", root.display()))?;
+        ProjectConfig::default().save(&root)?;
+
+        Ok(Self { config, author })
+    }
+
+    fn root(&self) -> &Path {
+        self.config.project_root()
+    }
+}
+
+impl Drop for Sandbox {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(self.root());
+    }
+}
+
+/// The tour steps, run against the sandbox.
+struct Tour<'a> {
+    sandbox: &'a Sandbox,
+    /// Path of the sandbox item once created
+    item: Option<PathBuf>,
+}
+
+impl Tour<'_> {
+    const fn config(&self) -> &Config {
+        &self.sandbox.config
+    }
+
+    fn item(&self) -> Result<(PathBuf, Item)> {
+        let path = self
+            .item
+            .clone()
+            .context("The sandbox item wasn't created")?;
+        let item = Item::load(&path)?;
+        Ok((path, item))
+    }
+
+    fn relative(&self, path: &Path) -> String {
+        self.config().relative_path(path).display().to_string()
+    }
+
+    fn welcome(&self) -> TourPage {
+        TourPage {
+            title: "Welcome".to_string(),
+            text: vec![
+                "queuestack keeps tasks and issues as Markdown files next to your code."
+                    .to_string(),
+                "This tour runs the basic commands in a throwaway project:".to_string(),
+                format!("  {}", self.sandbox.root().display()),
+                String::new(),
+                "Press Enter to run each step. The sandbox is removed at the end.".to_string(),
+            ],
+            ..TourPage::default()
+        }
+    }
+
+    fn create(&mut self) -> Result<TourPage> {
+        let (path, _) = new::create(
+            self.config(),
+            SANDBOX_TITLE.to_string(),
+            self.sandbox.author.clone(),
+            &["tour".to_string()],
+            Some("onboarding"),
+            Status::Open,
+        )?;
+        self.item = Some(path.clone());
+
+        let mut output = vec![format!("Created {}", self.relative(&path)), String::new()];
+        output.extend(std::fs::read_to_string(&path)?.lines().map(str::to_string));
+
+        Ok(TourPage {
+            title: "Create an item".to_string(),
+            text: vec![
+                "Every item gets a unique ID and a file named after its title.".to_string(),
+                "Labels and a category (a folder) help organize the queue.".to_string(),
+            ],
+            command: Some(format!(
+                "qs new \"{SANDBOX_TITLE}\" --label tour --category onboarding"
+            )),
+            output,
+        })
+    }
+
+    fn list(&self) -> TourPage {
+        let output = storage::walk_items(self.config())
+            .filter_map(|path| Item::load(&path).ok())
+            .map(|item| {
+                format!(
+                    "{}  {}  [{}]",
+                    item.id(),
+                    item.title(),
+                    item.labels().join(", ")
+                )
+            })
+            .collect();
+
+        TourPage {
+            title: "List items".to_string(),
+            text: vec![
+                "`qs list` shows open items. Filter by label, author, category, or date;"
+                    .to_string(),
+                "in a terminal it opens an interactive list with actions.".to_string(),
+            ],
+            command: Some("qs list --label tour".to_string()),
+            output,
+        }
+    }
+
+    fn search(&self) -> TourPage {
+        let items = storage::walk_items(self.config())
+            .filter_map(|path| Item::load(&path).ok())
+            .collect();
+        let output = search::rank_matches(items, "queuestack", false)
+            .iter()
+            .map(|item| format!("{}  {}", item.id(), item.title()))
+            .collect();
+
+        TourPage {
+            title: "Search".to_string(),
+            text: vec![
+                "`qs search` finds items by title or ID; add --full-text to search bodies."
+                    .to_string(),
+            ],
+            command: Some("qs search queuestack".to_string()),
+            output,
+        }
+    }
+
+    fn attach(&self) -> Result<TourPage> {
+        let (path, mut item) = self.item()?;
+        let notes = self.sandbox.root().join("notes.txt");
+        std::fs::write(&notes, "Steps to reproduce:\n1. Run the tour\n")?;
+
+        let source = notes.to_string_lossy().to_string();
+        let result = storage::process_attachment(self.config(), &source, &mut item, &path)?;
+        item.save(&path)?;
+
+        let output = match result {
+            AttachmentResult::FileCopied { new_name, .. } => vec![
+                format!("notes.txt -> {new_name}"),
+                format!(
+                    "Stored in {}",
+                    self.relative(&storage::attachment_dir_for_item(&path))
+                ),
+            ],
+            _ => Vec::new(),
+        };
+
+        Ok(TourPage {
+            title: "Attach a file".to_string(),
+            text: vec![
+                "Files are copied next to the item and listed in its frontmatter;".to_string(),
+                "URLs are stored as references.".to_string(),
+            ],
+            command: Some(format!("qs attachments add --id {} notes.txt", item.id())),
+            output,
+        })
+    }
+
+    fn close(&self) -> Result<TourPage> {
+        let (path, mut item) = self.item()?;
+        let (archived, _) = close::close_item(self.config(), &path, &mut item)?;
+
+        Ok(TourPage {
+            title: "Close the item".to_string(),
+            text: vec![
+                "Closing moves the item (and its attachments) into the archive.".to_string(),
+                "`qs reopen` brings it back.".to_string(),
+            ],
+            command: Some(format!("qs close --id {}", item.id())),
+            output: vec![format!("Archived to {}", self.relative(&archived))],
+        })
+    }
+
+    fn finish() -> TourPage {
+        TourPage {
+            title: "Done".to_string(),
+            text: vec![
+                "That's the basic loop: new, list, search, attach, close.".to_string(),
+                String::new(),
+                "Next steps:".to_string(),
+                "  qs init      Set up queuestack in your project".to_string(),
+                "  qs new       Create an item (opens a wizard)".to_string(),
+                "  qs --help    See all commands".to_string(),
+                String::new(),
+                "Press Enter to finish and remove the sandbox.".to_string(),
+            ],
+            ..TourPage::default()
+        }
+    }
+}
+
+impl Walkthrough for &mut Tour<'_> {
+    fn steps(&self) -> usize {
+        7
+    }
+
+    fn step(&mut self, index: usize) -> Result<TourPage> {
+        match index {
+            0 => Ok(self.welcome()),
+            1 => self.create(),
+            2 => Ok(self.list()),
+            3 => Ok(self.search()),
+            4 => self.attach(),
+            5 => self.close(),
+            _ => Ok(Tour::finish()),
+        }
+    }
+}
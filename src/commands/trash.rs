@@ -0,0 +1,60 @@
+//! # Trash Command
+//!
+//! Lists deleted items and restores them from the `.trash/` directory.
+//! Deletions older than `trash_days` are purged first, except when listing
+//! with `pure_reads`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use crate::{config::Config, storage::trash, ui};
+
+/// Arguments for the trash restore subcommand
+pub struct TrashRestoreArgs {
+    /// Item ID (partial match supported)
+    pub id: String,
+}
+
+/// Executes the trash list subcommand.
+pub fn execute_list() -> Result<()> {
+    let config = Config::load()?;
+    if !config.pure_reads() {
+        trash::purge(&config)?;
+    }
+
+    let items = trash::list(&config);
+    if items.is_empty() {
+        println!("Trash is empty.");
+        return Ok(());
+    }
+
+    for trashed in items {
+        println!(
+            "{}  {}  {}  {}",
+            trashed.item.id(),
+            trashed.deleted_at.format("%Y-%m-%d %H:%M"),
+            trashed.origin.display(),
+            trashed.item.title()
+        );
+    }
+    Ok(())
+}
+
+/// Executes the trash restore subcommand.
+pub fn execute_restore(args: &TrashRestoreArgs) -> Result<()> {
+    let config = Config::load()?;
+    trash::purge(&config)?;
+
+    let (path, warnings) = trash::restore(&config, &args.id)?;
+    ui::print_warnings(&warnings);
+
+    println!(
+        "{} Restored: {}",
+        "✓".green(),
+        config.relative_path(&path).display()
+    );
+    Ok(())
+}
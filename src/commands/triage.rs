@@ -0,0 +1,277 @@
+//! # Triage Command
+//!
+//! Lists open and in-progress items one per line, lets the user change their
+//! status, category, assignee, and labels in an editor, and applies the edited
+//! list on save, like `git rebase -i`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    collections::HashMap,
+    fs,
+    io::IsTerminal,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+
+use super::{
+    close,
+    list::{collect_items, sort_items, SortBy},
+};
+use crate::{
+    config::Config,
+    editor,
+    item::{
+        normalize_identifier,
+        triage::{self, TriageLine},
+        FilterCriteria, Item, Status,
+    },
+    policy::{self, Hook},
+    storage, ui,
+};
+
+/// Arguments for the triage command
+pub struct TriageArgs {
+    pub labels: Vec<String>,
+    pub any_labels: Vec<String>,
+    pub not_labels: Vec<String>,
+    pub author: Option<String>,
+    pub category: Option<String>,
+    /// Open the list in the editor and apply it on save
+    pub edit: bool,
+    /// Apply a previously edited list instead
+    pub apply: Option<PathBuf>,
+}
+
+/// An item whose line changed, validated and ready to apply.
+struct TriageChange {
+    path: PathBuf,
+    item: Item,
+    /// Target status; closing is applied last, through `qs close`
+    status: Status,
+    /// Target category, if it changes (`Some(None)` for the queuestack root)
+    #[allow(clippy::option_option)]
+    category: Option<Option<String>>,
+}
+
+/// Executes the triage command.
+pub fn execute(args: &TriageArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    if let Some(ref file) = args.apply {
+        let text = fs::read_to_string(file)
+            .with_context(|| format!("Failed to read {}", file.display()))?;
+        return apply(&config, &text);
+    }
+
+    let text = triage::render(&lines(&config, args));
+    if !args.edit {
+        print!("{text}");
+        return Ok(());
+    }
+    if !std::io::stdout().is_terminal() {
+        bail!("--edit needs a terminal; save the list with 'qs triage > list.txt' and apply it with 'qs triage --apply list.txt'");
+    }
+
+    let path = std::env::temp_dir().join(format!("qs-triage-{}.txt", std::process::id()));
+    fs::write(&path, &text)
+        .with_context(|| format!("Failed to write triage list: {}", path.display()))?;
+    editor::open(&path, &config)?;
+    let edited = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read triage list: {}", path.display()))?;
+
+    if let Err(e) = apply(&config, &edited) {
+        eprintln!(
+            "{} Kept your list in {path}; fix it and run 'qs triage --apply {path}'",
+            "!".yellow(),
+            path = path.display()
+        );
+        return Err(e);
+    }
+    let _ = fs::remove_file(&path);
+    Ok(())
+}
+
+/// Returns the triage lines of the matching open and in-progress items.
+fn lines(config: &Config, args: &TriageArgs) -> Vec<TriageLine> {
+    let filter = FilterCriteria {
+        labels: args.labels.clone(),
+        any_labels: args.any_labels.clone(),
+        not_labels: args.not_labels.clone(),
+        author: args.author.clone(),
+        identities: config.identities(),
+        category: args.category.clone(),
+        ..FilterCriteria::default()
+    };
+    let mut items = collect_items(config, false, &filter);
+    items.retain(|item| item.status().is_active());
+    sort_items(&mut items, SortBy::Id);
+
+    items
+        .iter()
+        .map(|item| TriageLine {
+            id: item.id().to_string(),
+            status: item.status(),
+            category: item
+                .path
+                .as_ref()
+                .and_then(|p| storage::derive_category(config, p)),
+            assignee: item.assignee(),
+            labels: item.labels().to_vec(),
+            title: item.title().to_string(),
+        })
+        .collect()
+}
+
+/// Validates an edited list and applies the changed lines.
+///
+/// Nothing is changed if any line is invalid.
+fn apply(config: &Config, text: &str) -> Result<()> {
+    let changes = prepare(config, triage::parse(text)?)?;
+    if changes.is_empty() {
+        println!("{}", "No changes to apply.".dimmed());
+        return Ok(());
+    }
+
+    for TriageChange {
+        mut path,
+        mut item,
+        status,
+        category,
+    } in changes
+    {
+        item.save(&path)?;
+
+        if let Some(category) = category {
+            let warnings;
+            (path, warnings) =
+                storage::move_to_category(config, &path, item.status(), category.as_deref())?;
+            ui::print_warnings(&warnings);
+        }
+
+        let verb = if status == Status::Closed {
+            let warnings;
+            (path, warnings) = close::close_item(config, &path, &mut item)?;
+            ui::print_warnings(&warnings);
+            "Closed"
+        } else if status == item.status() {
+            "Updated"
+        } else {
+            item.set_status(status);
+            item.save(&path)?;
+            let warnings;
+            (path, warnings) = storage::move_to_status(config, &path, status)?;
+            ui::print_warnings(&warnings);
+            "Updated"
+        };
+        ui::print_success(verb, config, &path);
+    }
+
+    storage::vault::sync(config)
+}
+
+/// Matches the lines to stored items and validates the changes.
+fn prepare(config: &Config, lines: Vec<TriageLine>) -> Result<Vec<TriageChange>> {
+    let mut stored: HashMap<String, Item> = storage::load_all_items(config)
+        .into_iter()
+        .map(|item| (item.id().to_string(), item))
+        .collect();
+    let identities = config.identities();
+
+    let mut problems = Vec::new();
+    let mut changes = Vec::new();
+    for line in lines {
+        let id = line.id.clone();
+        let Some(before) = stored.remove(&id) else {
+            problems.push(format!("{id}: no item with this id"));
+            continue;
+        };
+        let Some(path) = before.path.clone() else {
+            continue;
+        };
+
+        let mut item = before.clone();
+        let mut labels: Vec<String> = Vec::new();
+        for label in line.labels.iter().map(|l| normalize_identifier(l)) {
+            if !labels.contains(&label) {
+                labels.push(label);
+            }
+        }
+        item.frontmatter.labels = labels;
+        if item.labels() != before.labels() {
+            if let Err(e) = policy::check_labels(config, &mut item, before.labels()) {
+                problems.push(format!("{id}: {e}"));
+                continue;
+            }
+        }
+        let assignee = line
+            .assignee
+            .as_deref()
+            .map(|a| identities.canonical(a).to_string());
+        item.set_assignee(assignee);
+
+        let current_category = storage::derive_category(config, &path);
+        let category = line.category.as_deref().map(normalize_identifier);
+        let category = (category != current_category).then_some(category);
+
+        if item.frontmatter == before.frontmatter
+            && category.is_none()
+            && line.status == before.status()
+        {
+            continue;
+        }
+        if let Err(problem) = check(config, &before, &path, &line, category.as_ref()) {
+            problems.push(format!("{id}: {problem}"));
+            continue;
+        }
+        changes.push(TriageChange {
+            path,
+            item,
+            status: line.status,
+            category,
+        });
+    }
+
+    if !problems.is_empty() {
+        bail!(
+            "Invalid triage list, nothing was changed:\n  {}",
+            problems.join("\n  ")
+        );
+    }
+    Ok(changes)
+}
+
+/// Checks that a changed line can be applied to the item.
+fn check(
+    config: &Config,
+    item: &Item,
+    path: &Path,
+    line: &TriageLine,
+    category: Option<&Option<String>>,
+) -> Result<()> {
+    if !item.status().is_active() {
+        bail!(
+            "only open and in-progress items can be triaged (reopen it first with 'qs reopen --id {}')",
+            item.id()
+        );
+    }
+    if let Some(category) = category {
+        storage::check_stack_category(config, category.as_deref())?;
+    }
+    if line.status == Status::Closed {
+        let category = category
+            .cloned()
+            .unwrap_or_else(|| storage::derive_category(config, path));
+        policy::check(
+            config,
+            Hook::PreClose,
+            item,
+            category.as_deref(),
+            Some(path),
+        )?;
+    }
+    Ok(())
+}
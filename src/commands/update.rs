@@ -1,18 +1,28 @@
 //! # Update Command
 //!
-//! Updates an existing queuestack item.
+//! Updates an existing queuestack item and prints a field-level summary of the
+//! changes (title, labels, category, due date, assignee, file), as text or JSON.
 //!
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
 use owo_colors::OwoColorize;
+use serde::Serialize;
 
-use crate::{config::Config, item::normalize_identifier, storage, ui};
+use crate::{
+    config::Config,
+    item::{normalize_identifier, Item},
+    policy::{self, Edit},
+    storage::{self, plan::Plan},
+    ui::{self, OutputFormat},
+};
 
 /// Arguments for the update command
+#[allow(clippy::struct_excessive_bools)] // Each flag is an independent CLI option
 pub struct UpdateArgs {
     pub id: Option<String>,
     pub file: Option<PathBuf>,
@@ -21,10 +31,298 @@ pub struct UpdateArgs {
     pub remove_labels: Vec<String>,
     pub category: Option<String>,
     pub remove_category: bool,
+    /// New due date
+    pub due: Option<NaiveDate>,
+    /// Remove the due date
+    pub remove_due: bool,
+    /// New assignee
+    pub assignee: Option<String>,
+    /// Remove the assignee
+    pub remove_assignee: bool,
+    /// Update archived items too
+    pub force: bool,
+    /// Format of the change summary
+    pub format: OutputFormat,
+    /// Print nothing on success
+    pub quiet: bool,
+}
+
+/// A field's value before and after the update
+#[derive(Debug, Serialize)]
+struct Change<T> {
+    from: T,
+    to: T,
+}
+
+/// Field-level summary of an applied update
+#[derive(Debug, Serialize)]
+struct UpdateSummary {
+    changed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    /// Item file after the update, relative to the working directory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<Change<String>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    labels_added: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    labels_removed: Vec<String>,
+    /// Category change (`null` for the queuestack root)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    category: Option<Change<Option<String>>>,
+    /// Due date change (`null` for no due date)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due: Option<Change<Option<NaiveDate>>>,
+    /// Assignee change (`null` for unassigned)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assignee: Option<Change<Option<String>>>,
+    /// File move or rename
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file: Option<Change<String>>,
+}
+
+impl UpdateSummary {
+    /// Summary of an update that changed nothing
+    const fn unchanged() -> Self {
+        Self {
+            changed: false,
+            id: None,
+            path: None,
+            title: None,
+            labels_added: Vec::new(),
+            labels_removed: Vec::new(),
+            category: None,
+            due: None,
+            assignee: None,
+            file: None,
+        }
+    }
+
+    /// Compares the item before and after the update.
+    fn new(update: &PreparedUpdate, new_path: &Path) -> Self {
+        let PreparedUpdate {
+            config,
+            path: old_path,
+            before,
+            item,
+            ..
+        } = update;
+        let relative = |path: &Path| config.relative_path(path).display().to_string();
+        let difference = |a: &[String], b: &[String]| -> Vec<String> {
+            a.iter().filter(|l| !b.contains(l)).cloned().collect()
+        };
+
+        Self {
+            changed: true,
+            id: Some(item.id().to_string()),
+            path: Some(relative(new_path)),
+            title: (before.title() != item.title()).then(|| Change {
+                from: before.title().to_string(),
+                to: item.title().to_string(),
+            }),
+            labels_added: difference(item.labels(), before.labels()),
+            labels_removed: difference(before.labels(), item.labels()),
+            category: update.category_changed.then(|| Change {
+                from: update.old_category.clone(),
+                to: update.category.clone(),
+            }),
+            due: (before.due() != item.due()).then(|| Change {
+                from: before.due(),
+                to: item.due(),
+            }),
+            assignee: (before.assignee() != item.assignee()).then(|| Change {
+                from: before.assignee(),
+                to: item.assignee(),
+            }),
+            file: (old_path != new_path).then(|| Change {
+                from: relative(old_path),
+                to: relative(new_path),
+            }),
+        }
+    }
+
+    /// Prints the summary as aligned `field  old → new` lines.
+    fn print_text(&self) {
+        let arrow = "→".dimmed();
+        if let Some(Change { from, to }) = &self.title {
+            println!(
+                "  {:<9} {} {arrow} {}",
+                "title".dimmed(),
+                from.red(),
+                to.green()
+            );
+        }
+        if !self.labels_added.is_empty() || !self.labels_removed.is_empty() {
+            let added = self
+                .labels_added
+                .iter()
+                .map(|l| format!("+{l}").green().to_string());
+            let removed = self
+                .labels_removed
+                .iter()
+                .map(|l| format!("-{l}").red().to_string());
+            let labels: Vec<String> = added.chain(removed).collect();
+            println!("  {:<9} {}", "labels".dimmed(), labels.join(" "));
+        }
+        if let Some(Change { from, to }) = &self.category {
+            let name = |c: &Option<String>| c.clone().unwrap_or_else(|| "(none)".to_string());
+            println!(
+                "  {:<9} {} {arrow} {}",
+                "category".dimmed(),
+                name(from).red(),
+                name(to).green()
+            );
+        }
+        if let Some(Change { from, to }) = &self.due {
+            let date = |d: &Option<NaiveDate>| {
+                d.map_or_else(
+                    || "(none)".to_string(),
+                    |d| d.format("%Y-%m-%d").to_string(),
+                )
+            };
+            println!(
+                "  {:<9} {} {arrow} {}",
+                "due".dimmed(),
+                date(from).red(),
+                date(to).green()
+            );
+        }
+        if let Some(Change { from, to }) = &self.assignee {
+            let name = |a: &Option<String>| a.clone().unwrap_or_else(|| "(none)".to_string());
+            println!(
+                "  {:<9} {} {arrow} {}",
+                "assignee".dimmed(),
+                name(from).red(),
+                name(to).green()
+            );
+        }
+        if let Some(Change { from, to }) = &self.file {
+            println!(
+                "  {:<9} {} {arrow} {}",
+                "file".dimmed(),
+                from.red(),
+                to.green()
+            );
+        }
+    }
 }
 
 /// Executes the update command.
 pub fn execute(args: UpdateArgs) -> Result<()> {
+    let (format, quiet) = (args.format, args.quiet);
+    let Some(update) = prepare(args)? else {
+        if quiet {
+            return Ok(());
+        }
+        return print_summary(None, &UpdateSummary::unchanged(), format);
+    };
+
+    // Save updated frontmatter
+    update.item.save(&update.path)?;
+
+    // Handle filename change (title changed)
+    let mut path = storage::rename_item(&update.path, &update.item.filename())?;
+
+    // Handle category change (move to different directory)
+    if update.category_changed {
+        let (new_path, warnings) = storage::move_to_category(
+            &update.config,
+            &path,
+            update.item.status(),
+            update.category.as_deref(),
+        )?;
+        path = new_path;
+
+        // Print any attachment move warnings
+        ui::print_warnings(&warnings);
+    }
+
+    storage::vault::sync(&update.config)?;
+
+    if quiet {
+        return Ok(());
+    }
+    let summary = UpdateSummary::new(&update, &path);
+    print_summary(Some((&update.config, &path)), &summary, format)
+}
+
+/// Prints the change summary: the success line followed by the changed fields,
+/// or the summary as JSON.
+fn print_summary(
+    updated: Option<(&Config, &Path)>,
+    summary: &UpdateSummary,
+    format: OutputFormat,
+) -> Result<()> {
+    if format == OutputFormat::Json {
+        let json = serde_json::to_string_pretty(summary).context("Failed to serialize summary")?;
+        println!("{json}");
+        return Ok(());
+    }
+
+    match updated {
+        Some((config, path)) => {
+            ui::print_success("Updated", config, path);
+            summary.print_text();
+        }
+        None => println!("{}", "No changes to apply.".dimmed()),
+    }
+    Ok(())
+}
+
+/// Computes the update without executing it (`--dry-run`).
+pub fn plan(args: UpdateArgs) -> Result<Plan> {
+    let mut plan = Plan::new();
+    let Some(update) = prepare(args)? else {
+        return Ok(plan);
+    };
+
+    plan.write(&update.config, &update.path, &update.before, &update.item)?;
+
+    // Rename and category change combine into a single move
+    let dest_dir = if update.category_changed {
+        storage::status_directory(
+            &update.config,
+            update.item.status(),
+            update.category.as_deref(),
+        )
+    } else {
+        update
+            .path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default()
+    };
+    plan.move_item(
+        &update.config,
+        &update.path,
+        &dest_dir.join(update.item.filename()),
+    );
+
+    Ok(plan)
+}
+
+/// An update with all changes applied in memory but not yet on disk.
+struct PreparedUpdate {
+    config: Config,
+    path: PathBuf,
+    /// The item as currently stored
+    before: Item,
+    /// The item with all changes applied
+    item: Item,
+    /// Whether the item moves to a different category
+    category_changed: bool,
+    /// Current category (`None` for the queuestack root)
+    old_category: Option<String>,
+    /// Target category (`None` for the queuestack root)
+    category: Option<String>,
+}
+
+/// Validates the arguments and applies the changes in memory.
+///
+/// Returns `None` if there is nothing to change.
+fn prepare(args: UpdateArgs) -> Result<Option<PreparedUpdate>> {
     // Validate title is not empty (if provided)
     if let Some(ref title) = args.title {
         if title.trim().is_empty() {
@@ -57,10 +355,12 @@ pub fn execute(args: UpdateArgs) -> Result<()> {
 
     // Resolve item from --id or --file
     let item_ref = storage::ItemRef::from_options(args.id, args.file)?;
-    let storage::LoadedItem { mut path, mut item } = item_ref.resolve(&config)?;
+    let storage::LoadedItem { path, mut item } = item_ref.resolve(&config)?;
+    policy::check_editable(&config, Edit::Update, &item, &path, args.force)?;
+    let before = item.clone();
 
-    let mut changed = false;
-    let old_filename = item.filename();
+    // Saving writes frontmatter that was fixed while loading in its normalized form
+    let mut changed = !item.fixes.is_empty();
 
     // Update title
     if let Some(new_title) = args.title {
@@ -72,8 +372,10 @@ pub fn execute(args: UpdateArgs) -> Result<()> {
 
     // Add labels
     for label in &args.labels {
-        item.add_label(label);
-        changed = true;
+        if !item.labels().contains(&normalize_identifier(label)) {
+            item.add_label(label);
+            changed = true;
+        }
     }
 
     // Remove labels
@@ -84,9 +386,37 @@ pub fn execute(args: UpdateArgs) -> Result<()> {
             changed = true;
         }
     }
+    if item.labels() != before.labels() {
+        policy::check_labels(&config, &mut item, before.labels())?;
+    }
+
+    // Set or remove the due date
+    if args.remove_due || args.due.is_some() {
+        let due = if args.remove_due { None } else { args.due };
+        if due != item.due() {
+            item.set_due(due);
+            changed = true;
+        }
+    }
+
+    // Set or remove the assignee, under its canonical identity
+    if args.remove_assignee || args.assignee.is_some() {
+        let assignee = if args.remove_assignee {
+            None
+        } else {
+            args.assignee
+                .as_deref()
+                .map(|a| config.identities().canonical(a).to_string())
+        };
+        if assignee != item.assignee() {
+            item.set_assignee(assignee);
+            changed = true;
+        }
+    }
 
     // Normalize category
     let new_category = args.category.as_deref().map(normalize_identifier);
+    storage::check_stack_category(&config, new_category.as_deref())?;
 
     // Check for category change (derived from path, not stored in metadata)
     let current_category = storage::derive_category(&config, &path);
@@ -103,34 +433,20 @@ pub fn execute(args: UpdateArgs) -> Result<()> {
     }
 
     if !changed {
-        println!("{}", "No changes to apply.".dimmed());
-        return Ok(());
-    }
-
-    // Save updated frontmatter
-    item.save(&path)?;
-
-    // Handle filename change (title changed)
-    let new_filename = item.filename();
-    if old_filename != new_filename {
-        path = storage::rename_item(&path, &new_filename)?;
+        return Ok(None);
     }
 
-    // Handle category change (move to different directory)
-    if category_changed {
-        let category = if args.remove_category {
+    Ok(Some(PreparedUpdate {
+        config,
+        path,
+        before,
+        item,
+        category_changed,
+        old_category: current_category,
+        category: if args.remove_category {
             None
         } else {
-            new_category.as_deref()
-        };
-        let (new_path, warnings) = storage::move_to_category(&config, &path, category)?;
-        path = new_path;
-
-        // Print any attachment move warnings
-        ui::print_warnings(&warnings);
-    }
-
-    ui::print_success("Updated", &config, &path);
-
-    Ok(())
+            new_category
+        },
+    }))
 }
@@ -0,0 +1,30 @@
+//! # URL Command
+//!
+//! Prints a shareable link to an item, built from the `link_base` template or
+//! the web page of the `origin` remote. `qs resolve` turns it back into the item.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+
+use crate::{config::Config, permalink, storage};
+
+/// Arguments for the url command
+pub struct UrlArgs {
+    pub id: Option<String>,
+    pub file: Option<PathBuf>,
+}
+
+/// Executes the url command.
+pub fn execute(args: &UrlArgs) -> Result<()> {
+    let config = Config::load()?;
+
+    let item_ref = storage::ItemRef::from_options(args.id.clone(), args.file.clone())?;
+    let storage::LoadedItem { item, path } = item_ref.resolve(&config)?;
+    println!("{}", permalink::link(&config, item.id(), &path));
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+//! # Validate Command
+//!
+//! Checks the labels of all items (open, archived, and templates) against the
+//! `[labels]` policy. Labels in the wrong case are reported and, with
+//! `--fix`, normalized in place; labels breaking the charset or length
+//! limits are reported for renaming by hand.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use crate::{config::Config, item::Item, storage};
+
+/// Arguments for the validate command
+pub struct ValidateArgs {
+    /// Normalize labels in place
+    pub fix: bool,
+}
+
+/// Executes the validate command.
+pub fn execute(args: &ValidateArgs) -> Result<()> {
+    let config = Config::load()?;
+    let policy = config.label_policy();
+    if policy.is_empty() {
+        println!("No label policy configured (see [labels] in 'qs help config').");
+        return Ok(());
+    }
+
+    let mut checked = 0;
+    let mut fixed = 0;
+    let mut fixable = 0;
+    let mut problems = Vec::new();
+    for path in storage::walk_all(&config).chain(storage::walk_templates(&config)) {
+        let Ok(mut item) = Item::load(&path) else {
+            continue;
+        };
+        checked += 1;
+        let relative = config.relative_path(&path).display().to_string();
+
+        let renames: Vec<String> = item
+            .labels()
+            .iter()
+            .filter_map(|label| {
+                let normalized = policy.normalize(label);
+                (normalized != *label).then(|| format!("{label} → {normalized}"))
+            })
+            .collect();
+        if !renames.is_empty() {
+            if args.fix {
+                policy.normalize_all(&mut item.frontmatter.labels);
+                item.save(&path)?;
+                fixed += 1;
+                println!("{} Fixed {relative}: {}", "✓".green(), renames.join(", "));
+            } else {
+                fixable += 1;
+                problems.push(format!("{relative}: {}", renames.join(", ")));
+            }
+        }
+
+        for label in item.labels() {
+            if let Some(reason) = policy.violation(&policy.normalize(label))? {
+                problems.push(format!("{relative}: label '{label}' {reason}"));
+            }
+        }
+    }
+
+    if fixed > 0 {
+        storage::vault::sync(&config)?;
+    }
+
+    if problems.is_empty() {
+        println!("{} {checked} item(s) follow the label policy", "✓".green());
+        return Ok(());
+    }
+
+    for problem in &problems {
+        println!("  {problem}");
+    }
+    let hint = if fixable > 0 {
+        "run 'qs validate --fix' to normalize their case"
+    } else {
+        "rename the labels with 'qs patch'"
+    };
+    bail!("{} label problem(s) found; {hint}", problems.len());
+}
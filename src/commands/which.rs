@@ -0,0 +1,48 @@
+//! # Which Command
+//!
+//! Prints the resolved project root and the configuration files that apply,
+//! in the order they override each other. Helps untangle nested projects.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use anyhow::Result;
+
+use crate::config::{project::PROJECT_CONFIG_FILE, Config, GlobalConfig, ProjectRoot};
+
+/// Executes the which command.
+pub fn execute() -> Result<()> {
+    let global = GlobalConfig::load()?;
+    let root = ProjectRoot::resolve(&global)?;
+    let config = Config::load()?;
+
+    println!("Project root: {}", root.path.display());
+    let how = if root.explicit {
+        "--project-root"
+    } else {
+        "nearest .queuestack"
+    };
+    println!("Resolved by: {how} (nested_projects = \"{}\")", root.policy);
+
+    if root.detected.len() > 1 {
+        println!("Projects found (nearest first):");
+        for dir in &root.detected {
+            let marker = if *dir == root.path { "  (active)" } else { "" };
+            println!("  {}{marker}", dir.join(PROJECT_CONFIG_FILE).display());
+        }
+    }
+
+    println!("Config chain (later entries override earlier ones):");
+    if let Some(path) = GlobalConfig::path() {
+        println!("  global   {}", path.display());
+    }
+    if let Some(profile) = config.profile_name() {
+        println!("  profile  {profile}");
+    }
+    println!(
+        "  project  {}",
+        root.path.join(PROJECT_CONFIG_FILE).display()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+//! # Command Aliases
+//!
+//! Expands user-defined aliases from the `[aliases]` config table before the
+//! command line is parsed, like git aliases:
+//!
+//! ```toml
+//! [aliases]
+//! bug = "new --category bugs --label=bug"
+//! ```
+//!
+//! `qs bug "Crash on start"` then runs `qs new --category bugs --label=bug "Crash on start"`.
+//! Aliases can refer to other aliases but can't replace built-in commands.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{collections::BTreeMap, ffi::OsString};
+
+use anyhow::{bail, Result};
+
+use super::{GlobalConfig, ProjectConfig};
+
+/// Global options that take a value (the command word comes after them).
+const VALUE_OPTIONS: &[&str] = &["--profile", "--project-root"];
+
+/// Expands the configured alias in `args` (program name first), if any.
+///
+/// Aliases come from the global config, with the nearest project's aliases merged
+/// over them. Unreadable config files are ignored here; commands report them.
+pub fn expand(args: Vec<OsString>, builtins: &[&str]) -> Result<Vec<OsString>> {
+    let mut aliases = GlobalConfig::load()
+        .map(|config| config.aliases)
+        .unwrap_or_default();
+    if let Some(project) = ProjectConfig::find_project_root()
+        .and_then(|root| ProjectConfig::load(&root).ok())
+        .and_then(|config| config.aliases)
+    {
+        aliases.extend(project);
+    }
+
+    expand_with(args, builtins, &aliases)
+}
+
+/// Expands `args` using the given aliases.
+pub fn expand_with(
+    mut args: Vec<OsString>,
+    builtins: &[&str],
+    aliases: &BTreeMap<String, String>,
+) -> Result<Vec<OsString>> {
+    let Some(position) = command_position(&args) else {
+        return Ok(args);
+    };
+
+    let mut seen = Vec::new();
+    while let Some(name) = args[position].to_str().map(str::to_string) {
+        if builtins.contains(&name.as_str()) {
+            break;
+        }
+        let Some(expansion) = aliases.get(&name) else {
+            break;
+        };
+        if seen.contains(&name) {
+            bail!("Alias '{name}' expands to itself");
+        }
+
+        let Some(words) = shlex::split(expansion).filter(|words| !words.is_empty()) else {
+            bail!("Invalid alias '{name}': \"{expansion}\"");
+        };
+        args.splice(position..=position, words.into_iter().map(OsString::from));
+        seen.push(name);
+    }
+
+    Ok(args)
+}
+
+/// Returns the index of the command word, skipping the program name and global options.
+fn command_position(args: &[OsString]) -> Option<usize> {
+    let mut index = 1;
+    while let Some(arg) = args.get(index) {
+        let arg = arg.to_str()?;
+        if !arg.starts_with('-') {
+            return Some(index);
+        }
+        if arg == "--" {
+            return None;
+        }
+        index += if VALUE_OPTIONS.contains(&arg) { 2 } else { 1 };
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUILTINS: &[&str] = &["new", "list", "close"];
+
+    fn args(words: &[&str]) -> Vec<OsString> {
+        std::iter::once("qs")
+            .chain(words.iter().copied())
+            .map(OsString::from)
+            .collect()
+    }
+
+    fn aliases(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+        entries
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_expands_alias() {
+        let aliases = aliases(&[("bug", "new --category bugs --label \"needs triage\"")]);
+        let expanded = expand_with(args(&["bug", "Crash"]), BUILTINS, &aliases).unwrap();
+        assert_eq!(
+            expanded,
+            args(&[
+                "new",
+                "--category",
+                "bugs",
+                "--label",
+                "needs triage",
+                "Crash"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_skips_global_options() {
+        let aliases = aliases(&[("mine", "list --author Dom")]);
+        let expanded =
+            expand_with(args(&["--profile", "work", "mine"]), BUILTINS, &aliases).unwrap();
+        assert_eq!(
+            expanded,
+            args(&["--profile", "work", "list", "--author", "Dom"])
+        );
+    }
+
+    #[test]
+    fn test_builtins_are_not_replaced() {
+        let aliases = aliases(&[("list", "close")]);
+        let original = args(&["list"]);
+        let expanded = expand_with(original.clone(), BUILTINS, &aliases).unwrap();
+        assert_eq!(expanded, original);
+    }
+
+    #[test]
+    fn test_nested_aliases() {
+        let aliases = aliases(&[("bug", "new --category bugs"), ("p1", "bug --label p1")]);
+        let expanded = expand_with(args(&["p1", "Crash"]), BUILTINS, &aliases).unwrap();
+        assert_eq!(
+            expanded,
+            args(&["new", "--category", "bugs", "--label", "p1", "Crash"])
+        );
+    }
+
+    #[test]
+    fn test_alias_loop_is_an_error() {
+        let aliases = aliases(&[("a", "b"), ("b", "a --flag")]);
+        assert!(expand_with(args(&["a"]), BUILTINS, &aliases).is_err());
+    }
+
+    #[test]
+    fn test_unknown_word_is_left_alone() {
+        let original = args(&["nope"]);
+        let expanded = expand_with(original.clone(), BUILTINS, &BTreeMap::new()).unwrap();
+        assert_eq!(expanded, original);
+    }
+}
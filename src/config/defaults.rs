@@ -0,0 +1,251 @@
+//! # Command Defaults
+//!
+//! Applies per-command default options from `[defaults.<command>]` config
+//! tables before the command line is parsed, so personal or per-project
+//! preferences don't need shell wrappers:
+//!
+//! ```toml
+//! [defaults.list]
+//! sort = "date"
+//! no_interactive = true
+//!
+//! [defaults.search]
+//! scope = "archive"
+//! ```
+//!
+//! Keys are option names (`no_interactive` or `no-interactive`); values are
+//! `true` for flags, a string or number for options, and an array for options
+//! that can be repeated. Options given on the command line take precedence, and
+//! a default flag is countered by its opposite (`-i` for `no_interactive`).
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use clap::{Arg, ArgAction, Command};
+
+use super::{GlobalConfig, ProjectConfig};
+
+/// Default options by command path (`list`, `mirror fetch`) and option name
+pub type Defaults = BTreeMap<String, BTreeMap<String, toml::Value>>;
+
+/// Loads the configured defaults: the global ones with the nearest project's
+/// merged over them, option by option.
+///
+/// Unreadable config files are ignored here; commands report them.
+pub fn load() -> Defaults {
+    let global = GlobalConfig::load()
+        .map(|config| config.defaults)
+        .unwrap_or_default();
+    let project = ProjectConfig::find_project_root()
+        .and_then(|root| ProjectConfig::load(&root).ok())
+        .and_then(|config| config.defaults);
+    merge(global, project)
+}
+
+/// Merges project defaults over global ones, option by option.
+pub fn merge(mut global: Defaults, project: Option<Defaults>) -> Defaults {
+    for (command, options) in project.unwrap_or_default() {
+        global.entry(command).or_default().extend(options);
+    }
+    global
+}
+
+/// Sets the configured defaults as the default values of the commands' options.
+pub fn apply(mut command: Command, defaults: &Defaults) -> Result<Command> {
+    for (path, options) in defaults {
+        let names = resolve_path(&command, path)?;
+        let target = find(&command, &names);
+        let mut values = Vec::new();
+        for (key, value) in options {
+            let Some(arg) = target
+                .get_arguments()
+                .find(|arg| arg.get_id() == key.replace('-', "_").as_str())
+            else {
+                bail!("Unknown option '{key}' in [defaults.\"{path}\"]");
+            };
+            values.push((arg.get_id().to_string(), arg_values(arg, path, key, value)?));
+        }
+        command = set_defaults(command, &names, &values);
+    }
+    Ok(command)
+}
+
+/// Returns the subcommand names (aliases resolved) of a command path.
+fn resolve_path(command: &Command, path: &str) -> Result<Vec<String>> {
+    let mut names = Vec::new();
+    let mut current = command;
+    for word in path.split_whitespace() {
+        let Some(sub) = current.find_subcommand(word) else {
+            bail!("Unknown command '{path}' in [defaults]");
+        };
+        names.push(sub.get_name().to_string());
+        current = sub;
+    }
+    if names.is_empty() {
+        bail!("Empty command name in [defaults]");
+    }
+    Ok(names)
+}
+
+fn find<'a>(command: &'a Command, names: &[String]) -> &'a Command {
+    names.iter().fold(command, |current, name| {
+        current.find_subcommand(name).unwrap_or(current)
+    })
+}
+
+fn set_defaults(command: Command, names: &[String], values: &[(String, Vec<String>)]) -> Command {
+    let Some((name, rest)) = names.split_first() else {
+        return values.iter().fold(command, |command, (id, values)| {
+            command.mut_arg(id, |arg| arg.default_values(values.clone()))
+        });
+    };
+    command.mut_subcommand(name, |sub| set_defaults(sub, rest, values))
+}
+
+/// Converts a configured value into the option's default values, checking it
+/// fits the option.
+fn arg_values(arg: &Arg, path: &str, key: &str, value: &toml::Value) -> Result<Vec<String>> {
+    let location = format!("'{key}' in [defaults.\"{path}\"]");
+    if arg.is_required_set() {
+        bail!("{location}: the option is required and can't have a default");
+    }
+
+    let values = match (arg.get_action(), value) {
+        (ArgAction::SetTrue | ArgAction::SetFalse, toml::Value::Boolean(flag)) => {
+            vec![flag.to_string()]
+        }
+        (ArgAction::SetTrue | ArgAction::SetFalse, _) => {
+            bail!("{location} is a flag and expects true or false")
+        }
+        (ArgAction::Set | ArgAction::Append, toml::Value::Boolean(_)) => {
+            bail!("{location} expects a value, not true or false")
+        }
+        (ArgAction::Append, toml::Value::Array(items)) => items
+            .iter()
+            .map(|item| scalar(item).ok_or_else(|| anyhow::anyhow!("{location}: invalid value")))
+            .collect::<Result<_>>()?,
+        (ArgAction::Set, toml::Value::Array(_)) => bail!("{location} takes a single value"),
+        (ArgAction::Set | ArgAction::Append, value) => {
+            vec![scalar(value).ok_or_else(|| anyhow::anyhow!("{location}: invalid value"))?]
+        }
+        _ => bail!("{location} can't have a default"),
+    };
+
+    let possible = arg.get_possible_values();
+    if let Some(invalid) = values.iter().find(|value| {
+        !possible.is_empty()
+            && !possible
+                .iter()
+                .any(|candidate| candidate.matches(value, arg.is_ignore_case_set()))
+    }) {
+        let names: Vec<&str> = possible
+            .iter()
+            .map(clap::builder::PossibleValue::get_name)
+            .collect();
+        bail!(
+            "{location}: invalid value '{invalid}' (possible values: {})",
+            names.join(", ")
+        );
+    }
+    Ok(values)
+}
+
+fn scalar(value: &toml::Value) -> Option<String> {
+    match value {
+        toml::Value::String(text) => Some(text.clone()),
+        toml::Value::Integer(number) => Some(number.to_string()),
+        toml::Value::Float(number) => Some(number.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cli() -> Command {
+        Command::new("qs").subcommand(
+            Command::new("list")
+                .visible_alias("ls")
+                .arg(
+                    Arg::new("sort")
+                        .long("sort")
+                        .value_parser(["id", "date", "priority"]),
+                )
+                .arg(Arg::new("label").long("label").action(ArgAction::Append))
+                .arg(
+                    Arg::new("interactive")
+                        .short('i')
+                        .long("interactive")
+                        .action(ArgAction::SetTrue)
+                        .conflicts_with("no_interactive"),
+                )
+                .arg(
+                    Arg::new("no_interactive")
+                        .long("no-interactive")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+    }
+
+    fn defaults(command: &str, toml: &str) -> Defaults {
+        let options: BTreeMap<String, toml::Value> = toml::from_str(toml).unwrap();
+        BTreeMap::from([(command.to_string(), options)])
+    }
+
+    fn parse(defaults: &Defaults, args: &[&str]) -> clap::ArgMatches {
+        let matches = apply(cli(), defaults)
+            .unwrap()
+            .try_get_matches_from(std::iter::once("qs").chain(args.iter().copied()))
+            .unwrap();
+        matches.subcommand_matches("list").unwrap().clone()
+    }
+
+    #[test]
+    fn test_defaults_apply_unless_given() {
+        let defaults = defaults(
+            "ls",
+            "sort = \"date\"\nno-interactive = true\nlabel = [\"bug\", \"ui\"]",
+        );
+
+        let list = parse(&defaults, &["list"]);
+        assert_eq!(list.get_one::<String>("sort").unwrap(), "date");
+        assert!(list.get_flag("no_interactive"));
+        let labels: Vec<&String> = list.get_many("label").unwrap().collect();
+        assert_eq!(labels, ["bug", "ui"]);
+
+        // The command line wins, and the opposite flag doesn't conflict with a default
+        let list = parse(&defaults, &["list", "--sort", "id", "-i"]);
+        assert_eq!(list.get_one::<String>("sort").unwrap(), "id");
+        assert!(list.get_flag("interactive"));
+    }
+
+    #[test]
+    fn test_invalid_defaults_are_errors() {
+        for (command, toml) in [
+            ("lsit", "sort = \"date\""),
+            ("list", "order = \"date\""),
+            ("list", "sort = \"size\""),
+            ("list", "sort = true"),
+            ("list", "sort = [\"id\"]"),
+            ("list", "interactive = \"yes\""),
+        ] {
+            assert!(
+                apply(cli(), &defaults(command, toml)).is_err(),
+                "{command}: {toml}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_merge_project_over_global() {
+        let global = defaults("list", "sort = \"date\"\nno_interactive = true");
+        let project = defaults("list", "sort = \"priority\"");
+        let merged = merge(global, Some(project));
+        assert_eq!(merged["list"]["sort"].as_str(), Some("priority"));
+        assert_eq!(merged["list"]["no_interactive"].as_bool(), Some(true));
+    }
+}
@@ -7,6 +7,7 @@
 
 use std::{
     cell::RefCell,
+    collections::BTreeMap,
     fs,
     io::{self, IsTerminal, Write},
     path::PathBuf,
@@ -18,12 +19,17 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     constants::{
-        DEFAULT_ARCHIVE_DIR, DEFAULT_STACK_DIR, DEFAULT_TEMPLATE_DIR, GLOBAL_CONFIG_DIR,
-        GLOBAL_CONFIG_FILENAME,
+        DEFAULT_ARCHIVE_DIR, DEFAULT_MAX_BODY_WORDS, DEFAULT_STACK_DIR, DEFAULT_TEMPLATE_DIR,
+        DEFAULT_TRASH_DAYS, GLOBAL_CONFIG_DIR, GLOBAL_CONFIG_FILENAME,
     },
     id::DEFAULT_PATTERN,
+    item::{dates::DateFormat, parser::FrontmatterFormat, rules::Rule, slug::SlugStyle},
+    policy::{AttachmentPolicy, LabelPolicy, Protected},
+    storage::{sources::DirectoryAttachments, vcs::VcsKind, ArchiveLayout},
 };
 
+use super::{defaults::Defaults, project::NestedProjects};
+
 /// Valid field names in the global config file.
 /// Used for validation to detect unknown/invalid fields.
 const VALID_FIELDS: &[&str] = &[
@@ -31,10 +37,44 @@ const VALID_FIELDS: &[&str] = &[
     "use_git_user",
     "editor",
     "interactive",
+    "picker",
     "id_pattern",
     "stack_dir",
     "archive_dir",
+    "archive_layout",
     "template_dir",
+    "obsidian",
+    "pre_create",
+    "pre_close",
+    "agent_command",
+    "link_base",
+    "rules",
+    "attachment_path",
+    "s3_url",
+    "attachment_dedup",
+    "attachment_directories",
+    "fetch_link_titles",
+    "attachment_policy",
+    "labels",
+    "trash_days",
+    "pure_reads",
+    "max_body_words",
+    "date_format",
+    "frontmatter_format",
+    "slug_style",
+    "slug_transliterations",
+    "fold_accents",
+    "status_dirs",
+    "vcs",
+    "usage_log",
+    "identities",
+    "profile",
+    "profiles",
+    "nested_projects",
+    "aliases",
+    "defaults",
+    "mirrors",
+    "protected",
 ];
 
 /// Fields that should be present with actual values (have meaningful defaults).
@@ -47,6 +87,7 @@ const REQUIRED_FIELDS: &[&str] = &[
     "stack_dir",
     "archive_dir",
     "template_dir",
+    "obsidian",
 ];
 
 /// Legacy field names that should be migrated to their new names.
@@ -91,8 +132,28 @@ fn get_home_override() -> Option<PathBuf> {
     HOME_OVERRIDE.with(|cell| cell.borrow().clone())
 }
 
+/// A named set of personal settings, defined as `[profiles.<name>]`.
+///
+/// The active profile is chosen with `--profile`, `QS_PROFILE`, or the
+/// `profile` option; its values apply where the project config sets none.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Profile {
+    /// Display name used as the item author
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+
+    /// Editor command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editor: Option<String>,
+
+    /// Pattern for generating item IDs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_pattern: Option<String>,
+}
+
 /// Global configuration stored at ~/.config/queuestack/config
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(clippy::struct_excessive_bools)] // Each flag is an independent config option
 pub struct GlobalConfig {
     /// User's display name
     #[serde(default)]
@@ -110,6 +171,10 @@ pub struct GlobalConfig {
     #[serde(default = "default_true")]
     pub interactive: bool,
 
+    /// Selector for picking items: "builtin", "fzf", or a picker command
+    #[serde(default)]
+    pub picker: Option<String>,
+
     /// ID pattern for generating unique identifiers
     #[serde(default = "default_id_pattern", alias = "default_id_pattern")]
     pub id_pattern: String,
@@ -122,9 +187,143 @@ pub struct GlobalConfig {
     #[serde(default)]
     pub archive_dir: Option<String>,
 
+    /// How closed items are grouped in the archive: flat, by year, or by month
+    #[serde(default)]
+    pub archive_layout: ArchiveLayout,
+
     /// Directory name for templates (default: ".templates")
     #[serde(default)]
     pub template_dir: Option<String>,
+
+    /// Whether to keep the stack directory usable as an Obsidian vault
+    #[serde(default)]
+    pub obsidian: bool,
+
+    /// Policy script run before an item is created; non-zero exit blocks creation
+    #[serde(default)]
+    pub pre_create: Option<String>,
+
+    /// Policy script run before an item is closed; non-zero exit blocks closing
+    #[serde(default)]
+    pub pre_close: Option<String>,
+
+    /// Agent command run by `qs agent` for each matching item
+    #[serde(default)]
+    pub agent_command: Option<String>,
+
+    /// Template for shareable item links (`{path}`, `{id}`); derived from the git remote when unset
+    #[serde(default)]
+    pub link_base: Option<String>,
+
+    /// Auto-labeling rules applied when items are created
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<Rule>,
+
+    /// External attachment directory (`{id}` is replaced by the item ID)
+    #[serde(default)]
+    pub attachment_path: Option<String>,
+
+    /// Object storage location for file attachments (`s3://bucket/prefix`)
+    #[serde(default)]
+    pub s3_url: Option<String>,
+
+    /// Whether identical file attachments are stored once and hard-linked
+    #[serde(default)]
+    pub attachment_dedup: bool,
+
+    /// How directories given as attachments are attached: each file or one archive
+    #[serde(default)]
+    pub attachment_directories: DirectoryAttachments,
+
+    /// Whether to fetch and store the page title of URL attachments
+    #[serde(default)]
+    pub fetch_link_titles: bool,
+
+    /// Author identities: canonical name -> alternate spellings and emails
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub identities: BTreeMap<String, Vec<String>>,
+
+    /// Profile used when neither the project, `--profile`, nor `QS_PROFILE` selects one
+    #[serde(default)]
+    pub profile: Option<String>,
+
+    /// Named profiles (work, personal, client, ...)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Profile>,
+
+    /// Command aliases: name -> command line expanded in its place
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aliases: BTreeMap<String, String>,
+
+    /// Default options per command: command -> option -> value
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub defaults: Defaults,
+
+    /// Read-only mirrored stacks: name -> local path or git URL
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub mirrors: BTreeMap<String, String>,
+
+    /// How to pick the project when `.queuestack` files exist at several ancestor levels
+    #[serde(default)]
+    pub nested_projects: NestedProjects,
+
+    /// Categories and labels whose items need `--force` for destructive operations
+    #[serde(default, skip_serializing_if = "Protected::is_empty")]
+    pub protected: Protected,
+
+    /// Size limit, allowed extensions, and scanner for file attachments
+    #[serde(default, skip_serializing_if = "AttachmentPolicy::is_empty")]
+    pub attachment_policy: AttachmentPolicy,
+
+    /// Case, allowed characters, and length of labels
+    #[serde(default, skip_serializing_if = "LabelPolicy::is_empty")]
+    pub labels: LabelPolicy,
+
+    /// Days deleted items stay in `.trash/` before they are purged (0 keeps them)
+    #[serde(default = "default_trash_days")]
+    pub trash_days: u32,
+
+    /// Whether read-only commands leave every file of the project untouched
+    #[serde(default)]
+    pub pure_reads: bool,
+
+    /// Body length in words above which saving warns and `--full-text`
+    /// search skips the body (0 disables)
+    #[serde(default = "default_max_body_words")]
+    pub max_body_words: u32,
+
+    /// How item timestamps (`created_at`, `closed_at`) are written
+    #[serde(default)]
+    pub date_format: DateFormat,
+
+    /// Frontmatter syntax of new items
+    #[serde(default)]
+    pub frontmatter_format: FrontmatterFormat,
+
+    /// How letters outside ASCII appear in item file names
+    #[serde(default)]
+    pub slug_style: SlugStyle,
+
+    /// Replacements applied to titles before slugging: text -> replacement
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub slug_transliterations: BTreeMap<String, String>,
+
+    /// Whether filters and search also ignore diacritics (`cafe` finds `Café`)
+    #[serde(default)]
+    pub fold_accents: bool,
+
+    /// Directories open and in-progress items live in: status -> directory
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub status_dirs: BTreeMap<String, String>,
+
+    /// Version control system used for item files: auto, git, jj, or hg
+    #[serde(default)]
+    pub vcs: VcsKind,
+
+    /// Whether to record command usage in a local log for `qs insights`
+    /// (global only, so a project can't turn it on for everyone)
+    #[serde(default)]
+    pub usage_log: bool,
 }
 
 impl Default for GlobalConfig {
@@ -134,10 +333,44 @@ impl Default for GlobalConfig {
             use_git_user: true,
             editor: None,
             interactive: true,
+            picker: None,
             id_pattern: DEFAULT_PATTERN.to_string(),
             stack_dir: None,
             archive_dir: None,
+            archive_layout: ArchiveLayout::default(),
             template_dir: None,
+            obsidian: false,
+            pre_create: None,
+            pre_close: None,
+            agent_command: None,
+            link_base: None,
+            rules: Vec::new(),
+            attachment_path: None,
+            s3_url: None,
+            attachment_dedup: false,
+            attachment_directories: DirectoryAttachments::default(),
+            fetch_link_titles: false,
+            identities: BTreeMap::new(),
+            profile: None,
+            profiles: BTreeMap::new(),
+            aliases: BTreeMap::new(),
+            defaults: BTreeMap::new(),
+            mirrors: BTreeMap::new(),
+            nested_projects: NestedProjects::default(),
+            protected: Protected::default(),
+            attachment_policy: AttachmentPolicy::default(),
+            labels: LabelPolicy::default(),
+            trash_days: DEFAULT_TRASH_DAYS,
+            pure_reads: false,
+            max_body_words: DEFAULT_MAX_BODY_WORDS,
+            date_format: DateFormat::default(),
+            frontmatter_format: FrontmatterFormat::default(),
+            slug_style: SlugStyle::default(),
+            slug_transliterations: BTreeMap::new(),
+            fold_accents: false,
+            status_dirs: BTreeMap::new(),
+            vcs: VcsKind::default(),
+            usage_log: false,
         }
     }
 }
@@ -147,6 +380,14 @@ fn default_true() -> bool {
     true
 }
 
+const fn default_trash_days() -> u32 {
+    DEFAULT_TRASH_DAYS
+}
+
+const fn default_max_body_words() -> u32 {
+    DEFAULT_MAX_BODY_WORDS
+}
+
 fn default_id_pattern() -> String {
     DEFAULT_PATTERN.to_string()
 }
@@ -240,18 +481,49 @@ impl GlobalConfig {
     /// Required fields are always written with actual values.
     /// Optional personalization fields (`user_name`, `editor`) are shown as
     /// commented examples when not set.
-    fn save_with_comments(path: &PathBuf, config: &Self) -> Result<()> {
-        // Helper to format optional personalization fields (commented when not set)
-        let format_personalization = |value: &Option<String>, key: &str, example: &str| {
-            value.as_ref().map_or_else(
-                || format!("# {key} = \"{example}\""),
-                |v| format!("{key} = \"{v}\""),
-            )
-        };
-
+    #[allow(clippy::too_many_lines)] // Mostly the commented config template
+    fn save_with_comments(config_path: &PathBuf, config: &Self) -> Result<()> {
         // Personalization fields: commented when not set
-        let user_name_line = format_personalization(&config.user_name, "user_name", "Your Name");
-        let editor_line = format_personalization(&config.editor, "editor", "nvim");
+        let user_name_line =
+            Self::format_personalization(config.user_name.as_ref(), "user_name", "Your Name");
+        let editor_line = Self::format_personalization(config.editor.as_ref(), "editor", "nvim");
+        let profile_line = Self::format_personalization(config.profile.as_ref(), "profile", "work");
+        let picker_line = Self::format_personalization(config.picker.as_ref(), "picker", "fzf");
+        let pre_create_line = Self::format_personalization(
+            config.pre_create.as_ref(),
+            "pre_create",
+            "./scripts/check-new.sh",
+        );
+        let pre_close_line = Self::format_personalization(
+            config.pre_close.as_ref(),
+            "pre_close",
+            "./scripts/check-done.sh",
+        );
+        let agent_command_line = Self::format_personalization(
+            config.agent_command.as_ref(),
+            "agent_command",
+            "my-agent --task {path}",
+        );
+        let link_base_line = Self::format_personalization(
+            config.link_base.as_ref(),
+            "link_base",
+            "https://github.com/org/repo/blob/main/{path}",
+        );
+
+        let attachment_options = Self::format_attachment_options(config);
+
+        let identities_section = Self::format_identities(&config.identities)?;
+        let aliases_section = Self::format_aliases(&config.aliases)?;
+        let defaults_section = Self::format_defaults(&config.defaults)?;
+        let mirrors_section = Self::format_mirrors(&config.mirrors)?;
+        let slug_transliterations_section =
+            Self::format_slug_transliterations(&config.slug_transliterations)?;
+        let status_dirs_section = Self::format_status_dirs(&config.status_dirs)?;
+        let profiles_section = Self::format_profiles(&config.profiles)?;
+        let rules_section = Self::format_rules(&config.rules)?;
+        let protected_section = Self::format_protected(&config.protected)?;
+        let attachment_policy_section = Self::format_attachment_policy(&config.attachment_policy)?;
+        let labels_section = Self::format_labels(&config.labels)?;
 
         // Required fields: always written with effective values
         let stack_dir_line = format!("stack_dir = \"{}\"", config.stack_dir());
@@ -280,11 +552,21 @@ use_git_user = {use_git_user}
 # If not set, falls back to $VISUAL, then $EDITOR, then "vi".
 {editor_line}
 
+# Profile to use by default (see [profiles.<name>] below). A project's `profile`
+# option, the QS_PROFILE environment variable, or `--profile` select another.
+{profile_line}
+
 # Whether to enable interactive mode (opens editor, shows selection dialogs).
 # Set to false for scripting or if you prefer to edit files manually.
 # Default: true
 interactive = {interactive}
 
+# Selector used to pick items: "builtin" (default), "fzf" (or "external"), or a
+# command run through the shell that reads one option per line on stdin and
+# prints the chosen line. {{prompt}} and {{header}} are replaced by the prompt
+# and column header. `--picker` overrides it for one command.
+{picker_line}
+
 # Pattern for generating unique item IDs.
 # Default: "%y%m%d-%T%RRR" (e.g., "260109-0A2BK4M")
 #
@@ -316,23 +598,447 @@ interactive = {interactive}
 # Default: ".archive"
 {archive_dir_line}
 
+# How closed items are grouped in the archive:
+#   "flat"     - .archive/<category>/<item>
+#   "by-year"  - .archive/2026/<category>/<item>
+#   "by-month" - .archive/2026/01/<category>/<item>
+# The date is the day the item is closed. Items already archived stay where they
+# are and are found in any layout. Default: "flat"
+archive_layout = "{archive_layout}"
+
 # Default subdirectory name for templates within the queuestack directory.
 # Used when initializing new projects. Can be overridden per-project.
 # Default: ".templates"
 {template_dir_line}
+
+# What to do when .queuestack files exist at several ancestor levels (monorepos):
+#   "nearest"  - the innermost project wins
+#   "explicit" - nested projects require --project-root to pick one
+#   "error"    - nested projects are an error
+# `qs which` shows the resolved project. Default: "nearest"
+nested_projects = "{nested_projects}"
+
+# Days deleted items stay in the .trash/ directory (restore them with
+# `qs trash restore`) before they are purged. 0 keeps them until removed by hand.
+# Default: 30
+trash_days = {trash_days}
+
+# Never modify files or directories in read-only commands (list, show, info,
+# `qs trash list`, ...): expired trash is only purged by commands that write,
+# and git status runs without refreshing the index. For repositories where
+# any change triggers CI.
+# Default: false
+pure_reads = {pure_reads}
+
+# Item bodies longer than this many words get a warning when saved (consider
+# splitting the item), and `qs search --full-text` skips their bodies unless
+# --include-large is given. 0 disables the limit.
+# Default: 5000
+max_body_words = {max_body_words}
+
+# How item timestamps (created_at, closed_at) are written:
+#   "rfc3339" - 2026-01-15T09:30:00.123Z (fractional seconds when present)
+#   "seconds" - 2026-01-15T09:30:00Z
+#   "minutes" - 2026-01-15T09:30Z
+#   "date"    - 2026-01-15
+# Timestamps in other common formats are read either way. Default: "rfc3339"
+date_format = "{date_format}"
+
+# Frontmatter syntax of new items: "yaml" (--- delimiters) or "toml"
+# (+++ delimiters, as used by Hugo). Existing items keep their syntax, and
+# both are read either way. Default: "yaml"
+frontmatter_format = "{frontmatter_format}"
+
+# How letters outside ASCII appear in item file names:
+#   "unicode"       - kept as they are (fehlermeldung-prüfen, 日本語)
+#   "transliterate" - spelled in Latin where a table exists: diacritics are
+#                     removed, Greek, Cyrillic, kana, and Hangul are romanized
+#                     (fehlermeldung-prufen, roguin, hangeul)
+# Items are renamed on their next save. Default: "unicode"
+slug_style = "{slug_style}"
+
+# Labels, categories, authors, and search queries are always compared ignoring
+# case ("Bug" and "bug" are the same label). Whether diacritics are ignored too,
+# so "cafe" finds "Café". Default: false
+fold_accents = {fold_accents}
+
+# Version control system used to move and remove item files, read your name
+# (with use_git_user), and read the history for `qs stats` and `qs activity`:
+#   "auto" - detected from the project root: the nearest .jj, .hg, or .git
+#            (colocated jj repositories use jj)
+#   "git", "jj" (Jujutsu), or "hg" (Mercurial)
+# Default: "auto"
+vcs = "{vcs}"
+
+# Whether to record the commands you run (name and time only, never arguments)
+# in usage.jsonl next to this file, for `qs insights`. Nothing is ever sent
+# anywhere. Only read from this file, never from project configs. Default: false
+usage_log = {usage_log}
+
+# Whether to keep the queuestack directory usable as an Obsidian vault.
+# Item links use [[wikilink]] syntax, labels are mirrored as #tags in a footer
+# at the end of each item body, and an _index.md note is kept per category.
+# Default: false
+obsidian = {obsidian}
+
+# Policy scripts that gate operations. The script runs from the project root;
+# a non-zero exit blocks the operation and its stderr is shown.
+# The item is passed via environment variables: QS_ID, QS_TITLE, QS_AUTHOR,
+# QS_STATUS, QS_LABELS (comma-separated), QS_CATEGORY, QS_FILE (pre_close only).
+{pre_create_line}
+{pre_close_line}
+
+# Command `qs agent` runs for each matching item, through the shell from the
+# project root. {{id}} and {{path}} are replaced by the item's ID and file; the
+# item is also passed as QS_ID, QS_TITLE, QS_FILE, QS_LABELS, and QS_CATEGORY.
+{agent_command_line}
+
+# Link printed by `qs url` and understood by `qs resolve`. {{path}} is replaced by
+# the item file (relative to the project root) and {{id}} by the item ID. If not
+# set, links point to the file on the web page of the `origin` remote (GitHub,
+# GitLab, Bitbucket, and similar hosts), or use "qs://{{id}}" without a remote.
+{link_base_line}
+
+{attachment_options}
+
+# Author identities, like git's .mailmap: each key is a canonical name and its
+# value lists other spellings and emails of the same person. Used by --author
+# filters, stats, new item authors, and `assignee` patches.
+{identities_section}
+
+# Named profiles for contributing under different identities. A profile's
+# user_name, editor, and id_pattern replace the values above; project settings
+# still take precedence.
+{profiles_section}
+
+# Command aliases, expanded before the command line is parsed (like git aliases).
+# Extra arguments are appended; aliases can't replace built-in commands.
+{aliases_section}
+
+# Default options per command, applied unless given on the command line. Keys
+# are option names; use true for flags and an array for repeatable options.
+# A default flag is countered by its opposite (-i for no_interactive).
+{defaults_section}
+
+# Replacements applied to titles before they become file names, matched
+# case-insensitively and before slug_style (e.g. German umlauts as ue, oe, ae).
+{slug_transliterations_section}
+
+# Directories inside the stack that open and in-progress items live in, so the
+# directory structure shows the workflow state. Items move when their status
+# changes; categories go below the status directory. Without an entry, items
+# of that status stay in the stack root.
+{status_dirs_section}
+
+# Read-only mirrors of other projects' stacks, listed by `qs list` and `qs search`
+# with a [name] marker. A value is a path to another project (relative to the
+# project root) or a git URL, cloned shallowly by `qs mirror fetch`.
+{mirrors_section}
+
+# Protected categories (including subcategories) and labels. Deleting items,
+# removing their attachments, and exporting them requires --force, unless your
+# user name is listed in `allow`.
+{protected_section}
+
+# Restrictions on file attachments: the largest file (500KB, 10MB, 1GB), the
+# allowed extensions, and a scanner command run with the file as its last
+# argument (a non-zero exit rejects it). Directories are always rejected.
+{attachment_policy_section}
+
+# Label policy: labels are normalized to `case` ("lower", "upper", or
+# "preserve") when written; new labels must consist of `charset` characters (a
+# regex character class) and be at most `max_length` characters long.
+# Defined labels keep their spelling, show their color (a name or #rrggbb) in
+# listings and their description in `qs list --labels`; with `strict = true`
+# only defined labels can be added. `qs validate --fix` normalizes existing labels.
+{labels_section}
+
+# Auto-labeling rules, applied by `qs new` and retroactively by `qs rules apply`.
+# A rule matches when its `title` and/or `body` regex (case-insensitive) matches;
+# it then adds `labels` and fills in `set` custom fields that aren't set yet.
+{rules_section}
 "#,
-            user_name_line = user_name_line,
             use_git_user = config.use_git_user,
-            editor_line = editor_line,
             interactive = config.interactive,
-            id_pattern_line = id_pattern_line,
-            stack_dir_line = stack_dir_line,
-            archive_dir_line = archive_dir_line,
-            template_dir_line = template_dir_line,
+            obsidian = config.obsidian,
+            archive_layout = config.archive_layout,
+            nested_projects = config.nested_projects,
+            trash_days = config.trash_days,
+            pure_reads = config.pure_reads,
+            max_body_words = config.max_body_words,
+            date_format = config.date_format,
+            frontmatter_format = config.frontmatter_format,
+            slug_style = config.slug_style,
+            fold_accents = config.fold_accents,
+            vcs = config.vcs,
+            usage_log = config.usage_log,
         );
 
-        fs::write(path, content)
-            .with_context(|| format!("Failed to write global config: {}", path.display()))
+        fs::write(config_path, content)
+            .with_context(|| format!("Failed to write global config: {}", config_path.display()))
+    }
+
+    /// Formats an optional personalization field (commented example when not set).
+    fn format_personalization(value: Option<&String>, key: &str, example: &str) -> String {
+        value.map_or_else(
+            || format!("# {key} = \"{example}\""),
+            |v| format!("{key} = \"{v}\""),
+        )
+    }
+
+    /// Formats the attachment storage options (commented examples when not set).
+    fn format_attachment_options(config: &Self) -> String {
+        let attachment_path_line = Self::format_personalization(
+            config.attachment_path.as_ref(),
+            "attachment_path",
+            "../attachments/{id}",
+        );
+        let s3_url_line = Self::format_personalization(
+            config.s3_url.as_ref(),
+            "s3_url",
+            "s3://bucket/queuestack",
+        );
+
+        format!(
+            r#"# Store file attachments outside the repository ({{id}} is replaced by the item ID;
+# relative paths resolve against the project root). Default: next to each item.
+{attachment_path_line}
+
+# Upload file attachments to S3-compatible object storage instead (requires a
+# build with the 's3' feature and the aws CLI; set AWS_ENDPOINT_URL for non-AWS
+# providers). Items reference the s3:// object URL.
+{s3_url_line}
+
+# Store identical file attachments once in a shared store and hard-link them
+# into each item's attachment directory (checksums are always recorded).
+# Default: false
+attachment_dedup = {attachment_dedup}
+
+# How a directory given to `qs attach add` is attached: "files" attaches every
+# file below it (hidden files are skipped), "archive" attaches one .tgz of it.
+# Default: "files"
+attachment_directories = "{attachment_directories}"
+
+# Fetch the page title of URL attachments (with curl) and show it in attachment
+# listings and Obsidian footers.
+# Default: false
+fetch_link_titles = {fetch_link_titles}"#,
+            attachment_dedup = config.attachment_dedup,
+            attachment_directories = config.attachment_directories,
+            fetch_link_titles = config.fetch_link_titles,
+        )
+    }
+
+    /// Formats the `[aliases]` table for the commented config file.
+    ///
+    /// Existing aliases are written back as-is; without any, a commented example is shown.
+    fn format_aliases(aliases: &BTreeMap<String, String>) -> Result<String> {
+        #[derive(Serialize)]
+        struct Aliases<'a> {
+            aliases: &'a BTreeMap<String, String>,
+        }
+
+        if aliases.is_empty() {
+            return Ok("# [aliases]\n# bug = \"new --category bugs --label=bug\"".to_string());
+        }
+
+        let content =
+            toml::to_string(&Aliases { aliases }).context("Failed to serialize aliases")?;
+        Ok(content.trim_end().to_string())
+    }
+
+    /// Formats the `[defaults.<command>]` tables for the commented config file.
+    ///
+    /// Existing defaults are written back as-is; without any, a commented example is shown.
+    fn format_defaults(defaults: &Defaults) -> Result<String> {
+        #[derive(Serialize)]
+        struct Tables<'a> {
+            defaults: &'a Defaults,
+        }
+
+        if defaults.is_empty() {
+            return Ok("# [defaults.list]\n# sort = \"date\"\n# no_interactive = true".to_string());
+        }
+
+        let content =
+            toml::to_string(&Tables { defaults }).context("Failed to serialize defaults")?;
+        Ok(content.trim_end().to_string())
+    }
+
+    /// Formats the `[mirrors]` table for the commented config file.
+    ///
+    /// Existing mirrors are written back as-is; without any, a commented example is shown.
+    fn format_mirrors(mirrors: &BTreeMap<String, String>) -> Result<String> {
+        #[derive(Serialize)]
+        struct Mirrors<'a> {
+            mirrors: &'a BTreeMap<String, String>,
+        }
+
+        if mirrors.is_empty() {
+            return Ok(
+                "# [mirrors]\n# upstream = \"https://github.com/example/upstream.git\"".to_string(),
+            );
+        }
+
+        let content =
+            toml::to_string(&Mirrors { mirrors }).context("Failed to serialize mirrors")?;
+        Ok(content.trim_end().to_string())
+    }
+
+    /// Formats the `[slug_transliterations]` table for the commented config file.
+    ///
+    /// Existing entries are written back as-is; without any, a commented example is shown.
+    fn format_slug_transliterations(table: &BTreeMap<String, String>) -> Result<String> {
+        #[derive(Serialize)]
+        struct Transliterations<'a> {
+            slug_transliterations: &'a BTreeMap<String, String>,
+        }
+
+        if table.is_empty() {
+            return Ok(
+                "# [slug_transliterations]\n# \"ü\" = \"ue\"\n# \"c++\" = \"cpp\"".to_string(),
+            );
+        }
+
+        let content = toml::to_string(&Transliterations {
+            slug_transliterations: table,
+        })
+        .context("Failed to serialize slug transliterations")?;
+        Ok(content.trim_end().to_string())
+    }
+
+    /// Formats the `[status_dirs]` table for the commented config file.
+    ///
+    /// Existing entries are written back as-is; without any, a commented example is shown.
+    fn format_status_dirs(table: &BTreeMap<String, String>) -> Result<String> {
+        #[derive(Serialize)]
+        struct StatusDirs<'a> {
+            status_dirs: &'a BTreeMap<String, String>,
+        }
+
+        if table.is_empty() {
+            return Ok("# [status_dirs]\n# in-progress = \"doing\"".to_string());
+        }
+
+        let content = toml::to_string(&StatusDirs { status_dirs: table })
+            .context("Failed to serialize status directories")?;
+        Ok(content.trim_end().to_string())
+    }
+
+    /// Formats the `[protected]` table for the commented config file.
+    ///
+    /// Existing settings are written back as-is; without any, a commented example is shown.
+    fn format_protected(protected: &Protected) -> Result<String> {
+        #[derive(Serialize)]
+        struct Section<'a> {
+            protected: &'a Protected,
+        }
+
+        if protected.is_empty() {
+            return Ok("# [protected]\n# categories = [\"security\"]\n# labels = [\"legal-hold\"]\n# allow = [\"Your Name\"]".to_string());
+        }
+
+        let content =
+            toml::to_string(&Section { protected }).context("Failed to serialize protected")?;
+        Ok(content.trim_end().to_string())
+    }
+
+    /// Formats the `[attachment_policy]` table for the commented config file.
+    ///
+    /// Existing settings are written back as-is; without any, a commented example is shown.
+    fn format_attachment_policy(policy: &AttachmentPolicy) -> Result<String> {
+        #[derive(Serialize)]
+        struct Section<'a> {
+            attachment_policy: &'a AttachmentPolicy,
+        }
+
+        if policy.is_empty() {
+            return Ok("# [attachment_policy]\n# max_size = \"10MB\"\n# extensions = [\"png\", \"jpg\", \"pdf\", \"log\", \"txt\"]\n# scanner = \"clamscan --no-summary\"".to_string());
+        }
+
+        let content = toml::to_string(&Section {
+            attachment_policy: policy,
+        })
+        .context("Failed to serialize attachment_policy")?;
+        Ok(content.trim_end().to_string())
+    }
+
+    /// Formats the `[labels]` table for the commented config file.
+    ///
+    /// Existing settings are written back as-is; without any, a commented example is shown.
+    fn format_labels(labels: &LabelPolicy) -> Result<String> {
+        #[derive(Serialize)]
+        struct Section<'a> {
+            labels: &'a LabelPolicy,
+        }
+
+        if labels.is_empty() {
+            return Ok(
+                "# [labels]\n# case = \"lower\"\n# charset = \"a-z0-9-\"\n# max_length = 24\n# strict = true\n#\n# [[labels.defined]]\n# name = \"bug\"\n# description = \"Something isn't working\"\n# color = \"red\""
+                    .to_string(),
+            );
+        }
+
+        let content = toml::to_string(&Section { labels }).context("Failed to serialize labels")?;
+        Ok(content.trim_end().to_string())
+    }
+
+    /// Formats the `[identities]` table for the commented config file.
+    ///
+    /// Existing identities are written back as-is; without any, a commented example is shown.
+    fn format_identities(identities: &BTreeMap<String, Vec<String>>) -> Result<String> {
+        #[derive(Serialize)]
+        struct Identities<'a> {
+            identities: &'a BTreeMap<String, Vec<String>>,
+        }
+
+        if identities.is_empty() {
+            return Ok(
+                "# [identities]\n# \"Dominic Rodemer\" = [\"Dom\", \"dom@example.com\"]"
+                    .to_string(),
+            );
+        }
+
+        let content = toml::to_string(&Identities { identities })
+            .context("Failed to serialize identities")?;
+        Ok(content.trim_end().to_string())
+    }
+
+    /// Formats the `[profiles.<name>]` tables for the commented config file.
+    ///
+    /// Existing profiles are written back as-is; without any, a commented example is shown.
+    fn format_profiles(profiles: &BTreeMap<String, Profile>) -> Result<String> {
+        #[derive(Serialize)]
+        struct Profiles<'a> {
+            profiles: &'a BTreeMap<String, Profile>,
+        }
+
+        if profiles.is_empty() {
+            return Ok(
+                "# [profiles.work]\n# user_name = \"Your Name\"\n# editor = \"code --wait\"\n# id_pattern = \"W%y%m%d-%RRR\"".to_string(),
+            );
+        }
+
+        let content =
+            toml::to_string(&Profiles { profiles }).context("Failed to serialize profiles")?;
+        Ok(content.trim_end().to_string())
+    }
+
+    /// Formats the `[[rules]]` tables for the commented config file.
+    ///
+    /// Existing rules are written back as-is; without rules, a commented example is shown.
+    fn format_rules(rules: &[Rule]) -> Result<String> {
+        #[derive(Serialize)]
+        struct Rules<'a> {
+            rules: &'a [Rule],
+        }
+
+        if rules.is_empty() {
+            return Ok("# [[rules]]\n# title = \"panic|crash\"\n# labels = [\"bug\"]\n# set = { priority = \"high\" }".to_string());
+        }
+
+        let content = toml::to_string(&Rules { rules }).context("Failed to serialize rules")?;
+        Ok(content.trim_end().to_string())
     }
 
     /// Returns the effective queuestack directory name
@@ -483,6 +1189,8 @@ mod tests {
         assert_eq!(config.stack_dir(), "queuestack");
         assert_eq!(config.archive_dir(), ".archive");
         assert_eq!(config.template_dir(), ".templates");
+        assert!(!config.obsidian);
+        assert!(!config.attachment_dedup);
     }
 
     #[test]
@@ -6,18 +6,154 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+pub mod alias;
+pub mod defaults;
 pub mod global;
 pub mod project;
 
-use std::path::{Path, PathBuf};
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 
+use self::project::PROJECT_CONFIG_FILE;
 pub use self::{
-    global::{set_home_override, ConfigValidation, GlobalConfig},
-    project::ProjectConfig,
+    global::{set_home_override, ConfigValidation, GlobalConfig, Profile},
+    project::{GithubSync, NestedProjects, ProjectConfig},
+};
+use crate::{
+    constants::{ATTACHMENT_PATH_ID, CACHE_DIR, NOTICES_DIR, PROFILE_ENV_VAR, TRASH_DIR},
+    id::DEFAULT_PATTERN,
+    item::{
+        dates::{self, DateFormat},
+        fold,
+        identity::Identities,
+        labels,
+        parser::{self, FrontmatterFormat},
+        rules::Rule,
+        slug::{self, SlugOptions, SlugStyle},
+    },
+    picker::Picker,
+    policy::{AttachmentPolicy, LabelPolicy, Protected},
+    storage::{
+        self,
+        sources::DirectoryAttachments,
+        vcs::{self, VcsKind},
+        ArchiveLayout,
+    },
 };
-use crate::{id::DEFAULT_PATTERN, storage::git};
+
+thread_local! {
+    /// Profile selected on the command line (`--profile`), taking precedence
+    /// over `QS_PROFILE` and the configured `profile`.
+    static PROFILE_OVERRIDE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+thread_local! {
+    /// Project root selected on the command line (`--project-root`), bypassing detection.
+    static PROJECT_ROOT_OVERRIDE: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+}
+
+thread_local! {
+    /// Picker selected on the command line (`--picker`), taking precedence
+    /// over the configured `picker`.
+    static PICKER_OVERRIDE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Sets the profile selected on the command line.
+pub fn set_profile_override(profile: Option<String>) {
+    PROFILE_OVERRIDE.with(|cell| {
+        *cell.borrow_mut() = profile;
+    });
+}
+
+/// Sets the project root selected on the command line.
+pub fn set_project_root_override(root: Option<PathBuf>) {
+    PROJECT_ROOT_OVERRIDE.with(|cell| {
+        *cell.borrow_mut() = root;
+    });
+}
+
+/// Sets the picker selected on the command line.
+pub fn set_picker_override(picker: Option<String>) {
+    PICKER_OVERRIDE.with(|cell| {
+        *cell.borrow_mut() = picker;
+    });
+}
+
+/// How the project root was resolved
+#[derive(Debug, Clone)]
+pub struct ProjectRoot {
+    /// The resolved project root
+    pub path: PathBuf,
+    /// Every directory with a .queuestack file from the start directory upward, nearest first
+    pub detected: Vec<PathBuf>,
+    /// Nested project policy in effect
+    pub policy: NestedProjects,
+    /// Whether the root was given with `--project-root`
+    pub explicit: bool,
+}
+
+impl ProjectRoot {
+    /// Resolves the project root from `--project-root` or the current directory,
+    /// applying the nested project policy.
+    pub fn resolve(global: &GlobalConfig) -> Result<Self> {
+        let cwd = crate::storage::paths::current_dir()
+            .map_err(|e| anyhow::anyhow!("Cannot get current directory: {e}"))?;
+        let explicit = PROJECT_ROOT_OVERRIDE
+            .with(|cell| cell.borrow().clone())
+            .map(|root| {
+                let root = cwd.join(root);
+                root.canonicalize()
+                    .map_or(root, |root| crate::storage::paths::simplified(&root))
+            });
+
+        if let Some(ref root) = explicit {
+            if !root.join(PROJECT_CONFIG_FILE).exists() {
+                bail!("No {PROJECT_CONFIG_FILE} file in {}", root.display());
+            }
+        }
+
+        let detected = ProjectConfig::find_project_roots(explicit.as_deref().unwrap_or(&cwd));
+        let Some(nearest) = detected.first().cloned() else {
+            bail!("Not in a queuestack project (no .queuestack file found)");
+        };
+
+        // The nearest project that sets a policy decides
+        let policy = detected
+            .iter()
+            .find_map(|root| ProjectConfig::load(root).ok()?.nested_projects)
+            .unwrap_or(global.nested_projects);
+
+        if detected.len() > 1 {
+            let list = detected
+                .iter()
+                .map(|root| format!("  {}", root.join(PROJECT_CONFIG_FILE).display()))
+                .collect::<Vec<_>>()
+                .join("\n");
+            match policy {
+                NestedProjects::Error => {
+                    bail!("Nested queuestack projects found (nested_projects = \"error\"):\n{list}")
+                }
+                NestedProjects::Explicit if explicit.is_none() => bail!(
+                    "Nested queuestack projects found (nested_projects = \"explicit\"):\n{list}\n\
+                     Choose one with --project-root"
+                ),
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            path: explicit.clone().unwrap_or(nearest),
+            detected,
+            policy,
+            explicit: explicit.is_some(),
+        })
+    }
+}
 
 /// Merged configuration with project settings overriding global
 #[derive(Debug, Clone)]
@@ -28,6 +164,9 @@ pub struct Config {
     /// Project configuration (private - use resolution methods)
     project: ProjectConfig,
 
+    /// Active profile from the global config (empty if none is selected)
+    profile: Profile,
+
     /// Resolved project root path
     project_root: PathBuf,
 }
@@ -36,43 +175,97 @@ impl Config {
     /// Loads configuration from both global and project sources
     pub fn load() -> Result<Self> {
         let global = GlobalConfig::load()?;
-
-        let project_root = ProjectConfig::find_project_root().ok_or_else(|| {
-            anyhow::anyhow!("Not in a queuestack project (no .queuestack file found)")
-        })?;
+        let project_root = ProjectRoot::resolve(&global)?.path;
 
         let project = ProjectConfig::load(&project_root)?;
+        let profile = active_profile(&global, &project)?;
 
-        Ok(Self {
+        let config = Self {
             global,
             project,
+            profile,
             project_root,
-        })
+        };
+        config.apply_globals()?;
+        storage::check_status_dirs(&config)?;
+        Ok(config)
+    }
+
+    /// Sets the thread-local settings read outside of `Config` (date format,
+    /// frontmatter format, slugs, accent folding, label colors, VCS).
+    ///
+    /// [`Config::load`] applies them to the loading thread; other threads that
+    /// work with the config (like the daemon's connection threads) must call
+    /// this first.
+    pub fn apply_globals(&self) -> Result<()> {
+        dates::set_timestamp_format(self.date_format());
+        parser::set_default_format(self.frontmatter_format());
+        slug::set_options(SlugOptions {
+            style: self.slug_style(),
+            transliterations: self.slug_transliterations(),
+        });
+        fold::set_fold_accents(self.fold_accents());
+        labels::set_colors(&self.label_policy().defined)?;
+        vcs::select(self.vcs(), &self.project_root);
+        vcs::set_notice_marker(
+            GlobalConfig::dir().map(|dir| dir.join(NOTICES_DIR).join(self.project_key())),
+        );
+        Ok(())
     }
 
     /// Creates a config for initialization (no existing project required)
     pub fn for_init() -> Result<Self> {
         let global = GlobalConfig::load()?;
-        let project_root = std::env::current_dir()
+        let cwd = crate::storage::paths::current_dir()
             .map_err(|e| anyhow::anyhow!("Cannot get current directory: {e}"))?;
+        let project_root = PROJECT_ROOT_OVERRIDE
+            .with(|cell| cell.borrow().clone())
+            .map_or_else(|| cwd.clone(), |root| cwd.join(root));
         let project = ProjectConfig::default();
+        let profile = active_profile(&global, &project)?;
 
         Ok(Self {
             global,
             project,
+            profile,
             project_root,
         })
     }
 
+    /// Creates a config for a throwaway project with default settings, ignoring
+    /// the user's policy scripts, rules, and attachment storage
+    pub fn sandbox(project_root: PathBuf) -> Self {
+        Self {
+            global: GlobalConfig::default(),
+            project: ProjectConfig::default(),
+            profile: Profile::default(),
+            project_root,
+        }
+    }
+
+    /// Creates a config for reading a mirrored project's stack
+    ///
+    /// Uses the mirror's own `.queuestack` (if any) for its directory layout and
+    /// defaults for everything else, since its users' global configs are unknown.
+    pub fn mirror(project_root: PathBuf) -> Self {
+        Self {
+            global: GlobalConfig::default(),
+            project: ProjectConfig::load(&project_root).unwrap_or_default(),
+            profile: Profile::default(),
+            project_root,
+        }
+    }
+
     // -------------------------------------------------------------------------
     // Resolution methods: project overrides global
     // -------------------------------------------------------------------------
 
-    /// Returns the effective ID pattern (project overrides global)
+    /// Returns the effective ID pattern (project overrides profile overrides global)
     pub fn id_pattern(&self) -> &str {
         self.project
             .id_pattern
             .as_deref()
+            .or(self.profile.id_pattern.as_deref())
             .unwrap_or(&self.global.id_pattern)
     }
 
@@ -92,6 +285,13 @@ impl Config {
             .unwrap_or_else(|| self.global.archive_dir())
     }
 
+    /// Returns how closed items are grouped in the archive (project overrides global)
+    pub fn archive_layout(&self) -> ArchiveLayout {
+        self.project
+            .archive_layout
+            .unwrap_or(self.global.archive_layout)
+    }
+
     /// Returns the effective template directory name (project overrides global)
     pub fn template_dir(&self) -> &str {
         self.project
@@ -112,24 +312,225 @@ impl Config {
         self.project.interactive.unwrap_or(self.global.interactive)
     }
 
-    /// Returns the effective user name (project overrides global)
-    pub fn user_name(&self) -> Option<String> {
-        // First check project-level user_name
-        if let Some(ref name) = self.project.user_name {
-            return Some(name.clone());
+    /// Selector for picking items (`--picker` overrides project overrides global)
+    pub fn picker(&self) -> Picker {
+        PICKER_OVERRIDE
+            .with(|cell| cell.borrow().clone())
+            .or_else(|| self.project.picker.clone())
+            .or_else(|| self.global.picker.clone())
+            .map_or_else(Picker::default, |value| Picker::parse(&value))
+    }
+
+    /// Whether Obsidian vault mode is enabled (project overrides global)
+    pub fn obsidian(&self) -> bool {
+        self.project.obsidian.unwrap_or(self.global.obsidian)
+    }
+
+    /// Returns the effective `pre_create` policy script (project overrides global)
+    pub fn pre_create(&self) -> Option<String> {
+        self.project
+            .pre_create
+            .clone()
+            .or_else(|| self.global.pre_create.clone())
+    }
+
+    /// Returns the effective `pre_close` policy script (project overrides global)
+    pub fn pre_close(&self) -> Option<String> {
+        self.project
+            .pre_close
+            .clone()
+            .or_else(|| self.global.pre_close.clone())
+    }
+
+    /// Returns the effective `qs agent` command (project overrides global)
+    pub fn agent_command(&self) -> Option<String> {
+        self.project
+            .agent_command
+            .clone()
+            .or_else(|| self.global.agent_command.clone())
+    }
+
+    /// Returns the configured template for item links (project overrides global)
+    pub fn link_base(&self) -> Option<String> {
+        self.project
+            .link_base
+            .clone()
+            .or_else(|| self.global.link_base.clone())
+    }
+
+    /// Returns the effective auto-labeling rules (project rules replace global rules)
+    pub fn rules(&self) -> &[Rule] {
+        self.project.rules.as_deref().unwrap_or(&self.global.rules)
+    }
+
+    /// Returns the effective protected categories and labels (project table replaces global)
+    pub fn protected(&self) -> &Protected {
+        self.project
+            .protected
+            .as_ref()
+            .unwrap_or(&self.global.protected)
+    }
+
+    /// Returns the effective attachment restrictions (project table replaces global)
+    pub fn attachment_policy(&self) -> &AttachmentPolicy {
+        self.project
+            .attachment_policy
+            .as_ref()
+            .unwrap_or(&self.global.attachment_policy)
+    }
+
+    /// Returns the effective label restrictions (project table replaces global)
+    pub fn label_policy(&self) -> &LabelPolicy {
+        self.project.labels.as_ref().unwrap_or(&self.global.labels)
+    }
+
+    /// Returns the GitHub sync settings (project config only)
+    pub fn github(&self) -> GithubSync {
+        self.project.github.clone().unwrap_or_default()
+    }
+
+    /// Returns the effective external attachment path template (project overrides global)
+    pub fn attachment_path(&self) -> Option<String> {
+        self.project
+            .attachment_path
+            .clone()
+            .or_else(|| self.global.attachment_path.clone())
+    }
+
+    /// Returns the effective object storage location for attachments (project overrides global)
+    pub fn s3_url(&self) -> Option<String> {
+        self.project
+            .s3_url
+            .clone()
+            .or_else(|| self.global.s3_url.clone())
+    }
+
+    /// Days deleted items are kept in the trash, 0 for no limit (project overrides global)
+    pub fn trash_days(&self) -> u32 {
+        self.project.trash_days.unwrap_or(self.global.trash_days)
+    }
+
+    /// Whether read-only commands must not modify any file (project overrides global)
+    pub fn pure_reads(&self) -> bool {
+        self.project.pure_reads.unwrap_or(self.global.pure_reads)
+    }
+
+    /// Body length in words above which an item counts as oversized, `None`
+    /// if disabled (project overrides global)
+    pub fn max_body_words(&self) -> Option<usize> {
+        let words = self
+            .project
+            .max_body_words
+            .unwrap_or(self.global.max_body_words);
+        (words > 0).then_some(words as usize)
+    }
+
+    /// How item timestamps are written (project overrides global)
+    pub fn date_format(&self) -> DateFormat {
+        self.project.date_format.unwrap_or(self.global.date_format)
+    }
+
+    /// Frontmatter syntax of new items (project overrides global)
+    pub fn frontmatter_format(&self) -> FrontmatterFormat {
+        self.project
+            .frontmatter_format
+            .unwrap_or(self.global.frontmatter_format)
+    }
+
+    /// Whether commands are recorded in the local usage log (global only)
+    pub const fn usage_log(&self) -> bool {
+        self.global.usage_log
+    }
+
+    /// Whether identical file attachments are deduplicated (project overrides global)
+    pub fn attachment_dedup(&self) -> bool {
+        self.project
+            .attachment_dedup
+            .unwrap_or(self.global.attachment_dedup)
+    }
+
+    /// How directories given as attachments are attached (project overrides global)
+    pub fn attachment_directories(&self) -> DirectoryAttachments {
+        self.project
+            .attachment_directories
+            .unwrap_or(self.global.attachment_directories)
+    }
+
+    /// Whether page titles of URL attachments are fetched (project overrides global)
+    pub fn fetch_link_titles(&self) -> bool {
+        self.project
+            .fetch_link_titles
+            .unwrap_or(self.global.fetch_link_titles)
+    }
+
+    /// Returns the mirrored stacks by name (project entries are merged over global ones)
+    pub fn mirrors(&self) -> BTreeMap<String, String> {
+        let mut mirrors = self.global.mirrors.clone();
+        if let Some(ref project) = self.project.mirrors {
+            mirrors.extend(project.clone());
+        }
+        mirrors
+    }
+
+    /// Returns the effective slug style (project overrides global)
+    pub fn slug_style(&self) -> SlugStyle {
+        self.project.slug_style.unwrap_or(self.global.slug_style)
+    }
+
+    /// Whether filters and search ignore diacritics (project overrides global)
+    pub fn fold_accents(&self) -> bool {
+        self.project
+            .fold_accents
+            .unwrap_or(self.global.fold_accents)
+    }
+
+    /// Returns the version control system setting (project overrides global)
+    pub fn vcs(&self) -> VcsKind {
+        self.project.vcs.unwrap_or(self.global.vcs)
+    }
+
+    /// Returns the title replacements applied before slugging (project entries
+    /// are merged over global ones)
+    pub fn slug_transliterations(&self) -> BTreeMap<String, String> {
+        let mut table = self.global.slug_transliterations.clone();
+        if let Some(ref project) = self.project.slug_transliterations {
+            table.extend(project.clone());
         }
+        table
+    }
 
-        // Then check global user_name
-        if let Some(ref name) = self.global.user_name {
-            return Some(name.clone());
+    /// Returns the directories of open and in-progress items, keyed by status
+    /// (project entries are merged over global ones)
+    pub fn status_dirs(&self) -> BTreeMap<String, String> {
+        let mut table = self.global.status_dirs.clone();
+        if let Some(ref project) = self.project.status_dirs {
+            table.extend(project.clone());
         }
+        table
+    }
 
-        // Then try git config if enabled
-        if self.use_git_user() {
-            return git::user_name();
+    /// Returns the author identities (project entries are merged over global ones)
+    pub fn identities(&self) -> Identities {
+        let mut identities = Identities::new(&self.global.identities);
+        if let Some(ref project) = self.project.identities {
+            identities.extend(project);
         }
+        identities
+    }
+
+    /// Returns the effective user name (project overrides profile overrides global),
+    /// mapped to its canonical identity
+    pub fn user_name(&self) -> Option<String> {
+        // Project, profile, then global user_name, then the VCS config if enabled
+        let name = self
+            .project
+            .user_name
+            .clone()
+            .or_else(|| self.profile.user_name.clone())
+            .or_else(|| self.global.user_name.clone())
+            .or_else(|| self.use_git_user().then(vcs::user_name).flatten())?;
 
-        None
+        Some(self.identities().canonical(&name).to_string())
     }
 
     /// Returns the effective user name, prompting if not available.
@@ -150,11 +551,12 @@ impl Config {
         )
     }
 
-    /// Returns the effective editor command (project overrides global)
+    /// Returns the effective editor command (project overrides profile overrides global)
     pub fn editor(&self) -> Option<String> {
         self.project
             .editor
             .clone()
+            .or_else(|| self.profile.editor.clone())
             .or_else(|| self.global.editor.clone())
             .or_else(|| std::env::var("VISUAL").ok())
             .or_else(|| std::env::var("EDITOR").ok())
@@ -169,6 +571,29 @@ impl Config {
         &self.project_root
     }
 
+    /// Returns a stable key identifying the project, derived from its root path.
+    ///
+    /// Used to name per-project files outside the project (daemon socket, drafts).
+    pub fn project_key(&self) -> String {
+        // FNV-1a keeps the key stable across builds and platforms
+        let hash = self
+            .project_root
+            .to_string_lossy()
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            });
+        format!("{hash:016x}")
+    }
+
+    /// Returns the project's alias for qualified IDs (`alias:260109-ABC`): the
+    /// name of the project root directory.
+    pub fn project_alias(&self) -> String {
+        self.project_root
+            .file_name()
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned())
+    }
+
     /// Returns the queuestack directory path
     pub fn stack_path(&self) -> PathBuf {
         self.project_root.join(self.stack_dir())
@@ -179,22 +604,77 @@ impl Config {
         self.stack_path().join(self.archive_dir())
     }
 
+    /// Returns the trash directory path
+    pub fn trash_path(&self) -> PathBuf {
+        self.stack_path().join(TRASH_DIR)
+    }
+
+    /// Returns the directory of runtime state kept out of version control
+    pub fn cache_path(&self) -> PathBuf {
+        self.stack_path().join(CACHE_DIR)
+    }
+
     /// Returns the template directory path
     pub fn template_path(&self) -> PathBuf {
         self.stack_path().join(self.template_dir())
     }
 
-    /// Returns path to a category subdirectory within queuestack
-    pub fn category_path(&self, category: &str) -> PathBuf {
-        self.stack_path().join(category)
+    /// Returns the external attachment directory for an item, if `attachment_path` is set.
+    ///
+    /// Without an `{id}` placeholder, a subdirectory per item ID is used.
+    pub fn external_attachment_dir(&self, id: &str) -> Option<PathBuf> {
+        let template = self.attachment_path()?;
+        let path = if template.contains(ATTACHMENT_PATH_ID) {
+            PathBuf::from(template.replace(ATTACHMENT_PATH_ID, id))
+        } else {
+            PathBuf::from(template).join(id)
+        };
+        Some(self.project_root.join(path))
+    }
+
+    /// Returns path to a category subdirectory within queuestack, inside the
+    /// given `[status_dirs]` directory if any
+    pub fn category_path(&self, status_dir: Option<&str>, category: &str) -> PathBuf {
+        let mut path = self.stack_path();
+        path.extend(status_dir);
+        path.join(category)
+    }
+
+    /// Returns the name of the active profile, if one is selected
+    pub fn profile_name(&self) -> Option<String> {
+        active_profile_name(&self.global, &self.project)
     }
 
     /// Returns path relative to project root
     pub fn relative_path(&self, path: &Path) -> PathBuf {
-        path.strip_prefix(&self.project_root)
-            .map_or_else(|_| path.to_path_buf(), Path::to_path_buf)
+        crate::storage::paths::relative_to(path, &self.project_root)
+            .unwrap_or_else(|| path.to_path_buf())
     }
 }
 
 /// Default ID pattern constant re-export for convenience
 pub const DEFAULT_ID_PATTERN: &str = DEFAULT_PATTERN;
+
+/// Returns the name of the active profile, if any.
+fn active_profile_name(global: &GlobalConfig, project: &ProjectConfig) -> Option<String> {
+    PROFILE_OVERRIDE
+        .with(|cell| cell.borrow().clone())
+        .or_else(|| std::env::var(PROFILE_ENV_VAR).ok())
+        .filter(|name| !name.is_empty())
+        .or_else(|| project.profile.clone())
+        .or_else(|| global.profile.clone())
+}
+
+/// Resolves the active profile: `--profile`, then `QS_PROFILE`, then the
+/// project's `profile`, then the global `profile`.
+fn active_profile(global: &GlobalConfig, project: &ProjectConfig) -> Result<Profile> {
+    let Some(name) = active_profile_name(global, project) else {
+        return Ok(Profile::default());
+    };
+    match global.profiles.get(&name) {
+        Some(profile) => Ok(profile.clone()),
+        None => bail!(
+            "Unknown profile '{name}'. Define it as [profiles.{name}] in ~/.config/queuestack/config"
+        ),
+    }
+}
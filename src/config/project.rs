@@ -6,13 +6,22 @@
 //! Licensed under the MIT License.
 
 use std::{
-    env, fs,
+    collections::BTreeMap,
+    fmt, fs,
     path::{Path, PathBuf},
 };
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    item::{dates::DateFormat, fold, parser::FrontmatterFormat, rules::Rule, slug::SlugStyle},
+    policy::{AttachmentPolicy, LabelPolicy, Protected},
+    storage::{sources::DirectoryAttachments, vcs::VcsKind, ArchiveLayout},
+};
+
+use super::defaults::Defaults;
+
 /// Project configuration file name
 pub const PROJECT_CONFIG_FILE: &str = ".queuestack";
 
@@ -37,6 +46,10 @@ pub struct ProjectConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub interactive: Option<bool>,
 
+    /// Selector for picking items (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub picker: Option<String>,
+
     /// ID pattern override (overrides global)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id_pattern: Option<String>,
@@ -49,25 +62,200 @@ pub struct ProjectConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub archive_dir: Option<String>,
 
+    /// How closed items are grouped in the archive (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_layout: Option<ArchiveLayout>,
+
     /// Directory name for templates (overrides global)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub template_dir: Option<String>,
+
+    /// Whether to keep the stack directory usable as an Obsidian vault (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub obsidian: Option<bool>,
+
+    /// Policy script run before an item is created (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pre_create: Option<String>,
+
+    /// Policy script run before an item is closed (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pre_close: Option<String>,
+
+    /// Agent command run by `qs agent` for each matching item (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_command: Option<String>,
+
+    /// Template for shareable item links (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_base: Option<String>,
+
+    /// Auto-labeling rules (replace the global rules when set)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<Rule>>,
+
+    /// External attachment directory (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_path: Option<String>,
+
+    /// Object storage location for file attachments (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub s3_url: Option<String>,
+
+    /// Whether identical file attachments are stored once and hard-linked (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_dedup: Option<bool>,
+
+    /// How directories given as attachments are attached (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_directories: Option<DirectoryAttachments>,
+
+    /// Whether to fetch the page title of URL attachments (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fetch_link_titles: Option<bool>,
+
+    /// Author identities (merged over the global identities)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identities: Option<BTreeMap<String, Vec<String>>>,
+
+    /// Global config profile to use in this project (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+
+    /// Command aliases (merged over the global aliases)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aliases: Option<BTreeMap<String, String>>,
+
+    /// Default options per command (merged over the global defaults, option by option)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub defaults: Option<Defaults>,
+
+    /// Read-only mirrored stacks (merged over the global mirrors)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mirrors: Option<BTreeMap<String, String>>,
+
+    /// Nested project policy (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nested_projects: Option<NestedProjects>,
+
+    /// Protected categories and labels (replace the global settings when set)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protected: Option<Protected>,
+
+    /// Attachment restrictions (replace the global settings when set)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_policy: Option<AttachmentPolicy>,
+
+    /// Label restrictions (replace the global settings when set)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<LabelPolicy>,
+
+    /// Days deleted items stay in `.trash/` (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trash_days: Option<u32>,
+
+    /// Whether read-only commands leave every file untouched (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pure_reads: Option<bool>,
+
+    /// Body length in words above which saving warns (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_body_words: Option<u32>,
+
+    /// How item timestamps are written (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_format: Option<DateFormat>,
+
+    /// Frontmatter syntax of new items (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frontmatter_format: Option<FrontmatterFormat>,
+
+    /// How letters outside ASCII appear in item file names (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_style: Option<SlugStyle>,
+
+    /// Replacements applied to titles before slugging (merged over the global ones)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug_transliterations: Option<BTreeMap<String, String>>,
+
+    /// Whether filters and search also ignore diacritics (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fold_accents: Option<bool>,
+
+    /// Directories open and in-progress items live in (merged over the global ones)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_dirs: Option<BTreeMap<String, String>>,
+
+    /// Version control system used for item files (overrides global)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vcs: Option<VcsKind>,
+
+    /// GitHub repository and label mapping for `qs sync github` (project only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github: Option<GithubSync>,
+}
+
+/// Settings for `qs sync github`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GithubSync {
+    /// Repository as `owner/name` (the repository of the git remote if not set)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo: Option<String>,
+
+    /// Local label -> GitHub label; an empty name leaves the label out
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+}
+
+impl GithubSync {
+    /// Returns the GitHub label for a local label, or `None` if it is left out.
+    pub fn remote_label(&self, label: &str) -> Option<String> {
+        let mapped = self
+            .labels
+            .iter()
+            .find(|(local, _)| fold::eq(local, label))
+            .map_or(label, |(_, remote)| remote.as_str());
+        Some(mapped.trim().to_string()).filter(|name| !name.is_empty())
+    }
+}
+
+/// Policy for `.queuestack` files at several ancestor levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NestedProjects {
+    /// The innermost project wins
+    #[default]
+    Nearest,
+    /// Nested projects require `--project-root`
+    Explicit,
+    /// Nested projects are an error
+    Error,
+}
+
+impl fmt::Display for NestedProjects {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nearest => write!(f, "nearest"),
+            Self::Explicit => write!(f, "explicit"),
+            Self::Error => write!(f, "error"),
+        }
+    }
 }
 
 impl ProjectConfig {
     /// Finds the project root by searching for .queuestack file upward
     pub fn find_project_root() -> Option<PathBuf> {
-        let mut current = env::current_dir().ok()?;
-
-        loop {
-            if current.join(PROJECT_CONFIG_FILE).exists() {
-                return Some(current);
-            }
+        let cwd = crate::storage::paths::current_dir().ok()?;
+        Self::find_project_roots(&cwd).into_iter().next()
+    }
 
-            if !current.pop() {
-                return None;
-            }
-        }
+    /// Finds every directory from `start` upward that has a .queuestack file, nearest first
+    pub fn find_project_roots(start: &Path) -> Vec<PathBuf> {
+        start
+            .ancestors()
+            .filter(|dir| dir.join(PROJECT_CONFIG_FILE).exists())
+            .map(Path::to_path_buf)
+            .collect()
     }
 
     /// Returns the path to the project config file
@@ -103,8 +291,9 @@ impl ProjectConfig {
     ///
     /// All options are commented out by default, falling back to global config.
     /// This allows users to selectively override only what they need.
+    #[allow(clippy::too_many_lines)] // Mostly the commented config template
     pub fn save_with_comments(project_root: &Path) -> Result<()> {
-        let path = Self::path(project_root);
+        let config_path = Self::path(project_root);
 
         let content = r#"# queuestack Project Configuration
 # This file configures queuestack for this specific project.
@@ -123,10 +312,26 @@ impl ProjectConfig {
 # If not set, falls back to global config.
 # editor = "nvim"
 
+# Profile from the global config ([profiles.<name>]) to use in this project.
+# QS_PROFILE and `--profile` take precedence. If not set, falls back to global config.
+# profile = "work"
+
 # Whether to enable interactive mode (opens editor, shows selection dialogs).
 # If not set, falls back to global config.
 # interactive = true
 
+# Selector used to pick items: "builtin", "fzf" (or "external"), or a command
+# that reads one option per line on stdin and prints the chosen line.
+# {prompt} and {header} are replaced by the prompt and column header.
+# If not set, falls back to global config (default: "builtin").
+# picker = "fzf"
+
+# What to do when .queuestack files exist at several ancestor levels:
+# "nearest" (innermost project wins), "explicit" (require --project-root), or
+# "error". The nearest project that sets this decides.
+# If not set, falls back to global config (default: "nearest").
+# nested_projects = "nearest"
+
 # Pattern for generating unique item IDs.
 # If not set, falls back to global config.
 #
@@ -149,13 +354,214 @@ impl ProjectConfig {
 # If not set, falls back to global config (default: ".archive").
 # archive_dir = ".archive"
 
+# How closed items are grouped in the archive: "flat", "by-year"
+# (.archive/2026/...) or "by-month" (.archive/2026/01/...).
+# If not set, falls back to global config (default: "flat").
+# archive_layout = "flat"
+
 # Subdirectory name for templates within the queuestack directory.
 # If not set, falls back to global config (default: ".templates").
 # template_dir = ".templates"
+
+# Days deleted items stay in the .trash/ directory (restore them with
+# `qs trash restore`) before they are purged. 0 keeps them until removed by hand.
+# If not set, falls back to global config (default: 30).
+# trash_days = 30
+
+# Never modify files or directories in read-only commands: expired trash is
+# only purged by commands that write, and git status doesn't refresh the index.
+# If not set, falls back to global config (default: false).
+# pure_reads = true
+
+# Item bodies longer than this many words get a warning when saved, and
+# `qs search --full-text` skips their bodies unless --include-large is given.
+# 0 disables the limit.
+# If not set, falls back to global config (default: 5000).
+# max_body_words = 5000
+
+# How item timestamps (created_at, closed_at) are written: "rfc3339"
+# (2026-01-15T09:30:00.123Z), "seconds" (2026-01-15T09:30:00Z), "minutes"
+# (2026-01-15T09:30Z), or "date" (2026-01-15). Other common formats are read either way.
+# If not set, falls back to global config (default: "rfc3339").
+# date_format = "rfc3339"
+
+# Frontmatter syntax of new items: "yaml" (--- delimiters) or "toml" (+++
+# delimiters, as used by Hugo). Existing items keep their syntax.
+# If not set, falls back to global config (default: "yaml").
+# frontmatter_format = "yaml"
+
+# How letters outside ASCII appear in item file names: "unicode" (kept as they
+# are) or "transliterate" (spelled in Latin where a table exists: diacritics are
+# removed, Greek, Cyrillic, kana, and Hangul are romanized). Items are renamed
+# on their next save.
+# If not set, falls back to global config (default: "unicode").
+# slug_style = "transliterate"
+
+# Labels, categories, authors, and search queries are always compared ignoring
+# case. Whether diacritics are ignored too, so "cafe" finds "Café".
+# If not set, falls back to global config (default: false).
+# fold_accents = true
+
+# Version control system used to move and remove item files and read history:
+# "auto" (the nearest .jj, .hg, or .git), "git", "jj" (Jujutsu), or "hg"
+# (Mercurial).
+# If not set, falls back to global config (default: "auto").
+# vcs = "jj"
+
+# Whether to keep the queuestack directory usable as an Obsidian vault
+# ([[wikilinks]], #tags footer, per-category _index.md notes).
+# If not set, falls back to global config (default: false).
+# obsidian = true
+
+# Policy scripts that gate operations. The script runs from the project root;
+# a non-zero exit blocks the operation and its stderr is shown.
+# The item is passed via environment variables: QS_ID, QS_TITLE, QS_AUTHOR,
+# QS_STATUS, QS_LABELS (comma-separated), QS_CATEGORY, QS_FILE (pre_close only).
+# If not set, falls back to global config.
+# pre_create = "./scripts/check-new.sh"
+# pre_close = "./scripts/check-done.sh"
+
+# Command `qs agent` runs for each matching item, through the shell from the
+# project root. {id} and {path} are replaced by the item's ID and file; the
+# item is also passed as QS_ID, QS_TITLE, QS_FILE, QS_LABELS, and QS_CATEGORY.
+# If not set, falls back to global config.
+# agent_command = "my-agent --task {path}"
+
+# Link printed by `qs url` and understood by `qs resolve`. {path} is replaced by
+# the item file (relative to the project root) and {id} by the item ID.
+# If not set, falls back to global config (default: the file on the web page of
+# the `origin` remote, or "qs://{id}" without a remote).
+# link_base = "https://github.com/org/repo/blob/main/{path}"
+
+# Store file attachments outside the repository instead of in a
+# .attachments/ directory next to each item. {id} is replaced by the item ID;
+# relative paths resolve against the project root.
+# If not set, falls back to global config.
+# attachment_path = "../attachments/{id}"
+
+# Upload file attachments to S3-compatible object storage instead (requires a
+# build with the 's3' feature and the aws CLI).
+# If not set, falls back to global config.
+# s3_url = "s3://bucket/queuestack"
+
+# Store identical file attachments once in a shared store and hard-link them
+# into each item's attachment directory.
+# If not set, falls back to global config (default: false).
+# attachment_dedup = true
+
+# How a directory given to `qs attach add` is attached: "files" (every file
+# below it) or "archive" (one .tgz of it).
+# If not set, falls back to global config (default: "files").
+# attachment_directories = "archive"
+
+# Fetch the page title of URL attachments (with curl) and show it in attachment
+# listings and Obsidian footers.
+# If not set, falls back to global config (default: false).
+# fetch_link_titles = true
+
+# Author identities, like git's .mailmap: each key is a canonical name and its
+# value lists other spellings and emails of the same person. Used by --author
+# filters, stats, new item authors, and `assignee` patches.
+# Entries here are merged over the global identities.
+# [identities]
+# "Dominic Rodemer" = ["Dom", "dom@example.com"]
+
+# Command aliases, expanded before the command line is parsed (like git aliases).
+# Extra arguments are appended; aliases can't replace built-in commands.
+# Entries here are merged over the global aliases.
+# [aliases]
+# bug = "new --category bugs --label=bug"
+
+# Default options per command, applied unless given on the command line. Keys
+# are option names; use true for flags and an array for repeatable options.
+# Options here are merged over the global defaults for the same command.
+# [defaults.list]
+# sort = "date"
+# no_interactive = true
+
+# Replacements applied to titles before they become file names, matched
+# case-insensitively and before slug_style.
+# Entries here are merged over the global ones.
+# [slug_transliterations]
+# "ü" = "ue"
+# "c++" = "cpp"
+
+# Directories inside the stack that open and in-progress items live in, so the
+# directory structure shows the workflow state. Items move when their status
+# changes; categories go below the status directory.
+# Entries here are merged over the global ones.
+# [status_dirs]
+# in-progress = "doing"
+
+# Read-only mirrors of other projects' stacks, listed by `qs list` and `qs search`
+# with a [name] marker. A value is a path to another project (relative to the
+# project root) or a git URL, cloned shallowly by `qs mirror fetch`.
+# Entries here are merged over the global mirrors.
+# [mirrors]
+# upstream = "../upstream"
+# library = "https://github.com/example/library.git"
+
+# Protected categories (including subcategories) and labels. Deleting items,
+# removing their attachments, and exporting them requires --force, unless your
+# user name is listed in `allow`.
+# If set here, this table replaces the global one.
+# [protected]
+# categories = ["security"]
+# labels = ["legal-hold"]
+# allow = ["Your Name"]
+
+# Restrictions on file attachments: the largest file (500KB, 10MB, 1GB), the
+# allowed extensions, and a scanner command run with the file as its last
+# argument (a non-zero exit rejects it). Directories are always rejected.
+# If set here, this table replaces the global one.
+# [attachment_policy]
+# max_size = "10MB"
+# extensions = ["png", "jpg", "pdf", "log", "txt"]
+# scanner = "clamscan --no-summary"
+
+# Label policy: labels are normalized to `case` ("lower", "upper", or
+# "preserve") when written; new labels must consist of `charset` characters (a
+# regex character class) and be at most `max_length` characters long.
+# Defined labels keep their spelling, show their color (a name or #rrggbb) in
+# listings and their description in `qs list --labels`; with `strict = true`
+# only defined labels can be added. `qs validate --fix` normalizes existing labels.
+# If set here, this table replaces the global one.
+# [labels]
+# case = "lower"
+# charset = "a-z0-9-"
+# max_length = 24
+# strict = true
+#
+# [[labels.defined]]
+# name = "bug"
+# description = "Something isn't working"
+# color = "red"
+
+# GitHub Issues for `qs sync github` (uses the gh CLI). Active items without an
+# issue get one, and its number is stored in their `github_issue` field; titles,
+# bodies, and labels are pushed to the issue, and closing either side closes the
+# other. `repo` defaults to the repository of the git remote. [github.labels]
+# renames local labels on GitHub; an empty name leaves a label out.
+# Only read from the project config.
+# [github]
+# repo = "owner/name"
+#
+# [github.labels]
+# bug = "type: bug"
+# wip = ""
+
+# Auto-labeling rules, applied by `qs new` and retroactively by `qs rules apply`.
+# A rule matches when its `title` and/or `body` regex (case-insensitive) matches;
+# it then adds `labels` and fills in `set` custom fields that aren't set yet.
+# If any rules are set here, they replace the global rules.
+# [[rules]]
+# title = "panic|crash"
+# labels = ["bug"]
+# set = { priority = "high" }
 "#;
 
-        fs::write(&path, content)
-            .with_context(|| format!("Failed to write project config: {}", path.display()))
+        fs::write(&config_path, content)
+            .with_context(|| format!("Failed to write project config: {}", config_path.display()))
     }
 }
 
@@ -174,6 +580,13 @@ mod tests {
         assert!(config.stack_dir.is_none());
         assert!(config.archive_dir.is_none());
         assert!(config.template_dir.is_none());
+        assert!(config.obsidian.is_none());
+        assert!(config.pre_create.is_none());
+        assert!(config.pre_close.is_none());
+        assert!(config.rules.is_none());
+        assert!(config.attachment_dedup.is_none());
+        assert!(config.identities.is_none());
+        assert!(config.profile.is_none());
     }
 
     #[test]
@@ -193,6 +606,24 @@ interactive = false
         assert_eq!(config.interactive, Some(false));
     }
 
+    #[test]
+    fn test_github_label_mapping() {
+        let toml = r#"
+[github]
+repo = "acme/app"
+
+[github.labels]
+bug = "type: bug"
+wip = ""
+"#;
+        let config: ProjectConfig = toml::from_str(toml).unwrap();
+        let github = config.github.unwrap();
+        assert_eq!(github.repo.as_deref(), Some("acme/app"));
+        assert_eq!(github.remote_label("Bug").as_deref(), Some("type: bug"));
+        assert_eq!(github.remote_label("wip"), None);
+        assert_eq!(github.remote_label("ui").as_deref(), Some("ui"));
+    }
+
     #[test]
     fn test_parse_minimal_config() {
         // Empty config should work - all fields are optional
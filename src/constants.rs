@@ -21,6 +21,12 @@ pub const UI_COL_ID_WIDTH: usize = 15;
 /// Column width for status in list display.
 pub const UI_COL_STATUS_WIDTH: usize = 6;
 
+/// Total width of item cards (`show --brief`, `search --verbose`, TUI preview).
+pub const UI_CARD_WIDTH: usize = 80;
+
+/// Column width for status in item cards (fits `in-progress`).
+pub const UI_CARD_STATUS_WIDTH: usize = 11;
+
 // =============================================================================
 // Item Format
 // =============================================================================
@@ -31,9 +37,44 @@ pub const MAX_SLUG_LENGTH: usize = 50;
 /// YAML frontmatter delimiter.
 pub const FRONTMATTER_DELIMITER: &str = "---";
 
+/// TOML frontmatter delimiter.
+pub const TOML_FRONTMATTER_DELIMITER: &str = "+++";
+
 /// Suffix for attachment directory (sibling to item file).
 pub const ATTACHMENTS_DIR_SUFFIX: &str = ".attachments";
 
+/// URL scheme of attachments stored in S3-compatible object storage.
+pub const S3_URL_PREFIX: &str = "s3://";
+
+/// Placeholder for the item ID in the external `attachment_path`.
+pub const ATTACHMENT_PATH_ID: &str = "{id}";
+
+/// Shared store for deduplicated attachments (inside `stack_dir`).
+///
+/// Ends in the attachments suffix so item walks skip it.
+pub const ATTACHMENT_STORE_DIR: &str = ".store.attachments";
+
+/// Custom frontmatter field holding the number of an item's GitHub issue.
+pub const GITHUB_ISSUE_FIELD: &str = "github_issue";
+
+/// Custom frontmatter field holding an item's milestone.
+pub const MILESTONE_FIELD: &str = "milestone";
+
+/// Custom frontmatter field holding the ID of an item's parent.
+pub const PARENT_FIELD: &str = "parent";
+
+/// Custom frontmatter field holding an item's priority.
+pub const PRIORITY_FIELD: &str = "priority";
+
+/// Well-known priority values, most urgent first (others sort after them by name)
+pub const PRIORITY_ORDER: [&str; 6] = ["critical", "urgent", "high", "medium", "normal", "low"];
+
+/// Marker line starting the generated vault footer in item bodies (Obsidian mode).
+pub const VAULT_FOOTER_MARKER: &str = "<!-- queuestack:vault -->";
+
+/// File name of generated per-category index notes (Obsidian mode).
+pub const VAULT_INDEX_FILE: &str = "_index.md";
+
 // =============================================================================
 // File System
 // =============================================================================
@@ -50,12 +91,45 @@ pub const DEFAULT_ARCHIVE_DIR: &str = ".archive";
 /// Default subdirectory name for templates (inside `stack_dir`).
 pub const DEFAULT_TEMPLATE_DIR: &str = ".templates";
 
+/// Trash directory for deleted items (inside the stack directory)
+pub const TRASH_DIR: &str = ".trash";
+
+/// Runtime state kept out of version control (inside the stack directory)
+pub const CACHE_DIR: &str = ".cache";
+
+/// Lock file serializing item claims across processes (inside `CACHE_DIR`)
+pub const LOCK_FILE: &str = "claim.lock";
+
+/// Default number of days deleted items are kept in the trash
+pub const DEFAULT_TRASH_DAYS: u32 = 30;
+
+/// Files from one attachment call above which an interactive run asks for confirmation
+pub const ATTACHMENT_CONFIRM_THRESHOLD: usize = 20;
+
+/// Default body length (in words) above which saving an item warns
+pub const DEFAULT_MAX_BODY_WORDS: u32 = 5000;
+
 /// Global configuration directory name (inside user's config directory).
 pub const GLOBAL_CONFIG_DIR: &str = "queuestack";
 
 /// Global configuration file name (inside `GLOBAL_CONFIG_DIR`).
 pub const GLOBAL_CONFIG_FILENAME: &str = "config";
 
+/// Directory of per-project body drafts for `qs draft` (inside `GLOBAL_CONFIG_DIR`).
+pub const DRAFTS_DIR: &str = "drafts";
+
+/// Directory of per-project shallow clones of git mirrors (inside `GLOBAL_CONFIG_DIR`).
+pub const MIRRORS_DIR: &str = "mirrors";
+
+/// Markers of projects told that storage works without git (inside `GLOBAL_CONFIG_DIR`).
+pub const NOTICES_DIR: &str = "notices";
+
+/// Local usage log read by `qs insights` (inside `GLOBAL_CONFIG_DIR`).
+pub const USAGE_LOG_FILENAME: &str = "usage.jsonl";
+
+/// Environment variable selecting the active config profile.
+pub const PROFILE_ENV_VAR: &str = "QS_PROFILE";
+
 // =============================================================================
 // Shell Completion Paths
 // =============================================================================
@@ -84,6 +158,18 @@ pub const ELVISH_COMPLETIONS_DIR: &str = ".config/elvish/lib";
 /// Elvish completion file name.
 pub const ELVISH_COMPLETION_FILE: &str = "qs.elv";
 
+/// System-wide zsh completions directory (`qs setup --system`), on zsh's default `fpath`.
+pub const ZSH_SYSTEM_COMPLETIONS_DIR: &str = "/usr/local/share/zsh/site-functions";
+
+/// System-wide bash completions directory.
+pub const BASH_SYSTEM_COMPLETIONS_DIR: &str = "/usr/local/share/bash-completion/completions";
+
+/// System-wide fish completions directory.
+pub const FISH_SYSTEM_COMPLETIONS_DIR: &str = "/usr/local/share/fish/vendor_completions.d";
+
+/// System-wide elvish module directory.
+pub const ELVISH_SYSTEM_COMPLETIONS_DIR: &str = "/usr/local/share/elvish/lib";
+
 // =============================================================================
 // Shell RC Files
 // =============================================================================
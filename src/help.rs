@@ -0,0 +1,165 @@
+//! # Help Topics
+//!
+//! Guides for `qs help <topic>` that are too long for a command's `--help`:
+//! IDs, configuration, the day-to-day workflow, and use from agents and
+//! scripts. The guides are Markdown files in `docs/help/`, embedded at build
+//! time so they are available offline and match the installed version.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use owo_colors::OwoColorize;
+
+/// A help topic
+#[derive(Debug)]
+pub struct Topic {
+    pub name: &'static str,
+    /// One-line description for the topic list
+    pub summary: &'static str,
+    /// Markdown source
+    pub content: &'static str,
+}
+
+/// All help topics, in the order they are listed
+pub const TOPICS: [Topic; 4] = [
+    Topic {
+        name: "ids",
+        summary: "Item IDs, ID patterns, and partial matching",
+        content: include_str!("../docs/help/ids.md"),
+    },
+    Topic {
+        name: "config",
+        summary: "Config files and what each option does",
+        content: include_str!("../docs/help/config.md"),
+    },
+    Topic {
+        name: "workflow",
+        summary: "From creating an item to closing it, with git",
+        content: include_str!("../docs/help/workflow.md"),
+    },
+    Topic {
+        name: "agents",
+        summary: "Using qs from scripts and coding agents",
+        content: include_str!("../docs/help/agents.md"),
+    },
+];
+
+/// Finds a topic by name, ignoring case.
+pub fn find(name: &str) -> Option<&'static Topic> {
+    TOPICS
+        .iter()
+        .find(|topic| topic.name.eq_ignore_ascii_case(name))
+}
+
+/// Returns the topics containing `query` (ignoring case) with their matching
+/// lines, rendered as plain text.
+pub fn search(query: &str) -> Vec<(&'static Topic, Vec<String>)> {
+    let query = query.to_lowercase();
+    TOPICS
+        .iter()
+        .filter_map(|topic| {
+            let lines: Vec<String> = topic
+                .content
+                .lines()
+                .map(|line| strip_code_spans(line.trim_start_matches('#').trim()))
+                .filter(|line| line.to_lowercase().contains(&query))
+                .collect();
+            (!lines.is_empty()).then_some((topic, lines))
+        })
+        .collect()
+}
+
+/// Renders a topic's Markdown for the terminal.
+///
+/// Headings start at the left margin and everything else is indented, like
+/// command help. Code spans lose their backticks; with `color`, headings are
+/// highlighted and code is colored like commands in `--help`.
+pub fn render(content: &str, color: bool) -> String {
+    let mut out = String::new();
+    for line in content.lines() {
+        out.push_str(&render_line(line, color));
+        out.push('\n');
+    }
+    out
+}
+
+/// Renders one line of a topic.
+fn render_line(line: &str, color: bool) -> String {
+    if line.is_empty() {
+        return String::new();
+    }
+    if let Some(heading) = line.strip_prefix('#') {
+        let heading = heading.trim_start_matches('#').trim();
+        return if color {
+            heading.yellow().bold().to_string()
+        } else {
+            heading.to_string()
+        };
+    }
+    if line.starts_with("    ") {
+        return if color {
+            format!("  {}", line.green())
+        } else {
+            format!("  {line}")
+        };
+    }
+    format!("  {}", render_code_spans(line, color))
+}
+
+/// Removes the backticks around code spans.
+fn strip_code_spans(line: &str) -> String {
+    line.replace('`', "")
+}
+
+/// Removes the backticks around code spans, coloring the code with `color`.
+fn render_code_spans(line: &str, color: bool) -> String {
+    if !color {
+        return strip_code_spans(line);
+    }
+    line.split('`')
+        .enumerate()
+        .map(|(i, part)| {
+            if i % 2 == 1 {
+                part.green().to_string()
+            } else {
+                part.to_string()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_ignores_case() {
+        assert_eq!(find("IDs").map(|topic| topic.name), Some("ids"));
+        assert!(find("nope").is_none());
+    }
+
+    #[test]
+    fn test_render_plain() {
+        let markdown = "# Title\n\nUse `qs new`:\n\n    qs new \"Crash\"\n## Next\n- item\n";
+        assert_eq!(
+            render(markdown, false),
+            "Title\n\n  Use qs new:\n\n      qs new \"Crash\"\nNext\n  - item\n"
+        );
+    }
+
+    #[test]
+    fn test_search_returns_matching_lines() {
+        let results = search("PARTIAL ids");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0.name, "ids");
+        assert_eq!(results[0].1, vec!["Partial IDs"]);
+        assert!(search("no such phrase anywhere").is_empty());
+    }
+
+    #[test]
+    fn test_topics_start_with_a_heading() {
+        for topic in &TOPICS {
+            assert!(topic.content.starts_with("# "), "{}", topic.name);
+        }
+    }
+}
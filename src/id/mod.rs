@@ -45,6 +45,18 @@ pub fn extract_from_filename(filename: &str) -> Option<&str> {
     Some(&stem[..id_end])
 }
 
+/// Splits a project prefix off an ID reference.
+///
+/// `web:260109-ABC` names item `260109-ABC` of the project or mirror `web`;
+/// an ID without a prefix (or with an empty one) returns `None`.
+pub fn split_namespace(reference: &str) -> (Option<&str>, &str) {
+    match reference.split_once(':') {
+        Some((namespace, id)) if !namespace.trim().is_empty() => (Some(namespace.trim()), id),
+        Some((_, id)) => (None, id),
+        None => (None, reference),
+    }
+}
+
 /// Generates a unique ID based on the given pattern.
 ///
 /// # Arguments
@@ -116,6 +128,17 @@ pub fn generate(pattern: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_split_namespace() {
+        assert_eq!(
+            split_namespace("web:260109-ABC"),
+            (Some("web"), "260109-ABC")
+        );
+        assert_eq!(split_namespace("260109-ABC"), (None, "260109-ABC"));
+        assert_eq!(split_namespace(":2601"), (None, "2601"));
+        assert_eq!(split_namespace("web:"), (Some("web"), ""));
+    }
+
     #[test]
     fn test_default_pattern_format() {
         let id = generate(DEFAULT_PATTERN);
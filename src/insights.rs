@@ -0,0 +1,233 @@
+//! # Usage Insights
+//!
+//! Opt-in local log of the commands you run, summarized by `qs insights`.
+//! With `usage_log = true` in the global config, each command appends its
+//! name and the local time to `usage.jsonl` next to the global config.
+//! Arguments, item contents, and paths are never recorded, and the log is
+//! never sent anywhere.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Datelike, FixedOffset, Local, Timelike};
+use serde::{Deserialize, Serialize};
+
+use crate::{config::GlobalConfig, constants::USAGE_LOG_FILENAME};
+
+/// Parts of the day, by local start hour
+pub const DAY_PERIODS: [(&str, u32); 4] = [
+    ("night", 0),
+    ("morning", 6),
+    ("afternoon", 12),
+    ("evening", 18),
+];
+
+/// One recorded command
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    /// When the command ran, in the local time zone of that moment
+    pub at: DateTime<FixedOffset>,
+    /// Subcommand path, e.g. `attachments add`
+    pub command: String,
+}
+
+/// Path of the usage log (`~/.config/queuestack/usage.jsonl`).
+pub fn log_path() -> Option<PathBuf> {
+    GlobalConfig::dir().map(|dir| dir.join(USAGE_LOG_FILENAME))
+}
+
+/// Appends a command to the usage log if `usage_log` is enabled.
+///
+/// Never fails: a missing config or unwritable log must not break the command.
+pub fn record(command: &str) {
+    if !GlobalConfig::load().is_ok_and(|config| config.usage_log) {
+        return;
+    }
+    let Some(path) = log_path() else {
+        return;
+    };
+    let entry = Entry {
+        at: Local::now().fixed_offset(),
+        command: command.to_string(),
+    };
+    let Ok(line) = serde_json::to_string(&entry) else {
+        return;
+    };
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) {
+        let _ = writeln!(file, "{line}");
+    }
+}
+
+/// Reads the usage log. Unreadable lines are skipped; a missing log is empty.
+pub fn load(path: &Path) -> Result<Vec<Entry>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read usage log: {}", path.display()))?;
+    Ok(content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// Usage statistics over a set of entries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub total: usize,
+    /// Number of distinct days with at least one command
+    pub active_days: usize,
+    /// Commands by number of uses, most used first (ties by name)
+    pub commands: Vec<(String, usize)>,
+    /// Commands per local hour of the day
+    pub hours: [usize; 24],
+    /// Commands per weekday, Monday first
+    pub weekdays: [usize; 7],
+}
+
+impl Summary {
+    /// Commands per part of the day (see [`DAY_PERIODS`]).
+    pub fn periods(&self) -> [usize; 4] {
+        let mut periods = [0; 4];
+        for (hour, count) in self.hours.iter().enumerate() {
+            periods[hour / 6] += count;
+        }
+        periods
+    }
+
+    /// The hour with the most commands, if any.
+    pub fn busiest_hour(&self) -> Option<(usize, usize)> {
+        busiest(&self.hours)
+    }
+
+    /// The weekday (0 = Monday) with the most commands, if any.
+    pub fn busiest_weekday(&self) -> Option<(usize, usize)> {
+        busiest(&self.weekdays)
+    }
+}
+
+/// Summarizes the entries recorded at or after `since` (all when `None`).
+///
+/// Hours and weekdays are those of the local time the command ran at.
+pub fn summarize(entries: &[Entry], since: Option<DateTime<FixedOffset>>) -> Summary {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut days = BTreeSet::new();
+    let mut hours = [0; 24];
+    let mut weekdays = [0; 7];
+    let mut total = 0;
+
+    for entry in entries
+        .iter()
+        .filter(|entry| since.map_or(true, |since| entry.at >= since))
+    {
+        total += 1;
+        *counts.entry(&entry.command).or_default() += 1;
+        days.insert(entry.at.date_naive());
+        hours[entry.at.hour() as usize] += 1;
+        weekdays[entry.at.weekday().num_days_from_monday() as usize] += 1;
+    }
+
+    let mut commands: Vec<(String, usize)> = counts
+        .into_iter()
+        .map(|(command, count)| (command.to_string(), count))
+        .collect();
+    commands.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    Summary {
+        total,
+        active_days: days.len(),
+        commands,
+        hours,
+        weekdays,
+    }
+}
+
+/// Index and count of the largest non-zero count (the first on ties).
+fn busiest(counts: &[usize]) -> Option<(usize, usize)> {
+    counts
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|&(_, count)| count > 0)
+        .fold(None, |best, (i, count)| match best {
+            Some((_, max)) if max >= count => best,
+            _ => Some((i, count)),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(at: &str, command: &str) -> Entry {
+        Entry {
+            at: DateTime::parse_from_rfc3339(at).unwrap(),
+            command: command.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_summarize_counts_commands_and_times() {
+        let entries = [
+            // Monday morning and evening, Tuesday morning (local times)
+            entry("2026-01-05T09:15:00+01:00", "list"),
+            entry("2026-01-05T09:40:00+01:00", "new"),
+            entry("2026-01-05T20:00:00+01:00", "list"),
+            entry("2026-01-06T09:05:00-08:00", "attachments add"),
+        ];
+        let summary = summarize(&entries, None);
+
+        assert_eq!(summary.total, 4);
+        assert_eq!(summary.active_days, 2);
+        assert_eq!(
+            summary.commands,
+            vec![
+                ("list".to_string(), 2),
+                ("attachments add".to_string(), 1),
+                ("new".to_string(), 1),
+            ]
+        );
+        assert_eq!(summary.periods(), [0, 3, 0, 1]);
+        assert_eq!(summary.busiest_hour(), Some((9, 3)));
+        assert_eq!(summary.busiest_weekday(), Some((0, 3)));
+    }
+
+    #[test]
+    fn test_summarize_since() {
+        let entries = [
+            entry("2026-01-01T10:00:00Z", "list"),
+            entry("2026-02-01T10:00:00Z", "close"),
+        ];
+        let since = DateTime::parse_from_rfc3339("2026-01-15T00:00:00Z").unwrap();
+        let summary = summarize(&entries, Some(since));
+
+        assert_eq!(summary.total, 1);
+        assert_eq!(summary.commands, vec![("close".to_string(), 1)]);
+        assert_eq!(summarize(&[], None).busiest_hour(), None);
+    }
+
+    #[test]
+    fn test_load_skips_bad_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("usage.jsonl");
+        std::fs::write(
+            &path,
+            "{\"at\":\"2026-01-05T09:15:00+01:00\",\"command\":\"list\"}\nnot json\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            load(&path).unwrap(),
+            vec![entry("2026-01-05T09:15:00+01:00", "list")]
+        );
+        assert!(load(&dir.path().join("missing")).unwrap().is_empty());
+    }
+}
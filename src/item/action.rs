@@ -0,0 +1,81 @@
+//! # Item Actions
+//!
+//! Item-specific shell commands declared in frontmatter and run with `qs run`:
+//!
+//! ```yaml
+//! actions:
+//!   reproduce: cargo test --test flaky -- --nocapture
+//!   notes: cat {path}
+//! ```
+//!
+//! `{path}` expands to the item file and `{id}` to the item ID. Both are
+//! shell-quoted on substitution, so they must not be quoted in the command.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{borrow::Cow, path::Path, process::Command};
+
+/// Placeholder for the item file path
+pub const PATH_PLACEHOLDER: &str = "{path}";
+
+/// Placeholder for the item ID
+pub const ID_PLACEHOLDER: &str = "{id}";
+
+/// Substitutes the `{path}` and `{id}` placeholders in an action command.
+pub fn expand(command: &str, id: &str, path: &Path) -> String {
+    command
+        .replace(PATH_PLACEHOLDER, &quote(&path.to_string_lossy()))
+        .replace(ID_PLACEHOLDER, &quote(id))
+}
+
+/// Builds a command that runs `command` through the platform shell.
+pub fn shell(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    }
+}
+
+/// Quotes a value for the platform shell.
+pub fn quote(value: &str) -> String {
+    if cfg!(windows) {
+        return format!("\"{value}\"");
+    }
+    shlex::try_quote(value).map_or_else(|_| value.to_string(), Cow::into_owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_expand_placeholders() {
+        let path = Path::new("/work/queuestack/260101-AAA-flaky.md");
+        assert_eq!(
+            expand(
+                &format!("cat {PATH_PLACEHOLDER} # {ID_PLACEHOLDER}"),
+                "260101-AAA",
+                path
+            ),
+            "cat /work/queuestack/260101-AAA-flaky.md # 260101-AAA"
+        );
+        assert_eq!(expand("make test", "260101-AAA", path), "make test");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_expand_quotes_values() {
+        let path = Path::new("/my work/it's.md");
+        assert_eq!(
+            expand(&format!("cat {PATH_PLACEHOLDER}"), "X", path),
+            r#"cat "/my work/it's.md""#
+        );
+    }
+}
@@ -0,0 +1,384 @@
+//! # Item Cards
+//!
+//! Compact item summaries shared by `qs show --brief`, `qs search --verbose`,
+//! and the TUI preview pane. A card is laid out once as toned segments, so
+//! every renderer applies the same widths and color coding.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use chrono::{DateTime, Utc};
+
+use super::{
+    labels::{self, LabelColor},
+    search::find_match,
+    Item, Status,
+};
+use crate::{
+    constants::{UI_CARD_STATUS_WIDTH, UI_CARD_WIDTH, UI_COL_ID_WIDTH, VAULT_FOOTER_MARKER},
+    ui::{pad_to_width, truncate},
+};
+
+/// Separator between metadata parts
+const META_SEPARATOR: &str = " · ";
+
+/// Indent of the metadata and summary lines
+const CARD_INDENT: &str = "  ";
+
+/// Semantic tone of a card segment; renderers map tones to colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tone {
+    Id,
+    Status(Status),
+    Title,
+    Labels,
+    /// A label with a defined color
+    Label(LabelColor),
+    Meta,
+    Summary,
+    /// Highlighted search match
+    Match,
+    /// Spacing and separators
+    Plain,
+}
+
+/// A run of text with a single tone
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    pub tone: Tone,
+    pub text: String,
+}
+
+/// A compact, fixed-width summary of an item
+#[derive(Debug, Clone)]
+pub struct Card {
+    lines: Vec<Vec<Segment>>,
+}
+
+impl Card {
+    /// Lays out the card for an item.
+    ///
+    /// Line 1 holds ID, status, and title; line 2 labels, category, assignee,
+    /// age, attachment count, and body length; line 3 the first body
+    /// paragraph (if any).
+    pub fn new(item: &Item, category: Option<&str>, now: DateTime<Utc>) -> Self {
+        let title_width = UI_CARD_WIDTH - UI_COL_ID_WIDTH - UI_CARD_STATUS_WIDTH - 3;
+        let mut lines = vec![vec![
+            segment(
+                Tone::Id,
+                pad_to_width(&item.qualified_id(), UI_COL_ID_WIDTH),
+            ),
+            segment(Tone::Plain, " "),
+            segment(
+                Tone::Status(item.status()),
+                pad_to_width(&item.status().to_string(), UI_CARD_STATUS_WIDTH),
+            ),
+            segment(Tone::Plain, "  "),
+            segment(Tone::Title, truncate(item.title(), title_width)),
+        ]];
+
+        let mut meta = vec![segment(Tone::Plain, CARD_INDENT)];
+        if !item.labels().is_empty() {
+            let width = (UI_CARD_WIDTH - CARD_INDENT.len()) / 2;
+            meta.extend(label_segments(item.labels(), width));
+        }
+        let mut parts: Vec<String> = category.map(ToString::to_string).into_iter().collect();
+        if let Some(assignee) = item.assignee() {
+            parts.push(format!("@{assignee}"));
+        }
+        parts.push(format_age(item.created_at(), now));
+        match item.attachments().len() {
+            0 => {}
+            1 => parts.push("1 attachment".to_string()),
+            n => parts.push(format!("{n} attachments")),
+        }
+        let size = item.body_size();
+        if size.words > 0 {
+            parts.push(size.to_string());
+        }
+        let separator = if meta.len() > 1 { META_SEPARATOR } else { "" };
+        meta.push(segment(
+            Tone::Meta,
+            truncate(
+                &format!("{separator}{}", parts.join(META_SEPARATOR)),
+                (UI_CARD_WIDTH - CARD_INDENT.len()).saturating_sub(line_width(&meta[1..])),
+            ),
+        ));
+        lines.push(meta);
+
+        if let Some(summary) = first_paragraph(&item.body) {
+            lines.push(vec![
+                segment(Tone::Plain, CARD_INDENT),
+                segment(
+                    Tone::Summary,
+                    truncate(&summary, UI_CARD_WIDTH - CARD_INDENT.len()),
+                ),
+            ]);
+        }
+
+        Self { lines }
+    }
+
+    /// Drops the summary line (for when the full body is shown below the card).
+    #[must_use]
+    pub fn without_summary(mut self) -> Self {
+        self.lines.truncate(2);
+        self
+    }
+
+    /// Marks the first match of a search query in the title and summary.
+    #[must_use]
+    pub fn highlight(mut self, query: &str) -> Self {
+        for line in &mut self.lines {
+            *line = std::mem::take(line)
+                .into_iter()
+                .flat_map(|segment| split_match(segment, query))
+                .collect();
+        }
+        self
+    }
+
+    /// Returns the card's lines as toned segments.
+    pub fn lines(&self) -> &[Vec<Segment>] {
+        &self.lines
+    }
+
+    /// Renders the card as plain text (no colors), without trailing spaces.
+    pub fn plain(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| {
+                let text: String = line.iter().map(|s| s.text.as_str()).collect();
+                text.trim_end().to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn segment(tone: Tone, text: impl Into<String>) -> Segment {
+    Segment {
+        tone,
+        text: text.into(),
+    }
+}
+
+/// Lays out labels as a comma-separated list truncated to `width`, each label
+/// in its defined color.
+fn label_segments(labels: &[String], width: usize) -> Vec<Segment> {
+    let text = truncate(&labels.join(", "), width);
+    let mut rest = text.as_str();
+    let mut segments = Vec::new();
+    let parts = labels.iter().enumerate().flat_map(|(index, label)| {
+        let separator = (index > 0).then_some((Tone::Labels, ", "));
+        let tone = labels::color(label).map_or(Tone::Labels, Tone::Label);
+        separator.into_iter().chain([(tone, label.as_str())])
+    });
+    // Take each part from the truncated text until it runs out
+    for (tone, part) in parts {
+        if let Some(stripped) = rest.strip_prefix(part) {
+            segments.push(segment(tone, part));
+            rest = stripped;
+            continue;
+        }
+        let common: usize = rest
+            .char_indices()
+            .zip(part.chars())
+            .take_while(|((_, a), b)| a == b)
+            .map(|((_, c), _)| c.len_utf8())
+            .sum();
+        if common > 0 {
+            segments.push(segment(tone, &rest[..common]));
+            rest = &rest[common..];
+        }
+        break;
+    }
+    if !rest.is_empty() {
+        segments.push(segment(Tone::Labels, rest));
+    }
+    segments
+}
+
+/// Splits a title or summary segment around the first match of `query`.
+fn split_match(segment: Segment, query: &str) -> Vec<Segment> {
+    if !matches!(segment.tone, Tone::Title | Tone::Summary) {
+        return vec![segment];
+    }
+    let Some(range) = find_match(&segment.text, query) else {
+        return vec![segment];
+    };
+    let text = &segment.text;
+    [
+        (segment.tone, &text[..range.start]),
+        (Tone::Match, &text[range.clone()]),
+        (segment.tone, &text[range.end..]),
+    ]
+    .into_iter()
+    .filter(|(_, part)| !part.is_empty())
+    .map(|(tone, part)| self::segment(tone, part))
+    .collect()
+}
+
+fn line_width(segments: &[Segment]) -> usize {
+    use unicode_width::UnicodeWidthStr;
+    segments.iter().map(|s| s.text.width()).sum()
+}
+
+/// Returns the first prose paragraph of a body, joined into one line.
+///
+/// Headings and the generated vault footer are skipped.
+fn first_paragraph(body: &str) -> Option<String> {
+    let body = body.split(VAULT_FOOTER_MARKER).next().unwrap_or_default();
+    body.split("\n\n")
+        .map(|paragraph| {
+            paragraph
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .find(|paragraph| !paragraph.is_empty())
+}
+
+/// Formats an item's age compactly: `just now`, `12m ago`, `5h ago`, `3d ago`, `4mo ago`, `2y ago`.
+pub fn format_age(created: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let minutes = (now - created).num_minutes();
+    let days = minutes / 1440;
+    match minutes {
+        m if m < 1 => "just now".to_string(),
+        m if m < 60 => format!("{m}m ago"),
+        m if m < 1440 => format!("{}h ago", m / 60),
+        _ if days < 60 => format!("{days}d ago"),
+        _ if days < 730 => format!("{}mo ago", days / 30),
+        _ => format!("{}y ago", days / 365),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::item::Frontmatter;
+    use chrono::TimeZone;
+
+    fn sample_item() -> Item {
+        let mut item = Item::new(Frontmatter {
+            id: "260101-AAA".to_string(),
+            title: "Login fails on Safari".to_string(),
+            author: "Test User".to_string(),
+            created_at: Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap(),
+            status: Status::InProgress,
+            labels: vec!["bug".to_string(), "ui".to_string()],
+            attachments: vec!["1-shot.png".to_string(), "2-log.txt".to_string()],
+            ..Frontmatter::default()
+        });
+        item.set_assignee(Some("alice".to_string()));
+        item.body = "## Steps\n\nOpen the page\nand log in.\n\nMore details.\n".to_string();
+        item
+    }
+
+    #[test]
+    fn test_card_plain() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 4, 13, 0, 0).unwrap();
+        let card = Card::new(&sample_item(), Some("bugs"), now);
+        assert_eq!(
+            card.plain(),
+            "260101-AAA      in-progress  Login fails on Safari\n  \
+             bug, ui · bugs · @alice · 3d ago · 2 attachments · 9 words · 1 min read\n  \
+             Open the page and log in."
+        );
+    }
+
+    #[test]
+    fn test_card_minimal_item() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 12, 30, 0).unwrap();
+        let mut item = sample_item();
+        item.frontmatter.labels.clear();
+        item.frontmatter.attachments.clear();
+        item.set_assignee(None);
+        item.body = "# Only a heading\n".to_string();
+
+        let card = Card::new(&item, None, now);
+        assert_eq!(card.lines().len(), 2);
+        assert!(card.plain().ends_with("\n  30m ago · 3 words · 1 min read"));
+    }
+
+    #[test]
+    fn test_card_truncates_to_width() {
+        use unicode_width::UnicodeWidthStr;
+
+        let now = Utc.with_ymd_and_hms(2026, 1, 2, 12, 0, 0).unwrap();
+        let mut item = sample_item();
+        item.frontmatter.title = "word ".repeat(40);
+        item.body = "sentence ".repeat(40);
+
+        let card = Card::new(&item, Some("bugs"), now);
+        for line in card.plain().lines() {
+            assert!(line.width() <= UI_CARD_WIDTH, "too wide: {line}");
+        }
+    }
+
+    #[test]
+    fn test_label_colors() {
+        labels::set_colors(&[labels::LabelDef {
+            name: "bug".to_string(),
+            color: Some("red".to_string()),
+            ..labels::LabelDef::default()
+        }])
+        .unwrap();
+        let tones = |segments: Vec<Segment>| -> Vec<(Tone, String)> {
+            segments.into_iter().map(|s| (s.tone, s.text)).collect()
+        };
+        let labels = ["ui".to_string(), "bug".to_string(), "docs".to_string()];
+
+        assert_eq!(
+            tones(label_segments(&labels, 40)),
+            [
+                (Tone::Labels, "ui".to_string()),
+                (Tone::Labels, ", ".to_string()),
+                (Tone::Label(LabelColor::Red), "bug".to_string()),
+                (Tone::Labels, ", ".to_string()),
+                (Tone::Labels, "docs".to_string()),
+            ]
+        );
+        assert_eq!(
+            tones(label_segments(&labels, 7)),
+            [
+                (Tone::Labels, "ui".to_string()),
+                (Tone::Labels, ", ".to_string()),
+                (Tone::Label(LabelColor::Red), "bu".to_string()),
+                (Tone::Labels, "…".to_string()),
+            ]
+        );
+        labels::set_colors(&[]).unwrap();
+    }
+
+    #[test]
+    fn test_format_age() {
+        let created = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let age = |minutes| format_age(created, created + chrono::TimeDelta::minutes(minutes));
+        assert_eq!(age(0), "just now");
+        assert_eq!(age(12), "12m ago");
+        assert_eq!(age(5 * 60), "5h ago");
+        assert_eq!(age(3 * 1440), "3d ago");
+        assert_eq!(age(120 * 1440), "4mo ago");
+        assert_eq!(age(800 * 1440), "2y ago");
+    }
+
+    #[test]
+    fn test_card_highlight() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 4, 13, 0, 0).unwrap();
+        let card = Card::new(&sample_item(), None, now).highlight("LOGIN");
+        let matches: Vec<&str> = card
+            .lines()
+            .iter()
+            .flatten()
+            .filter(|s| s.tone == Tone::Match)
+            .map(|s| s.text.as_str())
+            .collect();
+        assert_eq!(matches, ["Login"]);
+        assert!(card
+            .plain()
+            .starts_with("260101-AAA      in-progress  Login fails on Safari\n"));
+    }
+}
@@ -0,0 +1,187 @@
+//! # Comments
+//!
+//! Comments are sections appended to an item's Markdown body, so they stay
+//! readable in any editor and merge like the rest of the file:
+//!
+//! ```markdown
+//! ## Comment by Alice on 2026-01-12 14:03 UTC
+//!
+//! Reproduced on macOS too.
+//! ```
+//!
+//! A comment runs until the next heading of level one or two, or the
+//! generated vault footer.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::Serialize;
+
+use super::Item;
+use crate::constants::VAULT_FOOTER_MARKER;
+
+/// Start of a comment heading
+const HEADING_PREFIX: &str = "## Comment by ";
+
+/// Separator between the author and the timestamp in a heading
+const HEADING_DATE_SEPARATOR: &str = " on ";
+
+/// Timestamp format in comment headings
+const DATE_FORMAT: &str = "%Y-%m-%d %H:%M UTC";
+
+/// A comment parsed from an item body
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Comment {
+    pub author: String,
+    /// `None` if the heading's timestamp can't be read
+    pub created_at: Option<DateTime<Utc>>,
+    pub text: String,
+}
+
+/// Appends a comment by `author` to the item body.
+pub fn add(item: &mut Item, author: &str, created_at: DateTime<Utc>, text: &str) {
+    let section = format!(
+        "{HEADING_PREFIX}{author}{HEADING_DATE_SEPARATOR}{}\n\n{}\n",
+        created_at.format(DATE_FORMAT),
+        text.trim()
+    );
+    append_section(item, &section);
+}
+
+/// Appends a Markdown section to the item body, before the generated vault
+/// footer if there is one.
+pub fn append_section(item: &mut Item, section: &str) {
+    let (body, footer) = match item.body.find(VAULT_FOOTER_MARKER) {
+        Some(pos) => item.body.split_at(pos),
+        None => (item.body.as_str(), ""),
+    };
+    let body = body.trim_end();
+    let mut new_body = if body.is_empty() {
+        section.to_string()
+    } else {
+        format!("{body}\n\n{section}")
+    };
+    if !footer.is_empty() {
+        new_body.push('\n');
+        new_body.push_str(footer);
+    }
+    item.body = new_body;
+}
+
+/// Returns the comments of an item body, oldest first.
+pub fn list(body: &str) -> Vec<Comment> {
+    let body = body.split(VAULT_FOOTER_MARKER).next().unwrap_or_default();
+
+    let mut comments = Vec::new();
+    let mut current: Option<(Comment, Vec<&str>)> = None;
+    let mut in_code_block = false;
+    for line in body.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+        let heading = !in_code_block && (line.starts_with("# ") || line.starts_with("## "));
+        if heading {
+            comments.extend(current.take().map(finish));
+            current = parse_heading(line).map(|comment| (comment, Vec::new()));
+        } else if let Some((_, ref mut lines)) = current {
+            lines.push(line);
+        }
+    }
+    comments.extend(current.map(finish));
+    comments
+}
+
+/// Parses a `## Comment by <author> on <timestamp>` heading.
+fn parse_heading(line: &str) -> Option<Comment> {
+    let rest = line.trim_end().strip_prefix(HEADING_PREFIX)?;
+    let (author, date) = rest
+        .rsplit_once(HEADING_DATE_SEPARATOR)
+        .unwrap_or((rest, ""));
+    let created_at = NaiveDateTime::parse_from_str(date, DATE_FORMAT)
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+        })
+        .map(|naive| naive.and_utc());
+    Some(Comment {
+        author: author.trim().to_string(),
+        created_at,
+        text: String::new(),
+    })
+}
+
+fn finish((mut comment, lines): (Comment, Vec<&str>)) -> Comment {
+    comment.text = lines.join("\n").trim().to_string();
+    comment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::item::Frontmatter;
+
+    fn item(body: &str) -> Item {
+        let mut item = Item::new(Frontmatter::default());
+        item.body = body.to_string();
+        item
+    }
+
+    fn time(s: &str) -> DateTime<Utc> {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M")
+            .unwrap()
+            .and_utc()
+    }
+
+    #[test]
+    fn test_add_and_list() {
+        let mut item = item("Steps to reproduce.\n");
+        add(
+            &mut item,
+            "Alice",
+            time("2026-01-12 14:03"),
+            "Seen on macOS.\n",
+        );
+        add(&mut item, "Bob", time("2026-01-13 09:00"), "Fixed in main.");
+
+        assert_eq!(
+            item.body,
+            "Steps to reproduce.\n\n\
+             ## Comment by Alice on 2026-01-12 14:03 UTC\n\nSeen on macOS.\n\n\
+             ## Comment by Bob on 2026-01-13 09:00 UTC\n\nFixed in main.\n"
+        );
+        let comments = list(&item.body);
+        assert_eq!(comments.len(), 2);
+        assert_eq!(comments[0].author, "Alice");
+        assert_eq!(comments[0].created_at, Some(time("2026-01-12 14:03")));
+        assert_eq!(comments[0].text, "Seen on macOS.");
+        assert_eq!(comments[1].text, "Fixed in main.");
+    }
+
+    #[test]
+    fn test_add_keeps_vault_footer_last() {
+        let mut item = item(&format!("Body\n\n{VAULT_FOOTER_MARKER}\n[[bugs]]\n"));
+        add(&mut item, "Alice", time("2026-01-12 14:03"), "Note");
+
+        assert!(item
+            .body
+            .ends_with(&format!("Note\n\n{VAULT_FOOTER_MARKER}\n[[bugs]]\n")));
+        assert_eq!(list(&item.body)[0].text, "Note");
+    }
+
+    #[test]
+    fn test_list_stops_at_other_headings() {
+        let body = "## Comment by Alice Smith on 2026-01-12\n\n\
+                    Text\n\n```sh\n## not a heading\n```\n\n\
+                    ### Details\n\nStill part of it\n\n\
+                    ## Notes\n\nNot a comment\n";
+        let comments = list(body);
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].author, "Alice Smith");
+        assert_eq!(comments[0].created_at, Some(time("2026-01-12 00:00")));
+        assert!(comments[0].text.contains("## not a heading"));
+        assert!(comments[0].text.ends_with("Still part of it"));
+    }
+}
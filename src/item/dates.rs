@@ -0,0 +1,502 @@
+//! # Date Filters
+//!
+//! Date ranges for filtering items by `created_at` or `closed_at`, and parsing
+//! of the `--since`/`--until` values: ISO dates (`2026-01-15`), RFC 3339
+//! timestamps, `today`/`yesterday`, and relative ages such as `7d`, `2w`,
+//! `3m`, or `1y` (optionally followed by `ago`).
+//!
+//! Also reads and writes the frontmatter timestamps (`created_at`,
+//! `closed_at`): they are written in the project's `date_format` and read
+//! leniently, since items generated by other tools use many formats.
+//!
+//! Due dates (the `due` field) are entered in plain words: `tomorrow`, `fri`,
+//! `next week`, `in 3 days`, or an ISO date. In frontmatter they are read like
+//! the other timestamps and written as a plain date unless they have a time
+//! of day.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{cell::Cell, fmt, str::FromStr};
+
+use anyhow::{bail, Result};
+use chrono::{
+    DateTime, Datelike, Days, Local, Months, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat,
+    TimeDelta, Utc, Weekday,
+};
+use serde::{Deserialize, Serialize};
+
+/// Date-time layouts accepted in frontmatter, with or without a UTC offset
+const DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+];
+
+/// Date-only layouts accepted in frontmatter (midnight UTC)
+const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%Y/%m/%d", "%Y%m%d"];
+
+thread_local! {
+    /// Format used when writing frontmatter timestamps (set from the config).
+    static TIMESTAMP_FORMAT: Cell<DateFormat> = const { Cell::new(DateFormat::Rfc3339) };
+}
+
+/// How frontmatter timestamps are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DateFormat {
+    /// RFC 3339 with fractional seconds when present (`2026-01-15T09:30:00.123Z`)
+    #[default]
+    Rfc3339,
+    /// RFC 3339 with whole seconds (`2026-01-15T09:30:00Z`)
+    Seconds,
+    /// Without seconds (`2026-01-15T09:30Z`)
+    Minutes,
+    /// Date only (`2026-01-15`)
+    Date,
+}
+
+impl fmt::Display for DateFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rfc3339 => write!(f, "rfc3339"),
+            Self::Seconds => write!(f, "seconds"),
+            Self::Minutes => write!(f, "minutes"),
+            Self::Date => write!(f, "date"),
+        }
+    }
+}
+
+impl DateFormat {
+    /// Formats a timestamp in this format.
+    pub fn format(self, timestamp: DateTime<Utc>) -> String {
+        match self {
+            Self::Rfc3339 => timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            Self::Seconds => timestamp.to_rfc3339_opts(SecondsFormat::Secs, true),
+            Self::Minutes => timestamp.format("%Y-%m-%dT%H:%MZ").to_string(),
+            Self::Date => timestamp.format("%Y-%m-%d").to_string(),
+        }
+    }
+}
+
+/// Sets the format frontmatter timestamps are written in (per thread).
+pub fn set_timestamp_format(format: DateFormat) {
+    TIMESTAMP_FORMAT.with(|cell| cell.set(format));
+}
+
+/// Parses a frontmatter timestamp leniently.
+///
+/// Accepts RFC 3339 and RFC 2822 timestamps, ISO 8601 date-times with a `T` or
+/// a space, with or without seconds, fractional seconds, and a UTC offset
+/// (none means UTC), plain dates (midnight UTC), and Unix timestamps in seconds.
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Some(timestamp.with_timezone(&Utc));
+    }
+    if let Ok(timestamp) = DateTime::parse_from_rfc2822(value) {
+        return Some(timestamp.with_timezone(&Utc));
+    }
+
+    let naive = value
+        .strip_suffix(['Z', 'z'])
+        .or_else(|| value.strip_suffix(" UTC"))
+        .unwrap_or(value);
+    for format in DATETIME_FORMATS {
+        if let Ok(timestamp) = NaiveDateTime::parse_from_str(naive, format) {
+            return Some(timestamp.and_utc());
+        }
+        for offset in ["%z", " %z"] {
+            if let Ok(timestamp) = DateTime::parse_from_str(value, &format!("{format}{offset}")) {
+                return Some(timestamp.with_timezone(&Utc));
+            }
+        }
+    }
+    for format in DATE_FORMATS {
+        if let Ok(date) = NaiveDate::parse_from_str(value, format) {
+            return Some(date.and_time(NaiveTime::MIN).and_utc());
+        }
+    }
+
+    value
+        .parse::<i64>()
+        .ok()
+        .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+}
+
+/// Serde helpers for `created_at`: written in the configured format, read leniently.
+pub mod timestamp {
+    use chrono::{DateTime, Utc};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    use super::{parse_timestamp, TIMESTAMP_FORMAT};
+
+    /// A timestamp as found in frontmatter: text, a Unix timestamp, or a
+    /// TOML date-time
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    pub(super) enum Raw {
+        Text(String),
+        Seconds(i64),
+        Toml(toml::value::Datetime),
+    }
+
+    impl Raw {
+        pub(super) fn parse<E: de::Error>(self) -> Result<DateTime<Utc>, E> {
+            match self {
+                Self::Text(text) => parse_timestamp(&text)
+                    .ok_or_else(|| E::custom(format!("invalid timestamp '{text}'"))),
+                Self::Seconds(seconds) => DateTime::from_timestamp(seconds, 0)
+                    .ok_or_else(|| E::custom(format!("invalid timestamp {seconds}"))),
+                Self::Toml(datetime) => Self::Text(datetime.to_string()).parse(),
+            }
+        }
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)] // Signature required by serde
+    pub fn serialize<S: Serializer>(
+        value: &DateTime<Utc>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&TIMESTAMP_FORMAT.with(std::cell::Cell::get).format(*value))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Utc>, D::Error> {
+        Raw::deserialize(deserializer)?.parse()
+    }
+}
+
+/// Serde helpers for optional timestamps such as `closed_at` (see [`timestamp`]).
+pub mod optional_timestamp {
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::timestamp::{self, Raw};
+
+    #[allow(clippy::ref_option)] // Signature required by serde
+    pub fn serialize<S: Serializer>(
+        value: &Option<DateTime<Utc>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => timestamp::serialize(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<DateTime<Utc>>, D::Error> {
+        Option::<Raw>::deserialize(deserializer)?
+            .map(Raw::parse)
+            .transpose()
+    }
+}
+
+/// Serde helpers for the `due` field: plain dates are written as `YYYY-MM-DD`,
+/// other values like [`timestamp`]. Blank values mean no due date.
+pub mod due {
+    use chrono::{DateTime, NaiveTime, Utc};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::timestamp::{self, Raw};
+
+    #[allow(clippy::ref_option)] // Signature required by serde
+    pub fn serialize<S: Serializer>(
+        value: &Option<DateTime<Utc>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) if value.time() == NaiveTime::MIN => {
+                serializer.serialize_str(&value.format("%Y-%m-%d").to_string())
+            }
+            Some(value) => timestamp::serialize(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<DateTime<Utc>>, D::Error> {
+        match Option::<Raw>::deserialize(deserializer)? {
+            Some(Raw::Text(text)) if text.trim().is_empty() => Ok(None),
+            raw => raw.map(Raw::parse).transpose(),
+        }
+    }
+}
+
+/// An inclusive date range; open-ended on either side when unset.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DateRange {
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl DateRange {
+    /// Returns true if neither bound is set.
+    pub const fn is_empty(&self) -> bool {
+        self.since.is_none() && self.until.is_none()
+    }
+
+    /// Returns true if the timestamp lies within the range.
+    pub fn contains(&self, timestamp: DateTime<Utc>) -> bool {
+        self.since.map_or(true, |since| timestamp >= since)
+            && self.until.map_or(true, |until| timestamp <= until)
+    }
+}
+
+/// Parses a `--since` value; calendar dates start at midnight UTC.
+pub fn parse_since(value: &str) -> Result<DateTime<Utc>> {
+    parse_bound(value, Utc::now(), false)
+}
+
+/// Parses an `--until` value; calendar dates are inclusive (end of day UTC).
+pub fn parse_until(value: &str) -> Result<DateTime<Utc>> {
+    parse_bound(value, Utc::now(), true)
+}
+
+fn parse_bound(value: &str, now: DateTime<Utc>, end_of_day: bool) -> Result<DateTime<Utc>> {
+    let value = value.trim();
+
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+
+    let day = match value.to_lowercase().as_str() {
+        "today" => Some(now.date_naive()),
+        "yesterday" => now.date_naive().checked_sub_days(Days::new(1)),
+        _ => NaiveDate::parse_from_str(value, "%Y-%m-%d").ok(),
+    };
+    if let Some(day) = day {
+        let time = if end_of_day {
+            NaiveTime::from_hms_milli_opt(23, 59, 59, 999)
+        } else {
+            Some(NaiveTime::MIN)
+        };
+        return Ok(day.and_time(time.unwrap_or(NaiveTime::MIN)).and_utc());
+    }
+
+    if let Some(timestamp) = parse_relative(value, now) {
+        return Ok(timestamp);
+    }
+
+    bail!(
+        "Invalid date '{value}': expected YYYY-MM-DD, an RFC 3339 timestamp, today, yesterday, or an age like 7d, 2w, 3m, 1y"
+    )
+}
+
+/// Parses `{n}{unit}` (`d`, `w`, `m`, `y`), optionally followed by `ago`.
+fn parse_relative(value: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let value = value.to_lowercase();
+    let value = value.trim_end_matches("ago").trim();
+    let unit_start = value.find(|c: char| !c.is_ascii_digit())?;
+    let (count, unit) = value.split_at(unit_start);
+    let count: u32 = count.parse().ok()?;
+
+    match unit.trim() {
+        "d" | "day" | "days" => now.checked_sub_signed(TimeDelta::days(count.into())),
+        "w" | "week" | "weeks" => now.checked_sub_signed(TimeDelta::weeks(count.into())),
+        "m" | "month" | "months" => now.checked_sub_months(Months::new(count)),
+        "y" | "year" | "years" => now.checked_sub_months(Months::new(count.checked_mul(12)?)),
+        _ => None,
+    }
+}
+
+/// Parses a due date relative to `today`.
+///
+/// Accepts an ISO date, `today`, `tomorrow`, a weekday (`fri`, `next monday`:
+/// the next one after today), `next week`, `next month`, or a time from now
+/// (`3d`, `in 2 weeks`, `+1m`).
+pub fn parse_due(value: &str, today: NaiveDate) -> Result<NaiveDate> {
+    let value = value.trim().to_lowercase();
+    let due = match value.as_str() {
+        "today" => Some(today),
+        "tomorrow" => today.checked_add_days(Days::new(1)),
+        "next week" => today.checked_add_days(Days::new(7)),
+        "next month" => today.checked_add_months(Months::new(1)),
+        _ => DATE_FORMATS
+            .iter()
+            .find_map(|format| NaiveDate::parse_from_str(&value, format).ok())
+            .or_else(|| next_weekday(&value, today))
+            .or_else(|| parse_ahead(&value, today)),
+    };
+    let Some(due) = due else {
+        bail!(
+            "Invalid due date '{value}': expected YYYY-MM-DD, today, tomorrow, a weekday, next week, next month, or a time from now like 3d, 2w, in 1 month"
+        );
+    };
+    Ok(due)
+}
+
+/// Parses a `--due` value relative to the local date (see [`parse_due`]).
+pub fn parse_due_from_today(value: &str) -> Result<NaiveDate> {
+    parse_due(value, Local::now().date_naive())
+}
+
+/// Returns the next date after `today` falling on a weekday (`fri`, `next friday`).
+fn next_weekday(value: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let weekday = Weekday::from_str(value.strip_prefix("next ").unwrap_or(value)).ok()?;
+    let days = (7 + weekday.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7;
+    today.checked_add_days(Days::new(u64::from(if days == 0 { 7 } else { days })))
+}
+
+/// Parses `{n}{unit}` (`d`, `w`, `m`, `y`) from `today`, optionally preceded by
+/// `in` or `+`.
+fn parse_ahead(value: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let value = value
+        .strip_prefix("in ")
+        .or_else(|| value.strip_prefix('+'))
+        .unwrap_or(value)
+        .trim();
+    let unit_start = value.find(|c: char| !c.is_ascii_digit())?;
+    let (count, unit) = value.split_at(unit_start);
+    let count: u32 = count.parse().ok()?;
+    match unit.trim() {
+        "d" | "day" | "days" => today.checked_add_days(Days::new(count.into())),
+        "w" | "week" | "weeks" => today.checked_add_days(Days::new(u64::from(count) * 7)),
+        "m" | "month" | "months" => today.checked_add_months(Months::new(count)),
+        "y" | "year" | "years" => today.checked_add_months(Months::new(count.checked_mul(12)?)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 15, 10, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_calendar_dates() {
+        assert_eq!(
+            parse_bound("2026-01-05", now(), false).unwrap(),
+            Utc.with_ymd_and_hms(2026, 1, 5, 0, 0, 0).unwrap()
+        );
+        let until = parse_bound("2026-01-05", now(), true).unwrap();
+        assert!(until > Utc.with_ymd_and_hms(2026, 1, 5, 23, 59, 59).unwrap());
+        assert!(until < Utc.with_ymd_and_hms(2026, 1, 6, 0, 0, 0).unwrap());
+        assert_eq!(
+            parse_bound("2026-01-05T08:00:00+02:00", now(), true).unwrap(),
+            Utc.with_ymd_and_hms(2026, 1, 5, 6, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_named_days() {
+        assert_eq!(
+            parse_bound("today", now(), false).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 15, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            parse_bound("Yesterday", now(), false).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 14, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_relative_ages() {
+        assert_eq!(
+            parse_bound("7d", now(), false).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 8, 10, 30, 0).unwrap()
+        );
+        assert_eq!(
+            parse_bound("2 weeks ago", now(), false).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 1, 10, 30, 0).unwrap()
+        );
+        assert_eq!(
+            parse_bound("3m", now(), false).unwrap(),
+            Utc.with_ymd_and_hms(2025, 12, 15, 10, 30, 0).unwrap()
+        );
+        assert_eq!(
+            parse_bound("1y", now(), false).unwrap(),
+            Utc.with_ymd_and_hms(2025, 3, 15, 10, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for value in ["", "soon", "7x", "2026-13-01", "d"] {
+            assert!(parse_bound(value, now(), false).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_parse_timestamp_variants() {
+        let expected = Utc.with_ymd_and_hms(2026, 1, 5, 8, 30, 0).unwrap();
+        for value in [
+            "2026-01-05T08:30:00Z",
+            "2026-01-05T08:30:00.000Z",
+            "2026-01-05T10:30:00+02:00",
+            "2026-01-05T08:30:00",
+            "2026-01-05 08:30:00",
+            "2026-01-05 08:30:00 UTC",
+            "2026-01-05 10:30:00 +0200",
+            "2026-01-05T10:30+02:00",
+            "2026-01-05T08:30Z",
+            "2026-01-05 08:30",
+            "Mon, 05 Jan 2026 08:30:00 +0000",
+            "1767601800",
+        ] {
+            assert_eq!(parse_timestamp(value), Some(expected), "{value}");
+        }
+
+        let midnight = Utc.with_ymd_and_hms(2026, 1, 5, 0, 0, 0).unwrap();
+        for value in ["2026-01-05", "2026/01/05", "20260105"] {
+            assert_eq!(parse_timestamp(value), Some(midnight), "{value}");
+        }
+
+        for value in ["", "soon", "2026-13-05", "05.01.2026"] {
+            assert_eq!(parse_timestamp(value), None, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_date_format() {
+        let timestamp =
+            Utc.with_ymd_and_hms(2026, 1, 5, 8, 30, 15).unwrap() + TimeDelta::milliseconds(250);
+        assert_eq!(
+            DateFormat::Rfc3339.format(timestamp),
+            "2026-01-05T08:30:15.250Z"
+        );
+        assert_eq!(
+            DateFormat::Seconds.format(timestamp),
+            "2026-01-05T08:30:15Z"
+        );
+        assert_eq!(DateFormat::Minutes.format(timestamp), "2026-01-05T08:30Z");
+        assert_eq!(DateFormat::Date.format(timestamp), "2026-01-05");
+    }
+
+    #[test]
+    fn test_date_range_contains() {
+        let range = DateRange {
+            since: Some(Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()),
+            until: None,
+        };
+        assert!(range.contains(Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()));
+        assert!(!range.contains(Utc.with_ymd_and_hms(2025, 12, 31, 23, 0, 0).unwrap()));
+        assert!(DateRange::default().contains(now()));
+    }
+
+    #[test]
+    fn test_parse_due() {
+        // 2026-03-15 is a Sunday
+        let today = NaiveDate::from_ymd_opt(2026, 3, 15).unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(parse_due("2026-04-01", today).unwrap(), date(2026, 4, 1));
+        assert_eq!(parse_due("Tomorrow", today).unwrap(), date(2026, 3, 16));
+        assert_eq!(parse_due("fri", today).unwrap(), date(2026, 3, 20));
+        assert_eq!(parse_due("next sunday", today).unwrap(), date(2026, 3, 22));
+        assert_eq!(parse_due("next week", today).unwrap(), date(2026, 3, 22));
+        assert_eq!(parse_due("in 3 days", today).unwrap(), date(2026, 3, 18));
+        assert_eq!(parse_due("2w", today).unwrap(), date(2026, 3, 29));
+        assert_eq!(parse_due("+1m", today).unwrap(), date(2026, 4, 15));
+        assert!(parse_due("someday", today).is_err());
+    }
+}
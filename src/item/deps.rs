@@ -0,0 +1,134 @@
+//! # Dependencies
+//!
+//! Blocking links between items. An item lists the items it `depends_on`, or
+//! the items it `blocks`; either side may record a link, and both are read
+//! the same way. An item is blocked while any of its blockers is still open
+//! or in progress.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use super::Item;
+
+/// Direction of a dependency link, as recorded on the item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkKind {
+    /// The item can't be worked on until the other is closed
+    DependsOn,
+    /// The other item can't be worked on until this one is closed
+    Blocks,
+}
+
+impl LinkKind {
+    /// Frontmatter field holding links of this kind
+    pub const fn field(self) -> &'static str {
+        match self {
+            Self::DependsOn => "depends_on",
+            Self::Blocks => "blocks",
+        }
+    }
+
+    /// Returns the linked IDs of an item.
+    pub fn ids(self, item: &Item) -> &[String] {
+        match self {
+            Self::DependsOn => item.depends_on(),
+            Self::Blocks => item.blocks(),
+        }
+    }
+
+    /// Returns the linked IDs of an item for editing.
+    pub fn ids_mut(self, item: &mut Item) -> &mut Vec<String> {
+        match self {
+            Self::DependsOn => &mut item.frontmatter.depends_on,
+            Self::Blocks => &mut item.frontmatter.blocks,
+        }
+    }
+}
+
+/// Which items are blocked by open or in-progress items
+#[derive(Debug, Clone, Default)]
+pub struct Blockers {
+    /// IDs of open and in-progress items
+    active: HashSet<String>,
+    /// Item ID -> IDs of active items declaring that they block it
+    blocked_by: HashMap<String, Vec<String>>,
+}
+
+impl Blockers {
+    /// Collects the links of the given items; closed items don't block.
+    pub fn new<'a>(items: impl IntoIterator<Item = &'a Item>) -> Self {
+        let mut blockers = Self::default();
+        for item in items {
+            if !item.status().is_active() {
+                continue;
+            }
+            blockers.active.insert(item.id().to_string());
+            for other in item.blocks() {
+                blockers
+                    .blocked_by
+                    .entry(other.clone())
+                    .or_default()
+                    .push(item.id().to_string());
+            }
+        }
+        blockers
+    }
+
+    /// Returns the IDs of the active items blocking an item, sorted.
+    pub fn of<'a>(&'a self, item: &'a Item) -> Vec<&'a str> {
+        let declared = self.blocked_by.get(item.id()).into_iter().flatten();
+        item.depends_on()
+            .iter()
+            .filter(|id| self.active.contains(id.as_str()))
+            .chain(declared)
+            .map(String::as_str)
+            .filter(|&id| id != item.id())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns true if an active item blocks the item.
+    pub fn is_blocked(&self, item: &Item) -> bool {
+        !self.of(item).is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::item::{Frontmatter, Status};
+
+    fn item(id: &str, status: Status, depends_on: &[&str], blocks: &[&str]) -> Item {
+        Item::new(Frontmatter {
+            id: id.to_string(),
+            status,
+            depends_on: depends_on.iter().map(ToString::to_string).collect(),
+            blocks: blocks.iter().map(ToString::to_string).collect(),
+            ..Frontmatter::default()
+        })
+    }
+
+    #[test]
+    fn test_blocked_from_either_side() {
+        let schema = item("A", Status::Open, &[], &["B"]);
+        let api = item("B", Status::InProgress, &[], &[]);
+        let docs = item("C", Status::Open, &["B", "Z"], &[]);
+        let blockers = Blockers::new([&schema, &api, &docs]);
+
+        assert_eq!(blockers.of(&api), ["A"]);
+        assert_eq!(blockers.of(&docs), ["B"]);
+        assert!(!blockers.is_blocked(&schema));
+    }
+
+    #[test]
+    fn test_closed_items_do_not_block() {
+        let schema = item("A", Status::Closed, &[], &["B"]);
+        let api = item("B", Status::Open, &["A"], &[]);
+        let blockers = Blockers::new([&schema, &api]);
+
+        assert!(!blockers.is_blocked(&api));
+    }
+}
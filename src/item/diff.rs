@@ -0,0 +1,244 @@
+//! # Item Diff
+//!
+//! Compares two versions of an item: a field-level diff of the frontmatter and
+//! a line diff of the body.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::fmt;
+
+use anyhow::{Context, Result};
+use serde_yml::Value;
+
+use super::Frontmatter;
+
+/// A changed frontmatter field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldChange {
+    Added {
+        field: String,
+        value: String,
+    },
+    Removed {
+        field: String,
+        value: String,
+    },
+    Changed {
+        field: String,
+        old: String,
+        new: String,
+    },
+}
+
+impl fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added { field, value } => write!(f, "+ {field}: {value}"),
+            Self::Removed { field, value } => write!(f, "- {field}: {value}"),
+            Self::Changed { field, old, new } => write!(f, "~ {field}: {old} -> {new}"),
+        }
+    }
+}
+
+/// Compares two frontmatters field by field, in frontmatter order.
+pub fn fields(old: &Frontmatter, new: &Frontmatter) -> Result<Vec<FieldChange>> {
+    let old = to_fields(old)?;
+    let new = to_fields(new)?;
+    let mut changes = Vec::new();
+
+    for (field, old_value) in &old {
+        match new.iter().find(|(key, _)| key == field) {
+            None => changes.push(FieldChange::Removed {
+                field: field.clone(),
+                value: format_value(old_value),
+            }),
+            Some((_, new_value)) if new_value != old_value => {
+                changes.push(FieldChange::Changed {
+                    field: field.clone(),
+                    old: format_value(old_value),
+                    new: format_value(new_value),
+                });
+            }
+            Some(_) => {}
+        }
+    }
+
+    for (field, new_value) in &new {
+        if !old.iter().any(|(key, _)| key == field) {
+            changes.push(FieldChange::Added {
+                field: field.clone(),
+                value: format_value(new_value),
+            });
+        }
+    }
+
+    Ok(changes)
+}
+
+fn to_fields(frontmatter: &Frontmatter) -> Result<Vec<(String, Value)>> {
+    let value = serde_yml::to_value(frontmatter).context("Failed to serialize frontmatter")?;
+    let Value::Mapping(map) = value else {
+        return Ok(Vec::new());
+    };
+    Ok(map.into_iter().collect())
+}
+
+/// Formats a YAML value on a single line (`[a, b]` for lists).
+pub fn format_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Sequence(seq) => {
+            let items: Vec<String> = seq.iter().map(format_value).collect();
+            format!("[{}]", items.join(", "))
+        }
+        other => serde_yml::to_string(other)
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+/// Line diff of two texts: removed lines as `-line`, added lines as `+line`.
+///
+/// Unchanged lines are omitted.
+pub fn changed_lines(old: &str, new: &str) -> Vec<String> {
+    lines(old, new)
+        .into_iter()
+        .filter(|line| !line.starts_with(' '))
+        .collect()
+}
+
+/// Line diff of two texts with `context` unchanged lines around each change.
+///
+/// Skipped unchanged runs are shown as a single `...` line.
+pub fn hunks(old: &str, new: &str, context: usize) -> Vec<String> {
+    let all = lines(old, new);
+    let changed: Vec<usize> = (0..all.len())
+        .filter(|&i| !all[i].starts_with(' '))
+        .collect();
+    if changed.is_empty() {
+        return Vec::new();
+    }
+
+    let near_change = |i: usize| {
+        changed
+            .iter()
+            .any(|&c| c.saturating_sub(context) <= i && i <= c + context)
+    };
+
+    let mut result = Vec::new();
+    let mut skipped = false;
+    for (i, line) in all.into_iter().enumerate() {
+        if near_change(i) {
+            if skipped {
+                result.push("...".to_string());
+                skipped = false;
+            }
+            result.push(line);
+        } else if !result.is_empty() {
+            skipped = true;
+        }
+    }
+    result
+}
+
+/// Full line diff: unchanged lines prefixed with a space, removed with `-`,
+/// added with `+`.
+///
+/// Uses a longest-common-subsequence table, which is plenty for item-sized
+/// inputs.
+fn lines(old: &str, new: &str) -> Vec<String> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+
+    // lcs[i][j] = LCS length of a[i..] and b[j..]
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            diff.push(format!(" {}", a[i]));
+            i += 1;
+            j += 1;
+        } else if i < a.len() && (j == b.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            diff.push(format!("-{}", a[i]));
+            i += 1;
+        } else {
+            diff.push(format!("+{}", b[j]));
+            j += 1;
+        }
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changed_lines() {
+        let old = "id: A\nstatus: open\nlabels:\n  - bug\n";
+        let new = "id: A\nstatus: closed\nlabels:\n  - bug\n  - ui\n";
+        assert_eq!(
+            changed_lines(old, new),
+            ["-status: open", "+status: closed", "+  - ui"]
+        );
+        assert!(changed_lines(old, old).is_empty());
+    }
+
+    #[test]
+    fn test_hunks_with_context() {
+        let old = "a\nb\nc\nd\ne\nf\ng\n";
+        let new = "a\nB\nc\nd\ne\nf\nG\n";
+        assert_eq!(
+            hunks(old, new, 1),
+            [" a", "-b", "+B", " c", "...", " f", "-g", "+G"]
+        );
+    }
+
+    #[test]
+    fn test_fields() {
+        let old = Frontmatter {
+            id: "A".to_string(),
+            title: "Old".to_string(),
+            labels: vec!["bug".to_string()],
+            ..Frontmatter::default()
+        };
+        let mut new = Frontmatter {
+            title: "New".to_string(),
+            labels: vec![],
+            ..old.clone()
+        };
+        new.extra
+            .insert("priority".to_string(), Value::from("high"));
+
+        assert_eq!(
+            fields(&old, &new).unwrap(),
+            [
+                FieldChange::Changed {
+                    field: "title".to_string(),
+                    old: "Old".to_string(),
+                    new: "New".to_string(),
+                },
+                FieldChange::Removed {
+                    field: "labels".to_string(),
+                    value: "[bug]".to_string(),
+                },
+                FieldChange::Added {
+                    field: "priority".to_string(),
+                    value: "high".to_string(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! # Text Folding
+//!
+//! Folded text is the comparison key for labels, categories, authors, and
+//! search queries, so `Bug`, `bug`, and `BUG` are the same label everywhere:
+//!
+//! - Case is folded with Unicode lowercase mapping, plus the full folds of
+//!   `ß`/`ẞ` (`ss`) and final sigma (`ς` → `σ`).
+//! - With `fold_accents` enabled, diacritics are removed as well (`é` → `e`,
+//!   `ü` → `u`), so `cafe` finds `Café`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{cell::Cell, collections::BTreeMap};
+
+use super::translit;
+
+thread_local! {
+    /// Whether diacritics are folded too (set from the config).
+    static FOLD_ACCENTS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Sets whether [`key`] and the comparisons built on it ignore diacritics.
+pub fn set_fold_accents(enabled: bool) {
+    FOLD_ACCENTS.with(|cell| cell.set(enabled));
+}
+
+/// Returns the folded form of `text`, used to compare it to other text.
+pub fn key(text: &str) -> String {
+    let accents = FOLD_ACCENTS.with(Cell::get);
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        push_folded(&mut out, c, accents);
+    }
+    out
+}
+
+/// Returns the folded form of a single character (one or more characters).
+pub fn char_key(c: char) -> String {
+    let mut out = String::new();
+    push_folded(&mut out, c, FOLD_ACCENTS.with(Cell::get));
+    out
+}
+
+/// Returns true if two strings are equal after folding.
+pub fn eq(a: &str, b: &str) -> bool {
+    a == b || key(a) == key(b)
+}
+
+/// Returns true if `haystack` contains `needle` after folding.
+pub fn contains(haystack: &str, needle: &str) -> bool {
+    key(haystack).contains(&key(needle))
+}
+
+/// Returns true if `text` starts with `prefix` after folding.
+pub fn starts_with(text: &str, prefix: &str) -> bool {
+    key(text).starts_with(&key(prefix))
+}
+
+/// Merges values that fold to the same key, like the labels `Bug` and `bug`.
+///
+/// Returns the most common spelling of each value (ties go to the first in
+/// sort order), by folded key.
+pub fn spellings<'a>(values: impl IntoIterator<Item = &'a str>) -> BTreeMap<String, String> {
+    let mut counts: BTreeMap<String, BTreeMap<&str, usize>> = BTreeMap::new();
+    for value in values {
+        *counts
+            .entry(key(value))
+            .or_default()
+            .entry(value)
+            .or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .map(|(key, spellings)| {
+            let (spelling, _) = spellings
+                .into_iter()
+                .rev()
+                .max_by_key(|&(_, count)| count)
+                .unwrap_or_default();
+            (key, spelling.to_string())
+        })
+        .collect()
+}
+
+fn push_folded(out: &mut String, c: char, accents: bool) {
+    match c {
+        'ß' | 'ẞ' => out.push_str("ss"),
+        'ς' => out.push('σ'),
+        // Combining marks of decomposed input
+        '\u{0300}'..='\u{036F}' if accents => {}
+        _ => {
+            for lower in c.to_lowercase() {
+                match translit::strip_diacritics(lower) {
+                    Some(plain) if accents => out.push_str(plain),
+                    _ => out.push(lower),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_case_folding() {
+        assert!(eq("Bug", "bug"));
+        assert!(eq("ÜBER", "über"));
+        assert!(eq("Straße", "STRASSE"));
+        assert!(eq("ΟΔΟΣ", "οδος"));
+        assert!(!eq("bug", "bugs"));
+    }
+
+    #[test]
+    fn test_accents_only_when_enabled() {
+        assert!(!eq("café", "cafe"));
+
+        set_fold_accents(true);
+        assert!(eq("Café", "cafe"));
+        assert!(eq("cafe\u{0301}", "café"));
+        assert!(contains("Résumé parsing", "resume"));
+        set_fold_accents(false);
+    }
+
+    #[test]
+    fn test_spellings_prefer_most_common() {
+        let merged = spellings(["bug", "Bug", "ui", "bug", "UI"]);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged["bug"], "bug");
+        // Ties go to the first in sort order
+        assert_eq!(merged["ui"], "UI");
+    }
+
+    #[test]
+    fn test_starts_with() {
+        assert!(starts_with("Frontend", "front"));
+        assert!(!starts_with("Frontend", "end"));
+    }
+}
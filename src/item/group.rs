@@ -0,0 +1,171 @@
+//! # Grouping
+//!
+//! Groups items by label, category, assignee, status, or milestone for
+//! `qs list --group-by`. Shared by the plain list output and the TUI.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::collections::BTreeMap;
+
+use super::{fold, identity::Identities, Item, Status};
+use crate::constants::MILESTONE_FIELD;
+
+/// Field to group items by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum GroupBy {
+    /// One group per label (items with several labels appear in each)
+    Label,
+    Category,
+    /// The `assignee` field, resolved through identities
+    Assignee,
+    Status,
+    /// The `milestone` custom field
+    Milestone,
+}
+
+impl GroupBy {
+    /// Returns the names of the groups an item belongs to.
+    ///
+    /// Empty when the item has no value for the field.
+    pub fn keys(self, item: &Item, category: Option<&str>, identities: &Identities) -> Vec<String> {
+        match self {
+            Self::Label => item.labels().to_vec(),
+            Self::Category => category.map(ToString::to_string).into_iter().collect(),
+            Self::Assignee => item
+                .assignee()
+                .map(|name| identities.canonical(&name).to_string())
+                .into_iter()
+                .collect(),
+            Self::Status => vec![item.status().to_string()],
+            Self::Milestone => item.custom_field(MILESTONE_FIELD).into_iter().collect(),
+        }
+    }
+
+    /// Name of the group for items without a value
+    const fn none_name(self) -> &'static str {
+        match self {
+            Self::Label => "(no label)",
+            Self::Category => "(no category)",
+            Self::Assignee => "(unassigned)",
+            Self::Status => "(no status)",
+            Self::Milestone => "(no milestone)",
+        }
+    }
+}
+
+/// A group of items
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    pub name: String,
+    /// Indices of the member items, in their original order
+    pub members: Vec<usize>,
+}
+
+/// Groups items given the group keys of each item (see [`GroupBy::keys`]).
+///
+/// Keys differing only in case (`Bug`, `bug`) form one group, named by the
+/// most common spelling. Status groups follow the workflow order; other groups
+/// are sorted by name, with the group of items without a value last.
+pub fn group(by: GroupBy, keys: impl IntoIterator<Item = Vec<String>>) -> Vec<Group> {
+    let keys: Vec<Vec<String>> = keys.into_iter().collect();
+    let spellings = fold::spellings(keys.iter().flatten().map(String::as_str));
+
+    let mut named: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+    let mut none = Vec::new();
+    for (index, keys) in keys.iter().enumerate() {
+        if keys.is_empty() {
+            none.push(index);
+        }
+        for key in keys {
+            let members = named.entry(&spellings[&fold::key(key)]).or_default();
+            if members.last() != Some(&index) {
+                members.push(index);
+            }
+        }
+    }
+
+    let mut groups: Vec<Group> = named
+        .into_iter()
+        .map(|(name, members)| Group {
+            name: name.to_string(),
+            members,
+        })
+        .collect();
+    if by == GroupBy::Status {
+        let order = [
+            Status::Open,
+            Status::InProgress,
+            Status::Closed,
+            Status::Template,
+        ]
+        .map(|status| status.to_string());
+        groups.sort_by_key(|group| order.iter().position(|name| *name == group.name));
+    }
+    if !none.is_empty() {
+        groups.push(Group {
+            name: by.none_name().to_string(),
+            members: none,
+        });
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(values: &[&[&str]]) -> Vec<Vec<String>> {
+        values
+            .iter()
+            .map(|keys| keys.iter().map(ToString::to_string).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_group_merges_spellings() {
+        let groups = group(GroupBy::Label, keys(&[&["Bug"], &["bug"], &["bug", "BUG"]]));
+        assert_eq!(
+            groups,
+            vec![Group {
+                name: "bug".to_string(),
+                members: vec![0, 1, 2]
+            }]
+        );
+    }
+
+    #[test]
+    fn test_group_by_name_with_none_last() {
+        let groups = group(
+            GroupBy::Label,
+            keys(&[&["ui", "bug"], &[], &["bug"], &["bug", "bug"]]),
+        );
+        assert_eq!(
+            groups,
+            vec![
+                Group {
+                    name: "bug".to_string(),
+                    members: vec![0, 2, 3]
+                },
+                Group {
+                    name: "ui".to_string(),
+                    members: vec![0]
+                },
+                Group {
+                    name: "(no label)".to_string(),
+                    members: vec![1]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_status_groups_follow_workflow() {
+        let groups = group(
+            GroupBy::Status,
+            keys(&[&["closed"], &["open"], &["in-progress"]]),
+        );
+        let names: Vec<&str> = groups.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(names, vec!["open", "in-progress", "closed"]);
+    }
+}
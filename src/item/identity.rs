@@ -0,0 +1,126 @@
+//! # Author Identities
+//!
+//! Maps the different spellings of a person (short names, full names, git
+//! emails) to one canonical name, in the spirit of git's `.mailmap`. The map is
+//! configured as an `[identities]` table of canonical name to aliases and is
+//! used by author filters, stats, and assignment.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::collections::BTreeMap;
+
+use super::fold;
+
+/// Case-insensitive (folded) lookup from alias to canonical name
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Identities {
+    /// Folded alias (including the canonical name itself) -> canonical name
+    aliases: BTreeMap<String, String>,
+}
+
+impl Identities {
+    /// Builds the lookup from a `canonical name -> aliases` table.
+    pub fn new(table: &BTreeMap<String, Vec<String>>) -> Self {
+        let mut identities = Self::default();
+        identities.extend(table);
+        identities
+    }
+
+    /// Adds entries from a `canonical name -> aliases` table; later entries win.
+    pub fn extend(&mut self, table: &BTreeMap<String, Vec<String>>) {
+        for (canonical, aliases) in table {
+            let canonical = canonical.trim();
+            if canonical.is_empty() {
+                continue;
+            }
+            for alias in std::iter::once(canonical).chain(aliases.iter().map(|a| a.trim())) {
+                if !alias.is_empty() {
+                    self.aliases.insert(fold::key(alias), canonical.to_string());
+                }
+            }
+        }
+    }
+
+    /// Looks up the canonical name for a spelling, if it is known.
+    ///
+    /// Accepts git-style `Name <email>` and tries the email first, then the name.
+    pub fn lookup(&self, name: &str) -> Option<&str> {
+        let name = name.trim();
+        if let Some((display, rest)) = name.split_once('<') {
+            let email = rest.trim_end_matches('>').trim();
+            return self.get(email).or_else(|| self.get(display.trim()));
+        }
+        self.get(name)
+    }
+
+    /// Returns the canonical name for a spelling, or the spelling itself if unknown.
+    pub fn canonical<'a>(&'a self, name: &'a str) -> &'a str {
+        self.lookup(name).unwrap_or_else(|| name.trim())
+    }
+
+    /// Returns true if two spellings refer to the same person.
+    pub fn same(&self, a: &str, b: &str) -> bool {
+        fold::eq(self.canonical(a), self.canonical(b))
+    }
+
+    fn get(&self, alias: &str) -> Option<&str> {
+        self.aliases.get(&fold::key(alias)).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Identities {
+        let mut table = BTreeMap::new();
+        table.insert(
+            "Dominic Rodemer".to_string(),
+            vec!["Dom".to_string(), "dom@example.com".to_string()],
+        );
+        Identities::new(&table)
+    }
+
+    #[test]
+    fn test_canonical() {
+        let identities = sample();
+        assert_eq!(identities.canonical("dom"), "Dominic Rodemer");
+        assert_eq!(identities.canonical("DOM@example.com"), "Dominic Rodemer");
+        assert_eq!(identities.canonical("dominic rodemer"), "Dominic Rodemer");
+        assert_eq!(identities.canonical("Alice"), "Alice");
+    }
+
+    #[test]
+    fn test_canonical_git_style() {
+        let identities = sample();
+        assert_eq!(
+            identities.canonical("Someone Else <dom@example.com>"),
+            "Dominic Rodemer"
+        );
+        assert_eq!(
+            identities.canonical("Dom <other@example.com>"),
+            "Dominic Rodemer"
+        );
+        assert!(identities.lookup("Alice <alice@example.com>").is_none());
+    }
+
+    #[test]
+    fn test_same() {
+        let identities = sample();
+        assert!(identities.same("Dom", "dom@example.com"));
+        assert!(identities.same("Alice", "alice"));
+        assert!(!identities.same("Dom", "Alice"));
+        assert!(!Identities::default().same("Dom", "Dominic Rodemer"));
+    }
+
+    #[test]
+    fn test_extend_overrides() {
+        let mut identities = sample();
+        let mut table = BTreeMap::new();
+        table.insert("Dominic R.".to_string(), vec!["Dom".to_string()]);
+        identities.extend(&table);
+        assert_eq!(identities.canonical("Dom"), "Dominic R.");
+        assert_eq!(identities.canonical("dom@example.com"), "Dominic Rodemer");
+    }
+}
@@ -0,0 +1,204 @@
+//! # Label Definitions
+//!
+//! The predefined label set of the `[labels]` config table: each label has a
+//! name and optionally a description and a color. Defined names are the
+//! canonical spelling of their labels, and with `strict = true` only defined
+//! labels may be added (see `policy::check_labels`).
+//!
+//! Colors are kept per thread, set from the config like the slug and date
+//! options, so cards and listings can color labels without the config.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{cell::RefCell, collections::HashMap};
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+
+use super::fold;
+
+thread_local! {
+    /// Folded label name -> color of the defined labels (set from the config).
+    static COLORS: RefCell<HashMap<String, LabelColor>> = RefCell::new(HashMap::new());
+}
+
+/// A predefined label (`[[labels.defined]]` entry)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LabelDef {
+    pub name: String,
+
+    /// What the label means, shown by `qs list --labels`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Color name (`red`, `blue`, ...) or hex value (`#d73a4a`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+/// Color a label is rendered in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelColor {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+    Rgb(u8, u8, u8),
+}
+
+impl LabelColor {
+    /// Parses a color name or a hex value (`#d73a4a` or `d73a4a`, as GitHub
+    /// shows them).
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let named = match text.to_ascii_lowercase().as_str() {
+            "black" => Some(Self::Black),
+            "red" => Some(Self::Red),
+            "green" => Some(Self::Green),
+            "yellow" => Some(Self::Yellow),
+            "blue" => Some(Self::Blue),
+            "magenta" | "purple" => Some(Self::Magenta),
+            "cyan" => Some(Self::Cyan),
+            "white" => Some(Self::White),
+            "gray" | "grey" => Some(Self::Gray),
+            _ => None,
+        };
+        if named.is_some() {
+            return named;
+        }
+
+        let hex = text.strip_prefix('#').unwrap_or(text);
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |at: usize| u8::from_str_radix(&hex[at..at + 2], 16).ok();
+        Some(Self::Rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+
+    /// Returns the color as a hex value without `#`, as GitHub expects it.
+    pub fn hex(self) -> String {
+        let (r, g, b) = match self {
+            Self::Black => (0x24, 0x29, 0x2f),
+            Self::Red => (0xd7, 0x3a, 0x4a),
+            Self::Green => (0x2d, 0xa4, 0x4e),
+            Self::Yellow => (0xfb, 0xca, 0x04),
+            Self::Blue => (0x03, 0x66, 0xd6),
+            Self::Magenta => (0x82, 0x50, 0xdf),
+            Self::Cyan => (0x1b, 0xa0, 0xb8),
+            Self::White => (0xff, 0xff, 0xff),
+            Self::Gray => (0x8b, 0x94, 0x9e),
+            Self::Rgb(r, g, b) => (r, g, b),
+        };
+        format!("{r:02x}{g:02x}{b:02x}")
+    }
+
+    /// Returns `text` colored for the terminal.
+    pub fn paint(self, text: &str) -> String {
+        match self {
+            Self::Black => text.black().to_string(),
+            Self::Red => text.red().to_string(),
+            Self::Green => text.green().to_string(),
+            Self::Yellow => text.yellow().to_string(),
+            Self::Blue => text.blue().to_string(),
+            Self::Magenta => text.magenta().to_string(),
+            Self::Cyan => text.cyan().to_string(),
+            Self::White => text.white().to_string(),
+            Self::Gray => text.bright_black().to_string(),
+            Self::Rgb(r, g, b) => text.truecolor(r, g, b).to_string(),
+        }
+    }
+}
+
+/// Sets the label colors used by [`color`]; fails on colors that can't be parsed.
+pub fn set_colors(defined: &[LabelDef]) -> Result<()> {
+    let mut colors = HashMap::new();
+    for label in defined {
+        let Some(ref text) = label.color else {
+            continue;
+        };
+        let Some(color) = LabelColor::parse(text) else {
+            bail!(
+                "Invalid color '{text}' for label '{}' (expected a color name or #rrggbb)",
+                label.name
+            );
+        };
+        colors.insert(fold::key(&label.name), color);
+    }
+    COLORS.with(|cell| *cell.borrow_mut() = colors);
+    Ok(())
+}
+
+/// Returns the color of a defined label, if it has one.
+pub fn color(label: &str) -> Option<LabelColor> {
+    COLORS.with(|cell| cell.borrow().get(&fold::key(label)).copied())
+}
+
+/// Returns `label` in its defined color, or magenta like undefined labels.
+pub fn paint(label: &str) -> String {
+    color(label).map_or_else(|| label.magenta().to_string(), |color| color.paint(label))
+}
+
+/// Finds the definition of a label, ignoring case.
+pub fn find<'a>(defined: &'a [LabelDef], label: &str) -> Option<&'a LabelDef> {
+    defined.iter().find(|def| fold::eq(&def.name, label))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn def(name: &str, color: Option<&str>) -> LabelDef {
+        LabelDef {
+            name: name.to_string(),
+            color: color.map(ToString::to_string),
+            ..LabelDef::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_colors() {
+        assert_eq!(LabelColor::parse("Red"), Some(LabelColor::Red));
+        assert_eq!(LabelColor::parse("grey"), Some(LabelColor::Gray));
+        assert_eq!(
+            LabelColor::parse("#d73a4a"),
+            Some(LabelColor::Rgb(0xd7, 0x3a, 0x4a))
+        );
+        assert_eq!(
+            LabelColor::parse("0E8A16"),
+            Some(LabelColor::Rgb(0x0e, 0x8a, 0x16))
+        );
+        assert_eq!(LabelColor::parse("#d73a4"), None);
+        assert_eq!(LabelColor::parse("rouge"), None);
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(LabelColor::Red.hex(), "d73a4a");
+        assert_eq!(LabelColor::parse("#0E8A16").unwrap().hex(), "0e8a16");
+    }
+
+    #[test]
+    fn test_colors_ignore_case() {
+        set_colors(&[def("bug", Some("red")), def("docs", None)]).unwrap();
+        assert_eq!(color("Bug"), Some(LabelColor::Red));
+        assert_eq!(color("docs"), None);
+        assert_eq!(color("ui"), None);
+
+        assert!(set_colors(&[def("bug", Some("rouge"))]).is_err());
+        set_colors(&[]).unwrap();
+    }
+
+    #[test]
+    fn test_find() {
+        let defined = [def("bug", None), def("needs-qa", None)];
+        assert_eq!(find(&defined, "BUG").map(|d| d.name.as_str()), Some("bug"));
+        assert!(find(&defined, "feature").is_none());
+    }
+}
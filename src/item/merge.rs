@@ -0,0 +1,434 @@
+//! # Item Merge
+//!
+//! Three-way merge of item files for the `qs merge-driver` git merge driver.
+//! Frontmatter is merged field by field: labels, dependencies, and attachments
+//! are combined, timestamps changed on both sides take the later value, and
+//! other fields take whichever side changed them. When both sides changed a field to
+//! different values, ours wins and the merge notes it. Only the body can end
+//! up with conflict markers.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+use serde_yml::Value;
+
+use super::{
+    diff,
+    parser::{self, Document},
+    Frontmatter, Status,
+};
+
+/// Conflict marker opening our side
+const OURS_MARKER: &str = "<<<<<<< ours";
+/// Conflict marker between the two sides
+const SEPARATOR_MARKER: &str = "=======";
+/// Conflict marker closing their side
+const THEIRS_MARKER: &str = ">>>>>>> theirs";
+
+/// Result of merging an item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Merged {
+    /// Merged file content
+    pub content: String,
+    /// Number of conflicting body regions (wrapped in conflict markers)
+    pub conflicts: usize,
+    /// Frontmatter fields both sides changed, resolved without markers
+    pub notes: Vec<String>,
+}
+
+/// Merges two versions of an item file against their common ancestor.
+///
+/// The result keeps the frontmatter syntax of `ours`. If any version can't be
+/// parsed, the whole files are merged line by line instead.
+pub fn merge(base: &str, ours: &str, theirs: &str) -> Result<Merged> {
+    let (Ok(base_doc), Ok(ours_doc), Ok(theirs_doc)) = (
+        parser::parse_document(base),
+        parser::parse_document(ours),
+        parser::parse_document(theirs),
+    ) else {
+        let (content, conflicts) = merge_text(base, ours, theirs);
+        return Ok(Merged {
+            content,
+            conflicts,
+            notes: vec!["merged as plain text (frontmatter could not be parsed)".to_string()],
+        });
+    };
+
+    let Document {
+        frontmatter: ours_fm,
+        body: ours_body,
+        format,
+        ..
+    } = ours_doc;
+    let mut notes = Vec::new();
+    let frontmatter = merge_frontmatter(
+        &base_doc.frontmatter,
+        &ours_fm,
+        &theirs_doc.frontmatter,
+        &mut notes,
+    );
+    let (body, conflicts) = merge_text(&base_doc.body, &ours_body, &theirs_doc.body);
+
+    Ok(Merged {
+        content: parser::serialize(&frontmatter, &body, format)?,
+        conflicts,
+        notes,
+    })
+}
+
+/// Merges frontmatter field by field, adding a note for every field both
+/// sides changed differently.
+pub fn merge_frontmatter(
+    base: &Frontmatter,
+    ours: &Frontmatter,
+    theirs: &Frontmatter,
+    notes: &mut Vec<String>,
+) -> Frontmatter {
+    let mut merger = Merger { notes };
+    let status = merger.scalar("status", &base.status, &ours.status, &theirs.status);
+    let closed_at = latest(&base.closed_at, &ours.closed_at, &theirs.closed_at);
+
+    Frontmatter {
+        id: merger.scalar("id", &base.id, &ours.id, &theirs.id),
+        title: merger.scalar("title", &base.title, &ours.title, &theirs.title),
+        author: merger.scalar("author", &base.author, &ours.author, &theirs.author),
+        assignee: merger.scalar("assignee", &base.assignee, &ours.assignee, &theirs.assignee),
+        created_at: latest(&base.created_at, &ours.created_at, &theirs.created_at),
+        status,
+        // A reopened item has no close time, whatever the other side did
+        closed_at: closed_at.filter(|_| status == Status::Closed),
+        due_date: merger.scalar("due", &base.due_date, &ours.due_date, &theirs.due_date),
+        labels: merge_list(&base.labels, &ours.labels, &theirs.labels),
+        depends_on: merge_list(&base.depends_on, &ours.depends_on, &theirs.depends_on),
+        blocks: merge_list(&base.blocks, &ours.blocks, &theirs.blocks),
+        attachments: merge_list(&base.attachments, &ours.attachments, &theirs.attachments),
+        checksums: merger.map(
+            "checksums",
+            &base.checksums,
+            &ours.checksums,
+            &theirs.checksums,
+        ),
+        link_titles: merger.map(
+            "link_titles",
+            &base.link_titles,
+            &ours.link_titles,
+            &theirs.link_titles,
+        ),
+        actions: merger.map("actions", &base.actions, &ours.actions, &theirs.actions),
+        extra: merger.extra(&base.extra, &ours.extra, &theirs.extra),
+    }
+}
+
+/// Collects notes while merging fields
+struct Merger<'a> {
+    notes: &'a mut Vec<String>,
+}
+
+impl Merger<'_> {
+    /// Takes the side that changed the value; ours if both changed it.
+    fn scalar<T: Clone + PartialEq + serde::Serialize>(
+        &mut self,
+        field: &str,
+        base: &T,
+        ours: &T,
+        theirs: &T,
+    ) -> T {
+        three_way(base, ours, theirs).unwrap_or_else(|| {
+            self.notes.push(format!(
+                "{field} changed on both sides, kept ours ({}) over theirs ({})",
+                display(ours),
+                display(theirs)
+            ));
+            ours.clone()
+        })
+    }
+
+    /// Merges a map key by key.
+    fn map<V: Clone + PartialEq + serde::Serialize>(
+        &mut self,
+        field: &str,
+        base: &BTreeMap<String, V>,
+        ours: &BTreeMap<String, V>,
+        theirs: &BTreeMap<String, V>,
+    ) -> BTreeMap<String, V> {
+        let keys: BTreeSet<&String> = base
+            .keys()
+            .chain(ours.keys())
+            .chain(theirs.keys())
+            .collect();
+        keys.into_iter()
+            .filter_map(|key| {
+                let value = self.scalar(
+                    &format!("{field}.{key}"),
+                    &base.get(key).cloned(),
+                    &ours.get(key).cloned(),
+                    &theirs.get(key).cloned(),
+                )?;
+                Some((key.clone(), value))
+            })
+            .collect()
+    }
+
+    /// Merges custom fields key by key; lists changed on both sides are combined.
+    fn extra(
+        &mut self,
+        base: &BTreeMap<String, Value>,
+        ours: &BTreeMap<String, Value>,
+        theirs: &BTreeMap<String, Value>,
+    ) -> BTreeMap<String, Value> {
+        let keys: BTreeSet<&String> = base
+            .keys()
+            .chain(ours.keys())
+            .chain(theirs.keys())
+            .collect();
+        keys.into_iter()
+            .filter_map(|key| {
+                let (base, ours, theirs) = (base.get(key), ours.get(key), theirs.get(key));
+                if let (Some(Value::Sequence(o)), Some(Value::Sequence(t))) = (ours, theirs) {
+                    let b = match base {
+                        Some(Value::Sequence(b)) => b.clone(),
+                        _ => Vec::new(),
+                    };
+                    return Some((key.clone(), Value::Sequence(merge_list(&b, o, t))));
+                }
+                let value = self.scalar(key, &base.cloned(), &ours.cloned(), &theirs.cloned())?;
+                Some((key.clone(), value))
+            })
+            .collect()
+    }
+}
+
+/// Returns the merged value if at most one side changed it (or both made the
+/// same change), `None` if the changes conflict.
+fn three_way<T: Clone + PartialEq>(base: &T, ours: &T, theirs: &T) -> Option<T> {
+    if ours == theirs || theirs == base {
+        Some(ours.clone())
+    } else if ours == base {
+        Some(theirs.clone())
+    } else {
+        None
+    }
+}
+
+/// Takes the side that changed the timestamp; the later one if both did.
+fn latest<T: Clone + Ord>(base: &T, ours: &T, theirs: &T) -> T {
+    three_way(base, ours, theirs).unwrap_or_else(|| ours.max(theirs).clone())
+}
+
+/// Combines two versions of a list: entries added on either side are kept,
+/// entries removed on either side are dropped. Ours keeps its order; entries
+/// only theirs added are appended.
+fn merge_list<T: Clone + PartialEq>(base: &[T], ours: &[T], theirs: &[T]) -> Vec<T> {
+    let removed =
+        |entry: &T| base.contains(entry) && (!ours.contains(entry) || !theirs.contains(entry));
+    let mut merged: Vec<T> = Vec::new();
+    for entry in ours.iter().chain(theirs) {
+        if !removed(entry) && !merged.contains(entry) {
+            merged.push(entry.clone());
+        }
+    }
+    merged
+}
+
+/// Formats a field value for a note.
+fn display<T: serde::Serialize>(value: &T) -> String {
+    match serde_yml::to_value(value) {
+        Ok(Value::Null) => "none".to_string(),
+        Ok(value) => diff::format_value(&value),
+        Err(_) => "?".to_string(),
+    }
+}
+
+/// Three-way line merge. Returns the merged text and the number of
+/// conflicting regions, which are wrapped in conflict markers.
+pub fn merge_text(base: &str, ours: &str, theirs: &str) -> (String, usize) {
+    let base_lines: Vec<&str> = base.lines().collect();
+    let ours_lines: Vec<&str> = ours.lines().collect();
+    let theirs_lines: Vec<&str> = theirs.lines().collect();
+    let in_ours = matching(&base_lines, &ours_lines);
+    let in_theirs = matching(&base_lines, &theirs_lines);
+
+    let mut merged: Vec<&str> = Vec::new();
+    let mut conflicts = 0;
+    let (mut b, mut o, mut t) = (0, 0, 0);
+    loop {
+        // Next base line kept by both sides; everything before it is a changed region
+        let stable = (b..base_lines.len()).find_map(|i| Some((i, in_ours[i]?, in_theirs[i]?)));
+        let (end_b, end_o, end_t) =
+            stable.unwrap_or((base_lines.len(), ours_lines.len(), theirs_lines.len()));
+
+        let base_chunk = &base_lines[b..end_b];
+        let ours_chunk = &ours_lines[o..end_o];
+        let theirs_chunk = &theirs_lines[t..end_t];
+        if ours_chunk == theirs_chunk || theirs_chunk == base_chunk {
+            merged.extend(ours_chunk);
+        } else if ours_chunk == base_chunk {
+            merged.extend(theirs_chunk);
+        } else {
+            conflicts += 1;
+            merged.push(OURS_MARKER);
+            merged.extend(ours_chunk);
+            merged.push(SEPARATOR_MARKER);
+            merged.extend(theirs_chunk);
+            merged.push(THEIRS_MARKER);
+        }
+
+        let Some((i, _, _)) = stable else {
+            break;
+        };
+        merged.push(base_lines[i]);
+        (b, o, t) = (end_b + 1, end_o + 1, end_t + 1);
+    }
+
+    let mut text = merged.join("\n");
+    if !merged.is_empty() && [base, ours, theirs].iter().any(|s| s.ends_with('\n')) {
+        text.push('\n');
+    }
+    (text, conflicts)
+}
+
+/// Maps each line of `a` to the line of `b` it is matched with in a longest
+/// common subsequence.
+fn matching(a: &[&str], b: &[&str]) -> Vec<Option<usize>> {
+    // lcs[i][j] = LCS length of a[i..] and b[j..]
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut matches = vec![None; a.len()];
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            matches[i] = Some(j);
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(fields: &str, body: &str) -> String {
+        format!(
+            "---\nid: 260101-AAAAAAA\ntitle: Crash\nauthor: Dom\ncreated_at: 2026-01-01T09:00:00Z\n{fields}---\n\n{body}"
+        )
+    }
+
+    #[test]
+    fn test_merge_text_clean_and_conflicting() {
+        let base = "a\nb\nc\nd\n";
+        assert_eq!(
+            merge_text(base, "A\nb\nc\nd\n", "a\nb\nc\nD\n"),
+            ("A\nb\nc\nD\n".to_string(), 0)
+        );
+        assert_eq!(
+            merge_text(base, "a\nx\nc\nd\n", "a\ny\nc\nd\n"),
+            (
+                "a\n<<<<<<< ours\nx\n=======\ny\n>>>>>>> theirs\nc\nd\n".to_string(),
+                1
+            )
+        );
+        assert_eq!(merge_text("", "", "new\n"), ("new\n".to_string(), 0));
+    }
+
+    #[test]
+    fn test_merge_list() {
+        let list = |s: &[&str]| s.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            merge_list(
+                &list(&["bug", "ui", "old"]),
+                &list(&["bug", "ui", "old", "urgent"]),
+                &list(&["bug", "perf"]),
+            ),
+            list(&["bug", "urgent", "perf"])
+        );
+    }
+
+    #[test]
+    fn test_merge_fields_without_conflicts() {
+        let base = item("status: open\nlabels:\n- bug\n", "Steps\n");
+        let ours = item(
+            "status: closed\nclosed_at: 2026-01-03T10:00:00Z\nlabels:\n- bug\n- ui\n",
+            "Steps\n",
+        );
+        let theirs = item(
+            "status: open\nlabels:\n- bug\n- urgent\npriority: high\n",
+            "Steps\nMore\n",
+        );
+        let merged = merge(&base, &ours, &theirs).unwrap();
+
+        assert_eq!(merged.conflicts, 0);
+        assert!(merged.notes.is_empty());
+        let (frontmatter, body) = parser::parse(&merged.content).unwrap();
+        assert_eq!(frontmatter.status, Status::Closed);
+        assert!(frontmatter.closed_at.is_some());
+        assert_eq!(frontmatter.labels, vec!["bug", "ui", "urgent"]);
+        assert_eq!(frontmatter.extra["priority"], Value::from("high"));
+        assert_eq!(body, "Steps\nMore\n");
+    }
+
+    #[test]
+    fn test_merge_conflicting_fields_resolved() {
+        let base = item("status: open\n", "");
+        let ours = item(
+            "status: closed\nclosed_at: 2026-01-03T10:00:00Z\npriority: high\n",
+            "",
+        );
+        let theirs = item(
+            "status: closed\nclosed_at: 2026-01-05T10:00:00Z\npriority: low\n",
+            "",
+        );
+        let merged = merge(&base, &ours, &theirs).unwrap();
+
+        let (frontmatter, _) = parser::parse(&merged.content).unwrap();
+        assert_eq!(
+            frontmatter.closed_at.unwrap().to_rfc3339(),
+            "2026-01-05T10:00:00+00:00"
+        );
+        assert_eq!(frontmatter.extra["priority"], Value::from("high"));
+        assert_eq!(
+            merged.notes,
+            vec!["priority changed on both sides, kept ours (high) over theirs (low)"]
+        );
+    }
+
+    #[test]
+    fn test_reopened_item_drops_close_time() {
+        let base = item("status: closed\nclosed_at: 2026-01-03T10:00:00Z\n", "");
+        let ours = item("status: open\n", "");
+        let theirs = item(
+            "status: closed\nclosed_at: 2026-01-04T10:00:00Z\nlabels:\n- ui\n",
+            "",
+        );
+        let merged = merge(&base, &ours, &theirs).unwrap();
+
+        let (frontmatter, _) = parser::parse(&merged.content).unwrap();
+        assert_eq!(frontmatter.status, Status::Open);
+        assert!(frontmatter.closed_at.is_none());
+        assert_eq!(frontmatter.labels, vec!["ui"]);
+    }
+
+    #[test]
+    fn test_unparseable_falls_back_to_text() {
+        let merged = merge("a\n", "b\n", "c\n").unwrap();
+        assert_eq!(merged.conflicts, 1);
+        assert_eq!(merged.notes.len(), 1);
+    }
+}
@@ -5,23 +5,46 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+pub mod action;
+pub mod card;
+pub mod comment;
+pub mod dates;
+pub mod deps;
+pub mod diff;
+pub mod fold;
+pub mod group;
+pub mod identity;
+pub mod labels;
+pub mod merge;
 pub mod parser;
+pub mod patch;
+pub mod quick;
+pub mod record;
+pub mod rules;
 pub mod search;
+pub mod size;
 pub mod slug;
+pub mod translit;
+pub mod triage;
+pub mod variables;
 
 use std::{
+    collections::BTreeMap,
     fmt,
     path::{Path, PathBuf},
 };
 
 use anyhow::{Context, Result};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use self::parser::FrontmatterFormat;
+use crate::constants::GITHUB_ISSUE_FIELD;
+
 pub use self::{
     search::{
-        matches_any_label, matches_author_filter, matches_category_filter, matches_filter,
-        matches_query, matches_search_text, FilterCriteria,
+        matches_all_labels, matches_any_label, matches_author_filter, matches_category_filter,
+        matches_filter, matches_query, matches_search_text, FilterCriteria,
     },
     slug::slugify,
 };
@@ -46,11 +69,14 @@ pub fn normalize_identifier(s: &str) -> String {
 }
 
 /// Item status
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum Status {
     #[default]
     Open,
+    /// Claimed and being worked on (still lives with the open items)
+    #[serde(rename = "in-progress")]
+    InProgress,
     Closed,
     Template,
 }
@@ -59,14 +85,22 @@ impl fmt::Display for Status {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Open => write!(f, "open"),
+            Self::InProgress => write!(f, "in-progress"),
             Self::Closed => write!(f, "closed"),
             Self::Template => write!(f, "template"),
         }
     }
 }
 
+impl Status {
+    /// Returns true for statuses of items that are not closed (open or in progress)
+    pub const fn is_active(self) -> bool {
+        matches!(self, Self::Open | Self::InProgress)
+    }
+}
+
 /// YAML frontmatter for an item
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Frontmatter {
     /// Unique sortable ID
     pub id: String,
@@ -77,20 +111,66 @@ pub struct Frontmatter {
     /// Creator's name
     pub author: String,
 
+    /// Person responsible for the item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+
     /// Creation timestamp (UTC)
+    #[serde(with = "dates::timestamp")]
     pub created_at: DateTime<Utc>,
 
     /// Item status
     #[serde(default)]
     pub status: Status,
 
+    /// When the item was last closed (UTC); cleared when it is reopened
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "dates::optional_timestamp"
+    )]
+    pub closed_at: Option<DateTime<Utc>>,
+
+    /// When the item is due (UTC), stored in the `due` field
+    #[serde(
+        rename = "due",
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "dates::due"
+    )]
+    pub due_date: Option<DateTime<Utc>>,
+
     /// Metadata labels/tags
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub labels: Vec<String>,
 
+    /// IDs of items that must be closed before this one can be worked on
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+
+    /// IDs of items that can't be worked on until this one is closed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<String>,
+
     /// Attached files (relative paths) and URLs
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub attachments: Vec<String>,
+
+    /// SHA-256 content hashes of file attachments, keyed by attachment name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub checksums: BTreeMap<String, String>,
+
+    /// Page titles of URL attachments, keyed by URL
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub link_titles: BTreeMap<String, String>,
+
+    /// Item-specific shell commands run with `qs run`, keyed by action name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub actions: BTreeMap<String, String>,
+
+    /// Custom fields not known to queuestack (e.g. `priority`), kept in key order
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_yml::Value>,
 }
 
 /// A complete item with frontmatter and body
@@ -104,6 +184,17 @@ pub struct Item {
 
     /// File path (if loaded from disk)
     pub path: Option<PathBuf>,
+
+    /// Frontmatter mistakes fixed while loading; the next save writes the
+    /// normalized form
+    pub fixes: Vec<String>,
+
+    /// Frontmatter syntax the item is written in
+    pub format: FrontmatterFormat,
+
+    /// Name of the read-only mirror the item was read from (`None` for the
+    /// project's own items); such items can't be saved
+    pub source: Option<String>,
 }
 
 impl Item {
@@ -114,31 +205,98 @@ impl Item {
             frontmatter,
             body: String::new(),
             path: None,
+            fixes: Vec::new(),
+            format: parser::default_format(),
+            source: None,
         }
     }
 
     /// Loads an item from a file path
+    ///
+    /// Common frontmatter mistakes are fixed up and listed in `fixes`.
     pub fn load(path: &Path) -> Result<Self> {
         let content = std::fs::read_to_string(path)
             .with_context(|| format!("Failed to read item: {}", path.display()))?;
 
-        let (frontmatter, body) = parser::parse(&content)
+        let document = parser::parse_document(&content)
             .with_context(|| format!("Failed to parse item: {}", path.display()))?;
 
         Ok(Self {
-            frontmatter,
-            body,
+            frontmatter: document.frontmatter,
+            body: document.body,
             path: Some(path.to_path_buf()),
+            fixes: document.fixes,
+            format: document.format,
+            source: None,
+        })
+    }
+
+    /// Loads only an item's frontmatter, leaving the body empty
+    ///
+    /// Reading stops at the closing delimiter and reuses `buf`, so listing many
+    /// items doesn't hold their bodies in memory. Don't save items loaded this
+    /// way; that would drop the body.
+    pub fn load_frontmatter(path: &Path, buf: &mut String) -> Result<Self> {
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("Failed to read item: {}", path.display()))?;
+
+        let document = parser::read_frontmatter(&mut std::io::BufReader::new(file), buf)
+            .with_context(|| format!("Failed to parse item: {}", path.display()))?;
+
+        Ok(Self {
+            frontmatter: document.frontmatter,
+            body: String::new(),
+            path: Some(path.to_path_buf()),
+            fixes: document.fixes,
+            format: document.format,
+            source: None,
         })
     }
 
     /// Saves the item to disk
     pub fn save(&self, path: &Path) -> Result<()> {
-        let content = parser::serialize(&self.frontmatter, &self.body)?;
+        if let Some(ref source) = self.source {
+            anyhow::bail!(
+                "Item '{}' comes from the read-only mirror '{source}'",
+                self.qualified_id()
+            );
+        }
+        let content = parser::serialize(&self.frontmatter, &self.body, self.format)?;
         std::fs::write(path, content)
             .with_context(|| format!("Failed to write item: {}", path.display()))
     }
 
+    /// Saves the item to a new file.
+    ///
+    /// Returns `false` without writing if the file already exists. The check
+    /// and the creation are one step, so concurrent processes can't both
+    /// claim the same file.
+    pub fn save_new(&self, path: &Path) -> Result<bool> {
+        use std::io::Write;
+
+        if let Some(ref source) = self.source {
+            anyhow::bail!(
+                "Item '{}' comes from the read-only mirror '{source}'",
+                self.qualified_id()
+            );
+        }
+        let content = parser::serialize(&self.frontmatter, &self.body, self.format)?;
+        let mut file = match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+        {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => return Ok(false),
+            Err(e) => {
+                return Err(e).with_context(|| format!("Failed to write item: {}", path.display()))
+            }
+        };
+        file.write_all(content.as_bytes())
+            .with_context(|| format!("Failed to write item: {}", path.display()))?;
+        Ok(true)
+    }
+
     /// Returns the filename for this item: `{id}-{slug}.md`
     pub fn filename(&self) -> String {
         let slug = slugify(&self.frontmatter.title);
@@ -154,6 +312,15 @@ impl Item {
         &self.frontmatter.id
     }
 
+    /// Returns the ID qualified with the mirror it comes from (`upstream:260109-ABC`),
+    /// or the plain ID for the project's own items.
+    pub fn qualified_id(&self) -> String {
+        self.source.as_ref().map_or_else(
+            || self.frontmatter.id.clone(),
+            |source| format!("{source}:{}", self.frontmatter.id),
+        )
+    }
+
     /// Returns the title
     pub fn title(&self) -> &str {
         &self.frontmatter.title
@@ -174,16 +341,114 @@ impl Item {
         &self.frontmatter.labels
     }
 
+    /// Returns a scalar custom frontmatter field (string or number) as text, if set
+    pub fn custom_field(&self, field: &str) -> Option<String> {
+        let text = match self.frontmatter.extra.get(field)? {
+            serde_yml::Value::String(s) => s.clone(),
+            serde_yml::Value::Number(n) => n.to_string(),
+            _ => return None,
+        };
+        Some(text).filter(|t| !t.is_empty())
+    }
+
+    /// Returns the item IDs of a custom field holding one ID or a list of IDs
+    pub fn id_list(&self, field: &str) -> Vec<String> {
+        let text = |value: &serde_yml::Value| match value {
+            serde_yml::Value::String(s) => Some(s.trim().to_string()),
+            serde_yml::Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        };
+        let ids: Vec<String> = match self.frontmatter.extra.get(field) {
+            Some(serde_yml::Value::Sequence(values)) => values.iter().filter_map(text).collect(),
+            Some(value) => text(value).into_iter().collect(),
+            None => Vec::new(),
+        };
+        ids.into_iter().filter(|id| !id.is_empty()).collect()
+    }
+
+    /// Sets a string custom frontmatter field, or removes it when `None`
+    pub fn set_custom_field(&mut self, field: &str, value: Option<String>) {
+        match value {
+            Some(value) => {
+                self.frontmatter
+                    .extra
+                    .insert(field.to_string(), serde_yml::Value::String(value));
+            }
+            None => {
+                self.frontmatter.extra.remove(field);
+            }
+        }
+    }
+
+    /// Returns the GitHub issue number from the `github_issue` field, if set
+    pub fn github_issue(&self) -> Option<u64> {
+        self.custom_field(GITHUB_ISSUE_FIELD)?.parse().ok()
+    }
+
+    /// Records the number of the item's GitHub issue
+    pub fn set_github_issue(&mut self, number: u64) {
+        self.frontmatter
+            .extra
+            .insert(GITHUB_ISSUE_FIELD.to_string(), number.into());
+    }
+
+    /// Returns the assignee, if set
+    pub fn assignee(&self) -> Option<String> {
+        self.frontmatter.assignee.clone()
+    }
+
+    /// Sets the assignee, or removes it when `None` or empty
+    pub fn set_assignee(&mut self, assignee: Option<String>) {
+        self.frontmatter.assignee = assignee.filter(|a| !a.is_empty());
+    }
+
+    /// Returns when the item is due, if set
+    pub const fn due_date(&self) -> Option<DateTime<Utc>> {
+        self.frontmatter.due_date
+    }
+
+    /// Returns the day the item is due (UTC), if set
+    pub fn due(&self) -> Option<NaiveDate> {
+        self.frontmatter.due_date.map(|due| due.date_naive())
+    }
+
+    /// Sets the due date (midnight UTC), or removes it when `None`
+    pub fn set_due(&mut self, due: Option<NaiveDate>) {
+        self.frontmatter.due_date = due.map(|day| day.and_time(NaiveTime::MIN).and_utc());
+    }
+
+    /// Returns true if the item is still active and was due before `today`
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.status().is_active() && self.due().is_some_and(|due| due < today)
+    }
+
     /// Returns the creation timestamp
     pub const fn created_at(&self) -> DateTime<Utc> {
         self.frontmatter.created_at
     }
 
-    /// Sets the status
+    /// Returns when the item was closed, if it is closed and the time is known
+    pub const fn closed_at(&self) -> Option<DateTime<Utc>> {
+        self.frontmatter.closed_at
+    }
+
+    /// Sets the status, stamping `closed_at` on close and clearing it on reopen
     pub fn set_status(&mut self, status: Status) {
+        if status == Status::Closed {
+            if self.frontmatter.status != Status::Closed || self.frontmatter.closed_at.is_none() {
+                self.frontmatter.closed_at = Some(Utc::now());
+            }
+        } else {
+            self.frontmatter.closed_at = None;
+        }
         self.frontmatter.status = status;
     }
 
+    /// Sets the ID
+    pub fn set_id(&mut self, id: String) {
+        self.frontmatter.id = id;
+    }
+
     /// Sets the title
     pub fn set_title(&mut self, title: String) {
         self.frontmatter.title = title;
@@ -202,14 +467,30 @@ impl Item {
         self.frontmatter.labels.retain(|l| l != label);
     }
 
+    /// Returns the IDs of the items this item depends on
+    pub fn depends_on(&self) -> &[String] {
+        &self.frontmatter.depends_on
+    }
+
+    /// Returns the IDs of the items this item blocks
+    pub fn blocks(&self) -> &[String] {
+        &self.frontmatter.blocks
+    }
+
     /// Returns the attachment directory for this item.
     ///
-    /// The attachment directory is a sibling directory named `{item-stem}.attachments/`.
+    /// The attachment directory is a sibling directory named `{item-stem}.attachments/`,
+    /// or the external `attachment_path` when configured.
     /// Returns `None` if the item has no path set.
-    pub fn attachment_dir(&self) -> Option<PathBuf> {
+    pub fn attachment_dir(&self, config: &crate::config::Config) -> Option<PathBuf> {
         self.path
             .as_ref()
-            .map(|p| crate::storage::attachment_dir_for_item(p))
+            .map(|p| crate::storage::attachment_dir(config, self.id(), p))
+    }
+
+    /// Returns the body's word count and reading time
+    pub fn body_size(&self) -> size::BodySize {
+        size::BodySize::of(&self.body)
     }
 
     /// Returns the attachments
@@ -222,12 +503,43 @@ impl Item {
         self.frontmatter.attachments.push(attachment);
     }
 
+    /// Returns the item's actions (name to shell command)
+    pub const fn actions(&self) -> &BTreeMap<String, String> {
+        &self.frontmatter.actions
+    }
+
+    /// Returns the recorded checksum of a file attachment
+    pub fn checksum(&self, attachment: &str) -> Option<&str> {
+        self.frontmatter
+            .checksums
+            .get(attachment)
+            .map(String::as_str)
+    }
+
+    /// Records the checksum of a file attachment
+    pub fn set_checksum(&mut self, attachment: String, checksum: String) {
+        self.frontmatter.checksums.insert(attachment, checksum);
+    }
+
+    /// Returns the page title recorded for a URL attachment
+    pub fn link_title(&self, url: &str) -> Option<&str> {
+        self.frontmatter.link_titles.get(url).map(String::as_str)
+    }
+
+    /// Records the page title of a URL attachment
+    pub fn set_link_title(&mut self, url: String, title: String) {
+        self.frontmatter.link_titles.insert(url, title);
+    }
+
     /// Removes an attachment by index (0-based)
     ///
     /// Returns the removed attachment, or None if index out of bounds
     pub fn remove_attachment(&mut self, index: usize) -> Option<String> {
         if index < self.frontmatter.attachments.len() {
-            Some(self.frontmatter.attachments.remove(index))
+            let removed = self.frontmatter.attachments.remove(index);
+            self.frontmatter.checksums.remove(&removed);
+            self.frontmatter.link_titles.remove(&removed);
+            Some(removed)
         } else {
             None
         }
@@ -243,17 +555,31 @@ impl Item {
         self.frontmatter
             .attachments
             .iter()
-            .filter(|a| !is_url(a))
-            .filter_map(|a| AttachmentFileName::parse(a))
+            .filter_map(|a| {
+                // Object storage URLs end in the attachment file name
+                if is_object_url(a) {
+                    a.rsplit('/').next()
+                } else if is_url(a) {
+                    None
+                } else {
+                    Some(a.as_str())
+                }
+            })
+            .filter_map(AttachmentFileName::parse)
             .map(|af| af.counter)
             .max()
             .map_or(1, |n| n + 1)
     }
 }
 
-/// Checks if a string is a URL (starts with http:// or https://)
+/// Checks if a string is a URL (http://, https://, or an s3:// object URL)
 pub fn is_url(s: &str) -> bool {
-    s.starts_with("http://") || s.starts_with("https://")
+    s.starts_with("http://") || s.starts_with("https://") || is_object_url(s)
+}
+
+/// Checks if a string is an object storage URL (s3://)
+pub fn is_object_url(s: &str) -> bool {
+    s.starts_with(crate::constants::S3_URL_PREFIX)
 }
 
 impl AsRef<Self> for Item {
@@ -273,8 +599,7 @@ mod tests {
             author: "Test".to_string(),
             created_at: Utc::now(),
             status: Status::Open,
-            labels: vec![],
-            attachments: vec![],
+            ..Frontmatter::default()
         }
     }
 
@@ -297,6 +622,7 @@ mod tests {
     #[test]
     fn test_status_display() {
         assert_eq!(Status::Open.to_string(), "open");
+        assert_eq!(Status::InProgress.to_string(), "in-progress");
         assert_eq!(Status::Closed.to_string(), "closed");
         assert_eq!(Status::Template.to_string(), "template");
     }
@@ -325,6 +651,13 @@ mod tests {
         assert!(!is_url("ftp://example.com")); // Only http(s) are URLs
     }
 
+    #[test]
+    fn test_is_url_object_storage() {
+        assert!(is_url("s3://bucket/260109-AAA/1-shot.png"));
+        assert!(is_object_url("s3://bucket/260109-AAA/1-shot.png"));
+        assert!(!is_object_url("https://example.com"));
+    }
+
     #[test]
     fn test_attachments_getter() {
         let mut fm = sample_frontmatter("260109-AAA");
@@ -364,6 +697,18 @@ mod tests {
         assert_eq!(item.attachments()[1], "file3.txt");
     }
 
+    #[test]
+    fn test_remove_attachment_drops_checksum() {
+        let mut fm = sample_frontmatter("260109-AAA");
+        fm.attachments = vec!["1-shot.png".to_string()];
+        let mut item = Item::new(fm);
+        item.set_checksum("1-shot.png".to_string(), "abc123".to_string());
+        assert_eq!(item.checksum("1-shot.png"), Some("abc123"));
+
+        item.remove_attachment(0);
+        assert_eq!(item.checksum("1-shot.png"), None);
+    }
+
     #[test]
     fn test_remove_attachment_invalid_index() {
         let fm = sample_frontmatter("260109-AAA");
@@ -410,6 +755,17 @@ mod tests {
         assert_eq!(item.next_attachment_counter(), 3);
     }
 
+    #[test]
+    fn test_next_counter_counts_object_urls() {
+        let mut fm = sample_frontmatter("260109-AAA");
+        fm.attachments = vec![
+            "1-file.txt".to_string(),
+            "s3://bucket/qs/260109-AAA/4-shot.png".to_string(),
+        ];
+        let item = Item::new(fm);
+        assert_eq!(item.next_attachment_counter(), 5);
+    }
+
     #[test]
     fn test_next_counter_urls_only() {
         let mut fm = sample_frontmatter("260109-AAA");
@@ -420,4 +776,19 @@ mod tests {
         let item = Item::new(fm);
         assert_eq!(item.next_attachment_counter(), 1);
     }
+
+    #[test]
+    fn test_id_list_accepts_single_id_and_list() {
+        let mut item = Item::new(sample_frontmatter("260109-02F7K9M"));
+        assert!(item.id_list("parent").is_empty());
+
+        item.set_custom_field("parent", Some("260101-AAA".to_string()));
+        assert_eq!(item.id_list("parent"), ["260101-AAA"]);
+
+        item.frontmatter.extra.insert(
+            "parent".to_string(),
+            serde_yml::from_str("[260101-AAA, ' 260102-BBB ', '']").unwrap(),
+        );
+        assert_eq!(item.id_list("parent"), ["260101-AAA", "260102-BBB"]);
+    }
 }
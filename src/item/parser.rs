@@ -1,16 +1,140 @@
-//! # YAML Frontmatter Parser
+//! # Frontmatter Parser
 //!
-//! Parses and serializes Markdown files with YAML frontmatter.
+//! Parses and serializes Markdown files with YAML frontmatter, or TOML
+//! frontmatter between `+++` lines as used by Hugo. The syntax is detected
+//! when parsing; new items use the configured `frontmatter_format`.
+//!
+//! Parsing is lenient about mistakes people and agents commonly make when
+//! editing items by hand: unquoted values containing colons, labels written as
+//! a comma-separated string, capitalized status values and field names, and
+//! numbers where text is expected. Such items are fixed up instead of being
+//! dropped, and the fixes are reported so the normalized form can be saved.
+//!
+//! The frontmatter ends at the first line that is exactly `---`, so bodies may
+//! start with a horizontal rule. YAML anchors and aliases are resolved (and
+//! expanded on the next save); a second YAML document inside the frontmatter
+//! is rejected rather than silently dropped.
 //!
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+use std::{
+    cell::Cell,
+    fmt::{self, Write},
+    io::BufRead,
+};
+
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_yml::{Mapping, Value};
+
+use super::{Frontmatter, Status};
+use crate::constants::{FRONTMATTER_DELIMITER, TOML_FRONTMATTER_DELIMITER};
+
+/// Key under which the `toml` crate passes date-times through serde
+const TOML_DATETIME_FIELD: &str = "$__toml_private_datetime";
+
+/// Frontmatter fields known to queuestack (custom fields are kept as they are)
+const KNOWN_FIELDS: [&str; 15] = [
+    "id",
+    "title",
+    "author",
+    "assignee",
+    "created_at",
+    "status",
+    "closed_at",
+    "due",
+    "labels",
+    "depends_on",
+    "blocks",
+    "attachments",
+    "checksums",
+    "link_titles",
+    "actions",
+];
+/// Other names of known fields (`blocked_by` was read by `qs graph` before
+/// `depends_on` existed)
+const FIELD_ALIASES: [(&str, &str); 1] = [("blocked_by", "depends_on")];
+/// Fields that must be text
+const TEXT_FIELDS: [&str; 4] = ["id", "title", "author", "assignee"];
+/// Fields that must be lists; a comma-separated string is split
+const LIST_FIELDS: [&str; 3] = ["labels", "depends_on", "blocks"];
+
+/// Syntax of an item's frontmatter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FrontmatterFormat {
+    /// YAML between `---` lines
+    #[default]
+    Yaml,
+    /// TOML between `+++` lines (as used by Hugo)
+    Toml,
+}
+
+impl fmt::Display for FrontmatterFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Yaml => write!(f, "yaml"),
+            Self::Toml => write!(f, "toml"),
+        }
+    }
+}
+
+impl FrontmatterFormat {
+    /// Returns the delimiter line around the frontmatter.
+    pub const fn delimiter(self) -> &'static str {
+        match self {
+            Self::Yaml => FRONTMATTER_DELIMITER,
+            Self::Toml => TOML_FRONTMATTER_DELIMITER,
+        }
+    }
+
+    /// Detects the format from the first line of a file.
+    fn detect(first_line: &str) -> Result<Self> {
+        if first_line.starts_with(FRONTMATTER_DELIMITER) {
+            Ok(Self::Yaml)
+        } else if first_line.starts_with(TOML_FRONTMATTER_DELIMITER) {
+            Ok(Self::Toml)
+        } else {
+            anyhow::bail!("File does not start with YAML (---) or TOML (+++) frontmatter");
+        }
+    }
 
-use super::Frontmatter;
-use crate::constants::FRONTMATTER_DELIMITER;
+    /// Returns true if a line closes frontmatter of this format (ignoring
+    /// trailing whitespace).
+    fn is_delimiter(self, line: &str) -> bool {
+        line.trim_end() == self.delimiter()
+    }
+}
+
+thread_local! {
+    /// Frontmatter syntax of new items (set from the config).
+    static DEFAULT_FORMAT: Cell<FrontmatterFormat> = const { Cell::new(FrontmatterFormat::Yaml) };
+}
+
+/// Sets the frontmatter syntax of new items.
+pub fn set_default_format(format: FrontmatterFormat) {
+    DEFAULT_FORMAT.with(|cell| cell.set(format));
+}
+
+/// Returns the frontmatter syntax of new items.
+pub fn default_format() -> FrontmatterFormat {
+    DEFAULT_FORMAT.with(Cell::get)
+}
 
-/// Parses a Markdown file with YAML frontmatter.
+/// A parsed item file
+#[derive(Debug)]
+pub struct Document {
+    pub frontmatter: Frontmatter,
+    /// Markdown body (empty when only the frontmatter was read)
+    pub body: String,
+    /// Syntax the frontmatter was written in
+    pub format: FrontmatterFormat,
+    /// Frontmatter mistakes that were fixed while parsing
+    pub fixes: Vec<String>,
+}
+
+/// Parses a Markdown file with YAML or TOML frontmatter.
 ///
 /// # Arguments
 /// * `content` - The full file content
@@ -18,49 +142,331 @@ use crate::constants::FRONTMATTER_DELIMITER;
 /// # Returns
 /// A tuple of (Frontmatter, body markdown)
 pub fn parse(content: &str) -> Result<(Frontmatter, String)> {
+    parse_document(content).map(|document| (document.frontmatter, document.body))
+}
+
+/// Parses a Markdown file with YAML or TOML frontmatter, also returning the
+/// frontmatter syntax and a description of every mistake that was fixed.
+pub fn parse_document(content: &str) -> Result<Document> {
     let content = content.trim_start();
+    let format = FrontmatterFormat::detect(content)?;
 
-    // Check for frontmatter start
-    if !content.starts_with(FRONTMATTER_DELIMITER) {
-        anyhow::bail!("File does not start with YAML frontmatter (---)");
+    // Find the closing delimiter: the first following line that is exactly the delimiter
+    let after_start = &content[format.delimiter().len()..];
+    let mut pos = 0;
+    let mut end = None;
+    for line in after_start.split_inclusive('\n') {
+        if pos > 0 && format.is_delimiter(line) {
+            end = Some((pos, pos + line.len()));
+            break;
+        }
+        pos += line.len();
     }
+    let (end_pos, body_start) =
+        end.ok_or_else(|| anyhow::anyhow!("No closing frontmatter delimiter found"))?;
 
-    // Find the closing delimiter
-    let after_start = &content[FRONTMATTER_DELIMITER.len()..];
-    let end_pos = after_start
-        .find(&format!("\n{FRONTMATTER_DELIMITER}"))
-        .ok_or_else(|| anyhow::anyhow!("No closing frontmatter delimiter found"))?;
-
-    let yaml_content = &after_start[..end_pos];
-    let body_start = end_pos + 1 + FRONTMATTER_DELIMITER.len();
-    let body = after_start
-        .get(body_start..)
-        .unwrap_or("")
+    let body = after_start[body_start..]
         .trim_start_matches(['\n', '\r'])
         .to_string();
 
-    let frontmatter: Frontmatter =
-        serde_yml::from_str(yaml_content).context("Failed to parse YAML frontmatter")?;
+    let (frontmatter, fixes) = parse_frontmatter(&after_start[..end_pos], format)?;
+    Ok(Document {
+        frontmatter,
+        body,
+        format,
+        fixes,
+    })
+}
+
+/// Reads only the frontmatter of a Markdown file, stopping at the closing
+/// delimiter so the body is never read.
+///
+/// `buf` is cleared and reused for the frontmatter text, so reading many
+/// files doesn't allocate a new buffer for each. The returned body is empty.
+pub fn read_frontmatter(reader: &mut impl BufRead, buf: &mut String) -> Result<Document> {
+    // Skip leading blank lines, like `parse`
+    loop {
+        buf.clear();
+        if reader.read_line(buf)? == 0 || !buf.trim().is_empty() {
+            break;
+        }
+    }
+    let format = FrontmatterFormat::detect(buf.trim_start())?;
+
+    buf.clear();
+    loop {
+        let start = buf.len();
+        if reader.read_line(buf)? == 0 {
+            anyhow::bail!("No closing frontmatter delimiter found");
+        }
+        if format.is_delimiter(&buf[start..]) {
+            buf.truncate(start);
+            break;
+        }
+    }
+
+    let (frontmatter, fixes) = parse_frontmatter(buf, format)?;
+    Ok(Document {
+        frontmatter,
+        body: String::new(),
+        format,
+        fixes,
+    })
+}
+
+/// Parses the text between the frontmatter delimiters.
+fn parse_frontmatter(
+    content: &str,
+    format: FrontmatterFormat,
+) -> Result<(Frontmatter, Vec<String>)> {
+    match format {
+        FrontmatterFormat::Yaml => parse_yaml(content),
+        FrontmatterFormat::Toml => parse_toml(content).map(|frontmatter| (frontmatter, Vec::new())),
+    }
+}
+
+/// Parses TOML frontmatter.
+///
+/// TOML dates in custom fields are kept as text, since custom fields are
+/// stored as YAML values.
+fn parse_toml(toml_content: &str) -> Result<Frontmatter> {
+    if toml_content.trim().is_empty() {
+        anyhow::bail!("Frontmatter is empty (is there a duplicate '+++' line?)");
+    }
+    let mut frontmatter: Frontmatter =
+        toml::from_str(toml_content).context("Failed to parse TOML frontmatter")?;
+    for value in frontmatter.extra.values_mut() {
+        unwrap_toml_datetimes(value);
+    }
+    Ok(frontmatter)
+}
+
+/// Replaces TOML date-times (which deserialize as a single-key map) with text.
+fn unwrap_toml_datetimes(value: &mut Value) {
+    match value {
+        Value::Mapping(mapping) => {
+            if mapping.len() == 1 {
+                if let Some(Value::String(text)) = mapping.get(TOML_DATETIME_FIELD) {
+                    *value = Value::String(text.clone());
+                    return;
+                }
+            }
+            mapping.values_mut().for_each(unwrap_toml_datetimes);
+        }
+        Value::Sequence(values) => values.iter_mut().for_each(unwrap_toml_datetimes),
+        _ => {}
+    }
+}
+
+/// Rejects frontmatter that is empty or holds more than one YAML document.
+///
+/// The YAML parser would silently keep only the first document, so a stray
+/// `--- ` or `...` marker would drop the rest of the fields.
+fn check_single_document(yaml_content: &str) -> Result<()> {
+    if yaml_content.trim().is_empty() {
+        anyhow::bail!("Frontmatter is empty (is there a duplicate '---' line?)");
+    }
+    let marker = yaml_content
+        .lines()
+        .map(str::trim_end)
+        .find(|line| *line == "..." || line.starts_with("--- ") || line.starts_with("---\t"));
+    if let Some(marker) = marker {
+        anyhow::bail!(
+            "Frontmatter contains more than one YAML document (found '{marker}'); \
+             keep all fields in a single block between the '---' lines"
+        );
+    }
+    Ok(())
+}
+
+/// Parses the YAML between the frontmatter delimiters, fixing common mistakes.
+///
+/// Returns the frontmatter and a description of each fix. Well-formed
+/// frontmatter is deserialized directly; only files that fail to parse (or
+/// misspell a field name) take the slower path through a generic YAML value.
+fn parse_yaml(yaml_content: &str) -> Result<(Frontmatter, Vec<String>)> {
+    check_single_document(yaml_content)?;
+
+    let (mut frontmatter, fixes) = match serde_yml::from_str::<Frontmatter>(yaml_content) {
+        Ok(frontmatter)
+            if !frontmatter
+                .extra
+                .keys()
+                .any(|key| known_field(key).is_some()) =>
+        {
+            (frontmatter, Vec::new())
+        }
+        strict => match (strict, parse_lenient(yaml_content)) {
+            (_, Ok(lenient)) => lenient,
+            (Ok(frontmatter), Err(_)) => (frontmatter, Vec::new()),
+            // The strict error points at the actual problem
+            (Err(e), Err(_)) => return Err(e).context("Failed to parse YAML frontmatter"),
+        },
+    };
+
+    // Empty custom fields carry no information; drop them so they aren't written back
+    frontmatter.extra.retain(|_, value| !value.is_null());
+    frontmatter.assignee = frontmatter.assignee.filter(|a| !a.is_empty());
+
+    Ok((frontmatter, fixes))
+}
+
+/// Parses frontmatter through a generic YAML value, fixing what it can.
+fn parse_lenient(yaml_content: &str) -> Result<(Frontmatter, Vec<String>)> {
+    let mut fixes = Vec::new();
+    let mut value: Value = match serde_yml::from_str(yaml_content) {
+        Ok(value) => value,
+        Err(e) => {
+            let (quoted, quote_fixes) = quote_plain_values(yaml_content);
+            let Ok(value) = serde_yml::from_str(&quoted) else {
+                return Err(e).context("Failed to parse YAML frontmatter");
+            };
+            fixes = quote_fixes;
+            value
+        }
+    };
+    if let Value::Mapping(ref mut mapping) = value {
+        normalize(mapping, &mut fixes);
+    }
+
+    let frontmatter = serde_yml::from_value(value).context("Failed to parse YAML frontmatter")?;
+    Ok((frontmatter, fixes))
+}
+
+/// Returns the known field a key refers to, if it differs only in case or
+/// separators (e.g. `Title` or `created-at`) or is an alias (`blocked_by`).
+fn known_field(key: &str) -> Option<&'static str> {
+    let canonical = key.to_lowercase().replace('-', "_");
+    let canonical = FIELD_ALIASES
+        .into_iter()
+        .find(|(alias, _)| *alias == canonical)
+        .map_or(canonical.as_str(), |(_, field)| field);
+    KNOWN_FIELDS
+        .into_iter()
+        .find(|field| *field == canonical && *field != key)
+}
+
+/// Quotes top-level values that aren't valid plain YAML scalars, such as
+/// `title: Fix: crash on login` or values starting with a backtick.
+fn quote_plain_values(yaml_content: &str) -> (String, Vec<String>) {
+    let mut fixes = Vec::new();
+    let mut result = String::with_capacity(yaml_content.len() + 16);
+    for line in yaml_content.lines() {
+        let quoted = line
+            .split_once(": ")
+            .filter(|(key, _)| {
+                !key.is_empty()
+                    && key
+                        .chars()
+                        .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+            })
+            .and_then(|(key, value)| {
+                let value = value.trim();
+                let needs_quotes = value.contains(": ")
+                    || value.ends_with(':')
+                    || value.starts_with(['`', '@', '%']);
+                let quotable = !value.starts_with(['\'', '"', '[', '{', '|', '>', '&', '*', '!']);
+                (needs_quotes && quotable).then_some((key, value))
+            });
+        match quoted {
+            Some((key, value)) => {
+                let _ = write!(result, "{key}: '{}'", value.replace('\'', "''"));
+                fixes.push(format!("quoted the {key} value"));
+            }
+            None => result.push_str(line),
+        }
+        result.push('\n');
+    }
+    (result, fixes)
+}
+
+/// Fixes field names, labels, status, and text fields in the parsed mapping.
+fn normalize(mapping: &mut Mapping, fixes: &mut Vec<String>) {
+    let renames: Vec<(String, &str)> = mapping
+        .keys()
+        .filter_map(|key| {
+            let known = known_field(key)?;
+            (!mapping.contains_key(known)).then(|| (key.clone(), known))
+        })
+        .collect();
+    for (key, known) in renames {
+        if let Some(value) = mapping.remove(&key) {
+            mapping.insert(known.to_string(), value);
+            fixes.push(format!("renamed field '{key}' to '{known}'"));
+        }
+    }
+
+    for field in LIST_FIELDS {
+        if let Some(value) = mapping.get_mut(field) {
+            if let Value::String(text) = value {
+                let list: Vec<Value> = text
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|entry| !entry.is_empty())
+                    .map(Value::from)
+                    .collect();
+                fixes.push(format!("split {field} '{text}' into a list"));
+                *value = Value::Sequence(list);
+            }
+        }
+    }
+
+    if let Some(Value::String(status)) = mapping.get_mut("status") {
+        let normalized = status.trim().to_lowercase().replace(['_', ' '], "-");
+        let valid = [
+            Status::Open,
+            Status::InProgress,
+            Status::Closed,
+            Status::Template,
+        ]
+        .map(|status| status.to_string());
+        if *status != normalized && valid.contains(&normalized) {
+            fixes.push(format!("changed status '{status}' to '{normalized}'"));
+            *status = normalized;
+        }
+    }
 
-    Ok((frontmatter, body))
+    for field in TEXT_FIELDS {
+        if let Some(value) = mapping.get_mut(field) {
+            let text = match value {
+                Value::Number(number) => number.to_string(),
+                Value::Bool(flag) => flag.to_string(),
+                _ => continue,
+            };
+            fixes.push(format!("converted {field} to text"));
+            *value = Value::String(text);
+        }
+    }
 }
 
 /// Serializes frontmatter and body back to Markdown format.
 ///
 /// # Arguments
-/// * `frontmatter` - The YAML frontmatter data
+/// * `frontmatter` - The frontmatter data
 /// * `body` - The Markdown body content
+/// * `format` - The frontmatter syntax to write
 ///
 /// # Returns
 /// The complete file content as a String
-pub fn serialize(frontmatter: &Frontmatter, body: &str) -> Result<String> {
-    let yaml = serde_yml::to_string(frontmatter).context("Failed to serialize frontmatter")?;
+pub fn serialize(
+    frontmatter: &Frontmatter,
+    body: &str,
+    format: FrontmatterFormat,
+) -> Result<String> {
+    let data = match format {
+        FrontmatterFormat::Yaml => serde_yml::to_string(frontmatter).map_err(anyhow::Error::from),
+        FrontmatterFormat::Toml => toml::to_string(frontmatter).map_err(anyhow::Error::from),
+    }
+    .context("Failed to serialize frontmatter")?;
 
     let mut result = String::new();
-    result.push_str(FRONTMATTER_DELIMITER);
+    result.push_str(format.delimiter());
     result.push('\n');
-    result.push_str(&yaml);
-    result.push_str(FRONTMATTER_DELIMITER);
+    result.push_str(&data);
+    if !data.ends_with('\n') {
+        result.push('\n');
+    }
+    result.push_str(format.delimiter());
     result.push_str("\n\n\n"); // Two empty lines after frontmatter
 
     if !body.is_empty() {
@@ -75,7 +481,7 @@ pub fn serialize(frontmatter: &Frontmatter, body: &str) -> Result<String> {
 
 /// Creates a new item file content with minimal template.
 pub fn create_template(frontmatter: &Frontmatter) -> Result<String> {
-    serialize(frontmatter, "")
+    serialize(frontmatter, "", default_format())
 }
 
 #[cfg(test)]
@@ -91,7 +497,7 @@ mod tests {
             created_at: Utc::now(),
             status: super::super::Status::Open,
             labels: vec!["bug".to_string()],
-            attachments: vec![],
+            ..Frontmatter::default()
         }
     }
 
@@ -100,7 +506,7 @@ mod tests {
         let fm = sample_frontmatter();
         let body = "This is the description.\n\nWith multiple paragraphs.";
 
-        let serialized = serialize(&fm, body).unwrap();
+        let serialized = serialize(&fm, body, FrontmatterFormat::Yaml).unwrap();
         let (parsed_fm, parsed_body) = parse(&serialized).unwrap();
 
         assert_eq!(parsed_fm.id, fm.id);
@@ -111,7 +517,7 @@ mod tests {
     #[test]
     fn test_parse_empty_body() {
         let fm = sample_frontmatter();
-        let serialized = serialize(&fm, "").unwrap();
+        let serialized = serialize(&fm, "", FrontmatterFormat::Yaml).unwrap();
         let (_, body) = parse(&serialized).unwrap();
         assert!(body.is_empty());
     }
@@ -127,4 +533,193 @@ mod tests {
         let result = parse("---\nid: test\n");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_parse_fixes_common_mistakes() {
+        let content = "---\nid: 260109-02F7K9M\nTitle: Fix: crash on login\nauthor: Agent\n\
+                       created_at: 2026-01-09T10:00:00Z\nStatus: In Progress\n\
+                       labels: bug, ui,\npriority: high\n---\n\nBody\n";
+
+        let Document {
+            frontmatter: fm,
+            body,
+            fixes,
+            ..
+        } = parse_document(content).unwrap();
+        assert_eq!(fm.title, "Fix: crash on login");
+        assert_eq!(fm.status, super::super::Status::InProgress);
+        assert_eq!(fm.labels, vec!["bug", "ui"]);
+        assert!(fm.extra.contains_key("priority"));
+        assert_eq!(body, "Body\n");
+        assert_eq!(
+            fixes,
+            vec![
+                "quoted the Title value",
+                "renamed field 'Title' to 'title'",
+                "renamed field 'Status' to 'status'",
+                "split labels 'bug, ui,' into a list",
+                "changed status 'In Progress' to 'in-progress'",
+            ]
+        );
+
+        let serialized = serialize(&fm, &body, FrontmatterFormat::Yaml).unwrap();
+        assert!(parse_document(&serialized).unwrap().fixes.is_empty());
+    }
+
+    #[test]
+    fn test_parse_dependency_fields() {
+        let content = "---\nid: 260109-02F7K9M\ntitle: T\nauthor: A\n\
+                       created_at: 2026-01-09T10:00:00Z\nblocks: 260101-AAA\n\
+                       blocked_by: [260102-BBB]\n---\n";
+
+        let Document {
+            frontmatter: fm,
+            fixes,
+            ..
+        } = parse_document(content).unwrap();
+        assert_eq!(fm.blocks, vec!["260101-AAA"]);
+        assert_eq!(fm.depends_on, vec!["260102-BBB"]);
+        assert!(fm.extra.is_empty());
+        assert_eq!(
+            fixes,
+            vec![
+                "renamed field 'blocked_by' to 'depends_on'",
+                "split blocks '260101-AAA' into a list",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_keeps_invalid_yaml_errors() {
+        assert!(parse("---\nid: [unclosed\n---\n").is_err());
+        assert!(parse(
+            "---\nid: x\ntitle: T\nauthor: A\ncreated_at: 2026-01-09\nstatus: done\n---\n"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_body_starting_with_horizontal_rule() {
+        let fm = sample_frontmatter();
+        for body in [
+            "---\n\nAfter the rule\n",
+            "---\n---\n",
+            "Text\n\n---\n\nMore\n",
+        ] {
+            let serialized = serialize(&fm, body, FrontmatterFormat::Yaml).unwrap();
+            let (parsed, parsed_body) = parse(&serialized).unwrap();
+            assert_eq!(parsed.id, fm.id);
+            assert_eq!(parsed_body, body);
+
+            let mut buf = String::new();
+            let headers = read_frontmatter(&mut serialized.as_bytes(), &mut buf).unwrap();
+            assert_eq!(headers.frontmatter.id, fm.id);
+        }
+
+        // Written by hand without a blank line after the frontmatter
+        let content = "---\nid: x\ntitle: T\nauthor: A\ncreated_at: 2026-01-09\n---  \n---\nBody\n";
+        let (parsed, body) = parse(content).unwrap();
+        assert_eq!(parsed.title, "T");
+        assert_eq!(body, "---\nBody\n");
+    }
+
+    #[test]
+    fn test_anchors_and_aliases() {
+        let content = "---\nid: x\ntitle: &title Login fails\nauthor: *title\n\
+                       created_at: 2026-01-09\nlabels: &labels [bug, ui]\n\
+                       defaults: &defaults {priority: high}\n\
+                       triage:\n  <<: *defaults\n  owner: alice\n---\n";
+        let (parsed, _) = parse(content).unwrap();
+        assert_eq!(parsed.author, "Login fails");
+        assert_eq!(parsed.labels, vec!["bug", "ui"]);
+
+        let serialized = serialize(&parsed, "", FrontmatterFormat::Yaml).unwrap();
+        assert!(!serialized.contains('&') && !serialized.contains('*'));
+        assert!(serialized.contains("priority: high"));
+    }
+
+    #[test]
+    fn test_multiple_documents_rejected() {
+        let second = "---\nid: x\ntitle: T\nauthor: A\n--- # more\ncreated_at: 2026-01-09\n---\n";
+        let err = format!("{:#}", parse(second).unwrap_err());
+        assert!(err.contains("more than one YAML document"), "{err}");
+
+        let end =
+            "---\nid: x\ntitle: T\nauthor: A\ncreated_at: 2026-01-09\n...\nstatus: closed\n---\n";
+        assert!(parse(end).is_err());
+
+        let duplicate = "---\n---\nid: x\ntitle: T\nauthor: A\ncreated_at: 2026-01-09\n---\n";
+        let err = format!("{:#}", parse(duplicate).unwrap_err());
+        assert!(err.contains("duplicate '---'"), "{err}");
+        let mut buf = String::new();
+        assert!(read_frontmatter(&mut duplicate.as_bytes(), &mut buf).is_err());
+    }
+
+    #[test]
+    fn test_read_frontmatter_matches_parse() {
+        let serialized = serialize(
+            &sample_frontmatter(),
+            "Body\n---\nmore",
+            FrontmatterFormat::Yaml,
+        )
+        .unwrap();
+        let content = format!("\n{serialized}");
+        let mut buf = String::new();
+
+        let fm = read_frontmatter(&mut content.as_bytes(), &mut buf)
+            .unwrap()
+            .frontmatter;
+        let (parsed, _) = parse(&content).unwrap();
+        assert_eq!(fm.id, parsed.id);
+        assert_eq!(fm.labels, parsed.labels);
+
+        assert!(read_frontmatter(&mut &b"---\nid: test\n"[..], &mut buf).is_err());
+        assert!(read_frontmatter(&mut &b"No frontmatter"[..], &mut buf).is_err());
+    }
+
+    #[test]
+    fn test_toml_roundtrip() {
+        let mut fm = sample_frontmatter();
+        fm.closed_at = Some(fm.created_at);
+        fm.checksums
+            .insert("1-log.txt".to_string(), "abc".to_string());
+        fm.extra
+            .insert("priority".to_string(), serde_yml::Value::from("high"));
+        let body = "---\n\nBody after a rule\n";
+
+        let serialized = serialize(&fm, body, FrontmatterFormat::Toml).unwrap();
+        assert!(
+            serialized.starts_with("+++\nid = \"260109-02F7K9M\"\n"),
+            "{serialized}"
+        );
+
+        let document = parse_document(&serialized).unwrap();
+        assert_eq!(document.format, FrontmatterFormat::Toml);
+        assert_eq!(document.frontmatter.title, fm.title);
+        assert_eq!(document.frontmatter.labels, fm.labels);
+        assert_eq!(document.frontmatter.checksums, fm.checksums);
+        assert_eq!(document.frontmatter.extra, fm.extra);
+        assert_eq!(document.body, body);
+
+        let mut buf = String::new();
+        let headers = read_frontmatter(&mut serialized.as_bytes(), &mut buf).unwrap();
+        assert_eq!(headers.format, FrontmatterFormat::Toml);
+        assert_eq!(headers.frontmatter.id, fm.id);
+    }
+
+    #[test]
+    fn test_toml_native_dates() {
+        let content = "+++\nid = \"x\"\ntitle = \"T\"\nauthor = \"A\"\n\
+                       created_at = 2026-01-09T10:30:00Z\ndue = 2026-02-01\n+++\n\nBody\n";
+        let (fm, body) = parse(content).unwrap();
+        assert_eq!(fm.created_at.to_rfc3339(), "2026-01-09T10:30:00+00:00");
+        assert_eq!(
+            fm.due_date.unwrap().to_rfc3339(),
+            "2026-02-01T00:00:00+00:00"
+        );
+        assert_eq!(body, "Body\n");
+
+        assert!(parse("+++\n+++\nid = \"x\"\n+++\n").is_err());
+        assert!(parse("+++\nid = \"x\"\n---\n").is_err());
+    }
 }
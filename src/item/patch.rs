@@ -0,0 +1,465 @@
+//! # Field Patches
+//!
+//! Field-level edits to item frontmatter, shared by `qs patch` and anything
+//! else that needs to describe a change to an item as text.
+//!
+//! A patch is a list of operations, one per `--set` flag or one per line in a
+//! patch file. Each operation has the form `field<op>value`:
+//!
+//! | Syntax          | Meaning                                               |
+//! |-----------------|-------------------------------------------------------|
+//! | `field=value`   | Set a field (an empty value removes it)               |
+//! | `field+=value`  | Add a value to a list field (duplicates are ignored)  |
+//! | `field-=value`  | Remove a value from a list field                      |
+//!
+//! Built-in fields: `title` (set only), `status` (set to `open` or
+//! `in-progress`; use `qs close` to close), `labels` (`labels=a,b` replaces
+//! the list), `due` (set only, to any date `--due` accepts; empty removes it),
+//! `assignee` (set only; empty removes it), and the item ID lists `depends_on` and `blocks` (IDs are not
+//! checked; `qs link` checks them). Any other field is stored as a custom frontmatter field. The
+//! fields `id`, `author`, `created_at` and `attachments` are managed by
+//! queuestack and cannot be patched. In patch files, blank lines and lines
+//! starting with `#` are ignored.
+//!
+//! Operations only touch the fields they name, and frontmatter is always
+//! written in a fixed field order, so the same patch applied on two branches
+//! produces identical files and merges cleanly.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::fmt;
+
+use anyhow::{bail, Result};
+use serde_yml::Value;
+
+use super::{dates, deps::LinkKind, normalize_identifier, Item, Status};
+
+/// Fields that are managed by queuestack and rejected by patches.
+const PROTECTED_FIELDS: &[&str] = &[
+    "id",
+    "author",
+    "created_at",
+    "closed_at",
+    "attachments",
+    "checksums",
+    "link_titles",
+    "actions",
+];
+
+/// Patch operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatchOperator {
+    /// `=` - set (or clear) a field
+    Set,
+    /// `+=` - add a value to a list field
+    Add,
+    /// `-=` - remove a value from a list field
+    Remove,
+}
+
+impl PatchOperator {
+    /// Returns the textual form of the operator
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Set => "=",
+            Self::Add => "+=",
+            Self::Remove => "-=",
+        }
+    }
+}
+
+/// A single field-level patch operation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatchOp {
+    pub field: String,
+    pub op: PatchOperator,
+    pub value: String,
+}
+
+impl PatchOp {
+    /// Parses an operation such as `labels+=urgent` or `priority=high`.
+    pub fn parse(s: &str) -> Result<Self> {
+        let Some(eq) = s.find('=') else {
+            bail!(
+                "Invalid patch operation '{s}': expected field=value, field+=value or field-=value"
+            );
+        };
+
+        let (field, op) = match s[..eq].chars().last() {
+            Some('+') => (&s[..eq - 1], PatchOperator::Add),
+            Some('-') => (&s[..eq - 1], PatchOperator::Remove),
+            _ => (&s[..eq], PatchOperator::Set),
+        };
+
+        let field = field.trim();
+        if field.is_empty() {
+            bail!("Invalid patch operation '{s}': missing field name");
+        }
+        if !field
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            bail!("Invalid field name '{field}': use letters, digits, '-' or '_'");
+        }
+
+        Ok(Self {
+            field: field.to_string(),
+            op,
+            value: s[eq + 1..].trim().to_string(),
+        })
+    }
+
+    /// Parses a patch file: one operation per line, `#` comments and blank lines ignored.
+    pub fn parse_lines(text: &str) -> Result<Vec<Self>> {
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(Self::parse)
+            .collect()
+    }
+}
+
+impl fmt::Display for PatchOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.field, self.op.as_str(), self.value)
+    }
+}
+
+/// Applies patch operations to an item in order.
+///
+/// Returns `true` if the item changed. On error the item may be partially
+/// modified, so callers should only save it when this returns `Ok`.
+pub fn apply(item: &mut Item, ops: &[PatchOp]) -> Result<bool> {
+    let mut changed = false;
+    for op in ops {
+        changed |= apply_one(item, op)?;
+    }
+    Ok(changed)
+}
+
+fn apply_one(item: &mut Item, op: &PatchOp) -> Result<bool> {
+    if PROTECTED_FIELDS.contains(&op.field.as_str()) {
+        bail!("Field '{}' cannot be patched", op.field);
+    }
+
+    match op.field.as_str() {
+        "title" => apply_title(item, op),
+        "status" => apply_status(item, op),
+        "assignee" => apply_assignee(item, op),
+        "due" => apply_due(item, op),
+        "labels" => Ok(apply_labels(item, op)),
+        "depends_on" => Ok(apply_links(item, op, LinkKind::DependsOn)),
+        "blocks" => Ok(apply_links(item, op, LinkKind::Blocks)),
+        _ => apply_custom(item, op),
+    }
+}
+
+fn apply_title(item: &mut Item, op: &PatchOp) -> Result<bool> {
+    if op.op != PatchOperator::Set {
+        bail!("Field 'title' only supports '='");
+    }
+    if op.value.is_empty() {
+        bail!("Title cannot be empty");
+    }
+    if item.title() == op.value {
+        return Ok(false);
+    }
+    item.set_title(op.value.clone());
+    Ok(true)
+}
+
+fn apply_status(item: &mut Item, op: &PatchOp) -> Result<bool> {
+    if op.op != PatchOperator::Set {
+        bail!("Field 'status' only supports '='");
+    }
+    // Closing moves files, so it stays with `qs close`/`qs reopen`
+    let status = match op.value.as_str() {
+        "open" => Status::Open,
+        "in-progress" => Status::InProgress,
+        other => bail!("Cannot patch status to '{other}': use 'open' or 'in-progress'"),
+    };
+    if !item.status().is_active() {
+        bail!("Cannot change the status of a {} item", item.status());
+    }
+    if item.status() == status {
+        return Ok(false);
+    }
+    item.set_status(status);
+    Ok(true)
+}
+
+fn apply_assignee(item: &mut Item, op: &PatchOp) -> Result<bool> {
+    if op.op != PatchOperator::Set {
+        bail!("Field 'assignee' only supports '='");
+    }
+    let assignee = Some(op.value.clone()).filter(|a| !a.is_empty());
+    if item.assignee() == assignee {
+        return Ok(false);
+    }
+    item.set_assignee(assignee);
+    Ok(true)
+}
+
+fn apply_due(item: &mut Item, op: &PatchOp) -> Result<bool> {
+    if op.op != PatchOperator::Set {
+        bail!("Field 'due' only supports '='");
+    }
+    let due = if op.value.is_empty() {
+        None
+    } else {
+        Some(dates::parse_due_from_today(&op.value)?)
+    };
+    if item.due() == due {
+        return Ok(false);
+    }
+    item.set_due(due);
+    Ok(true)
+}
+
+fn apply_labels(item: &mut Item, op: &PatchOp) -> bool {
+    let before = item.labels().to_vec();
+    match op.op {
+        PatchOperator::Set => {
+            item.frontmatter.labels.clear();
+            for label in op.value.split(',') {
+                item.add_label(label.trim());
+            }
+        }
+        PatchOperator::Add => item.add_label(&op.value),
+        PatchOperator::Remove => item.remove_label(&normalize_identifier(&op.value)),
+    }
+    item.labels() != before.as_slice()
+}
+
+fn apply_links(item: &mut Item, op: &PatchOp, kind: LinkKind) -> bool {
+    let ids = kind.ids_mut(item);
+    let before = ids.clone();
+    let values = op
+        .value
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty());
+    if op.op == PatchOperator::Remove {
+        let remove: Vec<&str> = values.collect();
+        ids.retain(|id| !remove.contains(&id.as_str()));
+    } else {
+        if op.op == PatchOperator::Set {
+            ids.clear();
+        }
+        for id in values {
+            if !ids.iter().any(|existing| existing == id) {
+                ids.push(id.to_string());
+            }
+        }
+    }
+    *ids != before
+}
+
+fn apply_custom(item: &mut Item, op: &PatchOp) -> Result<bool> {
+    let extra = &mut item.frontmatter.extra;
+    let value = Value::String(op.value.clone());
+
+    match op.op {
+        PatchOperator::Set => {
+            if op.value.is_empty() {
+                return Ok(extra.remove(&op.field).is_some());
+            }
+            if extra.get(&op.field) == Some(&value) {
+                return Ok(false);
+            }
+            extra.insert(op.field.clone(), value);
+            Ok(true)
+        }
+        PatchOperator::Add => match extra.get_mut(&op.field) {
+            None => {
+                extra.insert(op.field.clone(), Value::Sequence(vec![value]));
+                Ok(true)
+            }
+            Some(Value::Sequence(list)) => {
+                if list.contains(&value) {
+                    return Ok(false);
+                }
+                list.push(value);
+                Ok(true)
+            }
+            Some(_) => bail!("Field '{}' is not a list", op.field),
+        },
+        PatchOperator::Remove => match extra.get_mut(&op.field) {
+            None => Ok(false),
+            Some(Value::Sequence(list)) => {
+                let len = list.len();
+                list.retain(|v| v != &value);
+                let changed = list.len() != len;
+                if list.is_empty() {
+                    extra.remove(&op.field);
+                }
+                Ok(changed)
+            }
+            Some(_) => bail!("Field '{}' is not a list", op.field),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::item::Frontmatter;
+
+    fn sample_item() -> Item {
+        Item::new(Frontmatter {
+            id: "260109-AAA".to_string(),
+            title: "Test".to_string(),
+            labels: vec!["bug".to_string()],
+            ..Frontmatter::default()
+        })
+    }
+
+    fn ops(specs: &[&str]) -> Vec<PatchOp> {
+        specs.iter().map(|s| PatchOp::parse(s).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_parse_operators() {
+        let op = PatchOp::parse("labels+=urgent").unwrap();
+        assert_eq!(op.field, "labels");
+        assert_eq!(op.op, PatchOperator::Add);
+        assert_eq!(op.value, "urgent");
+
+        assert_eq!(
+            PatchOp::parse("labels-=bug").unwrap().op,
+            PatchOperator::Remove
+        );
+        assert_eq!(
+            PatchOp::parse("priority=high").unwrap().op,
+            PatchOperator::Set
+        );
+    }
+
+    #[test]
+    fn test_parse_value_may_contain_equals() {
+        let op = PatchOp::parse("title=a = b").unwrap();
+        assert_eq!(op.value, "a = b");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(PatchOp::parse("priority").is_err());
+        assert!(PatchOp::parse("=high").is_err());
+        assert!(PatchOp::parse("pri ority=high").is_err());
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        for spec in ["labels+=urgent", "labels-=bug", "priority=high"] {
+            assert_eq!(PatchOp::parse(spec).unwrap().to_string(), spec);
+        }
+    }
+
+    #[test]
+    fn test_parse_lines_skips_comments() {
+        let parsed = PatchOp::parse_lines("# comment\n\nlabels+=a\n  priority=low\n").unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[1].field, "priority");
+    }
+
+    #[test]
+    fn test_apply_links() {
+        let mut item = sample_item();
+        assert!(apply(&mut item, &ops(&["blocks+=260101-AAA, 260102-BBB"])).unwrap());
+        assert!(!apply(&mut item, &ops(&["blocks+=260101-AAA"])).unwrap());
+        assert!(apply(&mut item, &ops(&["blocks-=260101-AAA"])).unwrap());
+        assert_eq!(item.blocks(), ["260102-BBB"]);
+        assert!(item.frontmatter.extra.is_empty());
+
+        assert!(apply(&mut item, &ops(&["depends_on=260103-CCC"])).unwrap());
+        assert_eq!(item.depends_on(), ["260103-CCC"]);
+    }
+
+    #[test]
+    fn test_apply_labels() {
+        let mut item = sample_item();
+        assert!(apply(&mut item, &ops(&["labels+=Needs Review"])).unwrap());
+        assert_eq!(item.labels(), ["bug", "needs-review"]);
+
+        assert!(!apply(&mut item, &ops(&["labels+=bug"])).unwrap());
+        assert!(apply(&mut item, &ops(&["labels-=bug"])).unwrap());
+        assert_eq!(item.labels(), ["needs-review"]);
+
+        assert!(apply(&mut item, &ops(&["labels=x, y"])).unwrap());
+        assert_eq!(item.labels(), ["x", "y"]);
+    }
+
+    #[test]
+    fn test_apply_custom_fields() {
+        let mut item = sample_item();
+        assert!(apply(&mut item, &ops(&["priority=high"])).unwrap());
+        assert!(!apply(&mut item, &ops(&["priority=high"])).unwrap());
+        assert_eq!(
+            item.frontmatter.extra.get("priority"),
+            Some(&Value::String("high".to_string()))
+        );
+
+        assert!(apply(&mut item, &ops(&["priority="])).unwrap());
+        assert!(item.frontmatter.extra.is_empty());
+    }
+
+    #[test]
+    fn test_apply_custom_lists() {
+        let mut item = sample_item();
+        assert!(apply(&mut item, &ops(&["watchers+=alice", "watchers+=bob"])).unwrap());
+        assert!(apply(&mut item, &ops(&["watchers-=alice"])).unwrap());
+        assert!(apply(&mut item, &ops(&["watchers-=bob"])).unwrap());
+        assert!(!item.frontmatter.extra.contains_key("watchers"));
+
+        apply(&mut item, &ops(&["priority=high"])).unwrap();
+        assert!(apply(&mut item, &ops(&["priority+=x"])).is_err());
+    }
+
+    #[test]
+    fn test_apply_status() {
+        let mut item = sample_item();
+        assert!(apply(&mut item, &ops(&["status=in-progress"])).unwrap());
+        assert_eq!(item.status(), Status::InProgress);
+        assert!(apply(&mut item, &ops(&["status=open"])).unwrap());
+        assert!(apply(&mut item, &ops(&["status=closed"])).is_err());
+
+        item.set_status(Status::Closed);
+        assert!(apply(&mut item, &ops(&["status=in-progress"])).is_err());
+    }
+
+    #[test]
+    fn test_apply_assignee() {
+        let mut item = sample_item();
+        assert!(apply(&mut item, &ops(&["assignee=alice"])).unwrap());
+        assert!(!apply(&mut item, &ops(&["assignee=alice"])).unwrap());
+        assert_eq!(item.assignee().as_deref(), Some("alice"));
+        assert!(item.frontmatter.extra.is_empty());
+
+        assert!(apply(&mut item, &ops(&["assignee+=bob"])).is_err());
+        assert!(apply(&mut item, &ops(&["assignee="])).unwrap());
+        assert_eq!(item.assignee(), None);
+    }
+
+    #[test]
+    fn test_apply_due() {
+        let mut item = sample_item();
+        assert!(apply(&mut item, &ops(&["due=2026-02-01"])).unwrap());
+        assert!(!apply(&mut item, &ops(&["due=2026-02-01"])).unwrap());
+        assert_eq!(item.due().unwrap().to_string(), "2026-02-01");
+        assert!(item.frontmatter.extra.is_empty());
+
+        assert!(apply(&mut item, &ops(&["due=someday"])).is_err());
+        assert!(apply(&mut item, &ops(&["due+=2026-03-01"])).is_err());
+        assert!(apply(&mut item, &ops(&["due="])).unwrap());
+        assert_eq!(item.due(), None);
+    }
+
+    #[test]
+    fn test_apply_rejects_protected_fields() {
+        let mut item = sample_item();
+        assert!(apply(&mut item, &ops(&["id=X"])).is_err());
+        assert!(apply(&mut item, &ops(&["created_at=now"])).is_err());
+        assert!(apply(&mut item, &ops(&["title+=x"])).is_err());
+    }
+}
@@ -0,0 +1,107 @@
+//! # Quick Syntax
+//!
+//! Parses the terse inline syntax of `qs push`:
+//!
+//! ```text
+//! qs push "Fix login crash" +bug +urgent @bugs
+//! ```
+//!
+//! Arguments are split into words. `+label` adds a label, `@category` sets the
+//! category, and every other word is part of the title. A leading backslash
+//! keeps a word literal (`\@home` is the title word `@home`).
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use anyhow::{bail, Result};
+
+/// Prefix of label words
+const LABEL_PREFIX: char = '+';
+
+/// Prefix of the category word
+const CATEGORY_PREFIX: char = '@';
+
+/// Title, labels, and category parsed from quick syntax
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QuickEntry {
+    pub title: String,
+    pub labels: Vec<String>,
+    pub category: Option<String>,
+}
+
+/// Parses quick-syntax arguments into a title, labels, and category.
+pub fn parse<S: AsRef<str>>(args: &[S]) -> Result<QuickEntry> {
+    let mut entry = QuickEntry::default();
+    let mut title: Vec<&str> = Vec::new();
+
+    for word in args.iter().flat_map(|arg| arg.as_ref().split_whitespace()) {
+        if let Some(literal) = word.strip_prefix('\\') {
+            title.push(literal);
+        } else if let Some(label) = marker(word, LABEL_PREFIX) {
+            if !entry.labels.iter().any(|l| l == label) {
+                entry.labels.push(label.to_string());
+            }
+        } else if let Some(category) = marker(word, CATEGORY_PREFIX) {
+            if let Some(ref existing) = entry.category {
+                bail!("Only one category allowed (got @{existing} and @{category})");
+            }
+            entry.category = Some(category.to_string());
+        } else {
+            title.push(word);
+        }
+    }
+
+    if title.is_empty() {
+        bail!("Title cannot be empty");
+    }
+    entry.title = title.join(" ");
+    Ok(entry)
+}
+
+/// Returns the rest of `word` if it starts with `prefix` and is not only the prefix.
+fn marker(word: &str, prefix: char) -> Option<&str> {
+    word.strip_prefix(prefix).filter(|rest| !rest.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_title_labels_and_category() {
+        let entry = parse(&["Fix login crash", "+bug", "+urgent", "@bugs"]).unwrap();
+        assert_eq!(
+            entry,
+            QuickEntry {
+                title: "Fix login crash".to_string(),
+                labels: vec!["bug".to_string(), "urgent".to_string()],
+                category: Some("bugs".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_markers_anywhere_and_inside_arguments() {
+        let entry = parse(&["+ui", "Dark mode +ui", "for", "@features settings"]).unwrap();
+        assert_eq!(entry.title, "Dark mode for settings");
+        assert_eq!(entry.labels, vec!["ui"]);
+        assert_eq!(entry.category.as_deref(), Some("features"));
+    }
+
+    #[test]
+    fn test_parse_literal_words() {
+        let entry = parse(&["Email", "\\@support", "about", "C", "+", "\\+1"]).unwrap();
+        assert_eq!(entry.title, "Email @support about C + +1");
+        assert!(entry.labels.is_empty());
+        assert_eq!(entry.category, None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse(&["+bug", "@bugs"]).is_err());
+        assert!(parse(&["Title", "@a", "@b"])
+            .unwrap_err()
+            .to_string()
+            .contains("Only one category"));
+    }
+}
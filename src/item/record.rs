@@ -0,0 +1,65 @@
+//! # Item Record
+//!
+//! Flat, serializable view of an item for machine-readable output.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::{Item, Status};
+use crate::{config::Config, storage};
+
+/// Serializable summary of an item, including where it lives on disk.
+#[derive(Debug, Clone, Serialize)]
+pub struct ItemRecord {
+    pub id: String,
+    pub title: String,
+    pub author: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due: Option<DateTime<Utc>>,
+    pub labels: Vec<String>,
+    pub category: Option<String>,
+    /// Path relative to the project root
+    pub path: String,
+    /// Attached files (relative to the attachment directory) and URLs
+    pub attachments: Vec<String>,
+}
+
+impl ItemRecord {
+    /// Builds a record from an item, its category, and its relative path.
+    pub fn new(item: &Item, category: Option<String>, path: String) -> Self {
+        Self {
+            id: item.qualified_id(),
+            title: item.title().to_string(),
+            author: item.author().to_string(),
+            assignee: item.assignee(),
+            created_at: item.created_at(),
+            status: item.status(),
+            closed_at: item.closed_at(),
+            due: item.due_date(),
+            labels: item.labels().to_vec(),
+            category,
+            path,
+            attachments: item.attachments().to_vec(),
+        }
+    }
+
+    /// Builds a record for an item stored at `path` in the project of `config`.
+    pub fn at(config: &Config, item: &Item, path: &Path) -> Self {
+        Self::new(
+            item,
+            storage::derive_category(config, path),
+            config.relative_path(path).display().to_string(),
+        )
+    }
+}
@@ -0,0 +1,234 @@
+//! # Auto-Labeling Rules
+//!
+//! Config-declared rules that label items by pattern. Each `[[rules]]` entry
+//! matches on the title and/or body (case-insensitive regular expressions) and
+//! adds labels and fills in custom fields:
+//!
+//! ```toml
+//! [[rules]]
+//! title = "panic|crash"
+//! labels = ["bug"]
+//! set = { priority = "high" }
+//! ```
+//!
+//! Rules only ever add: labels already present are kept, and `set` fields that
+//! already have a value are left alone, so re-applying rules is safe.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context, Result};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use serde_yml::Value;
+
+use super::{normalize_identifier, Item};
+
+/// Frontmatter fields that rules cannot set through `set`.
+const RESERVED_FIELDS: &[&str] = &[
+    "id",
+    "title",
+    "author",
+    "created_at",
+    "status",
+    "closed_at",
+    "due",
+    "labels",
+    "attachments",
+    "checksums",
+    "link_titles",
+    "actions",
+];
+
+/// A single auto-labeling rule
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rule {
+    /// Regular expression matched against the title
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Regular expression matched against the body
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// Labels to add
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+
+    /// Custom fields to fill in when not already set
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub set: BTreeMap<String, String>,
+}
+
+impl Rule {
+    /// Returns true if every pattern of the rule matches the item.
+    fn matches(&self, item: &Item) -> Result<bool> {
+        if self.title.is_none() && self.body.is_none() {
+            bail!("Rule has no 'title' or 'body' pattern");
+        }
+        if let Some(ref pattern) = self.title {
+            if !compile(pattern)?.is_match(item.title()) {
+                return Ok(false);
+            }
+        }
+        if let Some(ref pattern) = self.body {
+            if !compile(pattern)?.is_match(&item.body) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+fn compile(pattern: &str) -> Result<Regex> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(true)
+        .build()
+        .with_context(|| format!("Invalid rule pattern '{pattern}'"))
+}
+
+/// Applies all matching rules to the item.
+///
+/// Returns a description of each change (`+bug`, `priority=high`); empty if
+/// nothing changed.
+pub fn apply(rules: &[Rule], item: &mut Item) -> Result<Vec<String>> {
+    let mut changes = Vec::new();
+
+    for rule in rules {
+        if !rule.matches(item)? {
+            continue;
+        }
+
+        for label in &rule.labels {
+            let label = normalize_identifier(label);
+            if !label.is_empty() && !item.labels().contains(&label) {
+                item.add_label(&label);
+                changes.push(format!("+{label}"));
+            }
+        }
+
+        for (field, value) in &rule.set {
+            if RESERVED_FIELDS.contains(&field.as_str()) {
+                bail!("Rules cannot set '{field}'");
+            }
+            if field == "assignee" {
+                if item.assignee().is_none() {
+                    item.set_assignee(Some(value.clone()));
+                    changes.push(format!("{field}={value}"));
+                }
+            } else if !item.frontmatter.extra.contains_key(field) {
+                item.frontmatter
+                    .extra
+                    .insert(field.clone(), Value::String(value.clone()));
+                changes.push(format!("{field}={value}"));
+            }
+        }
+    }
+
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::item::Frontmatter;
+
+    fn item(title: &str) -> Item {
+        Item::new(Frontmatter {
+            id: "260101-AAA".to_string(),
+            title: title.to_string(),
+            ..Frontmatter::default()
+        })
+    }
+
+    fn crash_rule() -> Rule {
+        Rule {
+            title: Some("panic|crash".to_string()),
+            labels: vec!["bug".to_string()],
+            set: BTreeMap::from([("priority".to_string(), "high".to_string())]),
+            ..Rule::default()
+        }
+    }
+
+    #[test]
+    fn test_apply_matching_rule() {
+        let mut item = item("App CRASHES on start");
+        let changes = apply(&[crash_rule()], &mut item).unwrap();
+        assert_eq!(changes, ["+bug", "priority=high"]);
+        assert_eq!(item.labels(), ["bug"]);
+        assert_eq!(
+            item.frontmatter.extra.get("priority"),
+            Some(&Value::from("high"))
+        );
+
+        // Re-applying is a no-op
+        assert!(apply(&[crash_rule()], &mut item).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_apply_keeps_existing_fields() {
+        let mut item = item("Panic in parser");
+        item.frontmatter
+            .extra
+            .insert("priority".to_string(), Value::from("low"));
+        assert_eq!(apply(&[crash_rule()], &mut item).unwrap(), ["+bug"]);
+        assert_eq!(
+            item.frontmatter.extra.get("priority"),
+            Some(&Value::from("low"))
+        );
+    }
+
+    #[test]
+    fn test_apply_sets_assignee() {
+        let rule = Rule {
+            set: BTreeMap::from([("assignee".to_string(), "alice".to_string())]),
+            ..crash_rule()
+        };
+        let mut item = item("Crash");
+        assert_eq!(
+            apply(std::slice::from_ref(&rule), &mut item).unwrap(),
+            ["+bug", "assignee=alice"]
+        );
+        assert_eq!(item.assignee().as_deref(), Some("alice"));
+        assert!(item.frontmatter.extra.is_empty());
+
+        item.set_assignee(Some("bob".to_string()));
+        assert!(apply(&[rule], &mut item).unwrap().is_empty());
+        assert_eq!(item.assignee().as_deref(), Some("bob"));
+    }
+
+    #[test]
+    fn test_apply_requires_all_patterns() {
+        let rule = Rule {
+            body: Some("stack trace".to_string()),
+            ..crash_rule()
+        };
+        let mut item = item("Crash");
+        assert!(apply(std::slice::from_ref(&rule), &mut item)
+            .unwrap()
+            .is_empty());
+
+        item.body = "Stack trace attached".to_string();
+        assert!(!apply(&[rule], &mut item).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_apply_rejects_invalid_rules() {
+        let mut item = item("Crash");
+        assert!(apply(&[Rule::default()], &mut item).is_err());
+
+        let bad_pattern = Rule {
+            title: Some("(".to_string()),
+            ..Rule::default()
+        };
+        assert!(apply(&[bad_pattern], &mut item).is_err());
+
+        let reserved = Rule {
+            set: BTreeMap::from([("status".to_string(), "closed".to_string())]),
+            ..crash_rule()
+        };
+        assert!(apply(&[reserved], &mut item).is_err());
+    }
+}
@@ -6,7 +6,11 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
-use super::Item;
+use std::ops::Range;
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+
+use super::{dates::DateRange, deps::Blockers, fold, identity::Identities, Item, Status};
 
 // =============================================================================
 // Filter Criteria
@@ -20,12 +24,34 @@ use super::Item;
 pub struct FilterCriteria {
     /// Text search query (matches title, ID, and optionally body).
     pub search: String,
-    /// Labels to filter by (OR logic - item must have ANY of these).
+    /// Labels to filter by (AND logic - item must have ALL of these).
     pub labels: Vec<String>,
-    /// Category to filter by (exact match, case-insensitive).
+    /// Labels to filter by (OR logic - item must have ANY of these).
+    pub any_labels: Vec<String>,
+    /// Labels to exclude (item must have NONE of these).
+    pub not_labels: Vec<String>,
+    /// Category to filter by (exact match, folded).
     pub category: Option<String>,
-    /// Author to filter by (substring match, case-insensitive).
+    /// Author to filter by (substring match, folded).
     pub author: Option<String>,
+    /// Assignee to filter by (exact match, folded).
+    pub assignee: Option<String>,
+    /// Author identities used to unify spellings in the author and assignee filters.
+    pub identities: Identities,
+    /// Creation date range (inclusive).
+    pub created: DateRange,
+    /// Close date range (inclusive); only closed items can match.
+    pub closed: DateRange,
+    /// Only items due on or after this date.
+    pub due_after: Option<NaiveDate>,
+    /// Only items due on or before this date.
+    pub due_before: Option<NaiveDate>,
+    /// Only open or in-progress items whose due date has passed.
+    pub overdue: bool,
+    /// Only blocked (`Some(true)`) or unblocked (`Some(false)`) items.
+    pub blocked: Option<bool>,
+    /// Dependencies between the active items, used by the blocked filter.
+    pub blockers: Blockers,
 }
 
 impl FilterCriteria {
@@ -38,8 +64,17 @@ impl FilterCriteria {
     pub fn is_empty(&self) -> bool {
         self.search.is_empty()
             && self.labels.is_empty()
+            && self.any_labels.is_empty()
+            && self.not_labels.is_empty()
             && self.category.is_none()
             && self.author.is_none()
+            && self.assignee.is_none()
+            && self.created.is_empty()
+            && self.closed.is_empty()
+            && self.due_after.is_none()
+            && self.due_before.is_none()
+            && !self.overdue
+            && self.blocked.is_none()
     }
 }
 
@@ -63,85 +98,152 @@ pub fn matches_filter(item: &Item, criteria: &FilterCriteria, item_category: Opt
         return false;
     }
 
-    // Label filter (OR logic - item must have ANY of the specified labels)
-    if !criteria.labels.is_empty() && !matches_labels(item, &criteria.labels) {
+    // Label filters: ALL of `labels`, ANY of `any_labels`, NONE of `not_labels`
+    if !matches_all_labels(item.labels(), &criteria.labels) {
+        return false;
+    }
+    if !criteria.any_labels.is_empty() && !matches_any_label(item.labels(), &criteria.any_labels) {
+        return false;
+    }
+    if matches_any_label(item.labels(), &criteria.not_labels) {
         return false;
     }
 
-    // Category filter (case-insensitive, with "uncategorized" special case)
+    // Category filter (folded, with "uncategorized" special case)
     if let Some(ref filter_cat) = criteria.category {
         if !matches_category(item_category, filter_cat) {
             return false;
         }
     }
 
-    // Author filter (case-insensitive substring match)
+    // Author filter (folded substring match)
     if let Some(ref filter_author) = criteria.author {
-        if !matches_author(item, filter_author) {
+        if !matches_author(item, filter_author, &criteria.identities) {
+            return false;
+        }
+    }
+
+    // Assignee filter (same identity, folded)
+    if let Some(ref filter_assignee) = criteria.assignee {
+        if !matches_assignee(item, filter_assignee, &criteria.identities) {
             return false;
         }
     }
 
+    // Date filters (inclusive ranges)
+    if !criteria.created.contains(item.created_at()) {
+        return false;
+    }
+    if !criteria.closed.is_empty() && !matches_closed(item, &criteria.closed) {
+        return false;
+    }
+    if !matches_due(item, criteria.due_after, criteria.due_before) {
+        return false;
+    }
+    if criteria.overdue && !item.is_overdue(Local::now().date_naive()) {
+        return false;
+    }
+
+    // Dependency filter
+    if criteria
+        .blocked
+        .is_some_and(|blocked| criteria.blockers.is_blocked(item) != blocked)
+    {
+        return false;
+    }
+
     true
 }
 
+/// Checks if a closed item was closed within the range.
+///
+/// Items closed before `closed_at` was recorded fall back to their creation date.
+fn matches_closed(item: &Item, range: &DateRange) -> bool {
+    item.status() == Status::Closed
+        && range.contains(item.closed_at().unwrap_or_else(|| item.created_at()))
+}
+
+/// Checks if the item is due within the range; items without a due date only
+/// match when neither bound is set.
+fn matches_due(item: &Item, after: Option<NaiveDate>, before: Option<NaiveDate>) -> bool {
+    if after.is_none() && before.is_none() {
+        return true;
+    }
+    item.due().is_some_and(|due| {
+        after.map_or(true, |after| due >= after) && before.map_or(true, |before| due <= before)
+    })
+}
+
 // =============================================================================
 // Individual Filter Predicates (public for TUI reuse)
 // =============================================================================
 
-/// Checks if text fields match search query (case-insensitive).
+/// Checks if text fields match search query (folded, see [`fold`]).
 ///
 /// Returns true if any of title, ID, or body contain the query.
 pub fn matches_search_text(title: &str, id: &str, body: &str, query: &str) -> bool {
-    let query_lower = query.to_lowercase();
+    let query = fold::key(query);
 
-    title.to_lowercase().contains(&query_lower)
-        || id.to_lowercase().contains(&query_lower)
-        || body.to_lowercase().contains(&query_lower)
+    fold::key(title).contains(&query)
+        || fold::key(id).contains(&query)
+        || fold::key(body).contains(&query)
 }
 
-/// Checks if item has ANY of the specified labels (OR logic, case-insensitive).
+/// Checks if item has ANY of the specified labels (OR logic, folded).
 pub fn matches_any_label(item_labels: &[String], filter_labels: &[String]) -> bool {
     filter_labels
         .iter()
-        .any(|filter| item_labels.iter().any(|l| l.eq_ignore_ascii_case(filter)))
+        .any(|filter| item_labels.iter().any(|l| fold::eq(l, filter)))
+}
+
+/// Checks if item has ALL of the specified labels (AND logic, folded).
+pub fn matches_all_labels(item_labels: &[String], filter_labels: &[String]) -> bool {
+    filter_labels
+        .iter()
+        .all(|filter| item_labels.iter().any(|l| fold::eq(l, filter)))
 }
 
-/// Checks if item's category matches the filter (case-insensitive).
+/// Checks if item's category matches the filter (folded).
 ///
 /// Handles "uncategorized" as a special case for items with no category.
 pub fn matches_category_filter(item_category: Option<&str>, filter_category: &str) -> bool {
     item_category.map_or_else(
-        || filter_category.eq_ignore_ascii_case("uncategorized"),
-        |cat| cat.eq_ignore_ascii_case(filter_category),
+        || fold::eq(filter_category, "uncategorized"),
+        |cat| fold::eq(cat, filter_category),
     )
 }
 
-/// Checks if author matches filter (case-insensitive substring).
+/// Checks if author matches filter (folded substring).
 pub fn matches_author_filter(item_author: &str, filter_author: &str) -> bool {
-    item_author
-        .to_lowercase()
-        .contains(&filter_author.to_lowercase())
+    fold::contains(item_author, filter_author)
 }
 
 // Internal wrappers for Item
-fn matches_labels(item: &Item, labels: &[String]) -> bool {
-    matches_any_label(item.labels(), labels)
-}
-
 fn matches_category(item_category: Option<&str>, filter_category: &str) -> bool {
     matches_category_filter(item_category, filter_category)
 }
 
-fn matches_author(item: &Item, filter_author: &str) -> bool {
+/// Matches the raw or canonical author, or any spelling of the filtered identity.
+fn matches_author(item: &Item, filter_author: &str, identities: &Identities) -> bool {
+    let canonical = identities.canonical(item.author());
     matches_author_filter(item.author(), filter_author)
+        || matches_author_filter(canonical, filter_author)
+        || identities
+            .lookup(filter_author)
+            .is_some_and(|filter| fold::eq(filter, canonical))
+}
+
+/// Matches the assignee, or any spelling of the same identity.
+fn matches_assignee(item: &Item, filter_assignee: &str, identities: &Identities) -> bool {
+    item.assignee()
+        .is_some_and(|assignee| identities.same(&assignee, filter_assignee))
 }
 
 // =============================================================================
 // Simple Query Matching (for search command)
 // =============================================================================
 
-/// Check if an item matches the search query (case-insensitive).
+/// Check if an item matches the search query (folded, see [`fold`]).
 ///
 /// Searches the item's title and ID. When `full_text` is true,
 /// also searches the body content.
@@ -149,29 +251,128 @@ fn matches_author(item: &Item, filter_author: &str) -> bool {
 /// This is a simpler interface for the search command. For full filtering
 /// with labels/category/author, use `matches_filter()` instead.
 pub fn matches_query(item: &Item, query: &str, full_text: bool) -> bool {
-    let query_lower = query.to_lowercase();
+    let query = fold::key(query);
 
     // Always search title
-    if item.title().to_lowercase().contains(&query_lower) {
+    if fold::key(item.title()).contains(&query) {
         return true;
     }
 
     // Always search ID
-    if item.id().to_lowercase().contains(&query_lower) {
+    if fold::key(item.id()).contains(&query) {
         return true;
     }
 
     // Optionally search body
-    if full_text && item.body.to_lowercase().contains(&query_lower) {
+    if full_text && fold::key(&item.body).contains(&query) {
         return true;
     }
 
     false
 }
 
+// =============================================================================
+// Ranking and Highlighting (for search command)
+// =============================================================================
+
+/// Score of a title match (highest tier).
+const SCORE_TITLE: u32 = 300;
+/// Score of an ID match.
+const SCORE_ID: u32 = 200;
+/// Score of a body match (lowest tier).
+const SCORE_BODY: u32 = 100;
+/// Bonus when the title starts with the query.
+const SCORE_TITLE_PREFIX: u32 = 20;
+/// Maximum recency boost; halves roughly every month of age.
+///
+/// Together with the prefix bonus this stays below the gap between tiers, so
+/// a newer body match never outranks an older title match.
+const SCORE_RECENCY_MAX: u32 = 50;
+
+/// Scores how well an item matches a search query, or `None` if it doesn't match.
+///
+/// Title matches rank above ID matches, which rank above body matches (only
+/// searched when `full_text` is true). Within a tier, titles starting with
+/// the query and newer items rank higher.
+pub fn match_score(item: &Item, query: &str, full_text: bool, now: DateTime<Utc>) -> Option<u32> {
+    let base = if let Some(range) = find_match(item.title(), query) {
+        if range.start == 0 {
+            SCORE_TITLE + SCORE_TITLE_PREFIX
+        } else {
+            SCORE_TITLE
+        }
+    } else if find_match(item.id(), query).is_some() {
+        SCORE_ID
+    } else if full_text && find_match(&item.body, query).is_some() {
+        SCORE_BODY
+    } else {
+        return None;
+    };
+
+    // Items dated in the future count as new
+    let age_days = u32::try_from((now - item.created_at()).num_days()).unwrap_or(0);
+    Some(base + SCORE_RECENCY_MAX * 30 / age_days.saturating_add(30))
+}
+
+/// Keeps the items matching a query, best match first (ties sorted by ID).
+pub fn rank_matches(items: Vec<Item>, query: &str, full_text: bool) -> Vec<Item> {
+    rank_matches_within(items, query, full_text, None)
+}
+
+/// Like [`rank_matches`], but only searches bodies of at most `max_body_words`
+/// words; longer items can still match by title or ID.
+pub fn rank_matches_within(
+    items: Vec<Item>,
+    query: &str,
+    full_text: bool,
+    max_body_words: Option<usize>,
+) -> Vec<Item> {
+    let now = Utc::now();
+    let mut scored: Vec<(u32, Item)> = items
+        .into_iter()
+        .filter_map(|item| {
+            let full_text = full_text && !item.body_size().exceeds(max_body_words);
+            match_score(&item, query, full_text, now).map(|score| (score, item))
+        })
+        .collect();
+    scored.sort_by(|(a_score, a), (b_score, b)| {
+        b_score.cmp(a_score).then_with(|| a.id().cmp(b.id()))
+    });
+    scored.into_iter().map(|(_, item)| item).collect()
+}
+
+/// Finds the first occurrence of `query` in `text`, compared folded.
+///
+/// Returns the byte range in `text`, so it can be used to highlight the match.
+pub fn find_match(text: &str, query: &str) -> Option<Range<usize>> {
+    let needle: Vec<char> = fold::key(query).chars().collect();
+    if needle.is_empty() {
+        return None;
+    }
+    text.char_indices()
+        .find_map(|(start, _)| match_len(&text[start..], &needle).map(|len| start..start + len))
+}
+
+/// Returns the byte length of the prefix of `haystack` matching the folded `needle`.
+fn match_len(haystack: &str, needle: &[char]) -> Option<usize> {
+    let mut rest = needle;
+    for (offset, c) in haystack.char_indices() {
+        for folded in fold::char_key(c).chars() {
+            match rest.split_first() {
+                Some((&expected, tail)) if expected == folded => rest = tail,
+                _ => return None,
+            }
+        }
+        if rest.is_empty() {
+            return Some(offset + c.len_utf8());
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
-    use chrono::Utc;
+    use chrono::{TimeDelta, Utc};
 
     use super::*;
     use crate::item::{Frontmatter, Status};
@@ -183,14 +384,26 @@ mod tests {
             author: "Test".to_string(),
             created_at: Utc::now(),
             status: Status::Open,
-            labels: vec![],
-            attachments: vec![],
+            ..Frontmatter::default()
         };
         let mut item = Item::new(frontmatter);
         item.body = body.to_string();
         item
     }
 
+    #[test]
+    fn test_rank_matches_within_skips_large_bodies() {
+        let small = sample_item("Small", "mentions the token here");
+        let large = sample_item("Large", &format!("{}token", "filler ".repeat(50)));
+        let ranked =
+            rank_matches_within(vec![small.clone(), large.clone()], "token", true, Some(10));
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].title(), "Small");
+
+        let ranked = rank_matches_within(vec![small, large], "token", true, None);
+        assert_eq!(ranked.len(), 2);
+    }
+
     #[test]
     fn test_matches_title() {
         let item = sample_item("Fix Login Bug", "");
@@ -227,4 +440,164 @@ mod tests {
         assert!(matches_query(&item, "important", true));
         assert!(matches_query(&item, "260109", true));
     }
+
+    #[test]
+    fn test_find_match() {
+        assert_eq!(find_match("Fix Login Bug", "login"), Some(4..9));
+        assert_eq!(find_match("Fix Login Bug", "LOGIN"), Some(4..9));
+        assert_eq!(find_match("Größe ändern", "ÄNDERN"), Some(8..15));
+        assert_eq!(find_match("Fix Login Bug", "xyz"), None);
+        assert_eq!(find_match("Fix Login Bug", ""), None);
+    }
+
+    #[test]
+    fn test_match_score_tiers() {
+        let now = Utc::now();
+        let title = sample_item("Crash on login", "");
+        let prefix = sample_item("Login crash", "");
+        let body = sample_item("Crash", "Happens on login");
+
+        let score = |item: &Item| match_score(item, "login", true, now).unwrap();
+        assert!(score(&prefix) > score(&title));
+        assert!(score(&title) > match_score(&title, "02F7", true, now).unwrap());
+        assert!(match_score(&title, "02F7", true, now).unwrap() > score(&body));
+        assert_eq!(match_score(&body, "login", false, now), None);
+    }
+
+    #[test]
+    fn test_match_score_recency_boost_stays_within_tier() {
+        let now = Utc::now();
+        let mut old_title = sample_item("Crash on login", "");
+        old_title.frontmatter.created_at = now - TimeDelta::days(3650);
+        let mut old_body = sample_item("Crash", "login");
+        old_body.frontmatter.created_at = now - TimeDelta::days(365);
+        let new_body = sample_item("Crash", "login");
+
+        let score = |item: &Item| match_score(item, "login", true, now).unwrap();
+        assert!(score(&new_body) > score(&old_body));
+        assert!(score(&old_title) > score(&new_body));
+    }
+
+    #[test]
+    fn test_rank_matches_orders_by_score() {
+        let mut body = sample_item("Crash", "login fails");
+        body.frontmatter.id = "260101-AAA".to_string();
+        let mut title = sample_item("Login fails", "");
+        title.frontmatter.id = "260102-BBB".to_string();
+        let mut other = sample_item("Unrelated", "");
+        other.frontmatter.id = "260103-CCC".to_string();
+
+        let ranked = rank_matches(vec![body, title, other], "login", true);
+        let ids: Vec<&str> = ranked.iter().map(Item::id).collect();
+        assert_eq!(ids, ["260102-BBB", "260101-AAA"]);
+    }
+
+    #[test]
+    fn test_matches_filter_label_logic() {
+        let mut item = sample_item("Crash", "");
+        item.frontmatter.labels = vec!["bug".to_string(), "urgent".to_string()];
+        let labels = |names: &[&str]| names.iter().map(ToString::to_string).collect();
+        let matches = |criteria: FilterCriteria| matches_filter(&item, &criteria, None);
+
+        assert!(matches(FilterCriteria {
+            labels: labels(&["bug", "URGENT"]),
+            ..FilterCriteria::default()
+        }));
+        assert!(!matches(FilterCriteria {
+            labels: labels(&["bug", "ui"]),
+            ..FilterCriteria::default()
+        }));
+        assert!(matches(FilterCriteria {
+            any_labels: labels(&["ui", "bug"]),
+            ..FilterCriteria::default()
+        }));
+        assert!(!matches(FilterCriteria {
+            any_labels: labels(&["ui", "docs"]),
+            ..FilterCriteria::default()
+        }));
+        assert!(!matches(FilterCriteria {
+            labels: labels(&["bug"]),
+            not_labels: labels(&["urgent"]),
+            ..FilterCriteria::default()
+        }));
+        assert!(matches(FilterCriteria {
+            labels: labels(&["bug", "urgent"]),
+            not_labels: labels(&["wontfix"]),
+            ..FilterCriteria::default()
+        }));
+    }
+
+    #[test]
+    fn test_matches_filter_author_identities() {
+        let mut table = std::collections::BTreeMap::new();
+        table.insert(
+            "Dominic Rodemer".to_string(),
+            vec!["Dom".to_string(), "dom@example.com".to_string()],
+        );
+        let mut item = sample_item("Crash", "");
+        item.frontmatter.author = "Dom".to_string();
+        let matches = |author: &str| {
+            let criteria = FilterCriteria {
+                author: Some(author.to_string()),
+                identities: Identities::new(&table),
+                ..FilterCriteria::default()
+            };
+            matches_filter(&item, &criteria, None)
+        };
+
+        assert!(matches("dom"));
+        assert!(matches("Rodemer"));
+        assert!(matches("dom@example.com"));
+        assert!(!matches("Alice"));
+    }
+
+    #[test]
+    fn test_matches_filter_due_dates() {
+        let date = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok();
+        let mut item = sample_item("Release", "");
+        let matches = |item: &Item, after: &str, before: &str, overdue: bool| {
+            let criteria = FilterCriteria {
+                due_after: date(after),
+                due_before: date(before),
+                overdue,
+                ..FilterCriteria::default()
+            };
+            matches_filter(item, &criteria, None)
+        };
+
+        // Items without a due date only match without due filters
+        assert!(matches(&item, "", "", false));
+        assert!(!matches(&item, "", "2026-02-01", false));
+        assert!(!matches(&item, "", "", true));
+
+        item.set_due(date("2020-02-01"));
+        assert!(matches(&item, "2020-02-01", "2020-02-01", false));
+        assert!(!matches(&item, "2020-02-02", "", false));
+        assert!(!matches(&item, "", "2020-01-31", false));
+        assert!(matches(&item, "", "", true));
+
+        // Closed items are never overdue
+        item.frontmatter.status = Status::Closed;
+        assert!(!matches(&item, "", "", true));
+    }
+
+    #[test]
+    fn test_matches_filter_assignee() {
+        let mut item = sample_item("Release", "");
+        let matches = |item: &Item, assignee: &str| {
+            let criteria = FilterCriteria {
+                assignee: Some(assignee.to_string()),
+                ..FilterCriteria::default()
+            };
+            matches_filter(item, &criteria, None)
+        };
+
+        // Unassigned items never match
+        assert!(!matches(&item, "Alice"));
+
+        item.set_assignee(Some("Alice".to_string()));
+        assert!(matches(&item, "alice"));
+        assert!(!matches(&item, "Ali"));
+        assert!(!matches(&item, "Bob"));
+    }
 }
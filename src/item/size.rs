@@ -0,0 +1,119 @@
+//! # Body Size
+//!
+//! Word count, reading time, and byte size of an item's body. Shown on item
+//! cards, and compared against `max_body_words` to warn about items that have
+//! grown large enough to be worth splitting.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::fmt;
+
+use crate::constants::VAULT_FOOTER_MARKER;
+
+/// Reading speed used for the reading time estimate
+pub const WORDS_PER_MINUTE: usize = 200;
+
+/// Size statistics of an item body
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BodySize {
+    /// Words, not counting markdown syntax such as `#` or `-`
+    pub words: usize,
+    /// Bytes of the body text
+    pub bytes: usize,
+}
+
+impl BodySize {
+    /// Measures a body, leaving out the generated vault footer.
+    pub fn of(body: &str) -> Self {
+        let body = body.split(VAULT_FOOTER_MARKER).next().unwrap_or_default();
+        let words = body
+            .split_whitespace()
+            .filter(|word| word.chars().any(char::is_alphanumeric))
+            .count();
+        Self {
+            words,
+            bytes: body.trim().len(),
+        }
+    }
+
+    /// Estimated reading time in whole minutes (at least 1 for a non-empty body).
+    pub const fn reading_minutes(&self) -> usize {
+        self.words.div_ceil(WORDS_PER_MINUTE)
+    }
+
+    /// Returns true if the body has more words than the limit (`None` for no limit).
+    pub fn exceeds(&self, limit: Option<usize>) -> bool {
+        limit.is_some_and(|limit| self.words > limit)
+    }
+
+    /// Returns the warning for a body over the limit, if it is.
+    pub fn warning(&self, id: &str, limit: Option<usize>) -> Option<String> {
+        let limit = limit.filter(|_| self.exceeds(limit))?;
+        Some(format!(
+            "Item {id} has {} words (limit {limit}); consider splitting it into smaller items",
+            self.words
+        ))
+    }
+}
+
+impl fmt::Display for BodySize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = if self.words == 1 { "word" } else { "words" };
+        write!(
+            f,
+            "{} {unit} · {} min read",
+            self.words,
+            self.reading_minutes()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_words_without_markup() {
+        let size = BodySize::of("## Steps\n\n- Open the page\n- Log in\n\n---\n");
+        assert_eq!(size.words, 6);
+        assert_eq!(BodySize::of("").words, 0);
+        assert_eq!(BodySize::of("").reading_minutes(), 0);
+    }
+
+    #[test]
+    fn test_ignores_vault_footer() {
+        let body = format!("Short note\n\n{VAULT_FOOTER_MARKER}\n[[one]] [[two]] [[three]]\n");
+        assert_eq!(BodySize::of(&body).words, 2);
+    }
+
+    #[test]
+    fn test_reading_time_and_display() {
+        let size = BodySize {
+            words: 401,
+            bytes: 2000,
+        };
+        assert_eq!(size.reading_minutes(), 3);
+        assert_eq!(size.to_string(), "401 words · 3 min read");
+        assert_eq!(
+            BodySize { words: 1, bytes: 4 }.to_string(),
+            "1 word · 1 min read"
+        );
+    }
+
+    #[test]
+    fn test_limit() {
+        let size = BodySize {
+            words: 120,
+            bytes: 700,
+        };
+        assert!(size.exceeds(Some(100)));
+        assert!(!size.exceeds(Some(120)));
+        assert!(!size.exceeds(None));
+        assert_eq!(size.warning("260101-AAA", Some(120)), None);
+        assert_eq!(
+            size.warning("260101-AAA", Some(100)).unwrap(),
+            "Item 260101-AAA has 120 words (limit 100); consider splitting it into smaller items"
+        );
+    }
+}
@@ -4,19 +4,67 @@
 //!
 //! ## Rules
 //! 1. Convert to lowercase (Unicode-aware)
-//! 2. Replace non-alphanumeric characters with hyphens
-//! 3. Collapse multiple hyphens
-//! 4. Trim hyphens from start/end
-//! 5. Truncate to 50 characters
+//! 2. Apply the configured `[slug_transliterations]`, then, with
+//!    `slug_style = "transliterate"`, the built-in tables (see `translit`)
+//! 3. Replace non-alphanumeric characters with hyphens
+//! 4. Collapse multiple hyphens
+//! 5. Trim hyphens from start/end
+//! 6. Truncate to 50 characters
+//! 7. Append `_` to reserved Windows device names (`con`, `nul`, ...)
 //!
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
-use crate::constants::MAX_SLUG_LENGTH;
+use std::{cell::RefCell, collections::BTreeMap, fmt};
 
-/// Converts a title string into a URL-safe slug.
+use serde::{Deserialize, Serialize};
+
+use super::translit;
+use crate::{constants::MAX_SLUG_LENGTH, storage::paths::is_reserved_name};
+
+/// How letters outside ASCII appear in slugs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SlugStyle {
+    /// Keep letters of any script (`über-änderung`, `日本語`)
+    #[default]
+    Unicode,
+    /// Spell letters in Latin where a table exists (`uber-anderung`, `hangeul`)
+    Transliterate,
+}
+
+impl fmt::Display for SlugStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unicode => write!(f, "unicode"),
+            Self::Transliterate => write!(f, "transliterate"),
+        }
+    }
+}
+
+/// Slug settings from the config
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SlugOptions {
+    pub style: SlugStyle,
+    /// Replacements applied first, keyed by lowercase text (`ü = "ue"`)
+    pub transliterations: BTreeMap<String, String>,
+}
+
+thread_local! {
+    /// Slug settings of the loaded project (set from the config).
+    static OPTIONS: RefCell<SlugOptions> = RefCell::new(SlugOptions::default());
+}
+
+/// Sets the slug settings used by [`slugify`].
+pub fn set_options(options: SlugOptions) {
+    OPTIONS.with(|cell| *cell.borrow_mut() = options);
+}
+
+/// Converts a title string into a URL-safe slug, using the configured
+/// [`SlugOptions`].
 ///
-/// Supports full UTF-8: umlauts, CJK characters, and other Unicode are preserved.
+/// By default full UTF-8 is supported: umlauts, CJK characters, and other
+/// Unicode are preserved.
 ///
 /// # Arguments
 /// * `title` - The original title string
@@ -31,6 +79,32 @@ use crate::constants::MAX_SLUG_LENGTH;
 /// assert_eq!(slugify("Über Änderung"), "über-änderung");
 /// ```
 pub fn slugify(title: &str) -> String {
+    OPTIONS.with(|cell| slugify_with(title, &cell.borrow()))
+}
+
+/// Converts a title string into a URL-safe slug with the given settings.
+///
+/// # Example
+/// ```
+/// use queuestack::item::slug::{slugify_with, SlugOptions, SlugStyle};
+/// let options = SlugOptions {
+///     style: SlugStyle::Transliterate,
+///     transliterations: [("ü".to_string(), "ue".to_string())].into(),
+/// };
+/// assert_eq!(slugify_with("Fehlermeldung prüfen", &options), "fehlermeldung-pruefen");
+/// ```
+pub fn slugify_with(title: &str, options: &SlugOptions) -> String {
+    let mut title = title.to_lowercase();
+    for (from, to) in &options.transliterations {
+        let from = from.to_lowercase();
+        if !from.is_empty() {
+            title = title.replace(&from, &to.to_lowercase());
+        }
+    }
+    if options.style == SlugStyle::Transliterate {
+        title = translit::transliterate(&title);
+    }
+
     let mut result = String::with_capacity(title.len());
     let mut prev_was_hyphen = true; // Start true to trim leading hyphens
 
@@ -81,6 +155,11 @@ pub fn slugify(title: &str) -> String {
         }
     }
 
+    // Keep the slug usable as a file name on Windows
+    if is_reserved_name(&result) {
+        result.push('_');
+    }
+
     result
 }
 
@@ -131,6 +210,13 @@ mod tests {
         assert!(!slug.ends_with('-'));
     }
 
+    #[test]
+    fn test_reserved_names() {
+        assert_eq!(slugify("CON"), "con_");
+        assert_eq!(slugify("nul"), "nul_");
+        assert_eq!(slugify("Console"), "console");
+    }
+
     #[test]
     fn test_empty_string() {
         assert_eq!(slugify(""), "");
@@ -140,4 +226,64 @@ mod tests {
     fn test_only_special_chars() {
         assert_eq!(slugify("!@#$%"), "");
     }
+
+    fn transliterate() -> SlugOptions {
+        SlugOptions {
+            style: SlugStyle::Transliterate,
+            transliterations: BTreeMap::new(),
+        }
+    }
+
+    /// Slugs are file names: these must never change once released
+    #[test]
+    fn test_transliterated_slugs_are_stable() {
+        let cases = [
+            ("Fehlermeldung prüfen", "fehlermeldung-prufen"),
+            ("Straße & Größe", "strasse-grosse"),
+            ("Café résumé", "cafe-resume"),
+            ("Łódź Œuvre", "lodz-oeuvre"),
+            ("Ошибка входа", "oshibka-vkhoda"),
+            ("Αθήνα", "athina"),
+            ("ログイン画面のバグ", "roguin-画面-nobagu"),
+            ("サーバーエラー", "sabaera"),
+            ("한글 제목", "hangeul-jemok"),
+            ("ＦＵＬＬ ｗｉｄｔｈ", "full-width"),
+            ("日本語タイトル", "日本語-taitoru"),
+            ("Fix Login Bug!", "fix-login-bug"),
+        ];
+        for (title, slug) in cases {
+            assert_eq!(slugify_with(title, &transliterate()), slug, "{title}");
+        }
+    }
+
+    #[test]
+    fn test_custom_transliterations() {
+        let options = SlugOptions {
+            style: SlugStyle::Unicode,
+            transliterations: [
+                ("Ä".to_string(), "ae".to_string()),
+                ("ü".to_string(), "ue".to_string()),
+                ("c++".to_string(), "cpp".to_string()),
+            ]
+            .into(),
+        };
+        assert_eq!(slugify_with("Über Änderung", &options), "ueber-aenderung");
+        assert_eq!(slugify_with("C++ parser", &options), "cpp-parser");
+        // Other letters keep the style's handling
+        assert_eq!(slugify_with("Größe", &options), "größe");
+
+        let options = SlugOptions {
+            style: SlugStyle::Transliterate,
+            ..options
+        };
+        assert_eq!(slugify_with("Größe prüfen", &options), "grosse-pruefen");
+    }
+
+    #[test]
+    fn test_configured_options() {
+        set_options(transliterate());
+        assert_eq!(slugify("Über"), "uber");
+        set_options(SlugOptions::default());
+        assert_eq!(slugify("Über"), "über");
+    }
 }
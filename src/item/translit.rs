@@ -0,0 +1,336 @@
+//! # Transliteration
+//!
+//! Rewrites text in Latin letters for `slug_style = "transliterate"`, so
+//! titles in other scripts still give readable ASCII file names:
+//!
+//! - Compatibility forms are folded (fullwidth letters, ligatures, super- and
+//!   subscript digits), as in Unicode NFKD.
+//! - Diacritics are removed (`é` → `e`, `ő` → `o`); letters without a
+//!   decomposition get their usual spelling (`ß` → `ss`, `æ` → `ae`, `ł` → `l`).
+//! - Greek and Cyrillic use common romanization tables.
+//! - Hiragana and katakana use Hepburn romanization (`ログイン` → `roguin`).
+//! - Hangul syllables are decomposed and romanized (`한글` → `hangeul`).
+//!
+//! Letters without a table (e.g. CJK ideographs) are kept as they are, set
+//! apart from neighbouring Latin letters by a space (`画面のバグ` →
+//! `画面 nobagu`), so slugs separate the runs with a hyphen. The tables are part of the file name format: changing an entry renames items
+//! on their next save, so entries are only ever added.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+/// Latin letters with diacritics and special letters, by replacement
+const LATIN: &[(&str, &str)] = &[
+    ("àáâãäåāăąǎǻạảấầẩẫậắằẳẵặ", "a"),
+    ("æǽ", "ae"),
+    ("çćĉċč", "c"),
+    ("ďđð", "d"),
+    ("èéêëēĕėęěẹẻẽếềểễệ", "e"),
+    ("ĝğġģ", "g"),
+    ("ĥħ", "h"),
+    ("ìíîïĩīĭįıǐỉị", "i"),
+    ("ĳ", "ij"),
+    ("ĵ", "j"),
+    ("ķ", "k"),
+    ("ĺļľŀł", "l"),
+    ("ñńņňŉ", "n"),
+    ("ŋ", "ng"),
+    ("òóôõöøōŏőơǒǿọỏốồổỗộớờởỡợ", "o"),
+    ("œ", "oe"),
+    ("ŕŗř", "r"),
+    ("śŝşšș", "s"),
+    ("ß", "ss"),
+    ("ţťŧț", "t"),
+    ("þ", "th"),
+    ("ùúûüũūŭůűųưǔǖǘǚǜụủứừửữự", "u"),
+    ("ŵ", "w"),
+    ("ýÿŷỳỵỷỹ", "y"),
+    ("źżž", "z"),
+];
+
+/// Greek letters (lowercase, with tonos and dialytika)
+const GREEK: &[(&str, &str)] = &[
+    ("αά", "a"),
+    ("β", "v"),
+    ("γ", "g"),
+    ("δ", "d"),
+    ("εέ", "e"),
+    ("ζ", "z"),
+    ("ηήιίϊΐ", "i"),
+    ("θ", "th"),
+    ("κ", "k"),
+    ("λ", "l"),
+    ("μ", "m"),
+    ("ν", "n"),
+    ("ξ", "x"),
+    ("οόωώ", "o"),
+    ("π", "p"),
+    ("ρ", "r"),
+    ("σς", "s"),
+    ("τ", "t"),
+    ("υύϋΰ", "y"),
+    ("φ", "f"),
+    ("χ", "ch"),
+    ("ψ", "ps"),
+];
+
+/// Cyrillic letters (lowercase, Russian and Ukrainian)
+const CYRILLIC: &[(&str, &str)] = &[
+    ("а", "a"),
+    ("б", "b"),
+    ("в", "v"),
+    ("гґ", "g"),
+    ("д", "d"),
+    ("еэ", "e"),
+    ("ё", "yo"),
+    ("є", "ye"),
+    ("ж", "zh"),
+    ("з", "z"),
+    ("иі", "i"),
+    ("ї", "yi"),
+    ("йы", "y"),
+    ("к", "k"),
+    ("л", "l"),
+    ("м", "m"),
+    ("н", "n"),
+    ("о", "o"),
+    ("п", "p"),
+    ("р", "r"),
+    ("с", "s"),
+    ("т", "t"),
+    ("у", "u"),
+    ("ф", "f"),
+    ("х", "kh"),
+    ("ц", "ts"),
+    ("ч", "ch"),
+    ("ш", "sh"),
+    ("щ", "shch"),
+    ("ъь", ""),
+    ("ю", "yu"),
+    ("я", "ya"),
+];
+
+/// Ligatures and other compatibility characters
+const COMPATIBILITY: &[(&str, &str)] = &[
+    ("ﬀ", "ff"),
+    ("ﬁ", "fi"),
+    ("ﬂ", "fl"),
+    ("ﬃ", "ffi"),
+    ("ﬄ", "ffl"),
+    ("ﬅﬆ", "st"),
+    ("⁰₀", "0"),
+    ("¹₁", "1"),
+    ("²₂", "2"),
+    ("³₃", "3"),
+    ("⁴₄", "4"),
+    ("⁵₅", "5"),
+    ("⁶₆", "6"),
+    ("⁷₇", "7"),
+    ("⁸₈", "8"),
+    ("⁹₉", "9"),
+];
+
+/// Hiragana U+3041..=U+3096 in Hepburn romanization (katakana is mapped onto it)
+const KANA: [&str; 86] = [
+    "a", "a", "i", "i", "u", "u", "e", "e", "o", "o", // ぁ-お
+    "ka", "ga", "ki", "gi", "ku", "gu", "ke", "ge", "ko", "go", // か-ご
+    "sa", "za", "shi", "ji", "su", "zu", "se", "ze", "so", "zo", // さ-ぞ
+    "ta", "da", "chi", "ji", "", "tsu", "zu", "te", "de", "to", "do", // た-ど
+    "na", "ni", "nu", "ne", "no", // な-の
+    "ha", "ba", "pa", "hi", "bi", "pi", "fu", "bu", "pu", // は-ぷ
+    "he", "be", "pe", "ho", "bo", "po", // へ-ぽ
+    "ma", "mi", "mu", "me", "mo", // ま-も
+    "ya", "ya", "yu", "yu", "yo", "yo", // ゃ-よ
+    "ra", "ri", "ru", "re", "ro", // ら-ろ
+    "wa", "wa", "i", "e", "o", "n", "vu", "ka", "ke", // ゎ-ゖ
+];
+
+/// Small kana that modify the preceding syllable
+const SMALL_VOWELS: [char; 5] = ['ぁ', 'ぃ', 'ぅ', 'ぇ', 'ぉ'];
+const SMALL_Y: [char; 3] = ['ゃ', 'ゅ', 'ょ'];
+const SMALL_TSU: char = 'っ';
+
+/// Hangul initial consonants (Revised Romanization)
+const HANGUL_INITIAL: [&str; 19] = [
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p",
+    "h",
+];
+/// Hangul vowels (Revised Romanization)
+const HANGUL_VOWEL: [&str; 21] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we",
+    "wi", "yu", "eu", "ui", "i",
+];
+/// Hangul final consonants (Revised Romanization)
+const HANGUL_FINAL: [&str; 28] = [
+    "", "k", "k", "k", "n", "n", "n", "t", "l", "k", "m", "l", "l", "l", "p", "l", "m", "p", "p",
+    "t", "t", "ng", "t", "t", "k", "t", "p", "t",
+];
+
+/// Transliterates lowercase text to Latin letters where a table exists.
+pub fn transliterate(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().map(to_hiragana).peekable();
+    // Set by a small tsu: double the next syllable's consonant
+    let mut geminate = false;
+
+    while let Some(c) = chars.next() {
+        if let Some(mut syllable) = kana(c) {
+            if c == SMALL_TSU {
+                geminate = true;
+                continue;
+            }
+            // Digraphs: き+ゃ → kya, し+ゃ → sha; フ+ァ → fa
+            if let Some(&next) = chars.peek() {
+                if SMALL_Y.contains(&next) && syllable.len() > 1 && syllable.ends_with('i') {
+                    let stem = &syllable[..syllable.len() - 1];
+                    let vowel = &kana(next).unwrap_or_default()[1..];
+                    syllable = if matches!(stem, "sh" | "ch" | "j") {
+                        format!("{stem}{vowel}")
+                    } else {
+                        format!("{stem}y{vowel}")
+                    };
+                    chars.next();
+                } else if SMALL_VOWELS.contains(&next) && syllable.len() > 1 {
+                    syllable.pop();
+                    syllable.push_str(&kana(next).unwrap_or_default());
+                    chars.next();
+                }
+            }
+            if std::mem::take(&mut geminate) {
+                if let Some(first) = syllable.chars().next() {
+                    syllable.insert(0, first);
+                }
+            }
+            push_separated(&mut out, &syllable);
+            continue;
+        }
+        geminate = false;
+        match letter(c) {
+            Some(latin) => push_separated(&mut out, &latin),
+            None => push_separated(&mut out, c.encode_utf8(&mut [0; 4])),
+        }
+    }
+    out
+}
+
+/// Appends `text`, separated by a space where a run of kept non-Latin letters
+/// meets a run of Latin letters.
+fn push_separated(out: &mut String, text: &str) {
+    if let (Some(last), Some(first)) = (out.chars().next_back(), text.chars().next()) {
+        if last.is_alphanumeric() && first.is_alphanumeric() && last.is_ascii() != first.is_ascii()
+        {
+            out.push(' ');
+        }
+    }
+    out.push_str(text);
+}
+
+/// Returns the spelling of a lowercase Latin letter without its diacritics,
+/// or `None` if it has none (used for accent-insensitive matching).
+pub fn strip_diacritics(c: char) -> Option<&'static str> {
+    LATIN
+        .iter()
+        .find(|(letters, _)| letters.contains(c))
+        .map(|(_, latin)| *latin)
+}
+
+/// Maps katakana onto hiragana (same syllables, offset by 0x60).
+fn to_hiragana(c: char) -> char {
+    if ('\u{30A1}'..='\u{30F6}').contains(&c) {
+        char::from_u32(c as u32 - 0x60).unwrap_or(c)
+    } else {
+        c
+    }
+}
+
+/// Romanizes a single hiragana character.
+fn kana(c: char) -> Option<String> {
+    let index = (c as u32).checked_sub(0x3041)? as usize;
+    KANA.get(index).map(|syllable| (*syllable).to_string())
+}
+
+/// Transliterates a single non-kana character, or `None` to keep it.
+fn letter(c: char) -> Option<String> {
+    // Combining marks (left over from decomposed input) and the prolonged
+    // sound mark carry no letter of their own
+    if ('\u{0300}'..='\u{036F}').contains(&c) || c == 'ー' {
+        return Some(String::new());
+    }
+    // Fullwidth ASCII
+    if ('\u{FF01}'..='\u{FF5E}').contains(&c) {
+        let ascii = char::from_u32(c as u32 - 0xFEE0)?;
+        return Some(ascii.to_lowercase().collect());
+    }
+    if ('\u{AC00}'..='\u{D7A3}').contains(&c) {
+        return Some(hangul(c));
+    }
+    [LATIN, GREEK, CYRILLIC, COMPATIBILITY]
+        .iter()
+        .flat_map(|table| table.iter())
+        .find(|(letters, _)| letters.contains(c))
+        .map(|(_, latin)| (*latin).to_string())
+}
+
+/// Romanizes a precomposed Hangul syllable.
+fn hangul(c: char) -> String {
+    let index = c as usize - 0xAC00;
+    let initial = HANGUL_INITIAL[index / 588];
+    let vowel = HANGUL_VOWEL[(index % 588) / 28];
+    let last = HANGUL_FINAL[index % 28];
+    format!("{initial}{vowel}{last}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latin() {
+        assert_eq!(transliterate("prüfen"), "prufen");
+        assert_eq!(transliterate("straße"), "strasse");
+        assert_eq!(transliterate("œuvre à côté"), "oeuvre a cote");
+        assert_eq!(transliterate("łódź"), "lodz");
+        assert_eq!(transliterate("việt"), "viet");
+        assert_eq!(transliterate("e\u{0301}"), "e");
+    }
+
+    #[test]
+    fn test_greek_and_cyrillic() {
+        assert_eq!(transliterate("αθήνα"), "athina");
+        assert_eq!(transliterate("ошибка входа"), "oshibka vkhoda");
+        assert_eq!(transliterate("київ"), "kiyiv");
+    }
+
+    #[test]
+    fn test_compatibility() {
+        assert_eq!(transliterate("ｆｉｘ"), "fix");
+        assert_eq!(transliterate("ﬁle"), "file");
+        assert_eq!(transliterate("m²"), "m2");
+    }
+
+    #[test]
+    fn test_kana() {
+        assert_eq!(transliterate("ろぐいん"), "roguin");
+        assert_eq!(transliterate("ログイン"), "roguin");
+        assert_eq!(transliterate("きょう"), "kyou");
+        assert_eq!(transliterate("しゃしん"), "shashin");
+        assert_eq!(transliterate("ちょっと"), "chotto");
+        assert_eq!(transliterate("サーバー"), "saba");
+        assert_eq!(transliterate("パーティー"), "pati");
+        assert_eq!(transliterate("ファイル"), "fairu");
+    }
+
+    #[test]
+    fn test_hangul() {
+        assert_eq!(transliterate("한글"), "hangeul");
+        assert_eq!(transliterate("제목"), "jemok");
+        assert_eq!(transliterate("서울"), "seoul");
+    }
+
+    #[test]
+    fn test_untransliterated_letters_are_kept() {
+        assert_eq!(transliterate("画面のバグ"), "画面 nobagu");
+        assert_eq!(transliterate("ログイン画面"), "roguin 画面");
+        assert_eq!(transliterate("fix bug"), "fix bug");
+    }
+}
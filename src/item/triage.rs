@@ -0,0 +1,199 @@
+//! # Triage Lists
+//!
+//! Renders items as an editable list for `qs triage`, one item per line in the
+//! spirit of `git rebase -i`, and parses the edited list back:
+//!
+//! ```text
+//! 260109-0A2B3C4 | open        | bugs | Alice | bug, urgent | Fix login crash
+//! ```
+//!
+//! Columns are the ID, status, category, assignee, labels (comma-separated),
+//! and title. The title is shown for reference only and may contain `|`.
+//! Empty columns clear the field; blank lines and `#` comments are ignored.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{collections::HashSet, fmt::Write as _};
+
+use anyhow::{bail, Result};
+
+use super::Status;
+
+/// Column separator
+const SEPARATOR: char = '|';
+
+/// Number of columns in a line
+const COLUMNS: usize = 6;
+
+/// Instructions written above the list
+const HEADER: &str = "\
+# Triage: edit the status, category, assignee, and labels below, then save and
+# quit. Columns are separated by '|':
+#
+#   id | status | category | assignee | labels | title
+#
+# status    open, in-progress, or closed (closes and archives the item)
+# category  empty for the queuestack root
+# labels    comma-separated
+#
+# Titles are shown for reference only. Removing a line leaves its item
+# unchanged; emptying the list changes nothing.
+
+";
+
+/// One line of a triage list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TriageLine {
+    pub id: String,
+    pub status: Status,
+    pub category: Option<String>,
+    pub assignee: Option<String>,
+    pub labels: Vec<String>,
+    pub title: String,
+}
+
+/// Renders the lines below the instructions, with aligned columns.
+pub fn render(lines: &[TriageLine]) -> String {
+    let cells: Vec<[String; COLUMNS - 1]> = lines
+        .iter()
+        .map(|line| {
+            [
+                line.id.clone(),
+                line.status.to_string(),
+                line.category.clone().unwrap_or_default(),
+                line.assignee.clone().unwrap_or_default(),
+                line.labels.join(", "),
+            ]
+        })
+        .collect();
+    let mut widths = [0; COLUMNS - 1];
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut out = String::from(HEADER);
+    for (line, row) in lines.iter().zip(&cells) {
+        for (cell, width) in row.iter().zip(widths) {
+            let _ = write!(out, "{cell:<width$} {SEPARATOR} ");
+        }
+        let _ = writeln!(out, "{}", line.title);
+    }
+    out
+}
+
+/// Parses an edited triage list.
+///
+/// All invalid lines are reported together, with their line numbers.
+pub fn parse(text: &str) -> Result<Vec<TriageLine>> {
+    let mut lines = Vec::new();
+    let mut problems = Vec::new();
+    let mut seen = HashSet::new();
+
+    for (index, raw) in text.lines().enumerate() {
+        let raw = raw.trim();
+        if raw.is_empty() || raw.starts_with('#') {
+            continue;
+        }
+        match parse_line(raw) {
+            Ok(line) if !seen.insert(line.id.clone()) => {
+                problems.push(format!("line {}: {} is listed twice", index + 1, line.id));
+            }
+            Ok(line) => lines.push(line),
+            Err(e) => problems.push(format!("line {}: {e}", index + 1)),
+        }
+    }
+
+    if !problems.is_empty() {
+        bail!("Invalid triage list:\n  {}", problems.join("\n  "));
+    }
+    Ok(lines)
+}
+
+fn parse_line(raw: &str) -> Result<TriageLine> {
+    let columns: Vec<&str> = raw.splitn(COLUMNS, SEPARATOR).map(str::trim).collect();
+    let &[id, status, category, assignee, labels, title] = columns.as_slice() else {
+        bail!(
+            "expected {COLUMNS} columns separated by '{SEPARATOR}', found {}",
+            columns.len()
+        );
+    };
+    if id.is_empty() {
+        bail!("missing id");
+    }
+    let status = match status {
+        "open" => Status::Open,
+        "in-progress" => Status::InProgress,
+        "closed" => Status::Closed,
+        other => bail!("unknown status '{other}': use open, in-progress, or closed"),
+    };
+    let optional = |s: &str| Some(s.to_string()).filter(|s| !s.is_empty());
+
+    Ok(TriageLine {
+        id: id.to_string(),
+        status,
+        category: optional(category),
+        assignee: optional(assignee),
+        labels: labels
+            .split(',')
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .map(String::from)
+            .collect(),
+        title: title.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(id: &str, category: Option<&str>, labels: &[&str]) -> TriageLine {
+        TriageLine {
+            id: id.to_string(),
+            status: Status::Open,
+            category: category.map(String::from),
+            assignee: None,
+            labels: labels.iter().map(ToString::to_string).collect(),
+            title: "Fix | crash".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render_and_parse_round_trip() {
+        let lines = vec![
+            line("260101-AAA", Some("bugs"), &["bug", "ui"]),
+            line("260102-BBB", None, &[]),
+        ];
+        let text = render(&lines);
+        assert!(text.contains("260101-AAA | open | bugs | "));
+        assert_eq!(parse(&text).unwrap(), lines);
+    }
+
+    #[test]
+    fn test_parse_edits() {
+        let lines = parse("260101-AAA | in-progress | | Alice Smith | a,b , c | Title\n").unwrap();
+        assert_eq!(lines[0].status, Status::InProgress);
+        assert_eq!(lines[0].category, None);
+        assert_eq!(lines[0].assignee.as_deref(), Some("Alice Smith"));
+        assert_eq!(lines[0].labels, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_parse_reports_every_invalid_line() {
+        let err = parse(
+            "# comment\n\
+             260101-AAA | done | | | | Title\n\
+             260102-BBB | open\n\
+             260103-CCC | open | | | | One\n\
+             260103-CCC | open | | | | Two\n",
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("line 2: unknown status 'done'"));
+        assert!(err.contains("line 3: expected 6 columns"));
+        assert!(err.contains("line 5: 260103-CCC is listed twice"));
+    }
+}
@@ -0,0 +1,231 @@
+//! # Template Variables
+//!
+//! Expands `{{name}}` placeholders in template bodies when an item is created.
+//!
+//! ## Variables
+//! - `{{id}}`, `{{title}}`, `{{author}}`, `{{category}}`
+//! - `{{assignee}}` - assignee, if set
+//! - `{{date}}` - creation date (`YYYY-MM-DD`)
+//! - `{{due}}` - due date (`YYYY-MM-DD`), if set
+//! - `{{labels}}` - comma-separated labels
+//! - `{{<field>}}` - any custom frontmatter field (e.g. `{{priority}}`)
+//!
+//! Unknown variables are left untouched.
+//!
+//! ## Prompts
+//! `{{prompt:Steps to reproduce}}` declares a value that is asked for when an
+//! item is created from the template (or passed with `--var`). Every
+//! occurrence of the same prompt is replaced with the answer.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+
+use super::{slugify, Item};
+
+/// Prefix of prompt placeholders (`{{prompt:Steps to reproduce}}`)
+const PROMPT_PREFIX: &str = "prompt:";
+
+/// Expands template variables in `text` using the item's metadata.
+///
+/// Whitespace inside the braces is ignored (`{{ id }}` works like `{{id}}`).
+pub fn expand(text: &str, item: &Item, category: Option<&str>) -> String {
+    replace_placeholders(text, |name| lookup(name, item, category))
+}
+
+/// Returns the prompts declared in `text`, in order of first appearance.
+pub fn prompts(text: &str) -> Vec<String> {
+    let mut prompts: Vec<String> = Vec::new();
+    replace_placeholders(text, |name| {
+        if let Some(prompt) = prompt_name(name) {
+            if !prompts.iter().any(|p| p == prompt) {
+                prompts.push(prompt.to_string());
+            }
+        }
+        None
+    });
+    prompts
+}
+
+/// Replaces `{{prompt:...}}` placeholders with their answers, keyed by prompt.
+///
+/// Prompts without an answer are left untouched.
+pub fn fill_prompts(text: &str, answers: &BTreeMap<String, String>) -> String {
+    replace_placeholders(text, |name| answers.get(prompt_name(name)?).cloned())
+}
+
+/// Assigns `--var` values to the declared prompts.
+///
+/// A key matches a prompt ignoring case and punctuation, so
+/// `steps-to-reproduce` answers "Steps to reproduce". Fails for keys that
+/// match no prompt.
+pub fn match_vars(
+    prompts: &[String],
+    vars: &[(String, String)],
+) -> Result<BTreeMap<String, String>> {
+    let mut answers = BTreeMap::new();
+    for (key, value) in vars {
+        let Some(prompt) = prompts.iter().find(|p| slugify(p) == slugify(key)) else {
+            if prompts.is_empty() {
+                bail!("Unknown variable '{key}': the template has no prompts");
+            }
+            bail!(
+                "Unknown variable '{key}'. The template asks for: {}",
+                prompts.join(", ")
+            );
+        };
+        answers.insert(prompt.clone(), value.clone());
+    }
+    Ok(answers)
+}
+
+/// Parses a `--var key=value` argument.
+pub fn parse_var(value: &str) -> Result<(String, String)> {
+    match value.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => {
+            Ok((key.trim().to_string(), value.to_string()))
+        }
+        _ => bail!("expected key=value, got '{value}'"),
+    }
+}
+
+/// Returns the prompt text of a placeholder name like `prompt:Steps`.
+fn prompt_name(name: &str) -> Option<&str> {
+    let prompt = name.strip_prefix(PROMPT_PREFIX)?.trim();
+    (!prompt.is_empty()).then_some(prompt)
+}
+
+/// Replaces every `{{name}}` placeholder for which `f` returns a value.
+///
+/// `f` receives the name with surrounding whitespace trimmed. Placeholders it
+/// returns `None` for, and unclosed braces, are kept as they are.
+fn replace_placeholders(text: &str, mut f: impl FnMut(&str) -> Option<String>) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let name = rest[start + 2..start + 2 + len].trim();
+
+        result.push_str(&rest[..start]);
+        match f(name) {
+            Some(value) => result.push_str(&value),
+            None => result.push_str(&rest[start..start + len + 4]),
+        }
+        rest = &rest[start + len + 4..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Resolves a single variable name, or `None` if it is unknown.
+fn lookup(name: &str, item: &Item, category: Option<&str>) -> Option<String> {
+    let value = match name {
+        "id" => item.id().to_string(),
+        "title" => item.title().to_string(),
+        "author" => item.author().to_string(),
+        "assignee" => item.assignee()?,
+        "date" => item.created_at().format("%Y-%m-%d").to_string(),
+        "due" => item.due()?.to_string(),
+        "category" => category.unwrap_or_default().to_string(),
+        "labels" => item.labels().join(", "),
+        _ => match item.frontmatter.extra.get(name)? {
+            serde_yml::Value::String(s) => s.clone(),
+            serde_yml::Value::Sequence(seq) => seq
+                .iter()
+                .filter_map(|v| serde_yml::to_string(v).ok())
+                .map(|s| s.trim().to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            other => serde_yml::to_string(other).ok()?.trim().to_string(),
+        },
+    };
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::item::Frontmatter;
+
+    fn item() -> Item {
+        let mut frontmatter = Frontmatter {
+            id: "260109-ABC".to_string(),
+            title: "Fix login".to_string(),
+            author: "Dom".to_string(),
+            created_at: chrono::Utc.with_ymd_and_hms(2026, 1, 9, 12, 0, 0).unwrap(),
+            labels: vec!["bug".to_string(), "ui".to_string()],
+            ..Frontmatter::default()
+        };
+        frontmatter
+            .extra
+            .insert("priority".to_string(), serde_yml::Value::from("high"));
+        Item::new(frontmatter)
+    }
+
+    #[test]
+    fn test_expand_builtin_variables() {
+        let text = "# {{title}} ({{id}})\nBy {{author}} on {{date}} in {{category}}: {{labels}}";
+        assert_eq!(
+            expand(text, &item(), Some("backend")),
+            "# Fix login (260109-ABC)\nBy Dom on 2026-01-09 in backend: bug, ui"
+        );
+    }
+
+    #[test]
+    fn test_expand_custom_fields_and_whitespace() {
+        assert_eq!(
+            expand("Priority: {{ priority }}", &item(), None),
+            "Priority: high"
+        );
+        assert_eq!(expand("In {{category}}.", &item(), None), "In .");
+    }
+
+    #[test]
+    fn test_expand_leaves_unknown_and_unclosed() {
+        assert_eq!(
+            expand("{{unknown}} and {{id", &item(), None),
+            "{{unknown}} and {{id"
+        );
+    }
+
+    #[test]
+    fn test_prompts_and_answers() {
+        let text =
+            "## Steps\n{{prompt:Steps to reproduce}}\n\n## Expected\n{{ prompt: Expected }}\n\
+                    Again: {{prompt:Steps to reproduce}} {{prompt:}}";
+        let prompts = prompts(text);
+        assert_eq!(prompts, vec!["Steps to reproduce", "Expected"]);
+
+        let vars = vec![("steps_to_reproduce".to_string(), "Click login".to_string())];
+        let answers = match_vars(&prompts, &vars).unwrap();
+        assert_eq!(
+            fill_prompts(text, &answers),
+            "## Steps\nClick login\n\n## Expected\n{{ prompt: Expected }}\n\
+             Again: Click login {{prompt:}}"
+        );
+
+        let unknown = vec![("actual".to_string(), "x".to_string())];
+        let err = match_vars(&prompts, &unknown).unwrap_err().to_string();
+        assert!(err.contains("Steps to reproduce, Expected"), "{err}");
+    }
+
+    #[test]
+    fn test_parse_var() {
+        assert_eq!(
+            parse_var("Steps to reproduce=a=b").unwrap(),
+            ("Steps to reproduce".to_string(), "a=b".to_string())
+        );
+        assert_eq!(parse_var("empty=").unwrap().1, "");
+        assert!(parse_var("novalue").is_err());
+        assert!(parse_var("=x").is_err());
+    }
+}
@@ -15,12 +15,19 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+pub mod bench;
+pub mod clipboard;
 pub mod commands;
 pub mod config;
 pub mod constants;
 pub mod editor;
+pub mod help;
 pub mod id;
+pub mod insights;
 pub mod item;
+pub mod permalink;
+pub mod picker;
+pub mod policy;
 pub mod storage;
 pub mod tui;
 pub mod ui;
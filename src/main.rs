@@ -6,16 +6,33 @@
 //! Licensed under the MIT License.
 
 use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
 use clap::builder::{styling::AnsiColor, Styles};
-use clap::{ArgGroup, Parser, Subcommand};
+use clap::{parser::ValueSource, ArgGroup, FromArgMatches, Parser, Subcommand};
 use owo_colors::OwoColorize;
 
 use clap::CommandFactory;
 use clap_complete::Shell;
+use queuestack::clipboard::CopyWhat;
 use queuestack::commands::{
-    self, AttachAddArgs, AttachRemoveArgs, InteractiveArgs, ListMode, ListOptions, NewArgs,
-    SearchArgs, SortBy, StatusFilter, UpdateArgs,
+    self, ActivityArgs, AgentArgs, AttachAddArgs, AttachDuplicatesArgs, AttachOpenArgs,
+    AttachRemoveArgs, BenchArgs, BulkApplyArgs, BulkExportArgs, CleanArgs, CommentArgs,
+    CommentsArgs, CommitTemplateArgs, CompleteArgs, CompleteKind, CopyArgs, DaemonArgs, DeleteArgs,
+    DiffArgs, DraftApplyArgs, DraftArgs, DraftDiscardArgs, DuArgs, ExportArgs, ExportFormat,
+    GraphArgs, GraphFormat, HelpArgs, HooksInstallArgs, InitArgs, InsightsArgs, InteractiveArgs,
+    LinkArgs, ListMode, ListOptions, MergeDriverArgs, MirrorFetchArgs, NewArgs, OutputFormat,
+    PatchArgs, PopArgs, PopOrder, ResolveArgs, RulesApplyArgs, RunArgs, Scope, SearchArgs,
+    Selection, SetupShell, ShowArgs, SortBy, StatsArgs, StatsFormat, StatusFilter, SyncGithubArgs,
+    TrashRestoreArgs, TriageArgs, UpdateArgs, UrlArgs, ValidateArgs,
 };
+use queuestack::config::defaults;
+use queuestack::item::group::GroupBy;
+
+/// Long help of the due date options, listing every accepted value
+const DUE_LONG_HELP: &str =
+    "Accepts an ISO date (2026-02-01, also 2026/02/01 or 20260201), today, \
+tomorrow, a weekday (fri, next monday: the next one after today), next week, next month, or a \
+time from now (3d, 2w, in 1 month, +1y). Relative values are resolved against the local date.";
 
 const STYLES: Styles = Styles::styled()
     .header(AnsiColor::Yellow.on_default().bold())
@@ -51,7 +68,7 @@ macro_rules! global_help {
         concat!(
             h!("Configuration Files:"),
             "\n  ",
-            "~/.config/queuestack/config  Global configuration (user name, editor, ID pattern)\n  ",
+            "~/.config/queuestack/config  Global configuration (user name, editor, ID pattern, profiles)\n  ",
             ".queuestack                  Project configuration (queuestack directory, archive directory)\n\n",
             h!("ID Pattern Tokens:"),
             "\n  ",
@@ -76,7 +93,10 @@ macro_rules! global_help {
             c!("qs "),
             a!("<COMMAND>"),
             c!(" --help"),
-            "            Show detailed help for a command"
+            "            Show detailed help for a command\n  ",
+            c!("qs help "),
+            a!("<TOPIC>"),
+            "                Guides: ids, config, workflow, agents"
         )
     };
 }
@@ -95,7 +115,35 @@ grep-friendly, and easy to integrate into any workflow.
 Each item gets a unique ID, a slugified filename, and can be organized into categories."
 )]
 #[command(after_help = global_help!())]
+#[command(disable_help_subcommand = true)]
 struct Cli {
+    /// Global config profile to use (overrides `QS_PROFILE` and the configured profile)
+    #[arg(
+        long,
+        global = true,
+        value_name = "NAME",
+        help = "Use a profile from the global config (overrides QS_PROFILE)"
+    )]
+    profile: Option<String>,
+
+    /// Project root to use instead of searching upward for .queuestack
+    #[arg(
+        long,
+        global = true,
+        value_name = "DIR",
+        help = "Use the project in DIR instead of searching for .queuestack"
+    )]
+    project_root: Option<std::path::PathBuf>,
+
+    /// Selector for picking items (overrides the configured `picker`)
+    #[arg(
+        long,
+        global = true,
+        value_name = "PICKER",
+        help = "Pick items with builtin, fzf, or a picker command (overrides the picker option)"
+    )]
+    picker: Option<String>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -110,41 +158,77 @@ The configuration file contains all available options with detailed comments.\n\
 Directory structure created:\n  \
 .queuestack              Project configuration file\n  \
 queuestack/              Directory for items\n  \
-queuestack/.archive/     Archive directory for closed items",
+queuestack/.archive/     Archive directory for closed items\n\n\
+Git setup (also works in an initialized project):\n  \
+--gitattributes   Route item files through the queuestack merge driver\n  \
+--gitignore       Ignore the deduplicated attachment store, lock file, and temporary files\n  \
+--lfs [PATTERNS]  Store matching attachments with Git LFS (default: images, PDFs, \
+archives, videos)",
         after_help = concat!(
             h!("Examples:"), "\n  ",
             c!("qs init"), "                         Initialize in current directory\n  ",
-            c!("cd myproject && qs init"), "         Initialize in a specific project\n\n",
+            c!("cd myproject && qs init"), "         Initialize in a specific project\n  ",
+            c!("qs init --gitattributes"), "         Add the item merge attribute\n  ",
+            c!("qs init --lfs "), a!("\"*.psd\""), "           Store PSD attachments with Git LFS\n\n",
             h!("Note:"), " Run this command once per project, typically at the repository root."
         )
     )]
-    Init,
+    Init {
+        /// Add a merge driver attribute for item files to .gitattributes
+        #[arg(long, help = "Add a merge attribute for item files to .gitattributes")]
+        gitattributes: bool,
+
+        /// Add ignores for files qs keeps out of version control to .gitignore
+        #[arg(long, help = "Ignore the attachment store and temporary files")]
+        gitignore: bool,
+
+        /// Store attachments with Git LFS
+        #[arg(
+            long,
+            num_args = 0..,
+            value_name = "PATTERN",
+            help = "Store attachments matching these patterns with Git LFS"
+        )]
+        lfs: Option<Vec<String>>,
+    },
 
     /// Create a new item
     #[command(
         long_about = "Create a new item with the given title.\n\n\
 Generates a unique ID, creates a Markdown file with YAML frontmatter, and opens \
-your editor based on the 'interactive' config setting (default: true). Use -i to \
-force the editor to open, or --no-interactive to skip it.\n\n\
+your editor based on the 'interactive' config setting (default: true). Use \
+--no-interactive to skip it.\n\n\
+Without a title, or with -i in a terminal, the new item wizard opens instead, \
+pre-filled with the given title, labels, category, and attachments.\n\n\
 The filename is derived from the ID and a slugified title (e.g., '260109-0A2B3C4-fix-login-bug.md').\n\n\
 The author is determined from (in order):\n  \
 1. user_name in ~/.config/queuestack/config\n  \
-2. git config user.name (if use_git_user is true)\n  \
+2. git config user.name, or the jj/hg user name (if use_git_user is true)\n  \
 3. Interactive prompt (saved to ~/.config/queuestack/config for future use)\n\n\
 Templates:\n  \
 --as-template     Create a template instead of an item\n  \
---from-template   Create an item from an existing template",
+--from-template   Create an item from an existing template\n\n\
+Template bodies may contain variables, expanded when the item is created:\n  \
+{{id}} {{title}} {{author}} {{date}} {{category}} {{labels}}, or any custom \
+frontmatter field such as {{priority}}. Custom fields are copied from the template.\n\n\
+{{prompt:Steps to reproduce}} asks for a value when the item is created. Pass answers \
+with --var (the key matches the prompt ignoring case and punctuation); missing answers \
+are asked for in a terminal and are an error with --no-interactive.",
         after_help = concat!(
             h!("Examples:"), "\n  ",
             c!("qs new "), a!("\"Fix login bug\""), "\n  ",
             c!("qs new "), a!("\"Add dark mode\""), c!(" --label "), a!("feature ui"), "\n  ",
             c!("qs new "), a!("\"Memory leak\""), c!(" --label "), a!("bug urgent"), c!(" --category "), a!("bugs"), "\n  ",
             c!("qs new "), a!("\"Bug report\""), c!(" --attachment "), a!("screenshot.png debug.log"), "\n  ",
-            c!("qs new "), a!("\"Quick note\""), c!(" --no-interactive"), "       Skip editor\n\n",
+            c!("qs new "), a!("\"Release 2.0\""), c!(" --due "), a!("2026-02-01"), "\n  ",
+            c!("qs new "), a!("\"Fix login\""), c!(" --assignee "), a!("\"Alice\""), "\n  ",
+            c!("qs new "), a!("\"Quick note\""), c!(" --no-interactive"), "       Skip editor\n  ",
+            c!("qs new -i "), a!("\"Crash\""), c!(" --label "), a!("bug"), "          Review in the wizard\n\n",
             h!("Templates:"), "\n  ",
             c!("qs new --as-template "), a!("\"Bug Report\""), "      Create a template\n  ",
             c!("qs new --from-template "), a!("bug-report"), " ", a!("\"Fix login\""), "  From template\n  ",
-            c!("qs new --from-template"), "                       Select template interactively\n\n",
+            c!("qs new --from-template"), "                       Select template interactively\n  ",
+            c!("qs new --from-template "), a!("bug-report"), " ", a!("\"Crash\""), c!(" --var "), a!("steps=\"Open app\""), "\n\n",
             h!("Output:"), " Prints the relative path to the created file."
         )
     )]
@@ -160,6 +244,20 @@ Templates:\n  \
         #[arg(short, long, help = "Category subdirectory for the item")]
         category: Option<String>,
 
+        /// Due date
+        #[arg(
+            long,
+            value_name = "DATE",
+            value_parser = queuestack::item::dates::parse_due_from_today,
+            help = "Due date: YYYY-MM-DD, today, tomorrow, a weekday, next week, or a time from now like 3d",
+            long_help = DUE_LONG_HELP
+        )]
+        due: Option<NaiveDate>,
+
+        /// Person responsible for the item
+        #[arg(long, value_name = "NAME", help = "Assign the item to a person")]
+        assignee: Option<String>,
+
         /// Files or URLs to attach (multiple values allowed)
         #[arg(short, long, num_args = 1.., help = "Files or URLs to attach")]
         attachment: Vec<String>,
@@ -194,6 +292,53 @@ Templates:\n  \
         )]
         #[allow(clippy::option_option)]
         from_template: Option<Option<String>>,
+
+        /// Answer a template prompt (repeatable)
+        #[arg(
+            long = "var",
+            value_name = "KEY=VALUE",
+            requires = "from_template",
+            value_parser = queuestack::item::variables::parse_var,
+            help = "Answer a template prompt, e.g. --var \"steps=Click login\" (repeatable)"
+        )]
+        vars: Vec<(String, String)>,
+    },
+
+    /// Create a new item with terse syntax (`qs push "title" +label @category`)
+    #[command(
+        long_about = "Create a new item with terse positional syntax.\n\n\
+Works like 'qs new', with labels and the category given inline: words starting with '+' \
+are labels, a word starting with '@' is the category, and all other words form the title. \
+Quoting the title is optional. Start a word with a backslash to keep it literal (\\@home).",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs push "), a!("\"Fix login crash\" +bug +urgent @bugs"), "\n  ",
+            c!("qs push "), a!("Add dark mode +ui"), "\n  ",
+            c!("qs push "), a!("\"Quick note\""), c!(" --no-interactive"), "       Skip editor\n\n",
+            h!("Output:"), " Prints the relative path to the created file."
+        )
+    )]
+    Push {
+        /// Title words, +labels, and @category
+        #[arg(
+            required = true,
+            value_name = "WORDS",
+            help = "Title words, +labels, and an @category"
+        )]
+        words: Vec<String>,
+
+        /// Force interactive mode (open editor)
+        #[arg(
+            short = 'i',
+            long,
+            conflicts_with = "no_interactive",
+            help = "Force editor to open"
+        )]
+        interactive: bool,
+
+        /// Force non-interactive mode (don't open editor)
+        #[arg(long, help = "Skip opening editor")]
+        no_interactive: bool,
     },
 
     /// List items, labels, categories, attachments, or metadata
@@ -201,8 +346,20 @@ Templates:\n  \
         long_about = "List items in the current project.\n\n\
 Shows all open items. Based on the 'interactive' config setting (default: true), \
 presents a selector to choose an item to open. Use -i to force interactive \
-selection, or --no-interactive to just print the list.\n\n\
-Use filters to narrow down results.\n\n\
+selection, or --no-interactive to just print the list. --tree prints the items \
+as a tree of categories and subdirectories instead. --group-by prints sections \
+with item counts, or collapsible groups in the selector. --summary appends a line with \
+totals by status and priority. --verbose prints a summary card per item (including its \
+word count and reading time) instead of its path. --format json prints the items as a \
+JSON array (id, title, author, created_at, status, closed_at, labels, category, path, and \
+attachments), and with --meta the item as a JSON object.\n\n\
+--print-selection shows the selector on the terminal even when stdout is captured, \
+and prints the chosen item's path (or ID with --print-selection id) instead of opening \
+it: ITEM=$(qs list --print-selection). Cancelling exits with an error.\n\n\
+--scope picks what is listed: open items (default), the archive of closed items, \
+templates, or all of them.\n\n\
+Use filters to narrow down results. --label requires every given label, --any-label \
+at least one of them, and --not-label excludes items with any of them.\n\n\
 Special modes:\n  \
 --labels        List unique labels across all items\n  \
 --categories    List unique categories across all items\n  \
@@ -213,10 +370,26 @@ Special modes:\n  \
             h!("Examples:"), "\n  ",
             c!("qs list"), "                            List items, select one to open\n  ",
             c!("qs list --no-interactive"), "           Just print the list\n  ",
-            c!("qs list --closed"), "                   List archived/closed items\n  ",
+            c!("qs list --tree"), "                     Print items as a category tree\n  ",
+            c!("qs list --scope "), a!("archive"), "            List archived/closed items\n  ",
             c!("qs list --label "), a!("bug"), "                Filter by label\n  ",
+            c!("qs list --label "), a!("bug"), c!(" --label "), a!("urgent"), "     Items labeled bug AND urgent\n  ",
+            c!("qs list --any-label "), a!("bug ui"), "         Items labeled bug OR ui\n  ",
+            c!("qs list --label "), a!("bug"), c!(" --not-label "), a!("wontfix"), " Exclude wontfix items\n  ",
+            c!("qs list --since "), a!("7d"), "                 Items created in the last 7 days\n  ",
+            c!("qs list --scope "), a!("archive"), c!(" --since "), a!("2w"), "  Items closed in the last 2 weeks\n  ",
+            c!("qs list --due-before "), a!("fri"), "           Items due by Friday\n  ",
+            c!("qs list --overdue"), "                  Items past their due date\n  ",
+            c!("qs list --unblocked"), "                Items not waiting on other items\n  ",
             c!("qs list --author "), a!("\"John\""), "            Filter by author\n  ",
+            c!("qs list --assignee "), a!("\"Alice\""), "         Filter by assignee\n  ",
+            c!("qs list --mine"), "                     Items assigned to you\n  ",
             c!("qs list --sort "), a!("date"), "                Sort by creation date\n  ",
+            c!("qs list --group-by "), a!("status"), "          Group items by status\n  ",
+            c!("qs list --summary"), "                  Add totals by status and priority\n  ",
+            c!("qs list --verbose"), "                  List items as summary cards\n  ",
+            c!("qs list --format "), a!("json"), "             Print items as JSON\n  ",
+            c!("ITEM=$(qs list --print-selection)"), "  Pick an item from a script\n  ",
             c!("qs list --labels"), "                   List all unique labels\n  ",
             c!("qs list --categories"), "               List all unique categories\n  ",
             c!("qs list --attachments --id "), a!("260109"), "      List attachments for item\n  ",
@@ -226,34 +399,127 @@ Special modes:\n  \
         )
     )]
     List {
-        /// Show only open items
+        /// Items to list: open, archive, templates, or all
         #[arg(
             long,
-            conflicts_with = "closed",
-            help = "Show only open items (default if no status filter)"
+            value_enum,
+            default_value_t = Scope::Open,
+            help = "What to list: open items, the archive, templates, or all"
         )]
+        scope: Scope,
+
+        /// Same as --scope open
+        #[arg(long, hide = true, conflicts_with_all = ["closed", "scope"])]
         open: bool,
 
-        /// Show only closed items
-        #[arg(
-            long,
-            conflicts_with = "open",
-            help = "Show only closed/archived items"
-        )]
+        /// Same as --scope archive
+        #[arg(long, hide = true, conflicts_with_all = ["open", "scope"])]
         closed: bool,
 
         /// Filter by label (can be specified multiple times for AND logic)
-        #[arg(long, num_args = 1.., help = "Filter items by label(s)")]
+        #[arg(
+            long,
+            num_args = 1..,
+            help = "Only items with all of these labels (repeatable)"
+        )]
         label: Vec<String>,
 
+        /// Filter by any of several labels (OR logic)
+        #[arg(
+            long,
+            num_args = 1..,
+            help = "Only items with at least one of these labels (repeatable)"
+        )]
+        any_label: Vec<String>,
+
+        /// Exclude labels
+        #[arg(
+            long,
+            num_args = 1..,
+            help = "Exclude items with any of these labels (repeatable)"
+        )]
+        not_label: Vec<String>,
+
         /// Filter by author
         #[arg(long, help = "Filter items by author name (substring match)")]
         author: Option<String>,
 
+        /// Filter by assignee
+        #[arg(
+            long,
+            value_name = "NAME",
+            conflicts_with = "mine",
+            help = "Filter items by assignee"
+        )]
+        assignee: Option<String>,
+
+        /// Only items assigned to the current user
+        #[arg(
+            long,
+            conflicts_with = "assignee",
+            help = "Only items assigned to you (your user_name)"
+        )]
+        mine: bool,
+
         /// Filter by category
         #[arg(long = "category", help = "Filter items by category")]
         filter_category: Option<String>,
 
+        /// Only items created (closed, with --scope archive) on or after this date
+        #[arg(
+            long,
+            value_parser = queuestack::item::dates::parse_since,
+            help = "Only items created (closed, with --scope archive) on or after a date: YYYY-MM-DD, today, 7d, 2w, 3m, 1y"
+        )]
+        since: Option<DateTime<Utc>>,
+
+        /// Only items created (closed, with --scope archive) on or before this date
+        #[arg(
+            long,
+            value_parser = queuestack::item::dates::parse_until,
+            help = "Only items created (closed, with --scope archive) on or before a date (inclusive)"
+        )]
+        until: Option<DateTime<Utc>>,
+
+        /// Only items due on or after this date
+        #[arg(
+            long,
+            value_name = "DATE",
+            value_parser = queuestack::item::dates::parse_due_from_today,
+            help = "Only items due on or after a date: YYYY-MM-DD, today, fri, 2w",
+            long_help = DUE_LONG_HELP
+        )]
+        due_after: Option<NaiveDate>,
+
+        /// Only items due on or before this date
+        #[arg(
+            long,
+            value_name = "DATE",
+            value_parser = queuestack::item::dates::parse_due_from_today,
+            help = "Only items due on or before a date (inclusive)",
+            long_help = DUE_LONG_HELP
+        )]
+        due_before: Option<NaiveDate>,
+
+        /// Only items past their due date
+        #[arg(long, help = "Only open or in-progress items past their due date")]
+        overdue: bool,
+
+        /// Only items waiting on open blockers
+        #[arg(
+            long,
+            conflicts_with = "unblocked",
+            help = "Only items that depend on an open or in-progress item"
+        )]
+        blocked: bool,
+
+        /// Only items without open blockers
+        #[arg(
+            long,
+            help = "Only items with no open or in-progress blockers (actionable work)"
+        )]
+        unblocked: bool,
+
         /// Sort order
         #[arg(
             long,
@@ -263,6 +529,32 @@ Special modes:\n  \
         )]
         sort: SortBy,
 
+        /// Group items into sections
+        #[arg(
+            long,
+            value_enum,
+            conflicts_with_all = ["tree", "labels", "categories", "attachments", "meta", "templates"],
+            help = "Group items by label, category, assignee, status, or milestone"
+        )]
+        group_by: Option<GroupBy>,
+
+        /// Append a summary footer
+        #[arg(
+            long,
+            conflicts_with_all = ["interactive", "labels", "categories", "attachments", "meta", "templates"],
+            help = "Append a footer with totals by status and priority (implies --no-interactive)"
+        )]
+        summary: bool,
+
+        /// Print summary cards instead of paths
+        #[arg(
+            short,
+            long,
+            conflicts_with_all = ["interactive", "tree", "group_by", "labels", "categories", "attachments", "meta", "templates"],
+            help = "Print a summary card per item instead of its path (implies --no-interactive)"
+        )]
+        verbose: bool,
+
         /// Force interactive mode (show selector)
         #[arg(
             short = 'i',
@@ -276,6 +568,26 @@ Special modes:\n  \
         #[arg(long, help = "Just print the list")]
         no_interactive: bool,
 
+        /// Pick an item and print it, for scripts
+        #[arg(
+            long,
+            value_enum,
+            value_name = "WHAT",
+            num_args = 0..=1,
+            default_missing_value = "path",
+            conflicts_with_all = ["no_interactive", "summary", "verbose", "tree", "group_by", "labels", "categories", "attachments", "meta", "templates"],
+            help = "Pick an item on the terminal and print its path (or id) instead of opening it"
+        )]
+        print_selection: Option<Selection>,
+
+        /// Print items as a directory tree
+        #[arg(
+            long,
+            conflicts_with_all = ["interactive", "labels", "categories", "attachments", "meta", "templates"],
+            help = "Print items as a tree of categories and subdirectories"
+        )]
+        tree: bool,
+
         /// List all unique labels
         #[arg(
             long,
@@ -318,6 +630,20 @@ Special modes:\n  \
         )]
         templates: bool,
 
+        /// Leave out items of read-only mirrors
+        #[arg(long, help = "Don't list items of the configured mirrors")]
+        no_mirrors: bool,
+
+        /// Output format
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = OutputFormat::Text,
+            conflicts_with_all = ["interactive", "print_selection", "summary", "verbose", "tree", "group_by", "labels", "categories", "attachments", "templates"],
+            help = "Output format (json prints items or --meta as JSON)"
+        )]
+        format: OutputFormat,
+
         /// Item ID (partial match supported)
         #[arg(
             long,
@@ -340,21 +666,39 @@ Special modes:\n  \
     /// Search for items and interactively select one to open
     #[command(
         long_about = "Search for items by title or ID.\n\n\
-Performs a case-insensitive substring search against item titles and IDs. Based on \
+Performs a case-insensitive substring search against item titles and IDs. Results are \
+ranked by match quality (title, then ID, then body matches; newer items first within \
+each) and the matched text is highlighted in terminal output. Based on \
 the 'interactive' config setting (default: true), presents a selector for matches. \
 Use -i to force interactive selection, or --no-interactive to just list results.\n\n\
 Search behavior:\n  \
 - Single match: opens the item directly (in interactive mode)\n  \
 - Multiple matches: shows interactive selector or lists results\n  \
 - No matches: returns an error\n\n\
-Use --full-text to also search within the markdown body content.",
+Use --full-text to also search within the markdown body content, --verbose \
+to print a summary card per match instead of its path, and --format json to print \
+the matches as a JSON array, best first. Bodies longer than \
+max_body_words (default 5000) are left out of --full-text unless --include-large \
+is given.\n\n\
+--print-selection shows the selector on the terminal even when stdout is captured, \
+and prints the chosen match's path (or ID with --print-selection id) instead of \
+opening it. A single match is printed without asking.\n\n\
+--scope picks what is searched: open items (default), the archive of closed items, \
+templates, or all of them at once.\n\n\
+Narrow results by label: --label requires every given label, --any-label requires at \
+least one, and --not-label excludes items with that label. Each can be repeated.",
         after_help = concat!(
             h!("Examples:"), "\n  ",
             c!("qs search "), a!("\"login bug\""), "                 Search and select interactively\n  ",
             c!("qs search "), a!("\"260109-0A2B3C4\""), "            Search by ID\n  ",
             c!("qs search "), a!("\"auth\""), c!(" --full-text"), "          Include body content in search\n  ",
             c!("qs search "), a!("\"bug\""), c!(" --no-interactive"), "      Just list matching items\n  ",
-            c!("qs search "), a!("\"old task\""), c!(" --closed"), "         Search in archived items\n\n",
+            c!("qs search "), a!("\"bug\""), c!(" --verbose"), "             List matches as summary cards\n  ",
+            c!("qs search "), a!("\"bug\""), c!(" --format "), a!("json"), "         Print matches as JSON\n  ",
+            c!("qs search "), a!("\"bug\""), c!(" --print-selection "), a!("id"), " Print the chosen match's ID\n  ",
+            c!("qs search "), a!("\"old task\""), c!(" --scope "), a!("archive"), "  Search in archived items\n  ",
+            c!("qs search "), a!("\"deploy\""), c!(" --scope "), a!("all"), "        Search items, archive, and templates\n  ",
+            c!("qs search "), a!("\"crash\""), c!(" --label "), a!("bug"), c!(" --not-label "), a!("wontfix"), " Labeled matches only\n\n",
             h!("Interactive mode:"), " Use arrow keys to navigate, Enter to select, Esc to cancel."
         )
     )]
@@ -366,6 +710,14 @@ Use --full-text to also search within the markdown body content.",
         #[arg(long, help = "Include body content in search")]
         full_text: bool,
 
+        /// Also search the bodies of items over `max_body_words`
+        #[arg(
+            long,
+            requires = "full_text",
+            help = "With --full-text, also search bodies longer than max_body_words"
+        )]
+        include_large: bool,
+
         /// Force interactive mode (show selector)
         #[arg(
             short = 'i',
@@ -379,37 +731,93 @@ Use --full-text to also search within the markdown body content.",
         #[arg(long, help = "Just list matching items")]
         no_interactive: bool,
 
-        /// Search in closed/archived items
-        #[arg(long, help = "Search in closed/archived items instead of open")]
+        /// Pick a match and print it, for scripts
+        #[arg(
+            long,
+            value_enum,
+            value_name = "WHAT",
+            num_args = 0..=1,
+            default_missing_value = "path",
+            conflicts_with_all = ["no_interactive", "verbose"],
+            help = "Pick a match on the terminal and print its path (or id) instead of opening it"
+        )]
+        print_selection: Option<Selection>,
+
+        /// Items to search: open, archive, templates, or all
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = Scope::Open,
+            help = "What to search: open items, the archive, templates, or all"
+        )]
+        scope: Scope,
+
+        /// Same as --scope archive
+        #[arg(long, hide = true, conflicts_with = "scope")]
         closed: bool,
+
+        /// Require a label (repeat for AND logic)
+        #[arg(long, help = "Only items with this label (repeatable; all must match)")]
+        label: Vec<String>,
+
+        /// Require at least one of these labels
+        #[arg(long, help = "Only items with any of these labels (repeatable)")]
+        any_label: Vec<String>,
+
+        /// Exclude a label
+        #[arg(long, help = "Exclude items with this label (repeatable)")]
+        not_label: Vec<String>,
+
+        /// Print summary cards instead of paths
+        #[arg(
+            short,
+            long,
+            conflicts_with = "interactive",
+            help = "Print a summary card per match instead of its path"
+        )]
+        verbose: bool,
+
+        /// Leave out items of read-only mirrors
+        #[arg(long, help = "Don't search items of the configured mirrors")]
+        no_mirrors: bool,
+
+        /// Output format
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = OutputFormat::Text,
+            conflicts_with_all = ["interactive", "print_selection", "verbose"],
+            help = "Output format"
+        )]
+        format: OutputFormat,
     },
 
-    /// Update an existing item
+    /// Show an item's summary card and body
     #[command(
-        long_about = "Update an existing item's metadata.\n\n\
-Modify the title, labels, or category of an item. If the title changes, the file \
-is renamed to reflect the new slug. In Git repositories, uses 'git mv' to preserve \
-history.\n\n\
-Labels are additive - new labels are added without removing existing ones. \
-To modify labels directly, edit the Markdown file.",
+        long_about = "Show a single item.\n\n\
+Prints a summary card (ID, status, title, labels, category, assignee, age, and \
+attachment count) followed by the item body. With --brief, only the card is printed, \
+including the first paragraph of the body. --raw prints the item file exactly as it is \
+on disk, and --format json prints all frontmatter fields (custom fields under 'fields'), \
+the attachments, and the body.\n\n\
+The assignee is read from an 'assignee' custom frontmatter field. Output is plain \
+text when not writing to a terminal.",
         after_help = concat!(
             h!("Examples:"), "\n  ",
-            c!("qs update --id "), a!("260109-0A2B3C4"), c!(" --title "), a!("\"New title\""), "\n  ",
-            c!("qs update --id "), a!("2601"), c!(" --label "), a!("urgent p1"), "      Partial ID\n  ",
-            c!("qs update --id "), a!("260109-0A2B3C4"), c!(" --category "), a!("bugs"), "\n  ",
-            c!("qs update --id "), a!("260109-0A2B3C4"), c!(" --remove-category"), "  Move to queuestack root\n  ",
-            c!("qs update --id "), a!("26"), c!(" --title "), a!("\"Fix\""), c!(" --label "), a!("done"), "      Partial ID\n\n",
-            h!("Note:"), " The --id flag supports partial matching for convenience."
+            c!("qs show --id "), a!("260109-0A2B3C4"), "\n  ",
+            c!("qs show --id "), a!("2601"), c!(" --brief"), "           Compact card only\n  ",
+            c!("qs show --id "), a!("2601"), c!(" --raw"), "             File content\n  ",
+            c!("qs show --id "), a!("2601"), c!(" --format json"), "     All fields and the body"
         ),
         group = ArgGroup::new("item_ref").required(true)
     )]
-    Update {
+    Show {
         /// Item ID (partial match supported)
         #[arg(
             long,
             conflicts_with = "file",
             group = "item_ref",
-            help = "Item ID to update (partial match supported)"
+            help = "Item ID to show (partial match supported)"
         )]
         id: Option<String>,
 
@@ -422,57 +830,51 @@ To modify labels directly, edit the Markdown file.",
         )]
         file: Option<std::path::PathBuf>,
 
-        /// New title
-        #[arg(long, help = "New title (renames file if changed)")]
-        title: Option<String>,
-
-        /// Add labels
-        #[arg(long, num_args = 1.., help = "Add label(s) to item")]
-        label: Vec<String>,
-
-        /// Remove labels
-        #[arg(long, num_args = 1.., help = "Remove label(s) from item")]
-        remove_label: Vec<String>,
+        /// Print only the summary card
+        #[arg(long, help = "Print only the compact summary card")]
+        brief: bool,
 
-        /// Move to category
+        /// Print the file unchanged
         #[arg(
             long,
-            conflicts_with = "remove_category",
-            help = "Move item to category subdirectory"
+            conflicts_with_all = ["brief", "format"],
+            help = "Print the item file exactly as it is on disk"
         )]
-        category: Option<String>,
+        raw: bool,
 
-        /// Remove from category (move to root)
+        /// Output format
         #[arg(
             long,
-            conflicts_with = "category",
-            help = "Remove from category (move to queuestack root)"
+            value_enum,
+            default_value_t = OutputFormat::Text,
+            conflicts_with = "brief",
+            help = "Output format"
         )]
-        remove_category: bool,
+        format: OutputFormat,
     },
 
-    /// Close an item (move to archive)
+    /// Print a shareable link to an item
     #[command(
-        long_about = "Close an item by moving it to the archive directory.\n\n\
-Sets the item's status to 'closed' and moves it from the queuestack directory to the \
-archive subdirectory. In Git repositories, uses 'git mv' to preserve history.\n\n\
-Closed items are excluded from 'qs list' by default (use --closed to see them).",
+        long_about = "Print a shareable link to an item.\n\n\
+The link comes from the 'link_base' config template, where {path} is replaced by the \
+item file (relative to the project root) and {id} by the item ID. Without it, the link \
+points to the file on the web page of the 'origin' git remote (GitHub, GitLab, \
+Bitbucket, and similar hosts), or is qs://<ID> when there is no remote.\n\n\
+Paste it anywhere; 'qs resolve <url>' finds the item again.",
         after_help = concat!(
             h!("Examples:"), "\n  ",
-            c!("qs close --id "), a!("260109-0A2B3C4"), "          Close by full ID\n  ",
-            c!("qs close --id "), a!("2601"), "                    Close by partial ID\n  ",
-            c!("qs list --closed"), "                      View closed items\n  ",
-            c!("qs reopen --id "), a!("260109-0A2B3C4"), "         Reopen if needed"
+            c!("qs url --id "), a!("2601"), "                   Print the item's link\n  ",
+            c!("qs resolve "), a!("\"$(qs url --id 2601)\""), "   Jump back to it"
         ),
         group = ArgGroup::new("item_ref").required(true)
     )]
-    Close {
+    Url {
         /// Item ID (partial match supported)
         #[arg(
             long,
             conflicts_with = "file",
             group = "item_ref",
-            help = "Item ID to close (partial match supported)"
+            help = "Item ID to link (partial match supported)"
         )]
         id: Option<String>,
 
@@ -486,27 +888,27 @@ Closed items are excluded from 'qs list' by default (use --closed to see them)."
         file: Option<std::path::PathBuf>,
     },
 
-    /// Reopen a closed item (move from archive)
+    /// Copy an item's ID, path, or link to the clipboard
     #[command(
-        long_about = "Reopen a closed item by moving it back from the archive.\n\n\
-Sets the item's status to 'open' and moves it from the archive directory back \
-to queuestack (or its original category). In Git repositories, uses 'git mv' to \
-preserve history.",
+        long_about = "Copy an item's ID, path, or link to the system clipboard.\n\n\
+--what picks the text: the ID (default), the item file path, the link printed by \
+'qs url', or a Markdown link titled with the item's title. Uses pbcopy on macOS, \
+clip on Windows, and wl-copy, xclip, or xsel elsewhere; without them, terminals \
+supporting OSC 52 set the clipboard.",
         after_help = concat!(
             h!("Examples:"), "\n  ",
-            c!("qs reopen --id "), a!("260109-0A2B3C4"), "         Reopen by full ID\n  ",
-            c!("qs reopen --id "), a!("2601"), "                   Reopen by partial ID\n  ",
-            c!("qs list"), "                               Verify item is back in open list"
+            c!("qs copy --id "), a!("2601"), "                      Copy the item's ID\n  ",
+            c!("qs copy --id "), a!("2601"), c!(" --what "), a!("markdown-link"), "  Copy a Markdown link"
         ),
         group = ArgGroup::new("item_ref").required(true)
     )]
-    Reopen {
+    Copy {
         /// Item ID (partial match supported)
         #[arg(
             long,
             conflicts_with = "file",
             group = "item_ref",
-            help = "Item ID to reopen (partial match supported)"
+            help = "Item ID to copy (partial match supported)"
         )]
         id: Option<String>,
 
@@ -518,57 +920,1623 @@ preserve history.",
             help = "Item file path"
         )]
         file: Option<std::path::PathBuf>,
+
+        /// What to copy
+        #[arg(long, value_enum, default_value = "id", help = "What to copy")]
+        what: CopyWhat,
     },
 
-    /// Manage item attachments (add, remove)
+    /// Find the item a link points to
     #[command(
-        long_about = "Manage attachments for items.\n\n\
-Attachments can be files (copied to a sibling .attachments directory) or URLs (stored as references). \
-File attachments are renamed to follow the pattern: {N}-{name}.{ext}\n\n\
-To list attachments for an item, use: qs list --attachments --id <ID>",
+        long_about = "Find the item a link from 'qs url' points to.\n\n\
+Prints the item's path, and opens it in your editor based on the 'interactive' \
+config setting (default: true). The link doesn't have to match the current \
+'link_base': any path component naming an item file or ID selects the item, so \
+links made before an item was renamed or closed still resolve.",
         after_help = concat!(
             h!("Examples:"), "\n  ",
-            c!("qs attachments add --id "), a!("260109-0A2B3C4"), " ", a!("screenshot.png"), "\n  ",
-            c!("qs attachments add --id "), a!("260109-0A2B3C4"), " ", a!("https://github.com/issue/42"), "\n  ",
-            c!("qs attachments remove --id "), a!("260109-0A2B3C4"), " ", a!("1"), " ", a!("2"), "\n\n",
-            h!("See also:"), " ", c!("qs list --attachments --id "), a!("<ID>"), " to list attachments"
+            c!("qs resolve "), a!("https://github.com/org/repo/blob/main/queuestack/260109-0A2B3C4-fix-login.md"), "\n  ",
+            c!("qs resolve "), a!("qs://260109-0A2B3C4"), c!(" --no-interactive"), "   Just print the path"
         )
     )]
-    Attachments {
-        #[command(subcommand)]
-        action: AttachmentsAction,
+    Resolve {
+        /// Link to an item
+        #[arg(help = "Link printed by qs url (or any URL naming an item file or ID)")]
+        url: String,
+
+        /// Force interactive mode (open editor)
+        #[arg(
+            short = 'i',
+            long,
+            conflicts_with = "no_interactive",
+            help = "Force opening the item in the editor"
+        )]
+        interactive: bool,
+
+        /// Force non-interactive mode (just print the path)
+        #[arg(long, help = "Just print the item's path")]
+        no_interactive: bool,
     },
 
-    /// One-time setup: create global config and install shell completions
+    /// Run one of an item's actions
     #[command(
-        long_about = "One-time setup for queuestack.\n\n\
-This command helps you get started with queuestack by:\n  \
-1. Creating the global configuration file (~/.config/queuestack/config) if it doesn't exist\n  \
-2. Installing tab completions for your shell\n\n\
-Shell detection tries (in order):\n  \
-1. --shell flag if provided\n  \
-2. Shell-specific env vars (FISH_VERSION, ZSH_VERSION, BASH_VERSION)\n  \
-3. $SHELL environment variable (login shell)\n\n\
-Run this once after installing queuestack to enable tab completion for commands and arguments.\n\n\
-The setup is idempotent - running it multiple times is safe and will just overwrite \
-the completion script with the latest version.",
+        long_about = "Run one of an item's actions.\n\n\
+Items can declare shell commands under 'actions' in their frontmatter, e.g. a flaky-test \
+item carrying its own reproduce command:\n\n\
+  actions:\n    reproduce: cargo test --test sync -- --nocapture\n\n\
+In a command, {path} expands to the item file and {id} to the item ID (both shell-quoted). \
+Actions run through the shell from the project root, with QS_ID and QS_FILE set. Without \
+an action name, the item's actions are listed.",
         after_help = concat!(
             h!("Examples:"), "\n  ",
-            c!("qs setup"), "                    Run one-time setup (auto-detect shell)\n  ",
-            c!("qs setup --shell fish"), "       Explicitly specify fish shell\n\n",
-            h!("Supported shells:"), " zsh, bash, fish, elvish, powershell\n\n",
-            h!("Note:"), " If shell detection fails, use ", c!("--shell"), " to specify explicitly."
-        )
+            c!("qs run --id "), a!("2601"), c!(" reproduce"), "           Run the 'reproduce' action\n  ",
+            c!("qs run --id "), a!("2601"), "                     List the item's actions\n\n",
+            h!("Note:"), " Actions are also offered in the interactive list's action menu."
+        ),
+        group = ArgGroup::new("item_ref").required(true)
     )]
-    Setup {
-        /// Shell to install completions for (overrides auto-detection)
-        #[arg(long, value_enum, help = "Shell to install completions for")]
-        shell: Option<Shell>,
-    },
+    Run {
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            group = "item_ref",
+            help = "Item ID (partial match supported)"
+        )]
+        id: Option<String>,
 
-    /// Generate shell completion scripts
-    #[command(
-        long_about = "Generate shell completion scripts for various shells.\n\n\
+        /// Item file path (alternative to --id)
+        #[arg(
+            long,
+            conflicts_with = "id",
+            group = "item_ref",
+            help = "Item file path"
+        )]
+        file: Option<std::path::PathBuf>,
+
+        /// Action to run
+        #[arg(help = "Action to run (omit to list the item's actions)")]
+        action: Option<String>,
+    },
+
+    /// Update an existing item
+    #[command(
+        long_about = "Update an existing item's metadata.\n\n\
+Modify the title, labels, category, or due date of an item. If the title changes, the file \
+is renamed to reflect the new slug. In Git repositories, uses 'git mv' to preserve \
+history.\n\n\
+Labels are additive - new labels are added without removing existing ones. \
+To modify labels directly, edit the Markdown file.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs update --id "), a!("260109-0A2B3C4"), c!(" --title "), a!("\"New title\""), "\n  ",
+            c!("qs update --id "), a!("2601"), c!(" --label "), a!("urgent p1"), "      Partial ID\n  ",
+            c!("qs update --id "), a!("260109-0A2B3C4"), c!(" --category "), a!("bugs"), "\n  ",
+            c!("qs update --id "), a!("260109-0A2B3C4"), c!(" --remove-category"), "  Move to queuestack root\n  ",
+            c!("qs update --id "), a!("2601"), c!(" --due "), a!("next week"), "\n  ",
+            c!("qs update --id "), a!("2601"), c!(" --category "), a!("bugs"), c!(" --dry-run --format json"), "\n  ",
+            c!("qs update --id "), a!("26"), c!(" --title "), a!("\"Fix\""), c!(" --label "), a!("done"), "      Partial ID\n\n",
+            h!("Note:"), " The --id flag supports partial matching for convenience."
+        ),
+        group = ArgGroup::new("item_ref").required(true)
+    )]
+    Update {
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            group = "item_ref",
+            help = "Item ID to update (partial match supported)"
+        )]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(
+            long,
+            conflicts_with = "id",
+            group = "item_ref",
+            help = "Item file path"
+        )]
+        file: Option<std::path::PathBuf>,
+
+        /// New title
+        #[arg(long, help = "New title (renames file if changed)")]
+        title: Option<String>,
+
+        /// Add labels
+        #[arg(long, num_args = 1.., help = "Add label(s) to item")]
+        label: Vec<String>,
+
+        /// Remove labels
+        #[arg(long, num_args = 1.., help = "Remove label(s) from item")]
+        remove_label: Vec<String>,
+
+        /// Move to category
+        #[arg(
+            long,
+            conflicts_with = "remove_category",
+            help = "Move item to category subdirectory"
+        )]
+        category: Option<String>,
+
+        /// Remove from category (move to root)
+        #[arg(
+            long,
+            conflicts_with = "category",
+            help = "Remove from category (move to queuestack root)"
+        )]
+        remove_category: bool,
+
+        /// Set the due date
+        #[arg(
+            long,
+            value_name = "DATE",
+            conflicts_with = "remove_due",
+            value_parser = queuestack::item::dates::parse_due_from_today,
+            help = "Set the due date: YYYY-MM-DD, today, tomorrow, a weekday, or a time from now like 3d",
+            long_help = DUE_LONG_HELP
+        )]
+        due: Option<NaiveDate>,
+
+        /// Remove the due date
+        #[arg(long, conflicts_with = "due", help = "Remove the due date")]
+        remove_due: bool,
+
+        /// Assign the item
+        #[arg(
+            long,
+            value_name = "NAME",
+            conflicts_with = "remove_assignee",
+            help = "Assign the item to a person"
+        )]
+        assignee: Option<String>,
+
+        /// Remove the assignee
+        #[arg(long, conflicts_with = "assignee", help = "Remove the assignee")]
+        remove_assignee: bool,
+
+        /// Update archived items
+        #[arg(long, help = "Update the item even if it is closed or archived")]
+        force: bool,
+
+        /// Print the planned file operations without executing them
+        #[arg(long, help = "Show the planned file operations without executing them")]
+        dry_run: bool,
+
+        /// Output format for the change summary or --dry-run
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = OutputFormat::Text,
+            help = "Output format of the change summary (or the --dry-run plan)"
+        )]
+        format: OutputFormat,
+
+        /// Print nothing on success
+        #[arg(
+            long,
+            short,
+            conflicts_with = "dry_run",
+            help = "Don't print the change summary"
+        )]
+        quiet: bool,
+    },
+
+    /// Apply field-level edits to an item
+    #[command(
+        long_about = "Apply field-level edits to an item's frontmatter.\n\n\
+Each operation names a single field, so patches touch only what they change and \
+merge cleanly when the same item is edited on different branches. Operations \
+are applied in order; patch files list one operation per line ('#' starts a comment).\n\n\
+Syntax:\n  \
+field=value       Set a field (empty value removes a custom field)\n  \
+field+=value      Add a value to a list field\n  \
+field-=value      Remove a value from a list field\n\n\
+Built-in fields are 'title', 'status' ('open' or 'in-progress') and 'labels' \
+('labels=a,b' replaces all labels). Any other field is stored as a custom \
+frontmatter field. The fields id, author, created_at and attachments cannot be patched.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs patch --id "), a!("260109"), c!(" --set "), a!("labels+=urgent"), c!(" --set "), a!("priority=high"), "\n  ",
+            c!("qs patch --id "), a!("260109"), c!(" --set "), a!("labels-=wontfix"), "\n  ",
+            c!("qs patch --id "), a!("260109"), c!(" --set "), a!("status=in-progress"), "    Claim an item\n  ",
+            c!("qs patch --id "), a!("260109"), c!(" --set "), a!("priority="), "          Remove custom field\n  ",
+            c!("qs patch --id "), a!("260109"), c!(" --patch-file "), a!("changes.patch"), "\n\n",
+            h!("Note:"), " Changing the title renames the file to match the new slug."
+        ),
+        group = ArgGroup::new("item_ref").required(true)
+    )]
+    Patch {
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            group = "item_ref",
+            help = "Item ID to patch (partial match supported)"
+        )]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(
+            long,
+            conflicts_with = "id",
+            group = "item_ref",
+            help = "Item file path"
+        )]
+        file: Option<std::path::PathBuf>,
+
+        /// Patch operations
+        #[arg(
+            long,
+            value_name = "OP",
+            help = "Patch operation (field=value, field+=value, field-=value)"
+        )]
+        set: Vec<String>,
+
+        /// Patch file
+        #[arg(long, value_name = "PATH", help = "Read operations from a patch file")]
+        patch_file: Option<std::path::PathBuf>,
+
+        /// Patch archived items
+        #[arg(long, help = "Patch the item even if it is closed or archived")]
+        force: bool,
+    },
+
+    /// Show how an item changed since a git revision
+    #[command(
+        long_about = "Show how an item changed since a git revision.\n\n\
+Compares the working copy with the item as it was at the revision (default: HEAD). \
+Frontmatter changes are listed per field ('+' added, '-' removed, '~' changed), \
+followed by a line diff of the body. The old version is found by ID, so items \
+that were renamed, moved to another category, or archived are still matched.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs diff --id "), a!("260109"), "                   Changes since last commit\n  ",
+            c!("qs diff --id "), a!("260109"), c!(" --since "), a!("main"), "      Changes on this branch\n  ",
+            c!("qs diff --id "), a!("260109"), c!(" --since "), a!("HEAD~5"), "\n\n",
+            h!("Note:"), " Requires a git repository."
+        ),
+        group = ArgGroup::new("item_ref").required(true)
+    )]
+    Diff {
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            group = "item_ref",
+            help = "Item ID to diff (partial match supported)"
+        )]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(
+            long,
+            conflicts_with = "id",
+            group = "item_ref",
+            help = "Item file path"
+        )]
+        file: Option<std::path::PathBuf>,
+
+        /// Git revision to compare against
+        #[arg(
+            long,
+            value_name = "REF",
+            help = "Git revision to compare against (default: HEAD)"
+        )]
+        since: Option<String>,
+    },
+
+    /// Edit an item's body in a draft, applied only on `qs draft apply`
+    #[command(
+        long_about = "Edit an item's body in a draft, applied only on 'qs draft apply'.\n\n\
+Copies the item's body to a draft file outside the project \
+(~/.config/queuestack/drafts/) and opens it in the editor. The tracked file is not \
+touched until 'qs draft apply'; 'qs draft discard' abandons the edit. Running \
+'qs draft' again on the same item continues the existing draft.\n\n\
+Applying refuses to overwrite a body that changed since the draft was started \
+(for example after a git pull); pass --force to overwrite it anyway.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs draft --id "), a!("2601"), "                 Start or continue a draft\n  ",
+            c!("qs draft list"), "                     List drafts\n  ",
+            c!("qs draft apply --id "), a!("2601"), "           Write the draft into the item\n  ",
+            c!("qs draft discard --id "), a!("2601"), "         Throw the draft away"
+        ),
+        args_conflicts_with_subcommands = true
+    )]
+    Draft {
+        #[command(subcommand)]
+        action: Option<DraftAction>,
+
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            help = "Item ID (partial match supported)"
+        )]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(long, conflicts_with = "id", help = "Item file path")]
+        file: Option<std::path::PathBuf>,
+    },
+
+    /// Claim the next item in the queue
+    #[command(
+        long_about = "Claim the next open item in the queue and print it.\n\n\
+Picks the oldest open item (--fifo, the default) or the newest (--lifo) matching the \
+filters, marks it 'in-progress', and prints it. Items already in progress are skipped.\n\n\
+Picking and claiming happen under a project lock, so two concurrent pops never return the \
+same item. Exits with an error when no open item matches, so scripts can loop until the \
+queue is empty.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs pop"), "                                Claim the oldest open item\n  ",
+            c!("qs pop --lifo"), "                         Claim the newest open item\n  ",
+            c!("qs pop --label "), a!("bug"), "                   Oldest open bug\n  ",
+            c!("qs pop --format json"), "                  Print the claimed item as JSON\n  ",
+            c!("while qs pop --label "), a!("auto"), c!("; do ...; done"), "  Work through the queue"
+        )
+    )]
+    Pop {
+        /// Take the oldest item
+        #[arg(long, conflicts_with = "lifo", help = "Take the oldest item (default)")]
+        fifo: bool,
+
+        /// Take the newest item
+        #[arg(long, help = "Take the newest item")]
+        lifo: bool,
+
+        /// Labels to match
+        #[arg(
+            long,
+            value_name = "LABEL",
+            help = "Only take items with this label (repeatable, all must match)"
+        )]
+        label: Vec<String>,
+
+        /// Category to match
+        #[arg(long, help = "Only take items in this category")]
+        category: Option<String>,
+
+        /// Output format
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = OutputFormat::Text,
+            help = "Output format"
+        )]
+        format: OutputFormat,
+    },
+
+    /// Close an item (move to archive)
+    #[command(
+        long_about = "Close an item by moving it to the archive directory.\n\n\
+Sets the item's status to 'closed' and moves it from the queuestack directory to the \
+archive subdirectory. In Git repositories, uses 'git mv' to preserve history.\n\n\
+Closed items are excluded from 'qs list' by default (use --scope archive to see them).",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs close --id "), a!("260109-0A2B3C4"), "          Close by full ID\n  ",
+            c!("qs close --id "), a!("2601"), "                    Close by partial ID\n  ",
+            c!("qs close --id "), a!("2601"), c!(" --dry-run"), "          Show planned file operations\n  ",
+            c!("qs close --id "), a!("2601"), c!(" --category "), a!("done/2026"), "  File under .archive/done/2026\n  ",
+            c!("qs list --scope "), a!("archive"), "              View closed items\n  ",
+            c!("qs reopen --id "), a!("260109-0A2B3C4"), "         Reopen if needed"
+        ),
+        group = ArgGroup::new("item_ref").required(true)
+    )]
+    Close {
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            group = "item_ref",
+            help = "Item ID to close (partial match supported)"
+        )]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(
+            long,
+            conflicts_with = "id",
+            group = "item_ref",
+            help = "Item file path"
+        )]
+        file: Option<std::path::PathBuf>,
+
+        /// Archive category
+        #[arg(
+            long,
+            value_name = "NAME",
+            help = "File under this archive category instead of the item's own (e.g. done/2026)"
+        )]
+        category: Option<String>,
+
+        /// Print the planned file operations without executing them
+        #[arg(long, help = "Show the planned file operations without executing them")]
+        dry_run: bool,
+
+        /// Output format for --dry-run
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = OutputFormat::Text,
+            requires = "dry_run",
+            help = "Dry-run output format"
+        )]
+        format: OutputFormat,
+    },
+
+    /// Reopen a closed item (move from archive)
+    #[command(
+        long_about = "Reopen a closed item by moving it back from the archive.\n\n\
+Sets the item's status to 'open' and moves it from the archive directory back \
+to queuestack (or its original category). In Git repositories, uses 'git mv' to \
+preserve history.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs reopen --id "), a!("260109-0A2B3C4"), "         Reopen by full ID\n  ",
+            c!("qs reopen --id "), a!("2601"), "                   Reopen by partial ID\n  ",
+            c!("qs reopen --id "), a!("2601"), c!(" --to-category "), a!("bugs"), "  Reopen into another category\n  ",
+            c!("qs list"), "                               Verify item is back in open list"
+        ),
+        group = ArgGroup::new("item_ref").required(true)
+    )]
+    Reopen {
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            group = "item_ref",
+            help = "Item ID to reopen (partial match supported)"
+        )]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(
+            long,
+            conflicts_with = "id",
+            group = "item_ref",
+            help = "Item file path"
+        )]
+        file: Option<std::path::PathBuf>,
+
+        /// Category to reopen into
+        #[arg(
+            long,
+            value_name = "NAME",
+            help = "Reopen into this category instead of the original one"
+        )]
+        to_category: Option<String>,
+
+        /// Print the planned file operations without executing them
+        #[arg(long, help = "Show the planned file operations without executing them")]
+        dry_run: bool,
+
+        /// Output format for --dry-run
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = OutputFormat::Text,
+            requires = "dry_run",
+            help = "Dry-run output format"
+        )]
+        format: OutputFormat,
+    },
+
+    /// Manage item attachments (add, remove)
+    #[command(
+        long_about = "Manage attachments for items.\n\n\
+Attachments can be files (copied to a sibling .attachments directory) or URLs (stored as references). \
+File attachments are renamed to follow the pattern: {N}-{name}.{ext}\n\n\
+To list attachments for an item, use: qs list --attachments --id <ID>",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs attachments add --id "), a!("260109-0A2B3C4"), " ", a!("screenshot.png"), "\n  ",
+            c!("qs attachments add --id "), a!("260109-0A2B3C4"), " ", a!("https://github.com/issue/42"), "\n  ",
+            c!("qs attachments remove --id "), a!("260109-0A2B3C4"), " ", a!("1"), " ", a!("2"), "\n\n",
+            h!("See also:"), " ", c!("qs list --attachments --id "), a!("<ID>"), " to list attachments"
+        )
+    )]
+    Attachments {
+        #[command(subcommand)]
+        action: AttachmentsAction,
+    },
+
+    /// Print a commit message prefix for the in-progress item
+    #[command(
+        name = "commit-template",
+        long_about = "Print a commit message prefix referencing the item you are working on.\n\n\
+Without --id or --file, uses the single item whose status is 'in-progress'. Claim an \
+item with 'qs patch --id <ID> --set status=in-progress'.\n\n\
+Install 'qs hooks install' to prefix commit messages automatically via a \
+prepare-commit-msg hook.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs commit-template"), "                     Prints e.g. \"[260109-0A2B3C4] \"\n  ",
+            c!("qs commit-template --id "), a!("2601"), "           Prefix for a specific item\n  ",
+            c!("git commit -m \"$(qs commit-template)Fix login\""), "\n\n",
+            h!("See also:"), " ", c!("qs hooks install")
+        )
+    )]
+    CommitTemplate {
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            help = "Item ID (default: the in-progress item)"
+        )]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(long, conflicts_with = "id", help = "Item file path")]
+        file: Option<std::path::PathBuf>,
+    },
+
+    /// Export items to another task format (org, taskpaper)
+    #[command(
+        long_about = "Export items to other plain-text task formats.\n\n\
+Formats:\n  \
+org         Emacs Org-mode outline: TODO/STARTED/DONE headings, labels as tags,\n              \
+due dates as DEADLINE, metadata in a PROPERTIES drawer\n  \
+taskpaper   TaskPaper: one project per category, labels as @tags, @due(date),\n              \
+@done for closed items, body as notes\n\n\
+Writes to stdout unless --output is given. Only open items are exported by default. \
+Exporting items in protected categories or with protected labels requires --force.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs export org"), " > queue.org           Export open items\n  ",
+            c!("qs export taskpaper --all"), "           Include closed items\n  ",
+            c!("qs export org --output "), a!("todo.org"), "     Write to a file"
+        )
+    )]
+    Export {
+        /// Export format
+        #[arg(value_enum)]
+        format: ExportFormat,
+
+        /// Export only closed items
+        #[arg(
+            long,
+            conflicts_with = "all",
+            help = "Export only closed/archived items"
+        )]
+        closed: bool,
+
+        /// Export open and closed items
+        #[arg(long, conflicts_with = "closed", help = "Export open and closed items")]
+        all: bool,
+
+        /// Output file
+        #[arg(
+            short,
+            long,
+            value_name = "PATH",
+            help = "Write to a file instead of stdout"
+        )]
+        output: Option<std::path::PathBuf>,
+
+        /// Export protected items too
+        #[arg(long, help = "Export even if protected items are included")]
+        force: bool,
+    },
+
+    /// Render the dependency graph of open items (mermaid, dot)
+    #[command(
+        long_about = "Render the dependency graph of open and in-progress items.\n\n\
+Dependencies are read from the 'depends_on', 'blocks', and 'parent' frontmatter fields \
+(record links with 'qs link'). Blocking edges are solid and parent edges \
+dashed; in-progress items are drawn with a thick border. Only items with a dependency on \
+another listed item are drawn.\n\n\
+Formats:\n  \
+mermaid   Mermaid flowchart, for Markdown docs, GitHub, and wikis\n  \
+dot       Graphviz DOT, for rendering with 'dot -Tsvg'\n\n\
+Writes to stdout unless --output is given. Including items in protected categories or \
+with protected labels requires --force.\n\n\
+--tui browses the graph on the terminal instead, with the items blocking the most others \
+(directly or through a chain) first. Left and Right jump to the highlighted item's blocker \
+and to the item it blocks, and Enter opens its action menu.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs graph"), "                            Mermaid flowchart\n  ",
+            c!("qs graph --milestone "), a!("v2.0"), "           Only one milestone\n  ",
+            c!("qs graph --format dot"), " | dot -Tsvg > deps.svg\n  ",
+            c!("qs graph --tui"), "                      Browse bottlenecks on the terminal\n\n",
+            h!("Frontmatter:"), "\n  ",
+            "depends_on: [260109-0A2B3C4]     parent: 260101-9Z8Y7X6"
+        )
+    )]
+    Graph {
+        /// Output format
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = GraphFormat::Mermaid,
+            help = "Output format: mermaid or dot"
+        )]
+        format: GraphFormat,
+
+        /// Only items of this milestone
+        #[arg(long, help = "Only items of this milestone")]
+        milestone: Option<String>,
+
+        /// Only items of this category
+        #[arg(long, help = "Only items of this category")]
+        category: Option<String>,
+
+        /// Output file
+        #[arg(
+            short,
+            long,
+            value_name = "PATH",
+            help = "Write to a file instead of stdout"
+        )]
+        output: Option<std::path::PathBuf>,
+
+        /// Include protected items too
+        #[arg(long, help = "Render even if protected items are included")]
+        force: bool,
+
+        /// Browse on the terminal
+        #[arg(
+            long,
+            conflicts_with_all = ["format", "output"],
+            help = "Browse the graph on the terminal, bottlenecks first"
+        )]
+        tui: bool,
+    },
+
+    /// Record that an item blocks or depends on other items
+    #[command(
+        long_about = "Record that an item blocks or depends on other items.\n\n\
+'--blocks B' adds B to the item's 'blocks' field, '--depends-on B' adds it to \
+'depends_on'; both say the same thing from either side. Referenced IDs must name existing \
+items and are stored in full. An item is blocked while any item it depends on (or that \
+blocks it) is open or in progress: list actionable work with 'qs list --unblocked'.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs link --id "), a!("2601"), c!(" --blocks "), a!("2602"), "            2601 must be closed first\n  ",
+            c!("qs link --id "), a!("2602"), c!(" --depends-on "), a!("2601"), "        The same link, stored on 2602\n  ",
+            c!("qs link --id "), a!("2601"), c!(" --blocks "), a!("2602"), c!(" --remove"), "   Remove the link\n  ",
+            c!("qs list --blocked"), "                      Items waiting on others"
+        ),
+        group = ArgGroup::new("item_ref").required(true)
+    )]
+    Link {
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            group = "item_ref",
+            help = "Item ID to link (partial match supported)"
+        )]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(
+            long,
+            conflicts_with = "id",
+            group = "item_ref",
+            help = "Item file path"
+        )]
+        file: Option<std::path::PathBuf>,
+
+        /// Items this item blocks
+        #[arg(
+            long,
+            value_name = "ID",
+            num_args = 1..,
+            required_unless_present = "depends_on",
+            help = "Items that can't be worked on until this one is closed"
+        )]
+        blocks: Vec<String>,
+
+        /// Items this item depends on
+        #[arg(
+            long,
+            value_name = "ID",
+            num_args = 1..,
+            help = "Items that must be closed before this one can be worked on"
+        )]
+        depends_on: Vec<String>,
+
+        /// Remove the links
+        #[arg(long, help = "Remove the links instead of adding them")]
+        remove: bool,
+
+        /// Link archived items too
+        #[arg(long, help = "Link archived items too")]
+        force: bool,
+    },
+
+    /// Edit the frontmatter of many items at once in one YAML file
+    #[command(
+        long_about = "Edit the frontmatter of many items at once.\n\n\
+'qs bulk export' writes the frontmatter of the matching items to a YAML list; edit it in \
+any editor (change 50 assignees at once) and apply it with 'qs bulk apply'. Items are \
+matched by id, and entries removed from the file are left unchanged.\n\n\
+Before anything is written, every entry is validated: ids must exist and appear once, \
+created_at, closed_at, attachments, checksums, and link_titles can't change, and status \
+can only switch between open and in-progress (use 'qs close' and 'qs reopen' for the \
+rest). The changes are then shown as a diff and, in a terminal, confirmed. Title changes \
+rename the file; status changes move it between status directories.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs bulk export --label "), a!("backend"), " > items.yaml   Export matching items\n  ",
+            c!("qs bulk apply "), a!("items.yaml"), " --dry-run         Preview the changes\n  ",
+            c!("qs bulk apply "), a!("items.yaml"), "                   Apply them"
+        )
+    )]
+    Bulk {
+        #[command(subcommand)]
+        action: BulkAction,
+    },
+
+    /// Triage open items in an editor, one per line
+    #[command(
+        long_about = "Triage open and in-progress items in an editor, like 'git rebase -i'.\n\n\
+Lists the matching items one per line with their status, category, assignee, and \
+labels, separated by '|'. With --edit the list opens in the editor and the changes \
+are applied when you save and quit; without it the list is printed, to be edited and \
+applied later with --apply.\n\n\
+Set the status to open, in-progress, or closed (closes and archives the item, like \
+'qs close'). Lines are matched by ID, and removed lines leave their item unchanged. \
+Every line is validated before anything is written; if the list is invalid, nothing \
+changes and the edited list is kept for another try.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs triage --edit"), "                       Triage all open items\n  ",
+            c!("qs triage --edit --label "), a!("bug"), "           Only items labeled bug\n  ",
+            c!("qs triage"), " > ", a!("list.txt"), "                 Save the list to edit later\n  ",
+            c!("qs triage --apply "), a!("list.txt"), "            Apply the edited list"
+        )
+    )]
+    Triage {
+        /// Open the list in the editor and apply it on save
+        #[arg(
+            long,
+            conflicts_with = "apply",
+            help = "Edit the list and apply it on save"
+        )]
+        edit: bool,
+
+        /// Apply an edited list
+        #[arg(
+            long,
+            value_name = "FILE",
+            help = "Apply a list saved with 'qs triage > FILE' and edited"
+        )]
+        apply: Option<std::path::PathBuf>,
+
+        /// Filter by label (can be specified multiple times for AND logic)
+        #[arg(
+            long,
+            value_name = "LABEL",
+            conflicts_with = "apply",
+            help = "Only items with all of these labels (repeatable)"
+        )]
+        label: Vec<String>,
+
+        /// Filter by any of several labels (OR logic)
+        #[arg(
+            long,
+            value_name = "LABEL",
+            conflicts_with = "apply",
+            help = "Only items with at least one of these labels (repeatable)"
+        )]
+        any_label: Vec<String>,
+
+        /// Exclude labels
+        #[arg(
+            long,
+            value_name = "LABEL",
+            conflicts_with = "apply",
+            help = "Exclude items with any of these labels (repeatable)"
+        )]
+        not_label: Vec<String>,
+
+        /// Filter by author
+        #[arg(
+            long,
+            conflicts_with = "apply",
+            help = "Filter items by author name (substring match)"
+        )]
+        author: Option<String>,
+
+        /// Filter by category
+        #[arg(long, conflicts_with = "apply", help = "Filter items by category")]
+        category: Option<String>,
+    },
+
+    /// Show disk usage per item and category
+    #[command(
+        long_about = "Show the disk space taken by items and categories, largest first.\n\n\
+An item's size is its file plus the files in its attachment directory. Items at or \
+above --threshold (default 1MB) are highlighted: candidates to prune, or to move to \
+external storage with attachment_path or s3_url. Closed items are included unless \
+--open is given. Deduplicated attachments count for every item linking them.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs du"), "                        Largest 20 items and all categories\n  ",
+            c!("qs du --threshold "), a!("10MB"), "      Highlight items of 10 MB or more\n  ",
+            c!("qs du --limit "), a!("0"), c!(" --open"), "       All open items\n  ",
+            c!("qs du --format "), a!("json"), "          Machine-readable output"
+        )
+    )]
+    Du {
+        /// Highlight items at or above this size
+        #[arg(
+            long,
+            value_name = "SIZE",
+            default_value = commands::DU_DEFAULT_THRESHOLD,
+            help = "Highlight items at or above this size (e.g. 500KB, 10MB)"
+        )]
+        threshold: String,
+
+        /// Number of items to list
+        #[arg(
+            long,
+            short = 'n',
+            default_value_t = 20,
+            help = "Number of items to list (0 for all)"
+        )]
+        limit: usize,
+
+        /// Leave out closed items
+        #[arg(long, help = "Only open and in-progress items")]
+        open: bool,
+
+        /// Output format
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = OutputFormat::Text,
+            help = "Output format"
+        )]
+        format: OutputFormat,
+    },
+
+    /// Show item counts and first-response time
+    #[command(
+        long_about = "Show item counts by status and first-response metrics.\n\n\
+First response is the time from an item's creation to the first commit touching it \
+by someone other than its author (compared by name), taken from git history. It is a \
+proxy for how quickly incoming reports get attention. Items are matched by ID, so \
+renames, category moves, and archiving are followed.\n\n\
+--author breaks the numbers down per person: items created, items closed (counted for \
+the assignee, or the author if unassigned), open items currently assigned, and the \
+average cycle time from creation to close. Authors are sorted by current load, and \
+spellings are unified through the configured identities.\n\n\
+--format markdown prints Markdown tables with mermaid pie charts, ready to paste into a \
+README, wiki page, or pull request description.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs stats"), "                    Show queue statistics\n  ",
+            c!("qs stats --author"), "           Per-author load and cycle time\n  ",
+            c!("qs stats --format "), a!("json"), "      Machine-readable output\n  ",
+            c!("qs stats --format "), a!("markdown"), "  Tables and charts for a README or PR\n\n",
+            h!("Note:"), " First-response metrics require a git repository."
+        )
+    )]
+    Stats {
+        /// Break the numbers down by author
+        #[arg(
+            long,
+            help = "Show created, closed, assigned, and cycle time per author"
+        )]
+        author: bool,
+
+        /// Output format
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = StatsFormat::Text,
+            help = "Output format: text, json, or markdown (tables and mermaid charts)"
+        )]
+        format: StatsFormat,
+    },
+
+    /// Show a chronological feed of project activity
+    #[command(
+        long_about = "Show a chronological feed of project activity.\n\n\
+Combines the items' creation times and authors with the git history of the stack \
+directory into one feed, oldest first: who created, updated, closed, or reopened an item \
+and who attached a file to it. Items are matched by ID, so renames and category moves \
+are followed, and names are unified through the configured identities. Only committed \
+changes appear, apart from creation.\n\n\
+Shows the latest 30 entries by default; --since limits the feed by time instead of \
+count. --follow keeps running and prints new entries as they are committed.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs activity"), "                     Latest activity\n  ",
+            c!("qs activity --since "), a!("7d"), "          Everything from the last week\n  ",
+            c!("qs activity --follow"), "            Keep watching for new activity"
+        )
+    )]
+    Activity {
+        /// Only show activity since this date
+        #[arg(
+            long,
+            value_name = "DATE",
+            value_parser = queuestack::item::dates::parse_since,
+            help = "Only show activity on or after a date: YYYY-MM-DD, today, 7d, 2w, 3m, 1y"
+        )]
+        since: Option<DateTime<Utc>>,
+
+        /// Number of entries to show
+        #[arg(
+            short = 'n',
+            long,
+            value_name = "N",
+            default_value_t = 30,
+            help = "Show the latest N entries (0 = all; ignored with --since)"
+        )]
+        limit: usize,
+
+        /// Keep printing new activity
+        #[arg(
+            short,
+            long,
+            help = "Keep running and print new activity as it happens"
+        )]
+        follow: bool,
+    },
+
+    /// Summarize your own command usage from the local usage log
+    #[command(
+        long_about = "Summarize your own command usage from the local usage log.\n\n\
+Shows which commands you run most, at what time of day, and on which weekdays. The log is \
+opt-in: set 'usage_log = true' in the global config to start recording. Only command names \
+and local times are recorded (never arguments or item contents), in usage.jsonl next to the \
+global config, and nothing is ever sent anywhere.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs insights"), "                 Last 30 days\n  ",
+            c!("qs insights --days "), a!("7"), "        Last week\n  ",
+            c!("qs insights --days "), a!("0"), "        Everything recorded\n  ",
+            c!("qs insights --clear"), "         Delete the usage log"
+        )
+    )]
+    Insights {
+        /// Days to include
+        #[arg(
+            long,
+            value_name = "N",
+            default_value_t = 30,
+            help = "Only include the last N days (0 = everything)"
+        )]
+        days: u32,
+
+        /// Delete the log
+        #[arg(long, conflicts_with = "days", help = "Delete the usage log")]
+        clear: bool,
+    },
+
+    /// Time core operations
+    #[command(
+        long_about = "Time core operations.\n\n\
+Times walking and parsing all items, ID lookup, search, and ID generation in the \
+current project and prints the median of several runs. --save writes the timings to \
+a JSON file; --compare fails when any operation is more than --threshold percent \
+slower than such a baseline, which makes it usable as a performance regression gate.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs bench"), "                            Time the current project\n  ",
+            c!("qs bench --save "), a!("base.json"), "           Save a baseline\n  ",
+            c!("qs bench --compare "), a!("base.json"), "        Fail if slower than the baseline"
+        )
+    )]
+    Bench {
+        /// Runs per operation
+        #[arg(
+            long,
+            default_value_t = 5,
+            help = "Runs per operation (the median is reported)"
+        )]
+        runs: usize,
+
+        /// Save timings as JSON
+        #[arg(long, value_name = "PATH", help = "Write the timings to a JSON file")]
+        save: Option<std::path::PathBuf>,
+
+        /// Baseline to compare against
+        #[arg(
+            long,
+            value_name = "PATH",
+            help = "Fail if slower than the timings in this JSON file"
+        )]
+        compare: Option<std::path::PathBuf>,
+
+        /// Allowed slowdown in percent
+        #[arg(
+            long,
+            default_value_t = 20,
+            requires = "compare",
+            help = "Allowed slowdown against the baseline, in percent"
+        )]
+        threshold: u32,
+    },
+
+    /// Take a guided tour of queuestack in a throwaway project
+    #[command(
+        long_about = "Take a guided tour of queuestack in a throwaway project.\n\n\
+Creates a temporary project and walks through creating, listing, searching, attaching files \
+to, and closing an item, showing the equivalent command for each step. The temporary project \
+is removed afterwards; your own projects are not touched.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs tour"), "                     Start the tour\n\n",
+            h!("Note:"), " Requires an interactive terminal."
+        )
+    )]
+    Tour,
+
+    /// Read guides on IDs, configuration, workflow, and agents
+    #[command(
+        long_about = "Read the built-in guides, or the help of a command.\n\n\
+Topics: ids (ID patterns and partial matching), config (config files and options), workflow \
+(from creating an item to closing it), and agents (use from scripts and coding agents). The \
+guides are part of qs, so they work offline and match the installed version. Without a topic, \
+prints the general help and lists the topics. A command name prints that command's help.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs help "), a!("ids"), "                 How IDs work\n  ",
+            c!("qs help "), a!("agents"), "              Using qs from scripts and agents\n  ",
+            c!("qs help --search "), a!("profile"), "    Find a topic mentioning profiles\n  ",
+            c!("qs help "), a!("attachments add"), "     Help for a command"
+        )
+    )]
+    Help {
+        /// Topic or command
+        #[arg(
+            value_name = "TOPIC",
+            help = "Topic (ids, config, workflow, agents) or command"
+        )]
+        topic: Vec<String>,
+
+        /// Search the topics
+        #[arg(
+            long,
+            value_name = "TEXT",
+            conflicts_with = "topic",
+            help = "Show the topic lines containing TEXT"
+        )]
+        search: Option<String>,
+    },
+
+    /// Show the resolved project root and config chain
+    #[command(
+        long_about = "Show the resolved project root and the configuration files that apply.\n\n\
+Lists every .queuestack file found from the current directory upward when projects are \
+nested, the nested_projects policy in effect, and the config chain (global config, active \
+profile, project config) in override order.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs which"), "                          Show the project used here\n  ",
+            c!("qs which --project-root ../app"), "    Check another project\n\n",
+            h!("Note:"), " Set nested_projects to \"explicit\" or \"error\" to guard against \
+nested projects resolving unexpectedly."
+        )
+    )]
+    Which,
+
+    /// Show a project overview: settings, paths, counts, git status, and problems
+    #[command(
+        long_about = "Show a project overview for support and debugging.\n\n\
+Prints the resolved settings and the config files they come from, the stack, archive, and \
+template paths, item counts by status, git status of the stack, the attachment store, and \
+any detected problems: unparseable items, duplicate IDs, closed items outside the archive \
+(and active items inside it), missing attachments, empty directories, and unknown \
+global config keys.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs info"), "                           Overview of the current project\n  ",
+            c!("qs info --project-root ../app"), "     Overview of another project"
+        )
+    )]
+    Info,
+
+    /// Install git hooks (prepare-commit-msg)
+    #[command(
+        long_about = "Install git hooks that integrate queuestack with your commits.\n\n\
+The prepare-commit-msg hook prefixes new commit messages with the in-progress item \
+(see 'qs commit-template'). It skips merges, squashes, amends, and messages that \
+already reference the item, and never blocks a commit.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs hooks install"), "            Install the prepare-commit-msg hook\n  ",
+            c!("qs hooks install --force"), "    Replace an existing hook"
+        )
+    )]
+    Hooks {
+        #[command(subcommand)]
+        action: HooksAction,
+    },
+
+    /// Git merge driver for item files
+    #[command(
+        long_about = "Merge two versions of an item file as a git merge driver.\n\n\
+Git passes the common ancestor, our version, and their version; the result is written \
+over our version. Frontmatter is merged field by field: labels and attachments are \
+combined, timestamps changed on both sides take the later value, and other fields take \
+the side that changed them. If both sides changed a field differently, ours wins with a \
+warning. Only conflicting body lines get conflict markers, in which case the command \
+fails so git reports the conflict.\n\n\
+Register the driver with --install, then route item files to it with \
+'qs init --gitattributes'.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs merge-driver --install"), "     Register the driver in .git/config\n  ",
+            c!("qs merge-driver "), a!("%O %A %B"), "      How git calls the driver"
+        )
+    )]
+    MergeDriver {
+        /// Common ancestor (%O)
+        #[arg(required_unless_present = "install", help = "Common ancestor (%O)")]
+        base: Option<std::path::PathBuf>,
+
+        /// Our version (%A)
+        #[arg(
+            required_unless_present = "install",
+            help = "Our version (%A), replaced by the result"
+        )]
+        ours: Option<std::path::PathBuf>,
+
+        /// Their version (%B)
+        #[arg(required_unless_present = "install", help = "Their version (%B)")]
+        theirs: Option<std::path::PathBuf>,
+
+        /// Register the driver
+        #[arg(
+            long,
+            conflicts_with_all = ["base", "ours", "theirs"],
+            help = "Register the driver in the repository's git config"
+        )]
+        install: bool,
+    },
+
+    /// Apply auto-labeling rules to existing items
+    #[command(
+        long_about = "Apply the auto-labeling rules from the [[rules]] config section.\n\n\
+Rules run automatically when items are created. Use 'qs rules apply' to label \
+items created before a rule existed. A rule matches when its 'title' and/or 'body' \
+regex (case-insensitive) matches; it adds 'labels' and fills in 'set' custom fields \
+that aren't set yet, so applying rules repeatedly is safe. Archived items are skipped by \
+--all and need --force when named.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs rules apply --all"), "             Apply to every open item\n  ",
+            c!("qs rules apply --id "), a!("2601"), "        Apply to one item\n\n",
+            h!("Config:"), "\n  ",
+            "[[rules]]\n  ",
+            "title = \"panic|crash\"\n  ",
+            "labels = [\"bug\"]\n  ",
+            "set = { priority = \"high\" }"
+        )
+    )]
+    Rules {
+        #[command(subcommand)]
+        action: RulesAction,
+    },
+
+    /// Add a comment to an item
+    #[command(
+        long_about = "Add a comment to an item.\n\n\
+The comment is appended to the item body as a section headed with your name and the \
+current time, e.g. '## Comment by Alice on 2026-01-12 14:03 UTC', so discussions stay \
+readable in any editor and merge like the rest of the file. List them with 'qs comments'.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs comment --id "), a!("2601"), c!(" \"Seen on macOS too\""), "      Add a comment\n  ",
+            c!("qs comments --id "), a!("2601"), "                        List the comments"
+        ),
+        group = ArgGroup::new("item_ref").required(true)
+    )]
+    Comment {
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            group = "item_ref",
+            help = "Item ID to comment on (partial match supported)"
+        )]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(
+            long,
+            conflicts_with = "id",
+            group = "item_ref",
+            help = "Item file path"
+        )]
+        file: Option<std::path::PathBuf>,
+
+        /// Comment text
+        #[arg(help = "Comment text (Markdown)")]
+        text: String,
+
+        /// Comment on archived items too
+        #[arg(long, help = "Comment on archived items too")]
+        force: bool,
+    },
+
+    /// List the comments of an item
+    #[command(
+        long_about = "List the comments of an item, oldest first.\n\n\
+Comments are the '## Comment by <author> on <date>' sections added by 'qs comment'.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs comments --id "), a!("2601"), "                  List the comments\n  ",
+            c!("qs comments --id "), a!("2601"), c!(" --format json"), "    Print them as JSON"
+        ),
+        group = ArgGroup::new("item_ref").required(true)
+    )]
+    Comments {
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            group = "item_ref",
+            help = "Item ID (partial match supported)"
+        )]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(
+            long,
+            conflicts_with = "id",
+            group = "item_ref",
+            help = "Item file path"
+        )]
+        file: Option<std::path::PathBuf>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text, help = "Output format")]
+        format: OutputFormat,
+    },
+
+    /// Delete an item (move it to the trash)
+    #[command(
+        long_about = "Delete an item by moving it and its attachments into the .trash/ directory.\n\n\
+In Git repositories the move uses 'git mv', so the deletion shows up in git like any other \
+move. Category directories left empty are removed. Restore the item with \
+'qs trash restore <id>'; it is purged after 'trash_days' days.\n\n\
+Asks for confirmation first; --force (alias --yes) deletes without asking for scripts, \
+including items in protected categories or with protected labels. --dry-run prints the \
+planned moves instead.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs delete --id "), a!("2601"), "                  Delete after confirming\n  ",
+            c!("qs delete --id "), a!("2601"), c!(" --force"), "          Delete without asking\n  ",
+            c!("qs delete --id "), a!("2601"), c!(" --dry-run"), "        Preview file operations\n  ",
+            c!("qs trash restore "), a!("2601"), "                Undo the deletion"
+        ),
+        group = ArgGroup::new("item_ref").required(true)
+    )]
+    Delete {
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            group = "item_ref",
+            help = "Item ID to delete (partial match supported)"
+        )]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(
+            long,
+            conflicts_with = "id",
+            group = "item_ref",
+            help = "Item file path"
+        )]
+        file: Option<std::path::PathBuf>,
+
+        /// Skip the confirmation and delete protected items too
+        #[arg(
+            short = 'y',
+            long,
+            visible_alias = "yes",
+            help = "Delete without asking, even if the item is protected"
+        )]
+        force: bool,
+
+        /// Print the planned file operations without executing them
+        #[arg(long, help = "Show the planned file operations without executing them")]
+        dry_run: bool,
+
+        /// Output format for --dry-run
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = OutputFormat::Text,
+            requires = "dry_run",
+            help = "Dry-run output format"
+        )]
+        format: OutputFormat,
+    },
+
+    /// List and restore deleted items
+    #[command(
+        long_about = "List and restore deleted items.\n\n\
+Deleting an item moves it and its attachments into the .trash/ directory of the \
+stack, so the deletion shows up in git like any other move. Deleted items are \
+kept for 'trash_days' days (default 30, 0 keeps them) and then purged.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs trash list"), "                 List deleted items\n  ",
+            c!("qs trash restore "), a!("2601"), "         Restore an item to where it was"
+        )
+    )]
+    Trash {
+        #[command(subcommand)]
+        action: TrashAction,
+    },
+
+    /// Remove empty directories from the stack
+    #[command(
+        long_about = "Remove empty directories from the stack.\n\n\
+Closing, reopening, moving, and deleting items remove the directories they leave \
+empty. Items deleted by hand or by other tools can leave empty category, archive, \
+attachment, and trash directories behind; this sweeps the whole stack for them. \
+Directories holding only a generated _index.md count as empty. 'qs info' reports \
+empty directories as a problem.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs clean"), "                Remove empty directories\n  ",
+            c!("qs clean --dry-run"), "      List them without removing anything"
+        )
+    )]
+    Clean {
+        /// List the directories without removing them
+        #[arg(long, help = "List empty directories without removing them")]
+        dry_run: bool,
+    },
+
+    /// Check item labels against the label policy
+    #[command(
+        long_about = "Check the labels of all items against the label policy.\n\n\
+The [labels] config table sets the case labels are written in ('lower', 'upper', or \
+'preserve'), the characters they may consist of (a regex character class such as \
+'a-z0-9-'), and their maximum length; [[labels.defined]] entries predefine labels \
+with a description and a color, and 'strict = true' allows only those. New labels are \
+normalized and checked whenever an item is written; this reports existing items that \
+don't follow the policy, including archived items and templates, and exits non-zero \
+if any don't.\n\n\
+--fix rewrites labels in the wrong case or spelled differently from their definition. \
+Other violations can't be fixed automatically and are left for renaming with 'qs patch'.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs validate"), "           Report labels breaking the policy\n  ",
+            c!("qs validate --fix"), "     Normalize their case in place\n\n",
+            h!("Config:"), "\n  ",
+            "[labels]\n  ",
+            "case = \"lower\"\n  ",
+            "charset = \"a-z0-9-\"\n  ",
+            "max_length = 24\n  ",
+            "strict = true\n\n  ",
+            "[[labels.defined]]\n  ",
+            "name = \"bug\"\n  ",
+            "description = \"Something isn't working\"\n  ",
+            "color = \"#d73a4a\""
+        )
+    )]
+    Validate {
+        /// Normalize labels in place
+        #[arg(long, help = "Rewrite labels in the wrong case")]
+        fix: bool,
+    },
+
+    /// List and fetch read-only mirrors of other stacks
+    #[command(
+        long_about = "List and fetch read-only mirrors of other stacks.\n\n\
+Mirrors are registered in the [mirrors] config table as a name and either a path \
+(another checkout, relative to the project root) or a git URL. Their items appear in \
+'qs list' and 'qs search' with a [name] marker but can't be changed. A git mirror is \
+cloned shallowly by 'qs mirror fetch' and only updated when fetched again.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs mirror list"), "                List mirrors and their state\n  ",
+            c!("qs mirror fetch"), "               Fetch all git mirrors\n  ",
+            c!("qs mirror fetch "), a!("upstream"), "      Fetch one mirror\n\n",
+            h!("Config:"), "\n  ",
+            "[mirrors]\n  ",
+            "upstream = \"../upstream\"\n  ",
+            "library = \"https://github.com/example/library.git\""
+        )
+    )]
+    Mirror {
+        #[command(subcommand)]
+        action: MirrorAction,
+    },
+
+    /// Sync items with GitHub Issues
+    #[command(
+        long_about = "Sync items with issues of a GitHub repository, using the gh CLI.\n\n\
+Active items without an issue get one, and its number is stored in the item's \
+github_issue field. Titles, bodies, and labels of linked items are pushed to their \
+issues; labels GitHub doesn't have yet are created with the color and description of \
+their [[labels.defined]] entry. Closing either side closes the other: a closed item \
+closes its issue, and a closed issue closes its item. Items in protected categories or \
+with protected labels are skipped unless --force is given.\n\n\
+The repository defaults to the one of the git remote. Set it, and rename or leave out \
+labels, in the [github] table of the project config.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs sync github"), "                Create, update, and close issues and items\n  ",
+            c!("qs sync github --dry-run"), "      Show what would change\n\n",
+            h!("Config:"), "\n  ",
+            "[github]\n  ",
+            "repo = \"owner/name\"\n\n  ",
+            "[github.labels]\n  ",
+            "bug = \"type: bug\"\n  ",
+            "wip = \"\""
+        )
+    )]
+    Sync {
+        #[command(subcommand)]
+        target: SyncTarget,
+    },
+
+    /// One-time setup: create global config and install shell completions
+    #[command(
+        long_about = "One-time setup for queuestack.\n\n\
+This command helps you get started with queuestack by:\n  \
+1. Creating the global configuration file (~/.config/queuestack/config) if it doesn't exist\n  \
+2. Installing tab completions for your shell\n\n\
+Shell detection tries (in order):\n  \
+1. --shell flag if provided ('all' installs for every supported shell on the PATH)\n  \
+2. Shell-specific env vars (FISH_VERSION, ZSH_VERSION, BASH_VERSION)\n  \
+3. $SHELL environment variable (login shell)\n\n\
+With --system, completions go to system-wide locations under /usr/local/share \
+(for all users); without permission to write there, setup installs them for the \
+current user instead and says so.\n\n\
+--print-paths writes nothing: it prints the global config path and the completion \
+file of each shell (or the --shell given) as tab-separated 'name<TAB>path' lines, \
+for package post-install scripts.\n\n\
+Run this once after installing queuestack to enable tab completion for commands and arguments.\n\n\
+The setup is idempotent - running it multiple times is safe and will just overwrite \
+the completion script with the latest version.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs setup"), "                    Run one-time setup (auto-detect shell)\n  ",
+            c!("qs setup --shell fish"), "       Explicitly specify fish shell\n  ",
+            c!("qs setup --shell all"), "        Install for every shell on the PATH\n  ",
+            c!("sudo qs setup --system"), "      Install completions system-wide\n  ",
+            c!("qs setup --print-paths --system"), "  Print the system-wide paths\n\n",
+            h!("Supported shells:"), " zsh, bash, fish, elvish, powershell\n\n",
+            h!("Note:"), " If shell detection fails, use ", c!("--shell"), " to specify explicitly."
+        )
+    )]
+    Setup {
+        /// Shell to install completions for (overrides auto-detection)
+        #[arg(
+            long,
+            value_enum,
+            help = "Shell to install completions for, or 'all' for every shell on the PATH"
+        )]
+        shell: Option<SetupShell>,
+
+        /// Install completions system-wide
+        #[arg(
+            long,
+            help = "Install completions system-wide (under /usr/local/share)"
+        )]
+        system: bool,
+
+        /// Print the paths setup uses instead of writing them
+        #[arg(
+            long,
+            help = "Print the config and completion paths (name<TAB>path) without writing"
+        )]
+        print_paths: bool,
+    },
+
+    /// Run a JSON-RPC server for editor integrations
+    #[command(
+        long_about = "Run a long-lived JSON-RPC 2.0 server on a Unix socket.\n\n\
+Editor extensions (VS Code, Neovim, ...) can keep a connection open and query \
+items with low latency instead of spawning qs for every keystroke. Requests and \
+responses are newline-delimited JSON objects.\n\n\
+Methods:\n  \
+list          {closed?, labels?, category?, author?}  List items\n  \
+search        {query, full_text?, closed?}            Search items\n  \
+resolve-id    {id}                                    Resolve a (partial) ID\n  \
+create        {title, labels?, category?}             Create an item\n  \
+close         {id}                                    Close an item\n\n\
+The socket path is printed to stderr on startup. Restart the daemon after \
+changing configuration.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs daemon"), "                             Listen on the default socket\n  ",
+            c!("qs daemon --socket "), a!("/tmp/qs.sock"), "\n\n",
+            h!("Request:"), "\n  ",
+            "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"resolve-id\",\"params\":{\"id\":\"2601\"}}"
+        )
+    )]
+    Daemon {
+        /// Socket path
+        #[arg(
+            long,
+            value_name = "PATH",
+            help = "Unix socket path (default: per-project path in temp dir)"
+        )]
+        socket: Option<std::path::PathBuf>,
+    },
+
+    /// Hand labeled items to an agent command (experimental)
+    #[command(
+        long_about = "Hand labeled items to an agent command (experimental).\n\n\
+Watches the queue and runs the configured 'agent_command' for each open item that has all \
+of the given labels ('auto' by default), oldest first, one at a time. The command runs through \
+the shell from the project root; {id} and {path} are replaced by the item's ID and file, and \
+the item is also passed as QS_ID, QS_TITLE, QS_FILE, QS_LABELS, and QS_CATEGORY.\n\n\
+The item is marked in progress while the agent runs. Afterwards the agent's output is \
+appended to the item as a comment. On exit status 0 the item is closed (subject to the \
+pre_close policy); otherwise it is reopened and labeled 'agent-failed' so it is not picked \
+up again until the label is removed.\n\n\
+This command is experimental and may change.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs agent"), "                          Watch for items labeled auto\n  ",
+            c!("qs agent --once"), "                   Process the current queue and exit\n  ",
+            c!("qs agent --label "), a!("docs"), " --category ", a!("chores"), "\n\n",
+            h!("Config:"), "\n  ",
+            "agent_command = \"my-agent --task {path}\""
+        )
+    )]
+    Agent {
+        /// Labels to match
+        #[arg(
+            long,
+            value_name = "LABEL",
+            default_values_t = [String::from("auto")],
+            help = "Only pick up items with this label (repeatable, all must match)"
+        )]
+        label: Vec<String>,
+
+        /// Category to match
+        #[arg(long, help = "Only pick up items in this category")]
+        category: Option<String>,
+
+        /// Exit after one pass
+        #[arg(long, help = "Process the current queue once and exit")]
+        once: bool,
+
+        /// Seconds between scans
+        #[arg(
+            long,
+            value_name = "SECONDS",
+            default_value_t = 30,
+            help = "Seconds between scans of the queue"
+        )]
+        interval: u64,
+    },
+
+    /// Print completion candidates for editors and shells (`value<TAB>description`)
+    #[command(name = "__complete", hide = true)]
+    Complete {
+        /// What to complete
+        #[arg(value_enum)]
+        kind: CompleteKind,
+
+        /// Prefix to match (ID prefix or title word prefix for ids)
+        #[arg(default_value = "")]
+        prefix: String,
+
+        /// Include closed/archived items
+        #[arg(long)]
+        closed: bool,
+
+        /// Maximum number of candidates
+        #[arg(long, default_value_t = commands::complete::DEFAULT_LIMIT)]
+        limit: usize,
+    },
+
+    /// Generate shell completion scripts
+    #[command(
+        long_about = "Generate shell completion scripts for various shells.\n\n\
 Outputs the completion script to stdout. Redirect to a file or source directly \
 in your shell configuration.\n\n\
 For automatic installation, use 'qs setup' instead.",
@@ -590,6 +2558,183 @@ For automatic installation, use 'qs setup' instead.",
     },
 }
 
+/// Subcommands for the hooks command
+#[derive(Subcommand)]
+enum HooksAction {
+    /// Install the prepare-commit-msg hook into the current git repository
+    Install {
+        /// Overwrite an existing hook
+        #[arg(long, help = "Replace an existing hook not installed by qs")]
+        force: bool,
+    },
+}
+
+/// Subcommands for the rules command
+#[derive(Subcommand)]
+enum RulesAction {
+    /// Apply rules to one item or all open items
+    #[command(group = ArgGroup::new("target").required(true))]
+    Apply {
+        /// Item ID (partial match supported)
+        #[arg(long, group = "target", help = "Item ID (partial match supported)")]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(long, group = "target", help = "Item file path")]
+        file: Option<std::path::PathBuf>,
+
+        /// Apply to every open item
+        #[arg(long, group = "target", help = "Apply to every open item")]
+        all: bool,
+
+        /// Apply to archived items too
+        #[arg(long, help = "Apply rules to the item even if it is archived")]
+        force: bool,
+    },
+}
+
+/// Subcommands for the draft command
+#[derive(Subcommand)]
+enum DraftAction {
+    /// List the project's drafts, most recently edited first
+    List,
+    /// Write a draft into its item and remove the draft
+    Apply {
+        /// Item ID (partial match supported)
+        #[arg(long, help = "Item ID (partial match supported)")]
+        id: String,
+
+        /// Overwrite a body that changed since the draft was started
+        #[arg(
+            long,
+            help = "Apply even if the item's body changed since the draft was started"
+        )]
+        force: bool,
+    },
+    /// Delete a draft without applying it
+    Discard {
+        /// Item ID (partial match supported)
+        #[arg(long, help = "Item ID (partial match supported)")]
+        id: String,
+    },
+}
+
+/// Subcommands for the trash command
+#[derive(Subcommand)]
+enum TrashAction {
+    /// List deleted items, most recent first
+    List,
+    /// Restore a deleted item to its original location
+    Restore {
+        /// Item ID (partial match supported)
+        id: String,
+    },
+}
+
+/// Subcommands for the bulk command
+#[derive(Subcommand)]
+enum BulkAction {
+    /// Write the frontmatter of matching items to a YAML file
+    Export {
+        /// Export only closed items
+        #[arg(
+            long,
+            conflicts_with = "all",
+            help = "Export only closed/archived items"
+        )]
+        closed: bool,
+
+        /// Export open and closed items
+        #[arg(long, conflicts_with = "closed", help = "Export open and closed items")]
+        all: bool,
+
+        /// Filter by label (can be specified multiple times for AND logic)
+        #[arg(
+            long,
+            value_name = "LABEL",
+            help = "Only items with all of these labels (repeatable)"
+        )]
+        label: Vec<String>,
+
+        /// Filter by any of several labels (OR logic)
+        #[arg(
+            long,
+            value_name = "LABEL",
+            help = "Only items with at least one of these labels (repeatable)"
+        )]
+        any_label: Vec<String>,
+
+        /// Exclude labels
+        #[arg(
+            long,
+            value_name = "LABEL",
+            help = "Exclude items with any of these labels (repeatable)"
+        )]
+        not_label: Vec<String>,
+
+        /// Filter by author
+        #[arg(long, help = "Filter items by author name (substring match)")]
+        author: Option<String>,
+
+        /// Filter by category
+        #[arg(long, help = "Filter items by category")]
+        category: Option<String>,
+
+        /// Output file
+        #[arg(
+            short,
+            long,
+            value_name = "PATH",
+            help = "Write to a file instead of stdout"
+        )]
+        output: Option<std::path::PathBuf>,
+
+        /// Export protected items too
+        #[arg(long, help = "Export even if protected items are included")]
+        force: bool,
+    },
+    /// Validate an edited file and apply its changes
+    Apply {
+        /// File written by 'qs bulk export'
+        file: std::path::PathBuf,
+
+        /// Show the changes without applying them
+        #[arg(long, help = "Show the changes without applying them")]
+        dry_run: bool,
+
+        /// Edit archived items too
+        #[arg(long, help = "Apply changes to archived items too")]
+        force: bool,
+    },
+}
+
+/// Subcommands for the mirror command
+#[derive(Subcommand)]
+enum MirrorAction {
+    /// List configured mirrors with their source and state
+    List,
+    /// Clone or update git mirrors (shallow)
+    Fetch {
+        /// Mirror name (all git mirrors if omitted)
+        name: Option<String>,
+    },
+}
+
+/// Subcommands for the sync command
+#[derive(Subcommand)]
+enum SyncTarget {
+    /// Create, update, and close GitHub issues and items
+    Github {
+        /// Print what would change without touching GitHub or items
+        #[arg(long, help = "Show what would change without syncing")]
+        dry_run: bool,
+
+        /// Sync protected items too
+        #[arg(long, help = "Publish protected items to GitHub too")]
+        force: bool,
+    },
+}
+
 /// Subcommands for the attachments command
 #[derive(Subcommand)]
 enum AttachmentsAction {
@@ -599,8 +2744,13 @@ enum AttachmentsAction {
             h!("Examples:"), "\n  ",
             c!("qs attachments add --id "), a!("260109-0A2B3C4"), " ", a!("screenshot.png"), "\n  ",
             c!("qs attachments add --id "), a!("260109-0A2B3C4"), " ", a!("file1.txt file2.txt"), "\n  ",
-            c!("qs attachments add --id "), a!("260109-0A2B3C4"), " ", a!("https://github.com/issue/42"), "\n\n",
-            h!("Note:"), " Files are copied to the item directory. URLs are stored as references."
+            c!("qs attachments add --id "), a!("260109-0A2B3C4"), " ", a!("https://github.com/issue/42"), "\n  ",
+            c!("qs attachments add --id "), a!("260109-0A2B3C4"), " ", a!("'logs/*.log'"), "\n  ",
+            c!("qs attachments add --id "), a!("260109-0A2B3C4"), " ", a!("crash-dump/"), "\n\n",
+            h!("Note:"), " Files are copied to the item directory. URLs are stored as references. \
+Patterns (*, ?, [abc], **) attach every matching file; directories attach each file below \
+them, or one .tgz archive with attachment_directories = \"archive\". Hidden files are skipped. \
+More than 20 files ask for confirmation in a terminal."
         ),
         group = ArgGroup::new("item_ref").required(true)
     )]
@@ -623,9 +2773,15 @@ enum AttachmentsAction {
         )]
         file: Option<std::path::PathBuf>,
 
-        /// Files or URLs to attach
-        #[arg(required = true, help = "Files or URLs to attach")]
+        /// Files, directories, glob patterns, or URLs to attach
+        #[arg(
+            required = true,
+            help = "Files, directories, glob patterns, or URLs to attach"
+        )]
         sources: Vec<String>,
+        /// Attach to archived items
+        #[arg(long, help = "Attach even if the item is closed or archived")]
+        force: bool,
     },
 
     /// Remove attachments from an item by index
@@ -634,7 +2790,8 @@ enum AttachmentsAction {
             h!("Examples:"), "\n  ",
             c!("qs attachments remove --id "), a!("260109-0A2B3C4"), " ", a!("1"), "\n  ",
             c!("qs attachments remove --id "), a!("260109-0A2B3C4"), " ", a!("1 2 3"), "    Remove multiple\n\n",
-            h!("Note:"), " Use ", c!("qs list --attachments --id <ID>"), " to see indices."
+            h!("Note:"), " Use ", c!("qs list --attachments --id <ID>"), " to see indices. Items in protected \
+categories or with protected labels need --force."
         ),
         group = ArgGroup::new("item_ref").required(true)
     )]
@@ -660,9 +2817,142 @@ enum AttachmentsAction {
         /// Attachment indices to remove (1-based)
         #[arg(required = true, help = "Attachment indices to remove (1-based)")]
         indices: Vec<usize>,
+
+        /// Remove even if the item is protected
+        #[arg(long, help = "Remove even if the item is protected")]
+        force: bool,
+    },
+
+    /// Open an attachment with the default application
+    #[command(
+        long_about = "Open an attachment with the system's default application.\n\n\
+Files open from the item's attachment directory (or the external attachment_path), \
+URLs open in the browser, and s3:// attachments open through a presigned URL that \
+is valid for one hour.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs attachments open --id "), a!("260109-0A2B3C4"), " ", a!("1"), "\n  ",
+            c!("qs attachments open --id "), a!("260109"), " ", a!("2"), c!(" --print"), "    Print the path or URL\n\n",
+            h!("Note:"), " Use ", c!("qs list --attachments --id <ID>"), " to see indices."
+        ),
+        group = ArgGroup::new("item_ref").required(true)
+    )]
+    Open {
+        /// Item ID (partial match supported)
+        #[arg(
+            long,
+            conflicts_with = "file",
+            group = "item_ref",
+            help = "Item ID (partial match supported)"
+        )]
+        id: Option<String>,
+
+        /// Item file path (alternative to --id)
+        #[arg(
+            long,
+            conflicts_with = "id",
+            group = "item_ref",
+            help = "Item file path"
+        )]
+        file: Option<std::path::PathBuf>,
+
+        /// Attachment index (1-based)
+        #[arg(help = "Attachment index to open (1-based)")]
+        index: usize,
+
+        /// Print instead of opening
+        #[arg(long, help = "Print the resolved path or URL instead of opening it")]
+        print: bool,
+    },
+
+    /// List files attached to more than one item
+    #[command(
+        long_about = "List file attachments whose content is identical across items, grouped by \
+SHA-256 checksum.\n\n\
+Checksums are recorded in each item's frontmatter when files are attached; older \
+attachments are hashed from disk. With --link, duplicates are stored once in a shared, \
+git-ignored store and hard-linked into each attachment directory.",
+        after_help = concat!(
+            h!("Examples:"), "\n  ",
+            c!("qs attachments duplicates"), "\n  ",
+            c!("qs attachments duplicates --link"), "    Store duplicates once"
+        )
+    )]
+    Duplicates {
+        /// Hard-link duplicates into the shared store
+        #[arg(long, help = "Hard-link duplicates into the shared store")]
+        link: bool,
     },
 }
 
+/// Returns the command line with a configured alias expanded.
+fn expand_aliases() -> Result<Vec<std::ffi::OsString>> {
+    let command = Cli::command();
+    let builtins: Vec<&str> = command
+        .get_subcommands()
+        .flat_map(|sub| std::iter::once(sub.get_name()).chain(sub.get_all_aliases()))
+        .collect();
+    queuestack::config::alias::expand(std::env::args_os().collect(), &builtins)
+}
+
+/// Subcommand path of a parsed command line (e.g. `attachments add`).
+fn command_path(matches: &clap::ArgMatches) -> String {
+    let mut names = Vec::new();
+    let mut current = matches;
+    while let Some((name, sub)) = current.subcommand() {
+        names.push(name);
+        current = sub;
+    }
+    names.join(" ")
+}
+
+/// Resolves the scope of list and search. The hidden `--closed` shorthand
+/// selects the archive; when it comes from `[defaults]`, a `--scope` given on
+/// the command line wins (both can't be given there).
+fn resolve_scope(matches: &clap::ArgMatches, scope: Scope, closed: bool) -> Scope {
+    let scope_given = matches
+        .subcommand()
+        .and_then(|(_, sub)| sub.value_source("scope"))
+        == Some(ValueSource::CommandLine);
+    if closed && !scope_given {
+        Scope::Archive
+    } else {
+        scope
+    }
+}
+
+/// Prints a help topic, the help of the named command, or the general help
+/// followed by the topic list.
+fn execute_help(topic: Vec<String>, search: Option<String>) -> Result<()> {
+    let is_command = topic
+        .first()
+        .is_some_and(|name| queuestack::help::find(name).is_none());
+    if is_command {
+        let args = std::iter::once("qs".to_string())
+            .chain(topic.iter().cloned())
+            .chain(["--help".to_string()]);
+        return match Cli::try_parse_from(args) {
+            Err(err) if err.kind() == clap::error::ErrorKind::DisplayHelp => {
+                err.print()?;
+                Ok(())
+            }
+            _ => anyhow::bail!(
+                "Unknown help topic or command '{}'. Topics: {}",
+                topic.join(" "),
+                commands::help::topic_names()
+            ),
+        };
+    }
+    if topic.is_empty() && search.is_none() {
+        Cli::command().print_help()?;
+        println!();
+    }
+    commands::help(&HelpArgs {
+        topic: topic.into_iter().next(),
+        search,
+    })
+}
+
 fn main() {
     if let Err(err) = run() {
         eprintln!("{} {err:#}", "error:".red().bold());
@@ -672,24 +2962,48 @@ fn main() {
 
 #[allow(clippy::too_many_lines)]
 fn run() -> Result<()> {
-    let cli = Cli::parse();
+    let command = defaults::apply(Cli::command(), &defaults::load())?;
+    let matches = command.get_matches_from(expand_aliases()?);
+    let cli = Cli::from_arg_matches(&matches)?;
+    let command = command_path(&matches);
+    // Editors run completions on every keystroke, and reading the summary
+    // shouldn't change it
+    if !matches!(command.as_str(), "__complete" | "insights") {
+        queuestack::insights::record(&command);
+    }
+    queuestack::config::set_profile_override(cli.profile);
+    queuestack::config::set_project_root_override(cli.project_root);
+    queuestack::config::set_picker_override(cli.picker);
 
     match cli.command {
-        Commands::Init => commands::init(),
+        Commands::Init {
+            gitattributes,
+            gitignore,
+            lfs,
+        } => commands::init_with(&InitArgs {
+            gitattributes,
+            gitignore,
+            lfs,
+        }),
 
         Commands::New {
             title,
             label,
             category,
+            due,
+            assignee,
             attachment,
             interactive,
             no_interactive,
             as_template,
             from_template,
+            vars,
         } => commands::new(NewArgs {
             title,
             labels: label,
             category,
+            due,
+            assignee,
             attachments: attachment,
             interactive: InteractiveArgs {
                 interactive,
@@ -697,26 +3011,71 @@ fn run() -> Result<()> {
             },
             as_template,
             from_template,
+            vars,
         }),
 
+        Commands::Push {
+            words,
+            interactive,
+            no_interactive,
+        } => {
+            let entry = queuestack::item::quick::parse(&words)?;
+            commands::new(NewArgs {
+                title: Some(entry.title),
+                labels: entry.labels,
+                category: entry.category,
+                due: None,
+                assignee: None,
+                attachments: Vec::new(),
+                interactive: InteractiveArgs {
+                    interactive,
+                    no_interactive,
+                },
+                as_template: false,
+                from_template: None,
+                vars: Vec::new(),
+            })
+        }
+
         Commands::List {
+            scope,
             open: _,
             closed,
             label,
+            any_label,
+            not_label,
             author,
+            assignee,
+            mine,
             filter_category,
+            since,
+            until,
+            due_after,
+            due_before,
+            overdue,
+            blocked,
+            unblocked,
             sort,
+            group_by,
+            summary,
+            verbose,
             interactive,
             no_interactive,
+            print_selection,
+            tree,
             labels,
             categories,
             attachments,
             meta,
             templates,
+            no_mirrors,
             id,
             file,
+            format,
         } => {
-            let mode = if labels {
+            let mode = if tree {
+                ListMode::Tree
+            } else if labels {
                 ListMode::Labels
             } else if categories {
                 ListMode::Categories
@@ -729,43 +3088,103 @@ fn run() -> Result<()> {
             } else {
                 ListMode::Items
             };
-            let status = if closed {
-                StatusFilter::Closed
-            } else {
-                StatusFilter::Open // Default to open
-            };
             commands::list(&ListOptions {
                 mode,
-                status,
+                scope: resolve_scope(&matches, scope, closed),
                 labels: label,
+                any_labels: any_label,
+                not_labels: not_label,
                 author,
+                assignee,
+                mine,
                 category: filter_category,
+                since,
+                until,
+                due_after,
+                due_before,
+                overdue,
+                blocked: (blocked || unblocked).then_some(blocked),
                 sort,
+                group_by,
+                summary,
+                verbose,
+                print_selection,
                 interactive: InteractiveArgs {
                     interactive,
                     no_interactive,
                 },
                 id,
                 file,
+                mirrors: !no_mirrors,
+                format,
             })
         }
 
         Commands::Search {
             query,
             full_text,
+            include_large,
             interactive,
             no_interactive,
+            print_selection,
+            scope,
             closed,
+            label,
+            any_label,
+            not_label,
+            verbose,
+            no_mirrors,
+            format,
         } => commands::search(&SearchArgs {
             query,
             full_text,
+            include_large,
+            interactive: InteractiveArgs {
+                interactive,
+                no_interactive,
+            },
+            scope: resolve_scope(&matches, scope, closed),
+            labels: label,
+            any_labels: any_label,
+            not_labels: not_label,
+            verbose,
+            mirrors: !no_mirrors,
+            print_selection,
+            format,
+        }),
+
+        Commands::Show {
+            id,
+            file,
+            brief,
+            raw,
+            format,
+        } => commands::show(&ShowArgs {
+            id,
+            file,
+            brief,
+            raw,
+            format,
+        }),
+
+        Commands::Url { id, file } => commands::url(&UrlArgs { id, file }),
+
+        Commands::Copy { id, file, what } => commands::copy(&CopyArgs { id, file, what }),
+
+        Commands::Resolve {
+            url,
+            interactive,
+            no_interactive,
+        } => commands::resolve(&ResolveArgs {
+            url,
             interactive: InteractiveArgs {
                 interactive,
                 no_interactive,
             },
-            closed,
         }),
 
+        Commands::Run { id, file, action } => commands::run(&RunArgs { id, file, action }),
+
         Commands::Update {
             id,
             file,
@@ -774,34 +3193,427 @@ fn run() -> Result<()> {
             remove_label,
             category,
             remove_category,
-        } => commands::update(UpdateArgs {
+            due,
+            remove_due,
+            assignee,
+            remove_assignee,
+            force,
+            dry_run,
+            format,
+            quiet,
+        } => {
+            let args = UpdateArgs {
+                id,
+                file,
+                title,
+                labels: label,
+                remove_labels: remove_label,
+                category,
+                remove_category,
+                due,
+                remove_due,
+                assignee,
+                remove_assignee,
+                force,
+                format,
+                quiet,
+            };
+            if dry_run {
+                commands::plan_update(args)?.print(format)
+            } else {
+                commands::update(args)
+            }
+        }
+
+        Commands::Patch {
             id,
             file,
-            title,
+            set,
+            patch_file,
+            force,
+        } => commands::patch(&PatchArgs {
+            id,
+            file,
+            set,
+            patch_file,
+            force,
+        }),
+
+        Commands::Diff { id, file, since } => commands::diff(&DiffArgs { id, file, since }),
+
+        Commands::Draft { action, id, file } => match action {
+            None => commands::draft(&DraftArgs { id, file }),
+            Some(DraftAction::List) => commands::draft_list(),
+            Some(DraftAction::Apply { id, force }) => {
+                commands::draft_apply(&DraftApplyArgs { id, force })
+            }
+            Some(DraftAction::Discard { id }) => commands::draft_discard(&DraftDiscardArgs { id }),
+        },
+
+        Commands::Pop {
+            fifo: _,
+            lifo,
+            label,
+            category,
+            format,
+        } => commands::pop(&PopArgs {
+            order: if lifo { PopOrder::Lifo } else { PopOrder::Fifo },
             labels: label,
-            remove_labels: remove_label,
             category,
-            remove_category,
+            format,
         }),
 
-        Commands::Close { id, file } => commands::execute_close(id, file),
+        Commands::Close {
+            id,
+            file,
+            category,
+            dry_run,
+            format,
+        } => {
+            if dry_run {
+                commands::plan_close(id, file, category.as_deref())?.print(format)
+            } else {
+                commands::execute_close(id, file, category.as_deref())
+            }
+        }
 
-        Commands::Reopen { id, file } => commands::execute_reopen(id, file),
+        Commands::Reopen {
+            id,
+            file,
+            to_category,
+            dry_run,
+            format,
+        } => {
+            if dry_run {
+                commands::plan_reopen(id, file, to_category.as_deref())?.print(format)
+            } else {
+                commands::execute_reopen(id, file, to_category.as_deref())
+            }
+        }
 
         Commands::Attachments { action } => match action {
-            AttachmentsAction::Add { id, file, sources } => {
-                commands::attach_add(&AttachAddArgs { id, file, sources })
+            AttachmentsAction::Add {
+                id,
+                file,
+                sources,
+                force,
+            } => commands::attach_add(&AttachAddArgs {
+                id,
+                file,
+                sources,
+                force,
+            }),
+            AttachmentsAction::Remove {
+                id,
+                file,
+                indices,
+                force,
+            } => commands::attach_remove(&AttachRemoveArgs {
+                id,
+                file,
+                indices,
+                force,
+            }),
+            AttachmentsAction::Open {
+                id,
+                file,
+                index,
+                print,
+            } => commands::attach_open(&AttachOpenArgs {
+                id,
+                file,
+                index,
+                print,
+            }),
+            AttachmentsAction::Duplicates { link } => {
+                commands::attach_duplicates(&AttachDuplicatesArgs { link })
+            }
+        },
+
+        Commands::CommitTemplate { id, file } => {
+            commands::commit_template(&CommitTemplateArgs { id, file })
+        }
+
+        Commands::Export {
+            format,
+            closed,
+            all,
+            output,
+            force,
+        } => {
+            let status = if all {
+                StatusFilter::All
+            } else if closed {
+                StatusFilter::Closed
+            } else {
+                StatusFilter::Open
+            };
+            commands::export(&ExportArgs {
+                format,
+                status,
+                output,
+                force,
+            })
+        }
+
+        Commands::Graph {
+            format,
+            milestone,
+            category,
+            output,
+            force,
+            tui,
+        } => commands::graph(&GraphArgs {
+            format,
+            milestone,
+            category,
+            output,
+            force,
+            tui,
+        }),
+
+        Commands::Link {
+            id,
+            file,
+            blocks,
+            depends_on,
+            remove,
+            force,
+        } => commands::link(LinkArgs {
+            id,
+            file,
+            blocks,
+            depends_on,
+            remove,
+            force,
+        }),
+
+        Commands::Triage {
+            edit,
+            apply,
+            label,
+            any_label,
+            not_label,
+            author,
+            category,
+        } => commands::triage(&TriageArgs {
+            labels: label,
+            any_labels: any_label,
+            not_labels: not_label,
+            author,
+            category,
+            edit,
+            apply,
+        }),
+
+        Commands::Bulk { action } => match action {
+            BulkAction::Export {
+                closed,
+                all,
+                label,
+                any_label,
+                not_label,
+                author,
+                category,
+                output,
+                force,
+            } => {
+                let status = if all {
+                    StatusFilter::All
+                } else if closed {
+                    StatusFilter::Closed
+                } else {
+                    StatusFilter::Open
+                };
+                commands::bulk_export(&BulkExportArgs {
+                    status,
+                    labels: label,
+                    any_labels: any_label,
+                    not_labels: not_label,
+                    author,
+                    category,
+                    output,
+                    force,
+                })
+            }
+            BulkAction::Apply {
+                file,
+                dry_run,
+                force,
+            } => commands::bulk_apply(&BulkApplyArgs {
+                file,
+                dry_run,
+                force,
+            }),
+        },
+
+        Commands::Rules { action } => match action {
+            RulesAction::Apply {
+                id,
+                file,
+                all,
+                force,
+            } => commands::rules_apply(&RulesApplyArgs {
+                id,
+                file,
+                all,
+                force,
+            }),
+        },
+
+        Commands::Comment {
+            id,
+            file,
+            text,
+            force,
+        } => commands::comment(CommentArgs {
+            id,
+            file,
+            text,
+            force,
+        }),
+        Commands::Comments { id, file, format } => {
+            commands::comments(CommentsArgs { id, file, format })
+        }
+
+        Commands::Delete {
+            id,
+            file,
+            force,
+            dry_run,
+            format,
+        } => {
+            let args = DeleteArgs { id, file, force };
+            if dry_run {
+                commands::plan_delete(args)?.print(format)
+            } else {
+                commands::delete(args)
             }
-            AttachmentsAction::Remove { id, file, indices } => {
-                commands::attach_remove(&AttachRemoveArgs { id, file, indices })
+        }
+
+        Commands::Trash { action } => match action {
+            TrashAction::List => commands::trash_list(),
+            TrashAction::Restore { id } => commands::trash_restore(&TrashRestoreArgs { id }),
+        },
+
+        Commands::Clean { dry_run } => commands::clean(&CleanArgs { dry_run }),
+
+        Commands::Validate { fix } => commands::validate(&ValidateArgs { fix }),
+
+        Commands::Mirror { action } => match action {
+            MirrorAction::List => commands::mirror_list(),
+            MirrorAction::Fetch { name } => commands::mirror_fetch(&MirrorFetchArgs { name }),
+        },
+
+        Commands::Sync { target } => match target {
+            SyncTarget::Github { dry_run, force } => {
+                commands::sync_github(&SyncGithubArgs { dry_run, force })
             }
         },
 
-        Commands::Setup { shell } => {
+        Commands::Activity {
+            since,
+            limit,
+            follow,
+        } => commands::activity(&ActivityArgs {
+            since,
+            limit: if since.is_some() { 0 } else { limit },
+            follow,
+        }),
+
+        Commands::Du {
+            threshold,
+            limit,
+            open,
+            format,
+        } => commands::du(&DuArgs {
+            threshold,
+            limit,
+            open,
+            format,
+        }),
+
+        Commands::Stats { author, format } => commands::stats(&StatsArgs {
+            by_author: author,
+            format,
+        }),
+
+        Commands::Bench {
+            runs,
+            save,
+            compare,
+            threshold,
+        } => commands::bench(&BenchArgs {
+            runs,
+            save,
+            compare,
+            threshold,
+        }),
+
+        Commands::Insights { days, clear } => commands::insights(&InsightsArgs { days, clear }),
+
+        Commands::Agent {
+            label,
+            category,
+            once,
+            interval,
+        } => commands::agent(&AgentArgs {
+            labels: label,
+            category,
+            once,
+            interval,
+        }),
+
+        Commands::Which => commands::which(),
+
+        Commands::Info => commands::info(),
+
+        Commands::Tour => commands::tour(),
+
+        Commands::Help { topic, search } => execute_help(topic, search),
+
+        Commands::Hooks { action } => match action {
+            HooksAction::Install { force } => commands::hooks_install(&HooksInstallArgs { force }),
+        },
+
+        Commands::MergeDriver {
+            base,
+            ours,
+            theirs,
+            install,
+        } => commands::merge_driver(&MergeDriverArgs {
+            base,
+            ours,
+            theirs,
+            install,
+        }),
+
+        Commands::Setup {
+            shell,
+            system,
+            print_paths,
+        } => {
+            if print_paths {
+                return commands::setup_print_paths(shell, system);
+            }
             let mut cmd = Cli::command();
-            commands::setup(&mut cmd, shell)
+            commands::setup(&mut cmd, shell, system)
         }
 
+        Commands::Daemon { socket } => commands::daemon(&DaemonArgs { socket }),
+
+        Commands::Complete {
+            kind,
+            prefix,
+            closed,
+            limit,
+        } => commands::complete(&CompleteArgs {
+            kind,
+            prefix,
+            closed,
+            limit,
+        }),
+
         Commands::Completions { shell } => {
             let mut cmd = Cli::command();
             commands::completions(shell, &mut cmd)
@@ -0,0 +1,230 @@
+//! # Item Links
+//!
+//! Builds shareable links to items (`qs url`) and finds the item a link points
+//! to (`qs resolve`). Links come from the `link_base` template: `{path}` is
+//! replaced by the item file relative to the project root and `{id}` by the
+//! item ID. Without a template, links point to the file on the web page of the
+//! `origin` remote, or use `qs://{id}` when there is no remote.
+//!
+//! Resolving doesn't depend on the template: the last path component of the
+//! link naming an item file or ID selects the item, so links made with another
+//! base, or before the item was renamed or closed, still resolve.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{fmt::Write, path::Path};
+
+use crate::{config::Config, storage::git};
+
+/// Placeholder for the item file, relative to the project root
+pub const PATH_PLACEHOLDER: &str = "{path}";
+
+/// Placeholder for the item ID
+pub const ID_PLACEHOLDER: &str = "{id}";
+
+/// Link template used outside a repository with a web remote
+pub const SCHEME_BASE: &str = "qs://{id}";
+
+/// Returns the link template: `link_base`, the web page of the `origin`
+/// remote, or [`SCHEME_BASE`].
+pub fn base(config: &Config) -> String {
+    config
+        .link_base()
+        .or_else(|| remote_base(config.project_root()))
+        .unwrap_or_else(|| SCHEME_BASE.to_string())
+}
+
+fn remote_base(root: &Path) -> Option<String> {
+    let remote = git::remote_url(root)?;
+    let branch = git::default_branch(root).unwrap_or_else(|| "HEAD".to_string());
+    let prefix = git::prefix(root).unwrap_or_default();
+    web_base(&remote, &branch, &prefix)
+}
+
+/// Returns the link to an item file in the project.
+pub fn link(config: &Config, id: &str, path: &Path) -> String {
+    let relative = config
+        .relative_path(path)
+        .iter()
+        .map(|component| component.to_string_lossy().into_owned())
+        .collect::<Vec<_>>()
+        .join("/");
+    render(&base(config), id, &relative)
+}
+
+/// Fills in a link template; the ID is appended to a template without placeholders.
+pub fn render(base: &str, id: &str, path: &str) -> String {
+    if !base.contains(PATH_PLACEHOLDER) && !base.contains(ID_PLACEHOLDER) {
+        return format!("{base}{}", encode(id));
+    }
+    base.replace(ID_PLACEHOLDER, &encode(id))
+        .replace(PATH_PLACEHOLDER, &encode(path))
+}
+
+/// Turns a git remote URL into the template for file links on its web page.
+///
+/// Handles `https://host/org/repo.git`, `ssh://git@host:port/org/repo.git`,
+/// and `git@host:org/repo.git`; `prefix` is the project root's path in the
+/// repository. Local remotes have no web page and give `None`.
+pub fn web_base(remote: &str, branch: &str, prefix: &str) -> Option<String> {
+    let remote = remote.trim().trim_end_matches('/');
+    let address = match remote.split_once("://") {
+        Some(("https" | "http" | "ssh" | "git", rest)) => rest.to_string(),
+        Some(_) => return None,
+        // scp-like syntax: git@host:org/repo.git
+        None => {
+            let (host, path) = remote.split_once(':')?;
+            format!("{host}/{path}")
+        }
+    };
+
+    let (host, path) = address.split_once('/')?;
+    let host = host.rsplit('@').next()?.split(':').next()?;
+    let repo = path.strip_suffix(".git").unwrap_or(path).trim_matches('/');
+    if host.is_empty() || repo.is_empty() || !host.contains('.') {
+        return None;
+    }
+
+    let tree = if host.contains("gitlab") {
+        "-/blob"
+    } else if host.contains("bitbucket") {
+        "src"
+    } else {
+        "blob"
+    };
+    Some(format!(
+        "https://{host}/{repo}/{tree}/{}/{}{PATH_PLACEHOLDER}",
+        encode(branch),
+        encode(prefix)
+    ))
+}
+
+/// Finds which of `ids` a link points to.
+///
+/// Path components are tried from the end; a component matches an ID when it
+/// is the ID or an item file name (`<id>-<slug>.md`), ignoring case.
+pub fn find<'a>(link: &str, ids: &[&'a str]) -> Option<&'a str> {
+    let link = decode(link.trim());
+    link.split(['/', '?', '#', '&', '='])
+        .rev()
+        .find_map(|component| {
+            let stem = component.strip_suffix(".md").unwrap_or(component);
+            ids.iter()
+                .copied()
+                .filter(|id| names(stem, id))
+                .max_by_key(|id| id.len())
+        })
+}
+
+/// Returns true if a file stem is the ID or starts with `<id>-`.
+fn names(stem: &str, id: &str) -> bool {
+    let (Some(head), Some(rest)) = (stem.get(..id.len()), stem.get(id.len()..)) else {
+        return false;
+    };
+    !id.is_empty() && head.eq_ignore_ascii_case(id) && (rest.is_empty() || rest.starts_with('-'))
+}
+
+/// Percent-encodes everything but unreserved characters and `/`.
+fn encode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~/".contains(&byte) {
+            out.push(char::from(byte));
+        } else {
+            let _ = write!(out, "%{byte:02X}");
+        }
+    }
+    out
+}
+
+/// Decodes percent-encoded bytes; invalid escapes are kept as they are.
+fn decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = (bytes[index] == b'%')
+            .then(|| text.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        if let Some(byte) = escaped {
+            out.push(byte);
+            index += 3;
+        } else {
+            out.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_web_base_from_remotes() {
+        let github = "https://github.com/org/repo/blob/main/{path}";
+        assert_eq!(
+            web_base("git@github.com:org/repo.git", "main", "").unwrap(),
+            github
+        );
+        assert_eq!(
+            web_base("https://github.com/org/repo.git", "main", "").unwrap(),
+            github
+        );
+        assert_eq!(
+            web_base("ssh://git@github.com:22/org/repo", "main", "").unwrap(),
+            github
+        );
+        assert_eq!(
+            web_base("https://gitlab.com/group/sub/repo.git", "dev", "app/").unwrap(),
+            "https://gitlab.com/group/sub/repo/-/blob/dev/app/{path}"
+        );
+        assert_eq!(web_base("/srv/git/repo.git", "main", ""), None);
+        assert_eq!(web_base("file:///srv/git/repo.git", "main", ""), None);
+    }
+
+    #[test]
+    fn test_render_encodes() {
+        assert_eq!(
+            render(
+                "https://host/blob/main/{path}",
+                "260109-ABC",
+                "queuestack/bugs/260109-ABC-größe ändern.md"
+            ),
+            "https://host/blob/main/queuestack/bugs/260109-ABC-gr%C3%B6%C3%9Fe%20%C3%A4ndern.md"
+        );
+        assert_eq!(render(SCHEME_BASE, "260109-ABC", "x"), "qs://260109-ABC");
+        assert_eq!(
+            render("https://wiki/items/", "260109-ABC", "x"),
+            "https://wiki/items/260109-ABC"
+        );
+    }
+
+    #[test]
+    fn test_find() {
+        let ids = ["260109-ABC", "260109-ABCD", "260110-XYZ"];
+        assert_eq!(
+            find(
+                "https://github.com/org/repo/blob/main/queuestack/260109-ABC-fix-login.md",
+                &ids
+            ),
+            Some("260109-ABC")
+        );
+        assert_eq!(find("qs://260109-abcd", &ids), Some("260109-ABCD"));
+        assert_eq!(
+            find(
+                "https://host/blob/main/queuestack/260110-XYZ-gr%C3%B6%C3%9Fe.md#L3",
+                &ids
+            ),
+            Some("260110-XYZ")
+        );
+        assert_eq!(
+            find("https://host/items?id=260110-XYZ", &ids),
+            Some("260110-XYZ")
+        );
+        assert_eq!(find("https://host/260109-AB", &ids), None);
+    }
+}
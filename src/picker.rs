@@ -0,0 +1,169 @@
+//! # External Picker
+//!
+//! Delegates item selection to an external fuzzy finder such as fzf or skim,
+//! configured with `picker` or `--picker`. The options are written to the
+//! picker's stdin, one per line, and the line it prints back is the choice.
+//!
+//! The picker runs through the shell. `{prompt}` and `{header}` in the command
+//! are replaced by the selector prompt and column header, shell-quoted.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{fmt, io::Write, process::Stdio, thread};
+
+use anyhow::{bail, Context, Result};
+
+use crate::item::action::{quote, shell};
+
+/// Placeholder for the selector prompt
+pub const PROMPT_PLACEHOLDER: &str = "{prompt}";
+
+/// Placeholder for the column header line
+pub const HEADER_PLACEHOLDER: &str = "{header}";
+
+/// Command used for `picker = "fzf"` or `picker = "external"`
+pub const FZF_COMMAND: &str = "fzf --no-sort --prompt {prompt} --header {header}";
+
+/// Exit status of a shell that couldn't find the command
+const COMMAND_NOT_FOUND: i32 = 127;
+
+/// How interactive selections are made
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Picker {
+    /// The built-in terminal selector
+    #[default]
+    Builtin,
+    /// An external command, run through the shell
+    External(String),
+}
+
+impl Picker {
+    /// Parses a `picker` setting: `builtin`, `fzf` or `external` for fzf, or a
+    /// command template.
+    pub fn parse(value: &str) -> Self {
+        match value.trim() {
+            "" | "builtin" => Self::Builtin,
+            "fzf" | "external" => Self::External(FZF_COMMAND.to_string()),
+            command => Self::External(command.to_string()),
+        }
+    }
+
+    /// Returns true for the built-in selector.
+    pub const fn is_builtin(&self) -> bool {
+        matches!(self, Self::Builtin)
+    }
+}
+
+impl fmt::Display for Picker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Builtin => f.write_str("builtin"),
+            Self::External(command) => f.write_str(command),
+        }
+    }
+}
+
+/// Substitutes the `{prompt}` and `{header}` placeholders in a picker command.
+pub fn expand(command: &str, prompt: &str, header: &str) -> String {
+    command
+        .replace(PROMPT_PLACEHOLDER, &quote(&format!("{prompt}> ")))
+        .replace(HEADER_PLACEHOLDER, &quote(header))
+}
+
+/// Lets the user pick one of `options` with an external picker command.
+///
+/// Returns `Some(index)` of the chosen option, or `None` if the picker was
+/// cancelled (non-zero exit) or printed nothing.
+pub fn pick<T: ToString>(
+    command: &str,
+    prompt: &str,
+    header: &str,
+    options: &[T],
+) -> Result<Option<usize>> {
+    let lines: Vec<String> = options.iter().map(ToString::to_string).collect();
+    let command = expand(command, prompt, header);
+
+    let mut child = shell(&command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run picker: {command}"))?;
+
+    // Feed the options from a thread so a picker that answers early can't block us
+    let mut stdin = child.stdin.take().context("Picker has no stdin")?;
+    let input = lines.join("\n") + "\n";
+    let writer = thread::spawn(move || {
+        // The picker may exit before reading everything; that's not an error
+        let _ = stdin.write_all(input.as_bytes());
+    });
+    let output = child
+        .wait_with_output()
+        .with_context(|| format!("Failed to run picker: {command}"))?;
+    let _ = writer.join();
+
+    if output.status.code() == Some(COMMAND_NOT_FOUND) {
+        bail!("Picker command not found: {command}");
+    }
+    if !output.status.success() {
+        return Ok(None);
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let Some(choice) = stdout.lines().next().map(str::trim_end) else {
+        return Ok(None);
+    };
+    if choice.is_empty() {
+        return Ok(None);
+    }
+    lines
+        .iter()
+        .position(|line| line.trim_end() == choice)
+        .map(Some)
+        .with_context(|| format!("Picker returned an unknown line: {choice}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Picker::parse("builtin"), Picker::Builtin);
+        assert_eq!(Picker::parse(""), Picker::Builtin);
+        assert_eq!(
+            Picker::parse("external"),
+            Picker::External(FZF_COMMAND.to_string())
+        );
+        assert_eq!(Picker::parse("fzf"), Picker::parse("external"));
+        assert_eq!(
+            Picker::parse("sk --ansi"),
+            Picker::External("sk --ansi".to_string())
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_expand_quotes_placeholders() {
+        assert_eq!(
+            expand(
+                "fzf --prompt {prompt} --header {header}",
+                "Pick",
+                "ID  Title"
+            ),
+            "fzf --prompt 'Pick> ' --header 'ID  Title'"
+        );
+        assert_eq!(expand("sk", "Pick", "ID"), "sk");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_pick_maps_line_to_index() {
+        let options = ["260101-AAA  First", "260102-BBB  Second"];
+        assert_eq!(pick("grep BBB", "Pick", "", &options).unwrap(), Some(1));
+        assert_eq!(pick("grep CCC", "Pick", "", &options).unwrap(), None);
+        assert_eq!(pick("true", "Pick", "", &options).unwrap(), None);
+        assert!(pick("echo unknown", "Pick", "", &options).is_err());
+        assert!(pick("no-such-picker-command", "Pick", "", &options).is_err());
+    }
+}
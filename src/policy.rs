@@ -0,0 +1,624 @@
+//! # Policy Hooks
+//!
+//! Runs config-declared policy scripts (`pre_create`, `pre_close`) before an
+//! operation. A non-zero exit blocks the operation and its stderr is shown.
+//!
+//! Also guards items in protected categories or with protected labels: destructive
+//! operations on them need `--force` unless the current user is on the allow-list.
+//! Archived items are guarded the same way: editing them needs `--force`.
+//! And enforces the attachment policy (size limit, allowed extensions, and an
+//! optional scanner command) before a file is attached, and the label policy
+//! (case, allowed characters, length, and the defined label set) before
+//! labels are written.
+//!
+//! Scripts run from the project root and receive the item through environment
+//! variables: `QS_HOOK`, `QS_ID`, `QS_TITLE`, `QS_AUTHOR`, `QS_STATUS`,
+//! `QS_LABELS` (comma-separated), `QS_CATEGORY`, and `QS_FILE` (when the item
+//! already exists on disk).
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{fmt, path::Path, process::Command};
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::Config,
+    item::{
+        labels::{self, LabelDef},
+        Item, Status,
+    },
+    ui,
+};
+
+/// Operations that can be gated by a policy script
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hook {
+    PreCreate,
+    PreClose,
+}
+
+impl fmt::Display for Hook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PreCreate => write!(f, "pre_create"),
+            Self::PreClose => write!(f, "pre_close"),
+        }
+    }
+}
+
+/// Runs the policy script configured for `hook`, if any.
+///
+/// Returns an error containing the script's stderr when it exits non-zero.
+pub fn check(
+    config: &Config,
+    hook: Hook,
+    item: &Item,
+    category: Option<&str>,
+    path: Option<&Path>,
+) -> Result<()> {
+    let script = match hook {
+        Hook::PreCreate => config.pre_create(),
+        Hook::PreClose => config.pre_close(),
+    };
+    let Some(script) = script else {
+        return Ok(());
+    };
+
+    let mut cmd = script_command(config, &script, &hook.to_string())?;
+    cmd.env("QS_HOOK", hook.to_string())
+        .env("QS_ID", item.id())
+        .env("QS_TITLE", item.title())
+        .env("QS_AUTHOR", item.author())
+        .env("QS_STATUS", item.status().to_string())
+        .env("QS_LABELS", item.labels().join(","))
+        .env("QS_CATEGORY", category.unwrap_or_default());
+    if let Some(path) = path {
+        cmd.env("QS_FILE", path);
+    }
+
+    let output = cmd
+        .output()
+        .with_context(|| format!("Failed to run {hook} policy: {script}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stderr = stderr.trim();
+        if stderr.is_empty() {
+            bail!("{hook} policy rejected '{}' ({})", item.id(), output.status);
+        }
+        bail!("{hook} policy rejected '{}':\n{stderr}", item.id());
+    }
+
+    Ok(())
+}
+
+/// Builds the command for a configured script, run from the project root.
+fn script_command(config: &Config, script: &str, name: &str) -> Result<Command> {
+    let parts =
+        shlex::split(script).with_context(|| format!("Invalid {name} command syntax: {script}"))?;
+    let (program, args) = parts
+        .split_first()
+        .with_context(|| format!("Empty {name} command"))?;
+
+    // Relative script paths are resolved against the project root
+    let program = if program.starts_with("./") || program.starts_with("../") {
+        config.project_root().join(program)
+    } else {
+        program.into()
+    };
+
+    let mut cmd = Command::new(&program);
+    cmd.args(args).current_dir(config.project_root());
+    Ok(cmd)
+}
+
+/// Protected categories and labels (`[protected]` table)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Protected {
+    /// Categories whose items are protected, including subcategories (`security`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<String>,
+
+    /// Labels that protect an item
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+
+    /// Users who may run destructive operations on protected items without `--force`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+}
+
+impl Protected {
+    /// Returns true if nothing is protected and nobody is allowed
+    pub fn is_empty(&self) -> bool {
+        self.categories.is_empty() && self.labels.is_empty() && self.allow.is_empty()
+    }
+
+    /// Returns what protects the item (`category 'security'`, `label 'legal'`), if anything.
+    pub fn reason(&self, item: &Item, category: Option<&str>) -> Option<String> {
+        if let Some(category) = category {
+            let protected = self.categories.iter().find(|protected| {
+                let protected = protected.trim_matches('/');
+                category.eq_ignore_ascii_case(protected)
+                    || category
+                        .get(..=protected.len())
+                        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(&format!("{protected}/")))
+            });
+            if let Some(protected) = protected {
+                return Some(format!("category '{}'", protected.trim_matches('/')));
+            }
+        }
+
+        self.labels
+            .iter()
+            .find(|protected| {
+                item.labels()
+                    .iter()
+                    .any(|l| l.eq_ignore_ascii_case(protected))
+            })
+            .map(|label| format!("label '{label}'"))
+    }
+}
+
+/// Destructive operations guarded by protected categories and labels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Destructive {
+    Delete,
+    RemoveAttachment,
+    Export,
+}
+
+impl fmt::Display for Destructive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Delete => write!(f, "delete"),
+            Self::RemoveAttachment => write!(f, "remove attachments from"),
+            Self::Export => write!(f, "export"),
+        }
+    }
+}
+
+/// Fails if the item is protected, unless `force` is set or the current user
+/// is on the allow-list.
+pub fn check_protected(
+    config: &Config,
+    operation: Destructive,
+    item: &Item,
+    category: Option<&str>,
+    force: bool,
+) -> Result<()> {
+    let protected = config.protected();
+    let Some(reason) = protected.reason(item, category) else {
+        return Ok(());
+    };
+    if force || is_allowed(config, protected) {
+        return Ok(());
+    }
+
+    bail!(
+        "Cannot {operation} '{}': {reason} is protected. Use --force to {operation} it anyway.",
+        item.id()
+    );
+}
+
+/// Returns true if the current user is on the protected allow-list.
+fn is_allowed(config: &Config, protected: &Protected) -> bool {
+    let Some(user) = config.user_name() else {
+        return false;
+    };
+    let identities = config.identities();
+    protected
+        .allow
+        .iter()
+        .any(|name| identities.same(name, &user))
+}
+
+/// Edits refused on archived items without `--force`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edit {
+    Update,
+    Patch,
+    Attach,
+    Bulk,
+    Comment,
+    Link,
+    Rules,
+}
+
+impl fmt::Display for Edit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Update => write!(f, "update"),
+            Self::Patch => write!(f, "patch"),
+            Self::Attach => write!(f, "attach to"),
+            Self::Bulk => write!(f, "edit"),
+            Self::Comment => write!(f, "comment on"),
+            Self::Link => write!(f, "link"),
+            Self::Rules => write!(f, "apply rules to"),
+        }
+    }
+}
+
+/// Fails if the item is archived (closed, or stored in the archive), unless
+/// `force` is set.
+pub fn check_editable(
+    config: &Config,
+    edit: Edit,
+    item: &Item,
+    path: &Path,
+    force: bool,
+) -> Result<()> {
+    let archived = item.status() == Status::Closed || path.starts_with(config.archive_path());
+    if !archived || force {
+        return Ok(());
+    }
+
+    bail!(
+        "Cannot {edit} '{id}': it is archived. Reopen it first with 'qs reopen --id {id}', \
+         or use --force to {edit} it anyway.",
+        id = item.id()
+    );
+}
+
+/// Attachment restrictions (`[attachment_policy]` table)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttachmentPolicy {
+    /// Largest file that may be attached (`500KB`, `10MB`, `1GB`, or plain bytes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<String>,
+
+    /// Allowed file extensions (`png`, `pdf`); any extension when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<String>,
+
+    /// Command run with the file as its last argument; a non-zero exit rejects the file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scanner: Option<String>,
+}
+
+impl AttachmentPolicy {
+    /// Returns true if no restriction is configured
+    pub fn is_empty(&self) -> bool {
+        self.max_size.is_none() && self.extensions.is_empty() && self.scanner.is_none()
+    }
+
+    /// Returns why a file of `size` bytes at `file` violates the extension or
+    /// size limits, if it does.
+    pub fn violation(&self, file: &Path, size: u64) -> Result<Option<String>> {
+        if !self.extensions.is_empty() {
+            let Some(extension) = file.extension().map(|e| e.to_string_lossy()) else {
+                return Ok(Some(format!(
+                    "files without an extension are not allowed (allowed: {})",
+                    self.extensions.join(", ")
+                )));
+            };
+            let allowed = self
+                .extensions
+                .iter()
+                .any(|e| e.trim_start_matches('.').eq_ignore_ascii_case(&extension));
+            if !allowed {
+                return Ok(Some(format!(
+                    "extension '.{extension}' is not allowed (allowed: {})",
+                    self.extensions.join(", ")
+                )));
+            }
+        }
+
+        if let Some(ref max_size) = self.max_size {
+            let limit = ui::parse_size(max_size).with_context(|| {
+                format!(
+                    "Invalid attachment_policy max_size '{max_size}' (expected e.g. 500KB, 10MB)"
+                )
+            })?;
+            if size > limit {
+                return Ok(Some(format!(
+                    "{} exceeds the {max_size} limit",
+                    ui::format_size(size)
+                )));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// Checks a file against the attachment policy before it is attached.
+///
+/// Returns why the file is rejected, if it is. The scanner only runs for files
+/// that pass the extension and size limits.
+pub fn check_attachment(config: &Config, file: &Path) -> Result<Option<String>> {
+    let policy = config.attachment_policy();
+    if policy.is_empty() {
+        return Ok(None);
+    }
+
+    let size = file
+        .metadata()
+        .with_context(|| format!("Failed to read attachment: {}", file.display()))?
+        .len();
+    if let Some(reason) = policy.violation(file, size)? {
+        return Ok(Some(reason));
+    }
+
+    let Some(ref scanner) = policy.scanner else {
+        return Ok(None);
+    };
+    let output = script_command(config, scanner, "scanner")?
+        .arg(file)
+        .output()
+        .with_context(|| format!("Failed to run attachment scanner: {scanner}"))?;
+    if output.status.success() {
+        return Ok(None);
+    }
+
+    // Scanners report findings on stdout (clamscan) or stderr
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let message = stderr
+        .lines()
+        .chain(stdout.lines())
+        .map(str::trim)
+        .find(|line| !line.is_empty());
+    Ok(Some(message.map_or_else(
+        || format!("rejected by scanner ({})", output.status),
+        |message| format!("rejected by scanner: {message}"),
+    )))
+}
+
+/// Case that labels are normalized to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LabelCase {
+    /// Keep labels as written
+    #[default]
+    Preserve,
+    Lower,
+    Upper,
+}
+
+impl LabelCase {
+    /// Returns true for the default, which leaves labels unchanged
+    pub fn is_preserve(&self) -> bool {
+        *self == Self::Preserve
+    }
+}
+
+/// Label restrictions (`[labels]` table)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LabelPolicy {
+    /// Case labels are normalized to when they are written
+    #[serde(default, skip_serializing_if = "LabelCase::is_preserve")]
+    pub case: LabelCase,
+
+    /// Characters labels may consist of, as a regex character class (`a-z0-9-`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub charset: Option<String>,
+
+    /// Longest allowed label, in characters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+
+    /// Only allow the defined labels
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub strict: bool,
+
+    /// Predefined labels with descriptions and colors (`[[labels.defined]]`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub defined: Vec<LabelDef>,
+}
+
+impl LabelPolicy {
+    /// Returns true if no restriction is configured
+    pub fn is_empty(&self) -> bool {
+        self.case.is_preserve()
+            && self.charset.is_none()
+            && self.max_length.is_none()
+            && !self.strict
+            && self.defined.is_empty()
+    }
+
+    /// Returns the label in the configured case, or spelled like its
+    /// definition.
+    pub fn normalize(&self, label: &str) -> String {
+        if let Some(def) = labels::find(&self.defined, label) {
+            return def.name.clone();
+        }
+        match self.case {
+            LabelCase::Preserve => label.to_string(),
+            LabelCase::Lower => label.to_lowercase(),
+            LabelCase::Upper => label.to_uppercase(),
+        }
+    }
+
+    /// Returns why a (normalized) label breaks the charset or length limits,
+    /// or isn't defined while `strict` is set, if it does.
+    pub fn violation(&self, label: &str) -> Result<Option<String>> {
+        if self.strict && labels::find(&self.defined, label).is_none() {
+            return Ok(Some("is not a defined label".to_string()));
+        }
+
+        if let Some(ref charset) = self.charset {
+            let pattern = Regex::new(&format!("^[{charset}]*$")).with_context(|| {
+                format!("Invalid labels charset '{charset}' (expected e.g. a-z0-9-)")
+            })?;
+            if !pattern.is_match(label) {
+                return Ok(Some(format!("contains characters outside [{charset}]")));
+            }
+        }
+
+        if let Some(max_length) = self.max_length {
+            if label.chars().count() > max_length {
+                return Ok(Some(format!("is longer than {max_length} characters")));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Normalizes labels to the configured case, dropping duplicates this
+    /// creates; returns true if anything changed.
+    pub fn normalize_all(&self, labels: &mut Vec<String>) -> bool {
+        let mut normalized: Vec<String> = Vec::with_capacity(labels.len());
+        for label in labels.iter() {
+            let label = self.normalize(label);
+            if !normalized.contains(&label) {
+                normalized.push(label);
+            }
+        }
+        let changed = normalized != *labels;
+        *labels = normalized;
+        changed
+    }
+}
+
+/// Applies the label policy to an item before it is written.
+///
+/// Labels are normalized to the configured case. Labels that weren't in
+/// `before` must also pass the charset and length limits; labels the item
+/// already had are left to `qs validate`, so unrelated edits still work.
+pub fn check_labels(config: &Config, item: &mut Item, before: &[String]) -> Result<()> {
+    let policy = config.label_policy();
+    if policy.is_empty() {
+        return Ok(());
+    }
+
+    policy.normalize_all(&mut item.frontmatter.labels);
+    for label in item.labels() {
+        if before.iter().any(|old| policy.normalize(old) == *label) {
+            continue;
+        }
+        if let Some(reason) = policy.violation(label)? {
+            bail!("Label '{label}' {reason} (see [labels] in the config)");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::item::Frontmatter;
+
+    fn item(labels: &[&str]) -> Item {
+        Item::new(Frontmatter {
+            id: "260101-AAA".to_string(),
+            labels: labels.iter().map(ToString::to_string).collect(),
+            ..Frontmatter::default()
+        })
+    }
+
+    #[test]
+    fn test_protected_categories_include_subcategories() {
+        let protected = Protected {
+            categories: vec!["security/".to_string()],
+            ..Protected::default()
+        };
+        assert_eq!(
+            protected.reason(&item(&[]), Some("security")),
+            Some("category 'security'".to_string())
+        );
+        assert!(protected.reason(&item(&[]), Some("Security/cve")).is_some());
+        assert!(protected
+            .reason(&item(&[]), Some("security-docs"))
+            .is_none());
+        assert!(protected.reason(&item(&[]), None).is_none());
+    }
+
+    #[test]
+    fn test_protected_labels() {
+        let protected = Protected {
+            labels: vec!["legal".to_string()],
+            ..Protected::default()
+        };
+        assert_eq!(
+            protected.reason(&item(&["bug", "Legal"]), None),
+            Some("label 'legal'".to_string())
+        );
+        assert!(protected.reason(&item(&["bug"]), Some("legal")).is_none());
+    }
+
+    #[test]
+    fn test_label_policy() {
+        let policy = LabelPolicy {
+            case: LabelCase::Lower,
+            charset: Some("a-z0-9-".to_string()),
+            max_length: Some(8),
+            ..LabelPolicy::default()
+        };
+
+        let mut labels = vec!["Bug".to_string(), "bug".to_string(), "UI".to_string()];
+        assert!(policy.normalize_all(&mut labels));
+        assert_eq!(labels, ["bug", "ui"]);
+        assert!(!policy.normalize_all(&mut labels));
+
+        assert!(policy.violation("needs-qa").unwrap().is_none());
+        assert_eq!(
+            policy.violation("needs qa").unwrap().as_deref(),
+            Some("contains characters outside [a-z0-9-]")
+        );
+        assert_eq!(
+            policy.violation("regression").unwrap().as_deref(),
+            Some("is longer than 8 characters")
+        );
+
+        let strict = LabelPolicy {
+            strict: true,
+            defined: vec![LabelDef {
+                name: "needs-qa".to_string(),
+                ..LabelDef::default()
+            }],
+            ..LabelPolicy::default()
+        };
+        assert_eq!(strict.normalize("Needs-QA"), "needs-qa");
+        assert!(strict.violation("needs-qa").unwrap().is_none());
+        assert_eq!(
+            strict.violation("qa").unwrap().as_deref(),
+            Some("is not a defined label")
+        );
+
+        let invalid = LabelPolicy {
+            charset: Some("z-a".to_string()),
+            ..LabelPolicy::default()
+        };
+        assert!(invalid.violation("bug").is_err());
+    }
+
+    #[test]
+    fn test_attachment_policy_violations() {
+        let policy = AttachmentPolicy {
+            max_size: Some("1KB".to_string()),
+            extensions: vec!["png".to_string(), ".PDF".to_string()],
+            scanner: None,
+        };
+        assert!(policy
+            .violation(Path::new("shot.PNG"), 1024)
+            .unwrap()
+            .is_none());
+        assert!(policy
+            .violation(Path::new("doc.pdf"), 10)
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            policy.violation(Path::new("app.exe"), 10).unwrap(),
+            Some("extension '.exe' is not allowed (allowed: png, .PDF)".to_string())
+        );
+        assert!(policy
+            .violation(Path::new("Makefile"), 10)
+            .unwrap()
+            .is_some_and(|r| r.starts_with("files without an extension")));
+        assert_eq!(
+            policy.violation(Path::new("shot.png"), 2048).unwrap(),
+            Some("2.0 KB exceeds the 1KB limit".to_string())
+        );
+
+        let invalid = AttachmentPolicy {
+            max_size: Some("huge".to_string()),
+            ..AttachmentPolicy::default()
+        };
+        assert!(invalid.violation(Path::new("a.png"), 1).is_err());
+    }
+}
@@ -0,0 +1,141 @@
+//! # Drafts
+//!
+//! Work-in-progress copies of item bodies for `qs draft`. A draft lives
+//! outside the project, in `~/.config/queuestack/drafts/<project>/`, so
+//! half-finished edits never touch the tracked file until they are applied.
+//!
+//! Each draft is `<id>.md` holding the body being edited, next to
+//! `<id>.base` holding the body it started from. Applying refuses to
+//! overwrite an item whose body changed since the draft was started.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{
+    config::{Config, GlobalConfig},
+    constants::{DRAFTS_DIR, ITEM_FILE_EXTENSION},
+    item::Item,
+};
+
+/// Extension of the file holding the body a draft started from
+const BASE_EXTENSION: &str = "base";
+
+/// A draft of an item body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Draft {
+    /// ID of the drafted item
+    pub id: String,
+    /// Draft file
+    pub path: PathBuf,
+    /// When the draft was last edited
+    pub modified: Option<SystemTime>,
+}
+
+/// Returns the directory holding the project's drafts.
+pub fn dir(config: &Config) -> Result<PathBuf> {
+    let Some(global) = GlobalConfig::dir() else {
+        bail!("Could not determine home directory");
+    };
+    Ok(global.join(DRAFTS_DIR).join(config.project_key()))
+}
+
+/// Returns the draft file for an item ID.
+pub fn path(config: &Config, id: &str) -> Result<PathBuf> {
+    Ok(dir(config)?.join(format!("{id}.{ITEM_FILE_EXTENSION}")))
+}
+
+/// Starts a draft of the item's body, or returns the existing one.
+///
+/// Returns the draft file and whether it was newly created.
+pub fn start(config: &Config, item: &Item) -> Result<(PathBuf, bool)> {
+    let draft = path(config, item.id())?;
+    if draft.exists() {
+        return Ok((draft, false));
+    }
+
+    let parent = draft.parent().unwrap_or_else(|| Path::new("."));
+    fs::create_dir_all(parent)
+        .with_context(|| format!("Failed to create drafts directory: {}", parent.display()))?;
+    fs::write(draft.with_extension(BASE_EXTENSION), &item.body)
+        .with_context(|| format!("Failed to write draft: {}", draft.display()))?;
+    fs::write(&draft, &item.body)
+        .with_context(|| format!("Failed to write draft: {}", draft.display()))?;
+    Ok((draft, true))
+}
+
+/// Replaces the item's body with its draft, saves it, and removes the draft.
+///
+/// Fails if the item has no draft, or if its body changed since the draft
+/// was started (unless `force`).
+pub fn apply(config: &Config, path: &Path, item: &mut Item, force: bool) -> Result<()> {
+    let draft = self::path(config, item.id())?;
+    if !draft.exists() {
+        bail!("Item '{}' has no draft", item.id());
+    }
+    let body = fs::read_to_string(&draft)
+        .with_context(|| format!("Failed to read draft: {}", draft.display()))?;
+
+    if !force {
+        let base = fs::read_to_string(draft.with_extension(BASE_EXTENSION)).ok();
+        if base.as_deref().is_some_and(|base| base != item.body) {
+            bail!(
+                "Item '{}' changed since the draft was started; use --force to overwrite its body",
+                item.id()
+            );
+        }
+    }
+
+    item.body = body;
+    item.save(path)?;
+    remove(&draft)
+}
+
+/// Deletes the draft of an item ID. Returns false if there was none.
+pub fn discard(config: &Config, id: &str) -> Result<bool> {
+    let draft = path(config, id)?;
+    if !draft.exists() {
+        return Ok(false);
+    }
+    remove(&draft)?;
+    Ok(true)
+}
+
+/// Lists the project's drafts, most recently edited first.
+pub fn list(config: &Config) -> Result<Vec<Draft>> {
+    let dir = dir(config)?;
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Ok(Vec::new());
+    };
+
+    let mut drafts: Vec<Draft> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| ext == ITEM_FILE_EXTENSION)
+        })
+        .filter_map(|path| {
+            let id = path.file_stem()?.to_string_lossy().into_owned();
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            Some(Draft { id, path, modified })
+        })
+        .collect();
+    drafts.sort_by(|a, b| b.modified.cmp(&a.modified).then_with(|| a.id.cmp(&b.id)));
+    Ok(drafts)
+}
+
+/// Removes a draft file and its base.
+fn remove(draft: &Path) -> Result<()> {
+    fs::remove_file(draft)
+        .with_context(|| format!("Failed to remove draft: {}", draft.display()))?;
+    let _ = fs::remove_file(draft.with_extension(BASE_EXTENSION));
+    Ok(())
+}
@@ -6,15 +6,32 @@
 //! Licensed under the MIT License.
 
 use std::{
-    path::Path,
+    collections::{BTreeMap, HashSet},
+    path::{Path, PathBuf},
     process::{Command, Stdio},
 };
 
 use anyhow::{Context, Result};
 
+use super::{
+    paths,
+    vcs::{self, rename, Backend, Commit},
+};
+
+/// Creates a git command.
+///
+/// On Windows, enables `core.longpaths` so git can handle files beyond `MAX_PATH`.
+fn git_command() -> Command {
+    let mut command = Command::new("git");
+    if cfg!(windows) {
+        command.args(["-c", "core.longpaths=true"]);
+    }
+    command
+}
+
 /// Gets the user name from git config (`git config user.name`).
 pub fn user_name() -> Option<String> {
-    Command::new("git")
+    git_command()
         .args(["config", "user.name"])
         .output()
         .ok()
@@ -23,9 +40,53 @@ pub fn user_name() -> Option<String> {
         .filter(|name| !name.is_empty())
 }
 
+/// Sets a value in the repository's git config (`git config <key> <value>`).
+pub fn set_config(key: &str, value: &str) -> Result<()> {
+    let status = git_command()
+        .args(["config", key, value])
+        .status()
+        .context("Failed to execute git config")?;
+    if !status.success() {
+        anyhow::bail!("git config {key} failed");
+    }
+    Ok(())
+}
+
+/// Clones the latest commit of `url` into `dest` (`git clone --depth 1`).
+pub fn shallow_clone(url: &str, dest: &Path) -> Result<()> {
+    let status = git_command()
+        .args(["clone", "--quiet", "--depth", "1", url])
+        .arg(dest)
+        .status()
+        .context("Failed to execute git clone")?;
+    if !status.success() {
+        anyhow::bail!("git clone {url} failed");
+    }
+    Ok(())
+}
+
+/// Updates a shallow clone to the latest commit of its remote's default branch.
+pub fn shallow_update(dir: &Path) -> Result<()> {
+    for args in [
+        &["fetch", "--quiet", "--depth", "1", "origin", "HEAD"][..],
+        &["reset", "--quiet", "--hard", "FETCH_HEAD"][..],
+    ] {
+        let status = git_command()
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .status()
+            .with_context(|| format!("Failed to execute git {}", args[0]))?;
+        if !status.success() {
+            anyhow::bail!("git {} in {} failed", args[0], dir.display());
+        }
+    }
+    Ok(())
+}
+
 /// Checks if the current directory is inside a git repository.
 pub fn is_git_repo() -> bool {
-    Command::new("git")
+    git_command()
         .args(["rev-parse", "--is-inside-work-tree"])
         .stdout(Stdio::null())
         .stderr(Stdio::null())
@@ -33,65 +94,421 @@ pub fn is_git_repo() -> bool {
         .is_ok_and(|s| s.success())
 }
 
-/// Checks if a file is tracked by git.
+/// Returns the directory git runs hooks from (respects `core.hooksPath` and worktrees).
+pub fn hooks_dir() -> Option<PathBuf> {
+    let output = git_command()
+        .args(["rev-parse", "--git-path", "hooks"])
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let dir = PathBuf::from(String::from_utf8_lossy(&output.stdout).trim());
+
+    // git prints the path relative to the current directory
+    if dir.is_absolute() {
+        Some(dir)
+    } else {
+        std::env::current_dir().ok().map(|cwd| cwd.join(dir))
+    }
+}
+
+/// Returns the backend used for files in `dir`.
+pub fn backend(dir: &Path) -> Backend {
+    let installed = git_command()
+        .arg("--version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|s| s.success());
+    if !installed {
+        Backend::Filesystem("git is not installed")
+    } else if output_in(dir, &["rev-parse", "--is-inside-work-tree"]).is_none() {
+        Backend::Filesystem("not a git repository")
+    } else {
+        Backend::Vcs("git")
+    }
+}
+
+/// Returns the root of the enclosing git work tree.
+fn toplevel() -> Option<PathBuf> {
+    let output = git_command()
+        .args(["rev-parse", "--show-toplevel"])
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let path = PathBuf::from(String::from_utf8_lossy(&output.stdout).trim());
+    path.canonicalize()
+        .ok()
+        .map(|path| paths::simplified(&path))
+}
+
+/// Canonicalizes a path in the same form as [`toplevel`], falling back to the path itself.
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize()
+        .map_or_else(|_| path.to_path_buf(), |path| paths::simplified(&path))
+}
+
+/// Lists the files below `dir` as they existed at revision `rev`.
 ///
-/// Returns `false` if the file is untracked, ignored (via .gitignore or global gitignore),
-/// or if we're not in a git repository.
-fn is_tracked(path: &Path) -> bool {
-    Command::new("git")
-        .args(["ls-files", "--error-unmatch", &path.to_string_lossy()])
+/// Returned paths are absolute (joined onto the work tree root).
+pub fn files_at_revision(rev: &str, dir: &Path) -> Result<Vec<PathBuf>> {
+    let root = toplevel().context("Not inside a git repository")?;
+
+    let verified = git_command()
+        .args([
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("{rev}^{{commit}}"),
+        ])
         .stdout(Stdio::null())
         .stderr(Stdio::null())
         .status()
-        .is_ok_and(|s| s.success())
+        .is_ok_and(|s| s.success());
+    if !verified {
+        anyhow::bail!("Unknown git revision '{rev}'");
+    }
+
+    let dir = canonical(dir);
+    let relative = dir.strip_prefix(&root).unwrap_or(&dir);
+
+    let output = git_command()
+        .arg("-C")
+        .arg(&root)
+        .args(["ls-tree", "-r", "--name-only", rev, "--"])
+        .arg(relative)
+        .output()
+        .context("Failed to execute git ls-tree")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git ls-tree failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| root.join(line))
+        .collect())
+}
+
+/// Reads a file's content at revision `rev`. `path` must be inside the work tree.
+pub fn file_at_revision(rev: &str, path: &Path) -> Result<String> {
+    let root = toplevel().context("Not inside a git repository")?;
+    let relative = path.strip_prefix(&root).unwrap_or(path);
+
+    let output = git_command()
+        .arg("-C")
+        .arg(&root)
+        .arg("show")
+        .arg(format!("{rev}:{}", relative.to_string_lossy()))
+        .output()
+        .context("Failed to execute git show")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to read {} at {rev}: {}",
+            relative.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Returns the commits that touched files below `dir`, oldest first.
+///
+/// Returns an empty history outside a git repository.
+pub fn history(dir: &Path) -> Result<Vec<Commit>> {
+    let Some(root) = toplevel() else {
+        return Ok(Vec::new());
+    };
+    let dir = canonical(dir);
+    let relative = dir.strip_prefix(&root).unwrap_or(&dir);
+
+    let output = git_command()
+        .arg("-C")
+        .arg(&root)
+        .args([
+            "log",
+            "--reverse",
+            "--name-only",
+            "--format=%x1e%an%x1f%ae%x1f%aI",
+            "--",
+        ])
+        .arg(relative)
+        .output()
+        .context("Failed to execute git log")?;
+    if !output.status.success() {
+        // No commits yet
+        return Ok(Vec::new());
+    }
+
+    Ok(vcs::parse_log(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Working tree state of the repository containing a directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoStatus {
+    /// Current branch, or `None` on a detached HEAD
+    pub branch: Option<String>,
+    /// Number of modified, staged, or untracked files below the directory
+    pub changes: usize,
+}
+
+/// Runs a git command in `dir` and returns its trimmed output, or `None` if it
+/// fails or prints nothing.
+fn output_in(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = git_command()
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string()).filter(|out| !out.is_empty())
+}
+
+/// Returns the URL of the `origin` remote of the repository containing `dir`.
+pub fn remote_url(dir: &Path) -> Option<String> {
+    output_in(dir, &["remote", "get-url", "origin"])
+}
+
+/// Returns the default branch of `origin` (`origin/HEAD`), falling back to the
+/// current branch.
+pub fn default_branch(dir: &Path) -> Option<String> {
+    output_in(
+        dir,
+        &[
+            "symbolic-ref",
+            "--quiet",
+            "--short",
+            "refs/remotes/origin/HEAD",
+        ],
+    )
+    .and_then(|name| name.strip_prefix("origin/").map(str::to_string))
+    .or_else(|| output_in(dir, &["symbolic-ref", "--quiet", "--short", "HEAD"]))
+}
+
+/// Returns the path of `dir` below the work tree root, `/`-separated with a
+/// trailing slash (empty at the root).
+pub fn prefix(dir: &Path) -> Option<String> {
+    let output = git_command()
+        .arg("-C")
+        .arg(dir)
+        .args(["rev-parse", "--show-prefix"])
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Returns the git status of the files below `dir`, or `None` outside a git repository.
+///
+/// Runs without optional locks, so git doesn't rewrite its index as a side effect.
+pub fn status(dir: &Path) -> Option<RepoStatus> {
+    let output = git_command()
+        .arg("--no-optional-locks")
+        .arg("-C")
+        .arg(dir)
+        .args([
+            "status",
+            "--porcelain",
+            "--branch",
+            "--untracked-files=all",
+            "--",
+            ".",
+        ])
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines();
+
+    // "## main...origin/main [ahead 1]", "## No commits yet on main", "## HEAD (no branch)"
+    let header = lines.next()?.strip_prefix("## ")?;
+    let header = header.strip_prefix("No commits yet on ").unwrap_or(header);
+    let branch = header
+        .split("...")
+        .next()
+        .and_then(|name| name.split_whitespace().next())
+        .filter(|name| *name != "HEAD")
+        .map(str::to_string);
+
+    Some(RepoStatus {
+        branch,
+        changes: lines.count(),
+    })
+}
+
+/// Maximum number of paths passed to a single git invocation (keeps command
+/// lines well below the Windows limit).
+const GIT_BATCH_SIZE: usize = 200;
+
+/// Returns the subset of `paths` tracked by git, using one `git ls-files` call
+/// per batch.
+///
+/// Untracked and ignored files are left out; outside a git repository (or
+/// without git) the set is empty, and the user is told once.
+fn tracked_files(paths: &[&Path]) -> HashSet<PathBuf> {
+    if paths.is_empty() {
+        return HashSet::new();
+    }
+    let Some(root) = toplevel() else {
+        vcs::notify_filesystem();
+        return HashSet::new();
+    };
+
+    // Compare canonical paths; git lists them relative to the work tree root
+    let canonical: Vec<(PathBuf, &Path)> = paths
+        .iter()
+        .filter(|path| path.exists())
+        .map(|path| (canonical(path), *path))
+        .collect();
+
+    let mut listed = HashSet::new();
+    for chunk in canonical.chunks(GIT_BATCH_SIZE) {
+        let output = git_command()
+            .arg("-C")
+            .arg(&root)
+            .args(["ls-files", "-z", "--full-name", "--"])
+            .args(chunk.iter().map(|(path, _)| path))
+            .stderr(Stdio::null())
+            .output()
+            .ok()
+            .filter(|output| output.status.success());
+        let Some(output) = output else {
+            continue;
+        };
+        listed.extend(
+            String::from_utf8_lossy(&output.stdout)
+                .split('\0')
+                .filter(|line| !line.is_empty())
+                .map(|line| root.join(line)),
+        );
+    }
+
+    canonical
+        .into_iter()
+        .filter(|(path, _)| listed.contains(path))
+        .map(|(_, original)| original.to_path_buf())
+        .collect()
 }
 
 /// Moves a file, using `git mv` if tracked, otherwise standard rename.
 pub fn move_file(from: &Path, to: &Path) -> Result<()> {
-    // Ensure parent directory exists
-    if let Some(parent) = to.parent() {
-        std::fs::create_dir_all(parent)
-            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    match move_files(&[(from.to_path_buf(), to.to_path_buf())])
+        .into_iter()
+        .next()
+    {
+        Some((_, err)) => Err(err),
+        None => Ok(()),
     }
+}
 
-    if is_git_repo() && is_tracked(from) {
-        // File is tracked by git - use git mv
-        let status = Command::new("git")
-            .args(["mv", &from.to_string_lossy(), &to.to_string_lossy()])
-            .status()
-            .context("Failed to execute git mv")?;
+/// Moves several files, batching the git work.
+///
+/// Tracked files keeping their name are moved with one `git mv` per destination
+/// directory; tracked files being renamed get their own `git mv`; untracked files
+/// (or all files outside a git repository) are renamed directly. If `git mv`
+/// fails, the files fall back to a standard rename.
+///
+/// Returns the source paths that could not be moved, with the reason.
+pub fn move_files(moves: &[(PathBuf, PathBuf)]) -> Vec<(PathBuf, anyhow::Error)> {
+    let mut failures = Vec::new();
 
-        if !status.success() {
+    // Ensure parent directories exist
+    let mut pending = Vec::new();
+    for (from, to) in moves {
+        if let Some(parent) = to.parent() {
+            if let Err(e) = std::fs::create_dir_all(parent) {
+                failures.push((
+                    from.clone(),
+                    anyhow::Error::new(e)
+                        .context(format!("Failed to create directory: {}", parent.display())),
+                ));
+                continue;
+            }
+        }
+        pending.push((from.as_path(), to.as_path()));
+    }
+
+    let sources: Vec<&Path> = pending.iter().map(|(from, _)| *from).collect();
+    let tracked = tracked_files(&sources);
+
+    // Group tracked moves into `git mv <sources...> <target>` invocations
+    let mut into_dir: BTreeMap<&Path, Vec<(&Path, &Path)>> = BTreeMap::new();
+    let mut single = Vec::new();
+    for (from, to) in pending {
+        if !tracked.contains(from) {
+            rename(from, to, &mut failures);
+        } else if let (true, Some(dir)) = (from.file_name() == to.file_name(), to.parent()) {
+            into_dir.entry(dir).or_default().push((from, to));
+        } else {
+            single.push((from, to));
+        }
+    }
+
+    let batches = into_dir
+        .into_iter()
+        .flat_map(|(dir, moves)| {
+            moves
+                .chunks(GIT_BATCH_SIZE)
+                .map(|chunk| (chunk.to_vec(), dir))
+                .collect::<Vec<_>>()
+        })
+        .chain(single.into_iter().map(|(from, to)| (vec![(from, to)], to)));
+
+    for (moves, target) in batches {
+        let status = git_command()
+            .arg("mv")
+            .args(moves.iter().map(|(from, _)| from))
+            .arg(target)
+            .status();
+        if !status.is_ok_and(|s| s.success()) {
             // git mv failed unexpectedly - fall back to standard rename
-            std::fs::rename(from, to).with_context(|| {
-                format!("Failed to move {} to {}", from.display(), to.display())
-            })?;
+            for (from, to) in moves.into_iter().filter(|(from, _)| from.exists()) {
+                rename(from, to, &mut failures);
+            }
         }
-    } else {
-        // Not in git repo or file not tracked - use standard rename
-        std::fs::rename(from, to)
-            .with_context(|| format!("Failed to move {} to {}", from.display(), to.display()))?;
     }
 
-    Ok(())
+    failures
 }
 
 /// Removes a file, using `git rm` if tracked, otherwise standard remove.
 pub fn remove_file(path: &Path) -> Result<()> {
-    if is_git_repo() && is_tracked(path) {
-        // File is tracked by git - use git rm
-        let status = Command::new("git")
-            .args(["rm", "-f", &path.to_string_lossy()])
+    remove_files(&[path.to_path_buf()])
+}
+
+/// Removes several files, with one `git rm` per batch of tracked files and a
+/// standard remove for the rest.
+pub fn remove_files(paths: &[PathBuf]) -> Result<()> {
+    let sources: Vec<&Path> = paths.iter().map(PathBuf::as_path).collect();
+    let tracked = tracked_files(&sources);
+    let (tracked, untracked): (Vec<&Path>, Vec<&Path>) = sources
+        .into_iter()
+        .partition(|path| tracked.contains(*path));
+
+    for chunk in tracked.chunks(GIT_BATCH_SIZE) {
+        let status = git_command()
+            .args(["rm", "-f", "-q", "--"])
+            .args(chunk)
             .status()
             .context("Failed to execute git rm")?;
-
         if !status.success() {
             // git rm failed unexpectedly - fall back to standard remove
-            std::fs::remove_file(path)
-                .with_context(|| format!("Failed to remove {}", path.display()))?;
+            for path in chunk.iter().filter(|path| path.exists()) {
+                std::fs::remove_file(path)
+                    .with_context(|| format!("Failed to remove {}", path.display()))?;
+            }
         }
-    } else {
-        // Not in git repo or file not tracked - use standard remove
+    }
+
+    for path in untracked {
         std::fs::remove_file(path)
             .with_context(|| format!("Failed to remove {}", path.display()))?;
     }
@@ -105,6 +522,35 @@ mod tests {
     use std::fs;
     use tempfile::tempdir;
 
+    #[test]
+    fn test_status_no_git() {
+        let dir = tempdir().unwrap();
+        assert_eq!(status(dir.path()), None);
+    }
+
+    #[test]
+    fn test_status_counts_changes_below_dir() {
+        let dir = tempdir().unwrap();
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .arg("-C")
+                .arg(dir.path())
+                .args(args)
+                .output()
+                .is_ok_and(|output| output.status.success())
+        };
+        if !git(&["init", "-q", "-b", "main"]) {
+            return; // git not available
+        }
+        fs::create_dir_all(dir.path().join("stack")).unwrap();
+        fs::write(dir.path().join("stack/item.md"), "").unwrap();
+        fs::write(dir.path().join("other.txt"), "").unwrap();
+
+        let status = status(&dir.path().join("stack")).unwrap();
+        assert_eq!(status.branch.as_deref(), Some("main"));
+        assert_eq!(status.changes, 1);
+    }
+
     #[test]
     fn test_move_file_no_git() {
         let dir = tempdir().unwrap();
@@ -134,4 +580,45 @@ mod tests {
         assert!(!from.exists());
         assert!(to.exists());
     }
+
+    #[test]
+    fn test_move_files_batch_no_git() {
+        let dir = tempdir().unwrap();
+        let moves: Vec<(PathBuf, PathBuf)> = (0..5)
+            .map(|i| {
+                let from = dir.path().join(format!("file{i}.txt"));
+                fs::write(&from, i.to_string()).unwrap();
+                (from, dir.path().join(format!("dest/file{i}.txt")))
+            })
+            .collect();
+        let missing = (
+            dir.path().join("missing.txt"),
+            dir.path().join("dest/missing.txt"),
+        );
+
+        let failures = move_files(&[moves.clone(), vec![missing.clone()]].concat());
+
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, missing.0);
+        for (from, to) in &moves {
+            assert!(!from.exists());
+            assert!(to.exists());
+        }
+    }
+
+    #[test]
+    fn test_remove_files_no_git() {
+        let dir = tempdir().unwrap();
+        let files: Vec<PathBuf> = (0..3)
+            .map(|i| {
+                let path = dir.path().join(format!("file{i}.txt"));
+                fs::write(&path, "content").unwrap();
+                path
+            })
+            .collect();
+
+        remove_files(&files).unwrap();
+
+        assert!(files.iter().all(|path| !path.exists()));
+    }
 }
@@ -0,0 +1,129 @@
+//! # Git Files
+//!
+//! Entries `qs init` can add to the project's `.gitattributes` and
+//! `.gitignore`: a merge driver for item files, Git LFS for attachments, and
+//! ignores for files qs keeps out of version control. Entries are appended
+//! below a `# queuestack` comment and never duplicated, so running init again
+//! is harmless.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::{
+    config::Config,
+    constants::{ATTACHMENTS_DIR_SUFFIX, ATTACHMENT_STORE_DIR, CACHE_DIR, ITEM_FILE_EXTENSION},
+};
+
+/// Name of the git merge driver for item files
+pub const MERGE_DRIVER: &str = "queuestack";
+
+/// Attachment patterns stored with Git LFS when none are given
+pub const DEFAULT_LFS_PATTERNS: [&str; 10] = [
+    "*.png", "*.jpg", "*.jpeg", "*.gif", "*.webp", "*.pdf", "*.zip", "*.gz", "*.mp4", "*.mov",
+];
+
+/// Comment line above the entries added by qs
+const HEADER: &str = "# queuestack";
+
+/// Returns `.gitattributes` entries routing item files through the merge driver.
+///
+/// Until the driver is configured (see `qs merge-driver`), git treats the
+/// files like any other text file.
+pub fn merge_attributes(config: &Config) -> Vec<String> {
+    vec![format!(
+        "{}/**/*.{ITEM_FILE_EXTENSION} merge={MERGE_DRIVER}",
+        config.stack_dir()
+    )]
+}
+
+/// Returns `.gitattributes` entries storing matching attachments with Git LFS.
+pub fn lfs_attributes(config: &Config, patterns: &[String]) -> Vec<String> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            format!(
+                "{}/**/*{ATTACHMENTS_DIR_SUFFIX}/{pattern} filter=lfs diff=lfs merge=lfs -text",
+                config.stack_dir()
+            )
+        })
+        .collect()
+}
+
+/// Returns `.gitignore` entries for files qs keeps out of version control.
+pub fn ignores(config: &Config) -> Vec<String> {
+    let stack = config.stack_dir();
+    vec![
+        // Blobs of deduplicated attachments (git stores identical content once anyway)
+        format!("{stack}/{ATTACHMENT_STORE_DIR}/"),
+        // Leftovers of interrupted attachment deduplication
+        format!("{stack}/**/.*.dedup"),
+        // Runtime state such as the lock held while an item is claimed
+        format!("{stack}/{CACHE_DIR}/"),
+    ]
+}
+
+/// Appends the entries missing from a file, creating it if needed.
+///
+/// Returns the number of entries added.
+pub fn append_missing(path: &Path, entries: &[String]) -> Result<usize> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    };
+    let missing: Vec<&String> = entries
+        .iter()
+        .filter(|entry| !content.lines().any(|line| line.trim() == entry.as_str()))
+        .collect();
+    if missing.is_empty() {
+        return Ok(0);
+    }
+
+    let mut result = content;
+    if !result.is_empty() && !result.ends_with('\n') {
+        result.push('\n');
+    }
+    if !result.lines().any(|line| line == HEADER) {
+        if !result.is_empty() {
+            result.push('\n');
+        }
+        result.push_str(HEADER);
+        result.push('\n');
+    }
+    for entry in &missing {
+        result.push_str(entry);
+        result.push('\n');
+    }
+    std::fs::write(path, result).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(missing.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_missing_is_idempotent() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".gitignore");
+        std::fs::write(&path, "target/").unwrap();
+        let entries = vec!["a/".to_string(), "b/**/.*.dedup".to_string()];
+
+        assert_eq!(append_missing(&path, &entries).unwrap(), 2);
+        assert_eq!(append_missing(&path, &entries).unwrap(), 0);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "target/\n\n# queuestack\na/\nb/**/.*.dedup\n"
+        );
+
+        let more = vec!["c/".to_string()];
+        assert_eq!(append_missing(&path, &more).unwrap(), 1);
+        assert!(std::fs::read_to_string(&path)
+            .unwrap()
+            .ends_with("b/**/.*.dedup\nc/\n"));
+    }
+}
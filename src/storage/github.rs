@@ -0,0 +1,236 @@
+//! # GitHub Issues
+//!
+//! Reads and writes GitHub issues for `qs sync github`. Requests go through
+//! the `gh` CLI, so authentication (`gh auth login`, `GH_TOKEN`) and GitHub
+//! Enterprise hosts work as usual. Without a configured repository, `gh`
+//! uses the one of the project's git remote. Issues and labels are read
+//! through the REST API page by page, so repositories of any size are
+//! listed completely.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{path::Path, process::Command};
+
+use anyhow::{bail, Context, Result};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+
+/// Issues and labels requested per page (the API maximum)
+const PAGE_SIZE: &str = "per_page=100";
+
+/// An issue as listed by the issues API
+#[derive(Debug, Clone, Deserialize)]
+pub struct Issue {
+    pub number: u64,
+    pub state: String,
+    pub title: String,
+    #[serde(default, deserialize_with = "null_as_empty")]
+    pub body: String,
+    #[serde(default)]
+    pub labels: Vec<IssueLabel>,
+}
+
+impl Issue {
+    /// Returns true if the issue is closed
+    pub fn is_closed(&self) -> bool {
+        self.state.eq_ignore_ascii_case("closed")
+    }
+
+    /// Returns the names of the issue's labels
+    pub fn label_names(&self) -> Vec<String> {
+        self.labels.iter().map(|label| label.name.clone()).collect()
+    }
+}
+
+/// A label as listed by `gh`
+#[derive(Debug, Clone, Deserialize)]
+pub struct IssueLabel {
+    pub name: String,
+}
+
+/// Changes to apply to an existing issue
+#[derive(Debug, Default)]
+pub struct IssueEdit<'a> {
+    pub title: Option<&'a str>,
+    pub body: Option<&'a str>,
+    pub add_labels: Vec<String>,
+    pub remove_labels: Vec<String>,
+}
+
+impl IssueEdit<'_> {
+    /// Returns true if the edit changes nothing
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none()
+            && self.body.is_none()
+            && self.add_labels.is_empty()
+            && self.remove_labels.is_empty()
+    }
+}
+
+/// A GitHub repository reached through the `gh` CLI
+pub struct Repo<'a> {
+    /// `owner/name`, or `None` for the repository of the git remote
+    pub name: Option<&'a str>,
+    /// Directory `gh` runs in (the project root)
+    pub root: &'a Path,
+}
+
+impl Repo<'_> {
+    /// Lists all issues, open and closed (without pull requests).
+    pub fn issues(&self) -> Result<Vec<Issue>> {
+        self.list(
+            "issues",
+            &["state=all"],
+            r#".[] | select(has("pull_request") | not)"#,
+        )
+        .context("Failed to parse the issue list from gh")
+    }
+
+    /// Lists the names of the repository's labels.
+    pub fn labels(&self) -> Result<Vec<String>> {
+        let labels: Vec<IssueLabel> = self
+            .list("labels", &[], ".[]")
+            .context("Failed to parse the label list from gh")?;
+        Ok(labels.into_iter().map(|label| label.name).collect())
+    }
+
+    /// Creates a label; `color` is a hex value without `#`.
+    pub fn create_label(
+        &self,
+        name: &str,
+        color: Option<&str>,
+        description: Option<&str>,
+    ) -> Result<()> {
+        let mut args = vec!["label", "create", name];
+        if let Some(color) = color {
+            args.extend(["--color", color]);
+        }
+        if let Some(description) = description {
+            args.extend(["--description", description]);
+        }
+        self.gh(&args).map(|_| ())
+    }
+
+    /// Creates an issue and returns its number.
+    pub fn create_issue(&self, title: &str, body: &str, labels: &[String]) -> Result<u64> {
+        let mut args = vec!["issue", "create", "--title", title, "--body", body];
+        for label in labels {
+            args.extend(["--label", label.as_str()]);
+        }
+        let url = self.gh(&args)?;
+        issue_number(&url).with_context(|| format!("Unexpected output from gh issue create: {url}"))
+    }
+
+    /// Applies changes to an issue.
+    pub fn edit_issue(&self, number: u64, edit: &IssueEdit<'_>) -> Result<()> {
+        let number = number.to_string();
+        let mut args = vec!["issue", "edit", number.as_str()];
+        if let Some(title) = edit.title {
+            args.extend(["--title", title]);
+        }
+        if let Some(body) = edit.body {
+            args.extend(["--body", body]);
+        }
+        for label in &edit.add_labels {
+            args.extend(["--add-label", label.as_str()]);
+        }
+        for label in &edit.remove_labels {
+            args.extend(["--remove-label", label.as_str()]);
+        }
+        self.gh(&args).map(|_| ())
+    }
+
+    /// Closes an issue.
+    pub fn close_issue(&self, number: u64) -> Result<()> {
+        self.gh(&["issue", "close", &number.to_string()])
+            .map(|_| ())
+    }
+
+    /// Reads every page of a repository collection (`issues`, `labels`) and
+    /// parses the elements selected by `filter`, a jq expression.
+    fn list<T: DeserializeOwned>(
+        &self,
+        collection: &str,
+        params: &[&str],
+        filter: &str,
+    ) -> Result<Vec<T>> {
+        let endpoint = format!(
+            "repos/{}/{collection}",
+            self.name.unwrap_or("{owner}/{repo}")
+        );
+        let mut args = vec!["api", endpoint.as_str(), "--method", "GET", "--paginate"];
+        for param in params.iter().chain([&PAGE_SIZE]) {
+            args.extend(["-f", param]);
+        }
+        args.extend(["--jq", filter]);
+
+        // The api command takes the repository from the endpoint, not --repo
+        let output = self.run(&args, false)?;
+        output
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).map_err(Into::into))
+            .collect()
+    }
+
+    /// Runs the `gh` CLI against the repository and returns its trimmed stdout.
+    fn gh(&self, args: &[&str]) -> Result<String> {
+        self.run(args, true)
+    }
+
+    /// Runs `gh` in the project root, passing `--repo` if `with_repo` is set.
+    fn run(&self, args: &[&str], with_repo: bool) -> Result<String> {
+        let mut command = Command::new("gh");
+        command.args(args).current_dir(self.root);
+        if let (true, Some(name)) = (with_repo, self.name) {
+            command.args(["--repo", name]);
+        }
+        let output = command
+            .output()
+            .context("Failed to run the gh CLI (is it installed?)")?;
+
+        if !output.status.success() {
+            bail!(
+                "gh {} failed: {}",
+                args.iter().take(2).copied().collect::<Vec<_>>().join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+}
+
+/// Reads `null` as an empty string (the API returns `null` for empty bodies).
+fn null_as_empty<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Reads the issue number from an issue URL (`.../issues/12`).
+fn issue_number(url: &str) -> Option<u64> {
+    let url = url.lines().last()?.trim();
+    let (rest, number) = url.rsplit_once('/')?;
+    if !rest.ends_with("/issues") {
+        return None;
+    }
+    number.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_number() {
+        assert_eq!(
+            issue_number("https://github.com/acme/app/issues/42"),
+            Some(42)
+        );
+        assert_eq!(
+            issue_number("Creating issue in acme/app\n\nhttps://github.com/acme/app/issues/7"),
+            Some(7)
+        );
+        assert_eq!(issue_number("https://github.com/acme/app/pull/7"), None);
+        assert_eq!(issue_number(""), None);
+    }
+}
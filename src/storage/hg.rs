@@ -0,0 +1,169 @@
+//! # Mercurial Integration
+//!
+//! Item files are moved and removed directly, then the changes are recorded
+//! with `hg mv --after` and `hg rm --after` (untracked files are left alone).
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use anyhow::{Context, Result};
+
+use super::vcs::{self, Backend, Commit};
+
+/// Log template producing the records read by [`vcs::parse_log`]
+const LOG_TEMPLATE: &str =
+    "\x1e{author|person}\x1f{author|email}\x1f{date|rfc3339date}\n{files % '{file}\n'}";
+
+/// Maximum number of paths passed to a single hg invocation.
+const HG_BATCH_SIZE: usize = 200;
+
+/// Creates an hg command with output unaffected by user settings.
+fn hg_command() -> Command {
+    let mut command = Command::new("hg");
+    command.env("HGPLAIN", "1");
+    command
+}
+
+/// Returns the root of the Mercurial repository containing `dir`.
+pub fn root(dir: &Path) -> Option<PathBuf> {
+    let output = hg_command()
+        .arg("--cwd")
+        .arg(dir)
+        .arg("root")
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let root = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!root.is_empty()).then(|| PathBuf::from(root))
+}
+
+/// Returns the backend used for files in `dir`.
+pub fn backend(dir: &Path) -> Backend {
+    let installed = hg_command()
+        .arg("--version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|s| s.success());
+    if !installed {
+        Backend::Filesystem("hg is not installed")
+    } else if root(dir).is_none() {
+        Backend::Filesystem("not a Mercurial repository")
+    } else {
+        Backend::Vcs("hg")
+    }
+}
+
+/// Moves files, then records the moves of tracked files with `hg mv --after`.
+///
+/// Returns the source paths that could not be moved, with the reason.
+pub fn move_files(moves: &[(PathBuf, PathBuf)]) -> Vec<(PathBuf, anyhow::Error)> {
+    if moves.is_empty() {
+        return Vec::new();
+    }
+    let failures = vcs::move_directly(moves);
+    if root(Path::new(".")).is_none() {
+        vcs::notify_filesystem();
+        return failures;
+    }
+
+    for (from, to) in moves
+        .iter()
+        .filter(|(from, _)| !failures.iter().any(|(failed, _)| failed == from))
+    {
+        // Fails for untracked files, which need nothing recorded
+        let _ = hg_command()
+            .args(["mv", "--after", "-q", "--"])
+            .arg(from)
+            .arg(to)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
+    }
+    failures
+}
+
+/// Removes files, then records the removals of tracked files with `hg rm --after`.
+pub fn remove_files(paths: &[PathBuf]) -> Result<()> {
+    if paths.is_empty() {
+        return Ok(());
+    }
+    vcs::remove_directly(paths)?;
+    if root(Path::new(".")).is_none() {
+        vcs::notify_filesystem();
+        return Ok(());
+    }
+
+    for chunk in paths.chunks(HG_BATCH_SIZE) {
+        let _ = hg_command()
+            .args(["rm", "--after", "-q", "--"])
+            .args(chunk)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
+    }
+    Ok(())
+}
+
+/// Gets the user name from hg config (`ui.username`, without the email).
+pub fn user_name() -> Option<String> {
+    let output = hg_command()
+        .args(["config", "ui.username"])
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    person(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Returns the name part of `Name <email>`.
+fn person(username: &str) -> Option<String> {
+    let name = username.split('<').next().unwrap_or_default().trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// Returns the commits that touched files below `dir`, oldest first.
+///
+/// Returns an empty history outside a Mercurial repository or without hg.
+pub fn history(dir: &Path) -> Result<Vec<Commit>> {
+    let Some(root) = root(dir) else {
+        return Ok(Vec::new());
+    };
+
+    let output = hg_command()
+        .arg("--cwd")
+        .arg(&root)
+        .args(["log", "--template", LOG_TEMPLATE, "--"])
+        .arg(dir)
+        .output()
+        .context("Failed to execute hg log")?;
+    if !output.status.success() {
+        return Ok(Vec::new());
+    }
+
+    // hg lists the newest commit first
+    let mut commits = vcs::parse_log(&String::from_utf8_lossy(&output.stdout));
+    commits.reverse();
+    Ok(commits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_person() {
+        assert_eq!(
+            person("Ada Lovelace <ada@example.com>\n").as_deref(),
+            Some("Ada Lovelace")
+        );
+        assert_eq!(person("ada\n").as_deref(), Some("ada"));
+        assert_eq!(person(" <ada@example.com>"), None);
+    }
+}
@@ -0,0 +1,123 @@
+//! # Jujutsu Integration
+//!
+//! jj records moves and removals when it snapshots the working copy, so item
+//! files are renamed and removed directly; `git mv` in a colocated repository
+//! only adds index churn jj doesn't use.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use anyhow::{Context, Result};
+
+use super::vcs::{self, Backend, Commit};
+
+/// Log template producing the records read by [`vcs::parse_log`]
+const LOG_TEMPLATE: &str = "\"\x1e\" ++ author.name() ++ \"\x1f\" ++ author.email() ++ \"\x1f\" \
+    ++ author.timestamp().format(\"%Y-%m-%dT%H:%M:%S%:z\") ++ \"\\n\" \
+    ++ self.diff().files().map(|entry| entry.path() ++ \"\\n\").join(\"\")";
+
+/// Returns the root of the jj repository containing `dir`.
+pub fn root(dir: &Path) -> Option<PathBuf> {
+    let dir = dir.canonicalize().ok()?;
+    dir.ancestors()
+        .find(|ancestor| ancestor.join(".jj").is_dir())
+        .map(Path::to_path_buf)
+}
+
+/// Returns the backend used for files in `dir`.
+pub fn backend(dir: &Path) -> Backend {
+    if root(dir).is_some() {
+        Backend::Vcs("jj")
+    } else {
+        Backend::Filesystem("not a jj repository")
+    }
+}
+
+/// Moves files directly; jj picks the moves up on its next snapshot.
+pub fn move_files(moves: &[(PathBuf, PathBuf)]) -> Vec<(PathBuf, anyhow::Error)> {
+    if !moves.is_empty() && root(Path::new(".")).is_none() {
+        vcs::notify_filesystem();
+    }
+    vcs::move_directly(moves)
+}
+
+/// Removes files directly; jj picks the removals up on its next snapshot.
+pub fn remove_files(paths: &[PathBuf]) -> Result<()> {
+    if !paths.is_empty() && root(Path::new(".")).is_none() {
+        vcs::notify_filesystem();
+    }
+    vcs::remove_directly(paths)
+}
+
+/// Gets the user name from jj config (`jj config get user.name`).
+pub fn user_name() -> Option<String> {
+    Command::new("jj")
+        .args(["config", "get", "user.name"])
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+/// Returns the commits before the working-copy commit that touched files
+/// below `dir`, oldest first.
+///
+/// Returns an empty history outside a jj repository or without jj.
+pub fn history(dir: &Path) -> Result<Vec<Commit>> {
+    let Some(root) = root(dir) else {
+        return Ok(Vec::new());
+    };
+    let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+    let relative = dir.strip_prefix(&root).unwrap_or(&dir);
+    let pattern = relative
+        .to_string_lossy()
+        .replace('\\', "/")
+        .replace('"', "\\\"");
+    let pattern = if pattern.is_empty() { "." } else { &pattern };
+
+    // Paths are printed relative to the current directory, so run from the root
+    let output = match Command::new("jj")
+        .current_dir(&root)
+        .args(["log", "--no-graph", "--reversed", "-r"])
+        .arg(format!("::@- & files(\"{pattern}\")"))
+        .args(["-T", LOG_TEMPLATE])
+        .stderr(Stdio::null())
+        .output()
+    {
+        Ok(output) => output,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).context("Failed to execute jj log"),
+    };
+    if !output.status.success() {
+        return Ok(Vec::new());
+    }
+
+    Ok(vcs::parse_log(&String::from_utf8_lossy(&output.stdout)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_backend_finds_repository() {
+        let dir = tempdir().unwrap();
+        assert_eq!(
+            backend(dir.path()),
+            Backend::Filesystem("not a jj repository")
+        );
+
+        fs::create_dir_all(dir.path().join(".jj")).unwrap();
+        fs::create_dir_all(dir.path().join("queuestack")).unwrap();
+        assert_eq!(backend(&dir.path().join("queuestack")), Backend::Vcs("jj"));
+    }
+}
@@ -0,0 +1,175 @@
+//! # Link Titles
+//!
+//! Fetches the page title of URL attachments so listings can tell ten GitHub
+//! links apart. Pages are downloaded with curl (a few seconds at most) and the
+//! title is read from the `og:title` meta tag or the `<title>` element.
+//! Failures are not errors: the URL is simply attached without a title.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{process::Command, sync::OnceLock};
+
+use regex::Regex;
+
+/// Seconds a title fetch may take
+const FETCH_TIMEOUT_SECS: &str = "5";
+
+/// Largest page downloaded for its title, in bytes
+const MAX_PAGE_BYTES: &str = "2000000";
+
+/// Longest title kept, in characters
+const MAX_TITLE_CHARS: usize = 120;
+
+/// Fetches the title of a web page; `None` if it can't be fetched or has none.
+pub fn fetch_title(url: &str) -> Option<String> {
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        return None;
+    }
+    let output = Command::new("curl")
+        .args(["--fail", "--silent", "--location"])
+        .args(["--max-time", FETCH_TIMEOUT_SECS])
+        .args(["--max-filesize", MAX_PAGE_BYTES])
+        .args([
+            "--user-agent",
+            concat!("queuestack/", env!("CARGO_PKG_VERSION")),
+        ])
+        .arg(url)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_title(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Extracts the title of an HTML page, preferring `og:title`.
+pub fn parse_title(html: &str) -> Option<String> {
+    static META: OnceLock<Regex> = OnceLock::new();
+    static ATTRIBUTE: OnceLock<Regex> = OnceLock::new();
+    static TITLE: OnceLock<Regex> = OnceLock::new();
+    let meta = META.get_or_init(|| Regex::new(r"(?is)<meta\s[^>]*>").expect("valid regex"));
+    let attribute = ATTRIBUTE.get_or_init(|| {
+        Regex::new(r#"(?is)([a-z:-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).expect("valid regex")
+    });
+    let title =
+        TITLE.get_or_init(|| Regex::new(r"(?is)<title[^>]*>(.*?)</title>").expect("valid regex"));
+
+    let og_title = meta.find_iter(html).find_map(|tag| {
+        let mut property = None;
+        let mut content = None;
+        for captures in attribute.captures_iter(tag.as_str()) {
+            let value = captures.get(2).or_else(|| captures.get(3))?.as_str();
+            match captures[1].to_ascii_lowercase().as_str() {
+                "property" | "name" => property = Some(value),
+                "content" => content = Some(value),
+                _ => {}
+            }
+        }
+        content.filter(|_| property.is_some_and(|p| p.eq_ignore_ascii_case("og:title")))
+    });
+
+    og_title
+        .into_iter()
+        .chain(
+            title
+                .captures(html)
+                .and_then(|c| c.get(1))
+                .map(|m| m.as_str()),
+        )
+        .map(clean)
+        .find(|title| !title.is_empty())
+}
+
+/// Decodes common HTML entities, collapses whitespace, and shortens the title.
+fn clean(raw: &str) -> String {
+    let decoded = decode_entities(raw);
+    let title = decoded.split_whitespace().collect::<Vec<_>>().join(" ");
+    if title.chars().count() > MAX_TITLE_CHARS {
+        let short: String = title.chars().take(MAX_TITLE_CHARS - 1).collect();
+        format!("{}…", short.trim_end())
+    } else {
+        title
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                "middot" => Some('·'),
+                "ndash" => Some('–'),
+                "mdash" => Some('—'),
+                "hellip" => Some('…'),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            }?;
+            Some((c, end))
+        });
+        if let Some((c, end)) = decoded {
+            out.push(c);
+            rest = &rest[end + 1..];
+        } else {
+            out.push('&');
+            rest = &rest[1..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_title_element() {
+        let html = "<html><head>\n<TITLE>\n  Login fails on Safari &middot;\n  Issue #42 &#183; org/repo\n</TITLE></head></html>";
+        assert_eq!(
+            parse_title(html).unwrap(),
+            "Login fails on Safari · Issue #42 · org/repo"
+        );
+        assert_eq!(parse_title("<p>no title</p>"), None);
+        assert_eq!(parse_title("<title>  </title>"), None);
+    }
+
+    #[test]
+    fn test_prefers_og_title() {
+        let html = r#"<title>GitHub</title><meta property="og:title" content="Fix &quot;login&quot; bug by alice · Pull Request #7">"#;
+        assert_eq!(
+            parse_title(html).unwrap(),
+            "Fix \"login\" bug by alice · Pull Request #7"
+        );
+
+        let html = r#"<meta content='Don"t panic' property='og:title'><title>Other</title>"#;
+        assert_eq!(parse_title(html).unwrap(), "Don\"t panic");
+    }
+
+    #[test]
+    fn test_long_titles_are_shortened() {
+        let html = format!("<title>{}</title>", "word ".repeat(50));
+        let title = parse_title(&html).unwrap();
+        assert_eq!(title.chars().count(), MAX_TITLE_CHARS);
+        assert!(title.ends_with('…'));
+    }
+
+    #[test]
+    fn test_non_http_urls_are_not_fetched() {
+        assert_eq!(fetch_title("s3://bucket/file.png"), None);
+    }
+}
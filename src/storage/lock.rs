@@ -0,0 +1,124 @@
+//! # Project Lock
+//!
+//! Advisory lock that serializes claiming items across processes, so two
+//! concurrent `qs pop` (or `qs agent`) runs never claim the same item.
+//!
+//! The lock is a file in the stack's cache directory created with `create_new`, which
+//! is atomic on every platform. It is held only for the few milliseconds it
+//! takes to pick and save an item, so a lock older than a minute is left over
+//! from a crashed process and is broken.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{config::Config, constants::LOCK_FILE, storage};
+
+/// How long to wait for another process to release the lock
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Age after which a lock is considered abandoned
+const STALE_AFTER: Duration = Duration::from_secs(60);
+
+/// Delay between attempts while the lock is held elsewhere
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// A held project lock, released when dropped
+#[derive(Debug)]
+pub struct ProjectLock {
+    path: PathBuf,
+}
+
+impl ProjectLock {
+    /// Acquires the project's lock, waiting for other processes to release it.
+    pub fn acquire(config: &Config) -> Result<Self> {
+        let dir = storage::create_cache_dir(config)?;
+        Self::acquire_at(dir.join(LOCK_FILE), TIMEOUT)
+    }
+
+    /// Acquires the lock file at `path`, giving up after `timeout`.
+    fn acquire_at(path: PathBuf, timeout: Duration) -> Result<Self> {
+        let start = Instant::now();
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut file) => {
+                    // The holder's PID helps when debugging a stuck lock
+                    let _ = writeln!(file, "{}", std::process::id());
+                    return Ok(Self { path });
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                    if is_stale(&path) {
+                        let _ = fs::remove_file(&path);
+                        continue;
+                    }
+                    if start.elapsed() >= timeout {
+                        bail!(
+                            "Timed out waiting for the lock {} (remove it if no qs process is running)",
+                            path.display()
+                        );
+                    }
+                    thread::sleep(POLL_INTERVAL);
+                }
+                Err(e) => {
+                    return Err(e)
+                        .with_context(|| format!("Failed to create lock: {}", path.display()))
+                }
+            }
+        }
+    }
+}
+
+impl Drop for ProjectLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Returns true if the lock file was last written longer than `STALE_AFTER` ago.
+fn is_stale(path: &Path) -> bool {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age > STALE_AFTER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_is_exclusive_until_dropped() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(LOCK_FILE);
+
+        let lock = ProjectLock::acquire_at(path.clone(), TIMEOUT).unwrap();
+        assert!(path.exists());
+        let err = ProjectLock::acquire_at(path.clone(), Duration::ZERO).unwrap_err();
+        assert!(err.to_string().contains("Timed out waiting for the lock"));
+
+        drop(lock);
+        assert!(!path.exists());
+        assert!(ProjectLock::acquire_at(path, Duration::ZERO).is_ok());
+    }
+
+    #[test]
+    fn test_stale_lock_is_broken() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(LOCK_FILE);
+        let file = fs::File::create(&path).unwrap();
+        file.set_modified(SystemTime::now() - STALE_AFTER * 2)
+            .unwrap();
+
+        assert!(ProjectLock::acquire_at(path, Duration::ZERO).is_ok());
+    }
+}
@@ -0,0 +1,184 @@
+//! # Mirrors
+//!
+//! Read-only stacks of other projects, registered in the `[mirrors]` config
+//! table and shown by `qs list` and `qs search` next to the project's own
+//! items:
+//!
+//! ```toml
+//! [mirrors]
+//! upstream = "../upstream"                               # another checkout or submodule
+//! library = "https://github.com/example/library.git"     # fetched shallowly
+//! ```
+//!
+//! A path is read in place (relative to the project root). A git URL is cloned
+//! with `--depth 1` into `~/.config/queuestack/mirrors/<project>/<name>` by
+//! `qs mirror fetch` and read from there; nothing is fetched implicitly.
+//! Mirrored items carry their mirror's name in `Item::source` and can't be
+//! saved.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+
+use super::{derive_category, git, walk_archived, walk_items};
+use crate::{
+    config::{Config, GlobalConfig},
+    constants::MIRRORS_DIR,
+    item::{matches_filter, FilterCriteria, Item},
+};
+
+/// A mirror and its loaded items
+pub type MirroredItems = (Mirror, Vec<Item>);
+
+/// A registered mirror
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mirror {
+    pub name: String,
+    /// Path or git URL as configured
+    pub source: String,
+    /// Project root the mirror is read from (the clone, for git URLs)
+    pub root: PathBuf,
+}
+
+impl Mirror {
+    /// Returns true if the mirror is fetched from a git URL.
+    pub fn is_remote(&self) -> bool {
+        is_url(&self.source)
+    }
+
+    /// Returns true if the mirror's project can be read (exists or is fetched).
+    pub fn is_available(&self) -> bool {
+        self.root.is_dir()
+    }
+
+    /// Loads the mirror's active or archived items that match the filter,
+    /// tagged with the mirror's name.
+    ///
+    /// Categories are derived from the mirror's own configuration.
+    pub fn items(&self, archived: bool, filter: &FilterCriteria) -> Vec<Item> {
+        let config = Config::mirror(self.root.clone());
+        let paths: Vec<PathBuf> = if archived {
+            walk_archived(&config).collect()
+        } else {
+            walk_items(&config).collect()
+        };
+        paths
+            .into_iter()
+            .filter_map(|path| {
+                let mut item = Item::load(&path).ok()?;
+                let category = derive_category(&config, &path);
+                if !matches_filter(&item, filter, category.as_deref()) {
+                    return None;
+                }
+                item.source = Some(self.name.clone());
+                Some(item)
+            })
+            .collect()
+    }
+
+    /// Formats an item path for display: `[name] path/relative/to/mirror.md`.
+    pub fn display_path(&self, path: &Path) -> String {
+        let relative =
+            super::paths::relative_to(path, &self.root).unwrap_or_else(|| path.to_path_buf());
+        format!("[{}] {}", self.name, relative.display())
+    }
+}
+
+/// Returns true if a mirror source is a git URL rather than a path.
+pub fn is_url(source: &str) -> bool {
+    source.contains("://") || source.starts_with("git@")
+}
+
+/// Returns the directory holding the project's mirror clones.
+pub fn clone_dir(config: &Config) -> Result<PathBuf> {
+    let Some(global) = GlobalConfig::dir() else {
+        bail!("Could not determine home directory");
+    };
+    Ok(global.join(MIRRORS_DIR).join(config.project_key()))
+}
+
+/// Returns the project's mirrors, sorted by name.
+pub fn mirrors(config: &Config) -> Result<Vec<Mirror>> {
+    let mirrors = config.mirrors();
+    if mirrors.is_empty() {
+        return Ok(Vec::new());
+    }
+    let clones = clone_dir(config)?;
+    Ok(mirrors
+        .into_iter()
+        .map(|(name, source)| {
+            let root = if is_url(&source) {
+                clones.join(&name)
+            } else {
+                config.project_root().join(&source)
+            };
+            Mirror { name, source, root }
+        })
+        .collect())
+}
+
+/// Loads the matching items of every readable mirror, in mirror order.
+///
+/// Mirrors that can't be read (a missing path, or a git mirror that hasn't
+/// been fetched yet) are skipped with a warning.
+pub fn load_all(
+    config: &Config,
+    archived: bool,
+    filter: &FilterCriteria,
+) -> Result<(Vec<MirroredItems>, Vec<String>)> {
+    let mut loaded = Vec::new();
+    let mut warnings = Vec::new();
+    for mirror in mirrors(config)? {
+        if !mirror.is_available() {
+            warnings.push(if mirror.is_remote() {
+                format!(
+                    "Mirror '{}' hasn't been fetched yet; run 'qs mirror fetch {}'",
+                    mirror.name, mirror.name
+                )
+            } else {
+                format!(
+                    "Mirror '{}' not found: {}",
+                    mirror.name,
+                    mirror.root.display()
+                )
+            });
+            continue;
+        }
+        let items = mirror.items(archived, filter);
+        loaded.push((mirror, items));
+    }
+    Ok((loaded, warnings))
+}
+
+/// Fetches a git mirror: a shallow clone the first time, then updates.
+///
+/// Path mirrors are read in place and need no fetching.
+pub fn fetch(mirror: &Mirror) -> Result<()> {
+    if !mirror.is_remote() {
+        return Ok(());
+    }
+    if mirror.root.join(".git").exists() {
+        return git::shallow_update(&mirror.root);
+    }
+    if let Some(parent) = mirror.root.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    git::shallow_clone(&mirror.source, &mirror.root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_url() {
+        assert!(is_url("https://github.com/example/library.git"));
+        assert!(is_url("file:///srv/git/library"));
+        assert!(is_url("git@github.com:example/library.git"));
+        assert!(!is_url("../upstream"));
+        assert!(!is_url("/srv/checkouts/upstream"));
+    }
+}
@@ -5,19 +5,83 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+pub mod checksum;
+pub mod draft;
 pub mod git;
-
-use std::path::{Path, PathBuf};
+pub mod git_files;
+pub mod github;
+pub mod hg;
+pub mod jj;
+pub mod links;
+pub mod lock;
+pub mod mirror;
+pub mod paths;
+pub mod plan;
+#[cfg(feature = "s3")]
+pub mod s3;
+pub mod sources;
+pub mod suggest;
+pub mod trash;
+pub mod vault;
+pub mod vcs;
+
+use std::{
+    collections::HashSet,
+    fmt,
+    path::{Path, PathBuf},
+};
 
 use anyhow::{bail, Context, Result};
+use chrono::{Datelike, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
 use walkdir::WalkDir;
 
 use crate::{
     config::Config,
-    constants::{ATTACHMENTS_DIR_SUFFIX, ITEM_FILE_EXTENSION},
-    item::{slugify, Item},
+    constants::{
+        ATTACHMENTS_DIR_SUFFIX, ATTACHMENT_STORE_DIR, ITEM_FILE_EXTENSION, VAULT_INDEX_FILE,
+    },
+    id,
+    item::{rules, slugify, Item, Status},
+    policy::{self, Hook},
 };
 
+/// How closed items are grouped in the archive directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ArchiveLayout {
+    /// Directly under the archive (`.archive/<category>/`)
+    #[default]
+    Flat,
+    /// Under the year closed (`.archive/2026/<category>/`)
+    ByYear,
+    /// Under the year and month closed (`.archive/2026/01/<category>/`)
+    ByMonth,
+}
+
+impl ArchiveLayout {
+    /// Returns the date directories an item closed on `date` is archived under.
+    pub fn date_dirs(self, date: NaiveDate) -> PathBuf {
+        match self {
+            Self::Flat => PathBuf::new(),
+            Self::ByYear => PathBuf::from(format!("{:04}", date.year())),
+            Self::ByMonth => {
+                PathBuf::from(format!("{:04}", date.year())).join(format!("{:02}", date.month()))
+            }
+        }
+    }
+}
+
+impl fmt::Display for ArchiveLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Flat => write!(f, "flat"),
+            Self::ByYear => write!(f, "by-year"),
+            Self::ByMonth => write!(f, "by-month"),
+        }
+    }
+}
+
 /// Walks markdown files in a directory with specified depth constraints.
 fn walk_markdown_files(
     path: PathBuf,
@@ -27,14 +91,16 @@ fn walk_markdown_files(
     WalkDir::new(path)
         .min_depth(min_depth)
         .max_depth(max_depth)
+        .follow_links(true)
         .into_iter()
         .filter_map(Result::ok)
         .filter(|e| e.file_type().is_file())
         .filter(|e| {
             e.path()
                 .extension()
-                .is_some_and(|ext| ext == ITEM_FILE_EXTENSION)
+                .is_some_and(|ext| ext.eq_ignore_ascii_case(ITEM_FILE_EXTENSION))
         })
+        .filter(|e| e.file_name() != VAULT_INDEX_FILE)
         .map(walkdir::DirEntry::into_path)
         .filter(|p| !is_inside_attachments_dir(p))
 }
@@ -50,18 +116,30 @@ fn is_inside_attachments_dir(path: &Path) -> bool {
 
 /// Walks all item files in the queuestack directory.
 ///
-/// Excludes items in the archive and template directories.
+/// Excludes items in the archive, template, and trash directories. Categories
+/// sit one level deeper inside the configured status directories.
 pub fn walk_items(config: &Config) -> impl Iterator<Item = PathBuf> {
-    let archive_path = config.archive_path();
-    let template_path = config.template_path();
+    let excluded = [
+        config.archive_path(),
+        config.template_path(),
+        config.trash_path(),
+    ];
+    let max_depth = if config.status_dirs().is_empty() {
+        3
+    } else {
+        4
+    };
 
-    walk_markdown_files(config.stack_path(), 1, 3)
-        .filter(move |p| !p.starts_with(&archive_path) && !p.starts_with(&template_path))
+    walk_markdown_files(config.stack_path(), 1, max_depth)
+        .filter(move |p| !excluded.iter().any(|dir| p.starts_with(dir)))
 }
 
 /// Walks all archived item files.
+///
+/// Archive categories may be nested one level (`done/2026`), below the year
+/// and month directories of the `by-year` and `by-month` layouts.
 pub fn walk_archived(config: &Config) -> impl Iterator<Item = PathBuf> {
-    walk_markdown_files(config.archive_path(), 1, 2)
+    walk_markdown_files(config.archive_path(), 1, 5)
 }
 
 /// Walks all template files.
@@ -93,10 +171,56 @@ pub struct LoadedItem {
 
 /// Finds and loads an item by partial ID match.
 ///
-/// Convenience wrapper that combines `find_by_id` and `Item::load`.
+/// Convenience wrapper that combines `find_by_id` and `Item::load`. The ID may
+/// carry the project's alias (`web:260109-ABC`); items of read-only mirrors
+/// are refused, see [`find_and_load_read_only`].
 pub fn find_and_load(config: &Config, partial_id: &str) -> Result<LoadedItem> {
-    let path = find_by_id(config, partial_id)?;
-    let item = Item::load(&path)?;
+    let loaded = find_and_load_read_only(config, partial_id)?;
+    if let Some(ref source) = loaded.item.source {
+        bail!(
+            "Item '{}' comes from the read-only mirror '{source}'",
+            loaded.item.qualified_id()
+        );
+    }
+    Ok(loaded)
+}
+
+/// Finds and loads an item by partial ID match, including items of mirrors.
+///
+/// A prefix selects the stack: the project's alias (its directory name) or a
+/// mirror name (`upstream:2601`). Mirrored items have `source` set.
+pub fn find_and_load_read_only(config: &Config, partial_id: &str) -> Result<LoadedItem> {
+    let (namespace, id) = crate::id::split_namespace(partial_id);
+    let Some(namespace) = namespace else {
+        let path = find_by_id(config, id)?;
+        let item = Item::load(&path)?;
+        return Ok(LoadedItem { path, item });
+    };
+
+    if namespace.eq_ignore_ascii_case(&config.project_alias()) {
+        return find_and_load_read_only(config, id);
+    }
+    let mirrors = mirror::mirrors(config)?;
+    let Some(found) = mirrors
+        .iter()
+        .find(|m| m.name.eq_ignore_ascii_case(namespace))
+    else {
+        bail!(
+            "Unknown project '{namespace}' in '{partial_id}' (expected '{}' or a mirror name)",
+            config.project_alias()
+        );
+    };
+    if !found.is_available() {
+        bail!(
+            "Mirror '{}' isn't available; run 'qs mirror fetch {}'",
+            found.name,
+            found.name
+        );
+    }
+
+    let path = find_by_id(&Config::mirror(found.root.clone()), id)?;
+    let mut item = Item::load(&path)?;
+    item.source = Some(found.name.clone());
     Ok(LoadedItem { path, item })
 }
 
@@ -149,11 +273,20 @@ impl ItemRef {
             Self::File(path) => load_from_file(path),
         }
     }
+
+    /// Resolves the reference for viewing; IDs may name items of mirrors.
+    pub fn resolve_read_only(&self, config: &Config) -> Result<LoadedItem> {
+        match self {
+            Self::Id(id) => find_and_load_read_only(config, id),
+            Self::File(path) => load_from_file(path),
+        }
+    }
 }
 
 /// Finds an item by partial ID match.
 ///
-/// Returns the full path to the item file.
+/// Returns the full path to the item file. When nothing or more than one item
+/// matches, the error is a [`suggest::LookupError`] with ranked candidates.
 pub fn find_by_id(config: &Config, partial_id: &str) -> Result<PathBuf> {
     let partial_upper = partial_id.to_uppercase();
 
@@ -166,19 +299,23 @@ pub fn find_by_id(config: &Config, partial_id: &str) -> Result<PathBuf> {
         })
         .collect();
 
+    // A full ID wins over items that merely contain it
+    if matches.len() > 1 {
+        let exact = matches.iter().find(|path| {
+            path.file_stem()
+                .and_then(|s| s.to_str())
+                .and_then(crate::id::extract_from_filename)
+                .is_some_and(|id| id.eq_ignore_ascii_case(partial_id))
+        });
+        if let Some(exact) = exact {
+            return Ok(exact.clone());
+        }
+    }
+
     match matches.len() {
-        0 => bail!("No item found matching '{partial_id}'"),
+        0 => Err(suggest::LookupError::not_found(config, partial_id).into()),
         1 => Ok(matches.into_iter().next().unwrap()),
-        _ => {
-            let ids: Vec<_> = matches
-                .iter()
-                .filter_map(|p| p.file_stem().and_then(|s| s.to_str()))
-                .collect();
-            bail!(
-                "Multiple items match '{partial_id}':\n  {}",
-                ids.join("\n  ")
-            );
-        }
+        _ => Err(suggest::LookupError::ambiguous(partial_id, &matches).into()),
     }
 }
 
@@ -308,9 +445,64 @@ pub fn find_template(config: &Config, reference: &str) -> Result<PathBuf> {
     }
 }
 
-/// Determines the target directory for an item based on its category.
-pub fn target_directory(config: &Config, category: Option<&str>) -> PathBuf {
-    category.map_or_else(|| config.stack_path(), |cat| config.category_path(cat))
+/// Determines the directory an item with the given status and category lives in.
+///
+/// All item moves go through here: closed items go to the archive (following
+/// `archive_layout`), templates to the template directory, and open and
+/// in-progress items to their status directory from `[status_dirs]`, or the
+/// stack root when none is configured.
+pub fn status_directory(config: &Config, status: Status, category: Option<&str>) -> PathBuf {
+    let base = match status {
+        Status::Closed => archive_base(config),
+        Status::Template => config.template_path(),
+        Status::Open | Status::InProgress => status_base(config, status),
+    };
+    category.map_or_else(|| base.clone(), |cat| base.join(cat))
+}
+
+/// Returns the directory of an active status before any category.
+fn status_base(config: &Config, status: Status) -> PathBuf {
+    let stack = config.stack_path();
+    config
+        .status_dirs()
+        .get(&status.to_string())
+        .map_or_else(|| stack.clone(), |dir| stack.join(dir))
+}
+
+/// Returns the status directory an item in the stack lives in, or `None` when
+/// it isn't inside one.
+pub fn status_dir_of(config: &Config, path: &Path) -> Option<String> {
+    let relative = paths::relative_to(path, &config.stack_path())?;
+    let first = relative.iter().next()?.to_str()?;
+    // The item file itself is not a directory
+    if relative.iter().count() < 2 {
+        return None;
+    }
+    config
+        .status_dirs()
+        .values()
+        .find(|dir| *dir == first)
+        .cloned()
+}
+
+/// Validates the `[status_dirs]` table: only active statuses can be mapped,
+/// each to a single visible directory name that doesn't clash with the
+/// archive or template directory.
+pub fn check_status_dirs(config: &Config) -> Result<()> {
+    for (status, dir) in config.status_dirs() {
+        if status != Status::Open.to_string() && status != Status::InProgress.to_string() {
+            bail!(
+                "Invalid status '{status}' in [status_dirs] (use 'open' or 'in-progress'; \
+                 closed items go to archive_dir)"
+            );
+        }
+        paths::check_file_name(&dir)
+            .with_context(|| format!("Invalid directory '{dir}' for status '{status}'"))?;
+        if dir.starts_with('.') || dir == config.archive_dir() || dir == config.template_dir() {
+            bail!("Invalid directory '{dir}' for status '{status}' (reserved or hidden name)");
+        }
+    }
+    Ok(())
 }
 
 /// Derives the category from an item's file path.
@@ -318,29 +510,26 @@ pub fn target_directory(config: &Config, category: Option<&str>) -> PathBuf {
 /// Returns `Some(category)` if the item is in a category subdirectory,
 /// or `None` if it's in the root of queuestack/archive/templates.
 ///
-/// Works for active items (in `stack_path`), archived items (in `archive_path`),
-/// and templates (in `template_path`).
+/// Works for active items (in `stack_path`, skipping their status directory),
+/// archived items (in `archive_path`, skipping the year and month directories
+/// of dated archive layouts), and templates (in `template_path`).
 pub fn derive_category(config: &Config, path: &Path) -> Option<String> {
-    let stack_path = config.stack_path();
-    let archive_path = config.archive_path();
-    let template_path = config.template_path();
-
-    // Canonicalize paths to handle symlinks (e.g., /var -> /private/var on macOS)
-    let path = path.canonicalize().ok()?;
-    let stack_path = stack_path.canonicalize().ok()?;
-    let archive_path = archive_path.canonicalize().unwrap_or(archive_path);
-    let template_path = template_path.canonicalize().unwrap_or(template_path);
-
-    // Determine base path (template, archive, or queuestack)
-    let relative = if path.starts_with(&template_path) {
-        path.strip_prefix(&template_path).ok()?
-    } else if path.starts_with(&archive_path) {
-        path.strip_prefix(&archive_path).ok()?
-    } else if path.starts_with(&stack_path) {
-        path.strip_prefix(&stack_path).ok()?
-    } else {
-        return None;
-    };
+    // Template and archive live inside the stack directory, so check them first.
+    // Resolves symlinked and bind-mounted directories along the way.
+    let archive = config.archive_path();
+    let stack = config.stack_path();
+    let relative = [config.template_path(), archive.clone(), stack.clone()]
+        .iter()
+        .find_map(|base| {
+            let relative = paths::relative_to(path, base)?;
+            Some(if *base == archive {
+                strip_date_dirs(&relative)
+            } else if *base == stack && status_dir_of(config, path).is_some() {
+                relative.iter().skip(1).collect()
+            } else {
+                relative
+            })
+        })?;
 
     // Get parent directory relative to base
     let parent = relative.parent()?;
@@ -361,35 +550,97 @@ pub fn derive_category(config: &Config, path: &Path) -> Option<String> {
     Some(category.to_string())
 }
 
+/// Strips the leading year (`2026`) and month (`01`) directories of a path
+/// relative to the archive. Recognized in any layout, so items archived before
+/// a layout change are still found in their categories.
+fn strip_date_dirs(relative: &Path) -> PathBuf {
+    fn is_year(s: &str) -> bool {
+        s.len() == 4 && s.bytes().all(|b| b.is_ascii_digit())
+    }
+    fn is_month(s: &str) -> bool {
+        s.len() == 2 && matches!(s.parse::<u32>(), Ok(1..=12))
+    }
+
+    let parts: Vec<_> = relative.iter().collect();
+    // Only directories are stripped: the last component is the item file
+    let dirs = parts.len().saturating_sub(1);
+    let is_dir = |index: usize, check: fn(&str) -> bool| {
+        index < dirs && parts[index].to_str().is_some_and(check)
+    };
+
+    let start = match (is_dir(0, is_year), is_dir(1, is_month)) {
+        (false, _) => 0,
+        (true, false) => 1,
+        (true, true) => 2,
+    };
+    parts[start..].iter().collect()
+}
+
 /// Creates a new item file and returns its path.
-pub fn create_item(config: &Config, item: &Item, category: Option<&str>) -> Result<PathBuf> {
-    let dir = target_directory(config, category);
+///
+/// Applies the configured auto-labeling rules and the label policy to the
+/// item, then runs the `pre_create` policy; a rejection leaves nothing on disk.
+pub fn create_item(config: &Config, item: &mut Item, category: Option<&str>) -> Result<PathBuf> {
+    rules::apply(config.rules(), item)?;
+    policy::check_labels(config, item, &[])?;
+    policy::check(config, Hook::PreCreate, item, category, None)?;
+    check_stack_category(config, category)?;
+
+    let dir = status_directory(config, item.status(), category);
 
     std::fs::create_dir_all(&dir)
         .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
 
-    let path = dir.join(item.filename());
-
-    item.save(&path)?;
-
-    Ok(path)
+    save_new_item(config, item, &dir)
 }
 
 /// Creates a new template file and returns its path.
 ///
 /// Templates are stored in the `.templates/` directory (or category subdirectory).
-pub fn create_template(config: &Config, item: &Item, category: Option<&str>) -> Result<PathBuf> {
+pub fn create_template(
+    config: &Config,
+    item: &mut Item,
+    category: Option<&str>,
+) -> Result<PathBuf> {
+    check_category(category)?;
     let base = config.template_path();
     let dir = category.map_or_else(|| base.clone(), |cat| base.join(cat));
 
     std::fs::create_dir_all(&dir)
         .with_context(|| format!("Failed to create template directory: {}", dir.display()))?;
 
-    let path = dir.join(item.filename());
+    save_new_item(config, item, &dir)
+}
 
-    item.save(&path)?;
+/// Saves a new item in `dir` without replacing an existing file.
+///
+/// If the file name is taken (the same ID and title, e.g. from a concurrent
+/// `qs new`), the item gets a fresh ID. ID patterns that can't produce another
+/// ID right away (no `%R`) fail instead, since IDs must stay unique.
+fn save_new_item(config: &Config, item: &mut Item, dir: &Path) -> Result<PathBuf> {
+    const MAX_ATTEMPTS: usize = 20;
+
+    let mut path = dir.join(item.filename());
+    for _ in 0..MAX_ATTEMPTS {
+        if item.save_new(&path)? {
+            return Ok(path);
+        }
 
-    Ok(path)
+        let id = id::generate(config.id_pattern());
+        if id == item.id() {
+            bail!(
+                "ID '{id}' already exists: {} (add %R to id_pattern for unique IDs)",
+                path.display()
+            );
+        }
+        item.set_id(id);
+        path = dir.join(item.filename());
+    }
+    bail!(
+        "Could not find a free file name for '{}' in {}",
+        item.title(),
+        dir.display()
+    )
 }
 
 /// Internal helper to move an item to a destination directory.
@@ -416,10 +667,28 @@ fn move_item_to_dir(
     // Remember source directory for cleanup
     let src_dir = path.parent().map(Path::to_path_buf);
 
-    // Move attachments first (from source item path to destination item path)
-    let warnings = move_attachments(path, &dest);
+    // Move the item and its attachments in one batch (external attachment
+    // directories stay where they are)
+    let mut moves = vec![(path.to_path_buf(), dest.clone())];
+    if config.attachment_path().is_none() {
+        moves.extend(attachment_moves(path, &dest));
+    }
 
-    git::move_file(path, &dest)?;
+    let mut warnings = Vec::new();
+    for (from, err) in vcs::move_files(&moves) {
+        if from == path {
+            return Err(err);
+        }
+        warnings.push(format!(
+            "Failed to move attachment {}: {}",
+            from.display(),
+            err
+        ));
+    }
+
+    if config.attachment_path().is_none() {
+        cleanup_empty_attachment_dir(&attachment_dir_for_item(path));
+    }
 
     // Clean up empty source directory if it was a category
     if let Some(src_dir) = src_dir {
@@ -434,13 +703,66 @@ fn move_item_to_dir(
 /// Preserves category folder structure in archive.
 /// Returns the new path and any warnings from moving attachments.
 pub fn archive_item(config: &Config, path: &Path) -> Result<(PathBuf, Vec<String>)> {
+    move_to_status(config, path, Status::Closed)
+}
+
+/// Returns the archive directory an item moves to when closed (keeps its category).
+pub fn archive_directory(config: &Config, path: &Path) -> PathBuf {
+    status_directory(
+        config,
+        Status::Closed,
+        derive_category(config, path).as_deref(),
+    )
+}
+
+/// Moves an item to the directory of a new status, keeping its category.
+///
+/// Returns the new path and any warnings from moving attachments.
+pub fn move_to_status(
+    config: &Config,
+    path: &Path,
+    status: Status,
+) -> Result<(PathBuf, Vec<String>)> {
     let category = derive_category(config, path);
-    let archive_base = config.archive_path();
-    let dest_dir = category
-        .as_deref()
-        .map_or_else(|| archive_base.clone(), |cat| archive_base.join(cat));
+    move_item_to_dir(
+        config,
+        path,
+        &status_directory(config, status, category.as_deref()),
+    )
+}
 
-    move_item_to_dir(config, path, &dest_dir)
+/// Moves an item into an archive category (`done` or `done/2026`), regardless
+/// of its current category.
+pub fn archive_item_to(
+    config: &Config,
+    path: &Path,
+    category: &str,
+) -> Result<(PathBuf, Vec<String>)> {
+    move_item_to_dir(config, path, &archive_category_path(config, category)?)
+}
+
+/// Returns the archive directory of an archive category, validating each of
+/// its `/`-separated segments.
+pub fn archive_category_path(config: &Config, category: &str) -> Result<PathBuf> {
+    let segments: Vec<&str> = category.split('/').collect();
+    if segments.len() > 2 || segments.iter().any(|s| s.trim().is_empty()) {
+        bail!("Invalid archive category '{category}' (use 'name' or 'name/subname')");
+    }
+
+    let mut dir = archive_base(config);
+    for segment in segments {
+        check_category(Some(segment))?;
+        dir.push(segment);
+    }
+    Ok(dir)
+}
+
+/// Returns the directory items closed today are archived under, following the
+/// configured archive layout.
+fn archive_base(config: &Config) -> PathBuf {
+    config
+        .archive_path()
+        .join(config.archive_layout().date_dirs(Local::now().date_naive()))
 }
 
 /// Moves an item from the archive back to queuestack.
@@ -448,10 +770,16 @@ pub fn archive_item(config: &Config, path: &Path) -> Result<(PathBuf, Vec<String
 /// Derives category from archive path structure and restores to same category.
 /// Returns the new path and any warnings from moving attachments.
 pub fn unarchive_item(config: &Config, path: &Path) -> Result<(PathBuf, Vec<String>)> {
-    let category = derive_category(config, path);
-    let dest_dir = target_directory(config, category.as_deref());
+    move_to_status(config, path, Status::Open)
+}
 
-    move_item_to_dir(config, path, &dest_dir)
+/// Returns the directory an archived item moves back to when reopened.
+pub fn unarchive_directory(config: &Config, path: &Path) -> PathBuf {
+    status_directory(
+        config,
+        Status::Open,
+        derive_category(config, path).as_deref(),
+    )
 }
 
 /// Renames an item file (when title changes).
@@ -462,25 +790,47 @@ pub fn rename_item(path: &Path, new_filename: &str) -> Result<PathBuf> {
     let new_path = dir.join(new_filename);
 
     if path != new_path {
-        git::move_file(path, &new_path)?;
+        vcs::move_file(path, &new_path)?;
     }
 
     Ok(new_path)
 }
 
-/// Moves an item to a different category.
+/// Moves an item to a different category within the directory of its status.
 ///
 /// Returns the new path and any warnings from moving attachments.
 pub fn move_to_category(
     config: &Config,
     path: &Path,
+    status: Status,
     category: Option<&str>,
 ) -> Result<(PathBuf, Vec<String>)> {
-    let dest_dir = target_directory(config, category);
+    check_stack_category(config, category)?;
+    let dest_dir = status_directory(config, status, category);
     move_item_to_dir(config, path, &dest_dir)
 }
 
-/// Removes an empty category directory if it's safe to do so.
+/// Validates a category for items in the stack: besides [`check_category`],
+/// it must not be named like a status directory, which would hide it.
+pub fn check_stack_category(config: &Config, category: Option<&str>) -> Result<()> {
+    check_category(category)?;
+    if let Some(cat) = category {
+        if config.status_dirs().values().any(|dir| dir == cat) {
+            bail!("Invalid category '{cat}': it is the directory of a status ([status_dirs])");
+        }
+    }
+    Ok(())
+}
+
+/// Validates that a category name can be used as a directory on every platform.
+pub fn check_category(category: Option<&str>) -> Result<()> {
+    category.map_or(Ok(()), |cat| {
+        paths::check_file_name(cat).with_context(|| format!("Invalid category '{cat}'"))
+    })
+}
+
+/// Removes an empty category directory if it's safe to do so, then its parents
+/// that became empty (the year and month directories of dated archives).
 ///
 /// Only removes directories that:
 /// - Are inside the queuestack directory (including archive)
@@ -488,25 +838,112 @@ pub fn move_to_category(
 /// - Are empty
 fn cleanup_empty_category_dir(config: &Config, dir: &Path) {
     let stack_path = config.stack_path();
-    let archive_path = config.archive_path();
+    let roots = root_dirs(config);
 
-    // Never remove root directories
-    if dir == stack_path || dir == archive_path {
-        return;
+    let mut dir = dir;
+    loop {
+        // Never remove root directories
+        if roots.iter().any(|root| root == dir) {
+            return;
+        }
+
+        // Only clean up directories inside queuestack (which includes archive)
+        if !dir.starts_with(&stack_path) {
+            return;
+        }
+
+        // Check if directory is empty (a generated vault index note doesn't count)
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        let mut names = entries.filter_map(Result::ok).map(|e| e.file_name());
+        match (names.next(), names.next()) {
+            (None, _) => {}
+            (Some(name), None) if name == VAULT_INDEX_FILE => {
+                let _ = std::fs::remove_file(dir.join(VAULT_INDEX_FILE));
+            }
+            _ => return,
+        }
+        if std::fs::remove_dir(dir).is_err() {
+            return;
+        }
+
+        match dir.parent() {
+            Some(parent) => dir = parent,
+            None => return,
+        }
     }
+}
+
+/// Directories that are never removed when empty: the stack, archive,
+/// template, and trash directories.
+fn root_dirs(config: &Config) -> [PathBuf; 4] {
+    [
+        config.stack_path(),
+        config.archive_path(),
+        config.template_path(),
+        config.trash_path(),
+    ]
+}
+
+/// Finds the empty category, archive, attachment, and trash directories below
+/// the stack directory, deepest first.
+///
+/// A directory counts as empty if it holds nothing but empty directories and a
+/// generated vault index note. Hidden directories other than the archive,
+/// template, and trash directories (e.g. `.obsidian`) are left alone.
+pub fn find_empty_dirs(config: &Config) -> Vec<PathBuf> {
+    let roots = root_dirs(config);
+    let mut empty = HashSet::new();
+    let mut found = Vec::new();
+
+    let stack_path = config.stack_path();
+    // `filter_entry` can't prune when contents come first, so check the path
+    let is_managed = |dir: &Path| {
+        roots[1..].iter().any(|root| dir.starts_with(root))
+            || dir.strip_prefix(&stack_path).is_ok_and(|relative| {
+                !relative
+                    .iter()
+                    .any(|part| part.to_string_lossy().starts_with('.'))
+            })
+    };
 
-    // Only clean up directories inside queuestack (which includes archive)
-    if !dir.starts_with(&stack_path) {
-        return;
+    let entries = WalkDir::new(&stack_path)
+        .contents_first(true)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_dir());
+    for entry in entries {
+        let dir = entry.path();
+        if roots.iter().any(|root| root == dir) || !is_managed(dir) {
+            continue;
+        }
+        let Ok(children) = std::fs::read_dir(dir) else {
+            continue;
+        };
+        let is_empty = children
+            .filter_map(Result::ok)
+            .all(|child| child.file_name() == VAULT_INDEX_FILE || empty.contains(&child.path()));
+        if is_empty {
+            empty.insert(dir.to_path_buf());
+            found.push(dir.to_path_buf());
+        }
     }
+    found
+}
 
-    // Check if directory is empty
-    if let Ok(mut entries) = std::fs::read_dir(dir) {
-        if entries.next().is_none() {
-            // Directory is empty, remove it
-            let _ = std::fs::remove_dir(dir);
+/// Removes the empty directories found by [`find_empty_dirs`] and returns them.
+pub fn remove_empty_dirs(config: &Config) -> Result<Vec<PathBuf>> {
+    let dirs = find_empty_dirs(config);
+    for dir in &dirs {
+        let index = dir.join(VAULT_INDEX_FILE);
+        if index.is_file() {
+            std::fs::remove_file(&index)
+                .with_context(|| format!("Failed to remove {}", index.display()))?;
         }
+        std::fs::remove_dir(dir).with_context(|| format!("Failed to remove {}", dir.display()))?;
     }
+    Ok(dirs)
 }
 
 // =============================================================================
@@ -577,24 +1014,44 @@ pub fn attachment_dir_for_item(item_path: &Path) -> PathBuf {
     ))
 }
 
+/// Returns the attachment directory for an item.
+///
+/// This is the external directory when `attachment_path` is configured,
+/// otherwise the `.attachments/` directory next to the item file.
+pub fn attachment_dir(config: &Config, id: &str, item_path: &Path) -> PathBuf {
+    config
+        .external_attachment_dir(id)
+        .unwrap_or_else(|| attachment_dir_for_item(item_path))
+}
+
 /// Result of processing a single attachment.
 #[derive(Debug)]
 pub enum AttachmentResult {
-    /// URL was added directly to frontmatter
-    UrlAdded(String),
-    /// File was copied and added
-    FileCopied { original: String, new_name: String },
+    /// URL was added directly to frontmatter, with its page title if fetched
+    UrlAdded { url: String, title: Option<String> },
+    /// File was copied and added; `duplicates` lists other items with the same file
+    FileCopied {
+        original: String,
+        new_name: String,
+        duplicates: Vec<String>,
+    },
     /// File was not found
     FileNotFound(String),
+    /// File is a directory or violates the attachment policy
+    Rejected { file: String, reason: String },
 }
 
 /// Processes a single attachment source (file path or URL).
 ///
-/// - URLs are returned as-is for adding to frontmatter
-/// - Files are copied to the item's attachment directory with a standardized name
+/// - URLs are returned as-is for adding to frontmatter; with `fetch_link_titles`,
+///   their page title is recorded too
+/// - Files are checked against the attachment policy, then copied to the item's
+///   attachment directory with a standardized name, and their checksum is recorded
+///   in the frontmatter
 ///
 /// Returns `AttachmentResult` indicating what happened.
 pub fn process_attachment(
+    config: &Config,
     source: &str,
     item: &mut crate::item::Item,
     item_path: &Path,
@@ -603,16 +1060,21 @@ pub fn process_attachment(
 
     if is_url(source) {
         item.add_attachment(source.to_string());
-        return Ok(AttachmentResult::UrlAdded(source.to_string()));
+        let title = config
+            .fetch_link_titles()
+            .then(|| links::fetch_title(source))
+            .flatten();
+        if let Some(ref title) = title {
+            item.set_link_title(source.to_string(), title.clone());
+        }
+        return Ok(AttachmentResult::UrlAdded {
+            url: source.to_string(),
+            title,
+        });
     }
 
     // File attachment - resolve path
-    let source_path = Path::new(source);
-    let source_path = if source_path.is_relative() {
-        std::env::current_dir()?.join(source_path)
-    } else {
-        source_path.to_path_buf()
-    };
+    let source_path = paths::resolve(&std::env::current_dir()?, source);
 
     if !source_path.exists() {
         return Ok(AttachmentResult::FileNotFound(
@@ -620,23 +1082,154 @@ pub fn process_attachment(
         ));
     }
 
+    let reason = if source_path.is_dir() {
+        Some("is a directory".to_string())
+    } else {
+        policy::check_attachment(config, &source_path)?
+    };
+    if let Some(reason) = reason {
+        return Ok(AttachmentResult::Rejected {
+            file: source.to_string(),
+            reason,
+        });
+    }
+
     let counter = item.next_attachment_counter();
-    let attachment_dir = attachment_dir_for_item(item_path);
-    let new_filename = copy_attachment(&source_path, &attachment_dir, counter)?;
-    item.add_attachment(new_filename.clone());
+    let checksum = checksum::sha256_file(&source_path)?;
+    let duplicates = find_by_checksum(config, &checksum)
+        .into_iter()
+        .map(|(id, _)| id)
+        .filter(|id| id != item.id())
+        .collect();
+
+    let new_name = if let Some(base) = config.s3_url() {
+        upload_attachment(&base, &source_path, item.id(), counter)?
+    } else {
+        let attachment_dir = attachment_dir(config, item.id(), item_path);
+        let new_filename = copy_attachment(&source_path, &attachment_dir, counter)?;
+        if config.attachment_dedup() {
+            link_into_store(config, &attachment_dir.join(&new_filename), &checksum)?;
+        }
+        new_filename
+    };
+    item.add_attachment(new_name.clone());
+    item.set_checksum(new_name.clone(), checksum);
 
     Ok(AttachmentResult::FileCopied {
         original: source.to_string(),
-        new_name: new_filename,
+        new_name,
+        duplicates,
     })
 }
 
-/// Copies a file as an attachment to the item's attachment directory.
+/// Returns `(item ID, attachment)` for every recorded attachment with the given checksum.
+pub fn find_by_checksum(config: &Config, checksum: &str) -> Vec<(String, String)> {
+    load_all_items(config)
+        .into_iter()
+        .flat_map(|item| {
+            let id = item.id().to_string();
+            item.frontmatter
+                .checksums
+                .into_iter()
+                .filter(|(_, c)| c == checksum)
+                .map(move |(name, _)| (id.clone(), name))
+        })
+        .collect()
+}
+
+/// Creates the cache directory for runtime state and returns its path.
 ///
-/// Creates the attachment directory if it doesn't exist.
-/// Returns the new filename using the standard attachment naming convention.
-pub fn copy_attachment(source: &Path, attachment_dir: &Path, counter: u32) -> Result<String> {
-    // Get original filename parts
+/// The directory ignores itself with a `.gitignore` of `*`, so it stays out of
+/// version control whatever the project's own ignore files say.
+pub fn create_cache_dir(config: &Config) -> Result<PathBuf> {
+    let dir = config.cache_path();
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+    let ignore = dir.join(".gitignore");
+    if !ignore.exists() {
+        std::fs::write(&ignore, "*\n")
+            .with_context(|| format!("Failed to write {}", ignore.display()))?;
+    }
+    Ok(dir)
+}
+
+/// Returns the shared store directory for deduplicated attachments.
+pub fn attachment_store(config: &Config) -> PathBuf {
+    config.stack_path().join(ATTACHMENT_STORE_DIR)
+}
+
+/// Replaces an attachment file with a hard link to its blob in the shared store.
+///
+/// The first copy of a file becomes the blob. The store is git-ignored, since
+/// git already stores identical content once. When hard links aren't possible
+/// (e.g. across file systems), the plain copy is kept.
+pub fn link_into_store(config: &Config, file: &Path, checksum: &str) -> Result<()> {
+    let store = attachment_store(config);
+    std::fs::create_dir_all(&store)
+        .with_context(|| format!("Failed to create attachment store: {}", store.display()))?;
+    let ignore = store.join(".gitignore");
+    if !ignore.exists() {
+        std::fs::write(&ignore, "*\n")
+            .with_context(|| format!("Failed to write {}", ignore.display()))?;
+    }
+
+    let blob = store.join(checksum);
+    if !blob.exists() {
+        let _ = std::fs::hard_link(file, &blob);
+        return Ok(());
+    }
+
+    let name = file.file_name().unwrap_or_default().to_string_lossy();
+    let staged = file.with_file_name(format!(".{name}.dedup"));
+    if std::fs::hard_link(&blob, &staged).is_ok() {
+        std::fs::rename(&staged, file)
+            .with_context(|| format!("Failed to link attachment: {}", file.display()))?;
+    }
+    Ok(())
+}
+
+/// Uploads a file attachment to object storage; returns its `s3://` URL.
+#[cfg(feature = "s3")]
+fn upload_attachment(base: &str, source: &Path, id: &str, counter: u32) -> Result<String> {
+    let url = s3::object_url(base, id, &attachment_file_name(source, counter))?;
+    s3::upload(source, &url)?;
+    Ok(url)
+}
+
+#[cfg(not(feature = "s3"))]
+fn upload_attachment(_base: &str, _source: &Path, _id: &str, _counter: u32) -> Result<String> {
+    bail!("s3_url is set, but qs was built without the 's3' feature")
+}
+
+/// Deletes an attachment from object storage.
+///
+/// Only objects below the configured `s3_url` are deleted; other URLs are plain references.
+pub fn delete_object_attachment(config: &Config, url: &str) -> Result<()> {
+    let Some(base) = config.s3_url() else {
+        return Ok(());
+    };
+    if !url.starts_with(base.trim_end_matches('/')) {
+        return Ok(());
+    }
+
+    #[cfg(feature = "s3")]
+    return s3::delete(url);
+
+    #[cfg(not(feature = "s3"))]
+    bail!("s3_url is set, but qs was built without the 's3' feature")
+}
+
+/// Generates a time-limited HTTPS URL for an object storage attachment.
+pub fn presign_object_attachment(url: &str) -> Result<String> {
+    #[cfg(feature = "s3")]
+    return s3::presign(url);
+
+    #[cfg(not(feature = "s3"))]
+    bail!("Cannot open '{url}': qs was built without the 's3' feature")
+}
+
+/// Returns the standardized attachment file name for a source file: `{counter}-{slug}.{ext}`.
+fn attachment_file_name(source: &Path, counter: u32) -> String {
     let original_name = source
         .file_stem()
         .and_then(|s| s.to_str())
@@ -648,9 +1241,15 @@ pub fn copy_attachment(source: &Path, attachment_dir: &Path, counter: u32) -> Re
     let slug = slugify(original_name);
     let slug_part = if slug.is_empty() { "file" } else { &slug };
 
-    // Build filename using the struct
-    let attachment = AttachmentFileName::new(counter, slug_part, extension);
-    let new_filename = attachment.to_filename();
+    AttachmentFileName::new(counter, slug_part, extension).to_filename()
+}
+
+/// Copies a file as an attachment to the item's attachment directory.
+///
+/// Creates the attachment directory if it doesn't exist.
+/// Returns the new filename using the standard attachment naming convention.
+pub fn copy_attachment(source: &Path, attachment_dir: &Path, counter: u32) -> Result<String> {
+    let new_filename = attachment_file_name(source, counter);
 
     // Create attachment directory if needed
     std::fs::create_dir_all(attachment_dir).with_context(|| {
@@ -705,7 +1304,7 @@ pub fn delete_attachment(attachment_dir: &Path, filename: &str) -> Result<()> {
     }
 
     // Use git rm if in a git repo, otherwise standard remove
-    git::remove_file(&path)?;
+    vcs::remove_file(&path)?;
     cleanup_empty_attachment_dir(attachment_dir);
     Ok(())
 }
@@ -722,55 +1321,11 @@ fn cleanup_empty_attachment_dir(dir: &Path) {
     }
 }
 
-/// Deletes an item file and its attachment directory.
+/// Deletes an item by moving it and its attachment directory into the trash.
 ///
-/// Uses `trash` command if available (macOS), otherwise uses git rm or standard remove.
-pub fn delete_item(path: &Path) -> Result<()> {
-    if !path.exists() {
-        return Ok(());
-    }
-
-    let attachment_dir = attachment_dir_for_item(path);
-
-    // Try to use trash command (macOS) for safe deletion
-    #[cfg(target_os = "macos")]
-    {
-        use std::process::{Command, Stdio};
-
-        let status = Command::new("trash")
-            .arg(path)
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .status();
-
-        if status.is_ok_and(|s| s.success()) {
-            // Also trash attachment directory if it exists
-            if attachment_dir.exists() {
-                let _ = Command::new("trash")
-                    .arg(&attachment_dir)
-                    .stdout(Stdio::null())
-                    .stderr(Stdio::null())
-                    .status();
-            }
-            return Ok(());
-        }
-        // Fall through to git rm / standard remove
-    }
-
-    // Use git rm if in a git repo, otherwise standard remove
-    git::remove_file(path)?;
-
-    // Remove attachment directory if it exists
-    if attachment_dir.exists() {
-        std::fs::remove_dir_all(&attachment_dir).with_context(|| {
-            format!(
-                "Failed to remove attachment directory: {}",
-                attachment_dir.display()
-            )
-        })?;
-    }
-
-    Ok(())
+/// Returns the path inside the trash and any warnings from moving attachments.
+pub fn delete_item(config: &Config, path: &Path) -> Result<(PathBuf, Vec<String>)> {
+    trash::trash_item(config, path)
 }
 
 /// Finds all attachment files for an item.
@@ -792,54 +1347,25 @@ pub fn find_attachment_files(item_path: &Path) -> Vec<PathBuf> {
         .collect()
 }
 
-/// Moves the attachment directory alongside an item.
+/// Lists the attachment moves needed to keep an item's `.attachments/` directory
+/// alongside it.
 ///
 /// Called internally when archiving, unarchiving, or moving items between categories.
-/// Moves the entire `.attachments/` directory as a unit.
-/// Returns a list of warnings for any issues during the move.
-fn move_attachments(src_item_path: &Path, dest_item_path: &Path) -> Vec<String> {
-    let mut warnings = Vec::new();
-
-    let src_attachment_dir = attachment_dir_for_item(src_item_path);
+/// Files are moved individually (batched by the caller) because git tracks files,
+/// not directories.
+fn attachment_moves(src_item_path: &Path, dest_item_path: &Path) -> Vec<(PathBuf, PathBuf)> {
     let dest_attachment_dir = attachment_dir_for_item(dest_item_path);
 
-    // Nothing to move if source directory doesn't exist
-    if !src_attachment_dir.exists() {
-        return warnings;
-    }
-
-    // Move each file in the attachment directory
-    // We can't just rename the directory because git needs to track individual files
-    if let Ok(entries) = std::fs::read_dir(&src_attachment_dir) {
-        // Create destination directory
-        if let Err(e) = std::fs::create_dir_all(&dest_attachment_dir) {
-            warnings.push(format!(
-                "Failed to create attachment directory {}: {}",
-                dest_attachment_dir.display(),
-                e
-            ));
-            return warnings;
-        }
-
-        for entry in entries.filter_map(Result::ok) {
+    std::fs::read_dir(attachment_dir_for_item(src_item_path))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
             let src_path = entry.path();
-            if let Some(filename) = src_path.file_name() {
-                let dest_path = dest_attachment_dir.join(filename);
-                if let Err(e) = git::move_file(&src_path, &dest_path) {
-                    warnings.push(format!(
-                        "Failed to move attachment {}: {}",
-                        src_path.display(),
-                        e
-                    ));
-                }
-            }
-        }
-    }
-
-    // Clean up empty source directory
-    cleanup_empty_attachment_dir(&src_attachment_dir);
-
-    warnings
+            let dest_path = dest_attachment_dir.join(src_path.file_name()?);
+            Some((src_path, dest_path))
+        })
+        .collect()
 }
 
 // Tests for storage are in tests/integration.rs as they require
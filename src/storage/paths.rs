@@ -0,0 +1,316 @@
+//! # Portable Paths
+//!
+//! Path helpers that keep queuestack working on Windows: reserved device names,
+//! `\\?\` (verbatim) prefixes, and drive-relative paths like `C:notes.txt`;
+//! and with symlinked or bind-mounted project and stack directories.
+//!
+//! `std::fs` adds the `\\?\` prefix for long paths by itself, and `canonicalize`
+//! returns prefixed paths on Windows. External tools (git in particular) don't
+//! understand the prefix, so paths handed to them go through [`simplified`].
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+
+/// Device names Windows reserves in every directory, with or without an extension.
+const RESERVED_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM0", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7",
+    "COM8", "COM9", "LPT0", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Characters Windows doesn't allow in file names.
+const INVALID_CHARS: &[char] = &['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
+
+/// Longest path Windows APIs accept without the `\\?\` prefix.
+const MAX_PATH: usize = 260;
+
+/// Returns true if `name` is a reserved device name on Windows (`CON`, `nul.txt`, ...).
+pub fn is_reserved_name(name: &str) -> bool {
+    let stem = name.split('.').next().unwrap_or(name);
+    let stem = stem.trim_end_matches([' ', '.']);
+    RESERVED_NAMES
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(stem))
+}
+
+/// Validates a single path component (e.g. a category name) for all platforms.
+pub fn check_file_name(name: &str) -> Result<()> {
+    if is_reserved_name(name) {
+        bail!("'{name}' is a reserved file name on Windows");
+    }
+    if name
+        .chars()
+        .any(|c| c.is_control() || INVALID_CHARS.contains(&c))
+    {
+        bail!("'{name}' contains characters that aren't allowed in file names");
+    }
+    if name.ends_with(['.', ' ']) {
+        bail!("'{name}' can't end with a dot or space");
+    }
+    Ok(())
+}
+
+/// Strips the `\\?\` prefix from a path when it isn't needed.
+///
+/// Keeps the prefix for paths that are too long or contain components Windows
+/// would otherwise reinterpret. A no-op on other platforms.
+pub fn simplified(path: &Path) -> PathBuf {
+    if cfg!(windows) {
+        if let Some(plain) = path.to_str().and_then(strip_verbatim) {
+            return PathBuf::from(plain);
+        }
+    }
+    path.to_path_buf()
+}
+
+/// Resolves a user-supplied path against `cwd`.
+///
+/// On Windows, drive-relative paths (`D:report.pdf`) resolve against the
+/// current directory if it is on that drive, otherwise against the drive root.
+pub fn resolve(cwd: &Path, source: &str) -> PathBuf {
+    if cfg!(windows) {
+        if let Some((drive, rest)) = split_drive_relative(source) {
+            let on_drive = split_drive(&cwd.to_string_lossy())
+                .is_some_and(|cwd_drive| cwd_drive.eq_ignore_ascii_case(&drive));
+            let base = if on_drive {
+                cwd.to_path_buf()
+            } else {
+                PathBuf::from(format!("{drive}:\\"))
+            };
+            return base.join(rest);
+        }
+    }
+
+    let path = Path::new(source);
+    if path.is_relative() {
+        cwd.join(path)
+    } else {
+        path.to_path_buf()
+    }
+}
+
+/// Returns the current directory as the user sees it.
+///
+/// The kernel reports the physical directory with symlinks resolved; the shell's
+/// `PWD` keeps them. `PWD` is preferred whenever it still refers to the same
+/// directory, so that walking up from a symlinked stack directory finds the
+/// project it is linked into.
+pub fn current_dir() -> std::io::Result<PathBuf> {
+    let physical = std::env::current_dir()?;
+    let logical = std::env::var_os("PWD")
+        .map(PathBuf::from)
+        .filter(|pwd| pwd.is_absolute() && same_file(pwd, &physical));
+    Ok(logical.unwrap_or(physical))
+}
+
+/// Returns true if both paths exist and refer to the same file or directory,
+/// following symlinks and bind mounts.
+pub fn same_file(a: &Path, b: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+
+        match (a.metadata(), b.metadata()) {
+            (Ok(a), Ok(b)) => a.dev() == b.dev() && a.ino() == b.ino(),
+            _ => false,
+        }
+    }
+
+    #[cfg(not(unix))]
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Returns `path` relative to `base`, or `None` if it isn't below it.
+///
+/// Tries a plain prefix match first, then canonical paths (symlinks), and
+/// finally looks for an ancestor of `path` that is the same directory as
+/// `base` (symlinked subdirectories, bind mounts).
+pub fn relative_to(path: &Path, base: &Path) -> Option<PathBuf> {
+    if let Ok(relative) = path.strip_prefix(base) {
+        return Some(relative.to_path_buf());
+    }
+
+    let canonical = |p: &Path| p.canonicalize().ok().map(|p| simplified(&p));
+    if let (Some(path), Some(base)) = (canonical(path), canonical(base)) {
+        if let Ok(relative) = path.strip_prefix(&base) {
+            return Some(relative.to_path_buf());
+        }
+    }
+
+    path.ancestors()
+        .skip(1)
+        .find(|ancestor| same_file(ancestor, base))
+        .and_then(|ancestor| path.strip_prefix(ancestor).ok())
+        .map(Path::to_path_buf)
+}
+
+/// Returns the plain form of a verbatim drive path (`\\?\C:\dir` -> `C:\dir`),
+/// or `None` if the prefix has to stay.
+fn strip_verbatim(path: &str) -> Option<&str> {
+    let plain = path.strip_prefix(r"\\?\")?;
+    split_drive(plain)?;
+    if !plain[2..].starts_with('\\') || plain.len() >= MAX_PATH {
+        return None;
+    }
+
+    // Components that only a verbatim path can express
+    let representable = plain[3..]
+        .split('\\')
+        .all(|c| !matches!(c, "." | "..") && !c.ends_with(['.', ' ']) && !is_reserved_name(c));
+    representable.then_some(plain)
+}
+
+/// Returns the drive letter of a path starting with `X:`.
+fn split_drive(path: &str) -> Option<char> {
+    let mut chars = path.chars();
+    let drive = chars.next().filter(char::is_ascii_alphabetic)?;
+    (chars.next() == Some(':')).then_some(drive)
+}
+
+/// Splits a drive-relative path (`C:dir\file`, not `C:\dir\file`) into drive and rest.
+fn split_drive_relative(path: &str) -> Option<(char, &str)> {
+    let drive = split_drive(path)?;
+    let rest = &path[2..];
+    (!rest.starts_with(['\\', '/'])).then_some((drive, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserved_names() {
+        assert!(is_reserved_name("CON"));
+        assert!(is_reserved_name("nul"));
+        assert!(is_reserved_name("Com1.txt"));
+        assert!(is_reserved_name("aux.tar.gz"));
+        assert!(is_reserved_name("lpt9 "));
+        assert!(!is_reserved_name("console"));
+        assert!(!is_reserved_name("1-con.txt"));
+        assert!(!is_reserved_name("com10"));
+    }
+
+    #[test]
+    fn test_check_file_name() {
+        assert!(check_file_name("bugs").is_ok());
+        assert!(check_file_name("über-änderung").is_ok());
+        assert!(check_file_name("con").is_err());
+        assert!(check_file_name("a:b").is_err());
+        assert!(check_file_name("what?").is_err());
+        assert!(check_file_name("trailing.").is_err());
+    }
+
+    #[test]
+    fn test_strip_verbatim() {
+        assert_eq!(
+            strip_verbatim(r"\\?\C:\Users\dom\project"),
+            Some(r"C:\Users\dom\project")
+        );
+        assert_eq!(strip_verbatim(r"C:\Users"), None);
+        assert_eq!(strip_verbatim(r"\\?\UNC\server\share"), None);
+        assert_eq!(strip_verbatim(r"\\?\C:\stack\nul"), None);
+        assert_eq!(strip_verbatim(r"\\?\C:\stack\dots."), None);
+
+        let long = format!(r"\\?\C:\{}", "a".repeat(MAX_PATH));
+        assert_eq!(strip_verbatim(&long), None);
+    }
+
+    #[test]
+    fn test_split_drive_relative() {
+        assert_eq!(
+            split_drive_relative("C:notes.txt"),
+            Some(('C', "notes.txt"))
+        );
+        assert_eq!(
+            split_drive_relative(r"d:sub\file"),
+            Some(('d', r"sub\file"))
+        );
+        assert_eq!(split_drive_relative(r"C:\notes.txt"), None);
+        assert_eq!(split_drive_relative("C:/notes.txt"), None);
+        assert_eq!(split_drive_relative("notes.txt"), None);
+        assert_eq!(split_drive_relative("1:notes.txt"), None);
+    }
+
+    #[test]
+    fn test_resolve_relative() {
+        let cwd = std::env::temp_dir();
+        assert_eq!(resolve(&cwd, "file.txt"), cwd.join("file.txt"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_relative_to_through_symlinks() {
+        use std::os::unix::fs::symlink;
+
+        let dir = tempfile::tempdir().unwrap();
+        let shared = dir.path().join("shared");
+        let project = dir.path().join("project");
+        std::fs::create_dir_all(shared.join("bugs")).unwrap();
+        std::fs::create_dir_all(&project).unwrap();
+        std::fs::write(shared.join("bugs/item.md"), "").unwrap();
+
+        // Stack directory linked in from elsewhere
+        let stack = project.join("queuestack");
+        symlink(&shared, &stack).unwrap();
+        assert_eq!(
+            relative_to(&shared.join("bugs/item.md"), &stack),
+            Some(PathBuf::from("bugs/item.md"))
+        );
+        assert_eq!(
+            relative_to(&stack.join("bugs/item.md"), &shared),
+            Some(PathBuf::from("bugs/item.md"))
+        );
+
+        // Category directory linked in from outside the stack
+        let outside = dir.path().join("outside");
+        std::fs::create_dir_all(&outside).unwrap();
+        std::fs::write(outside.join("item.md"), "").unwrap();
+        symlink(&outside, shared.join("linked")).unwrap();
+        assert_eq!(
+            relative_to(&stack.join("linked/item.md"), &stack),
+            Some(PathBuf::from("linked/item.md"))
+        );
+
+        assert_eq!(relative_to(&outside.join("item.md"), &project), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_same_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let link = dir.path().join("link");
+        std::os::unix::fs::symlink(dir.path(), &link).unwrap();
+
+        assert!(same_file(dir.path(), &link));
+        assert!(!same_file(dir.path(), &dir.path().join("missing")));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_resolve_drive_relative() {
+        let cwd = Path::new(r"C:\work");
+        assert_eq!(resolve(cwd, "C:notes.txt"), Path::new(r"C:\work\notes.txt"));
+        assert_eq!(resolve(cwd, "c:notes.txt"), Path::new(r"C:\work\notes.txt"));
+        assert_eq!(resolve(cwd, "D:notes.txt"), Path::new(r"D:\notes.txt"));
+        assert_eq!(resolve(cwd, r"E:\abs.txt"), Path::new(r"E:\abs.txt"));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_simplified() {
+        assert_eq!(
+            simplified(Path::new(r"\\?\C:\Users\dom")),
+            Path::new(r"C:\Users\dom")
+        );
+        assert_eq!(
+            simplified(Path::new(r"\\?\UNC\server\share")),
+            Path::new(r"\\?\UNC\server\share")
+        );
+    }
+}
@@ -0,0 +1,148 @@
+//! # Dry-Run Plans
+//!
+//! Describes the file operations a command would perform, so `--dry-run` can
+//! print them for review instead of touching the disk.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{fmt::Write as _, path::Path};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use super::{attachment_dir, attachment_dir_for_item};
+use crate::{
+    config::Config,
+    item::{diff, Item},
+    ui::OutputFormat,
+};
+
+/// A single planned file operation. Paths are relative to the project root.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum FileOp {
+    /// Rewrite an item; `diff` lists changed frontmatter lines (`-old`, `+new`)
+    Write { path: String, diff: Vec<String> },
+    /// Move (or rename) a file or directory
+    Move { from: String, to: String },
+    /// Delete a file or directory
+    Delete { path: String },
+}
+
+/// An ordered list of file operations
+#[derive(Debug, Default, Serialize)]
+pub struct Plan {
+    pub operations: Vec<FileOp>,
+}
+
+impl Plan {
+    /// Creates an empty plan
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if the plan contains no operations
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Plans rewriting the item at `path` from `before` to `after`.
+    ///
+    /// Nothing is planned if the frontmatter is unchanged.
+    pub fn write(
+        &mut self,
+        config: &Config,
+        path: &Path,
+        before: &Item,
+        after: &Item,
+    ) -> Result<()> {
+        let old =
+            serde_yml::to_string(&before.frontmatter).context("Failed to serialize frontmatter")?;
+        let new =
+            serde_yml::to_string(&after.frontmatter).context("Failed to serialize frontmatter")?;
+        let diff = diff::changed_lines(&old, &new);
+        if !diff.is_empty() {
+            self.operations.push(FileOp::Write {
+                path: relative(config, path),
+                diff,
+            });
+        }
+        Ok(())
+    }
+
+    /// Plans moving an item file together with its attachment directory.
+    pub fn move_item(&mut self, config: &Config, from: &Path, to: &Path) {
+        if from == to {
+            return;
+        }
+        self.operations.push(FileOp::Move {
+            from: relative(config, from),
+            to: relative(config, to),
+        });
+
+        // External attachment directories stay where they are
+        let attachments = attachment_dir_for_item(from);
+        if config.attachment_path().is_none() && attachments.exists() {
+            self.operations.push(FileOp::Move {
+                from: relative(config, &attachments),
+                to: relative(config, &attachment_dir_for_item(to)),
+            });
+        }
+    }
+
+    /// Plans deleting an item file together with its attachment directory.
+    pub fn delete_item(&mut self, config: &Config, item: &Item, path: &Path) {
+        self.operations.push(FileOp::Delete {
+            path: relative(config, path),
+        });
+
+        let attachments = attachment_dir(config, item.id(), path);
+        if attachments.exists() {
+            self.operations.push(FileOp::Delete {
+                path: relative(config, &attachments),
+            });
+        }
+    }
+
+    /// Renders the plan as plain text or JSON (always newline-terminated).
+    pub fn render(&self, format: OutputFormat) -> Result<String> {
+        if format == OutputFormat::Json {
+            let json = serde_json::to_string_pretty(self).context("Failed to serialize plan")?;
+            return Ok(format!("{json}\n"));
+        }
+
+        if self.is_empty() {
+            return Ok("No changes to apply.\n".to_string());
+        }
+
+        let mut out = String::new();
+        for op in &self.operations {
+            match op {
+                FileOp::Write { path, diff } => {
+                    let _ = writeln!(out, "write   {path}");
+                    for line in diff {
+                        let _ = writeln!(out, "          {line}");
+                    }
+                }
+                FileOp::Move { from, to } => {
+                    let _ = writeln!(out, "move    {from} -> {to}");
+                }
+                FileOp::Delete { path } => {
+                    let _ = writeln!(out, "delete  {path}");
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Prints the plan to stdout.
+    pub fn print(&self, format: OutputFormat) -> Result<()> {
+        print!("{}", self.render(format)?);
+        Ok(())
+    }
+}
+
+fn relative(config: &Config, path: &Path) -> String {
+    config.relative_path(path).display().to_string()
+}
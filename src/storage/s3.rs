@@ -0,0 +1,84 @@
+//! # S3 Attachment Backend
+//!
+//! Stores file attachments in S3-compatible object storage instead of the
+//! repository (enabled with the `s3` feature and the `s3_url` option).
+//!
+//! Transfers go through the `aws` CLI, so credentials, profiles, and custom
+//! endpoints (`AWS_ENDPOINT_URL`, e.g. for `MinIO` or R2) work as usual. Objects
+//! are stored as `{s3_url}/{id}/{attachment}` and referenced by that `s3://`
+//! URL in the frontmatter.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{path::Path, process::Command};
+
+use anyhow::{bail, Context, Result};
+
+use crate::constants::S3_URL_PREFIX;
+
+/// How long presigned URLs stay valid (seconds)
+pub const PRESIGN_EXPIRY_SECS: u64 = 3600;
+
+/// Returns the object URL for an item's attachment below `base`.
+pub fn object_url(base: &str, id: &str, filename: &str) -> Result<String> {
+    if !base.starts_with(S3_URL_PREFIX) {
+        bail!("Invalid s3_url '{base}': expected s3://bucket[/prefix]");
+    }
+    Ok(format!("{}/{id}/{filename}", base.trim_end_matches('/')))
+}
+
+/// Uploads a local file to `url`.
+pub fn upload(source: &Path, url: &str) -> Result<()> {
+    aws(&[
+        "s3",
+        "cp",
+        "--only-show-errors",
+        &source.to_string_lossy(),
+        url,
+    ])
+    .map(|_| ())
+}
+
+/// Deletes the object at `url`.
+pub fn delete(url: &str) -> Result<()> {
+    aws(&["s3", "rm", "--only-show-errors", url]).map(|_| ())
+}
+
+/// Generates a time-limited HTTPS URL for the object at `url`.
+pub fn presign(url: &str) -> Result<String> {
+    let expiry = PRESIGN_EXPIRY_SECS.to_string();
+    aws(&["s3", "presign", url, "--expires-in", &expiry])
+}
+
+/// Runs the `aws` CLI and returns its trimmed stdout.
+fn aws(args: &[&str]) -> Result<String> {
+    let output = Command::new("aws")
+        .args(args)
+        .output()
+        .context("Failed to run the aws CLI (is it installed?)")?;
+
+    if !output.status.success() {
+        bail!(
+            "aws {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_url() {
+        assert_eq!(
+            object_url("s3://bucket/qs/", "260101-AAA", "1-shot.png").unwrap(),
+            "s3://bucket/qs/260101-AAA/1-shot.png"
+        );
+        assert!(object_url("bucket/qs", "260101-AAA", "1-shot.png").is_err());
+    }
+}
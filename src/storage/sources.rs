@@ -0,0 +1,373 @@
+//! # Attachment Sources
+//!
+//! Expands the sources given to `qs attach add` and `qs new --attachment` into
+//! single files: glob patterns (`*.log`, `dumps/**/*.dmp`) become the matching
+//! files, and directories become their files (recursively) or one `.tgz`
+//! archive, depending on `attachment_directories`. URLs and plain files pass
+//! through unchanged.
+//!
+//! Hidden files and directories are skipped unless the pattern names them
+//! (`.env*`). Patterns support `*`, `?`, `[abc]`, and `**` for any number of
+//! directories.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    fmt, fs,
+    path::{Component, Path, PathBuf},
+    process::Command,
+};
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use walkdir::WalkDir;
+
+use super::paths;
+use crate::item::is_url;
+
+/// File name extension of directory archives
+const ARCHIVE_EXTENSION: &str = "tgz";
+
+/// How a directory given as an attachment source is attached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DirectoryAttachments {
+    /// Each file below the directory, as a separate attachment
+    #[default]
+    Files,
+    /// One compressed tar archive of the directory
+    Archive,
+}
+
+impl fmt::Display for DirectoryAttachments {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Files => write!(f, "files"),
+            Self::Archive => write!(f, "archive"),
+        }
+    }
+}
+
+/// Attachment sources after expansion
+#[derive(Debug, Default)]
+pub struct Expanded {
+    /// URLs and file paths, in the order given (matches sorted by path)
+    pub sources: Vec<String>,
+    /// Temporary directory holding created archives; removed on drop
+    scratch: Option<PathBuf>,
+}
+
+impl Expanded {
+    /// Number of files (not URLs) to attach.
+    pub fn file_count(&self) -> usize {
+        self.sources.iter().filter(|source| !is_url(source)).count()
+    }
+}
+
+impl Drop for Expanded {
+    fn drop(&mut self) {
+        if let Some(ref scratch) = self.scratch {
+            let _ = fs::remove_dir_all(scratch);
+        }
+    }
+}
+
+/// Expands glob patterns and directories among `sources` into files.
+///
+/// Paths are resolved against `cwd` and shown relative to it. A pattern
+/// matching nothing is kept as given, so it is reported as not found.
+pub fn expand(
+    cwd: &Path,
+    sources: &[String],
+    directories: DirectoryAttachments,
+) -> Result<Expanded> {
+    let mut expanded = Expanded::default();
+    for source in sources {
+        if is_url(source) {
+            expanded.sources.push(source.clone());
+            continue;
+        }
+
+        let path = paths::resolve(cwd, source);
+        if path.is_dir() {
+            match directories {
+                DirectoryAttachments::Files => {
+                    let files = files_below(&path);
+                    if files.is_empty() {
+                        // Rejected as a directory when attached
+                        expanded.sources.push(source.clone());
+                    }
+                    expanded
+                        .sources
+                        .extend(files.iter().map(|file| display(cwd, file)));
+                }
+                DirectoryAttachments::Archive => {
+                    if expanded.scratch.is_none() {
+                        expanded.scratch = Some(scratch_dir()?);
+                    }
+                    let scratch = expanded.scratch.clone().unwrap_or_default();
+                    let archive = archive(&path, &scratch)?;
+                    expanded.sources.push(archive.display().to_string());
+                }
+            }
+        } else if !path.exists() && is_pattern(source) {
+            let matches = glob(&path)?;
+            if matches.is_empty() {
+                expanded.sources.push(source.clone());
+            }
+            expanded
+                .sources
+                .extend(matches.iter().map(|file| display(cwd, file)));
+        } else {
+            expanded.sources.push(source.clone());
+        }
+    }
+    Ok(expanded)
+}
+
+/// Returns true if a source contains glob characters.
+pub fn is_pattern(source: &str) -> bool {
+    source.contains(['*', '?', '['])
+}
+
+/// Returns the files matching an absolute glob pattern, sorted by path.
+pub fn glob(pattern: &Path) -> Result<Vec<PathBuf>> {
+    // Walk from the longest leading part without glob characters
+    let mut base = PathBuf::new();
+    let mut rest: Vec<String> = Vec::new();
+    for component in pattern.components() {
+        let part = component.as_os_str().to_string_lossy();
+        if rest.is_empty() && !is_pattern(&part) {
+            base.push(component);
+        } else if !matches!(component, Component::CurDir) {
+            rest.push(part.into_owned());
+        }
+    }
+
+    let regex = pattern_regex(&rest)?;
+    let hidden = rest.iter().any(|part| part.starts_with('.'));
+    let depth = if rest.iter().any(|part| part == "**") {
+        usize::MAX
+    } else {
+        rest.len()
+    };
+
+    let files = WalkDir::new(&base)
+        .min_depth(1)
+        .max_depth(depth)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| hidden || !is_hidden(entry.file_name()))
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .map(walkdir::DirEntry::into_path)
+        .filter(|path| {
+            let relative = path.strip_prefix(&base).unwrap_or(path);
+            let parts: Vec<String> = relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            regex.is_match(&parts.join("/"))
+        })
+        .collect();
+    Ok(files)
+}
+
+/// Translates the pattern components after the base directory into a regex
+/// over `/`-separated relative paths.
+fn pattern_regex(parts: &[String]) -> Result<Regex> {
+    let mut regex = String::from("^");
+    for (index, part) in parts.iter().enumerate() {
+        if part == "**" {
+            // Any number of directories, or everything below when last
+            regex.push_str(if index + 1 == parts.len() {
+                ".*"
+            } else {
+                "(?:[^/]+/)*"
+            });
+            continue;
+        }
+        let mut chars = part.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '*' => regex.push_str("[^/]*"),
+                '?' => regex.push_str("[^/]"),
+                '[' => {
+                    regex.push('[');
+                    if chars.peek() == Some(&'!') {
+                        chars.next();
+                        regex.push('^');
+                    }
+                    for c in chars.by_ref() {
+                        if c == ']' {
+                            break;
+                        }
+                        if c == '\\' || c == '[' {
+                            regex.push('\\');
+                        }
+                        regex.push(c);
+                    }
+                    regex.push(']');
+                }
+                c => regex.push_str(&regex::escape(&c.to_string())),
+            }
+        }
+        if index + 1 < parts.len() {
+            regex.push('/');
+        }
+    }
+    regex.push('$');
+    Regex::new(&regex).with_context(|| format!("Invalid pattern: {}", parts.join("/")))
+}
+
+/// Returns the files below a directory, recursively and sorted by path.
+fn files_below(dir: &Path) -> Vec<PathBuf> {
+    WalkDir::new(dir)
+        .min_depth(1)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| !is_hidden(entry.file_name()))
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .map(walkdir::DirEntry::into_path)
+        .collect()
+}
+
+/// Packs a directory into `<name>.tgz` in `scratch`.
+fn archive(dir: &Path, scratch: &Path) -> Result<PathBuf> {
+    let name = dir
+        .file_name()
+        .map_or_else(|| "attachment".into(), |name| name.to_string_lossy());
+    let parent = dir.parent().unwrap_or(dir);
+    let archive = scratch.join(format!("{name}.{ARCHIVE_EXTENSION}"));
+
+    let output = Command::new("tar")
+        .arg("-czf")
+        .arg(&archive)
+        .arg("-C")
+        .arg(parent)
+        .arg(name.as_ref())
+        .output()
+        .context("Failed to run tar to archive the directory")?;
+    if !output.status.success() {
+        bail!(
+            "Failed to archive {}: {}",
+            dir.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(archive)
+}
+
+fn scratch_dir() -> Result<PathBuf> {
+    let dir = std::env::temp_dir().join(format!("qs-attach-{}", std::process::id()));
+    fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    Ok(dir)
+}
+
+fn is_hidden(name: &std::ffi::OsStr) -> bool {
+    name.to_string_lossy().starts_with('.')
+}
+
+/// Shows a path relative to `cwd` when it is below it.
+fn display(cwd: &Path, path: &Path) -> String {
+    path.strip_prefix(cwd).unwrap_or(path).display().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn touch(root: &Path, relative: &str) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, relative).unwrap();
+    }
+
+    fn setup() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for file in [
+            "a.log",
+            "b.log",
+            "notes.txt",
+            ".hidden.log",
+            "dumps/1.dmp",
+            "dumps/deep/2.dmp",
+            "dumps/.cache/3.dmp",
+        ] {
+            touch(dir.path(), file);
+        }
+        dir
+    }
+
+    fn expand_all(cwd: &Path, sources: &[&str]) -> Vec<String> {
+        let sources: Vec<String> = sources.iter().map(ToString::to_string).collect();
+        expand(cwd, &sources, DirectoryAttachments::Files)
+            .unwrap()
+            .sources
+            .clone()
+    }
+
+    #[test]
+    fn test_glob_matches_files() {
+        let dir = setup();
+        assert_eq!(expand_all(dir.path(), &["*.log"]), ["a.log", "b.log"]);
+        assert_eq!(expand_all(dir.path(), &["?.log"]), ["a.log", "b.log"]);
+        assert_eq!(expand_all(dir.path(), &["[!a].log"]), ["b.log"]);
+        assert_eq!(expand_all(dir.path(), &[".*.log"]), [".hidden.log"]);
+        assert_eq!(
+            expand_all(dir.path(), &["dumps/**/*.dmp"]),
+            ["dumps/1.dmp", "dumps/deep/2.dmp"]
+        );
+        assert_eq!(
+            expand_all(dir.path(), &["dumps/**"]),
+            ["dumps/1.dmp", "dumps/deep/2.dmp"]
+        );
+    }
+
+    #[test]
+    fn test_unmatched_pattern_and_plain_sources_pass_through() {
+        let dir = setup();
+        assert_eq!(
+            expand_all(dir.path(), &["*.png", "notes.txt", "https://example.com"]),
+            ["*.png", "notes.txt", "https://example.com"]
+        );
+    }
+
+    #[test]
+    fn test_directory_expands_recursively() {
+        let dir = setup();
+        let expanded = expand(
+            dir.path(),
+            &["dumps".to_string(), "https://example.com".to_string()],
+            DirectoryAttachments::Files,
+        )
+        .unwrap();
+        assert_eq!(
+            expanded.sources,
+            ["dumps/1.dmp", "dumps/deep/2.dmp", "https://example.com"]
+        );
+        assert_eq!(expanded.file_count(), 2);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_directory_archive() {
+        let dir = setup();
+        let expanded = expand(
+            dir.path(),
+            &["dumps".to_string()],
+            DirectoryAttachments::Archive,
+        )
+        .unwrap();
+        let archive = PathBuf::from(&expanded.sources[0]);
+        assert_eq!(archive.file_name().unwrap(), "dumps.tgz");
+        assert!(archive.is_file());
+
+        // The archive is removed with the expansion
+        drop(expanded);
+        assert!(!archive.exists());
+    }
+}
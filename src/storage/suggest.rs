@@ -0,0 +1,207 @@
+//! # Lookup Suggestions
+//!
+//! Error for `--id` lookups that match no item or several, carrying ranked
+//! candidates so the message can say "did you mean" and JSON consumers (the
+//! daemon) get them as data instead of parsing text.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{fmt, path::PathBuf};
+
+use serde::Serialize;
+
+use crate::{config::Config, item::Item};
+
+/// Most candidates shown for a failed lookup
+pub const MAX_CANDIDATES: usize = 5;
+
+/// An item offered as a possible meaning of an ID reference
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Candidate {
+    pub id: String,
+    pub title: String,
+}
+
+/// A failed ID lookup: nothing matched, or more than one item did
+#[derive(Debug, Clone, Serialize)]
+pub struct LookupError {
+    /// The ID reference as given
+    pub query: String,
+    /// True if several items matched, false if none did
+    pub ambiguous: bool,
+    /// Best candidates first (at most [`MAX_CANDIDATES`])
+    pub candidates: Vec<Candidate>,
+    /// Number of candidates left out
+    pub more: usize,
+}
+
+impl fmt::Display for LookupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ambiguous {
+            write!(f, "Multiple items match '{}':", self.query)?;
+        } else {
+            write!(f, "No item found matching '{}'", self.query)?;
+            if !self.candidates.is_empty() {
+                write!(f, "\nDid you mean:")?;
+            }
+        }
+        for candidate in &self.candidates {
+            write!(f, "\n  {}  {}", candidate.id, candidate.title)?;
+        }
+        if self.more > 0 {
+            write!(f, "\n  ... and {} more", self.more)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for LookupError {}
+
+impl LookupError {
+    /// Builds the error for several matching items, IDs starting with the
+    /// query first.
+    pub fn ambiguous(query: &str, matches: &[PathBuf]) -> Self {
+        let upper = query.to_uppercase();
+        let mut ranked: Vec<(bool, Candidate)> = matches
+            .iter()
+            .filter_map(|path| candidate(path))
+            .map(|candidate| (!candidate.id.to_uppercase().starts_with(&upper), candidate))
+            .collect();
+        ranked.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.id.cmp(&b.1.id)));
+        Self::new(query, true, ranked.into_iter().map(|(_, c)| c).collect())
+    }
+
+    /// Builds the error for a reference matching nothing, with the items whose
+    /// ID or title come closest.
+    pub fn not_found(config: &Config, query: &str) -> Self {
+        let mut ranked: Vec<(usize, Candidate)> = super::walk_all(config)
+            .filter_map(|path| candidate(&path))
+            .filter_map(|candidate| Some((distance(query, &candidate)?, candidate)))
+            .collect();
+        ranked.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.id.cmp(&b.1.id)));
+        Self::new(query, false, ranked.into_iter().map(|(_, c)| c).collect())
+    }
+
+    fn new(query: &str, ambiguous: bool, mut candidates: Vec<Candidate>) -> Self {
+        let more = candidates.len().saturating_sub(MAX_CANDIDATES);
+        candidates.truncate(MAX_CANDIDATES);
+        Self {
+            query: query.to_string(),
+            ambiguous,
+            candidates,
+            more,
+        }
+    }
+}
+
+/// Reads an item's ID and title (frontmatter only).
+fn candidate(path: &std::path::Path) -> Option<Candidate> {
+    let item = Item::load_frontmatter(path, &mut String::new()).ok()?;
+    Some(Candidate {
+        id: item.frontmatter.id,
+        title: item.frontmatter.title,
+    })
+}
+
+/// How far a reference is from an item, or `None` if it's too far to suggest.
+///
+/// A title containing the reference counts as an exact hit; otherwise this is
+/// the smallest edit distance to a same-length stretch of the ID or to a word
+/// of the title. Up to a third of the reference's characters may differ.
+fn distance(query: &str, candidate: &Candidate) -> Option<usize> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return None;
+    }
+    let title = candidate.title.to_lowercase();
+    if query.chars().count() >= 3 && title.contains(&query) {
+        return Some(0);
+    }
+
+    let id: Vec<char> = candidate.id.to_lowercase().chars().collect();
+    let width = query.chars().count().min(id.len());
+    let id_distance = (0..=id.len() - width)
+        .map(|start| levenshtein(&query, &id[start..start + width].iter().collect::<String>()))
+        .min();
+    let title_distance = title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| levenshtein(&query, word))
+        .min();
+
+    let best = id_distance.into_iter().chain(title_distance).min()?;
+    let allowed = (query.chars().count() / 3).max(1);
+    (best <= allowed).then_some(best)
+}
+
+/// Levenshtein distance between two strings (in characters).
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(id: &str, title: &str) -> Candidate {
+        Candidate {
+            id: id.to_string(),
+            title: title.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("2601o9", "260109"), 1);
+    }
+
+    #[test]
+    fn test_distance_ranks_ids_and_titles() {
+        let item = candidate("260109-02F7K9M", "Login crash on start");
+        // A mistyped ID fragment
+        assert_eq!(distance("02G7K", &item), Some(1));
+        // Title text, exact and misspelled
+        assert_eq!(distance("crash", &item), Some(0));
+        assert_eq!(distance("logn", &item), Some(1));
+        // Too far from anything
+        assert_eq!(distance("export", &item), None);
+    }
+
+    #[test]
+    fn test_display_lists_candidates() {
+        let error = LookupError {
+            query: "2601o9".to_string(),
+            ambiguous: false,
+            candidates: vec![candidate("260109-ABC", "Login crash")],
+            more: 0,
+        };
+        assert_eq!(
+            error.to_string(),
+            "No item found matching '2601o9'\nDid you mean:\n  260109-ABC  Login crash"
+        );
+
+        let none = LookupError {
+            candidates: Vec::new(),
+            ..error
+        };
+        assert_eq!(none.to_string(), "No item found matching '2601o9'");
+    }
+}
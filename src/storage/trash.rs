@@ -0,0 +1,221 @@
+//! # Trash
+//!
+//! Deleted items are moved into the `.trash/` directory inside the stack
+//! instead of being removed, so they can be restored and the deletion shows up
+//! in git like any other move. Each deletion gets its own
+//! `.trash/<timestamp>/` directory mirroring the item's original location
+//! (category or archive). Deletions older than `trash_days` are purged.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
+use walkdir::WalkDir;
+
+use super::{move_item_to_dir, paths, vcs, walk_all, walk_markdown_files};
+use crate::{config::Config, item::Item};
+
+/// Name format of the per-deletion directories (UTC)
+const STAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+/// An item in the trash
+#[derive(Debug)]
+pub struct TrashedItem {
+    /// Current path inside the trash
+    pub path: PathBuf,
+    pub item: Item,
+    /// When the item was deleted
+    pub deleted_at: DateTime<Utc>,
+    /// Original path, relative to the stack directory
+    pub origin: PathBuf,
+}
+
+/// Moves an item and its attachments into the trash, then purges expired deletions.
+///
+/// Returns the path inside the trash and any warnings from moving attachments.
+pub fn trash_item(config: &Config, path: &Path) -> Result<(PathBuf, Vec<String>)> {
+    let dest_dir = deletion_dir(config, path)?;
+    let result = move_item_to_dir(config, path, &dest_dir)?;
+    purge(config)?;
+    Ok(result)
+}
+
+/// Returns the directory inside the trash an item deleted now is moved to.
+pub fn deletion_dir(config: &Config, path: &Path) -> Result<PathBuf> {
+    let relative = paths::relative_to(path, &config.stack_path())
+        .with_context(|| format!("Item is not in the stack directory: {}", path.display()))?;
+    let stamp = Utc::now().format(STAMP_FORMAT).to_string();
+    let mut dest_dir = config.trash_path().join(stamp);
+    if let Some(parent) = relative.parent() {
+        dest_dir.push(parent);
+    }
+    Ok(dest_dir)
+}
+
+/// Lists the items in the trash, most recently deleted first.
+pub fn list(config: &Config) -> Vec<TrashedItem> {
+    let mut items: Vec<TrashedItem> = deletions(config)
+        .into_iter()
+        .flat_map(|(dir, deleted_at)| {
+            walk_markdown_files(dir.clone(), 1, 3).filter_map(move |path| {
+                let item = Item::load(&path).ok()?;
+                let origin = path.strip_prefix(&dir).ok()?.to_path_buf();
+                Some(TrashedItem {
+                    path,
+                    item,
+                    deleted_at,
+                    origin,
+                })
+            })
+        })
+        .collect();
+    items.sort_by_key(|trashed| std::cmp::Reverse(trashed.deleted_at));
+    items
+}
+
+/// Moves a trashed item back to its original location.
+///
+/// Matches IDs like `find_by_id`. If the same item was deleted more than once,
+/// the most recent deletion is restored. Returns the restored path and any
+/// warnings from moving attachments.
+pub fn restore(config: &Config, partial_id: &str) -> Result<(PathBuf, Vec<String>)> {
+    let partial_upper = partial_id.to_uppercase();
+    let mut matches: Vec<TrashedItem> = list(config)
+        .into_iter()
+        .filter(|trashed| trashed.item.id().to_uppercase().contains(&partial_upper))
+        .collect();
+
+    // A full ID wins over items that merely contain it
+    if matches
+        .iter()
+        .any(|trashed| trashed.item.id().eq_ignore_ascii_case(partial_id))
+    {
+        matches.retain(|trashed| trashed.item.id().eq_ignore_ascii_case(partial_id));
+    }
+
+    let mut ids: Vec<&str> = matches.iter().map(|trashed| trashed.item.id()).collect();
+    ids.dedup();
+    if ids.len() > 1 {
+        bail!(
+            "Multiple trashed items match '{partial_id}':\n  {}",
+            ids.join("\n  ")
+        );
+    }
+    let Some(trashed) = matches.into_iter().next() else {
+        bail!("No trashed item found matching '{partial_id}'");
+    };
+
+    let id = trashed.item.id();
+    if let Some(existing) = walk_all(config)
+        .find(|path| Item::load(path).is_ok_and(|item| item.id().eq_ignore_ascii_case(id)))
+    {
+        bail!(
+            "Cannot restore '{id}': an item with this ID already exists ({})",
+            config.relative_path(&existing).display()
+        );
+    }
+
+    let dest = config.stack_path().join(&trashed.origin);
+    let dest_dir = dest
+        .parent()
+        .map_or_else(|| config.stack_path(), Path::to_path_buf);
+    let result = move_item_to_dir(config, &trashed.path, &dest_dir)?;
+    remove_empty_dirs(&config.trash_path());
+    Ok(result)
+}
+
+/// Permanently removes deletions older than `trash_days`.
+///
+/// Returns the number of items removed. Does nothing when `trash_days` is 0.
+pub fn purge(config: &Config) -> Result<usize> {
+    let days = config.trash_days();
+    if days == 0 {
+        return Ok(0);
+    }
+    let cutoff = Utc::now() - TimeDelta::days(i64::from(days));
+
+    let mut purged = 0;
+    for (dir, deleted_at) in deletions(config) {
+        if deleted_at >= cutoff {
+            continue;
+        }
+
+        // External attachment directories aren't moved into the trash
+        if config.attachment_path().is_some() {
+            for path in walk_markdown_files(dir.clone(), 1, 3) {
+                if let Ok(item) = Item::load(&path) {
+                    remove_external_attachments(config, item.id())?;
+                }
+            }
+        }
+
+        purged += walk_markdown_files(dir.clone(), 1, 3).count();
+        remove_dir(&dir)?;
+    }
+
+    remove_empty_dirs(&config.trash_path());
+    Ok(purged)
+}
+
+/// Returns the per-deletion directories with their deletion time.
+///
+/// Directories whose name isn't a timestamp are ignored.
+fn deletions(config: &Config) -> Vec<(PathBuf, DateTime<Utc>)> {
+    std::fs::read_dir(config.trash_path())
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let name = entry.file_name();
+            let deleted_at = NaiveDateTime::parse_from_str(name.to_str()?, STAMP_FORMAT).ok()?;
+            Some((entry.path(), deleted_at.and_utc()))
+        })
+        .collect()
+}
+
+/// Removes the external attachment directory of a purged item, unless a live
+/// item still uses the ID.
+fn remove_external_attachments(config: &Config, id: &str) -> Result<()> {
+    let Some(dir) = config.external_attachment_dir(id) else {
+        return Ok(());
+    };
+    let in_use = walk_all(config).any(|path| Item::load(&path).is_ok_and(|item| item.id() == id));
+    if in_use || !dir.exists() {
+        return Ok(());
+    }
+
+    remove_dir(&dir)
+}
+
+/// Removes a directory and its files (with `git rm` for tracked files).
+fn remove_dir(dir: &Path) -> Result<()> {
+    let files: Vec<PathBuf> = WalkDir::new(dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .map(walkdir::DirEntry::into_path)
+        .collect();
+    vcs::remove_files(&files)?;
+    if dir.exists() {
+        std::fs::remove_dir_all(dir)
+            .with_context(|| format!("Failed to remove {}", dir.display()))?;
+    }
+    Ok(())
+}
+
+/// Removes empty directories below `dir`, and `dir` itself if it ends up empty.
+fn remove_empty_dirs(dir: &Path) {
+    for entry in WalkDir::new(dir)
+        .contents_first(true)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_dir())
+    {
+        // Only succeeds if the directory is empty
+        let _ = std::fs::remove_dir(entry.path());
+    }
+}
@@ -0,0 +1,279 @@
+//! # Vault
+//!
+//! Obsidian vault mode. Keeps a generated footer (labels as `#tags`, attachments
+//! as `[[wikilinks]]`) at the end of each item body and an index note per
+//! category and status directory, so the stack directory can be opened as an
+//! Obsidian vault.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+use super::{attachment_dir, derive_category, status_dir_of, walk_all, walk_items};
+use crate::{
+    config::Config,
+    constants::{VAULT_FOOTER_MARKER, VAULT_INDEX_FILE},
+    item::{is_url, Item},
+};
+
+/// Brings footers and index notes up to date. Does nothing unless vault mode is enabled.
+///
+/// Only files whose content actually changes are rewritten.
+pub fn sync(config: &Config) -> Result<()> {
+    if !config.obsidian() {
+        return Ok(());
+    }
+
+    let stack_path = config.stack_path();
+    for path in walk_all(config) {
+        let Ok(mut item) = Item::load(&path) else {
+            continue;
+        };
+        let body = apply_footer(
+            &item.body,
+            footer(config, &item, &stack_path, &path).as_deref(),
+        );
+        if body != item.body {
+            item.body = body;
+            item.save(&path)?;
+        }
+    }
+
+    write_index_notes(config)
+}
+
+/// Builds the footer for an item, or `None` if it has no labels or attachments.
+fn footer(config: &Config, item: &Item, stack_path: &Path, path: &Path) -> Option<String> {
+    let mut lines = Vec::new();
+
+    if !item.labels().is_empty() {
+        let tags: Vec<String> = item.labels().iter().map(|l| format!("#{l}")).collect();
+        lines.push(tags.join(" "));
+    }
+
+    let attachment_dir = attachment_dir(config, item.id(), path);
+    for attachment in item.attachments() {
+        let target = attachment_dir.join(attachment);
+        if is_url(attachment) {
+            lines.push(item.link_title(attachment).map_or_else(
+                || format!("<{attachment}>"),
+                |title| format!("[{}]({attachment})", title.replace(['[', ']'], "")),
+            ));
+        } else if let Ok(target) = target.strip_prefix(stack_path) {
+            // Vault-relative path: attachment names repeat across items (1-..., 2-...)
+            lines.push(format!("[[{}]]", vault_path(target)));
+        } else {
+            // External attachments live outside the vault
+            lines.push(format!("<file://{}>", target.display()));
+        }
+    }
+
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// Replaces any existing footer in `body` with `footer`.
+///
+/// Everything from the marker line to the end of the body is generated content.
+pub fn apply_footer(body: &str, footer: Option<&str>) -> String {
+    let base = match body.find(VAULT_FOOTER_MARKER) {
+        Some(pos) => body[..pos].trim_end(),
+        None if footer.is_none() => return body.to_string(),
+        None => body.trim_end(),
+    };
+
+    match (base.is_empty(), footer) {
+        (true, None) => String::new(),
+        (false, None) => format!("{base}\n"),
+        (true, Some(footer)) => format!("{VAULT_FOOTER_MARKER}\n{footer}\n"),
+        (false, Some(footer)) => format!("{base}\n\n{VAULT_FOOTER_MARKER}\n{footer}\n"),
+    }
+}
+
+/// Writes `_index.md` into the stack root and every directory holding items:
+/// each category, and each `[status_dirs]` directory and category within it.
+///
+/// Index notes of directories that no longer contain items are removed.
+fn write_index_notes(config: &Config) -> Result<()> {
+    let stack_path = config.stack_path();
+
+    let mut groups: BTreeMap<NoteDir, Vec<(String, PathBuf)>> = BTreeMap::new();
+    for path in walk_items(config) {
+        let Ok(item) = Item::load(&path) else {
+            continue;
+        };
+        let dir = NoteDir {
+            status_dir: status_dir_of(config, &path),
+            category: derive_category(config, &path),
+        };
+        groups
+            .entry(dir)
+            .or_default()
+            .push((item.title().to_string(), path));
+    }
+    for entries in groups.values_mut() {
+        entries.sort_by(|a, b| a.1.cmp(&b.1));
+    }
+
+    // Root note: links to every directory note, then items in the stack root
+    let mut root = format!("# {}\n", config.stack_dir());
+    let names: Vec<String> = groups.keys().filter_map(NoteDir::name).collect();
+    if !names.is_empty() {
+        root.push('\n');
+        for name in &names {
+            let _ = writeln!(root, "- [[{name}/_index|{name}]]");
+        }
+    }
+    if let Some(entries) = groups.get(&NoteDir::default()) {
+        root.push('\n');
+        push_item_links(&mut root, entries);
+    }
+    write_if_changed(&stack_path.join(VAULT_INDEX_FILE), &root)?;
+
+    for (dir, entries) in &groups {
+        let Some(name) = dir.name() else {
+            continue;
+        };
+        let mut note = format!("# {name}\n\n");
+        push_item_links(&mut note, entries);
+        write_if_changed(&dir.path(config).join(VAULT_INDEX_FILE), &note)?;
+    }
+
+    // Drop stale notes left behind in directories that were emptied
+    let status_dirs: Vec<String> = config.status_dirs().into_values().collect();
+    let mut candidates = Vec::new();
+    for (name, path) in subdirs(&stack_path) {
+        if status_dirs.contains(&name) {
+            for (category, path) in subdirs(&path) {
+                candidates.push((NoteDir::new(Some(&name), Some(&category)), path));
+            }
+            candidates.push((NoteDir::new(Some(&name), None), path));
+        } else {
+            candidates.push((NoteDir::new(None, Some(&name)), path));
+        }
+    }
+    for (key, dir) in candidates {
+        let note = dir.join(VAULT_INDEX_FILE);
+        if groups.contains_key(&key) || !note.exists() {
+            continue;
+        }
+        fs::remove_file(&note)
+            .with_context(|| format!("Failed to remove index note: {}", note.display()))?;
+        let _ = fs::remove_dir(&dir); // Only succeeds if now empty
+    }
+
+    Ok(())
+}
+
+/// A directory that gets an index note, keyed by its status directory and category
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+struct NoteDir {
+    status_dir: Option<String>,
+    category: Option<String>,
+}
+
+impl NoteDir {
+    fn new(status_dir: Option<&str>, category: Option<&str>) -> Self {
+        Self {
+            status_dir: status_dir.map(str::to_string),
+            category: category.map(str::to_string),
+        }
+    }
+
+    /// Vault-relative directory name (`bugs`, `doing`, `doing/bugs`), or
+    /// `None` for the stack root.
+    fn name(&self) -> Option<String> {
+        match (&self.status_dir, &self.category) {
+            (None, None) => None,
+            (Some(dir), None) | (None, Some(dir)) => Some(dir.clone()),
+            (Some(status_dir), Some(category)) => Some(format!("{status_dir}/{category}")),
+        }
+    }
+
+    fn path(&self, config: &Config) -> PathBuf {
+        let status_dir = self.status_dir.as_deref();
+        self.category.as_deref().map_or_else(
+            || config.stack_path().join(status_dir.unwrap_or_default()),
+            |category| config.category_path(status_dir, category),
+        )
+    }
+}
+
+/// Lists the subdirectories of `dir` with their names.
+fn subdirs(dir: &Path) -> Vec<(String, PathBuf)> {
+    fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| {
+            (
+                entry.file_name().to_string_lossy().to_string(),
+                entry.path(),
+            )
+        })
+        .collect()
+}
+
+/// Appends one `- [[file-stem|Title]]` line per item.
+fn push_item_links(out: &mut String, entries: &[(String, PathBuf)]) {
+    for (title, path) in entries {
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let _ = writeln!(out, "- [[{stem}|{title}]]");
+    }
+}
+
+/// Formats a relative path with forward slashes, as Obsidian expects.
+fn vault_path(path: &Path) -> String {
+    path.iter()
+        .map(|c| c.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn write_if_changed(path: &Path, content: &str) -> Result<()> {
+    if fs::read_to_string(path).is_ok_and(|existing| existing == content) {
+        return Ok(());
+    }
+    fs::write(path, content)
+        .with_context(|| format!("Failed to write index note: {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_footer_adds_and_replaces() {
+        let body = "Some text\n";
+        let with = apply_footer(body, Some("#bug"));
+        assert_eq!(with, format!("Some text\n\n{VAULT_FOOTER_MARKER}\n#bug\n"));
+
+        let replaced = apply_footer(&with, Some("#bug #ui"));
+        assert_eq!(
+            replaced,
+            format!("Some text\n\n{VAULT_FOOTER_MARKER}\n#bug #ui\n")
+        );
+
+        // Idempotent
+        assert_eq!(apply_footer(&replaced, Some("#bug #ui")), replaced);
+    }
+
+    #[test]
+    fn test_apply_footer_removes() {
+        let body = format!("Some text\n\n{VAULT_FOOTER_MARKER}\n#bug\n");
+        assert_eq!(apply_footer(&body, None), "Some text\n");
+        assert_eq!(apply_footer("Untouched  \n\n", None), "Untouched  \n\n");
+        assert_eq!(
+            apply_footer("", Some("#bug")),
+            format!("{VAULT_FOOTER_MARKER}\n#bug\n")
+        );
+    }
+}
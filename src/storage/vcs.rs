@@ -0,0 +1,420 @@
+//! # Version Control
+//!
+//! Moves and removes item files, finds the user name, and reads the stack's
+//! history through the version control system of the project: git, Jujutsu
+//! (jj), or Mercurial (hg). The system is set with the `vcs` option or
+//! detected from the project root, where the nearest `.jj`, `.hg`, or `.git`
+//! wins (a colocated jj repository is jj).
+//!
+//! Without the system, or outside its repository, files are renamed and
+//! removed directly and the user is told once.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{git, hg, jj};
+
+/// Version control system selected in the config
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VcsKind {
+    /// Detected from the project root
+    #[default]
+    Auto,
+    Git,
+    /// Jujutsu
+    Jj,
+    /// Mercurial
+    Hg,
+}
+
+impl fmt::Display for VcsKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::Git => write!(f, "git"),
+            Self::Jj => write!(f, "jj"),
+            Self::Hg => write!(f, "hg"),
+        }
+    }
+}
+
+/// How item files are moved and removed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// Through a version control system (`git`, `jj`, `hg`)
+    Vcs(&'static str),
+    /// Plain renames and removals, with the reason no system is used
+    Filesystem(&'static str),
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Vcs(name) => write!(f, "{name}"),
+            Self::Filesystem(reason) => write!(f, "filesystem ({reason})"),
+        }
+    }
+}
+
+/// A commit that touched files below a directory
+#[derive(Debug, Clone)]
+pub struct Commit {
+    pub author: String,
+    pub email: String,
+    pub date: DateTime<Utc>,
+    /// Touched paths, relative to the repository root
+    pub files: Vec<String>,
+}
+
+/// File operations and history of a version control system
+pub trait Vcs {
+    /// Command name of the system (`git`, `jj`, `hg`)
+    fn name(&self) -> &'static str;
+
+    /// Returns how files in `dir` are stored.
+    fn backend(&self, dir: &Path) -> Backend;
+
+    /// Moves several files, creating the destination directories.
+    ///
+    /// Returns the source paths that could not be moved, with the reason.
+    fn move_files(&self, moves: &[(PathBuf, PathBuf)]) -> Vec<(PathBuf, anyhow::Error)>;
+
+    /// Removes several files.
+    fn remove_files(&self, paths: &[PathBuf]) -> Result<()>;
+
+    /// Returns the user name configured for the system.
+    fn user_name(&self) -> Option<String>;
+
+    /// Returns the commits that touched files below `dir`, oldest first
+    /// (empty outside a repository).
+    fn history(&self, dir: &Path) -> Result<Vec<Commit>>;
+}
+
+/// git: `git mv` and `git rm` for tracked files
+pub struct Git;
+
+impl Vcs for Git {
+    fn name(&self) -> &'static str {
+        "git"
+    }
+
+    fn backend(&self, dir: &Path) -> Backend {
+        git::backend(dir)
+    }
+
+    fn move_files(&self, moves: &[(PathBuf, PathBuf)]) -> Vec<(PathBuf, anyhow::Error)> {
+        git::move_files(moves)
+    }
+
+    fn remove_files(&self, paths: &[PathBuf]) -> Result<()> {
+        git::remove_files(paths)
+    }
+
+    fn user_name(&self) -> Option<String> {
+        git::user_name()
+    }
+
+    fn history(&self, dir: &Path) -> Result<Vec<Commit>> {
+        git::history(dir)
+    }
+}
+
+/// Jujutsu: files are moved and removed directly, as jj snapshots the working copy
+pub struct Jujutsu;
+
+impl Vcs for Jujutsu {
+    fn name(&self) -> &'static str {
+        "jj"
+    }
+
+    fn backend(&self, dir: &Path) -> Backend {
+        jj::backend(dir)
+    }
+
+    fn move_files(&self, moves: &[(PathBuf, PathBuf)]) -> Vec<(PathBuf, anyhow::Error)> {
+        jj::move_files(moves)
+    }
+
+    fn remove_files(&self, paths: &[PathBuf]) -> Result<()> {
+        jj::remove_files(paths)
+    }
+
+    fn user_name(&self) -> Option<String> {
+        jj::user_name()
+    }
+
+    fn history(&self, dir: &Path) -> Result<Vec<Commit>> {
+        jj::history(dir)
+    }
+}
+
+/// Mercurial: files are moved and removed directly, then recorded with
+/// `hg mv --after` and `hg rm --after`
+pub struct Mercurial;
+
+impl Vcs for Mercurial {
+    fn name(&self) -> &'static str {
+        "hg"
+    }
+
+    fn backend(&self, dir: &Path) -> Backend {
+        hg::backend(dir)
+    }
+
+    fn move_files(&self, moves: &[(PathBuf, PathBuf)]) -> Vec<(PathBuf, anyhow::Error)> {
+        hg::move_files(moves)
+    }
+
+    fn remove_files(&self, paths: &[PathBuf]) -> Result<()> {
+        hg::remove_files(paths)
+    }
+
+    fn user_name(&self) -> Option<String> {
+        hg::user_name()
+    }
+
+    fn history(&self, dir: &Path) -> Result<Vec<Commit>> {
+        hg::history(dir)
+    }
+}
+
+thread_local! {
+    /// System of the loaded project (set from the config).
+    static SELECTED: Cell<VcsKind> = const { Cell::new(VcsKind::Git) };
+    /// File recording that the filesystem notice was shown for the loaded
+    /// project (set from the config).
+    static NOTICE_MARKER: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+    /// Whether this process already showed the filesystem notice.
+    static NOTICE_SHOWN: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Selects the system used by [`current`]; `auto` detects it from `root`.
+pub fn select(kind: VcsKind, root: &Path) {
+    let kind = match kind {
+        VcsKind::Auto => detect(root),
+        kind => kind,
+    };
+    SELECTED.with(|cell| cell.set(kind));
+}
+
+/// Returns the system of the nearest repository containing `root` (git if none).
+pub fn detect(root: &Path) -> VcsKind {
+    for dir in root.ancestors() {
+        if dir.join(".jj").is_dir() {
+            return VcsKind::Jj;
+        }
+        if dir.join(".hg").is_dir() {
+            return VcsKind::Hg;
+        }
+        if dir.join(".git").exists() {
+            return VcsKind::Git;
+        }
+    }
+    VcsKind::Git
+}
+
+/// Returns the selected system (git until a project is loaded).
+pub fn current() -> &'static dyn Vcs {
+    match SELECTED.with(Cell::get) {
+        VcsKind::Jj => &Jujutsu,
+        VcsKind::Hg => &Mercurial,
+        VcsKind::Auto | VcsKind::Git => &Git,
+    }
+}
+
+/// Moves a file with the selected system.
+pub fn move_file(from: &Path, to: &Path) -> Result<()> {
+    match move_files(&[(from.to_path_buf(), to.to_path_buf())])
+        .into_iter()
+        .next()
+    {
+        Some((_, err)) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Moves several files with the selected system.
+///
+/// Returns the source paths that could not be moved, with the reason.
+pub fn move_files(moves: &[(PathBuf, PathBuf)]) -> Vec<(PathBuf, anyhow::Error)> {
+    current().move_files(moves)
+}
+
+/// Removes a file with the selected system.
+pub fn remove_file(path: &Path) -> Result<()> {
+    remove_files(&[path.to_path_buf()])
+}
+
+/// Removes several files with the selected system.
+pub fn remove_files(paths: &[PathBuf]) -> Result<()> {
+    current().remove_files(paths)
+}
+
+/// Returns the user name configured for the selected system.
+pub fn user_name() -> Option<String> {
+    current().user_name()
+}
+
+/// Returns the commits that touched files below `dir`, oldest first.
+pub fn history(dir: &Path) -> Result<Vec<Commit>> {
+    current().history(dir)
+}
+
+/// Sets the file recording that the filesystem notice was shown.
+pub fn set_notice_marker(path: Option<PathBuf>) {
+    NOTICE_MARKER.with(|cell| *cell.borrow_mut() = path);
+}
+
+/// Tells the user, once per project, that files are moved and removed without
+/// the selected system.
+pub(super) fn notify_filesystem() {
+    if NOTICE_SHOWN.with(|shown| shown.replace(true)) {
+        return;
+    }
+    let marker = NOTICE_MARKER.with(|cell| cell.borrow().clone());
+    if marker.as_ref().is_some_and(|marker| marker.exists()) {
+        return;
+    }
+    let vcs = current();
+    let Backend::Filesystem(reason) = vcs.backend(Path::new(".")) else {
+        return;
+    };
+    eprintln!(
+        "note: {reason}; item files are moved and removed without {} (see 'qs info')",
+        vcs.name()
+    );
+    if let Some(marker) = marker {
+        if let Some(parent) = marker.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        let _ = std::fs::write(&marker, "");
+    }
+}
+
+/// Moves files with standard renames, creating the destination directories.
+pub(super) fn move_directly(moves: &[(PathBuf, PathBuf)]) -> Vec<(PathBuf, anyhow::Error)> {
+    let mut failures = Vec::new();
+    for (from, to) in moves {
+        if let Some(parent) = to.parent() {
+            if let Err(e) = std::fs::create_dir_all(parent) {
+                failures.push((
+                    from.clone(),
+                    anyhow::Error::new(e)
+                        .context(format!("Failed to create directory: {}", parent.display())),
+                ));
+                continue;
+            }
+        }
+        rename(from, to, &mut failures);
+    }
+    failures
+}
+
+/// Removes files with a standard remove.
+pub(super) fn remove_directly(paths: &[PathBuf]) -> Result<()> {
+    for path in paths {
+        std::fs::remove_file(path)
+            .with_context(|| format!("Failed to remove {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Renames a file, recording a failure.
+pub(super) fn rename(from: &Path, to: &Path, failures: &mut Vec<(PathBuf, anyhow::Error)>) {
+    if let Err(e) = std::fs::rename(from, to) {
+        failures.push((
+            from.to_path_buf(),
+            anyhow::Error::new(e).context(format!(
+                "Failed to move {} to {}",
+                from.display(),
+                to.display()
+            )),
+        ));
+    }
+}
+
+/// Parses a log whose records start with `\x1e`, followed by the author name,
+/// email, and RFC 3339 date separated by `\x1f`, then one touched file per line.
+pub(super) fn parse_log(log: &str) -> Vec<Commit> {
+    log.split('\x1e')
+        .filter_map(|record| {
+            let mut lines = record.lines();
+            let mut fields = lines.next()?.split('\x1f');
+            let (author, email, date) = (fields.next()?, fields.next()?, fields.next()?);
+            let date = DateTime::parse_from_rfc3339(date.trim())
+                .ok()?
+                .with_timezone(&Utc);
+            Some(Commit {
+                author: author.to_string(),
+                email: email.to_string(),
+                date,
+                files: lines
+                    .filter(|line| !line.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_detect_nearest_repository() {
+        let dir = tempdir().unwrap();
+        let project = dir.path().join("app");
+        fs::create_dir_all(project.join(".hg")).unwrap();
+        fs::create_dir_all(dir.path().join(".git")).unwrap();
+        assert_eq!(detect(&project), VcsKind::Hg);
+        assert_eq!(detect(dir.path()), VcsKind::Git);
+
+        // A colocated jj repository also has .git
+        fs::create_dir_all(dir.path().join(".jj")).unwrap();
+        assert_eq!(detect(dir.path()), VcsKind::Jj);
+    }
+
+    #[test]
+    fn test_move_and_remove_directly() {
+        let dir = tempdir().unwrap();
+        let from = dir.path().join("item.md");
+        let to = dir.path().join("archive/item.md");
+        fs::write(&from, "content").unwrap();
+
+        let missing = dir.path().join("missing.md");
+        let failures = move_directly(&[(from.clone(), to.clone()), (missing.clone(), to.clone())]);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, missing);
+        assert!(!from.exists());
+
+        remove_directly(std::slice::from_ref(&to)).unwrap();
+        assert!(!to.exists());
+    }
+
+    #[test]
+    fn test_parse_log() {
+        let log = "\x1eAda\x1fada@example.com\x1f2026-01-09T10:00:00+01:00\n\n\
+                   queuestack/260109-ABC-crash.md\n\
+                   \x1eBob\x1fbob@example.com\x1f2026-01-10T08:00:00Z\n";
+        let commits = parse_log(log);
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].author, "Ada");
+        assert_eq!(commits[0].date.to_rfc3339(), "2026-01-09T09:00:00+00:00");
+        assert_eq!(commits[0].files, ["queuestack/260109-ABC-crash.md"]);
+        assert!(commits[1].files.is_empty());
+    }
+}
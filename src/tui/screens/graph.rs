@@ -0,0 +1,487 @@
+//! Dependency graph screen (`qs graph --tui`).
+//!
+//! Lists the items of the dependency graph with the most blocked items first,
+//! so bottlenecks are at the top. The highlighted item's blockers, blocked
+//! items, parent, and children are shown below the list; Left and Right jump
+//! along the blocking edges, and Enter opens the item's action popup.
+
+use std::{
+    collections::{BTreeSet, VecDeque},
+    path::PathBuf,
+};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::item_actions::{ItemAction, ItemPopup, PopupResult};
+use crate::{
+    constants::UI_TITLE_TRUNCATE_LEN,
+    item::Status,
+    tui::{
+        event::TuiEvent,
+        run,
+        widgets::{SelectAction, SelectList},
+        AppResult, TuiApp,
+    },
+    ui::{pad_to_width, truncate},
+};
+
+/// An item of the dependency graph.
+#[derive(Debug, Clone, Default)]
+pub struct GraphNode {
+    pub id: String,
+    pub title: String,
+    pub status: Status,
+    pub path: PathBuf,
+    /// Frontmatter actions (name, command)
+    pub actions: Vec<(String, String)>,
+    /// Indices of the nodes this item blocks
+    pub blocks: Vec<usize>,
+    /// Indices of the nodes this item is the parent of
+    pub children: Vec<usize>,
+}
+
+/// Relations of a node, derived from the edges of all nodes.
+#[derive(Debug, Default)]
+struct Relations {
+    /// Nodes blocking this one
+    blocked_by: Vec<usize>,
+    /// Parents of this node
+    parents: Vec<usize>,
+    /// Number of nodes blocked directly or through a chain of blockers
+    blocking: usize,
+}
+
+/// Dependency graph screen.
+struct GraphScreen {
+    nodes: Vec<GraphNode>,
+    relations: Vec<Relations>,
+    /// Node index of each list row, most blocking first
+    rows: Vec<usize>,
+    list: SelectList,
+    header: String,
+    /// Action popup of the selected item, if open
+    popup: Option<ItemPopup>,
+}
+
+impl GraphScreen {
+    fn new(nodes: Vec<GraphNode>) -> Self {
+        let mut relations: Vec<Relations> = nodes.iter().map(|_| Relations::default()).collect();
+        for (index, node) in nodes.iter().enumerate() {
+            for &other in &node.blocks {
+                relations[other].blocked_by.push(index);
+            }
+            for &child in &node.children {
+                relations[child].parents.push(index);
+            }
+        }
+        for (index, relation) in relations.iter_mut().enumerate() {
+            relation.blocking = blocking(&nodes, index);
+        }
+
+        let mut rows: Vec<usize> = (0..nodes.len()).collect();
+        rows.sort_by(|&a, &b| {
+            relations[b]
+                .blocking
+                .cmp(&relations[a].blocking)
+                .then_with(|| nodes[a].id.cmp(&nodes[b].id))
+        });
+
+        let header = format!(
+            "{:<15} {:>11}  {:>6}  {:>6}  {}",
+            "ID", "Status", "Blocks", "Waits", "Title"
+        );
+        let display: Vec<String> = rows
+            .iter()
+            .map(|&index| {
+                let node = &nodes[index];
+                format!(
+                    "{:<15} {:>11}  {:>6}  {:>6}  {}",
+                    node.id,
+                    status_name(node.status),
+                    relations[index].blocking,
+                    relations[index].blocked_by.len(),
+                    pad_to_width(&truncate(&node.title, UI_TITLE_TRUNCATE_LEN), 40)
+                )
+            })
+            .collect();
+        // Items that others wait on are shown in red
+        let bottlenecks = rows
+            .iter()
+            .enumerate()
+            .filter(|&(_, &index)| relations[index].blocking > 0)
+            .map(|(row, _)| row)
+            .collect();
+
+        Self {
+            nodes,
+            relations,
+            rows,
+            list: SelectList::new(display).with_highlighted(bottlenecks),
+            header,
+            popup: None,
+        }
+    }
+
+    /// Node index of the highlighted row.
+    fn selected(&self) -> Option<usize> {
+        self.list
+            .selected_index()
+            .and_then(|row| self.rows.get(row).copied())
+    }
+
+    /// Highlights the row of a node.
+    fn select_node(&mut self, index: usize) {
+        if let Some(row) = self.rows.iter().position(|&i| i == index) {
+            self.list.select(row);
+        }
+    }
+
+    /// Summary of the status flow: ready, in progress, and blocked items.
+    fn summary(&self) -> String {
+        let blocked = self
+            .relations
+            .iter()
+            .filter(|relation| !relation.blocked_by.is_empty())
+            .count();
+        let in_progress = self
+            .nodes
+            .iter()
+            .zip(&self.relations)
+            .filter(|(node, relation)| {
+                node.status == Status::InProgress && relation.blocked_by.is_empty()
+            })
+            .count();
+        let ready = self.nodes.len() - blocked - in_progress;
+        format!(
+            "Dependency graph: {} items · {ready} ready → {in_progress} in progress · {blocked} blocked",
+            self.nodes.len()
+        )
+    }
+
+    /// Handle events while browsing the list.
+    fn handle_browsing(&mut self, event: &TuiEvent) -> Option<AppResult<ItemAction>> {
+        let TuiEvent::Key(key) = event else {
+            return None;
+        };
+
+        match key.code {
+            // Jump to the first item blocking the highlighted one
+            KeyCode::Left | KeyCode::Char('h') => {
+                if let Some(&other) = self
+                    .selected()
+                    .and_then(|index| self.relations[index].blocked_by.first())
+                {
+                    self.select_node(other);
+                }
+                return None;
+            }
+            // Jump to the first item the highlighted one blocks
+            KeyCode::Right | KeyCode::Char('l') => {
+                if let Some(&other) = self
+                    .selected()
+                    .and_then(|index| self.nodes[index].blocks.first())
+                {
+                    self.select_node(other);
+                }
+                return None;
+            }
+            _ => {}
+        }
+
+        match self.list.handle_key(*key) {
+            SelectAction::Confirm => {
+                if let Some(index) = self.selected() {
+                    let node = &self.nodes[index];
+                    self.popup = Some(ItemPopup::new(
+                        node.path.clone(),
+                        node.status,
+                        node.actions.clone(),
+                    ));
+                }
+                None
+            }
+            SelectAction::Cancel => Some(AppResult::Cancelled),
+            SelectAction::None => None,
+        }
+    }
+
+    /// Handle events while showing the popup.
+    fn handle_popup(&mut self, event: &TuiEvent) -> Option<AppResult<ItemAction>> {
+        let TuiEvent::Key(key) = event else {
+            return None;
+        };
+        let popup = self.popup.as_mut()?;
+
+        match popup.handle_key(*key)? {
+            PopupResult::Action(action) => Some(AppResult::Done(action)),
+            PopupResult::Closed => {
+                self.popup = None;
+                None
+            }
+        }
+    }
+
+    /// Lines describing the relations of a node.
+    fn relation_lines(&self, index: usize) -> Vec<Line<'_>> {
+        let node = &self.nodes[index];
+        let relation = &self.relations[index];
+        let mut lines = vec![Line::from(vec![
+            Span::styled(node.id.as_str(), Style::default().fg(Color::Cyan)),
+            Span::raw(" "),
+            Span::styled(
+                node.title.as_str(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        ])];
+
+        for (label, others) in [
+            ("Blocked by", &relation.blocked_by),
+            ("Blocks", &node.blocks),
+            ("Parent", &relation.parents),
+            ("Children", &node.children),
+        ] {
+            if others.is_empty() {
+                continue;
+            }
+            let mut spans = vec![Span::styled(
+                format!("{label:<11}"),
+                Style::default().fg(Color::DarkGray),
+            )];
+            for (position, &other) in others.iter().enumerate() {
+                if position > 0 {
+                    spans.push(Span::raw(", "));
+                }
+                let other = &self.nodes[other];
+                spans.push(Span::styled(
+                    other.id.as_str(),
+                    Style::default().fg(Color::Cyan),
+                ));
+                spans.push(Span::raw(format!(
+                    " {}",
+                    truncate(&other.title, UI_TITLE_TRUNCATE_LEN)
+                )));
+            }
+            lines.push(Line::from(spans));
+        }
+        lines
+    }
+
+    fn render_graph(&mut self, frame: &mut Frame) {
+        // Layout: summary, header, list, relations, help
+        let chunks = Layout::vertical([
+            Constraint::Length(3), // Summary
+            Constraint::Length(1), // Header
+            Constraint::Min(5),    // List
+            Constraint::Length(7), // Relations
+            Constraint::Length(3), // Help
+        ])
+        .split(frame.area());
+
+        let summary = Paragraph::new(self.summary()).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+        frame.render_widget(summary, chunks[0]);
+
+        let header = Paragraph::new(self.header.as_str()).style(
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+        frame.render_widget(header, chunks[1]);
+
+        if self.nodes.is_empty() {
+            let empty = Paragraph::new("No dependencies.")
+                .style(Style::default().fg(Color::DarkGray))
+                .block(Block::default().borders(Borders::ALL));
+            frame.render_widget(empty, chunks[2]);
+        } else {
+            self.list.render(chunks[2], frame.buffer_mut(), true);
+        }
+
+        let relation_lines = self
+            .selected()
+            .map(|index| self.relation_lines(index))
+            .unwrap_or_default();
+        let relations = Paragraph::new(relation_lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+        frame.render_widget(relations, chunks[3]);
+
+        let help = Paragraph::new(Line::from(vec![
+            Span::styled("Enter", Style::default().fg(Color::Cyan)),
+            Span::raw(" Actions  "),
+            Span::styled("←", Style::default().fg(Color::Cyan)),
+            Span::raw(" Blocker  "),
+            Span::styled("→", Style::default().fg(Color::Cyan)),
+            Span::raw(" Blocked  "),
+            Span::styled("Esc", Style::default().fg(Color::Cyan)),
+            Span::raw(" Cancel"),
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+        frame.render_widget(help, chunks[4]);
+    }
+}
+
+impl TuiApp for GraphScreen {
+    type Output = ItemAction;
+
+    fn handle_event(&mut self, event: &TuiEvent) -> Option<AppResult<Self::Output>> {
+        if let TuiEvent::Key(key) = event {
+            // Handle Ctrl+C
+            if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                return Some(AppResult::Cancelled);
+            }
+        }
+
+        if self.popup.is_some() {
+            self.handle_popup(event)
+        } else {
+            self.handle_browsing(event)
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame) {
+        self.render_graph(frame);
+
+        if let Some(popup) = &mut self.popup {
+            popup.render(frame.area(), frame.buffer_mut());
+        }
+    }
+}
+
+/// Counts the nodes blocked by `start`, directly or through other blockers.
+fn blocking(nodes: &[GraphNode], start: usize) -> usize {
+    let mut seen = BTreeSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(index) = queue.pop_front() {
+        for &other in &nodes[index].blocks {
+            if seen.insert(other) {
+                queue.push_back(other);
+            }
+        }
+    }
+    seen.len() - 1
+}
+
+const fn status_name(status: Status) -> &'static str {
+    match status {
+        Status::Open => "open",
+        Status::InProgress => "in-progress",
+        Status::Closed => "closed",
+        Status::Template => "template",
+    }
+}
+
+/// Run the dependency graph screen.
+///
+/// Returns the action chosen for an item, or `Ok(None)` if cancelled.
+pub fn browse_graph(nodes: Vec<GraphNode>) -> anyhow::Result<Option<ItemAction>> {
+    run(GraphScreen::new(nodes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyEvent;
+
+    fn key_event(code: KeyCode) -> TuiEvent {
+        TuiEvent::Key(KeyEvent::new(code, KeyModifiers::empty()))
+    }
+
+    fn node(id: &str, blocks: Vec<usize>) -> GraphNode {
+        GraphNode {
+            id: id.to_string(),
+            title: format!("Item {id}"),
+            path: PathBuf::from(format!("{id}.md")),
+            blocks,
+            ..GraphNode::default()
+        }
+    }
+
+    /// A blocks B, B blocks C, and D blocks C.
+    fn screen() -> GraphScreen {
+        GraphScreen::new(vec![
+            node("A", vec![1]),
+            node("B", vec![2]),
+            node("C", vec![]),
+            node("D", vec![2]),
+        ])
+    }
+
+    #[test]
+    fn test_bottlenecks_first() {
+        let screen = screen();
+        assert_eq!(screen.rows, vec![0, 1, 3, 2]);
+        assert_eq!(screen.relations[0].blocking, 2);
+        assert_eq!(screen.relations[2].blocked_by, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_blocking_handles_cycles() {
+        let nodes = vec![node("A", vec![1]), node("B", vec![0])];
+        assert_eq!(blocking(&nodes, 0), 1);
+    }
+
+    #[test]
+    fn test_jump_along_edges() {
+        let mut screen = screen();
+        assert_eq!(screen.selected(), Some(0));
+
+        screen.handle_event(&key_event(KeyCode::Right));
+        assert_eq!(screen.selected(), Some(1));
+        screen.handle_event(&key_event(KeyCode::Right));
+        assert_eq!(screen.selected(), Some(2));
+        screen.handle_event(&key_event(KeyCode::Left));
+        assert_eq!(screen.selected(), Some(1));
+    }
+
+    #[test]
+    fn test_enter_opens_action_menu() {
+        let mut screen = screen();
+        assert!(screen.handle_event(&key_event(KeyCode::Enter)).is_none());
+        assert!(screen.popup.is_some());
+
+        // The first entry is View
+        let result = screen.handle_event(&key_event(KeyCode::Enter));
+        assert_eq!(
+            result,
+            Some(AppResult::Done(ItemAction::View(PathBuf::from("A.md"))))
+        );
+    }
+
+    #[test]
+    fn test_escape_closes_popup_then_cancels() {
+        let mut screen = screen();
+        screen.handle_event(&key_event(KeyCode::Enter));
+        assert!(screen.handle_event(&key_event(KeyCode::Esc)).is_none());
+        assert!(screen.popup.is_none());
+        assert_eq!(
+            screen.handle_event(&key_event(KeyCode::Esc)),
+            Some(AppResult::Cancelled)
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let screen = screen();
+        assert_eq!(
+            screen.summary(),
+            "Dependency graph: 4 items · 2 ready → 0 in progress · 2 blocked"
+        );
+    }
+}
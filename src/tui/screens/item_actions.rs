@@ -1,14 +1,18 @@
 //! Item selection screen with action popup and filter overlay.
 //!
 //! Provides an interactive list of items with a popup menu for quick actions
-//! like View, Edit, Close/Reopen, and Delete. Also supports filtering by
-//! search query, labels, and category.
+//! like View, Edit, the item's own actions, copying to the clipboard,
+//! Close/Reopen, and Delete. Also supports filtering by
+//! search query, labels, and category. The highlighted item's card is shown
+//! in a preview pane below the list. Items can be shown in collapsible groups
+//! (`qs list --group-by`).
 
-use std::path::PathBuf;
+use std::{collections::HashSet, path::PathBuf};
 
-use crossterm::event::{KeyCode, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
-    layout::{Constraint, Layout},
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
@@ -16,9 +20,15 @@ use ratatui::{
 };
 
 use crate::{
+    clipboard::CopyWhat,
     config::Config,
     constants::{UI_LABELS_TRUNCATE_LEN, UI_TITLE_TRUNCATE_LEN},
-    item::{matches_any_label, matches_category_filter, matches_search_text, Item, Status},
+    item::{
+        card::{Card, Tone},
+        group::Group,
+        labels::LabelColor,
+        matches_any_label, matches_category_filter, matches_search_text, Item, Status,
+    },
     storage,
     tui::{
         event::TuiEvent,
@@ -44,6 +54,10 @@ pub enum ItemAction {
     Reopen(PathBuf),
     /// Delete item (move to trash)
     Delete(PathBuf),
+    /// Run one of the item's frontmatter actions
+    Run(PathBuf, String),
+    /// Copy the item's ID, path, or link to the clipboard
+    Copy(PathBuf, CopyWhat),
 }
 
 /// Internal action kinds for the popup menu.
@@ -54,6 +68,9 @@ enum ActionKind {
     Close,
     Reopen,
     Delete,
+    /// Index into the item's actions
+    Run(usize),
+    Copy(CopyWhat),
     Cancel,
 }
 
@@ -68,6 +85,21 @@ struct ItemInfo {
     body: String,
     labels: Vec<String>,
     category: Option<String>,
+    /// Past its due date; shown in red
+    overdue: bool,
+    /// Frontmatter actions (name, command)
+    actions: Vec<(String, String)>,
+    /// Summary card for the preview pane
+    card: Card,
+}
+
+/// A row of the displayed list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row {
+    /// Group header (index into the groups)
+    Group(usize),
+    /// Item (index into all items)
+    Item(usize),
 }
 
 /// Screen state.
@@ -75,11 +107,7 @@ enum ScreenState {
     /// Browsing the item list.
     Browsing,
     /// Showing the action popup for an item.
-    ShowingPopup {
-        item_index: usize,
-        menu: ActionMenu,
-        actions: Vec<ActionKind>,
-    },
+    ShowingPopup { popup: ItemPopup },
     /// Showing the filter overlay.
     ShowingFilter { overlay: Box<FilterOverlay> },
 }
@@ -90,6 +118,12 @@ pub struct ItemActionScreen {
     all_items: Vec<ItemInfo>,
     /// Indices into `all_items` that match the current filter
     filtered_indices: Vec<usize>,
+    /// Item groups (empty when not grouping)
+    groups: Vec<Group>,
+    /// Indices of collapsed groups
+    collapsed: HashSet<usize>,
+    /// What each row of the display list shows
+    rows: Vec<Row>,
     /// Current filter state
     filter_state: FilterState,
     /// Available labels for filter overlay
@@ -105,24 +139,30 @@ pub struct ItemActionScreen {
 
 impl ItemActionScreen {
     /// Create a new item action screen.
+    ///
+    /// Items are shown under collapsible headers when `groups` is not empty.
     pub fn new<T: AsRef<Item>>(
         prompt: &str,
         items: &[T],
         config: &Config,
         available_labels: Vec<String>,
         available_categories: Vec<String>,
+        groups: Vec<Group>,
     ) -> Self {
         let header = format!(
             "{:<15} {:>6}  {:<40}  {:<20}  {}",
             "ID", "Status", "Title", "Labels", "Category"
         );
 
+        let now = chrono::Utc::now();
+        let today = chrono::Local::now().date_naive();
         let all_items: Vec<ItemInfo> = items
             .iter()
             .map(|item| {
                 let item = item.as_ref();
                 let status_str = match item.status() {
                     Status::Open => "open",
+                    Status::InProgress => "in-progress",
                     Status::Closed => "closed",
                     Status::Template => "template",
                 };
@@ -151,6 +191,13 @@ impl ItemActionScreen {
                     id: item.id().to_string(),
                     body: item.body.clone(),
                     labels: item.labels().to_vec(),
+                    overdue: item.is_overdue(today),
+                    actions: item
+                        .actions()
+                        .iter()
+                        .map(|(name, command)| (name.clone(), command.clone()))
+                        .collect(),
+                    card: Card::new(item, category_opt.as_deref(), now),
                     category: category_opt,
                 }
             })
@@ -158,20 +205,23 @@ impl ItemActionScreen {
 
         // Initially all items are shown
         let filtered_indices: Vec<usize> = (0..all_items.len()).collect();
-        let display_strings: Vec<String> = all_items.iter().map(|i| i.display.clone()).collect();
-        let list = SelectList::new(display_strings);
 
-        Self {
+        let mut screen = Self {
             all_items,
             filtered_indices,
+            groups,
+            collapsed: HashSet::new(),
+            rows: Vec::new(),
             filter_state: FilterState::default(),
             available_labels,
             available_categories,
-            list,
             header,
             prompt: prompt.to_string(),
             state: ScreenState::Browsing,
-        }
+            list: SelectList::new(Vec::<String>::new()),
+        };
+        screen.rebuild_display_list();
+        screen
     }
 
     /// Apply the current filter state to update `filtered_indices` and rebuild the list.
@@ -210,79 +260,80 @@ impl ItemActionScreen {
         self.rebuild_display_list();
     }
 
-    /// Rebuild the `SelectList` based on `filtered_indices`.
+    /// Rebuild the `SelectList` based on `filtered_indices` and the groups.
     fn rebuild_display_list(&mut self) {
+        self.rows = if self.groups.is_empty() {
+            self.filtered_indices
+                .iter()
+                .map(|&i| Row::Item(i))
+                .collect()
+        } else {
+            let visible: HashSet<usize> = self.filtered_indices.iter().copied().collect();
+            let mut rows = Vec::new();
+            for (index, group) in self.groups.iter().enumerate() {
+                let members = group.members.iter().filter(|i| visible.contains(i));
+                if members.clone().next().is_none() {
+                    continue; // Hide groups without matching items
+                }
+                rows.push(Row::Group(index));
+                if !self.collapsed.contains(&index) {
+                    rows.extend(members.map(|&i| Row::Item(i)));
+                }
+            }
+            rows
+        };
+
         let display_strings: Vec<String> = self
-            .filtered_indices
+            .rows
             .iter()
-            .map(|&i| self.all_items[i].display.clone())
+            .map(|&row| match row {
+                Row::Group(index) => {
+                    let group = &self.groups[index];
+                    let count = self
+                        .filtered_indices
+                        .iter()
+                        .filter(|i| group.members.contains(i))
+                        .count();
+                    let marker = if self.collapsed.contains(&index) {
+                        "▸"
+                    } else {
+                        "▾"
+                    };
+                    format!("{marker} {} ({count})", group.name)
+                }
+                Row::Item(i) => self.all_items[i].display.clone(),
+            })
             .collect();
 
-        // Create new list with filtered items
-        self.list = SelectList::new(display_strings);
-    }
+        let overdue: HashSet<usize> = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter(|&(_, &row)| matches!(row, Row::Item(i) if self.all_items[i].overdue))
+            .map(|(index, _)| index)
+            .collect();
 
-    /// Get the actual item index from the filtered list index.
-    fn actual_index(&self, filtered_idx: usize) -> Option<usize> {
-        self.filtered_indices.get(filtered_idx).copied()
+        // Create new list with filtered items, overdue ones in red
+        self.list = SelectList::new(display_strings).with_highlighted(overdue);
     }
 
-    /// Build popup menu items based on item status.
-    fn build_popup_items(status: Status) -> (Vec<MenuItem>, Vec<ActionKind>) {
-        let mut items = Vec::new();
-        let mut actions = Vec::new();
-
-        // Section 1: View/Edit actions
-        items.push(MenuItem::action("View...", "open in editor", actions.len()));
-        actions.push(ActionKind::View);
-
-        if status == Status::Open {
-            items.push(MenuItem::action(
-                "Edit...",
-                "modify via wizard",
-                actions.len(),
-            ));
-            actions.push(ActionKind::Edit);
+    /// Get the actual item index from the list index (`None` for group headers).
+    fn actual_index(&self, list_idx: usize) -> Option<usize> {
+        match self.rows.get(list_idx) {
+            Some(&Row::Item(index)) => Some(index),
+            _ => None,
         }
+    }
 
-        // Separator
-        items.push(MenuItem::separator());
-
-        // Section 2: Status actions
-        if status == Status::Open {
-            items.push(MenuItem::action_colored(
-                "Close",
-                "archive item",
-                Color::Yellow,
-                actions.len(),
-            ));
-            actions.push(ActionKind::Close);
-        } else {
-            items.push(MenuItem::action_colored(
-                "Reopen",
-                "restore to active",
-                Color::Green,
-                actions.len(),
-            ));
-            actions.push(ActionKind::Reopen);
+    /// Collapse or expand a group, keeping its header selected.
+    fn toggle_group(&mut self, group: usize) {
+        if !self.collapsed.remove(&group) {
+            self.collapsed.insert(group);
+        }
+        self.rebuild_display_list();
+        if let Some(row) = self.rows.iter().position(|&row| row == Row::Group(group)) {
+            self.list.select(row);
         }
-
-        items.push(MenuItem::action_colored(
-            "Delete",
-            "move to trash",
-            Color::Red,
-            actions.len(),
-        ));
-        actions.push(ActionKind::Delete);
-
-        // Separator
-        items.push(MenuItem::separator());
-
-        // Section 3: Cancel
-        items.push(MenuItem::action("Cancel", "ESC", actions.len()));
-        actions.push(ActionKind::Cancel);
-
-        (items, actions)
     }
 
     /// Open the popup for the currently selected item.
@@ -290,17 +341,8 @@ impl ItemActionScreen {
         if let Some(filtered_idx) = self.list.selected_index() {
             if let Some(actual_idx) = self.actual_index(filtered_idx) {
                 let item = &self.all_items[actual_idx];
-                let title = if item.status == Status::Open {
-                    "Actions"
-                } else {
-                    "Actions (Archived)"
-                };
-                let (menu_items, actions) = Self::build_popup_items(item.status);
-                let menu = ActionMenu::new(title, menu_items);
                 self.state = ScreenState::ShowingPopup {
-                    item_index: actual_idx,
-                    menu,
-                    actions,
+                    popup: ItemPopup::new(item.path.clone(), item.status, item.actions.clone()),
                 };
             }
         }
@@ -341,8 +383,14 @@ impl ItemActionScreen {
 
             match self.list.handle_key(*key) {
                 SelectAction::Confirm => {
-                    if !self.filtered_indices.is_empty() {
-                        self.open_popup();
+                    let row = self
+                        .list
+                        .selected_index()
+                        .and_then(|idx| self.rows.get(idx).copied());
+                    match row {
+                        Some(Row::Group(group)) => self.toggle_group(group),
+                        Some(Row::Item(_)) => self.open_popup(),
+                        None => {}
                     }
                     None
                 }
@@ -355,46 +403,24 @@ impl ItemActionScreen {
     }
 
     /// Handle events while showing the popup.
-    fn handle_popup(
-        &mut self,
-        event: &TuiEvent,
-        item_index: usize,
-        actions: &[ActionKind],
-    ) -> Option<AppResult<ItemAction>> {
+    fn handle_popup(&mut self, event: &TuiEvent) -> Option<AppResult<ItemAction>> {
         if let TuiEvent::Key(key) = event {
             // Handle Ctrl+C
             if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
                 return Some(AppResult::Cancelled);
             }
 
-            // Get mutable reference to menu
-            let ScreenState::ShowingPopup { menu, .. } = &mut self.state else {
+            let ScreenState::ShowingPopup { popup } = &mut self.state else {
                 return None;
             };
 
-            match menu.handle_key(*key) {
-                Some(ActionMenuResult::Selected(action_idx)) => {
-                    let item = &self.all_items[item_index];
-                    let path = item.path.clone();
-                    match actions[action_idx] {
-                        ActionKind::View => Some(AppResult::Done(ItemAction::View(path))),
-                        ActionKind::Edit => Some(AppResult::Done(ItemAction::Edit(path))),
-                        ActionKind::Close => Some(AppResult::Done(ItemAction::Close(path))),
-                        ActionKind::Reopen => Some(AppResult::Done(ItemAction::Reopen(path))),
-                        ActionKind::Delete => Some(AppResult::Done(ItemAction::Delete(path))),
-                        ActionKind::Cancel => {
-                            // Close popup, return to browsing
-                            self.state = ScreenState::Browsing;
-                            None
-                        }
-                    }
-                }
-                Some(ActionMenuResult::Cancelled) => {
+            match popup.handle_key(*key)? {
+                PopupResult::Action(action) => Some(AppResult::Done(action)),
+                PopupResult::Closed => {
                     // Close popup, return to browsing
                     self.state = ScreenState::Browsing;
                     None
                 }
-                None => None,
             }
         } else {
             None
@@ -440,11 +466,12 @@ impl ItemActionScreen {
     fn render_list(&mut self, frame: &mut Frame) {
         let area = frame.area();
 
-        // Layout: prompt, header, list, help
+        // Layout: prompt, header, list, preview, help
         let chunks = Layout::vertical([
             Constraint::Length(3), // Prompt
             Constraint::Length(1), // Header
             Constraint::Min(5),    // List
+            Constraint::Length(5), // Preview
             Constraint::Length(3), // Help
         ])
         .split(area);
@@ -497,12 +524,26 @@ impl ItemActionScreen {
             self.list.render(chunks[2], frame.buffer_mut(), true);
         }
 
+        // Preview of the highlighted item
+        let preview_lines = self
+            .list
+            .selected_index()
+            .and_then(|idx| self.actual_index(idx))
+            .map(|idx| card_lines(&self.all_items[idx].card))
+            .unwrap_or_default();
+        let preview = Paragraph::new(preview_lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+        frame.render_widget(preview, chunks[3]);
+
         // Help - with filter right-aligned
         let help_block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::DarkGray));
-        let help_inner = help_block.inner(chunks[3]);
-        frame.render_widget(help_block, chunks[3]);
+        let help_inner = help_block.inner(chunks[4]);
+        frame.render_widget(help_block, chunks[4]);
 
         // Left side: Enter Select, Esc Cancel
         let left_spans = vec![
@@ -544,15 +585,7 @@ impl TuiApp for ItemActionScreen {
     fn handle_event(&mut self, event: &TuiEvent) -> Option<AppResult<Self::Output>> {
         match &self.state {
             ScreenState::Browsing => self.handle_browsing(event),
-            ScreenState::ShowingPopup {
-                item_index,
-                actions,
-                ..
-            } => {
-                let item_index = *item_index;
-                let actions = actions.clone();
-                self.handle_popup(event, item_index, &actions)
-            }
+            ScreenState::ShowingPopup { .. } => self.handle_popup(event),
             ScreenState::ShowingFilter { .. } => self.handle_filter(event),
         }
     }
@@ -563,8 +596,8 @@ impl TuiApp for ItemActionScreen {
 
         // Render overlays on top
         match &mut self.state {
-            ScreenState::ShowingPopup { menu, .. } => {
-                menu.render(frame.area(), frame.buffer_mut());
+            ScreenState::ShowingPopup { popup } => {
+                popup.render(frame.area(), frame.buffer_mut());
             }
             ScreenState::ShowingFilter { overlay } => {
                 overlay.render(frame.area(), frame.buffer_mut());
@@ -574,8 +607,206 @@ impl TuiApp for ItemActionScreen {
     }
 }
 
+/// Result of a key press in the action popup.
+pub(super) enum PopupResult {
+    /// An action was chosen
+    Action(ItemAction),
+    /// The popup was dismissed
+    Closed,
+}
+
+/// Action popup for a single item, shared by the item list and the
+/// dependency graph screen.
+pub(super) struct ItemPopup {
+    path: PathBuf,
+    /// The item's frontmatter actions (name, command)
+    item_actions: Vec<(String, String)>,
+    menu: ActionMenu,
+    actions: Vec<ActionKind>,
+}
+
+impl ItemPopup {
+    /// Create the popup for the item at `path`.
+    pub(super) fn new(path: PathBuf, status: Status, item_actions: Vec<(String, String)>) -> Self {
+        let title = if status.is_active() {
+            "Actions"
+        } else {
+            "Actions (Archived)"
+        };
+        let (menu_items, actions) = Self::build_items(status, &item_actions);
+        Self {
+            path,
+            item_actions,
+            menu: ActionMenu::new(title, menu_items),
+            actions,
+        }
+    }
+
+    /// Build popup menu items based on item status and its own actions.
+    fn build_items(
+        status: Status,
+        item_actions: &[(String, String)],
+    ) -> (Vec<MenuItem>, Vec<ActionKind>) {
+        let mut items = Vec::new();
+        let mut actions = Vec::new();
+
+        // Section 1: View/Edit actions
+        items.push(MenuItem::action("View...", "open in editor", actions.len()));
+        actions.push(ActionKind::View);
+
+        if status.is_active() {
+            items.push(MenuItem::action(
+                "Edit...",
+                "modify via wizard",
+                actions.len(),
+            ));
+            actions.push(ActionKind::Edit);
+        }
+
+        // Separator
+        items.push(MenuItem::separator());
+
+        // Section 2: The item's own actions
+        if !item_actions.is_empty() {
+            for (index, (name, command)) in item_actions.iter().enumerate() {
+                items.push(MenuItem::action(
+                    format!("Run {name}"),
+                    truncate(command, UI_TITLE_TRUNCATE_LEN),
+                    actions.len(),
+                ));
+                actions.push(ActionKind::Run(index));
+            }
+            items.push(MenuItem::separator());
+        }
+
+        // Section 3: Clipboard actions
+        for (label, what) in [
+            ("Copy ID", CopyWhat::Id),
+            ("Copy path", CopyWhat::Path),
+            ("Copy link", CopyWhat::Url),
+            ("Copy Markdown link", CopyWhat::MarkdownLink),
+        ] {
+            items.push(MenuItem::action(label, "to clipboard", actions.len()));
+            actions.push(ActionKind::Copy(what));
+        }
+        items.push(MenuItem::separator());
+
+        // Section 4: Status actions
+        if status.is_active() {
+            items.push(MenuItem::action_colored(
+                "Close",
+                "archive item",
+                Color::Yellow,
+                actions.len(),
+            ));
+            actions.push(ActionKind::Close);
+        } else {
+            items.push(MenuItem::action_colored(
+                "Reopen",
+                "restore to active",
+                Color::Green,
+                actions.len(),
+            ));
+            actions.push(ActionKind::Reopen);
+        }
+
+        items.push(MenuItem::action_colored(
+            "Delete",
+            "move to trash",
+            Color::Red,
+            actions.len(),
+        ));
+        actions.push(ActionKind::Delete);
+
+        // Separator
+        items.push(MenuItem::separator());
+
+        // Section 5: Cancel
+        items.push(MenuItem::action("Cancel", "ESC", actions.len()));
+        actions.push(ActionKind::Cancel);
+
+        (items, actions)
+    }
+
+    /// Handle a key press; returns `None` while the popup stays open.
+    pub(super) fn handle_key(&mut self, key: KeyEvent) -> Option<PopupResult> {
+        match self.menu.handle_key(key)? {
+            ActionMenuResult::Selected(action_idx) => {
+                let path = self.path.clone();
+                let action = match self.actions[action_idx] {
+                    ActionKind::View => ItemAction::View(path),
+                    ActionKind::Edit => ItemAction::Edit(path),
+                    ActionKind::Close => ItemAction::Close(path),
+                    ActionKind::Reopen => ItemAction::Reopen(path),
+                    ActionKind::Delete => ItemAction::Delete(path),
+                    ActionKind::Run(index) => {
+                        ItemAction::Run(path, self.item_actions[index].0.clone())
+                    }
+                    ActionKind::Copy(what) => ItemAction::Copy(path, what),
+                    ActionKind::Cancel => return Some(PopupResult::Closed),
+                };
+                Some(PopupResult::Action(action))
+            }
+            ActionMenuResult::Cancelled => Some(PopupResult::Closed),
+        }
+    }
+
+    /// Render the popup centered in `area`.
+    pub(super) fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        self.menu.render(area, buf);
+    }
+}
+
+/// Converts a card to styled lines, using the same color coding as the CLI.
+fn card_lines(card: &Card) -> Vec<Line<'_>> {
+    card.lines()
+        .iter()
+        .map(|line| {
+            Line::from(
+                line.iter()
+                    .map(|segment| {
+                        let style = match segment.tone {
+                            Tone::Id => Style::default().fg(Color::Cyan),
+                            Tone::Status(Status::Open) => Style::default().fg(Color::Green),
+                            Tone::Status(Status::InProgress) => Style::default().fg(Color::Yellow),
+                            Tone::Status(Status::Closed | Status::Template) | Tone::Meta => {
+                                Style::default().fg(Color::DarkGray)
+                            }
+                            Tone::Title => Style::default().add_modifier(Modifier::BOLD),
+                            Tone::Labels => Style::default().fg(Color::Magenta),
+                            Tone::Label(color) => Style::default().fg(label_color(color)),
+                            Tone::Match => Style::default()
+                                .fg(Color::Yellow)
+                                .add_modifier(Modifier::BOLD),
+                            Tone::Summary | Tone::Plain => Style::default(),
+                        };
+                        Span::styled(segment.text.as_str(), style)
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect()
+}
+
+/// Maps a defined label color to its terminal color.
+const fn label_color(color: LabelColor) -> Color {
+    match color {
+        LabelColor::Black => Color::Black,
+        LabelColor::Red => Color::Red,
+        LabelColor::Green => Color::Green,
+        LabelColor::Yellow => Color::Yellow,
+        LabelColor::Blue => Color::Blue,
+        LabelColor::Magenta => Color::Magenta,
+        LabelColor::Cyan => Color::Cyan,
+        LabelColor::White => Color::White,
+        LabelColor::Gray => Color::DarkGray,
+        LabelColor::Rgb(r, g, b) => Color::Rgb(r, g, b),
+    }
+}
+
 /// Run the item action screen.
 ///
+/// Items are shown under collapsible headers when `groups` is not empty.
 /// Returns the selected action, or `Ok(None)` if cancelled.
 pub fn select_item_with_actions<T: AsRef<Item>>(
     prompt: &str,
@@ -583,6 +814,7 @@ pub fn select_item_with_actions<T: AsRef<Item>>(
     config: &Config,
     available_labels: Vec<String>,
     available_categories: Vec<String>,
+    groups: Vec<Group>,
 ) -> anyhow::Result<Option<ItemAction>> {
     use crate::tui::run;
     let screen = ItemActionScreen::new(
@@ -591,6 +823,7 @@ pub fn select_item_with_actions<T: AsRef<Item>>(
         config,
         available_labels,
         available_categories,
+        groups,
     );
     run(screen)
 }
@@ -0,0 +1,230 @@
+//! Guided walkthrough screen.
+//!
+//! Shows one page per step with an explanation, the equivalent command, and
+//! the output of running it. Steps run one at a time as the user advances.
+
+use anyhow::Result;
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::tui::{event::TuiEvent, run, AppResult, TuiApp};
+
+/// A page of the walkthrough.
+#[derive(Debug, Clone, Default)]
+pub struct TourPage {
+    /// Short step title
+    pub title: String,
+    /// Explanation shown above the command
+    pub text: Vec<String>,
+    /// Equivalent command line, if the step demonstrates one
+    pub command: Option<String>,
+    /// Result of running the step
+    pub output: Vec<String>,
+}
+
+/// The steps of a walkthrough.
+pub trait Walkthrough {
+    /// Number of steps.
+    fn steps(&self) -> usize;
+
+    /// Runs step `index` and returns the page describing it.
+    fn step(&mut self, index: usize) -> Result<TourPage>;
+}
+
+/// Walkthrough screen state.
+struct TourScreen<W: Walkthrough> {
+    walkthrough: W,
+    index: usize,
+    page: TourPage,
+}
+
+impl<W: Walkthrough> TourScreen<W> {
+    fn new(mut walkthrough: W) -> Self {
+        let page = Self::run_step(&mut walkthrough, 0);
+        Self {
+            walkthrough,
+            index: 0,
+            page,
+        }
+    }
+
+    /// Runs a step, turning a failure into a page that shows the error.
+    fn run_step(walkthrough: &mut W, index: usize) -> TourPage {
+        walkthrough.step(index).unwrap_or_else(|err| TourPage {
+            title: "Something went wrong".to_string(),
+            output: vec![format!("error: {err:#}")],
+            ..TourPage::default()
+        })
+    }
+
+    fn is_last(&self) -> bool {
+        self.index + 1 >= self.walkthrough.steps()
+    }
+}
+
+impl<W: Walkthrough> TuiApp for TourScreen<W> {
+    type Output = ();
+
+    fn handle_event(&mut self, event: &TuiEvent) -> Option<AppResult<Self::Output>> {
+        let TuiEvent::Key(key) = event else {
+            return None;
+        };
+
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(AppResult::Cancelled)
+            }
+            KeyCode::Esc | KeyCode::Char('q') => Some(AppResult::Cancelled),
+            KeyCode::Enter | KeyCode::Right | KeyCode::Char(' ') => {
+                if self.is_last() {
+                    return Some(AppResult::Done(()));
+                }
+                self.index += 1;
+                self.page = Self::run_step(&mut self.walkthrough, self.index);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame) {
+        let chunks = Layout::vertical([
+            Constraint::Min(5),    // Page
+            Constraint::Length(3), // Help
+        ])
+        .split(frame.area());
+
+        let mut lines: Vec<Line> = self
+            .page
+            .text
+            .iter()
+            .map(|text| Line::raw(text.as_str()))
+            .collect();
+
+        if let Some(ref command) = self.page.command {
+            lines.push(Line::raw(""));
+            lines.push(Line::from(vec![
+                Span::styled("$ ", Style::default().fg(Color::DarkGray)),
+                Span::styled(command.as_str(), Style::default().fg(Color::Green)),
+            ]));
+        }
+
+        if !self.page.output.is_empty() {
+            lines.push(Line::raw(""));
+            lines.extend(self.page.output.iter().map(|output| {
+                let style = if output.starts_with("error:") {
+                    Style::default().fg(Color::Red)
+                } else {
+                    Style::default().fg(Color::Gray)
+                };
+                Line::styled(format!("  {output}"), style)
+            }));
+        }
+
+        let title = format!(
+            " {}/{} {} ",
+            self.index + 1,
+            self.walkthrough.steps(),
+            self.page.title
+        );
+        let page = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .title(Span::styled(
+                    title,
+                    Style::default().add_modifier(Modifier::BOLD),
+                )),
+        );
+        frame.render_widget(page, chunks[0]);
+
+        let next = if self.is_last() {
+            " Finish  "
+        } else {
+            " Next  "
+        };
+        let help = Paragraph::new(Line::from(vec![
+            Span::styled("Enter", Style::default().fg(Color::Cyan)),
+            Span::raw(next),
+            Span::styled("Esc", Style::default().fg(Color::Cyan)),
+            Span::raw(" Quit"),
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        );
+        frame.render_widget(help, chunks[1]);
+    }
+}
+
+/// Runs a walkthrough.
+///
+/// Returns `Ok(true)` if the user finished it, `Ok(false)` if they quit early.
+pub fn run_tour<W: Walkthrough>(walkthrough: W) -> Result<bool> {
+    Ok(run(TourScreen::new(walkthrough))?.is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyEvent;
+
+    struct Counter {
+        runs: Vec<usize>,
+    }
+
+    impl Walkthrough for Counter {
+        fn steps(&self) -> usize {
+            3
+        }
+
+        fn step(&mut self, index: usize) -> Result<TourPage> {
+            self.runs.push(index);
+            if index == 1 {
+                anyhow::bail!("step failed");
+            }
+            Ok(TourPage {
+                title: format!("Step {index}"),
+                ..TourPage::default()
+            })
+        }
+    }
+
+    fn key_event(code: KeyCode) -> TuiEvent {
+        TuiEvent::Key(KeyEvent::new(code, KeyModifiers::empty()))
+    }
+
+    #[test]
+    fn test_steps_run_as_user_advances() {
+        let mut screen = TourScreen::new(Counter { runs: Vec::new() });
+        assert_eq!(screen.walkthrough.runs, vec![0]);
+
+        assert_eq!(screen.handle_event(&key_event(KeyCode::Enter)), None);
+        assert_eq!(screen.walkthrough.runs, vec![0, 1]);
+        assert_eq!(screen.page.output, vec!["error: step failed".to_string()]);
+
+        assert_eq!(screen.handle_event(&key_event(KeyCode::Right)), None);
+        assert_eq!(screen.page.title, "Step 2");
+
+        assert_eq!(
+            screen.handle_event(&key_event(KeyCode::Enter)),
+            Some(AppResult::Done(()))
+        );
+    }
+
+    #[test]
+    fn test_escape_quits() {
+        let mut screen = TourScreen::new(Counter { runs: Vec::new() });
+        assert_eq!(
+            screen.handle_event(&key_event(KeyCode::Esc)),
+            Some(AppResult::Cancelled)
+        );
+    }
+}
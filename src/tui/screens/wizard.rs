@@ -1,7 +1,9 @@
 //! New item wizard screen.
 //!
-//! Two-panel wizard for creating new items with Tab navigation.
+//! Three-panel wizard for creating new items with Tab navigation: metadata
+//! (including due date, priority, and assignee), attachments, and the body.
 
+use chrono::{Local, NaiveDate};
 use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
     layout::{Constraint, Layout, Rect},
@@ -11,17 +13,25 @@ use ratatui::{
     Frame,
 };
 
-use crate::tui::{
-    event::TuiEvent,
-    widgets::{MultiSelect, SelectList, TextInput},
-    AppResult, TuiApp,
+use crate::{
+    constants::PRIORITY_ORDER,
+    item::dates,
+    tui::{
+        event::TuiEvent,
+        widgets::{MultiSelect, SelectList, TextArea, TextInput},
+        AppResult, TuiApp,
+    },
 };
 
+/// Label of the due date input; the parsed date is shown once it is valid.
+const DUE_LABEL: &str = "Due (e.g. 2026-03-20, fri, next week, 3d)";
+
 /// Wizard panels for breadcrumb display.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WizardPanel {
     Meta,
     Attachments,
+    Body,
 }
 
 impl WizardPanel {
@@ -29,6 +39,7 @@ impl WizardPanel {
         match self {
             Self::Meta => "Meta",
             Self::Attachments => "Attachments",
+            Self::Body => "Body",
         }
     }
 }
@@ -37,37 +48,55 @@ impl WizardPanel {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FocusedWidget {
     Title,
+    Due,
     Category,
     Labels,
+    Priority,
+    Assignee,
     Attachments,
+    Body,
 }
 
 impl FocusedWidget {
     /// Cycle to next widget (Tab).
     const fn next(self) -> Self {
         match self {
-            Self::Title => Self::Category,
+            Self::Title => Self::Due,
+            Self::Due => Self::Category,
             Self::Category => Self::Labels,
-            Self::Labels => Self::Attachments,
-            Self::Attachments => Self::Title,
+            Self::Labels => Self::Priority,
+            Self::Priority => Self::Assignee,
+            Self::Assignee => Self::Attachments,
+            Self::Attachments => Self::Body,
+            Self::Body => Self::Title,
         }
     }
 
     /// Cycle to previous widget (Shift+Tab).
     const fn prev(self) -> Self {
         match self {
-            Self::Title => Self::Attachments,
-            Self::Category => Self::Title,
+            Self::Title => Self::Body,
+            Self::Due => Self::Title,
+            Self::Category => Self::Due,
             Self::Labels => Self::Category,
-            Self::Attachments => Self::Labels,
+            Self::Priority => Self::Labels,
+            Self::Assignee => Self::Priority,
+            Self::Attachments => Self::Assignee,
+            Self::Body => Self::Attachments,
         }
     }
 
     /// Get the panel this widget belongs to.
     const fn panel(self) -> WizardPanel {
         match self {
-            Self::Title | Self::Category | Self::Labels => WizardPanel::Meta,
+            Self::Title
+            | Self::Due
+            | Self::Category
+            | Self::Labels
+            | Self::Priority
+            | Self::Assignee => WizardPanel::Meta,
             Self::Attachments => WizardPanel::Attachments,
+            Self::Body => WizardPanel::Body,
         }
     }
 }
@@ -79,11 +108,17 @@ pub struct WizardOutput {
     pub attachments: Vec<String>,
     pub category: Option<String>,
     pub labels: Vec<String>,
+    pub due: Option<NaiveDate>,
+    pub priority: Option<String>,
+    pub assignee: Option<String>,
+    /// Body composed in the wizard
+    pub body: String,
     /// If true, skip opening the editor after creating the item.
     pub skip_editor: bool,
 }
 
 /// New item wizard application.
+#[allow(clippy::struct_excessive_bools)]
 pub struct NewItemWizard {
     focused: FocusedWidget,
     title_input: TextInput,
@@ -97,6 +132,18 @@ pub struct NewItemWizard {
     labels_list: MultiSelect,
     label_input: TextInput,
     label_input_mode: bool,
+    due_input: TextInput,
+    /// Date relative due dates (`fri`, `3d`) are resolved against
+    today: NaiveDate,
+    priority: Option<String>,
+    priorities: Vec<String>,
+    priority_list: SelectList,
+    assignee: Option<String>,
+    people: Vec<String>,
+    assignee_list: SelectList,
+    assignee_input: TextInput,
+    assignee_input_mode: bool,
+    body_input: TextArea,
     /// Whether this wizard is editing an existing item (changes header).
     is_editing: bool,
     /// The ID of the item being edited (shown in header when editing).
@@ -115,6 +162,8 @@ impl NewItemWizard {
         let mut label_items = existing_labels;
         label_items.push("Create new...".to_string());
 
+        let priorities: Vec<String> = PRIORITY_ORDER.iter().map(ToString::to_string).collect();
+
         Self {
             focused: FocusedWidget::Title,
             title_input: TextInput::new("Title"),
@@ -130,6 +179,17 @@ impl NewItemWizard {
                 .with_action_item_last(),
             label_input: TextInput::new("New label"),
             label_input_mode: false,
+            due_input: TextInput::new(DUE_LABEL),
+            today: Local::now().date_naive(),
+            priority: None,
+            priority_list: option_list("Priority", &priorities, None, None),
+            priorities,
+            assignee: None,
+            people: Vec::new(),
+            assignee_list: option_list("Assignee", &[], None, Some("Other...")),
+            assignee_input: TextInput::new("Assignee name"),
+            assignee_input_mode: false,
+            body_input: TextArea::new("Body"),
             is_editing: false,
             item_id: None,
         }
@@ -178,10 +238,73 @@ impl NewItemWizard {
         self
     }
 
-    /// Pre-select labels.
+    /// Pre-select labels, adding the ones not in the list yet.
     #[must_use]
     pub fn with_labels(mut self, labels: &[String]) -> Self {
         self.labels_list = self.labels_list.with_selected(labels);
+        for label in labels {
+            self.labels_list.add_item(label);
+        }
+        self
+    }
+
+    /// Pre-populate the due date (any format accepted by the due date input).
+    #[must_use]
+    pub fn with_due(mut self, due: Option<&str>) -> Self {
+        self.due_input = TextInput::new(DUE_LABEL).with_initial(due.unwrap_or_default());
+        self
+    }
+
+    /// Pre-select a priority, adding it to the list if it is not a
+    /// well-known one.
+    #[must_use]
+    pub fn with_priority(mut self, priority: Option<String>) -> Self {
+        if let Some(value) = &priority {
+            if !self.priorities.contains(value) {
+                self.priorities.push(value.clone());
+            }
+        }
+        self.priority = priority;
+        self.priority_list =
+            option_list("Priority", &self.priorities, self.priority.as_deref(), None);
+        self
+    }
+
+    /// Set the people offered by the assignee picker (e.g. known authors).
+    #[must_use]
+    pub fn with_people(mut self, people: Vec<String>) -> Self {
+        self.people = people;
+        self.rebuild_assignee_list();
+        self
+    }
+
+    /// Pre-select an assignee, adding it to the people if needed.
+    #[must_use]
+    pub fn with_assignee(mut self, assignee: Option<String>) -> Self {
+        self.assignee = assignee;
+        self.rebuild_assignee_list();
+        self
+    }
+
+    /// Rebuilds the assignee list so it contains and selects the assignee.
+    fn rebuild_assignee_list(&mut self) {
+        if let Some(assignee) = &self.assignee {
+            if !self.people.contains(assignee) {
+                self.people.push(assignee.clone());
+            }
+        }
+        self.assignee_list = option_list(
+            "Assignee",
+            &self.people,
+            self.assignee.as_deref(),
+            Some("Other..."),
+        );
+    }
+
+    /// Pre-populate the body.
+    #[must_use]
+    pub fn with_body(mut self, body: &str) -> Self {
+        self.body_input = self.body_input.with_initial(body);
         self
     }
 
@@ -199,17 +322,29 @@ impl NewItemWizard {
         self
     }
 
-    /// Check if saving is allowed (title must not be empty).
+    /// Check if saving is allowed (title must not be empty and the due date,
+    /// if any, must be valid).
     fn can_save(&self) -> bool {
-        !self.title_input.content().trim().is_empty()
+        !self.title_input.content().trim().is_empty() && self.due_date().is_ok()
+    }
+
+    /// Parses the due date input; `Ok(None)` when it is empty.
+    fn due_date(&self) -> anyhow::Result<Option<NaiveDate>> {
+        let due = self.due_input.content().trim();
+        if due.is_empty() {
+            return Ok(None);
+        }
+        dates::parse_due(due, self.today).map(Some)
     }
 
-    /// Check if we're in any input mode (category or label creation).
+    /// Check if we're in any input mode (category, label, or assignee creation).
     const fn is_input_mode(&self) -> bool {
-        self.category_input_mode || self.label_input_mode
+        self.category_input_mode || self.label_input_mode || self.assignee_input_mode
     }
 
-    fn complete(&self, skip_editor: bool) -> WizardOutput {
+    /// Builds the output; the editor is skipped when asked for or when the
+    /// body was written in the wizard (`open_editor` forces it).
+    fn finish(&self, skip_editor: bool, open_editor: bool) -> WizardOutput {
         // Collect selected labels (action item is automatically excluded by MultiSelect)
         let labels: Vec<String> = self
             .labels_list
@@ -223,7 +358,173 @@ impl NewItemWizard {
             attachments: self.attachments.clone(),
             category: self.category.clone(),
             labels,
-            skip_editor,
+            due: self.due_date().ok().flatten(),
+            priority: self.priority.clone(),
+            assignee: self.assignee.clone(),
+            body: self.body_input.content(),
+            skip_editor: !open_editor && (skip_editor || !self.body_input.is_empty()),
+        }
+    }
+
+    fn complete(&self, skip_editor: bool) -> WizardOutput {
+        self.finish(skip_editor, false)
+    }
+
+    /// Handles the keys shared by the new meta widgets: focus navigation,
+    /// saving, and cancelling. Returns `None` for keys left to the widget.
+    #[allow(clippy::option_option)]
+    fn handle_common_key(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> Option<Option<AppResult<WizardOutput>>> {
+        match key.code {
+            KeyCode::Tab => {
+                self.focused = self.focused.next();
+                Some(None)
+            }
+            KeyCode::BackTab => {
+                self.focused = self.focused.prev();
+                Some(None)
+            }
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                // Ctrl+Alt+S saves without opening the editor, Ctrl+S opens it
+                // if the body is empty
+                let skip_editor = key.modifiers.contains(KeyModifiers::ALT);
+                Some(
+                    self.can_save()
+                        .then(|| AppResult::Done(self.complete(skip_editor))),
+                )
+            }
+            KeyCode::Esc => Some(Some(AppResult::Cancelled)),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(Some(AppResult::Cancelled))
+            }
+            _ => None,
+        }
+    }
+
+    fn handle_due_key(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> Option<AppResult<WizardOutput>> {
+        if let Some(result) = self.handle_common_key(key) {
+            return result;
+        }
+        self.due_input.handle_key(key);
+        None
+    }
+
+    fn handle_priority_key(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> Option<AppResult<WizardOutput>> {
+        if let Some(result) = self.handle_common_key(key) {
+            return result;
+        }
+        if key.code == KeyCode::Enter {
+            // Index 0 = "(none)", 1..n = priorities
+            if let Some(idx) = self.priority_list.selected_index() {
+                self.priority = idx
+                    .checked_sub(1)
+                    .and_then(|i| self.priorities.get(i).cloned());
+            }
+        } else {
+            self.priority_list.handle_key(key);
+        }
+        None
+    }
+
+    fn handle_assignee_key(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> Option<AppResult<WizardOutput>> {
+        if self.assignee_input_mode {
+            match key.code {
+                KeyCode::Enter => {
+                    let content = self.assignee_input.content().trim().to_string();
+                    if !content.is_empty() {
+                        self.assignee = Some(content);
+                        self.rebuild_assignee_list();
+                    }
+                    self.assignee_input_mode = false;
+                    self.assignee_input = TextInput::new("Assignee name");
+                }
+                KeyCode::Esc => {
+                    self.assignee_input_mode = false;
+                    self.assignee_input = TextInput::new("Assignee name");
+                }
+                _ => {
+                    self.assignee_input.handle_key(key);
+                }
+            }
+            return None;
+        }
+        if let Some(result) = self.handle_common_key(key) {
+            return result;
+        }
+        if key.code == KeyCode::Enter {
+            // Index 0 = "(none)", 1..n = people, last = "Other..."
+            if let Some(idx) = self.assignee_list.selected_index() {
+                if idx == self.people.len() + 1 {
+                    self.assignee_input_mode = true;
+                } else {
+                    self.assignee = idx.checked_sub(1).and_then(|i| self.people.get(i).cloned());
+                }
+            }
+        } else {
+            self.assignee_list.handle_key(key);
+        }
+        None
+    }
+
+    fn handle_body_key(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> Option<AppResult<WizardOutput>> {
+        match key.code {
+            KeyCode::Tab => {
+                self.focused = self.focused.next();
+                None
+            }
+            KeyCode::BackTab => {
+                self.focused = self.focused.prev();
+                None
+            }
+            KeyCode::Char('s')
+                if key.modifiers.contains(KeyModifiers::CONTROL)
+                    && key.modifiers.contains(KeyModifiers::ALT) =>
+            {
+                // Ctrl+Alt+S: save without opening editor
+                if self.can_save() {
+                    Some(AppResult::Done(self.complete(true)))
+                } else {
+                    None
+                }
+            }
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                // Ctrl+S: save (opens the editor only if the body is empty)
+                if self.can_save() {
+                    Some(AppResult::Done(self.complete(false)))
+                } else {
+                    None
+                }
+            }
+            KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                // Ctrl+E: save and continue the body in the external editor
+                if self.can_save() {
+                    Some(AppResult::Done(self.finish(false, true)))
+                } else {
+                    None
+                }
+            }
+            KeyCode::Esc => Some(AppResult::Cancelled),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(AppResult::Cancelled)
+            }
+            _ => {
+                self.body_input.handle_key(key);
+                None
+            }
         }
     }
 
@@ -252,7 +553,7 @@ impl NewItemWizard {
                 }
             }
             KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                // Ctrl+S: save and open editor
+                // Ctrl+S: save (opens the editor if the body is empty)
                 if self.can_save() {
                     Some(AppResult::Done(self.complete(false)))
                 } else {
@@ -295,7 +596,7 @@ impl NewItemWizard {
                 }
             }
             KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                // Ctrl+S: save and open editor
+                // Ctrl+S: save (opens the editor if the body is empty)
                 if self.can_save() {
                     Some(AppResult::Done(self.complete(false)))
                 } else {
@@ -374,7 +675,7 @@ impl NewItemWizard {
                     }
                 }
                 KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    // Ctrl+S: save and open editor
+                    // Ctrl+S: save (opens the editor if the body is empty)
                     if self.can_save() {
                         Some(AppResult::Done(self.complete(false)))
                     } else {
@@ -472,7 +773,7 @@ impl NewItemWizard {
                     }
                 }
                 KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    // Ctrl+S: save and open editor
+                    // Ctrl+S: save (opens the editor if the body is empty)
                     if self.can_save() {
                         Some(AppResult::Done(self.complete(false)))
                     } else {
@@ -523,6 +824,15 @@ impl TuiApp for NewItemWizard {
                     FocusedWidget::Labels if self.label_input_mode => {
                         self.label_input.insert_text(content);
                     }
+                    FocusedWidget::Due => {
+                        self.due_input.insert_text(content);
+                    }
+                    FocusedWidget::Assignee if self.assignee_input_mode => {
+                        self.assignee_input.insert_text(content);
+                    }
+                    FocusedWidget::Body => {
+                        self.body_input.insert_text(content);
+                    }
                     _ => {}
                 }
                 None
@@ -532,6 +842,10 @@ impl TuiApp for NewItemWizard {
                 FocusedWidget::Attachments => self.handle_attachments_key(*key),
                 FocusedWidget::Category => self.handle_category_key(*key),
                 FocusedWidget::Labels => self.handle_labels_key(*key),
+                FocusedWidget::Due => self.handle_due_key(*key),
+                FocusedWidget::Priority => self.handle_priority_key(*key),
+                FocusedWidget::Assignee => self.handle_assignee_key(*key),
+                FocusedWidget::Body => self.handle_body_key(*key),
             },
             _ => None,
         }
@@ -555,6 +869,9 @@ impl TuiApp for NewItemWizard {
         match self.focused.panel() {
             WizardPanel::Meta => self.render_meta_panel(frame, chunks[1]),
             WizardPanel::Attachments => self.render_attachments_panel(frame, chunks[1]),
+            WizardPanel::Body => {
+                self.body_input.render(chunks[1], frame.buffer_mut(), true);
+            }
         }
 
         // Help bar
@@ -566,8 +883,12 @@ impl NewItemWizard {
     fn render_header(&self, frame: &mut Frame, area: Rect) {
         let current_panel = self.focused.panel();
 
-        // Panel indicators: Meta > Attachments
-        let panels = [WizardPanel::Meta, WizardPanel::Attachments];
+        // Panel indicators: Meta > Attachments > Body
+        let panels = [
+            WizardPanel::Meta,
+            WizardPanel::Attachments,
+            WizardPanel::Body,
+        ];
         let indicators: Vec<Span> = panels
             .iter()
             .enumerate()
@@ -604,10 +925,14 @@ impl NewItemWizard {
     fn render_meta_panel(&self, frame: &mut Frame, area: Rect) {
         // Meta panel layout:
         // - Title input (full width, 3 rows)
+        // - Due date input (full width, 3 rows)
         // - Category (left 50%) | Labels (right 50%)
+        // - Priority (left 50%) | Assignee (right 50%)
         let chunks = Layout::vertical([
-            Constraint::Length(3), // Title input
-            Constraint::Min(4),    // Category/Labels split
+            Constraint::Length(3),      // Title input
+            Constraint::Length(3),      // Due date input
+            Constraint::Percentage(60), // Category/Labels split
+            Constraint::Min(4),         // Priority/Assignee split
         ])
         .split(area);
 
@@ -615,10 +940,14 @@ impl NewItemWizard {
         let title_focused = self.focused == FocusedWidget::Title;
         self.render_title_widget(frame, chunks[0], title_focused);
 
+        // Due date input
+        let due_focused = self.focused == FocusedWidget::Due;
+        self.render_due_widget(frame, chunks[1], due_focused);
+
         // Category/Labels horizontal split (50/50)
         let split_chunks =
             Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
-                .split(chunks[1]);
+                .split(chunks[2]);
 
         // Category (left)
         let category_focused = self.focused == FocusedWidget::Category;
@@ -627,6 +956,58 @@ impl NewItemWizard {
         // Labels (right)
         let labels_focused = self.focused == FocusedWidget::Labels;
         self.render_labels_widget(frame, split_chunks[1], labels_focused);
+
+        // Priority/Assignee horizontal split (50/50)
+        let split_chunks =
+            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(chunks[3]);
+
+        // Priority (left)
+        let current = self.priority.as_deref().unwrap_or("(none)");
+        let mut list = self
+            .priority_list
+            .clone()
+            .with_title(format!("Priority: {current}"));
+        list.render(
+            split_chunks[0],
+            frame.buffer_mut(),
+            self.focused == FocusedWidget::Priority,
+        );
+
+        // Assignee (right)
+        let assignee_focused = self.focused == FocusedWidget::Assignee;
+        self.render_assignee_widget(frame, split_chunks[1], assignee_focused);
+    }
+
+    fn render_due_widget(&self, frame: &mut Frame, area: Rect, focused: bool) {
+        // Show the resolved date, or warn if the input is not a date
+        let input = match self.due_date() {
+            Ok(None) => self.due_input.clone(),
+            Ok(Some(date)) => self
+                .due_input
+                .clone()
+                .with_label(format!("Due: {}", date.format("%a %Y-%m-%d"))),
+            Err(_) => self.due_input.clone().with_warning("not a date"),
+        };
+        input.render(area, frame.buffer_mut(), focused);
+    }
+
+    fn render_assignee_widget(&self, frame: &mut Frame, area: Rect, focused: bool) {
+        let current = self.assignee.as_deref().unwrap_or("(none)");
+        let mut list = self
+            .assignee_list
+            .clone()
+            .with_title(format!("Assignee: {current}"));
+
+        if self.assignee_input_mode {
+            // Show input overlay for entering another name
+            let chunks = Layout::vertical([Constraint::Min(3), Constraint::Length(3)]).split(area);
+            list.render(chunks[0], frame.buffer_mut(), false);
+            self.assignee_input
+                .render(chunks[1], frame.buffer_mut(), true);
+        } else {
+            list.render(area, frame.buffer_mut(), focused);
+        }
     }
 
     fn render_title_widget(&self, frame: &mut Frame, area: Rect, focused: bool) {
@@ -748,11 +1129,13 @@ impl NewItemWizard {
             ]
         } else {
             match self.focused {
-                FocusedWidget::Title => vec![],
-                FocusedWidget::Category => vec![
-                    Span::styled("Enter", key_on),
-                    Span::styled(" Select", txt_on),
-                ],
+                FocusedWidget::Title | FocusedWidget::Due => vec![],
+                FocusedWidget::Category | FocusedWidget::Priority | FocusedWidget::Assignee => {
+                    vec![
+                        Span::styled("Enter", key_on),
+                        Span::styled(" Select", txt_on),
+                    ]
+                }
                 FocusedWidget::Labels => vec![
                     Span::styled("Enter", key_on),
                     Span::styled(" Toggle", txt_on),
@@ -763,6 +1146,12 @@ impl NewItemWizard {
                     Span::styled("Backspace", key_on),
                     Span::styled(" Remove", txt_on),
                 ],
+                FocusedWidget::Body => vec![
+                    Span::styled("Enter", key_on),
+                    Span::styled(" Newline  ", txt_on),
+                    Span::styled("Ctrl+E", if can_save { key_on } else { key_off }),
+                    Span::styled(" Editor", if can_save { txt_on } else { txt_off }),
+                ],
             }
         };
 
@@ -819,6 +1208,24 @@ impl NewItemWizard {
     }
 }
 
+/// Builds a select list of "(none)", the values, and an optional trailing
+/// action item, with `selected` (or "(none)") selected.
+fn option_list(
+    title: &str,
+    values: &[String],
+    selected: Option<&str>,
+    action: Option<&str>,
+) -> SelectList {
+    let mut items = vec!["(none)".to_string()];
+    items.extend(values.iter().cloned());
+    items.extend(action.map(String::from));
+    let mut list = SelectList::new(items).with_title(title);
+    if let Some(pos) = selected.and_then(|s| values.iter().position(|v| v == s)) {
+        list.select(pos + 1);
+    }
+    list
+}
+
 /// Parse a string containing shell-escaped paths separated by unescaped spaces or newlines.
 ///
 /// Paths can contain escaped spaces (e.g., `/path/to\ file.png`) and multiple
@@ -943,15 +1350,105 @@ mod tests {
 
     #[test]
     fn test_focused_widget_navigation() {
-        assert_eq!(FocusedWidget::Title.next(), FocusedWidget::Category);
+        assert_eq!(FocusedWidget::Title.next(), FocusedWidget::Due);
+        assert_eq!(FocusedWidget::Due.next(), FocusedWidget::Category);
         assert_eq!(FocusedWidget::Category.next(), FocusedWidget::Labels);
-        assert_eq!(FocusedWidget::Labels.next(), FocusedWidget::Attachments);
-        assert_eq!(FocusedWidget::Attachments.next(), FocusedWidget::Title);
-
-        assert_eq!(FocusedWidget::Title.prev(), FocusedWidget::Attachments);
-        assert_eq!(FocusedWidget::Category.prev(), FocusedWidget::Title);
+        assert_eq!(FocusedWidget::Labels.next(), FocusedWidget::Priority);
+        assert_eq!(FocusedWidget::Priority.next(), FocusedWidget::Assignee);
+        assert_eq!(FocusedWidget::Assignee.next(), FocusedWidget::Attachments);
+        assert_eq!(FocusedWidget::Attachments.next(), FocusedWidget::Body);
+        assert_eq!(FocusedWidget::Body.next(), FocusedWidget::Title);
+
+        assert_eq!(FocusedWidget::Title.prev(), FocusedWidget::Body);
+        assert_eq!(FocusedWidget::Due.prev(), FocusedWidget::Title);
+        assert_eq!(FocusedWidget::Category.prev(), FocusedWidget::Due);
         assert_eq!(FocusedWidget::Labels.prev(), FocusedWidget::Category);
-        assert_eq!(FocusedWidget::Attachments.prev(), FocusedWidget::Labels);
+        assert_eq!(FocusedWidget::Priority.prev(), FocusedWidget::Labels);
+        assert_eq!(FocusedWidget::Assignee.prev(), FocusedWidget::Priority);
+        assert_eq!(FocusedWidget::Attachments.prev(), FocusedWidget::Assignee);
+        assert_eq!(FocusedWidget::Body.prev(), FocusedWidget::Attachments);
+    }
+
+    #[test]
+    fn test_prefilled_wizard_keeps_new_values() {
+        let wizard = NewItemWizard::new(vec!["bugs".to_string()], vec!["ui".to_string()])
+            .with_title("Crash on start")
+            .with_category(Some("backend".to_string()))
+            .with_labels(&["ui".to_string(), "urgent".to_string()])
+            .with_attachments(vec!["log.txt".to_string()]);
+
+        let output = wizard.complete(false);
+        assert_eq!(output.title, "Crash on start");
+        assert_eq!(output.category.as_deref(), Some("backend"));
+        assert_eq!(output.labels, ["ui", "urgent"]);
+        assert_eq!(output.attachments, ["log.txt"]);
+        assert!(!output.skip_editor);
+    }
+
+    #[test]
+    fn test_body_written_in_wizard_skips_editor() {
+        let ctrl = |c| crossterm::event::KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+        let mut wizard = NewItemWizard::new(vec![], vec![])
+            .with_title("Note")
+            .with_body("First line\nSecond line");
+        wizard.focused = FocusedWidget::Body;
+
+        let Some(AppResult::Done(output)) = wizard.handle_body_key(ctrl('s')) else {
+            panic!("expected the wizard to finish");
+        };
+        assert_eq!(output.body, "First line\nSecond line");
+        assert!(output.skip_editor);
+
+        // Ctrl+E continues in the external editor
+        let Some(AppResult::Done(output)) = wizard.handle_body_key(ctrl('e')) else {
+            panic!("expected the wizard to finish");
+        };
+        assert!(!output.skip_editor);
+    }
+
+    #[test]
+    fn test_due_priority_and_assignee() {
+        let key = |code| crossterm::event::KeyEvent::new(code, KeyModifiers::NONE);
+        let mut wizard = NewItemWizard::new(vec![], vec![])
+            .with_title("Release")
+            .with_people(vec!["Alice".to_string(), "Bob".to_string()])
+            .with_assignee(Some("Carol".to_string()))
+            .with_priority(Some("blocker".to_string()))
+            .with_due(Some("fri"));
+        // 2026-03-15 is a Sunday
+        wizard.today = NaiveDate::from_ymd_opt(2026, 3, 15).unwrap();
+
+        let output = wizard.complete(false);
+        assert_eq!(output.due, NaiveDate::from_ymd_opt(2026, 3, 20));
+        assert_eq!(output.priority.as_deref(), Some("blocker"));
+        assert_eq!(output.assignee.as_deref(), Some("Carol"));
+
+        // Pick "high" (after "critical" and "urgent") and Bob
+        wizard.priority_list.select(0);
+        for _ in 0..3 {
+            wizard.handle_priority_key(key(KeyCode::Down));
+        }
+        wizard.handle_priority_key(key(KeyCode::Enter));
+        wizard.assignee_list.select(2);
+        wizard.handle_assignee_key(key(KeyCode::Enter));
+        let output = wizard.complete(false);
+        assert_eq!(output.priority.as_deref(), Some("high"));
+        assert_eq!(output.assignee.as_deref(), Some("Bob"));
+
+        // "Other..." asks for a name
+        wizard.assignee_list.select(wizard.people.len() + 1);
+        wizard.handle_assignee_key(key(KeyCode::Enter));
+        assert!(wizard.assignee_input_mode);
+        wizard.assignee_input.insert_text("Dave");
+        wizard.handle_assignee_key(key(KeyCode::Enter));
+        assert_eq!(wizard.complete(false).assignee.as_deref(), Some("Dave"));
+
+        // An invalid due date blocks saving
+        wizard = wizard.with_due(Some("someday"));
+        assert!(!wizard.can_save());
+        wizard = wizard.with_due(None);
+        assert!(wizard.can_save());
+        assert_eq!(wizard.complete(false).due, None);
     }
 
     #[test]
@@ -959,6 +1456,10 @@ mod tests {
         assert_eq!(FocusedWidget::Title.panel(), WizardPanel::Meta);
         assert_eq!(FocusedWidget::Category.panel(), WizardPanel::Meta);
         assert_eq!(FocusedWidget::Labels.panel(), WizardPanel::Meta);
+        assert_eq!(FocusedWidget::Due.panel(), WizardPanel::Meta);
+        assert_eq!(FocusedWidget::Priority.panel(), WizardPanel::Meta);
+        assert_eq!(FocusedWidget::Assignee.panel(), WizardPanel::Meta);
         assert_eq!(FocusedWidget::Attachments.panel(), WizardPanel::Attachments);
+        assert_eq!(FocusedWidget::Body.panel(), WizardPanel::Body);
     }
 }
@@ -2,10 +2,20 @@
 //!
 //! Ensures the terminal is always restored to its original state,
 //! even on panic.
+//!
+//! The TUI normally draws on stdout. With [`draw_on_tty`] it draws on the
+//! controlling terminal instead, so a script can capture stdout while the
+//! user picks an item (`ITEM=$(qs list --print-selection)`). Keyboard input
+//! and the terminal size already come from the terminal when stdin or stdout
+//! are redirected.
 
-use std::io::{self, Stdout};
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, IsTerminal, Stdout, Write},
+    sync::atomic::{AtomicBool, Ordering},
+};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use crossterm::{
     event::{DisableBracketedPaste, EnableBracketedPaste},
     execute,
@@ -13,29 +23,91 @@ use crossterm::{
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
 
+/// Path of the controlling terminal
+#[cfg(not(windows))]
+const TTY_PATH: &str = "/dev/tty";
+#[cfg(windows)]
+const TTY_PATH: &str = "CONOUT$";
+
+/// Whether TUIs draw on the controlling terminal rather than stdout
+static DRAW_ON_TTY: AtomicBool = AtomicBool::new(false);
+
+/// Makes TUIs draw on the controlling terminal instead of stdout.
+///
+/// Fails if there is no terminal to draw on.
+pub fn draw_on_tty() -> Result<()> {
+    open_tty().with_context(|| {
+        format!("No terminal to show the selector on ({TTY_PATH} is not available)")
+    })?;
+    DRAW_ON_TTY.store(true, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Returns true if a TUI can be shown: stdout is a terminal, or TUIs draw on
+/// the controlling terminal.
+pub fn is_available() -> bool {
+    DRAW_ON_TTY.load(Ordering::Relaxed) || io::stdout().is_terminal()
+}
+
+fn open_tty() -> io::Result<File> {
+    OpenOptions::new().read(true).write(true).open(TTY_PATH)
+}
+
+/// Where the TUI is drawn
+pub enum Output {
+    Stdout(Stdout),
+    Tty(File),
+}
+
+impl Output {
+    fn open() -> io::Result<Self> {
+        if DRAW_ON_TTY.load(Ordering::Relaxed) {
+            Ok(Self::Tty(open_tty()?))
+        } else {
+            Ok(Self::Stdout(io::stdout()))
+        }
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Stdout(out) => out.write(buf),
+            Self::Tty(out) => out.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Stdout(out) => out.flush(),
+            Self::Tty(out) => out.flush(),
+        }
+    }
+}
+
 /// RAII guard for terminal state.
 ///
 /// Enters raw mode and alternate screen on creation.
 /// Restores terminal on drop, even during panic.
 pub struct TerminalGuard {
-    terminal: Terminal<CrosstermBackend<Stdout>>,
+    terminal: Terminal<CrosstermBackend<Output>>,
 }
 
 impl TerminalGuard {
     /// Create a new terminal guard, entering raw mode and alternate screen.
     pub fn new() -> Result<Self> {
+        let mut output = Output::open()?;
         enable_raw_mode()?;
-        let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen, EnableBracketedPaste)?;
+        execute!(output, EnterAlternateScreen, EnableBracketedPaste)?;
 
-        let backend = CrosstermBackend::new(stdout);
+        let backend = CrosstermBackend::new(output);
         let terminal = Terminal::new(backend)?;
 
         Ok(Self { terminal })
     }
 
     /// Get mutable reference to the terminal.
-    pub fn terminal(&mut self) -> &mut Terminal<CrosstermBackend<Stdout>> {
+    pub fn terminal(&mut self) -> &mut Terminal<CrosstermBackend<Output>> {
         &mut self.terminal
     }
 }
@@ -44,6 +116,10 @@ impl Drop for TerminalGuard {
     fn drop(&mut self) {
         // Best effort cleanup - ignore errors during drop
         let _ = disable_raw_mode();
-        let _ = execute!(io::stdout(), DisableBracketedPaste, LeaveAlternateScreen);
+        let _ = execute!(
+            self.terminal.backend_mut(),
+            DisableBracketedPaste,
+            LeaveAlternateScreen
+        );
     }
 }
@@ -4,10 +4,12 @@ mod action_menu;
 mod filter_overlay;
 mod multi_select;
 mod select_list;
+mod text_area;
 mod text_input;
 
 pub use action_menu::{ActionMenu, ActionMenuResult, MenuItem};
 pub use filter_overlay::{FilterOverlay, FilterOverlayResult, FilterState};
 pub use multi_select::MultiSelect;
 pub use select_list::{SelectAction, SelectList};
+pub use text_area::TextArea;
 pub use text_input::TextInput;
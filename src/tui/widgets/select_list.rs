@@ -29,6 +29,8 @@ pub struct SelectList {
     title: String,
     /// Indices of items that are disabled (shown but not selectable)
     disabled: HashSet<usize>,
+    /// Indices of items shown in red to draw attention (e.g. overdue items)
+    highlighted: HashSet<usize>,
 }
 
 impl SelectList {
@@ -44,6 +46,7 @@ impl SelectList {
             state,
             title: String::new(),
             disabled: HashSet::new(),
+            highlighted: HashSet::new(),
         }
     }
 
@@ -67,6 +70,13 @@ impl SelectList {
         self
     }
 
+    /// Set which indices are shown in red (when not selected).
+    #[must_use]
+    pub fn with_highlighted(mut self, highlighted: HashSet<usize>) -> Self {
+        self.highlighted = highlighted;
+        self
+    }
+
     /// Select the first enabled item.
     fn select_first_enabled(&mut self) {
         for i in 0..self.items.len() {
@@ -89,6 +99,13 @@ impl SelectList {
         self.state.selected()
     }
 
+    /// Select the item at an index, if it exists and is enabled.
+    pub fn select(&mut self, index: usize) {
+        if index < self.items.len() && self.is_enabled(index) {
+            self.state.select(Some(index));
+        }
+    }
+
     /// Check if list is empty.
     pub fn is_empty(&self) -> bool {
         self.items.is_empty()
@@ -197,6 +214,8 @@ impl SelectList {
                     Style::default()
                         .fg(Color::Cyan)
                         .add_modifier(Modifier::BOLD)
+                } else if self.highlighted.contains(&i) {
+                    Style::default().fg(Color::Red)
                 } else {
                     Style::default()
                 };
@@ -236,6 +255,7 @@ impl Clone for SelectList {
         let mut new_list = Self::new(self.items.clone());
         new_list.title.clone_from(&self.title);
         new_list.disabled.clone_from(&self.disabled);
+        new_list.highlighted.clone_from(&self.highlighted);
         // Always preserve selection state, including None (all items disabled)
         new_list.state.select(self.state.selected());
         new_list
@@ -0,0 +1,288 @@
+//! Multi-line text area widget.
+//!
+//! Fully supports UTF-8 input including multi-byte characters.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget},
+};
+
+/// Multi-line text input with cursor.
+///
+/// The cursor column is tracked as a character index (not byte index)
+/// to properly handle UTF-8 multi-byte characters.
+#[derive(Debug, Clone)]
+pub struct TextArea {
+    lines: Vec<String>,
+    /// Cursor line
+    row: usize,
+    /// Cursor position in the line as character index
+    col: usize,
+    label: String,
+}
+
+impl TextArea {
+    /// Create an empty text area with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            lines: vec![String::new()],
+            row: 0,
+            col: 0,
+            label: label.into(),
+        }
+    }
+
+    /// Set initial content; the cursor is placed at the end.
+    #[must_use]
+    pub fn with_initial(mut self, value: &str) -> Self {
+        self.lines = vec![String::new()];
+        self.row = 0;
+        self.col = 0;
+        self.insert_text(value);
+        self
+    }
+
+    /// Get the current content, lines joined with newlines.
+    pub fn content(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Check if the text area is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lines.iter().all(String::is_empty)
+    }
+
+    /// Returns the character count of a line.
+    fn line_len(&self, row: usize) -> usize {
+        self.lines[row].chars().count()
+    }
+
+    /// Returns the byte index of the cursor in the current line.
+    fn cursor_byte_index(&self) -> usize {
+        let line = &self.lines[self.row];
+        line.char_indices()
+            .nth(self.col)
+            .map_or(line.len(), |(i, _)| i)
+    }
+
+    /// Insert text at the cursor, splitting lines at newlines.
+    ///
+    /// Used for paste operations; `\r\n` and `\r` count as newlines.
+    pub fn insert_text(&mut self, text: &str) {
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        for (i, part) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.insert_newline();
+            }
+            let byte_idx = self.cursor_byte_index();
+            self.lines[self.row].insert_str(byte_idx, part);
+            self.col += part.chars().count();
+        }
+    }
+
+    /// Splits the current line at the cursor.
+    fn insert_newline(&mut self) {
+        let byte_idx = self.cursor_byte_index();
+        let rest = self.lines[self.row].split_off(byte_idx);
+        self.row += 1;
+        self.col = 0;
+        self.lines.insert(self.row, rest);
+    }
+
+    /// Handle a key event.
+    ///
+    /// Returns `true` if the event was handled.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char(c) => {
+                // Let Ctrl combinations bubble up
+                if key.modifiers.contains(KeyModifiers::CONTROL) {
+                    return false;
+                }
+                let byte_idx = self.cursor_byte_index();
+                self.lines[self.row].insert(byte_idx, c);
+                self.col += 1;
+                true
+            }
+            KeyCode::Enter => {
+                self.insert_newline();
+                true
+            }
+            KeyCode::Backspace => {
+                if self.col > 0 {
+                    self.col -= 1;
+                    let byte_idx = self.cursor_byte_index();
+                    self.lines[self.row].remove(byte_idx);
+                } else if self.row > 0 {
+                    // Join with the previous line
+                    let line = self.lines.remove(self.row);
+                    self.row -= 1;
+                    self.col = self.line_len(self.row);
+                    self.lines[self.row].push_str(&line);
+                }
+                true
+            }
+            KeyCode::Delete => {
+                if self.col < self.line_len(self.row) {
+                    let byte_idx = self.cursor_byte_index();
+                    self.lines[self.row].remove(byte_idx);
+                } else if self.row + 1 < self.lines.len() {
+                    // Join with the next line
+                    let line = self.lines.remove(self.row + 1);
+                    self.lines[self.row].push_str(&line);
+                }
+                true
+            }
+            KeyCode::Left => {
+                if self.col > 0 {
+                    self.col -= 1;
+                } else if self.row > 0 {
+                    self.row -= 1;
+                    self.col = self.line_len(self.row);
+                }
+                true
+            }
+            KeyCode::Right => {
+                if self.col < self.line_len(self.row) {
+                    self.col += 1;
+                } else if self.row + 1 < self.lines.len() {
+                    self.row += 1;
+                    self.col = 0;
+                }
+                true
+            }
+            KeyCode::Up => {
+                if self.row > 0 {
+                    self.row -= 1;
+                    self.col = self.col.min(self.line_len(self.row));
+                }
+                true
+            }
+            KeyCode::Down => {
+                if self.row + 1 < self.lines.len() {
+                    self.row += 1;
+                    self.col = self.col.min(self.line_len(self.row));
+                }
+                true
+            }
+            KeyCode::Home => {
+                self.col = 0;
+                true
+            }
+            KeyCode::End => {
+                self.col = self.line_len(self.row);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Render the widget, scrolled so the cursor line is visible.
+    pub fn render(&self, area: Rect, buf: &mut Buffer, focused: bool) {
+        let border_style = if focused {
+            Style::default().fg(Color::Cyan)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(format!(" {} ", self.label));
+
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let height = usize::from(inner.height.max(1));
+        let offset = self.row.saturating_sub(height - 1);
+
+        let lines: Vec<Line> = self
+            .lines
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(height)
+            .map(|(row, line)| {
+                if !focused || row != self.row {
+                    return Line::from(line.as_str());
+                }
+                let byte_idx = self.cursor_byte_index();
+                let (before, after) = line.split_at(byte_idx);
+                let cursor_char = after.chars().next().unwrap_or(' ');
+                let after_cursor: String = after.chars().skip(1).collect();
+                Line::from(vec![
+                    Span::raw(before),
+                    Span::styled(
+                        cursor_char.to_string(),
+                        Style::default()
+                            .bg(Color::White)
+                            .fg(Color::Black)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(after_cursor),
+                ])
+            })
+            .collect();
+
+        Paragraph::new(lines).render(inner, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_typing_and_newlines() {
+        let mut area = TextArea::new("Body");
+        assert!(area.is_empty());
+        for c in "über".chars() {
+            area.handle_key(key(KeyCode::Char(c)));
+        }
+        area.handle_key(key(KeyCode::Enter));
+        area.handle_key(key(KeyCode::Char('x')));
+        assert_eq!(area.content(), "über\nx");
+        assert!(!area.is_empty());
+    }
+
+    #[test]
+    fn test_backspace_and_delete_join_lines() {
+        let mut area = TextArea::new("Body").with_initial("one\ntwo");
+        area.handle_key(key(KeyCode::Home));
+        area.handle_key(key(KeyCode::Backspace));
+        assert_eq!(area.content(), "onetwo");
+        assert_eq!((area.row, area.col), (0, 3));
+
+        area.handle_key(key(KeyCode::Enter));
+        area.handle_key(key(KeyCode::Up));
+        area.handle_key(key(KeyCode::End));
+        area.handle_key(key(KeyCode::Delete));
+        assert_eq!(area.content(), "onetwo");
+    }
+
+    #[test]
+    fn test_vertical_movement_clamps_column() {
+        let mut area = TextArea::new("Body").with_initial("a long line\nab");
+        area.handle_key(key(KeyCode::Up));
+        area.handle_key(key(KeyCode::End));
+        area.handle_key(key(KeyCode::Down));
+        assert_eq!((area.row, area.col), (1, 2));
+    }
+
+    #[test]
+    fn test_paste_multiline() {
+        let mut area = TextArea::new("Body").with_initial("ad");
+        area.handle_key(key(KeyCode::Left));
+        area.insert_text("b\r\nc");
+        assert_eq!(area.content(), "ab\ncd");
+        assert_eq!((area.row, area.col), (1, 1));
+    }
+}
@@ -18,10 +18,16 @@ use unicode_width::UnicodeWidthStr;
 use crate::{
     config::Config,
     constants::{
-        UI_COL_ID_WIDTH, UI_COL_STATUS_WIDTH, UI_LABELS_TRUNCATE_LEN, UI_TITLE_TRUNCATE_LEN,
+        ATTACHMENT_CONFIRM_THRESHOLD, UI_COL_ID_WIDTH, UI_COL_STATUS_WIDTH, UI_LABELS_TRUNCATE_LEN,
+        UI_TITLE_TRUNCATE_LEN,
     },
     editor,
-    item::{Item, Status},
+    item::{
+        card::{Card, Tone},
+        group::Group,
+        Item, Status,
+    },
+    picker::{self, Picker},
     storage::{self, AttachmentResult},
     tui::screens::{
         confirm as tui_confirm, select_from_list as tui_select,
@@ -78,6 +84,26 @@ where
 // Interactive Mode Resolution
 // =============================================================================
 
+/// Output format for machine-readable command output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Plain text
+    #[default]
+    Text,
+    /// JSON
+    Json,
+}
+
+/// What `--print-selection` prints for the chosen item
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Selection {
+    /// Item file path, relative to the working directory
+    #[default]
+    Path,
+    /// Item ID
+    Id,
+}
+
 /// Common interactive mode flags used across commands.
 ///
 /// Consolidates the `--interactive` / `--no-interactive` flag pattern.
@@ -118,7 +144,7 @@ impl InteractiveArgs {
     ///
     /// Use this for TUI/interactive features that require a terminal.
     pub fn should_run(&self, config: &Config) -> bool {
-        self.is_enabled(config) && std::io::stdout().is_terminal()
+        self.is_enabled(config) && crate::tui::terminal::is_available()
     }
 }
 
@@ -162,6 +188,8 @@ pub fn select_from_list_with_header<T: ToString>(
 ///
 /// Formats items as columns: ID | Status | Title | Labels | Category
 /// Works with both `&[Item]` and `&[&Item]` via `AsRef<Item>`.
+/// Uses the configured external picker instead of the built-in selector
+/// if one is set.
 /// Returns `Some(index)` if an item was selected, `None` if cancelled.
 pub fn select_item<T: AsRef<Item>>(
     prompt: &str,
@@ -187,6 +215,7 @@ pub fn select_item<T: AsRef<Item>>(
             let item = item.as_ref();
             let status = match item.status() {
                 Status::Open => "open",
+                Status::InProgress => "in-progress",
                 Status::Closed => "closed",
                 Status::Template => "template",
             };
@@ -211,13 +240,17 @@ pub fn select_item<T: AsRef<Item>>(
         })
         .collect();
 
-    select_from_list_with_header(prompt, &header, &options)
+    match config.picker() {
+        Picker::Builtin => select_from_list_with_header(prompt, &header, &options),
+        Picker::External(command) => picker::pick(&command, prompt, &header, &options),
+    }
 }
 
 /// Interactive item selection with action popup.
 ///
 /// Shows items in a list and when an item is selected, shows a popup menu
-/// with actions (View, Edit, Close/Reopen, Delete).
+/// with actions (View, Edit, Close/Reopen, Delete). Items are shown under
+/// collapsible headers when `groups` is not empty.
 /// Returns the selected action, or `Ok(None)` if cancelled.
 pub fn select_item_with_actions<T: AsRef<Item>>(
     prompt: &str,
@@ -225,6 +258,7 @@ pub fn select_item_with_actions<T: AsRef<Item>>(
     config: &Config,
     available_labels: Vec<String>,
     available_categories: Vec<String>,
+    groups: Vec<Group>,
 ) -> Result<Option<ItemAction>> {
     tui_select_item_with_actions(
         prompt,
@@ -232,6 +266,7 @@ pub fn select_item_with_actions<T: AsRef<Item>>(
         config,
         available_labels,
         available_categories,
+        groups,
     )
 }
 
@@ -243,11 +278,25 @@ pub fn confirm(message: &str) -> Result<Option<bool>> {
     tui_confirm(message)
 }
 
+/// Prints the item chosen in a selector for `--print-selection`.
+pub fn print_selection(item: &Item, config: &Config, selection: Selection) -> Result<()> {
+    match selection {
+        Selection::Path => {
+            let path = item.path.as_ref().context("Item has no path")?;
+            println!("{}", config.relative_path(path).display());
+        }
+        Selection::Id => println!("{}", item.qualified_id()),
+    }
+    Ok(())
+}
+
 /// Opens an item in the editor and prints its relative path.
 pub fn open_item_in_editor(item: &Item, config: &Config) -> Result<()> {
     let path = item.path.as_ref().context("Item has no path")?;
     println!("{}", config.relative_path(path).display());
-    editor::open(path, config).context("Failed to open editor")
+    editor::open(path, config).context("Failed to open editor")?;
+    print_size_warning(config, path);
+    Ok(())
 }
 
 // =============================================================================
@@ -266,6 +315,16 @@ pub fn print_success(verb: &str, config: &Config, path: &Path) {
     );
 }
 
+/// Warns if the item at `path` has a body longer than `max_body_words`.
+pub fn print_size_warning(config: &Config, path: &Path) {
+    let Ok(item) = Item::load(path) else {
+        return;
+    };
+    if let Some(warning) = item.body_size().warning(item.id(), config.max_body_words()) {
+        print_warnings(&[warning]);
+    }
+}
+
 /// Prints warnings with yellow prefix.
 pub fn print_warnings(warnings: &[String]) {
     for warning in warnings {
@@ -280,12 +339,15 @@ pub fn print_warnings(warnings: &[String]) {
 /// Processes attachments and prints results.
 ///
 /// This is a shared utility for `new` and `attach` commands that handles:
+/// - Expanding glob patterns and directories into files, asking for confirmation
+///   in a terminal when there are many
 /// - Processing each attachment source (files are copied to `.attachments/` sibling dir)
 /// - Printing colored output for each result
 /// - Saving the updated item
 ///
 /// Returns the number of successfully added attachments.
 pub fn process_and_save_attachments(
+    config: &Config,
     item: &mut Item,
     path: &Path,
     sources: &[String],
@@ -295,21 +357,44 @@ pub fn process_and_save_attachments(
     // Set path so attachment_dir() works
     item.path = Some(path.to_path_buf());
 
+    let expanded = storage::sources::expand(
+        &std::env::current_dir()?,
+        sources,
+        config.attachment_directories(),
+    )?;
+    let files = expanded.file_count();
+    if files > ATTACHMENT_CONFIRM_THRESHOLD
+        && config.interactive()
+        && std::io::stdout().is_terminal()
+        && confirm(&format!("Attach {files} files?"))? != Some(true)
+    {
+        println!("{}", "Cancelled.".dimmed());
+        return Ok(0);
+    }
+
     let mut added_count = 0;
 
-    for source in sources {
-        match storage::process_attachment(source, item, path)? {
-            AttachmentResult::UrlAdded(url) => {
-                println!("  {} {}", "+".green(), url);
+    for source in &expanded.sources {
+        match storage::process_attachment(config, source, item, path)? {
+            AttachmentResult::UrlAdded { url, title } => {
+                print_added_url(&url, title.as_deref());
                 added_count += 1;
             }
-            AttachmentResult::FileCopied { original, new_name } => {
+            AttachmentResult::FileCopied {
+                original,
+                new_name,
+                duplicates,
+            } => {
                 println!("  {} {} -> {}", "+".green(), original, new_name);
+                print_duplicate_attachment(&duplicates);
                 added_count += 1;
             }
             AttachmentResult::FileNotFound(p) => {
                 eprintln!("  {} File not found: {}", "!".yellow(), p);
             }
+            AttachmentResult::Rejected { file, reason } => {
+                eprintln!("  {} Rejected {}: {}", "!".yellow(), file, reason);
+            }
         }
     }
 
@@ -319,6 +404,127 @@ pub fn process_and_save_attachments(
     Ok(added_count)
 }
 
+/// Prints an added URL attachment with its page title, if known.
+pub fn print_added_url(url: &str, title: Option<&str>) {
+    match title {
+        Some(title) => println!("  {} {} ({})", "+".green(), url, title),
+        None => println!("  {} {}", "+".green(), url),
+    }
+}
+
+/// Warns that a newly attached file is already attached to other items.
+pub fn print_duplicate_attachment(duplicates: &[String]) {
+    if !duplicates.is_empty() {
+        eprintln!(
+            "  {} Same file is already attached to: {}",
+            "!".yellow(),
+            duplicates.join(", ")
+        );
+    }
+}
+
+/// Formats a byte count compactly: `512 B`, `1.5 KB`, `12.0 MB`.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// Parses a size like `500KB`, `10 MB`, `1G`, or `2048` (bytes), in units of 1024.
+pub fn parse_size(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: u64 = number.parse().ok()?;
+    let factor: u64 = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "K" | "KB" => 1 << 10,
+        "M" | "MB" => 1 << 20,
+        "G" | "GB" => 1 << 30,
+        _ => return None,
+    };
+    number.checked_mul(factor)
+}
+
+// =============================================================================
+// Item Cards
+// =============================================================================
+
+/// Builds the card for an item, deriving its category from the item path.
+pub fn item_card(item: &Item, config: &Config) -> Card {
+    let category = item
+        .path
+        .as_ref()
+        .and_then(|p| storage::derive_category(config, p));
+    Card::new(item, category.as_deref(), chrono::Utc::now())
+}
+
+/// Formats a card for the terminal.
+///
+/// Colors are only used when stdout is a terminal; piped output is plain text.
+pub fn format_card(card: &Card) -> String {
+    if !std::io::stdout().is_terminal() {
+        return card.plain();
+    }
+
+    card.lines()
+        .iter()
+        .map(|line| {
+            line.iter()
+                .map(|segment| {
+                    let text = segment.text.as_str();
+                    match segment.tone {
+                        Tone::Id => text.cyan().to_string(),
+                        Tone::Status(Status::Open) => text.green().to_string(),
+                        Tone::Status(Status::InProgress) => text.yellow().to_string(),
+                        Tone::Status(Status::Closed | Status::Template) => {
+                            text.dimmed().to_string()
+                        }
+                        Tone::Title => text.bold().to_string(),
+                        Tone::Labels => text.magenta().to_string(),
+                        Tone::Label(color) => color.paint(text),
+                        Tone::Meta => text.dimmed().to_string(),
+                        Tone::Match => text.yellow().bold().to_string(),
+                        Tone::Summary | Tone::Plain => text.to_string(),
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Highlights the first case-insensitive match of `query` in `text`.
+///
+/// Only when stdout is a terminal; piped output is returned unchanged.
+pub fn highlight(text: &str, query: &str) -> String {
+    if !std::io::stdout().is_terminal() {
+        return text.to_string();
+    }
+    crate::item::search::find_match(text, query).map_or_else(
+        || text.to_string(),
+        |range| {
+            format!(
+                "{}{}{}",
+                &text[..range.start],
+                (&text[range.clone()]).yellow().bold(),
+                &text[range.end..]
+            )
+        },
+    )
+}
+
 // =============================================================================
 // String Utilities
 // =============================================================================
@@ -370,6 +576,16 @@ pub fn pad_to_width(s: &str, width: usize) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("2048"), Some(2048));
+        assert_eq!(parse_size("500KB"), Some(500 * 1024));
+        assert_eq!(parse_size("10 mb"), Some(10 * 1024 * 1024));
+        assert_eq!(parse_size("1G"), Some(1024 * 1024 * 1024));
+        assert_eq!(parse_size("1.5MB"), None);
+        assert_eq!(parse_size("ten"), None);
+    }
+
     // ==========================================================================
     // Truncate Tests (display width based)
     // ==========================================================================
@@ -0,0 +1,116 @@
+//! # Activity Command Tests
+//!
+//! Tests for the `qs activity` feed built from item timestamps and git history.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::{fs, process::Command as StdCommand};
+
+use assert_cmd::Command;
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use queuestack::commands;
+
+/// Creates a qs command that shows times in UTC.
+fn qs_utc(env: &TestEnv) -> Command {
+    let mut cmd = qs_cmd(env);
+    cmd.env("TZ", "UTC");
+    cmd
+}
+
+/// Runs git in the project; returns false if git is unavailable or fails.
+fn git(env: &TestEnv, args: &[&str]) -> bool {
+    StdCommand::new("git")
+        .args(args)
+        .current_dir(env.project_path())
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Commits all changes as `author` at `date`.
+fn commit_as(env: &TestEnv, author: &str, date: &str) -> bool {
+    let name = format!("user.name={author}");
+    git(env, &["add", "-A"])
+        && StdCommand::new("git")
+            .args(["-c", &name, "-c", "user.email=test@example.com"])
+            .args(["commit", "-q", "-m", "update"])
+            .env("GIT_AUTHOR_DATE", date)
+            .env("GIT_COMMITTER_DATE", date)
+            .current_dir(env.project_path())
+            .output()
+            .is_ok_and(|output| output.status.success())
+}
+
+#[test]
+fn test_activity_feed_from_history() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    if !git(&env, &["init", "-q"]) {
+        return; // git not available
+    }
+
+    // Created 2026-01-09 12:00 by Test User
+    let item = create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+    assert!(commit_as(&env, "Test User", "2026-01-09T12:30:00Z"));
+
+    fs::write(&item, fs::read_to_string(&item).unwrap() + "More\n").unwrap();
+    assert!(commit_as(&env, "Bob", "2026-01-10T10:00:00Z"));
+
+    let attachments = env.stack_path().join("260101-AAA-crash.attachments");
+    fs::create_dir_all(&attachments).unwrap();
+    fs::write(attachments.join("1-log.txt"), "log").unwrap();
+    assert!(commit_as(&env, "Bob", "2026-01-10T11:00:00Z"));
+
+    qs_utc(&env)
+        .args(["close", "--id", "260101"])
+        .assert()
+        .success();
+    assert!(commit_as(&env, "Alice", "2026-01-11T09:00:00Z"));
+
+    qs_utc(&env).arg("activity").assert().success().stdout(
+        "2026-01-09 12:00  Test User created 260101-AAA  Crash\n\
+         2026-01-10 10:00  Bob updated 260101-AAA  Crash\n\
+         2026-01-10 11:00  Bob attached 1-log.txt to 260101-AAA  Crash\n\
+         2026-01-11 09:00  Alice closed 260101-AAA  Crash\n",
+    );
+
+    qs_utc(&env)
+        .args(["activity", "--since", "2026-01-11"])
+        .assert()
+        .success()
+        .stdout("2026-01-11 09:00  Alice closed 260101-AAA  Crash\n");
+
+    qs_utc(&env)
+        .args(["activity", "-n", "2"])
+        .assert()
+        .success()
+        .stdout(
+            "2026-01-10 11:00  Bob attached 1-log.txt to 260101-AAA  Crash\n\
+             2026-01-11 09:00  Alice closed 260101-AAA  Crash\n",
+        );
+}
+
+#[test]
+fn test_activity_without_git_shows_creation() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+
+    qs_utc(&env)
+        .arg("activity")
+        .assert()
+        .success()
+        .stdout("2026-01-09 12:00  Test User created 260101-AAA  Crash\n");
+
+    qs_utc(&env)
+        .args(["activity", "--since", "2026-02-01"])
+        .assert()
+        .success()
+        .stdout("No activity found.\n");
+}
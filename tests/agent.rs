@@ -0,0 +1,91 @@
+//! # Agent Command Tests
+//!
+//! Tests for the experimental `qs agent` loop.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+fn setup(agent_command: Option<&str>) -> TestEnv {
+    let env = TestEnv::new();
+    let mut config = GlobalConfigBuilder::new().interactive(false);
+    if let Some(command) = agent_command {
+        config = config.agent_command(command);
+    }
+    env.write_global_config(&config.build());
+    commands::init().expect("init");
+    env
+}
+
+#[cfg(unix)]
+#[test]
+fn test_agent_closes_item_on_success() {
+    let env = setup(Some("echo 'working on' $QS_ID; echo {id} >> agent.log"));
+    create_test_item(&env, "260101-AAAAA", "Automate me", "open", &["auto"], None);
+    create_test_item(&env, "260101-BBBBB", "Leave me", "open", &["bug"], None);
+
+    qs_cmd(&env)
+        .args(["agent", "--once"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260101-AAAAA Automate me"))
+        .stdout(predicate::str::contains("Closed item:"));
+
+    let log = std::fs::read_to_string(env.project_dir.path().join("agent.log")).unwrap();
+    assert_eq!(log, "260101-AAAAA\n");
+
+    let closed = env.find_item_by_id("260101-AAAAA").unwrap();
+    assert!(closed.starts_with(env.archive_path()));
+    let content = std::fs::read_to_string(closed).unwrap();
+    assert!(content.contains("status: closed"));
+    assert!(content.contains(", exit 0)\n\n```text\nworking on 260101-AAAAA\n```\n"));
+
+    let untouched = env.find_item_by_id("260101-BBBBB").unwrap();
+    assert!(!untouched.starts_with(env.archive_path()));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_agent_labels_failed_items() {
+    let env = setup(Some("echo oops >&2; exit 3"));
+    create_test_item(&env, "260101-AAAAA", "Break me", "open", &["auto"], None);
+
+    qs_cmd(&env)
+        .args(["agent", "--once"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Agent failed on 260101-AAAAA (exit 3), labeled agent-failed",
+        ));
+
+    let path = env.find_item_by_id("260101-AAAAA").unwrap();
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.contains("status: open"));
+    assert!(content.contains("  - agent-failed"));
+    assert!(content.contains(", exit 3)\n\n```text\noops\n```\n"));
+
+    // Failed items are not picked up again
+    qs_cmd(&env)
+        .args(["agent", "--once"])
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn test_agent_requires_command() {
+    let env = setup(None);
+
+    qs_cmd(&env)
+        .args(["agent", "--once"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No agent command configured"));
+}
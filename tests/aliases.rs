@@ -0,0 +1,93 @@
+//! # Alias Tests
+//!
+//! Tests for command aliases defined in the `[aliases]` config table.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{qs_cmd, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+#[test]
+fn test_global_alias_expands() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .alias("bug", "new --category bugs --label=bug")
+            .build(),
+    );
+    commands::init().unwrap();
+
+    qs_cmd(&env)
+        .args(["bug", "Crash on start"])
+        .assert()
+        .success();
+
+    let files = env.list_category_files("bugs");
+    assert_eq!(files.len(), 1);
+    let content = env.read_item(&files[0]);
+    assert!(content.contains("title: Crash on start"));
+    assert!(content.contains("- bug"));
+}
+
+#[test]
+fn test_project_alias_overrides_global() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .alias("task", "new --category global")
+            .build(),
+    );
+    commands::init().unwrap();
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .alias("task", "new --category project")
+            .build(),
+    );
+
+    qs_cmd(&env).args(["task", "Something"]).assert().success();
+
+    assert_eq!(env.list_category_files("project").len(), 1);
+    assert!(env.list_category_files("global").is_empty());
+}
+
+#[test]
+fn test_alias_cannot_replace_builtin() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .alias("list", "new Shadowed")
+            .build(),
+    );
+    commands::init().unwrap();
+
+    qs_cmd(&env).arg("list").assert().success();
+    assert!(env.list_stack_files().is_empty());
+}
+
+#[test]
+fn test_alias_loop_is_an_error() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .alias("a", "b")
+            .alias("b", "a")
+            .build(),
+    );
+    commands::init().unwrap();
+
+    qs_cmd(&env)
+        .arg("a")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("expands to itself"));
+}
@@ -0,0 +1,161 @@
+//! # Archive Layout Tests
+//!
+//! Tests for grouping closed items in the archive by year or month
+//! (`archive_layout`).
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::{fs, path::PathBuf};
+
+use chrono::{Datelike, Local};
+use common::{create_test_item, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
+use queuestack::{
+    commands::{self, execute_close, execute_reopen},
+    config::Config,
+    storage,
+};
+
+/// Sets up a project with the given archive layout.
+fn setup(layout: &str) -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .archive_dir(".archive")
+            .archive_layout(layout)
+            .build(),
+    );
+    env
+}
+
+/// Returns the `YYYY` and `MM` directory names for today.
+fn today_dirs() -> (String, String) {
+    let today = Local::now().date_naive();
+    (
+        format!("{:04}", today.year()),
+        format!("{:02}", today.month()),
+    )
+}
+
+#[test]
+fn test_flat_layout_is_default() {
+    let env = setup("flat");
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], Some("bugs"));
+
+    execute_close(Some("260101".to_string()), None, None).expect("close");
+
+    assert!(env
+        .archive_path()
+        .join("bugs")
+        .join("260101-AAA-task.md")
+        .exists());
+}
+
+#[test]
+fn test_by_year_layout() {
+    let env = setup("by-year");
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], Some("bugs"));
+
+    execute_close(Some("260101".to_string()), None, None).expect("close");
+
+    let (year, _) = today_dirs();
+    assert!(env
+        .archive_path()
+        .join(&year)
+        .join("bugs")
+        .join("260101-AAA-task.md")
+        .exists());
+}
+
+#[test]
+fn test_by_month_layout_round_trip() {
+    let env = setup("by-month");
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], Some("bugs"));
+
+    execute_close(Some("260101".to_string()), None, None).expect("close");
+
+    let (year, month) = today_dirs();
+    let archived: PathBuf = env
+        .archive_path()
+        .join(&year)
+        .join(&month)
+        .join("bugs")
+        .join("260101-AAA-task.md");
+    assert!(archived.exists());
+
+    // The date directories are not mistaken for categories
+    let config = Config::load().unwrap();
+    assert_eq!(
+        storage::derive_category(&config, &archived).as_deref(),
+        Some("bugs")
+    );
+    assert_eq!(storage::walk_archived(&config).count(), 1);
+
+    // Reopening restores the category and removes the emptied date directories
+    execute_reopen(Some("260101".to_string()), None, None).expect("reopen");
+    assert_eq!(env.list_category_files("bugs").len(), 1);
+    assert!(!env.archive_path().join(&year).exists());
+    assert!(env.archive_path().exists());
+}
+
+#[test]
+fn test_by_month_layout_without_category() {
+    let env = setup("by-month");
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    execute_close(Some("260101".to_string()), None, None).expect("close");
+
+    let (year, month) = today_dirs();
+    let archived = env
+        .archive_path()
+        .join(&year)
+        .join(&month)
+        .join("260101-AAA-task.md");
+    assert!(archived.exists());
+
+    let config = Config::load().unwrap();
+    assert_eq!(storage::derive_category(&config, &archived), None);
+
+    execute_reopen(Some("260101".to_string()), None, None).expect("reopen");
+    assert_eq!(env.list_stack_files().len(), 1);
+}
+
+#[test]
+fn test_dated_items_found_after_switching_to_flat() {
+    let env = setup("flat");
+    create_test_item(&env, "260101-AAA", "Task", "closed", &[], None);
+    let dated = env.archive_path().join("2025").join("11").join("bugs");
+    fs::create_dir_all(&dated).unwrap();
+    fs::rename(
+        env.stack_path().join("260101-AAA-task.md"),
+        dated.join("260101-AAA-task.md"),
+    )
+    .unwrap();
+
+    execute_reopen(Some("260101".to_string()), None, None).expect("reopen");
+
+    assert_eq!(env.list_category_files("bugs").len(), 1);
+    assert!(!env.archive_path().join("2025").exists());
+}
+
+#[test]
+fn test_close_category_follows_layout() {
+    let env = setup("by-year");
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    execute_close(Some("260101".to_string()), None, Some("done")).expect("close");
+
+    let (year, _) = today_dirs();
+    assert!(env
+        .archive_path()
+        .join(&year)
+        .join("done")
+        .join("260101-AAA-task.md")
+        .exists());
+}
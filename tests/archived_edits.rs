@@ -0,0 +1,159 @@
+//! # Archived Item Edit Tests
+//!
+//! Tests that `update`, `patch`, and `attachments add` refuse to edit closed or
+//! archived items unless `--force` is given.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::fs;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+/// Sets up a project with one archived item, 260101-AAA.
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    create_test_item(&env, "260101-AAA", "Done", "closed", &[], None);
+    fs::rename(
+        env.stack_path().join("260101-AAA-done.md"),
+        env.archive_path().join("260101-AAA-done.md"),
+    )
+    .unwrap();
+    env
+}
+
+#[test]
+fn test_update_archived_item_fails_with_hint() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260101-AAA", "--label", "bug"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Cannot update '260101-AAA': it is archived",
+        ))
+        .stderr(predicate::str::contains("qs reopen --id 260101-AAA"));
+
+    let content = fs::read_to_string(env.archive_path().join("260101-AAA-done.md")).unwrap();
+    assert!(!content.contains("bug"));
+}
+
+#[test]
+fn test_update_dry_run_on_archived_item_fails() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args([
+            "update",
+            "--id",
+            "260101-AAA",
+            "--title",
+            "New",
+            "--dry-run",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("it is archived"));
+}
+
+#[test]
+fn test_update_archived_item_with_force() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260101-AAA", "--label", "bug", "--force"])
+        .assert()
+        .success();
+
+    let content = fs::read_to_string(env.archive_path().join("260101-AAA-done.md")).unwrap();
+    assert!(content.contains("bug"));
+}
+
+#[test]
+fn test_patch_archived_item_requires_force() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["patch", "--id", "260101-AAA", "--set", "priority=high"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Cannot patch '260101-AAA'"));
+
+    qs_cmd(&env)
+        .args([
+            "patch",
+            "--id",
+            "260101-AAA",
+            "--set",
+            "priority=high",
+            "--force",
+        ])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_attach_to_archived_item_requires_force() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args([
+            "attachments",
+            "add",
+            "--id",
+            "260101-AAA",
+            "https://example.com",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Cannot attach to '260101-AAA'"))
+        .stderr(predicate::str::contains("--force"));
+
+    qs_cmd(&env)
+        .args([
+            "attachments",
+            "add",
+            "--id",
+            "260101-AAA",
+            "https://example.com",
+            "--force",
+        ])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_closed_item_outside_archive_is_guarded() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    create_test_item(&env, "260101-BBB", "Closed", "closed", &[], None);
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260101-BBB", "--title", "Renamed"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("it is archived"));
+}
+
+#[test]
+fn test_open_item_is_editable() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    create_test_item(&env, "260101-CCC", "Open", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260101-CCC", "--label", "bug"])
+        .assert()
+        .success();
+}
@@ -0,0 +1,133 @@
+//! # Assignee Tests
+//!
+//! Tests for assigning items with `qs new --assignee` and `qs update
+//! --assignee`, for the `--assignee` and `--mine` list filters, and for
+//! reading the `assignee` frontmatter field.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::path::PathBuf;
+
+use common::{qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::{commands, item::Item};
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env
+}
+
+/// Creates an item with `qs new` and returns its path.
+fn new_item(env: &TestEnv, title: &str, assignee: Option<&str>) -> PathBuf {
+    let mut cmd = qs_cmd(env);
+    cmd.args(["new", title]);
+    if let Some(assignee) = assignee {
+        cmd.args(["--assignee", assignee]);
+    }
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let path = String::from_utf8(output.stdout).unwrap();
+    env.project_dir.path().join(path.trim())
+}
+
+#[test]
+fn test_new_with_assignee() {
+    let env = setup();
+
+    let path = new_item(&env, "Release", Some("Alice"));
+
+    let item = Item::load(&path).unwrap();
+    assert_eq!(item.assignee().as_deref(), Some("Alice"));
+}
+
+#[test]
+fn test_assignee_in_frontmatter() {
+    let env = setup();
+    let path = new_item(&env, "Release", Some("Alice"));
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.contains("\nassignee: Alice\n"), "{content}");
+    assert!(!Item::load(&path)
+        .unwrap()
+        .frontmatter
+        .extra
+        .contains_key("assignee"));
+
+    // Numbers are read as names; empty values mean unassigned
+    std::fs::write(&path, content.replace("assignee: Alice", "assignee: 42")).unwrap();
+    assert_eq!(Item::load(&path).unwrap().assignee().as_deref(), Some("42"));
+    std::fs::write(&path, content.replace("assignee: Alice", "assignee: ''")).unwrap();
+    assert_eq!(Item::load(&path).unwrap().assignee(), None);
+}
+
+#[test]
+fn test_update_sets_and_removes_assignee() {
+    let env = setup();
+    let path = new_item(&env, "Release", None);
+    let id = Item::load(&path).unwrap().id().to_string();
+
+    qs_cmd(&env)
+        .args(["update", "--id", &id, "--assignee", "Bob"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("assignee"))
+        .stdout(predicate::str::contains("Bob"));
+    assert_eq!(
+        Item::load(&path).unwrap().assignee().as_deref(),
+        Some("Bob")
+    );
+
+    qs_cmd(&env)
+        .args(["update", "--id", &id, "--remove-assignee"])
+        .assert()
+        .success();
+    assert_eq!(Item::load(&path).unwrap().assignee(), None);
+}
+
+#[test]
+fn test_list_assignee_filter() {
+    let env = setup();
+    new_item(&env, "Login", Some("Alice"));
+    new_item(&env, "Docs", Some("Bob"));
+    new_item(&env, "Unassigned", None);
+
+    qs_cmd(&env)
+        .args(["list", "--assignee", "alice"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("login"))
+        .stdout(predicate::str::contains("docs").not())
+        .stdout(predicate::str::contains("unassigned").not());
+}
+
+#[test]
+fn test_list_mine() {
+    let env = setup();
+    new_item(&env, "Mine", Some("Test User"));
+    new_item(&env, "Theirs", Some("Bob"));
+    new_item(&env, "Unassigned", None);
+
+    qs_cmd(&env)
+        .args(["list", "--mine"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("mine"))
+        .stdout(predicate::str::contains("theirs").not())
+        .stdout(predicate::str::contains("unassigned").not());
+}
+
+#[test]
+fn test_list_mine_conflicts_with_assignee() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["list", "--mine", "--assignee", "Bob"])
+        .assert()
+        .failure();
+}
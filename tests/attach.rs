@@ -5,13 +5,16 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
 mod common;
 
 use common::{create_test_item, create_test_item_with_attachments, GlobalConfigBuilder, TestEnv};
 use queuestack::commands::{
-    self, AttachAddArgs, AttachRemoveArgs, InteractiveArgs, ListMode, ListOptions, SortBy,
-    StatusFilter, UpdateArgs,
+    self, AttachAddArgs, AttachDuplicatesArgs, AttachOpenArgs, AttachRemoveArgs, InteractiveArgs,
+    ListMode, ListOptions, OutputFormat, Scope, SortBy, UpdateArgs,
 };
+use queuestack::{item::Item, storage::checksum};
 
 // =============================================================================
 // Attach Add Command Tests
@@ -35,6 +38,7 @@ fn test_attach_add_file() {
         id: Some(item_id.to_string()),
         file: None,
         sources: vec![test_file.to_string_lossy().to_string()],
+        force: false,
     };
     commands::attach_add(&args).expect("attach add should succeed");
 
@@ -72,6 +76,7 @@ fn test_attach_add_url() {
         id: Some(item_id.to_string()),
         file: None,
         sources: vec!["https://github.com/user/repo/issues/42".to_string()],
+        force: false,
     };
     commands::attach_add(&args).expect("attach add URL should succeed");
 
@@ -110,6 +115,7 @@ fn test_attach_add_multiple() {
             file2.to_string_lossy().to_string(),
             "https://example.com".to_string(),
         ],
+        force: false,
     };
     commands::attach_add(&args).expect("attach add multiple should succeed");
 
@@ -132,6 +138,7 @@ fn test_attach_add_counter_increments() {
         id: Some(item_id.to_string()),
         file: None,
         sources: vec![file1.to_string_lossy().to_string()],
+        force: false,
     };
     commands::attach_add(&args1).unwrap();
 
@@ -141,6 +148,7 @@ fn test_attach_add_counter_increments() {
         id: Some(item_id.to_string()),
         file: None,
         sources: vec![file2.to_string_lossy().to_string()],
+        force: false,
     };
     commands::attach_add(&args2).unwrap();
 
@@ -174,6 +182,7 @@ fn test_attach_add_nonexistent_file() {
         id: Some("260101-AAA".to_string()),
         file: None,
         sources: vec!["/nonexistent/file.txt".to_string()],
+        force: false,
     };
     // Should fail when all files are not found
     let result = commands::attach_add(&args);
@@ -196,6 +205,7 @@ fn test_attach_add_nonexistent_item() {
         id: Some("NONEXISTENT".to_string()),
         file: None,
         sources: vec!["https://example.com".to_string()],
+        force: false,
     };
     let result = commands::attach_add(&args);
     assert!(result.is_err(), "Should fail for nonexistent item");
@@ -219,6 +229,7 @@ fn test_attach_add_to_closed_item_fails() {
         id: Some("260101-AAA".to_string()),
         file: None,
         sources: vec!["https://example.com".to_string()],
+        force: false,
     };
     let result = commands::attach_add(&args);
     assert!(result.is_err(), "Should fail for closed item");
@@ -236,6 +247,7 @@ fn test_attach_add_empty_sources_fails() {
         id: Some("260101-AAA".to_string()),
         file: None,
         sources: vec![],
+        force: false,
     };
     let result = commands::attach_add(&args);
     assert!(result.is_err(), "Should fail with empty sources");
@@ -254,6 +266,7 @@ fn test_attach_add_to_item_in_category() {
         id: Some("260101-AAA".to_string()),
         file: None,
         sources: vec![test_file.to_string_lossy().to_string()],
+        force: false,
     };
     commands::attach_add(&args).expect("attach add in category should succeed");
 
@@ -305,6 +318,7 @@ fn test_attach_remove_single() {
         id: Some(item_id.to_string()),
         file: None,
         indices: vec![1], // Remove the file attachment
+        force: false,
     };
     commands::attach_remove(&args).expect("attach remove should succeed");
 
@@ -333,6 +347,7 @@ fn test_attach_remove_multiple() {
         id: Some(item_id.to_string()),
         file: None,
         indices: vec![1, 3], // Remove first and third
+        force: false,
     };
     commands::attach_remove(&args).expect("attach remove multiple should succeed");
 
@@ -369,6 +384,7 @@ fn test_attach_remove_url_only_updates_frontmatter() {
         id: Some(item_id.to_string()),
         file: None,
         indices: vec![1],
+        force: false,
     };
     commands::attach_remove(&args).expect("remove URL should succeed");
 
@@ -403,6 +419,7 @@ fn test_attach_remove_invalid_index() {
         id: Some(item_id.to_string()),
         file: None,
         indices: vec![5], // Only 1 attachment exists
+        force: false,
     };
     let result = commands::attach_remove(&args);
     assert!(result.is_err(), "Should fail with invalid index");
@@ -420,6 +437,7 @@ fn test_attach_remove_from_empty_item() {
         id: Some("260101-AAA".to_string()),
         file: None,
         indices: vec![1],
+        force: false,
     };
     let result = commands::attach_remove(&args);
     assert!(result.is_err(), "Should fail when item has no attachments");
@@ -432,17 +450,33 @@ fn test_attach_remove_from_empty_item() {
 fn make_attachments_filter(id: &str) -> ListOptions {
     ListOptions {
         mode: ListMode::Attachments,
-        status: StatusFilter::Open,
+        scope: Scope::Open,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: Some(id.to_string()),
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     }
 }
 
@@ -514,6 +548,13 @@ fn test_update_category_moves_attachments() {
         remove_labels: vec![],
         category: Some("bugs".to_string()),
         remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
     };
     commands::update(args).expect("update category should succeed");
 
@@ -568,6 +609,7 @@ fn test_markdown_attachment_not_listed_as_item() {
         id: Some("260101-AAA".to_string()),
         file: None,
         sources: vec![test_md.to_string_lossy().to_string()],
+        force: false,
     };
     commands::attach_add(&args).expect("attach add should succeed");
 
@@ -591,3 +633,336 @@ fn test_markdown_attachment_not_listed_as_item() {
         .to_string_lossy()
         .contains("260101-AAA"));
 }
+
+// =============================================================================
+// External Attachment Path Tests
+// =============================================================================
+
+#[test]
+fn test_attach_external_path_keeps_files_outside_stack() {
+    let env = TestEnv::new();
+    let external = env.home_dir.path().join("attachments");
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .attachment_path(format!("{}/{{id}}", external.display()))
+            .build(),
+    );
+    commands::init().unwrap();
+
+    create_test_item(&env, "260101-AAA", "Test Item", "open", &[], Some("bugs"));
+    let test_file = env.create_test_file("screenshot.png", "png");
+
+    commands::attach_add(&AttachAddArgs {
+        id: Some("260101-AAA".to_string()),
+        file: None,
+        sources: vec![test_file.to_string_lossy().to_string()],
+        force: false,
+    })
+    .expect("attach add should succeed");
+
+    let stored = external.join("260101-AAA").join("1-screenshot.png");
+    assert!(
+        stored.exists(),
+        "Attachment should be in the external directory"
+    );
+    assert!(env.list_attachment_files("260101-AAA").is_empty());
+    let item_path = env.find_item_by_id("260101-AAA").unwrap();
+    assert!(env.read_item(&item_path).contains("1-screenshot.png"));
+
+    // Moving the item leaves the external directory alone
+    commands::update(UpdateArgs {
+        id: Some("260101-AAA".to_string()),
+        file: None,
+        title: Some("Renamed".to_string()),
+        labels: vec![],
+        remove_labels: vec![],
+        category: None,
+        remove_category: true,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
+    })
+    .expect("update should succeed");
+    commands::execute_close(Some("260101-AAA".to_string()), None, None)
+        .expect("close should succeed");
+    assert!(stored.exists());
+
+    commands::execute_reopen(Some("260101-AAA".to_string()), None, None).expect("reopen");
+    commands::attach_remove(&AttachRemoveArgs {
+        id: Some("260101-AAA".to_string()),
+        file: None,
+        indices: vec![1],
+        force: false,
+    })
+    .expect("attach remove should succeed");
+    assert!(
+        !stored.exists(),
+        "Removing the attachment deletes the external file"
+    );
+}
+
+#[test]
+fn test_attach_external_path_relative_without_placeholder() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+    env.write_project_config(
+        &common::ProjectConfigBuilder::new()
+            .attachment_path("files")
+            .build(),
+    );
+
+    create_test_item(&env, "260101-AAA", "Test Item", "open", &[], None);
+    let test_file = env.create_test_file("log.txt", "log");
+    commands::attach_add(&AttachAddArgs {
+        id: Some("260101-AAA".to_string()),
+        file: None,
+        sources: vec![test_file.to_string_lossy().to_string()],
+        force: false,
+    })
+    .expect("attach add should succeed");
+
+    assert!(env
+        .project_path()
+        .join("files/260101-AAA/1-log.txt")
+        .exists());
+}
+
+// =============================================================================
+// Attach Open / Object Storage Tests
+// =============================================================================
+
+#[test]
+fn test_attach_open_print_resolves_files_and_urls() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+
+    let item_path = create_test_item_with_attachments(
+        &env,
+        "260101-AAA",
+        "Test Item",
+        "open",
+        &["1-file.txt", "https://example.com/issue"],
+        None,
+    );
+
+    let open = |index| {
+        commands::attach_open(&AttachOpenArgs {
+            id: Some("260101-AAA".to_string()),
+            file: None,
+            index,
+            print: true,
+        })
+    };
+    open(1).expect("file attachment should resolve");
+    open(2).expect("URL attachment should resolve");
+    assert!(open(3).is_err(), "Out-of-range index should fail");
+    assert!(open(0).is_err(), "Indices are 1-based");
+
+    // Missing files are reported
+    std::fs::remove_file(
+        item_path
+            .with_file_name("260101-AAA-test-item.attachments")
+            .join("1-file.txt"),
+    )
+    .unwrap();
+    assert!(open(1).is_err());
+}
+
+#[cfg(not(feature = "s3"))]
+#[test]
+fn test_attach_s3_url_requires_feature() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .s3_url("s3://bucket/qs")
+            .build(),
+    );
+    commands::init().unwrap();
+
+    create_test_item(&env, "260101-AAA", "Test Item", "open", &[], None);
+    let test_file = env.create_test_file("shot.png", "png");
+
+    let err = commands::attach_add(&AttachAddArgs {
+        id: Some("260101-AAA".to_string()),
+        file: None,
+        sources: vec![test_file.to_string_lossy().to_string()],
+        force: false,
+    })
+    .unwrap_err();
+    assert!(err.to_string().contains("'s3' feature"));
+    assert!(env.list_attachment_files("260101-AAA").is_empty());
+}
+
+// =============================================================================
+// Checksum and Dedup Tests
+// =============================================================================
+
+fn attach(id: &str, source: &std::path::Path) {
+    commands::attach_add(&AttachAddArgs {
+        id: Some(id.to_string()),
+        file: None,
+        sources: vec![source.to_string_lossy().to_string()],
+        force: false,
+    })
+    .expect("attach add should succeed");
+}
+
+#[test]
+fn test_attach_records_checksum() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+
+    let item_path = create_test_item(&env, "260101-AAA", "Test Item", "open", &[], None);
+    let test_file = env.create_test_file("shot.png", "png bytes");
+    attach("260101-AAA", &test_file);
+
+    let item = Item::load(&item_path).unwrap();
+    assert_eq!(
+        item.checksum("1-shot.png"),
+        Some(checksum::sha256(b"png bytes").as_str())
+    );
+
+    // Removing the attachment drops its checksum
+    commands::attach_remove(&AttachRemoveArgs {
+        id: Some("260101-AAA".to_string()),
+        file: None,
+        indices: vec![1],
+        force: false,
+    })
+    .unwrap();
+    let content = std::fs::read_to_string(&item_path).unwrap();
+    assert!(!content.contains("checksums:"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_attach_dedup_hard_links_identical_files() {
+    use std::os::unix::fs::MetadataExt;
+
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .attachment_dedup(true)
+            .build(),
+    );
+    commands::init().unwrap();
+
+    create_test_item(&env, "260101-AAA", "First", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Second", "open", &[], None);
+    let test_file = env.create_test_file("shot.png", "png bytes");
+    attach("260101-AAA", &test_file);
+    attach("260102-BBB", &test_file);
+
+    let first = &env.list_attachment_files("260101-AAA")[0];
+    let second = &env.list_attachment_files("260102-BBB")[0];
+    assert_eq!(
+        std::fs::metadata(first).unwrap().ino(),
+        std::fs::metadata(second).unwrap().ino(),
+        "Identical attachments should share one file"
+    );
+
+    let store = env.stack_path().join(".store.attachments");
+    assert!(store.join(checksum::sha256(b"png bytes")).exists());
+    assert_eq!(
+        std::fs::read_to_string(store.join(".gitignore")).unwrap(),
+        "*\n"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_attach_duplicates_link_existing_attachments() {
+    use std::os::unix::fs::MetadataExt;
+
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+
+    // Attachments from before checksums were recorded, with identical content
+    let first = create_test_item_with_attachments(
+        &env,
+        "260101-AAA",
+        "First",
+        "open",
+        &["1-log.txt"],
+        None,
+    );
+    create_test_item_with_attachments(&env, "260102-BBB", "Second", "open", &["1-log.txt"], None);
+
+    commands::attach_duplicates(&AttachDuplicatesArgs { link: false }).unwrap();
+    assert!(Item::load(&first).unwrap().checksum("1-log.txt").is_none());
+
+    commands::attach_duplicates(&AttachDuplicatesArgs { link: true }).unwrap();
+    assert!(Item::load(&first).unwrap().checksum("1-log.txt").is_some());
+    let inode = |id| {
+        std::fs::metadata(&env.list_attachment_files(id)[0])
+            .unwrap()
+            .ino()
+    };
+    assert_eq!(inode("260101-AAA"), inode("260102-BBB"));
+}
+
+#[test]
+fn test_attach_add_glob_and_directory() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+    let item_path = create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+
+    env.create_test_file("app.log", "app");
+    env.create_test_file("db.log", "db");
+    env.create_test_file("notes.txt", "notes");
+    let dump = env.project_dir.path().join("dump");
+    std::fs::create_dir_all(dump.join("threads")).unwrap();
+    std::fs::write(dump.join("core.dmp"), "core").unwrap();
+    std::fs::write(dump.join("threads/1.txt"), "thread").unwrap();
+    std::fs::write(dump.join(".DS_Store"), "junk").unwrap();
+
+    commands::attach_add(&AttachAddArgs {
+        id: Some("260101-AAA".to_string()),
+        file: None,
+        sources: vec!["*.log".to_string(), "dump".to_string()],
+        force: false,
+    })
+    .expect("attach add should succeed");
+
+    let item = Item::load(&item_path).unwrap();
+    assert_eq!(
+        item.attachments(),
+        ["1-app.log", "2-db.log", "3-core.dmp", "4-1.txt"]
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_attach_add_directory_as_archive() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+    env.write_project_config(
+        &common::ProjectConfigBuilder::new()
+            .attachment_directories("archive")
+            .build(),
+    );
+    let item_path = create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+
+    let dump = env.project_dir.path().join("dump");
+    std::fs::create_dir_all(&dump).unwrap();
+    std::fs::write(dump.join("core.dmp"), "core").unwrap();
+
+    attach("260101-AAA", &dump);
+
+    let item = Item::load(&item_path).unwrap();
+    assert_eq!(item.attachments(), ["1-dump.tgz"]);
+    assert_eq!(env.list_attachment_files("260101-AAA").len(), 1);
+}
@@ -0,0 +1,134 @@
+//! # Attachment Policy Tests
+//!
+//! Tests for the `[attachment_policy]` size limit, allowed extensions, and scanner.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::{
+    commands::{self, AttachAddArgs},
+    config::GlobalConfig,
+};
+
+fn setup(global: GlobalConfigBuilder) -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&global.interactive(false).build());
+    commands::init().expect("init");
+    create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+    env
+}
+
+fn attach(sources: &[&str]) -> anyhow::Result<()> {
+    commands::attach_add(&AttachAddArgs {
+        id: Some("260101-AAA".to_string()),
+        file: None,
+        sources: sources.iter().map(ToString::to_string).collect(),
+        force: false,
+    })
+}
+
+fn attachment_names(env: &TestEnv) -> Vec<String> {
+    env.list_attachment_files("260101-AAA")
+        .iter()
+        .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+        .collect()
+}
+
+#[test]
+fn test_disallowed_extension_is_rejected() {
+    let env = setup(GlobalConfigBuilder::new().attachment_policy(None, &["log", "png"], None));
+    env.create_test_file("crash.log", "panic");
+    env.create_test_file("build.exe", "binary");
+
+    attach(&["crash.log", "build.exe"]).expect("allowed file should be attached");
+    assert_eq!(attachment_names(&env), vec!["1-crash.log"]);
+
+    qs_cmd(&env)
+        .args(["attachments", "add", "--id", "260101", "build.exe"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Rejected build.exe: extension '.exe' is not allowed (allowed: log, png)",
+        ));
+}
+
+#[test]
+fn test_file_over_max_size_is_rejected() {
+    let env = setup(GlobalConfigBuilder::new().attachment_policy(Some("1KB"), &[], None));
+    env.create_test_file("small.txt", "ok");
+    env.create_test_file("large.txt", &"x".repeat(2048));
+
+    let err = attach(&["large.txt"]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "No attachments were added (all files missing or rejected)"
+    );
+    assert!(env.list_attachment_files("260101-AAA").is_empty());
+
+    attach(&["small.txt"]).expect("small file should be attached");
+}
+
+#[test]
+fn test_directory_is_rejected() {
+    let env = setup(GlobalConfigBuilder::new());
+    std::fs::create_dir(env.project_path().join("target")).unwrap();
+
+    qs_cmd(&env)
+        .args(["attachments", "add", "--id", "260101", "target"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Rejected target: is a directory"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_scanner_rejects_file() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let env = setup(GlobalConfigBuilder::new().attachment_policy(None, &[], Some("./scan.sh")));
+    let scanner = env.create_test_file(
+        "scan.sh",
+        "#!/bin/sh\nif grep -q EICAR \"$1\"; then echo \"$1: Eicar FOUND\"; exit 1; fi\n",
+    );
+    std::fs::set_permissions(&scanner, std::fs::Permissions::from_mode(0o755)).unwrap();
+    env.create_test_file("clean.txt", "hello");
+    env.create_test_file("infected.txt", "EICAR");
+
+    attach(&["clean.txt"]).expect("clean file should be attached");
+
+    qs_cmd(&env)
+        .args(["attachments", "add", "--id", "260101", "infected.txt"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("rejected by scanner: "))
+        .stderr(predicate::str::contains("infected.txt: Eicar FOUND"));
+    assert_eq!(attachment_names(&env), vec!["1-clean.txt"]);
+}
+
+#[test]
+fn test_project_attachment_policy_replaces_global() {
+    let env = setup(GlobalConfigBuilder::new().attachment_policy(None, &["png"], None));
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .attachment_policy(Some("10MB"), &[], None)
+            .build(),
+    );
+    env.create_test_file("notes.txt", "notes");
+
+    attach(&["notes.txt"]).expect("project policy allows any extension");
+}
+
+#[test]
+fn test_attachment_policy_written_to_global_config() {
+    let _env = TestEnv::new();
+    GlobalConfig::create_default_if_missing().expect("create config");
+
+    let content = std::fs::read_to_string(GlobalConfig::path().unwrap()).unwrap();
+    assert!(content.contains("# [attachment_policy]\n# max_size = \"10MB\""));
+}
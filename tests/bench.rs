@@ -0,0 +1,93 @@
+//! # Bench Tests
+//!
+//! Tests for generating synthetic projects and for the `qs bench` timings and
+//! baseline comparison.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::{bench, storage, Status};
+
+#[test]
+fn test_generate_synthetic_project() {
+    let env = TestEnv::new();
+    let root = env.project_path().join("big");
+
+    let config = bench::generate(&root, 50).expect("generate should succeed");
+
+    let items = storage::load_all_items(&config);
+    assert_eq!(items.len(), 50);
+    assert_eq!(
+        items
+            .iter()
+            .filter(|i| i.status() == Status::Closed)
+            .count(),
+        10
+    );
+    assert_eq!(storage::walk_archived(&config).count(), 10);
+    assert!(root.join(".queuestack").exists());
+
+    let moved = bench::run_attachment_moves(&config, 1).expect("moves should succeed");
+    assert_eq!(moved.name, "attachment_move");
+    assert_eq!(storage::load_all_items(&config).len(), 50);
+    assert!(!config.stack_path().join("moved").exists());
+}
+
+#[test]
+fn test_generate_refuses_non_empty_directory() {
+    let env = TestEnv::new();
+    env.create_test_file("notes.txt", "keep");
+
+    let err = bench::generate(env.project_path(), 10).unwrap_err();
+    assert!(err.to_string().starts_with("Directory is not empty"));
+}
+
+#[test]
+fn test_bench_save_and_compare() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    bench::generate(&env.project_path().join("qs-bench-20"), 20).expect("generate");
+
+    qs_cmd(&env)
+        .args(["--project-root", "qs-bench-20", "bench", "--runs", "1"])
+        .args(["--save", "base.json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("20 items, median of 1 runs:\n"))
+        .stdout(predicate::str::contains("  walk "));
+
+    // A baseline where everything took no time at all is always beaten
+    let baseline = std::fs::read_to_string(env.project_path().join("base.json")).unwrap();
+    let zero = zero_timings(&baseline);
+    std::fs::write(env.project_path().join("zero.json"), zero).unwrap();
+
+    qs_cmd(&env)
+        .args(["--project-root", "qs-bench-20", "bench", "--runs", "1"])
+        .args(["--compare", "zero.json", "--threshold", "0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Slower than the baseline by more than 0%:",
+        ))
+        .stderr(predicate::str::contains("  parse: 0µs -> "));
+}
+
+/// Sets every `median_us` in a saved baseline to 0.
+fn zero_timings(json: &str) -> String {
+    json.lines()
+        .map(|line| {
+            if line.trim_start().starts_with("\"median_us\"") {
+                "    \"median_us\": 0,".to_string()
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
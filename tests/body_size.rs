@@ -0,0 +1,115 @@
+//! # Body Size Tests
+//!
+//! Tests for body word counts on cards (`qs show`, `qs list --verbose`), the
+//! `max_body_words` warning on save, and oversized bodies in full-text search.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::fs;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+/// Sets up a project with a short item and one of 450 words.
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+
+    create_test_item(&env, "260101-SMALL", "Small task", "open", &[], None);
+    let large = create_test_item(&env, "260102-LARGE", "Large spec", "open", &[], None);
+    let body = format!("{}needle\n", "word ".repeat(449));
+    let content = fs::read_to_string(&large)
+        .unwrap()
+        .replace("Test item body.", &body);
+    fs::write(&large, content).unwrap();
+    env
+}
+
+#[test]
+fn test_cards_show_word_count_and_reading_time() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["show", "--id", "260102", "--brief"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(" · 450 words · 3 min read\n"));
+
+    let output = qs_cmd(&env).args(["list", "--verbose"]).output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let cards: Vec<&str> = stdout.trim_end().split("\n\n").collect();
+    assert_eq!(cards.len(), 2);
+    assert!(cards[0].starts_with("260101-SMALL"));
+    assert!(cards[0].contains(" · 3 words · 1 min read"));
+    assert!(cards[1].starts_with("260102-LARGE"));
+    assert!(cards[1].contains(" · 450 words · 3 min read"));
+}
+
+#[test]
+fn test_full_text_search_skips_oversized_bodies() {
+    let env = setup();
+    env.write_project_config(&ProjectConfigBuilder::new().max_body_words(400).build());
+
+    qs_cmd(&env)
+        .args(["search", "needle", "--full-text", "--no-interactive"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No items found matching"));
+
+    qs_cmd(&env)
+        .args([
+            "search",
+            "needle",
+            "--full-text",
+            "--include-large",
+            "--no-interactive",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260102-LARGE"));
+
+    // Titles of oversized items still match
+    qs_cmd(&env)
+        .args(["search", "large", "--full-text", "--no-interactive"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260102-LARGE"));
+}
+
+#[test]
+fn test_saving_oversized_item_warns() {
+    let env = setup();
+    env.write_project_config(&ProjectConfigBuilder::new().max_body_words(20).build());
+
+    qs_cmd(&env)
+        .args(["new", "Checklist", "--as-template"])
+        .assert()
+        .success();
+    let template = &env.list_template_files()[0];
+    let content = fs::read_to_string(template).unwrap();
+    fs::write(template, format!("{content}{}\n", "step ".repeat(30))).unwrap();
+
+    qs_cmd(&env)
+        .args(["new", "Release", "--from-template", "checklist"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "has 30 words (limit 20); consider splitting it into smaller items",
+        ));
+
+    // Disabled with 0
+    env.write_project_config(&ProjectConfigBuilder::new().max_body_words(0).build());
+    qs_cmd(&env)
+        .args(["new", "Release again", "--from-template", "checklist"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("consider splitting").not());
+}
@@ -0,0 +1,191 @@
+//! # Bulk Command Tests
+//!
+//! Tests for `qs bulk export` and `qs bulk apply`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::fs;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env
+}
+
+/// Exports the items matching `args` and returns the YAML.
+fn export(env: &TestEnv, args: &[&str]) -> String {
+    let output = qs_cmd(env)
+        .args(["bulk", "export"])
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Writes `content` to `items.yaml` in the project and returns its name.
+fn write_items(env: &TestEnv, content: &str) -> &'static str {
+    fs::write(env.project_dir.path().join("items.yaml"), content).unwrap();
+    "items.yaml"
+}
+
+#[test]
+fn test_export_writes_frontmatter_of_matching_items() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "First", "open", &["backend"], None);
+    create_test_item(&env, "260102-BBB", "Second", "open", &["frontend"], None);
+
+    let yaml = export(&env, &["--label", "backend"]);
+
+    assert!(yaml.starts_with("# Edit the items below"));
+    assert!(yaml.contains("id: \"260101-AAA\""));
+    assert!(yaml.contains("title: First"));
+    assert!(!yaml.contains("260102-BBB"));
+}
+
+#[test]
+fn test_apply_round_trips_edits() {
+    let env = setup();
+    let first = create_test_item(&env, "260101-AAA", "First", "open", &["backend"], None);
+    let second = create_test_item(&env, "260102-BBB", "Second", "open", &["backend"], None);
+
+    let yaml = export(&env, &["--label", "backend"]).replace("author: Test User", "author: Alice");
+    let file = write_items(&env, &yaml);
+
+    qs_cmd(&env)
+        .args(["bulk", "apply", file])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("+author: Alice"))
+        .stdout(predicate::str::contains("Updated 2 items"));
+
+    assert!(fs::read_to_string(first).unwrap().contains("author: Alice"));
+    assert!(fs::read_to_string(second)
+        .unwrap()
+        .contains("author: Alice"));
+}
+
+#[test]
+fn test_apply_unchanged_file_does_nothing() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "First", "open", &[], None);
+
+    let file = write_items(&env, &export(&env, &[]));
+
+    qs_cmd(&env)
+        .args(["bulk", "apply", file])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No changes to apply."));
+}
+
+#[test]
+fn test_apply_dry_run_leaves_items_unchanged() {
+    let env = setup();
+    let path = create_test_item(&env, "260101-AAA", "First", "open", &[], None);
+    let before = fs::read_to_string(&path).unwrap();
+
+    let yaml = export(&env, &[]).replace("title: First", "title: Renamed");
+    let file = write_items(&env, &yaml);
+
+    qs_cmd(&env)
+        .args(["bulk", "apply", file, "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("+title: Renamed"))
+        .stdout(predicate::str::contains("260101-AAA-renamed.md"));
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), before);
+}
+
+#[test]
+fn test_apply_renames_file_on_title_change() {
+    let env = setup();
+    let path = create_test_item(&env, "260101-AAA", "First", "open", &[], None);
+
+    let yaml = export(&env, &[]).replace("title: First", "title: Renamed");
+    let file = write_items(&env, &yaml);
+
+    qs_cmd(&env)
+        .args(["bulk", "apply", file])
+        .assert()
+        .success();
+
+    assert!(!path.exists());
+    assert!(env.stack_path().join("260101-AAA-renamed.md").exists());
+}
+
+#[test]
+fn test_apply_rejects_invalid_entries_without_changing_anything() {
+    let env = setup();
+    let path = create_test_item(&env, "260101-AAA", "First", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Second", "open", &[], None);
+    let before = fs::read_to_string(&path).unwrap();
+
+    let yaml = export(&env, &[])
+        .replace("title: First", "title: Renamed")
+        .replace("260102-BBB", "260102-ZZZ");
+    let file = write_items(&env, &yaml);
+
+    qs_cmd(&env)
+        .args(["bulk", "apply", file])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("260102-ZZZ: no item with this id"));
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), before);
+}
+
+#[test]
+fn test_apply_rejects_read_only_fields_and_closing() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "First", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Second", "open", &[], None);
+
+    let yaml = export(&env, &[]);
+    let (first, second) = yaml.split_once("- id: \"260102-BBB\"").unwrap();
+    let first = first.replace("2026-01-09T12:00:00Z", "2020-01-01T00:00:00Z");
+    let second = second.replace("status: open", "status: closed");
+    let file = write_items(&env, &format!("{first}- id: \"260102-BBB\"{second}"));
+
+    qs_cmd(&env)
+        .args(["bulk", "apply", file])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "260101-AAA: created_at can't be changed",
+        ))
+        .stderr(predicate::str::contains(
+            "260102-BBB: use 'qs close' to close items",
+        ));
+}
+
+#[test]
+fn test_apply_refuses_archived_items_without_force() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "First", "closed", &[], None);
+
+    let yaml = export(&env, &["--all"]).replace("title: First", "title: Renamed");
+    let file = write_items(&env, &yaml);
+
+    qs_cmd(&env)
+        .args(["bulk", "apply", file])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Cannot edit '260101-AAA'"));
+
+    qs_cmd(&env)
+        .args(["bulk", "apply", file, "--force"])
+        .assert()
+        .success();
+}
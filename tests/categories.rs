@@ -5,10 +5,14 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
 mod common;
 
 use common::{create_test_item, GlobalConfigBuilder, TestEnv};
-use queuestack::commands::{self, InteractiveArgs, ListMode, ListOptions, SortBy, StatusFilter};
+use queuestack::commands::{
+    self, InteractiveArgs, ListMode, ListOptions, OutputFormat, Scope, SortBy,
+};
 
 #[test]
 fn test_categories_empty_project() {
@@ -18,17 +22,33 @@ fn test_categories_empty_project() {
 
     let filter = ListOptions {
         mode: ListMode::Categories,
-        status: StatusFilter::Open,
+        scope: Scope::Open,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     let result = commands::list(&filter);
@@ -48,17 +68,33 @@ fn test_categories_shows_unique_categories() {
 
     let filter = ListOptions {
         mode: ListMode::Categories,
-        status: StatusFilter::Open,
+        scope: Scope::Open,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     let result = commands::list(&filter);
@@ -88,17 +124,33 @@ fn test_categories_includes_archived_items() {
 
     let filter = ListOptions {
         mode: ListMode::Categories,
-        status: StatusFilter::Open,
+        scope: Scope::Open,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     // Should include categories from both open and archived items
@@ -114,19 +166,101 @@ fn test_categories_without_init() {
 
     let filter = ListOptions {
         mode: ListMode::Categories,
-        status: StatusFilter::Open,
+        scope: Scope::Open,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     let result = commands::list(&filter);
     assert!(result.is_err(), "categories without init should fail");
 }
+
+// =============================================================================
+// Symlinked Directories
+// =============================================================================
+
+#[cfg(unix)]
+#[test]
+fn test_symlinked_stack_directory() {
+    use queuestack::{config::Config, storage};
+
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    // Move the stack to a "shared drive" and link it back into the project
+    let shared = tempfile::TempDir::new().unwrap();
+    let target = shared.path().join("queuestack");
+    std::fs::rename(env.stack_path(), &target).unwrap();
+    std::os::unix::fs::symlink(&target, env.stack_path()).unwrap();
+
+    create_test_item(&env, "260101-AAA", "Task 1", "open", &[], Some("bugs"));
+    let config = Config::load().unwrap();
+
+    let items: Vec<_> = storage::walk_items(&config).collect();
+    assert_eq!(items.len(), 1);
+    assert_eq!(
+        storage::derive_category(&config, &items[0]).as_deref(),
+        Some("bugs")
+    );
+
+    // Paths through the link target resolve the same way
+    let physical = target.join("bugs").join(items[0].file_name().unwrap());
+    assert_eq!(
+        storage::derive_category(&config, &physical).as_deref(),
+        Some("bugs")
+    );
+
+    let (archived, _) = storage::archive_item(&config, &physical).unwrap();
+    assert_eq!(
+        storage::derive_category(&config, &archived).as_deref(),
+        Some("bugs")
+    );
+    assert!(target.join(".archive/bugs").exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_symlinked_category_directory() {
+    use queuestack::{config::Config, storage};
+
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    let shared = tempfile::TempDir::new().unwrap();
+    std::os::unix::fs::symlink(shared.path(), env.stack_path().join("shared")).unwrap();
+    create_test_item(&env, "260101-AAA", "Task 1", "open", &[], Some("shared"));
+
+    let config = Config::load().unwrap();
+    let items: Vec<_> = storage::walk_items(&config).collect();
+    assert_eq!(items.len(), 1, "items in linked categories should be found");
+    assert_eq!(
+        storage::derive_category(&config, &items[0]).as_deref(),
+        Some("shared")
+    );
+}
@@ -0,0 +1,150 @@
+//! # Clean Command Tests
+//!
+//! Tests for removing empty directories from the stack (`qs clean`) and for
+//! the cleanup done when items move.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::fs;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::{
+    commands::{self, execute_close, execute_reopen, CleanArgs},
+    config::Config,
+    storage,
+};
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env
+}
+
+#[test]
+fn test_clean_removes_empty_dirs() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Keep", "open", &[], Some("bugs"));
+    let stale = [
+        env.stack_path().join("features"),
+        env.stack_path()
+            .join("bugs")
+            .join("260101-ZZZ-gone.attachments"),
+        env.archive_path().join("old").join("nested"),
+    ];
+    for dir in &stale {
+        fs::create_dir_all(dir).unwrap();
+    }
+    // A category holding only a generated vault index counts as empty
+    fs::create_dir_all(env.stack_path().join("ideas")).unwrap();
+    fs::write(
+        env.stack_path().join("ideas").join("_index.md"),
+        "# ideas\n",
+    )
+    .unwrap();
+
+    commands::clean(&CleanArgs { dry_run: false }).expect("clean");
+
+    for dir in &stale {
+        assert!(!dir.exists(), "{} should be removed", dir.display());
+    }
+    assert!(!env.archive_path().join("old").exists());
+    assert!(!env.stack_path().join("ideas").exists());
+    // Roots and non-empty directories stay
+    assert!(env.stack_path().join("bugs").exists());
+    assert!(env.archive_path().exists());
+    assert!(env.template_path().exists());
+}
+
+#[test]
+fn test_clean_leaves_other_hidden_dirs() {
+    let env = setup();
+    let obsidian = env.stack_path().join(".obsidian").join("plugins");
+    fs::create_dir_all(&obsidian).unwrap();
+
+    commands::clean(&CleanArgs { dry_run: false }).expect("clean");
+
+    assert!(obsidian.exists());
+}
+
+#[test]
+fn test_clean_dry_run_lists_without_removing() {
+    let env = setup();
+    fs::create_dir_all(env.stack_path().join("features")).unwrap();
+
+    qs_cmd(&env)
+        .args(["clean", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("queuestack/features"))
+        .stdout(predicate::str::contains("Would remove 1 empty directory"));
+    assert!(env.stack_path().join("features").exists());
+
+    qs_cmd(&env)
+        .arg("clean")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed 1 empty directory"));
+    assert!(!env.stack_path().join("features").exists());
+
+    qs_cmd(&env)
+        .arg("clean")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No empty directories."));
+}
+
+#[test]
+fn test_info_reports_empty_dirs() {
+    let env = setup();
+    fs::create_dir_all(env.stack_path().join("features")).unwrap();
+
+    qs_cmd(&env)
+        .arg("info")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "1 empty directory in the stack (run qs clean)",
+        ));
+}
+
+#[test]
+fn test_restore_from_trash_removes_empty_deletion_dir() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], Some("bugs"));
+
+    let config = Config::load().unwrap();
+    storage::delete_item(
+        &config,
+        &env.stack_path().join("bugs").join("260101-AAA-task.md"),
+    )
+    .expect("delete");
+    assert!(!env.stack_path().join("bugs").exists());
+
+    qs_cmd(&env)
+        .args(["trash", "restore", "260101-AAA"])
+        .assert()
+        .success();
+
+    assert_eq!(env.list_category_files("bugs").len(), 1);
+    assert!(storage::find_empty_dirs(&config).is_empty());
+}
+
+#[test]
+fn test_close_and_reopen_leave_no_empty_dirs() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], Some("bugs"));
+
+    execute_close(Some("260101".to_string()), None, None).unwrap();
+    execute_reopen(Some("260101".to_string()), None, Some("features")).unwrap();
+
+    let config = Config::load().unwrap();
+    assert!(storage::find_empty_dirs(&config).is_empty());
+    assert!(!env.archive_path().join("bugs").exists());
+}
@@ -5,6 +5,8 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
 mod common;
 
 use common::{create_test_item, create_test_item_with_attachments, GlobalConfigBuilder, TestEnv};
@@ -22,7 +24,7 @@ fn test_close_moves_to_archive() {
 
     create_test_item(&env, "260101-AAA", "Task to Close", "open", &[], None);
 
-    execute_close(Some("260101".to_string()), None).expect("close should succeed");
+    execute_close(Some("260101".to_string()), None, None).expect("close should succeed");
 
     let stack_files = env.list_stack_files();
     assert!(stack_files.is_empty(), "Stack should be empty");
@@ -46,7 +48,7 @@ fn test_close_item_with_category() {
 
     create_test_item(&env, "260101-AAA", "Bug Task", "open", &[], Some("bugs"));
 
-    execute_close(Some("260101".to_string()), None).expect("close should succeed");
+    execute_close(Some("260101".to_string()), None, None).expect("close should succeed");
 
     let category_files = env.list_category_files("bugs");
     assert!(category_files.is_empty(), "Category should be empty");
@@ -61,7 +63,7 @@ fn test_close_nonexistent_id() {
     env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
     commands::init().expect("init should succeed");
 
-    let result = execute_close(Some("999999".to_string()), None);
+    let result = execute_close(Some("999999".to_string()), None, None);
     assert!(result.is_err(), "close with nonexistent ID should fail");
 }
 
@@ -72,10 +74,10 @@ fn test_close_already_closed() {
     commands::init().expect("init should succeed");
 
     create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
-    execute_close(Some("260101".to_string()), None).expect("first close should succeed");
+    execute_close(Some("260101".to_string()), None, None).expect("first close should succeed");
 
     // Try to close again
-    let result = execute_close(Some("260101".to_string()), None);
+    let result = execute_close(Some("260101".to_string()), None, None);
     assert!(result.is_err(), "closing already closed item should fail");
 }
 
@@ -88,7 +90,7 @@ fn test_close_with_partial_id() {
     create_test_item(&env, "260101-ABCDEFG", "Task", "open", &[], None);
 
     // Close with minimal partial ID
-    let result = execute_close(Some("2601".to_string()), None);
+    let result = execute_close(Some("2601".to_string()), None, None);
     assert!(result.is_ok(), "close with partial ID should succeed");
 
     let archive_files = env.list_archive_files();
@@ -101,7 +103,7 @@ fn test_close_nonexistent_item() {
     env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
     commands::init().expect("init should succeed");
 
-    let result = execute_close(Some("nonexistent".to_string()), None);
+    let result = execute_close(Some("nonexistent".to_string()), None, None);
     assert!(result.is_err(), "close nonexistent item should fail");
 }
 
@@ -111,7 +113,7 @@ fn test_close_without_init() {
     env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
     // Don't call init
 
-    let result = execute_close(Some("260101".to_string()), None);
+    let result = execute_close(Some("260101".to_string()), None, None);
     assert!(result.is_err(), "close without init should fail");
 }
 
@@ -127,10 +129,10 @@ fn test_reopen_moves_from_archive() {
 
     // Create item and close it
     create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
-    execute_close(Some("260101".to_string()), None).expect("close should succeed");
+    execute_close(Some("260101".to_string()), None, None).expect("close should succeed");
 
     // Now reopen
-    execute_reopen(Some("260101".to_string()), None).expect("reopen should succeed");
+    execute_reopen(Some("260101".to_string()), None, None).expect("reopen should succeed");
 
     let stack_files = env.list_stack_files();
     assert_eq!(stack_files.len(), 1, "Stack should have one item");
@@ -151,10 +153,10 @@ fn test_reopen_restores_category() {
 
     // Create item with category and close it
     create_test_item(&env, "260101-AAA", "Bug Task", "open", &[], Some("bugs"));
-    execute_close(Some("260101".to_string()), None).expect("close should succeed");
+    execute_close(Some("260101".to_string()), None, None).expect("close should succeed");
 
     // Reopen - should restore to category
-    execute_reopen(Some("260101".to_string()), None).expect("reopen should succeed");
+    execute_reopen(Some("260101".to_string()), None, None).expect("reopen should succeed");
 
     let category_files = env.list_category_files("bugs");
     assert_eq!(category_files.len(), 1, "Item should be back in category");
@@ -169,7 +171,7 @@ fn test_reopen_already_open() {
     create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
 
     // Try to reopen an already open item
-    let result = execute_reopen(Some("260101".to_string()), None);
+    let result = execute_reopen(Some("260101".to_string()), None, None);
     assert!(result.is_err(), "reopening already open item should fail");
 }
 
@@ -180,10 +182,10 @@ fn test_reopen_with_partial_id() {
     commands::init().expect("init should succeed");
 
     create_test_item(&env, "260101-ABCDEFG", "Task", "open", &[], None);
-    execute_close(Some("260101".to_string()), None).expect("close should succeed");
+    execute_close(Some("260101".to_string()), None, None).expect("close should succeed");
 
     // Reopen with minimal partial ID
-    let result = execute_reopen(Some("2601".to_string()), None);
+    let result = execute_reopen(Some("2601".to_string()), None, None);
     assert!(result.is_ok(), "reopen with partial ID should succeed");
 
     let stack_files = env.list_stack_files();
@@ -196,7 +198,7 @@ fn test_reopen_nonexistent_item() {
     env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
     commands::init().expect("init should succeed");
 
-    let result = execute_reopen(Some("nonexistent".to_string()), None);
+    let result = execute_reopen(Some("nonexistent".to_string()), None, None);
     assert!(result.is_err(), "reopen nonexistent item should fail");
 }
 
@@ -206,7 +208,7 @@ fn test_reopen_without_init() {
     env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
     // Don't call init
 
-    let result = execute_reopen(Some("260101".to_string()), None);
+    let result = execute_reopen(Some("260101".to_string()), None, None);
     assert!(result.is_err(), "reopen without init should fail");
 }
 
@@ -225,8 +227,8 @@ fn test_close_and_reopen_preserves_labels() {
         None,
     );
 
-    execute_close(Some("260101".to_string()), None).expect("close should succeed");
-    execute_reopen(Some("260101".to_string()), None).expect("reopen should succeed");
+    execute_close(Some("260101".to_string()), None, None).expect("close should succeed");
+    execute_reopen(Some("260101".to_string()), None, None).expect("reopen should succeed");
 
     let item = env.find_item_by_id("260101").expect("item should exist");
     let content = env.read_item(&item);
@@ -254,7 +256,7 @@ fn test_close_moves_attachments_to_archive() {
     assert_eq!(env.list_attachment_files(item_id).len(), 1);
 
     // Close the item
-    execute_close(Some(item_id.to_string()), None).expect("close should succeed");
+    execute_close(Some(item_id.to_string()), None, None).expect("close should succeed");
 
     // Verify attachment moved to archive
     assert!(
@@ -278,8 +280,8 @@ fn test_reopen_moves_attachments_from_archive() {
     create_test_item_with_attachments(&env, item_id, "Test Item", "open", &["1-file.txt"], None);
 
     // Close and then reopen
-    execute_close(Some(item_id.to_string()), None).expect("close should succeed");
-    execute_reopen(Some(item_id.to_string()), None).expect("reopen should succeed");
+    execute_close(Some(item_id.to_string()), None, None).expect("close should succeed");
+    execute_reopen(Some(item_id.to_string()), None, None).expect("reopen should succeed");
 
     // Verify attachment is back in stack
     assert_eq!(
@@ -309,7 +311,7 @@ fn test_close_item_with_category_moves_attachments() {
         Some("bugs"),
     );
 
-    execute_close(Some(item_id.to_string()), None).expect("close should succeed");
+    execute_close(Some(item_id.to_string()), None, None).expect("close should succeed");
 
     assert_eq!(
         env.list_archive_attachment_files(item_id).len(),
@@ -317,3 +319,94 @@ fn test_close_item_with_category_moves_attachments() {
         "Attachment should be in archive"
     );
 }
+
+// =============================================================================
+// Category Override Tests
+// =============================================================================
+
+#[test]
+fn test_close_into_nested_archive_category() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+
+    let item_id = "260101-AAA";
+    create_test_item_with_attachments(
+        &env,
+        item_id,
+        "Bug Task",
+        "open",
+        &["1-file.txt"],
+        Some("bugs"),
+    );
+
+    execute_close(Some(item_id.to_string()), None, Some("Done/2026"))
+        .expect("close should succeed");
+
+    let dir = env.archive_path().join("done").join("2026");
+    assert!(dir.join("260101-AAA-bug-task.md").exists());
+    assert!(dir
+        .join("260101-AAA-bug-task.attachments")
+        .join("1-file.txt")
+        .exists());
+    assert!(!env.stack_path().join("bugs").exists());
+
+    // Nested archived items are still found
+    execute_reopen(Some(item_id.to_string()), None, Some("bugs")).expect("reopen should succeed");
+    assert_eq!(env.list_category_files("bugs").len(), 1);
+    assert!(env.list_archive_files().is_empty());
+}
+
+#[test]
+fn test_reopen_to_category() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], Some("bugs"));
+    execute_close(Some("260101".to_string()), None, None).unwrap();
+
+    execute_reopen(Some("260101".to_string()), None, Some("features"))
+        .expect("reopen should succeed");
+
+    assert!(env.list_category_files("bugs").is_empty());
+    let files = env.list_category_files("features");
+    assert_eq!(files.len(), 1);
+    assert!(env.read_item(&files[0]).contains("status: open"));
+}
+
+#[test]
+fn test_close_rejects_invalid_archive_category() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    for category in ["done//2026", "a/b/c"] {
+        let result = execute_close(Some("260101".to_string()), None, Some(category));
+        assert!(result.is_err(), "{category} should be rejected");
+    }
+
+    // The item is untouched
+    assert_eq!(env.list_stack_files().len(), 1);
+    assert!(env
+        .read_item(&env.list_stack_files()[0])
+        .contains("status: open"));
+}
+
+#[test]
+fn test_plan_reopen_to_category() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], Some("bugs"));
+    execute_close(Some("260101".to_string()), None, None).unwrap();
+
+    let plan =
+        commands::plan_reopen(Some("260101".to_string()), None, Some("features")).expect("plan");
+    let text = format!("{plan:?}");
+    assert!(text.contains("features"), "{text}");
+    assert_eq!(env.list_archive_files().len(), 1, "Nothing moved");
+}
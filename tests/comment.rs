@@ -0,0 +1,128 @@
+//! # Comment Command Tests
+//!
+//! Tests for adding comments with `qs comment` and listing them with
+//! `qs comments`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::fs;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env
+}
+
+#[test]
+fn test_comment_appends_section() {
+    let env = setup();
+    let path = create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["comment", "--id", "260101-AAA", "Seen on macOS too"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Commented on"));
+
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(content.contains("## Comment by Test User on "));
+    assert!(content.trim_end().ends_with("Seen on macOS too"));
+}
+
+#[test]
+fn test_comments_lists_in_order() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+
+    for text in ["First", "Second"] {
+        qs_cmd(&env)
+            .args(["comment", "--id", "260101-AAA", text])
+            .assert()
+            .success();
+    }
+
+    let output = qs_cmd(&env)
+        .args(["comments", "--id", "260101-AAA"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let first = stdout.find("  First").unwrap();
+    let second = stdout.find("  Second").unwrap();
+    assert!(first < second);
+    assert_eq!(stdout.matches("Test User").count(), 2);
+}
+
+#[test]
+fn test_comments_json() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+    qs_cmd(&env)
+        .args(["comment", "--id", "260101-AAA", "Note"])
+        .assert()
+        .success();
+
+    let output = qs_cmd(&env)
+        .args(["comments", "--id", "260101-AAA", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let comments: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(comments[0]["author"], "Test User");
+    assert_eq!(comments[0]["text"], "Note");
+    assert!(comments[0]["created_at"].is_string());
+}
+
+#[test]
+fn test_comments_empty() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["comments", "--id", "260101-AAA"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No comments."));
+}
+
+#[test]
+fn test_comment_archived_needs_force() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Crash", "closed", &[], None);
+    fs::rename(
+        env.stack_path().join("260101-AAA-crash.md"),
+        env.archive_path().join("260101-AAA-crash.md"),
+    )
+    .unwrap();
+
+    qs_cmd(&env)
+        .args(["comment", "--id", "260101-AAA", "Late note"])
+        .assert()
+        .failure();
+    qs_cmd(&env)
+        .args(["comment", "--id", "260101-AAA", "Late note", "--force"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_comment_empty_text_fails() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["comment", "--id", "260101-AAA", "  "])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be empty"));
+}
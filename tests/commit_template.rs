@@ -0,0 +1,111 @@
+//! # Commit Template Tests
+//!
+//! Tests for `qs commit-template` and `qs hooks install`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::process::Command;
+
+use common::{create_test_item, GlobalConfigBuilder, TestEnv};
+use queuestack::{
+    commands::{self, commit_template, HooksInstallArgs, PatchArgs},
+    config::Config,
+};
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+    env
+}
+
+fn claim(id: &str) {
+    commands::patch(&PatchArgs {
+        id: Some(id.to_string()),
+        file: None,
+        set: vec!["status=in-progress".to_string()],
+        patch_file: None,
+        force: false,
+    })
+    .expect("patch should succeed");
+}
+
+#[test]
+fn test_commit_prefix_format() {
+    assert_eq!(
+        commit_template::commit_prefix("260109-0A2B3C4"),
+        "[260109-0A2B3C4] "
+    );
+}
+
+#[test]
+fn test_in_progress_item_found() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "One", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Two", "open", &[], None);
+    claim("260102");
+
+    let config = Config::load().unwrap();
+    let item = commit_template::in_progress_item(&config).expect("should find item");
+    assert_eq!(item.id(), "260102-BBB");
+
+    let content = env.read_item(&env.find_item_by_id("260102").unwrap());
+    assert!(content.contains("status: in-progress"));
+}
+
+#[test]
+fn test_in_progress_item_none_or_ambiguous() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "One", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Two", "open", &[], None);
+    let config = Config::load().unwrap();
+
+    let err = commit_template::in_progress_item(&config).unwrap_err();
+    assert!(err.to_string().contains("No item is in progress"));
+
+    claim("260101");
+    claim("260102");
+    let err = commit_template::in_progress_item(&config).unwrap_err();
+    assert!(err.to_string().contains("Multiple items"));
+}
+
+#[test]
+fn test_in_progress_item_can_be_closed() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "One", "open", &[], None);
+    claim("260101");
+
+    commands::execute_close(Some("260101".to_string()), None, None).expect("close should succeed");
+    assert_eq!(env.list_archive_files().len(), 1);
+}
+
+#[test]
+fn test_hooks_install_writes_prepare_commit_msg() {
+    let env = setup();
+    let git_ok = Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(env.project_path())
+        .status()
+        .is_ok_and(|s| s.success());
+    if !git_ok {
+        return; // git not available
+    }
+
+    commands::hooks_install(&HooksInstallArgs { force: false }).expect("install should succeed");
+    let hook = env.project_path().join(".git/hooks/prepare-commit-msg");
+    let content = std::fs::read_to_string(&hook).expect("hook should exist");
+    assert!(content.contains("qs commit-template"));
+
+    // Reinstalling over our own hook is fine
+    commands::hooks_install(&HooksInstallArgs { force: false }).expect("reinstall should succeed");
+
+    // Foreign hooks are only replaced with --force
+    std::fs::write(&hook, "#!/bin/sh\necho custom\n").unwrap();
+    assert!(commands::hooks_install(&HooksInstallArgs { force: false }).is_err());
+    commands::hooks_install(&HooksInstallArgs { force: true }).expect("force should succeed");
+}
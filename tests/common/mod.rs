@@ -7,12 +7,18 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+// Each test crate uses a different subset of the harness.
+#![allow(dead_code)]
+
 use std::{
-    env, fs,
+    env,
+    fmt::Write as _,
+    fs,
     path::{Path, PathBuf},
     sync::Mutex,
 };
 
+use assert_cmd::Command;
 use tempfile::TempDir;
 
 // Re-export from library - this is the mechanism for test isolation
@@ -43,7 +49,9 @@ impl TestEnv {
     /// Changes to the project directory for the duration of the test.
     pub fn new() -> Self {
         // Recover from poisoned mutex (if a previous test panicked while holding the lock)
-        let test_guard = TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let test_guard = TEST_LOCK
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
 
         let home_dir = TempDir::new().expect("Failed to create temp home dir");
         let project_dir = TempDir::new().expect("Failed to create temp project dir");
@@ -118,21 +126,21 @@ impl TestEnv {
 
     /// Lists all files in the stack directory (non-recursive).
     pub fn list_stack_files(&self) -> Vec<PathBuf> {
-        self.list_files_in(&self.stack_path())
+        Self::list_files_in(&self.stack_path())
     }
 
     /// Lists all files in the archive directory (recursive, including categories).
     pub fn list_archive_files(&self) -> Vec<PathBuf> {
-        self.list_files_recursive(&self.archive_path())
+        Self::list_files_recursive(&self.archive_path())
     }
 
     /// Lists all files in the template directory (recursive, including categories).
     pub fn list_template_files(&self) -> Vec<PathBuf> {
-        self.list_files_recursive(&self.template_path())
+        Self::list_files_recursive(&self.template_path())
     }
 
     /// Lists all .md files in a directory recursively.
-    fn list_files_recursive(&self, dir: &Path) -> Vec<PathBuf> {
+    fn list_files_recursive(dir: &Path) -> Vec<PathBuf> {
         if !dir.exists() {
             return Vec::new();
         }
@@ -142,12 +150,12 @@ impl TestEnv {
             .filter_map(Result::ok)
             .filter(|e| e.file_type().is_file())
             .filter(|e| e.path().extension().is_some_and(|ext| ext == "md"))
-            .map(|e| e.into_path())
+            .map(walkdir::DirEntry::into_path)
             .collect()
     }
 
     /// Lists all .md files in a directory (non-recursive).
-    fn list_files_in(&self, dir: &Path) -> Vec<PathBuf> {
+    fn list_files_in(dir: &Path) -> Vec<PathBuf> {
         if !dir.exists() {
             return Vec::new();
         }
@@ -161,17 +169,16 @@ impl TestEnv {
 
     /// Lists all .md files in a category subdirectory.
     pub fn list_category_files(&self, category: &str) -> Vec<PathBuf> {
-        self.list_files_in(&self.stack_path().join(category))
+        Self::list_files_in(&self.stack_path().join(category))
     }
 
     /// Counts total items across all locations.
     pub fn count_all_items(&self) -> usize {
-        let stack_count = self.count_items_recursive(&self.stack_path());
-        stack_count
+        Self::count_items_recursive(&self.stack_path())
     }
 
     /// Counts .md files recursively in a directory.
-    fn count_items_recursive(&self, dir: &Path) -> usize {
+    fn count_items_recursive(dir: &Path) -> usize {
         if !dir.exists() {
             return 0;
         }
@@ -184,6 +191,7 @@ impl TestEnv {
     }
 
     /// Reads an item file by its path and returns the content.
+    #[allow(clippy::unused_self)] // Reads naturally as `env.read_item(..)` in tests
     pub fn read_item(&self, path: &Path) -> String {
         fs::read_to_string(path).expect("Failed to read item file")
     }
@@ -195,7 +203,7 @@ impl TestEnv {
             .filter_map(Result::ok)
             .filter(|e| e.file_type().is_file())
             .filter(|e| e.path().extension().is_some_and(|ext| ext == "md"))
-            .map(|e| e.into_path())
+            .map(walkdir::DirEntry::into_path)
             .find(|p| {
                 p.file_name()
                     .and_then(|n| n.to_str())
@@ -214,6 +222,23 @@ impl Drop for TestEnv {
     }
 }
 
+/// Creates a qs command configured to run in the test environment.
+pub fn qs_cmd(env: &TestEnv) -> Command {
+    let mut cmd = Command::cargo_bin("qs").unwrap();
+    cmd.current_dir(env.project_dir.path());
+    cmd.env("HOME", env.home_dir.path());
+    cmd
+}
+
+/// Creates a qs command like [`qs_cmd`], with stand-in tools from `bin`
+/// first on `PATH`.
+pub fn qs_cmd_with_path(env: &TestEnv, bin: &Path) -> Command {
+    let mut cmd = qs_cmd(env);
+    let path = env::var("PATH").unwrap_or_default();
+    cmd.env("PATH", format!("{}:{path}", bin.display()));
+    cmd
+}
+
 // =============================================================================
 // Test Setup Helpers
 // =============================================================================
@@ -251,7 +276,7 @@ pub fn setup_test_env_non_interactive() -> TestEnv {
 struct ConfigLines(Vec<String>);
 
 impl ConfigLines {
-    fn new() -> Self {
+    const fn new() -> Self {
         Self(Vec::new())
     }
 
@@ -261,26 +286,187 @@ impl ConfigLines {
         }
     }
 
+    fn add_number(&mut self, key: &str, value: Option<u32>) {
+        if let Some(v) = value {
+            self.0.push(format!("{key} = {v}"));
+        }
+    }
+
     fn add_bool(&mut self, key: &str, value: Option<bool>) {
         if let Some(v) = value {
             self.0.push(format!("{key} = {v}"));
         }
     }
 
+    /// Adds the `[aliases]` table (must come after all plain keys).
+    fn add_aliases(&mut self, aliases: &[String]) {
+        if !aliases.is_empty() {
+            self.0.push("\n[aliases]".to_string());
+            self.0.extend(aliases.iter().cloned());
+        }
+    }
+
+    /// Adds the `[defaults.<command>]` tables (must come after all plain keys).
+    fn add_defaults(&mut self, defaults: &[String]) {
+        self.0.extend(defaults.iter().cloned());
+    }
+
+    /// Adds the `[status_dirs]` table (must come after all plain keys).
+    fn add_status_dirs(&mut self, entries: &[String]) {
+        if !entries.is_empty() {
+            self.0.push("\n[status_dirs]".to_string());
+            self.0.extend(entries.iter().cloned());
+        }
+    }
+
+    /// Adds the `[slug_transliterations]` table (must come after all plain keys).
+    fn add_slug_transliterations(&mut self, entries: &[String]) {
+        if !entries.is_empty() {
+            self.0.push("\n[slug_transliterations]".to_string());
+            self.0.extend(entries.iter().cloned());
+        }
+    }
+
+    /// Adds the `[mirrors]` table (must come after all plain keys).
+    fn add_mirrors(&mut self, mirrors: &[String]) {
+        if !mirrors.is_empty() {
+            self.0.push("\n[mirrors]".to_string());
+            self.0.extend(mirrors.iter().cloned());
+        }
+    }
+
+    /// Adds a preformatted table such as `[protected]` (must come after all plain keys).
+    fn add_table(&mut self, table: Option<&str>) {
+        if let Some(table) = table {
+            self.0.push(table.to_string());
+        }
+    }
+
+    /// Adds the `[identities]` table (must come after all plain keys).
+    fn add_identities(&mut self, identities: &[String]) {
+        if !identities.is_empty() {
+            self.0.push("\n[identities]".to_string());
+            self.0.extend(identities.iter().cloned());
+        }
+    }
+
+    /// Adds `[profiles.<name>]` tables (must come after all plain keys).
+    fn add_profiles(&mut self, profiles: &[String]) {
+        self.0.extend(profiles.iter().cloned());
+    }
+
+    /// Adds `[[rules]]` tables (must come after all plain keys).
+    fn add_rules(&mut self, rules: &[String]) {
+        self.0.extend(rules.iter().cloned());
+    }
+
     fn build(self) -> String {
         self.0.join("\n")
     }
 }
 
+/// Formats a `[[rules]]` table for the config builders.
+fn format_rule(title: &str, labels: &[&str], set: &[(&str, &str)]) -> String {
+    let labels: Vec<String> = labels.iter().map(|l| format!("\"{l}\"")).collect();
+    let set: Vec<String> = set.iter().map(|(k, v)| format!("{k} = \"{v}\"")).collect();
+    format!(
+        "\n[[rules]]\ntitle = \"{title}\"\nlabels = [{}]\nset = {{ {} }}",
+        labels.join(", "),
+        set.join(", ")
+    )
+}
+
+/// Formats a `[protected]` table for the config builders.
+fn format_protected(categories: &[&str], labels: &[&str], allow: &[&str]) -> String {
+    let list = |values: &[&str]| {
+        values
+            .iter()
+            .map(|v| format!("\"{v}\""))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    format!(
+        "\n[protected]\ncategories = [{}]\nlabels = [{}]\nallow = [{}]",
+        list(categories),
+        list(labels),
+        list(allow)
+    )
+}
+
+/// Formats an `[attachment_policy]` table for the config builders.
+fn format_attachment_policy(
+    max_size: Option<&str>,
+    extensions: &[&str],
+    scanner: Option<&str>,
+) -> String {
+    let mut table = "\n[attachment_policy]".to_string();
+    if let Some(max_size) = max_size {
+        let _ = write!(table, "\nmax_size = \"{max_size}\"");
+    }
+    let extensions: Vec<String> = extensions.iter().map(|e| format!("\"{e}\"")).collect();
+    let _ = write!(table, "\nextensions = [{}]", extensions.join(", "));
+    if let Some(scanner) = scanner {
+        let _ = write!(table, "\nscanner = '{scanner}'");
+    }
+    table
+}
+
+/// Formats an `[identities]` entry for the config builders.
+fn format_identity(canonical: &str, aliases: &[&str]) -> String {
+    let aliases: Vec<String> = aliases.iter().map(|a| format!("\"{a}\"")).collect();
+    format!("\"{canonical}\" = [{}]", aliases.join(", "))
+}
+
+/// Formats a `[profiles.<name>]` table for the global config builder.
+fn format_profile(name: &str, values: &[(&str, &str)]) -> String {
+    let values: Vec<String> = values
+        .iter()
+        .map(|(k, v)| format!("{k} = \"{v}\""))
+        .collect();
+    format!("\n[profiles.{name}]\n{}", values.join("\n"))
+}
+
 /// Builder for creating test configurations.
 pub struct GlobalConfigBuilder {
     user_name: Option<String>,
     use_git_user: bool,
     editor: Option<String>,
     interactive: bool,
+    picker: Option<String>,
     id_pattern: String,
     stack_dir: Option<String>,
     archive_dir: Option<String>,
+    archive_layout: Option<String>,
+    obsidian: Option<bool>,
+    pre_create: Option<String>,
+    pre_close: Option<String>,
+    agent_command: Option<String>,
+    link_base: Option<String>,
+    attachment_path: Option<String>,
+    s3_url: Option<String>,
+    attachment_dedup: Option<bool>,
+    attachment_directories: Option<String>,
+    fetch_link_titles: Option<bool>,
+    trash_days: Option<u32>,
+    pure_reads: Option<bool>,
+    max_body_words: Option<u32>,
+    date_format: Option<String>,
+    frontmatter_format: Option<String>,
+    slug_style: Option<String>,
+    vcs: Option<String>,
+    slug_transliterations: Vec<String>,
+    status_dirs: Vec<String>,
+    usage_log: Option<bool>,
+    profile: Option<String>,
+    nested_projects: Option<String>,
+    aliases: Vec<String>,
+    defaults: Vec<String>,
+    mirrors: Vec<String>,
+    protected: Option<String>,
+    attachment_policy: Option<String>,
+    identities: Vec<String>,
+    profiles: Vec<String>,
+    rules: Vec<String>,
 }
 
 impl Default for GlobalConfigBuilder {
@@ -290,9 +476,41 @@ impl Default for GlobalConfigBuilder {
             use_git_user: false,
             editor: Some("true".to_string()), // no-op editor
             interactive: true,
+            picker: None,
             id_pattern: "%y%m%d-%T%RRR".to_string(),
             stack_dir: None,
             archive_dir: None,
+            archive_layout: None,
+            obsidian: None,
+            pre_create: None,
+            pre_close: None,
+            agent_command: None,
+            link_base: None,
+            attachment_path: None,
+            s3_url: None,
+            attachment_dedup: None,
+            attachment_directories: None,
+            fetch_link_titles: None,
+            trash_days: None,
+            pure_reads: None,
+            max_body_words: None,
+            date_format: None,
+            frontmatter_format: None,
+            slug_style: None,
+            vcs: None,
+            slug_transliterations: Vec::new(),
+            status_dirs: Vec::new(),
+            usage_log: None,
+            profile: None,
+            nested_projects: None,
+            aliases: Vec::new(),
+            defaults: Vec::new(),
+            mirrors: Vec::new(),
+            protected: None,
+            attachment_policy: None,
+            identities: Vec::new(),
+            profiles: Vec::new(),
+            rules: Vec::new(),
         }
     }
 }
@@ -307,7 +525,7 @@ impl GlobalConfigBuilder {
         self
     }
 
-    pub fn use_git_user(mut self, use_git: bool) -> Self {
+    pub const fn use_git_user(mut self, use_git: bool) -> Self {
         self.use_git_user = use_git;
         self
     }
@@ -317,11 +535,16 @@ impl GlobalConfigBuilder {
         self
     }
 
-    pub fn interactive(mut self, interactive: bool) -> Self {
+    pub const fn interactive(mut self, interactive: bool) -> Self {
         self.interactive = interactive;
         self
     }
 
+    pub fn picker(mut self, picker: impl Into<String>) -> Self {
+        self.picker = Some(picker.into());
+        self
+    }
+
     pub fn id_pattern(mut self, pattern: impl Into<String>) -> Self {
         self.id_pattern = pattern.into();
         self
@@ -337,15 +560,227 @@ impl GlobalConfigBuilder {
         self
     }
 
+    pub fn archive_layout(mut self, layout: impl Into<String>) -> Self {
+        self.archive_layout = Some(layout.into());
+        self
+    }
+
+    pub const fn obsidian(mut self, enabled: bool) -> Self {
+        self.obsidian = Some(enabled);
+        self
+    }
+
+    pub fn pre_create(mut self, script: impl Into<String>) -> Self {
+        self.pre_create = Some(script.into());
+        self
+    }
+
+    pub fn pre_close(mut self, script: impl Into<String>) -> Self {
+        self.pre_close = Some(script.into());
+        self
+    }
+
+    pub fn agent_command(mut self, command: impl Into<String>) -> Self {
+        self.agent_command = Some(command.into());
+        self
+    }
+
+    pub fn link_base(mut self, template: impl Into<String>) -> Self {
+        self.link_base = Some(template.into());
+        self
+    }
+
+    pub fn attachment_path(mut self, path: impl Into<String>) -> Self {
+        self.attachment_path = Some(path.into());
+        self
+    }
+
+    pub fn s3_url(mut self, url: impl Into<String>) -> Self {
+        self.s3_url = Some(url.into());
+        self
+    }
+
+    pub const fn attachment_dedup(mut self, enabled: bool) -> Self {
+        self.attachment_dedup = Some(enabled);
+        self
+    }
+
+    pub fn attachment_directories(mut self, mode: impl Into<String>) -> Self {
+        self.attachment_directories = Some(mode.into());
+        self
+    }
+
+    pub const fn fetch_link_titles(mut self, enabled: bool) -> Self {
+        self.fetch_link_titles = Some(enabled);
+        self
+    }
+
+    pub const fn trash_days(mut self, days: u32) -> Self {
+        self.trash_days = Some(days);
+        self
+    }
+
+    pub const fn pure_reads(mut self, enabled: bool) -> Self {
+        self.pure_reads = Some(enabled);
+        self
+    }
+
+    pub const fn max_body_words(mut self, words: u32) -> Self {
+        self.max_body_words = Some(words);
+        self
+    }
+
+    pub fn date_format(mut self, format: impl Into<String>) -> Self {
+        self.date_format = Some(format.into());
+        self
+    }
+
+    pub fn frontmatter_format(mut self, format: impl Into<String>) -> Self {
+        self.frontmatter_format = Some(format.into());
+        self
+    }
+
+    pub fn slug_style(mut self, style: impl Into<String>) -> Self {
+        self.slug_style = Some(style.into());
+        self
+    }
+
+    pub fn vcs(mut self, vcs: impl Into<String>) -> Self {
+        self.vcs = Some(vcs.into());
+        self
+    }
+
+    /// Adds a title replacement (`"from" = "to"` in the `[slug_transliterations]` table).
+    pub fn slug_transliteration(mut self, from: &str, to: &str) -> Self {
+        self.slug_transliterations
+            .push(format!("\"{from}\" = \"{to}\""));
+        self
+    }
+
+    /// Sets the directory items of a status live in (`status = "dir"` in the `[status_dirs]` table).
+    pub fn status_dir(mut self, status: &str, dir: &str) -> Self {
+        self.status_dirs.push(format!("\"{status}\" = \"{dir}\""));
+        self
+    }
+
+    /// Enables the local usage log read by `qs insights`.
+    pub const fn usage_log(mut self, enabled: bool) -> Self {
+        self.usage_log = Some(enabled);
+        self
+    }
+
+    pub fn profile(mut self, name: impl Into<String>) -> Self {
+        self.profile = Some(name.into());
+        self
+    }
+
+    pub fn nested_projects(mut self, policy: impl Into<String>) -> Self {
+        self.nested_projects = Some(policy.into());
+        self
+    }
+
+    /// Defines a command alias (`name = "expansion"` in the `[aliases]` table).
+    pub fn alias(mut self, name: &str, expansion: &str) -> Self {
+        self.aliases.push(format!("{name} = \"{expansion}\""));
+        self
+    }
+
+    /// Sets default options of a command (`[defaults."<command>"]`); values are TOML.
+    pub fn defaults(mut self, command: &str, options: &[(&str, &str)]) -> Self {
+        self.defaults.push(format!("\n[defaults.\"{command}\"]"));
+        self.defaults.extend(
+            options
+                .iter()
+                .map(|(key, value)| format!("{key} = {value}")),
+        );
+        self
+    }
+
+    /// Registers a read-only mirror (`name = "source"` in the `[mirrors]` table).
+    pub fn mirror(mut self, name: &str, source: &str) -> Self {
+        self.mirrors.push(format!("{name} = \"{source}\""));
+        self
+    }
+
+    /// Sets the `[protected]` categories, labels, and allowed users.
+    pub fn protected(mut self, categories: &[&str], labels: &[&str], allow: &[&str]) -> Self {
+        self.protected = Some(format_protected(categories, labels, allow));
+        self
+    }
+
+    /// Sets the `[attachment_policy]` size limit, allowed extensions, and scanner.
+    pub fn attachment_policy(
+        mut self,
+        max_size: Option<&str>,
+        extensions: &[&str],
+        scanner: Option<&str>,
+    ) -> Self {
+        self.attachment_policy = Some(format_attachment_policy(max_size, extensions, scanner));
+        self
+    }
+
+    /// Defines a named profile with `user_name`, `editor`, or `id_pattern` values.
+    pub fn define_profile(mut self, name: &str, values: &[(&str, &str)]) -> Self {
+        self.profiles.push(format_profile(name, values));
+        self
+    }
+
+    /// Maps alternate spellings to a canonical author identity.
+    pub fn identity(mut self, canonical: &str, aliases: &[&str]) -> Self {
+        self.identities.push(format_identity(canonical, aliases));
+        self
+    }
+
+    /// Adds an auto-labeling rule matching `title`.
+    pub fn rule(mut self, title: &str, labels: &[&str], set: &[(&str, &str)]) -> Self {
+        self.rules.push(format_rule(title, labels, set));
+        self
+    }
+
     pub fn build(&self) -> String {
         let mut lines = ConfigLines::new();
         lines.add_string("user_name", self.user_name.as_deref());
         lines.add_bool("use_git_user", Some(self.use_git_user));
         lines.add_string("editor", self.editor.as_deref());
         lines.add_bool("interactive", Some(self.interactive));
+        lines.add_string("picker", self.picker.as_deref());
         lines.add_string("id_pattern", Some(&self.id_pattern));
         lines.add_string("stack_dir", self.stack_dir.as_deref());
         lines.add_string("archive_dir", self.archive_dir.as_deref());
+        lines.add_string("archive_layout", self.archive_layout.as_deref());
+        lines.add_bool("obsidian", self.obsidian);
+        lines.add_string("pre_create", self.pre_create.as_deref());
+        lines.add_string("pre_close", self.pre_close.as_deref());
+        lines.add_string("agent_command", self.agent_command.as_deref());
+        lines.add_string("link_base", self.link_base.as_deref());
+        lines.add_string("attachment_path", self.attachment_path.as_deref());
+        lines.add_string("s3_url", self.s3_url.as_deref());
+        lines.add_bool("attachment_dedup", self.attachment_dedup);
+        lines.add_string(
+            "attachment_directories",
+            self.attachment_directories.as_deref(),
+        );
+        lines.add_bool("fetch_link_titles", self.fetch_link_titles);
+        lines.add_number("trash_days", self.trash_days);
+        lines.add_bool("pure_reads", self.pure_reads);
+        lines.add_number("max_body_words", self.max_body_words);
+        lines.add_string("date_format", self.date_format.as_deref());
+        lines.add_string("frontmatter_format", self.frontmatter_format.as_deref());
+        lines.add_string("slug_style", self.slug_style.as_deref());
+        lines.add_string("vcs", self.vcs.as_deref());
+        lines.add_bool("usage_log", self.usage_log);
+        lines.add_string("profile", self.profile.as_deref());
+        lines.add_string("nested_projects", self.nested_projects.as_deref());
+        lines.add_aliases(&self.aliases);
+        lines.add_defaults(&self.defaults);
+        lines.add_mirrors(&self.mirrors);
+        lines.add_slug_transliterations(&self.slug_transliterations);
+        lines.add_status_dirs(&self.status_dirs);
+        lines.add_table(self.protected.as_deref());
+        lines.add_table(self.attachment_policy.as_deref());
+        lines.add_identities(&self.identities);
+        lines.add_profiles(&self.profiles);
+        lines.add_rules(&self.rules);
         lines.build()
     }
 }
@@ -358,9 +793,43 @@ pub struct ProjectConfigBuilder {
     use_git_user: Option<bool>,
     editor: Option<String>,
     interactive: Option<bool>,
+    picker: Option<String>,
     id_pattern: Option<String>,
     stack_dir: Option<String>,
     archive_dir: Option<String>,
+    archive_layout: Option<String>,
+    obsidian: Option<bool>,
+    pre_create: Option<String>,
+    pre_close: Option<String>,
+    agent_command: Option<String>,
+    link_base: Option<String>,
+    attachment_path: Option<String>,
+    s3_url: Option<String>,
+    attachment_dedup: Option<bool>,
+    attachment_directories: Option<String>,
+    fetch_link_titles: Option<bool>,
+    trash_days: Option<u32>,
+    pure_reads: Option<bool>,
+    max_body_words: Option<u32>,
+    date_format: Option<String>,
+    frontmatter_format: Option<String>,
+    slug_style: Option<String>,
+    fold_accents: Option<bool>,
+    vcs: Option<String>,
+    slug_transliterations: Vec<String>,
+    status_dirs: Vec<String>,
+    profile: Option<String>,
+    nested_projects: Option<String>,
+    aliases: Vec<String>,
+    defaults: Vec<String>,
+    mirrors: Vec<String>,
+    protected: Option<String>,
+    attachment_policy: Option<String>,
+    label_policy: Option<String>,
+    label_defs: Vec<String>,
+    github: Option<String>,
+    identities: Vec<String>,
+    rules: Vec<String>,
 }
 
 impl Default for ProjectConfigBuilder {
@@ -370,9 +839,43 @@ impl Default for ProjectConfigBuilder {
             use_git_user: None,
             editor: None,
             interactive: None,
+            picker: None,
             id_pattern: None,
             stack_dir: Some("queuestack".to_string()),
             archive_dir: Some("archive".to_string()),
+            archive_layout: None,
+            obsidian: None,
+            pre_create: None,
+            pre_close: None,
+            agent_command: None,
+            link_base: None,
+            attachment_path: None,
+            s3_url: None,
+            attachment_dedup: None,
+            attachment_directories: None,
+            fetch_link_titles: None,
+            trash_days: None,
+            pure_reads: None,
+            max_body_words: None,
+            date_format: None,
+            frontmatter_format: None,
+            slug_style: None,
+            fold_accents: None,
+            vcs: None,
+            slug_transliterations: Vec::new(),
+            status_dirs: Vec::new(),
+            profile: None,
+            nested_projects: None,
+            aliases: Vec::new(),
+            defaults: Vec::new(),
+            mirrors: Vec::new(),
+            protected: None,
+            attachment_policy: None,
+            label_policy: None,
+            label_defs: Vec::new(),
+            github: None,
+            identities: Vec::new(),
+            rules: Vec::new(),
         }
     }
 }
@@ -388,7 +891,7 @@ impl ProjectConfigBuilder {
     }
 
     #[allow(dead_code)]
-    pub fn use_git_user(mut self, use_git: bool) -> Self {
+    pub const fn use_git_user(mut self, use_git: bool) -> Self {
         self.use_git_user = Some(use_git);
         self
     }
@@ -400,11 +903,16 @@ impl ProjectConfigBuilder {
     }
 
     #[allow(dead_code)]
-    pub fn interactive(mut self, interactive: bool) -> Self {
+    pub const fn interactive(mut self, interactive: bool) -> Self {
         self.interactive = Some(interactive);
         self
     }
 
+    pub fn picker(mut self, picker: impl Into<String>) -> Self {
+        self.picker = Some(picker.into());
+        self
+    }
+
     pub fn id_pattern(mut self, pattern: impl Into<String>) -> Self {
         self.id_pattern = Some(pattern.into());
         self
@@ -420,15 +928,271 @@ impl ProjectConfigBuilder {
         self
     }
 
+    pub fn archive_layout(mut self, layout: impl Into<String>) -> Self {
+        self.archive_layout = Some(layout.into());
+        self
+    }
+
+    pub const fn obsidian(mut self, enabled: bool) -> Self {
+        self.obsidian = Some(enabled);
+        self
+    }
+
+    pub fn pre_create(mut self, script: impl Into<String>) -> Self {
+        self.pre_create = Some(script.into());
+        self
+    }
+
+    pub fn pre_close(mut self, script: impl Into<String>) -> Self {
+        self.pre_close = Some(script.into());
+        self
+    }
+
+    pub fn agent_command(mut self, command: impl Into<String>) -> Self {
+        self.agent_command = Some(command.into());
+        self
+    }
+
+    pub fn link_base(mut self, template: impl Into<String>) -> Self {
+        self.link_base = Some(template.into());
+        self
+    }
+
+    pub fn attachment_path(mut self, path: impl Into<String>) -> Self {
+        self.attachment_path = Some(path.into());
+        self
+    }
+
+    pub fn s3_url(mut self, url: impl Into<String>) -> Self {
+        self.s3_url = Some(url.into());
+        self
+    }
+
+    pub const fn attachment_dedup(mut self, enabled: bool) -> Self {
+        self.attachment_dedup = Some(enabled);
+        self
+    }
+
+    pub fn attachment_directories(mut self, mode: impl Into<String>) -> Self {
+        self.attachment_directories = Some(mode.into());
+        self
+    }
+
+    pub const fn fetch_link_titles(mut self, enabled: bool) -> Self {
+        self.fetch_link_titles = Some(enabled);
+        self
+    }
+
+    pub const fn trash_days(mut self, days: u32) -> Self {
+        self.trash_days = Some(days);
+        self
+    }
+
+    pub const fn pure_reads(mut self, enabled: bool) -> Self {
+        self.pure_reads = Some(enabled);
+        self
+    }
+
+    pub const fn max_body_words(mut self, words: u32) -> Self {
+        self.max_body_words = Some(words);
+        self
+    }
+
+    pub fn date_format(mut self, format: impl Into<String>) -> Self {
+        self.date_format = Some(format.into());
+        self
+    }
+
+    pub fn frontmatter_format(mut self, format: impl Into<String>) -> Self {
+        self.frontmatter_format = Some(format.into());
+        self
+    }
+
+    pub fn slug_style(mut self, style: impl Into<String>) -> Self {
+        self.slug_style = Some(style.into());
+        self
+    }
+
+    pub const fn fold_accents(mut self, enabled: bool) -> Self {
+        self.fold_accents = Some(enabled);
+        self
+    }
+
+    pub fn vcs(mut self, vcs: impl Into<String>) -> Self {
+        self.vcs = Some(vcs.into());
+        self
+    }
+
+    /// Adds a title replacement (`"from" = "to"` in the `[slug_transliterations]` table).
+    pub fn slug_transliteration(mut self, from: &str, to: &str) -> Self {
+        self.slug_transliterations
+            .push(format!("\"{from}\" = \"{to}\""));
+        self
+    }
+
+    /// Sets the directory items of a status live in (`status = "dir"` in the `[status_dirs]` table).
+    pub fn status_dir(mut self, status: &str, dir: &str) -> Self {
+        self.status_dirs.push(format!("\"{status}\" = \"{dir}\""));
+        self
+    }
+
+    pub fn profile(mut self, name: impl Into<String>) -> Self {
+        self.profile = Some(name.into());
+        self
+    }
+
+    pub fn nested_projects(mut self, policy: impl Into<String>) -> Self {
+        self.nested_projects = Some(policy.into());
+        self
+    }
+
+    /// Defines a command alias (`name = "expansion"` in the `[aliases]` table).
+    pub fn alias(mut self, name: &str, expansion: &str) -> Self {
+        self.aliases.push(format!("{name} = \"{expansion}\""));
+        self
+    }
+
+    /// Sets default options of a command (`[defaults."<command>"]`); values are TOML.
+    pub fn defaults(mut self, command: &str, options: &[(&str, &str)]) -> Self {
+        self.defaults.push(format!("\n[defaults.\"{command}\"]"));
+        self.defaults.extend(
+            options
+                .iter()
+                .map(|(key, value)| format!("{key} = {value}")),
+        );
+        self
+    }
+
+    /// Registers a read-only mirror (`name = "source"` in the `[mirrors]` table).
+    pub fn mirror(mut self, name: &str, source: &str) -> Self {
+        self.mirrors.push(format!("{name} = \"{source}\""));
+        self
+    }
+
+    /// Sets the `[protected]` categories, labels, and allowed users.
+    pub fn protected(mut self, categories: &[&str], labels: &[&str], allow: &[&str]) -> Self {
+        self.protected = Some(format_protected(categories, labels, allow));
+        self
+    }
+
+    /// Sets the `[attachment_policy]` size limit, allowed extensions, and scanner.
+    pub fn attachment_policy(
+        mut self,
+        max_size: Option<&str>,
+        extensions: &[&str],
+        scanner: Option<&str>,
+    ) -> Self {
+        self.attachment_policy = Some(format_attachment_policy(max_size, extensions, scanner));
+        self
+    }
+
+    /// Sets the `[labels]` case, allowed charset, and maximum length.
+    pub fn label_policy(
+        mut self,
+        case: &str,
+        charset: Option<&str>,
+        max_length: Option<u32>,
+    ) -> Self {
+        let mut table = format!("\n[labels]\ncase = \"{case}\"");
+        if let Some(charset) = charset {
+            let _ = write!(table, "\ncharset = \"{charset}\"");
+        }
+        if let Some(max_length) = max_length {
+            let _ = write!(table, "\nmax_length = {max_length}");
+        }
+        self.label_policy = Some(table);
+        self
+    }
+
+    /// Sets `strict = true` in the `[labels]` table.
+    pub fn strict_labels(mut self) -> Self {
+        self.label_policy
+            .get_or_insert_with(|| "\n[labels]".to_string())
+            .push_str("\nstrict = true");
+        self
+    }
+
+    /// Adds a `[[labels.defined]]` entry.
+    pub fn define_label(mut self, name: &str, description: &str, color: &str) -> Self {
+        self.label_defs.push(format!(
+            "\n[[labels.defined]]\nname = \"{name}\"\ndescription = \"{description}\"\ncolor = \"{color}\""
+        ));
+        self
+    }
+
+    /// Sets the `[github]` repository and `[github.labels]` mapping.
+    pub fn github(mut self, repo: &str, labels: &[(&str, &str)]) -> Self {
+        let mut table = format!("\n[github]\nrepo = \"{repo}\"");
+        if !labels.is_empty() {
+            table.push_str("\n\n[github.labels]");
+            for (local, remote) in labels {
+                let _ = write!(table, "\n\"{local}\" = \"{remote}\"");
+            }
+        }
+        self.github = Some(table);
+        self
+    }
+
+    /// Maps alternate spellings to a canonical author identity.
+    pub fn identity(mut self, canonical: &str, aliases: &[&str]) -> Self {
+        self.identities.push(format_identity(canonical, aliases));
+        self
+    }
+
+    /// Adds an auto-labeling rule matching `title`.
+    pub fn rule(mut self, title: &str, labels: &[&str], set: &[(&str, &str)]) -> Self {
+        self.rules.push(format_rule(title, labels, set));
+        self
+    }
+
     pub fn build(&self) -> String {
         let mut lines = ConfigLines::new();
         lines.add_string("user_name", self.user_name.as_deref());
         lines.add_bool("use_git_user", self.use_git_user);
         lines.add_string("editor", self.editor.as_deref());
         lines.add_bool("interactive", self.interactive);
+        lines.add_string("picker", self.picker.as_deref());
         lines.add_string("id_pattern", self.id_pattern.as_deref());
         lines.add_string("stack_dir", self.stack_dir.as_deref());
         lines.add_string("archive_dir", self.archive_dir.as_deref());
+        lines.add_string("archive_layout", self.archive_layout.as_deref());
+        lines.add_bool("obsidian", self.obsidian);
+        lines.add_string("pre_create", self.pre_create.as_deref());
+        lines.add_string("pre_close", self.pre_close.as_deref());
+        lines.add_string("agent_command", self.agent_command.as_deref());
+        lines.add_string("link_base", self.link_base.as_deref());
+        lines.add_string("attachment_path", self.attachment_path.as_deref());
+        lines.add_string("s3_url", self.s3_url.as_deref());
+        lines.add_bool("attachment_dedup", self.attachment_dedup);
+        lines.add_string(
+            "attachment_directories",
+            self.attachment_directories.as_deref(),
+        );
+        lines.add_bool("fetch_link_titles", self.fetch_link_titles);
+        lines.add_number("trash_days", self.trash_days);
+        lines.add_bool("pure_reads", self.pure_reads);
+        lines.add_number("max_body_words", self.max_body_words);
+        lines.add_string("date_format", self.date_format.as_deref());
+        lines.add_string("frontmatter_format", self.frontmatter_format.as_deref());
+        lines.add_string("slug_style", self.slug_style.as_deref());
+        lines.add_bool("fold_accents", self.fold_accents);
+        lines.add_string("vcs", self.vcs.as_deref());
+        lines.add_string("profile", self.profile.as_deref());
+        lines.add_string("nested_projects", self.nested_projects.as_deref());
+        lines.add_aliases(&self.aliases);
+        lines.add_defaults(&self.defaults);
+        lines.add_mirrors(&self.mirrors);
+        lines.add_slug_transliterations(&self.slug_transliterations);
+        lines.add_status_dirs(&self.status_dirs);
+        lines.add_table(self.protected.as_deref());
+        lines.add_table(self.attachment_policy.as_deref());
+        lines.add_table(self.label_policy.as_deref());
+        for def in &self.label_defs {
+            lines.add_table(Some(def));
+        }
+        lines.add_table(self.github.as_deref());
+        lines.add_identities(&self.identities);
+        lines.add_rules(&self.rules);
         lines.build()
     }
 }
@@ -451,22 +1215,22 @@ pub fn make_item_content(
             "\n{}",
             labels
                 .iter()
-                .map(|l| format!("  - {}", l))
+                .map(|l| format!("  - {l}"))
                 .collect::<Vec<_>>()
                 .join("\n")
         )
     };
 
-    let category_yaml = match category {
-        Some(cat) => format!("category: {cat}"),
-        None => "category: ~".to_string(),
-    };
+    let category_yaml = category.map_or_else(
+        || "category: ~".to_string(),
+        |cat| format!("category: {cat}"),
+    );
 
     let attachments_yaml = match attachments {
         Some(att) if !att.is_empty() => format!(
             "attachments:\n{}",
             att.iter()
-                .map(|a| format!("  - {}", a))
+                .map(|a| format!("  - {a}"))
                 .collect::<Vec<_>>()
                 .join("\n")
         ),
@@ -474,7 +1238,7 @@ pub fn make_item_content(
     };
 
     format!(
-        r#"---
+        r"---
 id: {id}
 title: {title}
 author: Test User
@@ -486,7 +1250,7 @@ labels: {labels_yaml}
 ---
 
 Test item body.
-"#
+"
     )
 }
 
@@ -505,14 +1269,10 @@ pub fn create_test_item(
         .map(|c| if c.is_alphanumeric() { c } else { '-' })
         .collect::<String>();
 
-    let filename = format!("{}-{}.md", id, slug);
+    let filename = format!("{id}-{slug}.md");
     let content = make_item_content(id, title, status, labels, category, None);
 
-    let dir = if let Some(cat) = category {
-        env.stack_path().join(cat)
-    } else {
-        env.stack_path()
-    };
+    let dir = category.map_or_else(|| env.stack_path(), |cat| env.stack_path().join(cat));
 
     fs::create_dir_all(&dir).expect("Failed to create directory");
 
@@ -540,7 +1300,7 @@ impl TestEnv {
     }
 
     /// Lists attachment files in the `.attachments/` directory for an item.
-    fn list_attachment_files_for_item(&self, item_path: &Path) -> Vec<PathBuf> {
+    fn list_attachment_files_for_item(item_path: &Path) -> Vec<PathBuf> {
         let attachment_dir = Self::attachment_dir_for_item(item_path);
         if !attachment_dir.exists() {
             return Vec::new();
@@ -564,7 +1324,7 @@ impl TestEnv {
             .filter_map(Result::ok)
             .filter(|e| e.file_type().is_file())
             .filter(|e| e.path().extension().is_some_and(|ext| ext == "md"))
-            .map(|e| e.into_path())
+            .map(walkdir::DirEntry::into_path)
             .filter(|p| !p.starts_with(&archive)) // Exclude archive
             .find(|p| {
                 p.file_name()
@@ -573,12 +1333,12 @@ impl TestEnv {
             });
 
         item_path
-            .map(|p| self.list_attachment_files_for_item(&p))
+            .map(|p| Self::list_attachment_files_for_item(&p))
             .unwrap_or_default()
     }
 
     /// Checks if an attachment file exists in the item's attachment directory.
-    #[allow(dead_code)]
+    #[allow(dead_code, clippy::unused_self)]
     pub fn attachment_exists(&self, item_path: &Path, attachment_name: &str) -> bool {
         let attachment_dir = Self::attachment_dir_for_item(item_path);
         attachment_dir.join(attachment_name).exists()
@@ -594,7 +1354,7 @@ impl TestEnv {
                 .and_then(|n| n.to_str())
                 .is_some_and(|name| name.to_lowercase().contains(&item_id.to_lowercase()))
             {
-                return self.list_attachment_files_for_item(&item_path);
+                return Self::list_attachment_files_for_item(&item_path);
             }
         }
         Vec::new()
@@ -619,14 +1379,10 @@ pub fn create_test_item_with_attachments(
         .map(|c| if c.is_alphanumeric() { c } else { '-' })
         .collect::<String>();
 
-    let filename = format!("{}-{}.md", id, slug);
+    let filename = format!("{id}-{slug}.md");
     let content = make_item_content(id, title, status, &[], category, Some(attachments));
 
-    let dir = if let Some(cat) = category {
-        env.stack_path().join(cat)
-    } else {
-        env.stack_path()
-    };
+    let dir = category.map_or_else(|| env.stack_path(), |cat| env.stack_path().join(cat));
 
     fs::create_dir_all(&dir).expect("Failed to create directory");
 
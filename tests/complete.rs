@@ -0,0 +1,105 @@
+//! # Completion Tests
+//!
+//! Tests for the hidden `qs __complete` command family.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use common::{create_test_item, GlobalConfigBuilder, TestEnv};
+use queuestack::{
+    commands::{
+        self,
+        complete::{candidates, CompleteKind},
+        daemon::handle_request,
+    },
+    config::Config,
+};
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+    env
+}
+
+#[test]
+fn test_complete_ids_by_prefix() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Login bug", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Dark mode", "open", &[], None);
+    create_test_item(&env, "250101-CCC", "Old thing", "open", &[], None);
+    let config = Config::load().unwrap();
+
+    let results = candidates(&config, CompleteKind::Ids, "2601", false, 50);
+    let values: Vec<_> = results.iter().map(|c| c.value.as_str()).collect();
+    assert_eq!(values, ["260101-AAA", "260102-BBB"]);
+    assert_eq!(results[0].description, "Login bug");
+}
+
+#[test]
+fn test_complete_ids_by_title_word() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Login bug", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Dark mode", "open", &[], None);
+    let config = Config::load().unwrap();
+
+    let results = candidates(&config, CompleteKind::Ids, "MO", false, 50);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].value, "260102-BBB");
+}
+
+#[test]
+fn test_complete_ids_respects_limit_and_archive() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "One", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Two", "open", &[], None);
+    let config = Config::load().unwrap();
+
+    assert_eq!(
+        candidates(&config, CompleteKind::Ids, "", false, 1).len(),
+        1
+    );
+
+    commands::execute_close(Some("260101".to_string()), None, None).unwrap();
+    assert_eq!(
+        candidates(&config, CompleteKind::Ids, "", false, 50).len(),
+        1
+    );
+    assert_eq!(
+        candidates(&config, CompleteKind::Ids, "", true, 50).len(),
+        2
+    );
+}
+
+#[test]
+fn test_complete_labels_and_categories() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "One", "open", &["bug", "backend"], None);
+    create_test_item(&env, "260102-BBB", "Two", "open", &["bug"], Some("docs"));
+    let config = Config::load().unwrap();
+
+    let labels = candidates(&config, CompleteKind::Labels, "b", false, 50);
+    let values: Vec<_> = labels.iter().map(|c| c.value.as_str()).collect();
+    assert_eq!(values, ["backend", "bug"]);
+    assert_eq!(labels[1].description, "2 item(s)");
+
+    let categories = candidates(&config, CompleteKind::Categories, "", false, 50);
+    assert_eq!(categories.len(), 1);
+    assert_eq!(categories[0].value, "docs");
+}
+
+#[test]
+fn test_complete_via_daemon() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Login bug", "open", &[], None);
+    let config = Config::load().unwrap();
+
+    let response = handle_request(
+        &config,
+        r#"{"jsonrpc":"2.0","id":1,"method":"complete","params":{"prefix":"login"}}"#,
+    );
+    let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+    assert_eq!(response["result"][0]["value"], "260101-AAA");
+}
@@ -1,11 +1,13 @@
 //! # Config Tests
 //!
-//! Tests for configuration handling including interactive flags, use_git_user,
+//! Tests for configuration handling including interactive flags, `use_git_user`,
 //! editor settings, and custom directory configurations.
 //!
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
 mod common;
 
 use common::{create_test_item, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
@@ -16,7 +18,7 @@ use queuestack::config::GlobalConfig;
 // Config Combination Tests (interactive + no_interactive)
 // =============================================================================
 
-/// Tests that commands work correctly with interactive=true and no_interactive=false.
+/// Tests that commands work correctly with interactive=true and `no_interactive=false`.
 /// Note: Editor won't actually open in tests because stdout is not a terminal.
 #[test]
 fn test_config_interactive_true_no_interactive_false() {
@@ -29,6 +31,8 @@ fn test_config_interactive_true_no_interactive_false() {
         title: Some("Test".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -36,6 +40,7 @@ fn test_config_interactive_true_no_interactive_false() {
         }, // Would open editor if in terminal
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     let result = commands::new(args);
@@ -43,7 +48,7 @@ fn test_config_interactive_true_no_interactive_false() {
     assert_eq!(env.count_all_items(), 1);
 }
 
-/// Tests that no_interactive flag overrides interactive=true config.
+/// Tests that `no_interactive` flag overrides interactive=true config.
 #[test]
 fn test_config_interactive_true_no_interactive_true() {
     let env = TestEnv::new();
@@ -55,6 +60,8 @@ fn test_config_interactive_true_no_interactive_true() {
         title: Some("Test".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -62,6 +69,7 @@ fn test_config_interactive_true_no_interactive_true() {
         }, // Overrides interactive
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     let result = commands::new(args);
@@ -69,7 +77,7 @@ fn test_config_interactive_true_no_interactive_true() {
     assert_eq!(env.count_all_items(), 1);
 }
 
-/// Tests that with interactive=false, editor never opens regardless of no_interactive.
+/// Tests that with interactive=false, editor never opens regardless of `no_interactive`.
 #[test]
 fn test_config_interactive_false_no_interactive_false() {
     let env = TestEnv::new();
@@ -81,6 +89,8 @@ fn test_config_interactive_false_no_interactive_false() {
         title: Some("Test".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -88,6 +98,7 @@ fn test_config_interactive_false_no_interactive_false() {
         }, // Doesn't matter since interactive is false
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     let result = commands::new(args);
@@ -95,7 +106,7 @@ fn test_config_interactive_false_no_interactive_false() {
     assert_eq!(env.count_all_items(), 1);
 }
 
-/// Tests that both interactive=false and no_interactive=true definitely prevents editor.
+/// Tests that both interactive=false and `no_interactive=true` definitely prevents editor.
 #[test]
 fn test_config_interactive_false_no_interactive_true() {
     let env = TestEnv::new();
@@ -106,6 +117,8 @@ fn test_config_interactive_false_no_interactive_true() {
         title: Some("Test".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -113,6 +126,7 @@ fn test_config_interactive_false_no_interactive_true() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     let result = commands::new(args);
@@ -124,7 +138,7 @@ fn test_config_interactive_false_no_interactive_true() {
 // use_git_user Config Tests
 // =============================================================================
 
-/// Tests that use_git_user=false prevents using git user.name even if available.
+/// Tests that `use_git_user=false` prevents using git user.name even if available.
 #[test]
 fn test_use_git_user_disabled() {
     let env = TestEnv::new();
@@ -141,6 +155,8 @@ fn test_use_git_user_disabled() {
         title: Some("Test".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -148,6 +164,7 @@ fn test_use_git_user_disabled() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -160,7 +177,7 @@ fn test_use_git_user_disabled() {
     );
 }
 
-/// Tests that use_git_user=true allows falling back to git config.
+/// Tests that `use_git_user=true` allows falling back to git config.
 /// Note: This test verifies the config is parsed correctly; actual git fallback
 /// depends on git being configured on the test machine.
 #[test]
@@ -179,6 +196,8 @@ fn test_use_git_user_enabled_with_explicit_name() {
         title: Some("Test".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -186,6 +205,7 @@ fn test_use_git_user_enabled_with_explicit_name() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -243,7 +263,7 @@ fn test_editor_with_arguments() {
     );
 }
 
-/// Tests that Config::editor() returns the configured value.
+/// Tests that `Config::editor()` returns the configured value.
 #[test]
 fn test_config_editor_resolution() {
     use queuestack::Config;
@@ -287,6 +307,8 @@ fn test_custom_stack_directory() {
         title: Some("Task".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -294,6 +316,7 @@ fn test_custom_stack_directory() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -318,7 +341,7 @@ fn test_custom_archive_directory() {
     std::fs::create_dir_all(env.stack_path().join("done")).expect("create archive dir");
 
     create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
-    execute_close(Some("260101".to_string()), None).expect("close should succeed");
+    execute_close(Some("260101".to_string()), None, None).expect("close should succeed");
 
     let done_dir = env.stack_path().join("done");
     let files: Vec<_> = std::fs::read_dir(&done_dir)
@@ -360,6 +383,8 @@ fn test_different_users_in_parallel() {
             title: Some("Alice's Task".to_string()),
             labels: vec![],
             category: None,
+            due: None,
+            assignee: None,
             attachments: vec![],
             interactive: InteractiveArgs {
                 interactive: false,
@@ -367,6 +392,7 @@ fn test_different_users_in_parallel() {
             },
             as_template: false,
             from_template: None,
+            vars: vec![],
         };
 
         commands::new(args).expect("new should succeed");
@@ -385,6 +411,8 @@ fn test_different_users_in_parallel() {
             title: Some("Bob's Task".to_string()),
             labels: vec![],
             category: None,
+            due: None,
+            assignee: None,
             attachments: vec![],
             interactive: InteractiveArgs {
                 interactive: false,
@@ -392,6 +420,7 @@ fn test_different_users_in_parallel() {
             },
             as_template: false,
             from_template: None,
+            vars: vec![],
         };
 
         commands::new(args).expect("new should succeed");
@@ -511,6 +540,7 @@ id_pattern = "%y%j-%RRR"
 stack_dir = "tasks"
 archive_dir = "done"
 template_dir = "blueprints"
+obsidian = true
 "#,
     );
 
@@ -532,6 +562,7 @@ id_pattern = "%y%j-%RRR"
 stack_dir = "tasks"
 archive_dir = "done"
 template_dir = "blueprints"
+obsidian = true
 invalid_option = true
 "#,
     );
@@ -548,9 +579,10 @@ invalid_option = true
     assert_eq!(config.stack_dir, Some("tasks".to_string()));
     assert_eq!(config.archive_dir, Some("done".to_string()));
     assert_eq!(config.template_dir, Some("blueprints".to_string()));
+    assert!(config.obsidian);
 }
 
-/// Tests that setup migrates legacy field names (default_id_pattern -> id_pattern).
+/// Tests that setup migrates legacy field names (`default_id_pattern` -> `id_pattern`).
 #[test]
 fn test_setup_migrates_legacy_fields() {
     let env = TestEnv::new();
@@ -596,3 +628,222 @@ default_id_pattern = "%y%j-%RRR"
         "ID pattern value should be preserved"
     );
 }
+
+/// Tests that rules survive a config update (rewritten with comments).
+#[test]
+fn test_setup_preserves_rules() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        r#"
+user_name = "Custom Name"
+invalid_option = true
+
+[[rules]]
+title = "panic|crash"
+labels = ["bug"]
+set = { priority = "high" }
+"#,
+    );
+
+    GlobalConfig::update_if_needed().expect("update should succeed");
+
+    let config = GlobalConfig::load().expect("load should succeed");
+    assert_eq!(config.user_name, Some("Custom Name".to_string()));
+    assert_eq!(config.rules.len(), 1);
+    assert_eq!(config.rules[0].title.as_deref(), Some("panic|crash"));
+    assert_eq!(config.rules[0].labels, ["bug"]);
+    assert_eq!(
+        config.rules[0].set.get("priority").map(String::as_str),
+        Some("high")
+    );
+    assert!(!env.read_global_config().contains("invalid_option"));
+}
+
+// =============================================================================
+// Profile Tests
+// =============================================================================
+
+fn write_profiles_config(env: &TestEnv, default_profile: Option<&str>) {
+    let mut builder = GlobalConfigBuilder::new()
+        .interactive(false)
+        .define_profile(
+            "work",
+            &[("user_name", "Work Me"), ("editor", "work-editor")],
+        )
+        .define_profile(
+            "client",
+            &[("user_name", "Client Me"), ("id_pattern", "C%RRR")],
+        );
+    if let Some(profile) = default_profile {
+        builder = builder.profile(profile);
+    }
+    env.write_global_config(&builder.build());
+}
+
+#[test]
+fn test_profile_selected_by_project() {
+    use queuestack::Config;
+
+    let env = TestEnv::new();
+    write_profiles_config(&env, None);
+    env.write_project_config(&ProjectConfigBuilder::new().profile("work").build());
+
+    let config = Config::load().expect("load config");
+    assert_eq!(config.user_name(), Some("Work Me".to_string()));
+    assert_eq!(config.editor(), Some("work-editor".to_string()));
+    assert_eq!(config.id_pattern(), "%y%m%d-%T%RRR");
+}
+
+#[test]
+fn test_profile_override_and_project_precedence() {
+    use queuestack::{config::set_profile_override, Config};
+
+    let env = TestEnv::new();
+    write_profiles_config(&env, Some("work"));
+    env.write_project_config(&ProjectConfigBuilder::new().user_name("Project Me").build());
+
+    // Default profile applies, but project values win
+    let config = Config::load().expect("load config");
+    assert_eq!(config.user_name(), Some("Project Me".to_string()));
+    assert_eq!(config.editor(), Some("work-editor".to_string()));
+
+    // --profile replaces the default profile
+    set_profile_override(Some("client".to_string()));
+    let config = Config::load();
+    set_profile_override(None);
+    let config = config.expect("load config");
+    assert_eq!(config.id_pattern(), "C%RRR");
+    assert_eq!(config.editor(), Some("true".to_string()));
+}
+
+#[test]
+fn test_unknown_profile_is_an_error() {
+    use queuestack::{config::set_profile_override, Config};
+
+    let env = TestEnv::new();
+    write_profiles_config(&env, None);
+    commands::init().expect("init should succeed");
+
+    set_profile_override(Some("missing".to_string()));
+    let result = Config::load();
+    set_profile_override(None);
+    let err = result.expect_err("unknown profile should fail");
+    assert!(err.to_string().contains("Unknown profile 'missing'"));
+}
+
+/// Tests that profiles survive a config update (rewritten with comments).
+#[test]
+fn test_setup_preserves_profiles() {
+    let env = TestEnv::new();
+    write_profiles_config(&env, Some("work"));
+
+    GlobalConfig::update_if_needed().expect("update should succeed");
+
+    let config = GlobalConfig::load().expect("load should succeed");
+    assert_eq!(config.profile.as_deref(), Some("work"));
+    assert_eq!(config.profiles.len(), 2);
+    assert_eq!(
+        config.profiles["client"].id_pattern.as_deref(),
+        Some("C%RRR")
+    );
+    assert!(env.read_global_config().contains("[profiles.work]"));
+}
+
+// =============================================================================
+// Nested Projects
+// =============================================================================
+
+/// Creates a project inside the test project and changes into it.
+fn enter_nested_project(env: &TestEnv, project_config: &str) -> std::path::PathBuf {
+    env.write_project_config(&ProjectConfigBuilder::new().build());
+    let inner = env.project_path().join("packages").join("app");
+    std::fs::create_dir_all(&inner).unwrap();
+    std::fs::write(inner.join(".queuestack"), project_config).unwrap();
+    std::env::set_current_dir(&inner).unwrap();
+    inner.canonicalize().unwrap()
+}
+
+#[test]
+fn test_nested_projects_nearest_wins() {
+    use queuestack::Config;
+
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    let inner = enter_nested_project(&env, "");
+
+    let config = Config::load().expect("load config");
+    assert_eq!(config.project_root().canonicalize().unwrap(), inner);
+    commands::which().expect("which should succeed");
+}
+
+#[test]
+fn test_nested_projects_error_policy() {
+    use queuestack::{config::set_project_root_override, Config};
+
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .nested_projects("error")
+            .build(),
+    );
+    enter_nested_project(&env, "");
+
+    let err = Config::load().unwrap_err();
+    assert!(
+        err.to_string().contains("Nested queuestack projects"),
+        "{err}"
+    );
+
+    // Even an explicit root doesn't allow nesting
+    set_project_root_override(Some(env.project_path().to_path_buf()));
+    let result = Config::load();
+    set_project_root_override(None);
+    assert!(result.is_ok(), "outer project itself is not nested");
+
+    set_project_root_override(Some("..".into()));
+    let result = Config::load();
+    set_project_root_override(None);
+    assert!(result.is_err(), "'..' has no .queuestack file");
+}
+
+#[test]
+fn test_nested_projects_explicit_policy() {
+    use queuestack::{config::set_project_root_override, Config};
+
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    let inner = enter_nested_project(
+        &env,
+        &ProjectConfigBuilder::new()
+            .nested_projects("explicit")
+            .build(),
+    );
+
+    let err = Config::load().unwrap_err();
+    assert!(err.to_string().contains("--project-root"), "{err}");
+
+    set_project_root_override(Some(env.project_path().to_path_buf()));
+    let outer = Config::load();
+    set_project_root_override(Some(".".into()));
+    let nested = Config::load();
+    set_project_root_override(None);
+
+    assert_eq!(
+        outer.unwrap().project_root().canonicalize().unwrap(),
+        env.project_path().canonicalize().unwrap()
+    );
+    assert_eq!(
+        nested.unwrap().project_root().canonicalize().unwrap(),
+        inner
+    );
+}
+
+#[test]
+fn test_nested_projects_written_to_global_config() {
+    let _env = TestEnv::new();
+    GlobalConfig::create_default_if_missing().expect("create config");
+
+    let content = std::fs::read_to_string(GlobalConfig::path().unwrap()).unwrap();
+    assert!(content.contains("nested_projects = \"nearest\""));
+}
@@ -0,0 +1,121 @@
+//! # Copy Command Tests
+//!
+//! Tests for copying item IDs, paths, and links to the clipboard (`qs copy`).
+//! A fake `xclip` on the PATH stands in for the clipboard.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![cfg(all(unix, not(target_os = "macos")))]
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::{fs, os::unix::fs::PermissionsExt, path::PathBuf};
+
+use assert_cmd::Command;
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+/// Creates a qs command without a Wayland session to copy to.
+fn qs_headless(env: &TestEnv) -> Command {
+    let mut cmd = qs_cmd(env);
+    cmd.env_remove("WAYLAND_DISPLAY");
+    cmd
+}
+
+/// Installs a fake `xclip` that writes the copied text to the returned file,
+/// and makes it the only program on the PATH.
+fn fake_clipboard(env: &TestEnv, cmd: &mut Command) -> PathBuf {
+    let bin = env.home_dir.path().join("bin");
+    fs::create_dir_all(&bin).unwrap();
+    let clip = env.home_dir.path().join("clipboard.txt");
+    let script = bin.join("xclip");
+    fs::write(
+        &script,
+        format!("#!/bin/sh\nexec /bin/cat > '{}'\n", clip.display()),
+    )
+    .unwrap();
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+    cmd.env("PATH", &bin);
+    clip
+}
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    create_test_item(
+        &env,
+        "260101-AAA",
+        "Crash [prod]",
+        "open",
+        &[],
+        Some("bugs"),
+    );
+    env
+}
+
+#[test]
+fn test_copy_id_by_default() {
+    let env = setup();
+
+    let mut cmd = qs_headless(&env);
+    let clip = fake_clipboard(&env, &mut cmd);
+    cmd.args(["copy", "--id", "260101"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Copied ID: 260101-AAA"));
+    assert_eq!(fs::read_to_string(clip).unwrap(), "260101-AAA");
+}
+
+#[test]
+fn test_copy_path_and_links() {
+    let env = setup();
+
+    let mut cmd = qs_headless(&env);
+    let clip = fake_clipboard(&env, &mut cmd);
+    cmd.args(["copy", "--id", "260101-AAA", "--what", "path"])
+        .assert()
+        .success();
+    let path = fs::read_to_string(&clip).unwrap();
+    assert!(path.contains("bugs/260101-AAA-"), "{path}");
+
+    // Without a git remote, links use the qs:// scheme
+    let mut cmd = qs_headless(&env);
+    fake_clipboard(&env, &mut cmd);
+    cmd.args(["copy", "--id", "260101-AAA", "--what", "url"])
+        .assert()
+        .success();
+    assert_eq!(fs::read_to_string(&clip).unwrap(), "qs://260101-AAA");
+
+    let mut cmd = qs_headless(&env);
+    fake_clipboard(&env, &mut cmd);
+    cmd.args(["copy", "--id", "260101-AAA", "--what", "markdown-link"])
+        .assert()
+        .success();
+    assert_eq!(
+        fs::read_to_string(&clip).unwrap(),
+        r"[Crash \[prod\]](qs://260101-AAA)"
+    );
+}
+
+#[test]
+fn test_copy_without_clipboard_tool_fails() {
+    let env = setup();
+
+    qs_headless(&env)
+        .env("PATH", env.home_dir.path())
+        .args(["copy", "--id", "260101-AAA"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No clipboard tool found"));
+}
+
+#[test]
+fn test_copy_requires_item() {
+    let env = setup();
+
+    qs_headless(&env).args(["copy"]).assert().failure();
+}
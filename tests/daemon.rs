@@ -0,0 +1,199 @@
+//! # Daemon Tests
+//!
+//! Tests for the JSON-RPC request handling behind `qs daemon`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{create_test_item, GlobalConfigBuilder, TestEnv};
+use queuestack::{
+    commands::{self, daemon::handle_request},
+    config::Config,
+};
+use serde_json::Value;
+
+fn call(config: &Config, request: &str) -> Value {
+    serde_json::from_str(&handle_request(config, request)).expect("response should be JSON")
+}
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .user_name("Test User")
+            .interactive(false)
+            .build(),
+    );
+    commands::init().expect("init should succeed");
+    env
+}
+
+#[test]
+fn test_daemon_list() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "First", "open", &["bug"], None);
+    create_test_item(&env, "260102-BBB", "Second", "open", &[], Some("docs"));
+    let config = Config::load().unwrap();
+
+    let response = call(&config, r#"{"jsonrpc":"2.0","id":1,"method":"list"}"#);
+    assert_eq!(response["id"], 1);
+    let items = response["result"]
+        .as_array()
+        .expect("result should be an array");
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0]["id"], "260101-AAA");
+    assert_eq!(items[1]["category"], "docs");
+
+    let response = call(
+        &config,
+        r#"{"jsonrpc":"2.0","id":2,"method":"list","params":{"labels":["bug"]}}"#,
+    );
+    assert_eq!(response["result"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn test_daemon_search_and_resolve() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Login bug", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Dark mode", "open", &[], None);
+    let config = Config::load().unwrap();
+
+    let response = call(
+        &config,
+        r#"{"jsonrpc":"2.0","id":1,"method":"search","params":{"query":"login"}}"#,
+    );
+    let items = response["result"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["title"], "Login bug");
+
+    let response = call(
+        &config,
+        r#"{"jsonrpc":"2.0","id":2,"method":"resolve-id","params":{"id":"260102"}}"#,
+    );
+    assert_eq!(response["result"]["id"], "260102-BBB");
+}
+
+#[test]
+fn test_daemon_create_and_close() {
+    let env = setup();
+    let config = Config::load().unwrap();
+
+    let response = call(
+        &config,
+        r#"{"jsonrpc":"2.0","id":1,"method":"create","params":{"title":"From editor","labels":["ui"]}}"#,
+    );
+    let id = response["result"]["id"].as_str().unwrap().to_string();
+    assert_eq!(response["result"]["author"], "Test User");
+    assert_eq!(env.list_stack_files().len(), 1);
+
+    let request =
+        format!(r#"{{"jsonrpc":"2.0","id":2,"method":"close","params":{{"id":"{id}"}}}}"#);
+    let response = call(&config, &request);
+    assert_eq!(response["result"]["status"], "closed");
+    assert_eq!(env.list_stack_files().len(), 0);
+    assert_eq!(env.list_archive_files().len(), 1);
+}
+
+#[test]
+fn test_daemon_errors() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "First", "open", &["bug"], None);
+    create_test_item(&env, "260102-BBB", "Second", "open", &[], Some("docs"));
+    let config = Config::load().unwrap();
+
+    let response = call(&config, r#"{"jsonrpc":"2.0","id":1,"method":"bogus"}"#);
+    assert_eq!(response["error"]["code"], -32601);
+
+    let response = call(&config, "not json");
+    assert_eq!(response["error"]["code"], -32600);
+
+    let response = call(
+        &config,
+        r#"{"jsonrpc":"2.0","id":3,"method":"resolve-id","params":{"id":"NOPE"}}"#,
+    );
+    assert_eq!(response["id"], 3);
+    assert!(response["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("No item found"));
+
+    // Failed lookups return their candidates as data
+    let response = call(
+        &config,
+        r#"{"jsonrpc":"2.0","id":4,"method":"resolve-id","params":{"id":"26010"}}"#,
+    );
+    let data = &response["error"]["data"];
+    assert_eq!(data["ambiguous"], true);
+    assert_eq!(data["candidates"][0]["id"], "260101-AAA");
+    assert_eq!(data["candidates"][1]["title"], "Second");
+
+    let response = call(
+        &config,
+        r#"{"jsonrpc":"2.0","id":5,"method":"resolve-id","params":{"id":"Secnd"}}"#,
+    );
+    let data = &response["error"]["data"];
+    assert_eq!(data["ambiguous"], false);
+    assert_eq!(data["candidates"][0]["id"], "260102-BBB");
+}
+
+/// Connection threads of a running daemon use the project's settings.
+#[cfg(unix)]
+#[test]
+fn test_daemon_connection_uses_project_settings() {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        os::unix::net::UnixStream,
+        process::{Command, Stdio},
+        thread,
+        time::Duration,
+    };
+
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .user_name("Test User")
+            .interactive(false)
+            .frontmatter_format("toml")
+            .build(),
+    );
+    commands::init().expect("init should succeed");
+
+    let socket = env.home_dir.path().join("qs.sock");
+    let mut daemon = Command::new(assert_cmd::cargo::cargo_bin("qs"))
+        .args(["daemon", "--socket"])
+        .arg(&socket)
+        .current_dir(env.project_path())
+        .env("HOME", env.home_dir.path())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("daemon should start");
+    let stream = (0..100).find_map(|_| {
+        UnixStream::connect(&socket).ok().or_else(|| {
+            thread::sleep(Duration::from_millis(50));
+            None
+        })
+    });
+
+    let response = stream.map(|mut stream| {
+        stream
+            .write_all(
+                b"{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"create\",\"params\":{\"title\":\"From editor\"}}\n",
+            )
+            .unwrap();
+        let mut line = String::new();
+        BufReader::new(stream).read_line(&mut line).unwrap();
+        line
+    });
+    daemon.kill().unwrap();
+    daemon.wait().unwrap();
+
+    let response: Value = serde_json::from_str(&response.expect("daemon should listen")).unwrap();
+    assert_eq!(response["result"]["title"], "From editor");
+    let files = env.list_stack_files();
+    assert_eq!(files.len(), 1);
+    assert!(env.read_item(&files[0]).starts_with("+++\n"));
+}
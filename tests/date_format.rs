@@ -0,0 +1,94 @@
+//! # Date Format Tests
+//!
+//! Tests for the `date_format` option and lenient parsing of frontmatter
+//! timestamps written by other tools.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
+use queuestack::{commands, storage, Config, Item};
+
+fn setup(global: GlobalConfigBuilder) -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&global.interactive(false).build());
+    commands::init().expect("init");
+    env
+}
+
+fn write_item(env: &TestEnv, id: &str, created_at: &str) -> std::path::PathBuf {
+    let path = env.stack_path().join(format!("{id}-imported.md"));
+    std::fs::write(
+        &path,
+        format!(
+            "---\nid: {id}\ntitle: Imported\nauthor: Bot\ncreated_at: {created_at}\nstatus: open\n---\n\nBody\n"
+        ),
+    )
+    .unwrap();
+    path
+}
+
+fn read_archived(path: &std::path::Path) -> String {
+    let name = path.file_name().unwrap();
+    let archived = walkdir::WalkDir::new(Config::load().unwrap().archive_path())
+        .into_iter()
+        .filter_map(Result::ok)
+        .find(|entry| entry.file_name() == name)
+        .expect("archived item");
+    std::fs::read_to_string(archived.path()).unwrap()
+}
+
+#[test]
+fn test_foreign_timestamps_are_parsed() {
+    let env = setup(GlobalConfigBuilder::new());
+    for (id, created_at) in [
+        ("260101-AAA", "2026-01-05"),
+        ("260101-BBB", "2026-01-05 08:30"),
+        ("260101-CCC", "'2026-01-05T10:30:00+02:00'"),
+        ("260101-DDD", "1767601800"),
+    ] {
+        let path = write_item(&env, id, created_at);
+        let item = Item::load(&path).unwrap_or_else(|e| panic!("{created_at}: {e:#}"));
+        assert_eq!(
+            item.created_at().format("%Y-%m-%d").to_string(),
+            "2026-01-05"
+        );
+    }
+
+    let config = Config::load().unwrap();
+    assert_eq!(storage::load_all_items(&config).len(), 4);
+}
+
+#[test]
+fn test_date_format_is_used_when_saving() {
+    let env = setup(GlobalConfigBuilder::new().date_format("seconds"));
+    env.write_project_config(&ProjectConfigBuilder::new().date_format("minutes").build());
+    let path = write_item(&env, "260101-AAA", "2026-01-05T08:30:15.250Z");
+
+    commands::execute_close(Some("260101-AAA".to_string()), None, None).expect("close");
+
+    let content = read_archived(&path);
+    assert!(
+        content.contains("created_at: \"2026-01-05T08:30Z\"\n"),
+        "{content}"
+    );
+    assert!(content.contains("closed_at: "), "{content}");
+}
+
+#[test]
+fn test_default_date_format_keeps_rfc3339() {
+    let env = setup(GlobalConfigBuilder::new());
+    let path = write_item(&env, "260101-AAA", "2026-01-05 08:30:15");
+
+    commands::execute_close(Some("260101-AAA".to_string()), None, None).expect("close");
+
+    let content = read_archived(&path);
+    assert!(
+        content.contains("created_at: \"2026-01-05T08:30:15Z\"\n"),
+        "{content}"
+    );
+}
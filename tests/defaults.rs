@@ -0,0 +1,133 @@
+//! # Command Defaults Tests
+//!
+//! Tests for per-command default options from `[defaults.<command>]` config tables.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+fn setup(global: GlobalConfigBuilder) -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&global.interactive(false).build());
+    commands::init().unwrap();
+    create_test_item(&env, "260101-AAA", "Zebra", "open", &["bug"], None);
+    create_test_item(&env, "260102-BBB", "Apple", "open", &[], None);
+    env
+}
+
+#[test]
+fn test_defaults_apply_to_command() {
+    let env = setup(GlobalConfigBuilder::new().defaults("list", &[("sort", "\"title\"")]));
+
+    qs_cmd(&env)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match("(?s)apple.*zebra").unwrap());
+
+    // The command line takes precedence
+    qs_cmd(&env)
+        .args(["list", "--sort", "id"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match("(?s)zebra.*apple").unwrap());
+}
+
+#[test]
+fn test_closed_default_yields_to_scope_option() {
+    let env = setup(
+        GlobalConfigBuilder::new()
+            .defaults("list", &[("closed", "true")])
+            .defaults("search", &[("scope", "\"archive\"")]),
+    );
+    commands::execute_close(Some("260102-BBB".to_string()), None, None).unwrap();
+
+    qs_cmd(&env)
+        .args(["list", "--format", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260102-BBB"))
+        .stdout(predicate::str::contains("260101-AAA").not());
+    qs_cmd(&env)
+        .args(["list", "--scope", "all", "--format", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260102-BBB"))
+        .stdout(predicate::str::contains("260101-AAA"));
+
+    qs_cmd(&env)
+        .args(["search", "Apple"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260102-BBB"));
+    qs_cmd(&env)
+        .args(["search", "Zebra", "--scope", "open"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260101-AAA"));
+}
+
+#[test]
+fn test_project_defaults_merge_over_global() {
+    let env = setup(
+        GlobalConfigBuilder::new()
+            .defaults("list", &[("sort", "\"title\""), ("label", "[\"bug\"]")]),
+    );
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .defaults("list", &[("sort", "\"id\"")])
+            .build(),
+    );
+
+    qs_cmd(&env)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout("queuestack/260101-AAA-zebra.md\n");
+}
+
+#[test]
+fn test_defaults_for_nested_command() {
+    let env =
+        setup(GlobalConfigBuilder::new().defaults("attachments add", &[("interactive", "true")]));
+
+    // Options are looked up on the subcommand, not on `attachments`
+    qs_cmd(&env)
+        .args(["attachments", "add", "--id", "260101-AAA", "missing.txt"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Unknown option 'interactive' in [defaults.\"attachments add\"]",
+        ));
+}
+
+#[test]
+fn test_invalid_default_value_is_reported() {
+    let env = setup(GlobalConfigBuilder::new().defaults("list", &[("sort", "\"size\"")]));
+
+    qs_cmd(&env)
+        .arg("list")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "'sort' in [defaults.\"list\"]: invalid value 'size'",
+        ));
+}
+
+#[test]
+fn test_unknown_default_command_is_reported() {
+    let env = setup(GlobalConfigBuilder::new().defaults("lsit", &[("sort", "\"id\"")]));
+
+    qs_cmd(&env)
+        .arg("list")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown command 'lsit'"));
+}
@@ -0,0 +1,122 @@
+//! # Diff Command Tests
+//!
+//! Tests for `qs diff` against git revisions.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use std::{fs, process::Command as StdCommand};
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands::{self, DiffArgs};
+
+/// Runs git in the project directory; returns false if git is unavailable.
+fn git(env: &TestEnv, args: &[&str]) -> bool {
+    StdCommand::new("git")
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .current_dir(env.project_path())
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+fn commit_all(env: &TestEnv, message: &str) -> bool {
+    git(env, &["add", "-A"]) && git(env, &["commit", "-q", "-m", message])
+}
+
+/// Sets up a project with one committed item; returns `None` if git is unavailable.
+fn setup() -> Option<TestEnv> {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+
+    if !git(&env, &["init", "-q"]) {
+        return None;
+    }
+    let path = create_test_item(&env, "260101-AAA", "Fix login", "open", &["bug"], None);
+    let content = fs::read_to_string(&path).unwrap();
+    fs::write(&path, format!("{content}\nStep one\nStep two\n")).unwrap();
+    if !commit_all(&env, "Initial") {
+        return None;
+    }
+    Some(env)
+}
+
+#[test]
+fn test_diff_shows_field_and_body_changes() {
+    let Some(env) = setup() else {
+        return; // git not available
+    };
+    let path = env.find_item_by_id("260101-AAA").unwrap();
+    let content = fs::read_to_string(&path)
+        .unwrap()
+        .replace("Step two", "Step 2")
+        .replace("status: open", "status: in-progress");
+    fs::write(&path, content).unwrap();
+
+    qs_cmd(&env)
+        .args(["diff", "--id", "260101-AAA"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("~ status: open -> in-progress\n"))
+        .stdout(predicate::str::contains("body:\n"))
+        .stdout(predicate::str::contains("  -Step two\n  +Step 2\n"))
+        .stdout(predicate::str::contains("\x1b[").not());
+}
+
+#[test]
+fn test_diff_follows_moved_items() {
+    let Some(env) = setup() else {
+        return;
+    };
+    commands::execute_close(Some("260101-AAA".to_string()), None, None).expect("close");
+
+    qs_cmd(&env)
+        .args(["diff", "--id", "260101-AAA", "--since", "HEAD"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "moved: queuestack/260101-AAA-fix-login.md -> queuestack/.archive/260101-AAA-fix-login.md\n",
+        ))
+        .stdout(predicate::str::contains("~ status: open -> closed\n"));
+}
+
+#[test]
+fn test_diff_without_changes() {
+    let Some(_env) = setup() else {
+        return;
+    };
+
+    // Library call prints "No changes"; just make sure it succeeds
+    commands::diff(&DiffArgs {
+        id: Some("260101".to_string()),
+        file: None,
+        since: None,
+    })
+    .expect("diff should succeed");
+}
+
+#[test]
+fn test_diff_new_item_and_unknown_revision() {
+    let Some(env) = setup() else {
+        return;
+    };
+    create_test_item(&env, "260102-BBB", "New item", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["diff", "--id", "260102-BBB"])
+        .assert()
+        .success()
+        .stdout("260102-BBB did not exist at HEAD\n");
+
+    qs_cmd(&env)
+        .args(["diff", "--id", "260101-AAA", "--since", "no-such-ref"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Unknown git revision 'no-such-ref'",
+        ));
+}
@@ -0,0 +1,131 @@
+//! # Draft Command Tests
+//!
+//! Tests for `qs draft`: starting, applying, and discarding body drafts.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use std::{fs, path::PathBuf};
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+fn setup() -> (TestEnv, PathBuf) {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    let item = create_test_item(&env, "260101-AAAAA", "Draft me", "open", &[], None);
+    (env, item)
+}
+
+/// Starts a draft and returns the path printed by `qs draft`.
+fn start_draft(env: &TestEnv) -> PathBuf {
+    let output = qs_cmd(env)
+        .args(["draft", "--id", "260101"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let path = stdout
+        .lines()
+        .find_map(|line| line.split_once("Started draft: ").map(|(_, path)| path))
+        .expect("draft path");
+    PathBuf::from(path)
+}
+
+#[test]
+fn test_draft_apply_writes_body() {
+    let (env, item) = setup();
+    let original = fs::read_to_string(&item).unwrap();
+
+    let draft = start_draft(&env);
+    assert!(draft.starts_with(env.home_dir.path()));
+    assert_eq!(fs::read_to_string(&draft).unwrap(), "Test item body.\n");
+
+    // Editing the draft leaves the tracked file alone
+    fs::write(&draft, "Rewritten body.\n").unwrap();
+    assert_eq!(fs::read_to_string(&item).unwrap(), original);
+
+    qs_cmd(&env)
+        .args(["draft", "--id", "260101"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("Continuing draft: "));
+
+    qs_cmd(&env)
+        .args(["draft", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("260101-AAAAA  "))
+        .stdout(predicate::str::contains("  Draft me\n"));
+
+    qs_cmd(&env)
+        .args(["draft", "apply", "--id", "2601"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Applied draft to item:"));
+
+    let content = fs::read_to_string(&item).unwrap();
+    assert!(content.contains("Rewritten body.\n"));
+    assert!(!content.contains("Test item body."));
+    assert!(!draft.exists());
+
+    qs_cmd(&env)
+        .args(["draft", "list"])
+        .assert()
+        .success()
+        .stdout("No drafts.\n");
+}
+
+#[test]
+fn test_draft_discard_leaves_item_untouched() {
+    let (env, item) = setup();
+    let original = fs::read_to_string(&item).unwrap();
+
+    let draft = start_draft(&env);
+    fs::write(&draft, "Half-finished thought").unwrap();
+
+    qs_cmd(&env)
+        .args(["draft", "discard", "--id", "260101-AAAAA"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Discarded draft of 260101-AAAAA"));
+
+    assert!(!draft.exists());
+    assert_eq!(fs::read_to_string(&item).unwrap(), original);
+
+    qs_cmd(&env)
+        .args(["draft", "apply", "--id", "260101"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No draft matches '260101'"));
+}
+
+#[test]
+fn test_draft_apply_refuses_changed_body() {
+    let (env, item) = setup();
+
+    let draft = start_draft(&env);
+    fs::write(&draft, "My version.\n").unwrap();
+    let changed = fs::read_to_string(&item)
+        .unwrap()
+        .replace("Test item body.", "Their version.");
+    fs::write(&item, changed).unwrap();
+
+    qs_cmd(&env)
+        .args(["draft", "apply", "--id", "260101"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "changed since the draft was started",
+        ));
+
+    qs_cmd(&env)
+        .args(["draft", "apply", "--id", "260101", "--force"])
+        .assert()
+        .success();
+    assert!(fs::read_to_string(&item).unwrap().contains("My version."));
+}
@@ -0,0 +1,216 @@
+//! # Dry-Run Tests
+//!
+//! Tests for `--dry-run` plans on close, reopen, update, and delete.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::fs;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::{
+    commands::{self, OutputFormat, UpdateArgs},
+    storage::plan::FileOp,
+};
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env
+}
+
+fn update_args(id: &str) -> UpdateArgs {
+    UpdateArgs {
+        id: Some(id.to_string()),
+        file: None,
+        title: None,
+        labels: vec![],
+        remove_labels: vec![],
+        category: None,
+        remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
+    }
+}
+
+#[test]
+fn test_close_dry_run_plans_status_and_move() {
+    let env = setup();
+    let path = create_test_item(&env, "260101-AAA", "Fix bug", "open", &[], Some("bugs"));
+    let before = fs::read_to_string(&path).unwrap();
+
+    let mut plan = commands::plan_close(Some("260101-AAA".to_string()), None, None).expect("plan");
+
+    // The close timestamp varies; check its presence, then compare the rest
+    let FileOp::Write { diff, .. } = &mut plan.operations[0] else {
+        panic!("expected a write first");
+    };
+    let closed_at = diff.pop().expect("closed_at line");
+    assert!(closed_at.starts_with("+closed_at: "), "{closed_at}");
+
+    assert_eq!(
+        plan.operations,
+        [
+            FileOp::Write {
+                path: "queuestack/bugs/260101-AAA-fix-bug.md".to_string(),
+                diff: vec!["-status: open".to_string(), "+status: closed".to_string()],
+            },
+            FileOp::Move {
+                from: "queuestack/bugs/260101-AAA-fix-bug.md".to_string(),
+                to: "queuestack/.archive/bugs/260101-AAA-fix-bug.md".to_string(),
+            },
+        ]
+    );
+
+    // Nothing touched on disk
+    assert_eq!(fs::read_to_string(&path).unwrap(), before);
+    assert!(env.list_archive_files().is_empty());
+}
+
+#[test]
+fn test_update_dry_run_combines_rename_and_category_move() {
+    let env = setup();
+    let path = create_test_item(&env, "260101-AAA", "Old title", "open", &[], None);
+    let before = fs::read_to_string(&path).unwrap();
+
+    let plan = commands::plan_update(UpdateArgs {
+        title: Some("New title".to_string()),
+        labels: vec!["ui".to_string()],
+        category: Some("frontend".to_string()),
+        ..update_args("260101-AAA")
+    })
+    .expect("plan");
+
+    let FileOp::Write { ref diff, .. } = plan.operations[0] else {
+        panic!("expected write, got {:?}", plan.operations[0]);
+    };
+    assert!(diff.contains(&"-title: Old title".to_string()));
+    assert!(diff.contains(&"+title: New title".to_string()));
+    assert!(diff.contains(&"+  - ui".to_string()));
+    assert_eq!(
+        plan.operations[1],
+        FileOp::Move {
+            from: "queuestack/260101-AAA-old-title.md".to_string(),
+            to: "queuestack/frontend/260101-AAA-new-title.md".to_string(),
+        }
+    );
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), before);
+    assert!(env.list_category_files("frontend").is_empty());
+}
+
+#[test]
+fn test_update_dry_run_without_changes_is_empty() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    let plan = commands::plan_update(update_args("260101-AAA")).expect("plan");
+    assert!(plan.is_empty());
+    assert_eq!(
+        plan.render(OutputFormat::Text).unwrap(),
+        "No changes to apply.\n"
+    );
+}
+
+#[test]
+fn test_close_dry_run_cli_output() {
+    let env = setup();
+    let path = create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["close", "--id", "260101-AAA", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "write   queuestack/260101-AAA-task.md\n          -status: open\n          +status: closed\n",
+        ))
+        .stdout(predicate::str::contains(
+            "move    queuestack/260101-AAA-task.md -> queuestack/.archive/260101-AAA-task.md\n",
+        ));
+
+    let output = qs_cmd(&env)
+        .args([
+            "close",
+            "--id",
+            "260101-AAA",
+            "--dry-run",
+            "--format",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["operations"][0]["op"], "write");
+    assert_eq!(json["operations"][1]["op"], "move");
+    assert_eq!(
+        json["operations"][1]["to"],
+        "queuestack/.archive/260101-AAA-task.md"
+    );
+
+    assert!(path.exists());
+}
+
+#[test]
+fn test_delete_dry_run_plans_trash_move() {
+    let env = setup();
+    let path = create_test_item(&env, "260101-AAA", "Crash", "open", &[], Some("bugs"));
+
+    qs_cmd(&env)
+        .args(["delete", "--id", "260101-AAA", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(
+            r"^move    queuestack/bugs/260101-AAA-crash\.md -> queuestack/\.trash/\d{8}-\d{6}/bugs/260101-AAA-crash\.md\n$",
+        ).unwrap());
+    assert!(path.exists());
+    assert!(!env.stack_path().join(".trash").exists());
+}
+
+#[test]
+fn test_delete_dry_run_checks_protection() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .protected(&["bugs"], &[], &[])
+            .build(),
+    );
+    commands::init().expect("init");
+    create_test_item(&env, "260101-AAA", "Crash", "open", &[], Some("bugs"));
+
+    qs_cmd(&env)
+        .args(["delete", "--id", "260101-AAA", "--dry-run"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Cannot delete '260101-AAA'"));
+    qs_cmd(&env)
+        .args(["delete", "--id", "260101-AAA", "--dry-run", "--force"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "move    queuestack/bugs/260101-AAA-crash.md",
+        ));
+}
+
+#[test]
+fn test_format_requires_dry_run() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["close", "--id", "260101-AAA", "--format", "json"])
+        .assert()
+        .failure();
+}
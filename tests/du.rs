@@ -0,0 +1,140 @@
+//! # Disk Usage Command Tests
+//!
+//! Tests for the per-item and per-category disk usage report (`qs du`).
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::fs;
+
+use common::{
+    create_test_item, create_test_item_with_attachments, qs_cmd, GlobalConfigBuilder, TestEnv,
+};
+use predicates::prelude::*;
+use queuestack::commands;
+
+/// Sets up a small item, a large item with a 2 KB attachment in `bugs`, and a
+/// closed item.
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    create_test_item(&env, "260101-AAA", "Small", "open", &[], None);
+    create_test_item_with_attachments(
+        &env,
+        "260101-BBB",
+        "Large",
+        "open",
+        &["1-dump.bin"],
+        Some("bugs"),
+    );
+    fs::write(
+        env.stack_path()
+            .join("bugs")
+            .join("260101-BBB-large.attachments")
+            .join("1-dump.bin"),
+        vec![0u8; 2048],
+    )
+    .unwrap();
+    create_test_item(&env, "260101-CCC", "Done", "closed", &[], None);
+    fs::rename(
+        env.stack_path().join("260101-CCC-done.md"),
+        env.archive_path().join("260101-CCC-done.md"),
+    )
+    .unwrap();
+    env
+}
+
+fn du_json(env: &TestEnv, args: &[&str]) -> serde_json::Value {
+    let output = qs_cmd(env)
+        .arg("du")
+        .args(args)
+        .args(["--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn test_du_sorts_items_by_size() {
+    let env = setup();
+
+    let report = du_json(&env, &[]);
+    let items = report["items"].as_array().unwrap();
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0]["id"], "260101-BBB");
+    assert_eq!(items[0]["attachment_bytes"], 2048);
+    assert_eq!(items[0]["category"], "bugs");
+    let total = items[0]["total_bytes"].as_u64().unwrap();
+    assert_eq!(
+        total,
+        items[0]["body_bytes"].as_u64().unwrap() + 2048,
+        "total is file plus attachments"
+    );
+
+    let categories = report["categories"].as_array().unwrap();
+    assert_eq!(categories[0]["category"], "bugs");
+    assert_eq!(categories[0]["items"], 1);
+    assert!(categories[1]["category"].is_null());
+    assert_eq!(categories[1]["items"], 2);
+}
+
+#[test]
+fn test_du_threshold_and_limit() {
+    let env = setup();
+
+    let report = du_json(&env, &["--threshold", "2KB", "--limit", "1"]);
+    let items = report["items"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["over_threshold"], true);
+    assert_eq!(report["threshold_bytes"], 2048);
+    // The total covers all items, not just the listed ones
+    assert!(report["total_bytes"].as_u64().unwrap() > items[0]["total_bytes"].as_u64().unwrap());
+}
+
+#[test]
+fn test_du_open_leaves_out_closed_items() {
+    let env = setup();
+
+    let report = du_json(&env, &["--open"]);
+    let ids: Vec<&str> = report["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["id"].as_str().unwrap())
+        .collect();
+    assert!(!ids.contains(&"260101-CCC"));
+    assert_eq!(ids.len(), 2);
+}
+
+#[test]
+fn test_du_text_output() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["du", "--threshold", "2KB"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Categories:"))
+        .stdout(predicate::str::contains("(root)"))
+        .stdout(predicate::str::contains("260101-BBB"))
+        .stdout(predicate::str::contains(
+            "1 listed item(s) at or above 2.0 KB",
+        ));
+}
+
+#[test]
+fn test_du_rejects_invalid_threshold() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["du", "--threshold", "lots"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid threshold 'lots'"));
+}
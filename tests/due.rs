@@ -0,0 +1,168 @@
+//! # Due Date Tests
+//!
+//! Tests for setting due dates with `qs new --due` and `qs update --due`, and
+//! for the `--due-before`, `--due-after`, and `--overdue` list filters, and
+//! reading the `due` frontmatter field.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use common::{qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::{commands, item::Item};
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env
+}
+
+/// Creates an item with `qs new` and returns its path.
+fn new_item(env: &TestEnv, title: &str, due: Option<&str>) -> PathBuf {
+    let mut cmd = qs_cmd(env);
+    cmd.args(["new", title]);
+    if let Some(due) = due {
+        cmd.args(["--due", due]);
+    }
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let path = String::from_utf8(output.stdout).unwrap();
+    env.project_dir.path().join(path.trim())
+}
+
+fn date(s: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+}
+
+#[test]
+fn test_new_with_due_date() {
+    let env = setup();
+
+    let path = new_item(&env, "Release", Some("2026-02-01"));
+
+    let item = Item::load(&path).unwrap();
+    assert_eq!(item.due(), Some(date("2026-02-01")));
+}
+
+#[test]
+fn test_due_date_in_frontmatter() {
+    let env = setup();
+    let path = new_item(&env, "Release", Some("2026-02-01"));
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.contains("\ndue: \"2026-02-01\"\n"), "{content}");
+    assert!(!Item::load(&path)
+        .unwrap()
+        .frontmatter
+        .extra
+        .contains_key("due"));
+
+    // Timestamps are read too, and keep their time of day
+    std::fs::write(
+        &path,
+        content.replace("due: \"2026-02-01\"", "due: 2026-02-01T17:30:00Z"),
+    )
+    .unwrap();
+    let item = Item::load(&path).unwrap();
+    assert_eq!(
+        item.due_date().unwrap().to_rfc3339(),
+        "2026-02-01T17:30:00+00:00"
+    );
+    assert_eq!(item.due(), Some(date("2026-02-01")));
+
+    // Invalid due dates are rejected instead of ignored
+    std::fs::write(
+        &path,
+        content.replace("due: \"2026-02-01\"", "due: someday"),
+    )
+    .unwrap();
+    let err = Item::load(&path).unwrap_err();
+    assert!(
+        format!("{err:#}").contains("invalid timestamp 'someday'"),
+        "{err:#}"
+    );
+}
+
+#[test]
+fn test_new_rejects_invalid_due_date() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["new", "Release", "--due", "someday"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid due date 'someday'"));
+}
+
+#[test]
+fn test_update_sets_and_removes_due_date() {
+    let env = setup();
+    let path = new_item(&env, "Release", None);
+    let id = Item::load(&path).unwrap().id().to_string();
+
+    qs_cmd(&env)
+        .args(["update", "--id", &id, "--due", "2026-03-01"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("due"))
+        .stdout(predicate::str::contains("2026-03-01"));
+    assert_eq!(Item::load(&path).unwrap().due(), Some(date("2026-03-01")));
+
+    qs_cmd(&env)
+        .args(["update", "--id", &id, "--remove-due"])
+        .assert()
+        .success();
+    assert_eq!(Item::load(&path).unwrap().due(), None);
+}
+
+#[test]
+fn test_list_due_range_filters() {
+    let env = setup();
+    new_item(&env, "January", Some("2026-01-15"));
+    new_item(&env, "February", Some("2026-02-15"));
+    new_item(&env, "Undated", None);
+
+    qs_cmd(&env)
+        .args(["list", "--due-before", "2026-01-31"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("january"))
+        .stdout(predicate::str::contains("february").not())
+        .stdout(predicate::str::contains("undated").not());
+
+    qs_cmd(&env)
+        .args([
+            "list",
+            "--due-after",
+            "2026-02-01",
+            "--due-before",
+            "2026-02-15",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("february"))
+        .stdout(predicate::str::contains("january").not());
+}
+
+#[test]
+fn test_list_overdue() {
+    let env = setup();
+    new_item(&env, "Late", Some("2020-01-01"));
+    new_item(&env, "Future", Some("2099-01-01"));
+    new_item(&env, "Undated", None);
+
+    qs_cmd(&env)
+        .args(["list", "--overdue"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("late"))
+        .stdout(predicate::str::contains("future").not())
+        .stdout(predicate::str::contains("undated").not());
+}
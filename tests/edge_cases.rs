@@ -6,9 +6,11 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
 mod common;
 
-use common::{create_test_item, GlobalConfigBuilder, TestEnv};
+use common::{create_test_item, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
 use queuestack::commands::{self, InteractiveArgs, NewArgs, UpdateArgs};
 
 // =============================================================================
@@ -25,6 +27,8 @@ fn test_special_characters_in_title() {
         title: Some("Bug: 100% failure rate (critical!)".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -32,6 +36,7 @@ fn test_special_characters_in_title() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed with special characters");
@@ -48,6 +53,8 @@ fn test_unicode_in_title() {
         title: Some("Support für Umlaute (日本語テスト)".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -55,12 +62,73 @@ fn test_unicode_in_title() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed with unicode");
     assert_eq!(env.count_all_items(), 1);
 }
 
+#[test]
+fn test_transliterated_slug_style() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .slug_style("transliterate")
+            .slug_transliteration("ü", "ue")
+            .build(),
+    );
+    commands::init().expect("init should succeed");
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .slug_transliteration("Ö", "oe")
+            .build(),
+    );
+
+    for title in ["Fehlermeldung prüfen", "Größe ändern", "ログイン画面"] {
+        let args = NewArgs {
+            title: Some(title.to_string()),
+            labels: vec![],
+            category: None,
+            due: None,
+            assignee: None,
+            attachments: vec![],
+            interactive: InteractiveArgs {
+                interactive: false,
+                no_interactive: true,
+            },
+            as_template: false,
+            from_template: None,
+            vars: vec![],
+        };
+        commands::new(args).expect("new should succeed");
+    }
+
+    let mut names: Vec<String> = env
+        .list_stack_files()
+        .iter()
+        .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+        .map(|name| {
+            name.split_once('-')
+                .unwrap()
+                .1
+                .split_once('-')
+                .unwrap()
+                .1
+                .to_string()
+        })
+        .collect();
+    names.sort();
+    assert_eq!(
+        names,
+        [
+            "fehlermeldung-pruefen.md",
+            "groesse-andern.md",
+            "roguin-画面.md"
+        ]
+    );
+}
+
 #[test]
 fn test_empty_title() {
     let env = TestEnv::new();
@@ -69,9 +137,11 @@ fn test_empty_title() {
 
     // Empty title should be rejected
     let args = NewArgs {
-        title: Some("".to_string()),
+        title: Some(String::new()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -79,6 +149,7 @@ fn test_empty_title() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     let result = commands::new(args);
@@ -97,6 +168,8 @@ fn test_very_long_title() {
         title: Some(long_title),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -104,6 +177,7 @@ fn test_very_long_title() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed with long title");
@@ -125,6 +199,8 @@ fn test_whitespace_only_title() {
         title: Some("   ".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -132,6 +208,7 @@ fn test_whitespace_only_title() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     // Whitespace-only title should be rejected (trimmed to empty)
@@ -150,6 +227,8 @@ fn test_category_with_special_characters() {
         title: Some("Task".to_string()),
         labels: vec![],
         category: Some("my-category_v2".to_string()),
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -157,6 +236,7 @@ fn test_category_with_special_characters() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     let result = commands::new(args);
@@ -180,6 +260,8 @@ fn test_label_with_special_characters() {
             "priority_high".to_string(),
         ],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -187,6 +269,7 @@ fn test_label_with_special_characters() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -211,6 +294,8 @@ fn test_duplicate_labels_ignored() {
         title: Some("Task".to_string()),
         labels: vec!["bug".to_string(), "bug".to_string(), "bug".to_string()],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -218,6 +303,7 @@ fn test_duplicate_labels_ignored() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -255,6 +341,13 @@ fn test_partial_id_matching() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
     };
 
     commands::update(args).expect("update with partial ID should succeed");
@@ -278,8 +371,67 @@ fn test_ambiguous_partial_id() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
     };
 
     let result = commands::update(args);
     assert!(result.is_err(), "update with ambiguous ID should fail");
 }
+
+// =============================================================================
+// Lenient Frontmatter
+// =============================================================================
+
+#[test]
+fn test_hand_written_frontmatter_is_fixed_on_save() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    let path = env.stack_path().join("260101-ABCD-bug-login-crash.md");
+    std::fs::write(
+        &path,
+        "---\nid: 260101-ABCD\nTitle: Bug: login crash\nauthor: Agent\n\
+         created_at: 2026-01-01T10:00:00Z\nstatus: Open\nlabels: bug, ui\n---\n\nBody\n",
+    )
+    .unwrap();
+
+    let item = queuestack::Item::load(&path).expect("item should load");
+    assert_eq!(item.title(), "Bug: login crash");
+    assert_eq!(item.labels(), ["bug", "ui"]);
+    assert_eq!(item.fixes.len(), 4);
+    assert_eq!(env.count_all_items(), 1);
+
+    let args = UpdateArgs {
+        id: Some("260101-ABCD".to_string()),
+        file: None,
+        title: None,
+        labels: vec![],
+        remove_labels: vec![],
+        category: None,
+        remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
+    };
+    commands::update(args).expect("update should save the fixes");
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(
+        content.contains("title: \"Bug: login crash\"\n"),
+        "{content}"
+    );
+    assert!(content.contains("status: open\n"), "{content}");
+    assert!(content.contains("labels:\n  - bug\n  - ui\n"), "{content}");
+    assert!(queuestack::Item::load(&path).unwrap().fixes.is_empty());
+}
@@ -0,0 +1,94 @@
+//! # Export Tests
+//!
+//! Tests for `qs export` (Org-mode and `TaskPaper`).
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::fs;
+
+use common::{create_test_item, GlobalConfigBuilder, TestEnv};
+use queuestack::commands::{self, ExportArgs, ExportFormat, PatchArgs, StatusFilter};
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+    env
+}
+
+fn export(env: &TestEnv, format: ExportFormat, status: StatusFilter) -> String {
+    let output = env.project_path().join("export.out");
+    commands::export(&ExportArgs {
+        format,
+        status,
+        output: Some(output.clone()),
+        force: false,
+    })
+    .expect("export should succeed");
+    fs::read_to_string(output).unwrap()
+}
+
+fn patch(id: &str, set: &[&str]) {
+    commands::patch(&PatchArgs {
+        id: Some(id.to_string()),
+        file: None,
+        set: set.iter().map(|s| (*s).to_string()).collect(),
+        patch_file: None,
+        force: false,
+    })
+    .expect("patch should succeed");
+}
+
+#[test]
+fn test_export_org() {
+    let env = setup();
+    let path = create_test_item(
+        &env,
+        "260101-AAA",
+        "Login bug",
+        "open",
+        &["bug", "high-priority"],
+        Some("backend"),
+    );
+    let content = env.read_item(&path);
+    fs::write(&path, format!("{content}\n## Steps\n* click login\n")).unwrap();
+    patch("260101", &["due=2026-03-01", "status=in-progress"]);
+
+    let org = export(&env, ExportFormat::Org, StatusFilter::Open);
+    assert!(org.contains("#+TODO: TODO STARTED | DONE"));
+    assert!(org.contains("* STARTED Login bug :bug:high_priority:\n"));
+    assert!(org.contains("DEADLINE: <2026-03-01 Sun>"));
+    assert!(org.contains(":ID: 260101-AAA"));
+    assert!(org.contains(":CATEGORY: backend"));
+    assert!(org.contains("\n*** Steps\n"));
+    assert!(org.contains("\n- click login\n"));
+}
+
+#[test]
+fn test_export_taskpaper() {
+    let env = setup();
+    create_test_item(
+        &env,
+        "260101-AAA",
+        "Login bug",
+        "open",
+        &["bug"],
+        Some("backend"),
+    );
+    create_test_item(&env, "260102-BBB", "Write docs", "open", &[], None);
+    create_test_item(&env, "260103-CCC", "Old task", "open", &[], None);
+    commands::execute_close(Some("260103".to_string()), None, None).unwrap();
+
+    let open = export(&env, ExportFormat::Taskpaper, StatusFilter::Open);
+    assert!(open.starts_with("Uncategorized:\n\t- Write docs @id(260102-BBB)\n"));
+    assert!(open.contains("backend:\n\t- Login bug @bug @id(260101-AAA)\n"));
+    assert!(!open.contains("Old task"));
+
+    let all = export(&env, ExportFormat::Taskpaper, StatusFilter::All);
+    assert!(all.contains("\t- Old task @done @id(260103-CCC)\n"));
+}
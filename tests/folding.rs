@@ -0,0 +1,78 @@
+//! # Folding Tests
+//!
+//! Tests that labels, categories, and search queries are compared ignoring
+//! case everywhere, and ignoring diacritics with `fold_accents`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    create_test_item(&env, "260101-AAA", "Café menu", "open", &["Bug"], None);
+    create_test_item(&env, "260102-BBB", "Login", "open", &["bug"], None);
+    create_test_item(&env, "260103-CCC", "Crash", "open", &["bug", "ui"], None);
+    env
+}
+
+#[test]
+fn test_label_counts_merge_case() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["list", "--labels"])
+        .assert()
+        .success()
+        .stdout("bug (3)\nui (1)\n");
+}
+
+#[test]
+fn test_label_filter_ignores_case() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["list", "--label", "BUG"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260101-AAA"))
+        .stdout(predicate::str::contains("260102-BBB"))
+        .stdout(predicate::str::contains("260103-CCC"));
+}
+
+#[test]
+fn test_group_by_label_merges_case() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["list", "--group-by", "label"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Bug").not());
+}
+
+#[test]
+fn test_search_accents_only_with_fold_accents() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["search", "cafe"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No items found"));
+
+    env.write_project_config(&ProjectConfigBuilder::new().fold_accents(true).build());
+    qs_cmd(&env)
+        .args(["search", "CAFE"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260101-AAA"));
+}
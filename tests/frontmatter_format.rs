@@ -0,0 +1,116 @@
+//! # Frontmatter Format Tests
+//!
+//! Tests for TOML frontmatter (`+++` delimiters) and the `frontmatter_format`
+//! option.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{create_test_item, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
+use queuestack::{
+    commands::{self, InteractiveArgs, NewArgs, UpdateArgs},
+    storage, Config,
+};
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env
+}
+
+fn new_item(title: &str) {
+    commands::new(NewArgs {
+        title: Some(title.to_string()),
+        labels: vec!["docs".to_string()],
+        category: None,
+        due: None,
+        assignee: None,
+        attachments: vec![],
+        interactive: InteractiveArgs {
+            interactive: false,
+            no_interactive: true,
+        },
+        as_template: false,
+        from_template: None,
+        vars: vec![],
+    })
+    .expect("new");
+}
+
+fn update_title(id: &str, title: &str) {
+    commands::update(UpdateArgs {
+        id: Some(id.to_string()),
+        file: None,
+        title: Some(title.to_string()),
+        labels: vec![],
+        remove_labels: vec![],
+        category: None,
+        remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
+    })
+    .expect("update");
+}
+
+#[test]
+fn test_new_items_use_toml_when_configured() {
+    let env = setup();
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .frontmatter_format("toml")
+            .build(),
+    );
+
+    new_item("Hugo page");
+
+    let files = env.list_stack_files();
+    assert_eq!(files.len(), 1);
+    let content = std::fs::read_to_string(&files[0]).unwrap();
+    assert!(content.starts_with("+++\n"), "{content}");
+    assert!(content.contains("title = \"Hugo page\"\n"), "{content}");
+    assert!(content.contains("labels = [\"docs\"]\n"), "{content}");
+
+    let config = Config::load().unwrap();
+    let items = storage::load_all_items(&config);
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].title(), "Hugo page");
+}
+
+#[test]
+fn test_items_keep_their_frontmatter_format() {
+    let env = setup();
+    let yaml = create_test_item(&env, "260101-AAA", "Yaml item", "open", &[], None);
+    let toml = env.stack_path().join("260101-BBB-toml-item.md");
+    std::fs::write(
+        &toml,
+        "+++\nid = \"260101-BBB\"\ntitle = \"Toml item\"\nauthor = \"Hugo\"\n\
+         created_at = 2026-01-01T10:00:00Z\nstatus = \"open\"\n+++\n\nBody\n",
+    )
+    .unwrap();
+
+    let config = Config::load().unwrap();
+    assert_eq!(storage::load_all_items(&config).len(), 2);
+
+    update_title("260101-BBB", "Toml renamed");
+    update_title("260101-AAA", "Yaml renamed");
+
+    let toml = env.stack_path().join("260101-BBB-toml-renamed.md");
+    let content = std::fs::read_to_string(toml).unwrap();
+    assert!(content.starts_with("+++\n"), "{content}");
+    assert!(content.contains("title = \"Toml renamed\"\n"), "{content}");
+    assert!(content.ends_with("Body\n"), "{content}");
+
+    let yaml = yaml.with_file_name("260101-AAA-yaml-renamed.md");
+    let content = std::fs::read_to_string(yaml).unwrap();
+    assert!(content.starts_with("---\n"), "{content}");
+}
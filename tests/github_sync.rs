@@ -0,0 +1,280 @@
+//! # GitHub Sync Tests
+//!
+//! Tests for `qs sync github`. A stand-in `gh` on `PATH` serves the issue
+//! and label lists (one JSON object per line, as `--jq '.[]'` prints them)
+//! and records every call.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![cfg(unix)]
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::{
+    fmt::Write as _,
+    fs,
+    os::unix::fs::PermissionsExt,
+    path::{Path, PathBuf},
+};
+
+use common::{
+    create_test_item, qs_cmd_with_path, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv,
+};
+use predicates::prelude::*;
+use queuestack::{
+    commands,
+    item::{Item, Status},
+};
+use serde_json::Value;
+
+/// Writes a `gh` that lists `issues` and the labels of `labels.json` (only
+/// `bug` at first), answers `issue create` with issue #101, and logs its
+/// arguments to `gh.log`.
+fn fake_gh(env: &TestEnv, issues: &str) -> PathBuf {
+    let bin = env.home_dir.path().join("bin");
+    fs::create_dir_all(&bin).unwrap();
+    write_json(env, "issues.json", issues);
+    write_json(env, "labels.json", r#"[{"name": "bug"}]"#);
+    let script = bin.join("gh");
+    fs::write(
+        &script,
+        format!(
+            "#!/bin/sh\necho \"$*\" >> \"{log}\"\n\
+             case \"$1 $2\" in\n\
+             \"api repos/acme/app/issues\") cat \"{issues}\" ;;\n\
+             \"api repos/acme/app/labels\") cat \"{labels}\" ;;\n\
+             \"issue create\") echo 'https://github.com/acme/app/issues/101' ;;\n\
+             esac\n",
+            log = gh_log_path(env).display(),
+            issues = env.home_dir.path().join("issues.json").display(),
+            labels = env.home_dir.path().join("labels.json").display()
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+    bin
+}
+
+/// Replaces a list served by the stand-in gh, given as a JSON array.
+fn write_json(env: &TestEnv, name: &str, json: &str) {
+    let Value::Array(elements) = serde_json::from_str(json).unwrap() else {
+        panic!("expected a JSON array");
+    };
+    let lines = elements.iter().fold(String::new(), |mut lines, e| {
+        let _ = writeln!(lines, "{e}");
+        lines
+    });
+    fs::write(env.home_dir.path().join(name), lines).unwrap();
+}
+
+fn gh_log_path(env: &TestEnv) -> PathBuf {
+    env.home_dir.path().join("gh.log")
+}
+
+fn gh_log(env: &TestEnv) -> String {
+    fs::read_to_string(gh_log_path(env)).unwrap_or_default()
+}
+
+fn setup(env: &TestEnv) {
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .github("acme/app", &[("ui", "area: ui"), ("wip", "")])
+            .build(),
+    );
+}
+
+/// Links an item file to a GitHub issue.
+fn link_issue(path: &Path, number: u64) {
+    let mut item = Item::load(path).unwrap();
+    item.set_github_issue(number);
+    item.save(path).unwrap();
+}
+
+#[test]
+fn test_sync_creates_issues_with_mapped_labels() {
+    let env = TestEnv::new();
+    setup(&env);
+    let path = create_test_item(
+        &env,
+        "260101-AAA",
+        "Crash",
+        "open",
+        &["bug", "ui", "wip"],
+        None,
+    );
+    let bin = fake_gh(&env, "[]");
+
+    qs_cmd_with_path(&env, &bin)
+        .args(["sync", "github"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created #101 for 260101-AAA"))
+        .stdout(predicate::str::contains("1 created"));
+
+    assert_eq!(Item::load(&path).unwrap().github_issue(), Some(101));
+    let log = gh_log(&env);
+    assert!(log.contains("--repo acme/app"), "{log}");
+    assert!(
+        log.contains("api repos/acme/app/issues --method GET --paginate -f state=all"),
+        "{log}"
+    );
+    assert!(log.contains("label create area: ui"), "{log}");
+    assert!(!log.contains("label create bug"), "{log}");
+    assert!(
+        log.contains(
+            "issue create --title Crash --body Test item body. --label bug --label area: ui --repo"
+        ),
+        "{log}"
+    );
+
+    // Linked items in line with their issue aren't touched
+    write_json(
+        &env,
+        "issues.json",
+        r#"[{"number": 101, "state": "OPEN", "title": "Crash", "body": "Test item body.\n",
+             "labels": [{"name": "area: ui"}, {"name": "bug"}]}]"#,
+    );
+    write_json(
+        &env,
+        "labels.json",
+        r#"[{"name": "bug"}, {"name": "Area: UI"}]"#,
+    );
+    qs_cmd_with_path(&env, &bin)
+        .args(["sync", "github"])
+        .assert()
+        .success()
+        .stdout("Everything is in sync.\n");
+}
+
+#[test]
+fn test_sync_propagates_closes_both_ways() {
+    let env = TestEnv::new();
+    setup(&env);
+    let remote_closed = create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+    link_issue(&remote_closed, 1);
+    let local_closed = create_test_item(&env, "260101-BBB", "Typo", "closed", &[], None);
+    link_issue(&local_closed, 2);
+    let renamed = create_test_item(&env, "260101-CCC", "New title", "open", &["bug"], None);
+    link_issue(&renamed, 3);
+    let bin = fake_gh(
+        &env,
+        r#"[
+  {"number": 1, "state": "CLOSED", "title": "Crash", "body": "Test item body.", "labels": []},
+  {"number": 2, "state": "OPEN", "title": "Typo", "body": "Test item body.", "labels": []},
+  {"number": 3, "state": "OPEN", "title": "Old title", "body": "Test item body.",
+   "labels": [{"name": "ui-only"}, {"name": "area: ui"}]}
+]"#,
+    );
+    let unrelated = create_test_item(&env, "260101-DDD", "Other", "open", &["ui"], None);
+    link_issue(&unrelated, 4);
+
+    qs_cmd_with_path(&env, &bin)
+        .args(["sync", "github"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Closed 260101-AAA (#1 is closed)"))
+        .stdout(predicate::str::contains("Closed #2 (260101-BBB is closed)"))
+        .stdout(predicate::str::contains("Updated #3 from 260101-CCC"))
+        .stderr(predicate::str::contains(
+            "Issue #4 of 260101-DDD not found on GitHub",
+        ));
+
+    assert!(!remote_closed.exists());
+    let archived = env.find_item_by_id("260101-AAA").unwrap();
+    assert_eq!(Item::load(&archived).unwrap().status(), Status::Closed);
+
+    let log = gh_log(&env);
+    assert!(log.contains("issue close 2"), "{log}");
+    // Labels used by items are managed; others set on GitHub stay
+    assert!(
+        log.contains(
+            "issue edit 3 --title New title --add-label bug --remove-label area: ui --repo"
+        ),
+        "{log}"
+    );
+}
+
+#[test]
+fn test_sync_dry_run_changes_nothing() {
+    let env = TestEnv::new();
+    setup(&env);
+    let path = create_test_item(&env, "260101-AAA", "Crash", "open", &["ui"], None);
+    let before = fs::read_to_string(&path).unwrap();
+    let bin = fake_gh(&env, "[]");
+
+    qs_cmd_with_path(&env, &bin)
+        .args(["sync", "github", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Would create label 'area: ui'"))
+        .stdout(predicate::str::contains(
+            "Would create an issue for 260101-AAA: Crash",
+        ))
+        .stdout(predicate::str::contains("Would sync: 1 created"));
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), before);
+    let log = gh_log(&env);
+    assert!(!log.contains("create"), "{log}");
+}
+
+#[test]
+fn test_sync_reports_gh_failures() {
+    let env = TestEnv::new();
+    setup(&env);
+    let bin = env.home_dir.path().join("bin");
+    fs::create_dir_all(&bin).unwrap();
+    let script = bin.join("gh");
+    fs::write(
+        &script,
+        "#!/bin/sh\necho 'authentication required' >&2\nexit 4\n",
+    )
+    .unwrap();
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+    qs_cmd_with_path(&env, &bin)
+        .args(["sync", "github"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "gh api repos/acme/app/issues failed: authentication required",
+        ));
+}
+
+#[test]
+fn test_sync_skips_protected_items() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .protected(&["security"], &[], &[])
+            .build(),
+    );
+    commands::init().expect("init");
+    env.write_project_config(&ProjectConfigBuilder::new().github("acme/app", &[]).build());
+    let secret = create_test_item(&env, "260101-AAA", "Leak", "open", &[], Some("security"));
+    create_test_item(&env, "260101-BBB", "Typo", "open", &[], None);
+    let bin = fake_gh(&env, "[]");
+
+    qs_cmd_with_path(&env, &bin)
+        .args(["sync", "github"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created #101 for 260101-BBB"))
+        .stdout(predicate::str::contains("1 created"))
+        .stderr(predicate::str::contains(
+            "Cannot export '260101-AAA': category 'security' is protected",
+        ));
+    assert!(!gh_log(&env).contains("--title Leak"));
+    assert_eq!(Item::load(&secret).unwrap().github_issue(), None);
+
+    qs_cmd_with_path(&env, &bin)
+        .args(["sync", "github", "--force"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created #101 for 260101-AAA"));
+    assert!(gh_log(&env).contains("--title Leak"));
+}
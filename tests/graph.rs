@@ -0,0 +1,153 @@
+//! # Graph Command Tests
+//!
+//! Tests for rendering the dependency graph with `qs graph` as mermaid and
+//! Graphviz DOT.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::{fs, path::Path};
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env
+}
+
+/// Adds frontmatter lines to an item file.
+fn add_fields(path: &Path, fields: &str) {
+    let content = fs::read_to_string(path).unwrap();
+    fs::write(
+        path,
+        content.replacen("status:", &format!("{fields}\nstatus:"), 1),
+    )
+    .unwrap();
+}
+
+/// Creates a blocker, a blocked item, a child, and an unrelated item.
+fn create_graph(env: &TestEnv) {
+    let blocker = create_test_item(env, "260101-AAA", "Schema", "open", &[], None);
+    create_test_item(env, "260102-BBB", "API", "in-progress", &[], None);
+    let child = create_test_item(env, "260103-CCC", "Docs", "open", &[], None);
+    create_test_item(env, "260104-DDD", "Unrelated", "open", &[], None);
+    add_fields(&blocker, "blocks: 260102-BBB\nmilestone: v2");
+    add_fields(
+        &child,
+        "parent: 260102-BBB\nblocked_by: [260101-AAA, 260109-ZZZ]",
+    );
+}
+
+#[test]
+fn test_graph_mermaid() {
+    let env = setup();
+    create_graph(&env);
+
+    qs_cmd(&env).arg("graph").assert().success().stdout(
+        "flowchart LR\n    \
+         n0[\"260101-AAA: Schema\"]\n    \
+         n1[\"260102-BBB: API\"]:::inProgress\n    \
+         n2[\"260103-CCC: Docs\"]\n    \
+         n0 -->|blocks| n1\n    \
+         n0 -->|blocks| n2\n    \
+         n1 -.->|parent of| n2\n    \
+         classDef inProgress stroke-width:3px\n",
+    );
+}
+
+#[test]
+fn test_graph_dot() {
+    let env = setup();
+    create_graph(&env);
+
+    qs_cmd(&env)
+        .args(["graph", "--format", "dot"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("digraph queuestack {\n"))
+        .stdout(predicate::str::contains(
+            "\"260102-BBB\" [label=\"260102-BBB\\nAPI\", style=bold];",
+        ))
+        .stdout(predicate::str::contains(
+            "\"260101-AAA\" -> \"260102-BBB\" [label=\"blocks\"];",
+        ))
+        .stdout(predicate::str::contains(
+            "\"260102-BBB\" -> \"260103-CCC\" [label=\"parent of\", style=dashed];",
+        ))
+        .stdout(predicate::str::contains("260104-DDD").not());
+}
+
+#[test]
+fn test_graph_skips_closed_items() {
+    let env = setup();
+    create_graph(&env);
+    let path = env.stack_path().join("260101-AAA-schema.md");
+    let content = fs::read_to_string(&path).unwrap();
+    fs::write(&path, content.replace("status: open", "status: closed")).unwrap();
+
+    qs_cmd(&env)
+        .arg("graph")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260101-AAA").not())
+        .stdout(predicate::str::contains("parent of"));
+}
+
+#[test]
+fn test_graph_milestone_scope() {
+    let env = setup();
+    create_graph(&env);
+
+    // Only the blocker is in the milestone, so nothing is connected
+    qs_cmd(&env)
+        .args(["graph", "--milestone", "V2"])
+        .assert()
+        .success()
+        .stdout("flowchart LR\n");
+}
+
+#[test]
+fn test_graph_output_file() {
+    let env = setup();
+    create_graph(&env);
+
+    qs_cmd(&env)
+        .args(["graph", "--output", "deps.mmd"])
+        .assert()
+        .success()
+        .stdout("");
+
+    let content = fs::read_to_string(env.project_dir.path().join("deps.mmd")).unwrap();
+    assert!(content.starts_with("flowchart LR\n"));
+}
+
+#[test]
+fn test_graph_tui_requires_terminal() {
+    let env = setup();
+    create_graph(&env);
+
+    qs_cmd(&env)
+        .args(["graph", "--tui"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("requires a terminal"));
+}
+
+#[test]
+fn test_graph_tui_conflicts_with_format() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["graph", "--tui", "--format", "dot"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
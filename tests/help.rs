@@ -0,0 +1,80 @@
+//! # Help Command Tests
+//!
+//! Tests for `qs help` topics, topic search, and command help.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use common::{qs_cmd, TestEnv};
+use predicates::prelude::*;
+
+#[test]
+fn test_help_topic_is_plain_text_without_project() {
+    let env = TestEnv::new();
+
+    qs_cmd(&env)
+        .args(["help", "ids"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("Item IDs\n"))
+        .stdout(predicate::str::contains("\nPartial IDs\n"))
+        .stdout(predicate::str::contains("      qs show --id 02f7k\n"))
+        .stdout(predicate::str::contains("`").not())
+        .stdout(predicate::str::contains("\x1b[").not());
+}
+
+#[test]
+fn test_help_lists_topics() {
+    let env = TestEnv::new();
+
+    qs_cmd(&env)
+        .arg("help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Usage: qs"))
+        .stdout(predicate::str::contains(
+            "Help topics:\n  ids        Item IDs, ID patterns, and partial matching\n",
+        ))
+        .stdout(predicate::str::contains("  agents     "));
+}
+
+#[test]
+fn test_help_search() {
+    let env = TestEnv::new();
+
+    qs_cmd(&env)
+        .args(["help", "--search", "no-interactive"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("agents\n"))
+        .stdout(predicate::str::contains(
+            "  Pass --no-interactive (or set interactive = false) so no selector,\n",
+        ));
+
+    qs_cmd(&env)
+        .args(["help", "--search", "xyzzy"])
+        .assert()
+        .success()
+        .stdout("No help topics match 'xyzzy'.\n");
+}
+
+#[test]
+fn test_help_for_command() {
+    let env = TestEnv::new();
+
+    qs_cmd(&env)
+        .args(["help", "trash", "restore"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Usage: qs trash restore"));
+
+    qs_cmd(&env)
+        .args(["help", "nope"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Unknown help topic or command 'nope'. Topics: ids, config, workflow, agents",
+        ));
+}
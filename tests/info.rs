@@ -0,0 +1,90 @@
+//! # Info Command Tests
+//!
+//! Tests for the `qs info` project overview and its problem checks.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::fs;
+
+use common::{
+    create_test_item, create_test_item_with_attachments, qs_cmd, GlobalConfigBuilder, TestEnv,
+};
+use predicates::prelude::*;
+use queuestack::commands;
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env
+}
+
+#[test]
+fn test_info_overview() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "One", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Two", "in-progress", &[], Some("bugs"));
+
+    qs_cmd(&env)
+        .arg("info")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Project: "))
+        .stdout(predicate::str::contains("  interactive      false"))
+        .stdout(predicate::str::contains("  stack "))
+        .stdout(predicate::str::contains(
+            "Items: 1 open, 1 in progress, 0 closed, 0 templates",
+        ))
+        .stdout(predicate::str::contains("Attachment store: not created"))
+        .stdout(predicate::str::contains("Problems: none"));
+}
+
+#[test]
+fn test_info_reports_problems() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "One", "open", &[], None);
+    create_test_item(&env, "260101-AAA", "Copy", "open", &[], Some("bugs"));
+    create_test_item(&env, "260102-BBB", "Done", "closed", &[], None);
+    let item =
+        create_test_item_with_attachments(&env, "260103-CCC", "Shot", "open", &["1-a.png"], None);
+    fs::remove_file(
+        item.with_file_name("260103-CCC-shot.attachments")
+            .join("1-a.png"),
+    )
+    .unwrap();
+    fs::write(env.stack_path().join("broken.md"), "no frontmatter").unwrap();
+
+    qs_cmd(&env)
+        .arg("info")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Problems:\n"))
+        .stdout(predicate::str::contains("Duplicate ID 260101-AAA"))
+        .stdout(predicate::str::contains(
+            "260102-BBB-done.md is closed but not archived",
+        ))
+        .stdout(predicate::str::contains("lists missing attachment 1-a.png"))
+        .stdout(predicate::str::contains(
+            "Cannot parse queuestack/broken.md",
+        ));
+}
+
+#[test]
+fn test_info_reports_unknown_global_keys() {
+    let env = setup();
+    let config = GlobalConfigBuilder::new().interactive(false).build();
+    env.write_global_config(&format!("{config}\nmystery_option = true\n"));
+
+    qs_cmd(&env)
+        .arg("info")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Unknown key 'mystery_option' in global config",
+        ));
+}
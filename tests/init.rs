@@ -5,10 +5,12 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
 mod common;
 
 use common::{GlobalConfigBuilder, TestEnv};
-use queuestack::commands;
+use queuestack::commands::{self, InitArgs};
 
 #[test]
 fn test_init_creates_project_structure() {
@@ -61,3 +63,54 @@ fn test_init_fails_if_already_initialized() {
     let result = commands::init();
     assert!(result.is_err(), "Second init should fail");
 }
+
+#[test]
+fn test_init_writes_git_files() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().build());
+    std::fs::write(env.project_path().join(".gitignore"), "target/\n").unwrap();
+
+    commands::init_with(&InitArgs {
+        gitattributes: true,
+        gitignore: true,
+        lfs: Some(vec!["*.psd".to_string()]),
+    })
+    .expect("init should succeed");
+    assert!(env.stack_path().exists());
+
+    let attributes = std::fs::read_to_string(env.project_path().join(".gitattributes")).unwrap();
+    assert_eq!(
+        attributes,
+        "# queuestack\n\
+         queuestack/**/*.md merge=queuestack\n\
+         queuestack/**/*.attachments/*.psd filter=lfs diff=lfs merge=lfs -text\n"
+    );
+    let ignore = std::fs::read_to_string(env.project_path().join(".gitignore")).unwrap();
+    assert_eq!(
+        ignore,
+        "target/\n\n# queuestack\nqueuestack/.store.attachments/\nqueuestack/**/.*.dedup\nqueuestack/.cache/\n"
+    );
+}
+
+#[test]
+fn test_init_git_files_in_initialized_project() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().build());
+    commands::init().expect("init should succeed");
+    assert!(!env.project_path().join(".gitattributes").exists());
+
+    // Adding git files to an existing project is allowed, and repeatable
+    let args = InitArgs {
+        lfs: Some(vec![]),
+        ..InitArgs::default()
+    };
+    commands::init_with(&args).expect("git setup should succeed");
+    commands::init_with(&args).expect("git setup should be repeatable");
+
+    let attributes = std::fs::read_to_string(env.project_path().join(".gitattributes")).unwrap();
+    assert_eq!(attributes.matches("*.png filter=lfs").count(), 1);
+    assert!(attributes.contains("*.pdf filter=lfs"));
+    assert!(!env.project_path().join(".gitignore").exists());
+
+    assert!(commands::init().is_err(), "plain init still fails");
+}
@@ -0,0 +1,77 @@
+//! # Insights Command Tests
+//!
+//! Tests for the opt-in usage log and `qs insights`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+fn usage_log(env: &TestEnv) -> std::path::PathBuf {
+    env.home_dir.path().join(".config/queuestack/usage.jsonl")
+}
+
+#[test]
+fn test_nothing_recorded_by_default() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+
+    qs_cmd(&env).args(["list"]).assert().success();
+    assert!(!usage_log(&env).exists());
+
+    qs_cmd(&env)
+        .arg("insights")
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("Usage logging is off.\n"));
+}
+
+#[test]
+fn test_insights_summarizes_recorded_commands() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .usage_log(true)
+            .build(),
+    );
+    commands::init().expect("init");
+
+    qs_cmd(&env).args(["list"]).assert().success();
+    qs_cmd(&env).args(["list", "--closed"]).assert().success();
+    qs_cmd(&env)
+        .args(["new", "Secret title", "--label", "private"])
+        .assert()
+        .success();
+    qs_cmd(&env).args(["__complete", "ids"]).assert().success();
+
+    // Only command names are recorded
+    let log = std::fs::read_to_string(usage_log(&env)).unwrap();
+    assert_eq!(log.lines().count(), 3);
+    assert!(!log.contains("Secret") && !log.contains("private") && !log.contains("closed"));
+
+    qs_cmd(&env)
+        .args(["insights", "--days", "0"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "3 commands on 1 day (all time)\n",
+        ))
+        .stdout(predicate::str::contains(
+            "  list      2   67%  ████████████████████\n  new       1   33%  ██████████\n",
+        ))
+        .stdout(predicate::str::contains("Busiest weekday: "));
+
+    qs_cmd(&env)
+        .args(["insights", "--clear"])
+        .assert()
+        .success();
+    assert!(!usage_log(&env).exists());
+}
@@ -5,10 +5,14 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
 mod common;
 
 use common::{create_test_item, GlobalConfigBuilder, TestEnv};
-use queuestack::commands::{self, InteractiveArgs, ListMode, ListOptions, SortBy, StatusFilter};
+use queuestack::commands::{
+    self, InteractiveArgs, ListMode, ListOptions, OutputFormat, Scope, SortBy,
+};
 
 #[test]
 fn test_labels_empty_project() {
@@ -18,17 +22,33 @@ fn test_labels_empty_project() {
 
     let filter = ListOptions {
         mode: ListMode::Labels,
-        status: StatusFilter::Open,
+        scope: Scope::Open,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     let result = commands::list(&filter);
@@ -47,17 +67,33 @@ fn test_labels_shows_unique_labels() {
 
     let filter = ListOptions {
         mode: ListMode::Labels,
-        status: StatusFilter::Open,
+        scope: Scope::Open,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     let result = commands::list(&filter);
@@ -94,17 +130,33 @@ fn test_labels_includes_archived_items() {
 
     let filter = ListOptions {
         mode: ListMode::Labels,
-        status: StatusFilter::Open,
+        scope: Scope::Open,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     // Should include labels from both open and archived items
@@ -120,17 +172,33 @@ fn test_labels_without_init() {
 
     let filter = ListOptions {
         mode: ListMode::Labels,
-        status: StatusFilter::Open,
+        scope: Scope::Open,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     let result = commands::list(&filter);
@@ -0,0 +1,128 @@
+//! # Link Command Tests
+//!
+//! Tests for recording dependencies with `qs link` and listing blocked and
+//! unblocked items.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::fs;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env
+}
+
+fn list(env: &TestEnv, flag: &str) -> String {
+    let output = qs_cmd(env).args(["list", flag]).output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_link_stores_full_ids() {
+    let env = setup();
+    let schema = create_test_item(&env, "260101-AAA", "Schema", "open", &[], None);
+    let api = create_test_item(&env, "260101-BBB", "API", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["link", "--id", "AAA", "--blocks", "BBB"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Linked"));
+    qs_cmd(&env)
+        .args(["link", "--id", "BBB", "--depends-on", "AAA"])
+        .assert()
+        .success();
+
+    let content = fs::read_to_string(&schema).unwrap();
+    assert!(content.contains("blocks:\n  - \"260101-BBB\""), "{content}");
+    let content = fs::read_to_string(&api).unwrap();
+    assert!(
+        content.contains("depends_on:\n  - \"260101-AAA\""),
+        "{content}"
+    );
+}
+
+#[test]
+fn test_link_rejects_unknown_and_self() {
+    let env = setup();
+    let path = create_test_item(&env, "260101-AAA", "Schema", "open", &[], None);
+    let before = fs::read_to_string(&path).unwrap();
+
+    qs_cmd(&env)
+        .args(["link", "--id", "AAA", "--blocks", "ZZZ"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("ZZZ"));
+    qs_cmd(&env)
+        .args(["link", "--id", "AAA", "--depends-on", "AAA"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("linked to itself"));
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), before);
+}
+
+#[test]
+fn test_list_blocked_and_unblocked() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Schema", "open", &[], None);
+    create_test_item(&env, "260101-BBB", "API", "open", &[], None);
+    create_test_item(&env, "260101-CCC", "Docs", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["link", "--id", "BBB", "--depends-on", "AAA"])
+        .assert()
+        .success();
+    qs_cmd(&env)
+        .args(["link", "--id", "BBB", "--blocks", "CCC"])
+        .assert()
+        .success();
+
+    let blocked = list(&env, "--blocked");
+    assert!(!blocked.contains("260101-AAA"));
+    assert!(blocked.contains("260101-BBB"));
+    assert!(blocked.contains("260101-CCC"));
+
+    let unblocked = list(&env, "--unblocked");
+    assert!(unblocked.contains("260101-AAA"));
+    assert!(!unblocked.contains("260101-BBB"));
+
+    // Closing the blocker frees the item
+    qs_cmd(&env)
+        .args(["close", "--id", "AAA"])
+        .assert()
+        .success();
+    assert!(list(&env, "--unblocked").contains("260101-BBB"));
+}
+
+#[test]
+fn test_link_remove() {
+    let env = setup();
+    let path = create_test_item(&env, "260101-AAA", "Schema", "open", &[], None);
+    create_test_item(&env, "260101-BBB", "API", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["link", "--id", "AAA", "--blocks", "BBB"])
+        .assert()
+        .success();
+    qs_cmd(&env)
+        .args(["link", "--id", "AAA", "--blocks", "260101-BBB", "--remove"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Unlinked"));
+
+    assert!(!fs::read_to_string(&path).unwrap().contains("blocks"));
+    assert!(!list(&env, "--blocked").contains("260101-BBB"));
+}
@@ -0,0 +1,97 @@
+//! # Link Title Tests
+//!
+//! Tests for fetching page titles of URL attachments (`fetch_link_titles`).
+//! A stand-in `curl` on `PATH` serves the pages.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![cfg(unix)]
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::{fs, os::unix::fs::PermissionsExt, path::PathBuf};
+
+use common::{
+    create_test_item, qs_cmd_with_path, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv,
+};
+use predicates::prelude::*;
+use queuestack::{commands, item::Item};
+
+const ISSUE: &str = "https://github.com/org/repo/issues/42";
+const MISSING: &str = "https://example.com/missing";
+
+/// Writes a `curl` that answers the issue URL with a page and fails otherwise.
+fn fake_curl(env: &TestEnv) -> PathBuf {
+    let bin = env.home_dir.path().join("bin");
+    fs::create_dir_all(&bin).unwrap();
+    let script = bin.join("curl");
+    fs::write(
+        &script,
+        format!(
+            "#!/bin/sh\nfor url; do :; done\n\
+             [ \"$url\" = \"{ISSUE}\" ] || exit 22\n\
+             echo '<html><title>Login fails on Safari &middot; Issue #42</title></html>'\n"
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+    bin
+}
+
+#[test]
+fn test_url_attachments_get_page_titles() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env.write_project_config(&ProjectConfigBuilder::new().fetch_link_titles(true).build());
+    let item_path = create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+    let bin = fake_curl(&env);
+
+    qs_cmd_with_path(&env, &bin)
+        .args(["attachments", "add", "--id", "260101", ISSUE, MISSING])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "{ISSUE} (Login fails on Safari · Issue #42)"
+        )));
+
+    let item = Item::load(&item_path).unwrap();
+    assert_eq!(
+        item.link_title(ISSUE),
+        Some("Login fails on Safari · Issue #42")
+    );
+    assert_eq!(item.link_title(MISSING), None);
+
+    qs_cmd_with_path(&env, &bin)
+        .args(["list", "--attachments", "--id", "260101"])
+        .assert()
+        .success()
+        .stdout(format!(
+            "{ISSUE}  (Login fails on Safari · Issue #42)\n{MISSING}\n"
+        ));
+
+    // Removing the attachment drops its title
+    qs_cmd_with_path(&env, &bin)
+        .args(["attachments", "remove", "--id", "260101", "1"])
+        .assert()
+        .success();
+    let content = fs::read_to_string(&item_path).unwrap();
+    assert!(!content.contains("link_titles"));
+}
+
+#[test]
+fn test_titles_are_not_fetched_by_default() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    let item_path = create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+    let bin = fake_curl(&env);
+
+    qs_cmd_with_path(&env, &bin)
+        .args(["attachments", "add", "--id", "260101", ISSUE])
+        .assert()
+        .success();
+    assert_eq!(Item::load(&item_path).unwrap().link_title(ISSUE), None);
+}
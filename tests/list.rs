@@ -5,10 +5,22 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
 mod common;
 
-use common::{create_test_item, GlobalConfigBuilder, TestEnv};
-use queuestack::commands::{self, InteractiveArgs, ListMode, ListOptions, SortBy, StatusFilter};
+use common::{create_test_item, make_item_content, qs_cmd, GlobalConfigBuilder, TestEnv};
+use queuestack::commands::{
+    self, InteractiveArgs, ListMode, ListOptions, OutputFormat, Scope, SortBy,
+};
+
+/// Creates an open item with the given creation timestamp.
+fn create_item_at(env: &TestEnv, id: &str, title: &str, created_at: &str) {
+    let path = create_test_item(env, id, title, "open", &[], None);
+    let content = std::fs::read_to_string(&path).expect("read item");
+    let content = content.replace("2026-01-09T12:00:00Z", created_at);
+    std::fs::write(&path, content).expect("write item");
+}
 
 #[test]
 fn test_list_empty_project() {
@@ -18,17 +30,33 @@ fn test_list_empty_project() {
 
     let filter = ListOptions {
         mode: ListMode::Items,
-        status: StatusFilter::All,
+        scope: Scope::All,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     // Should not error even if empty
@@ -57,17 +85,33 @@ fn test_list_shows_open_items() {
 
     let filter = ListOptions {
         mode: ListMode::Items,
-        status: StatusFilter::Open,
+        scope: Scope::Open,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     // Should succeed (output goes to stdout)
@@ -93,17 +137,33 @@ fn test_list_filter_by_label() {
 
     let filter = ListOptions {
         mode: ListMode::Items,
-        status: StatusFilter::All,
+        scope: Scope::All,
         labels: vec!["bug".to_string()],
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     let result = commands::list(&filter);
@@ -121,17 +181,33 @@ fn test_list_sort_by_title() {
 
     let filter = ListOptions {
         mode: ListMode::Items,
-        status: StatusFilter::All,
+        scope: Scope::All,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Title,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     let result = commands::list(&filter);
@@ -156,17 +232,33 @@ fn test_list_shows_closed_items() {
 
     let filter = ListOptions {
         mode: ListMode::Items,
-        status: StatusFilter::Closed,
+        scope: Scope::Archive,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     let result = commands::list(&filter);
@@ -184,17 +276,33 @@ fn test_list_filter_by_author() {
     // Author filter uses exact match (case-insensitive)
     let filter = ListOptions {
         mode: ListMode::Items,
-        status: StatusFilter::All,
+        scope: Scope::All,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: Some("Test User".to_string()),
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     let result = commands::list(&filter);
@@ -212,17 +320,33 @@ fn test_list_sort_by_date() {
 
     let filter = ListOptions {
         mode: ListMode::Items,
-        status: StatusFilter::All,
+        scope: Scope::All,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Date,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     let result = commands::list(&filter);
@@ -249,17 +373,33 @@ fn test_list_combined_filters() {
     // Author filter uses exact match (case-insensitive)
     let filter = ListOptions {
         mode: ListMode::Items,
-        status: StatusFilter::Open,
+        scope: Scope::Open,
         labels: vec!["bug".to_string()],
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: Some("Test User".to_string()),
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Title,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     let result = commands::list(&filter);
@@ -283,17 +423,33 @@ fn test_list_open_and_closed_flags_together() {
     // Both flags true - should show all items
     let filter = ListOptions {
         mode: ListMode::Items,
-        status: StatusFilter::All,
+        scope: Scope::All,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     let result = commands::list(&filter);
@@ -311,17 +467,33 @@ fn test_list_without_init() {
 
     let filter = ListOptions {
         mode: ListMode::Items,
-        status: StatusFilter::All,
+        scope: Scope::All,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     let result = commands::list(&filter);
@@ -339,17 +511,33 @@ fn test_list_author_case_insensitive() {
     // Author filter uses exact match but is case-insensitive
     let filter = ListOptions {
         mode: ListMode::Items,
-        status: StatusFilter::All,
+        scope: Scope::All,
         labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: Some("TEST USER".to_string()), // uppercase of "Test User"
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     let result = commands::list(&filter);
@@ -366,17 +554,33 @@ fn test_list_nonexistent_label_filter() {
 
     let filter = ListOptions {
         mode: ListMode::Items,
-        status: StatusFilter::All,
+        scope: Scope::All,
         labels: vec!["nonexistent-label".to_string()],
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     // Should succeed but return empty list
@@ -385,6 +589,7 @@ fn test_list_nonexistent_label_filter() {
 }
 
 #[test]
+#[allow(clippy::too_many_lines)] // One block per flag combination
 fn test_list_interactive_combinations() {
     // Test with interactive=true, no_interactive=true (override)
     {
@@ -395,17 +600,33 @@ fn test_list_interactive_combinations() {
 
         let filter = ListOptions {
             mode: ListMode::Items,
-            status: StatusFilter::All,
+            scope: Scope::All,
             labels: Vec::new(),
+            any_labels: Vec::new(),
+            not_labels: Vec::new(),
             author: None,
+            assignee: None,
+            mine: false,
             category: None,
+            since: None,
+            until: None,
+            due_after: None,
+            due_before: None,
+            overdue: false,
+            blocked: None,
             sort: SortBy::Id,
+            group_by: None,
+            summary: false,
+            verbose: false,
+            print_selection: None,
             interactive: InteractiveArgs {
                 interactive: false,
                 no_interactive: true,
             }, // Override interactive
             id: None,
             file: None,
+            mirrors: true,
+            format: OutputFormat::Text,
         };
 
         commands::list(&filter).expect("list should succeed");
@@ -420,17 +641,33 @@ fn test_list_interactive_combinations() {
 
         let filter = ListOptions {
             mode: ListMode::Items,
-            status: StatusFilter::All,
+            scope: Scope::All,
             labels: Vec::new(),
+            any_labels: Vec::new(),
+            not_labels: Vec::new(),
             author: None,
+            assignee: None,
+            mine: false,
             category: None,
+            since: None,
+            until: None,
+            due_after: None,
+            due_before: None,
+            overdue: false,
+            blocked: None,
             sort: SortBy::Id,
+            group_by: None,
+            summary: false,
+            verbose: false,
+            print_selection: None,
             interactive: InteractiveArgs {
                 interactive: false,
                 no_interactive: false,
             }, // Would show selector if in terminal
             id: None,
             file: None,
+            mirrors: true,
+            format: OutputFormat::Text,
         };
 
         // Works because we're not in a terminal, so interactive selection is skipped
@@ -446,17 +683,33 @@ fn test_list_interactive_combinations() {
 
         let filter = ListOptions {
             mode: ListMode::Items,
-            status: StatusFilter::All,
+            scope: Scope::All,
             labels: Vec::new(),
+            any_labels: Vec::new(),
+            not_labels: Vec::new(),
             author: None,
+            assignee: None,
+            mine: false,
             category: None,
+            since: None,
+            until: None,
+            due_after: None,
+            due_before: None,
+            overdue: false,
+            blocked: None,
             sort: SortBy::Id,
+            group_by: None,
+            summary: false,
+            verbose: false,
+            print_selection: None,
             interactive: InteractiveArgs {
                 interactive: false,
                 no_interactive: false,
             },
             id: None,
             file: None,
+            mirrors: true,
+            format: OutputFormat::Text,
         };
 
         commands::list(&filter).expect("list should succeed");
@@ -471,19 +724,497 @@ fn test_list_interactive_combinations() {
 
         let filter = ListOptions {
             mode: ListMode::Items,
-            status: StatusFilter::All,
+            scope: Scope::All,
             labels: Vec::new(),
+            any_labels: Vec::new(),
+            not_labels: Vec::new(),
             author: None,
+            assignee: None,
+            mine: false,
             category: None,
+            since: None,
+            until: None,
+            due_after: None,
+            due_before: None,
+            overdue: false,
+            blocked: None,
             sort: SortBy::Id,
+            group_by: None,
+            summary: false,
+            verbose: false,
+            print_selection: None,
             interactive: InteractiveArgs {
                 interactive: false,
                 no_interactive: true,
             },
             id: None,
             file: None,
+            mirrors: true,
+            format: OutputFormat::Text,
         };
 
         commands::list(&filter).expect("list should succeed");
     }
 }
+
+#[test]
+fn test_list_since_until_filters_by_creation_date() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    create_item_at(&env, "251201-AAA", "December Task", "2025-12-01T09:00:00Z");
+    create_item_at(&env, "260110-BBB", "January Task", "2026-01-10T09:00:00Z");
+    create_item_at(&env, "260201-CCC", "February Task", "2026-02-01T09:00:00Z");
+
+    let output = qs_cmd(&env)
+        .args(["list", "--since", "2026-01-01", "--until", "2026-01-31"])
+        .output()
+        .expect("run qs");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("260110-BBB"));
+    assert!(!stdout.contains("251201-AAA"));
+    assert!(!stdout.contains("260201-CCC"));
+
+    // --until is inclusive of the whole day
+    let output = qs_cmd(&env)
+        .args(["list", "--until", "2026-01-10"])
+        .output()
+        .expect("run qs");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("251201-AAA"));
+    assert!(stdout.contains("260110-BBB"));
+    assert!(!stdout.contains("260201-CCC"));
+}
+
+#[test]
+fn test_list_closed_since_filters_by_close_date() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    // Created long ago, closed now
+    create_item_at(&env, "240101-AAA", "Old Task", "2024-01-01T09:00:00Z");
+    commands::execute_close(Some("240101-AAA".to_string()), None, None).expect("close");
+
+    let output = qs_cmd(&env)
+        .args(["list", "--scope", "archive", "--since", "today"])
+        .output()
+        .expect("run qs");
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("240101-AAA"));
+
+    let output = qs_cmd(&env)
+        .args(["list", "--scope", "archive", "--until", "2025-01-01"])
+        .output()
+        .expect("run qs");
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("240101-AAA"));
+
+    // Reopening clears the close date
+    commands::execute_reopen(Some("240101-AAA".to_string()), None, None).expect("reopen");
+    let content = std::fs::read_to_string(env.stack_path().join("240101-AAA-old-task.md"))
+        .expect("read reopened item");
+    assert!(!content.contains("closed_at"));
+}
+
+#[test]
+fn test_list_scope() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+    create_test_item(&env, "260101-AAA", "Open Task", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Closed Task", "closed", &[], None);
+    std::fs::rename(
+        env.stack_path().join("260102-BBB-closed-task.md"),
+        env.archive_path().join("260102-BBB-closed-task.md"),
+    )
+    .expect("move to archive");
+    std::fs::create_dir_all(env.template_path()).unwrap();
+    std::fs::write(
+        env.template_path().join("260103-CCC-bug-report.md"),
+        make_item_content("260103-CCC", "Bug Report", "template", &[], None, None),
+    )
+    .unwrap();
+
+    let list = |scope: &str| {
+        let output = qs_cmd(&env)
+            .args(["list", "--scope", scope])
+            .output()
+            .expect("run qs");
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    let templates = list("templates");
+    assert!(templates.contains("260103-CCC"));
+    assert!(!templates.contains("260101-AAA") && !templates.contains("260102-BBB"));
+
+    let all = list("all");
+    assert!(all.contains("260101-AAA") && all.contains("260102-BBB") && all.contains("260103-CCC"));
+
+    let output = qs_cmd(&env)
+        .args(["list", "--scope", "all", "--summary"])
+        .output()
+        .expect("run qs");
+    assert!(
+        String::from_utf8_lossy(&output.stdout).contains("3 items: 1 open, 1 closed, 1 template")
+    );
+}
+
+#[test]
+fn test_list_rejects_invalid_date() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    let output = qs_cmd(&env)
+        .args(["list", "--since", "someday"])
+        .output()
+        .expect("run qs");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid date"));
+}
+
+#[test]
+fn test_list_label_boolean_logic() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    create_test_item(&env, "260101-AAA", "Both", "open", &["bug", "urgent"], None);
+    create_test_item(&env, "260102-BBB", "Bug Only", "open", &["bug"], None);
+    create_test_item(
+        &env,
+        "260103-CCC",
+        "Ignored",
+        "open",
+        &["bug", "urgent", "wontfix"],
+        None,
+    );
+    create_test_item(&env, "260104-DDD", "Feature", "open", &["feature"], None);
+
+    let list = |args: &[&str]| {
+        let output = qs_cmd(&env)
+            .arg("list")
+            .args(args)
+            .output()
+            .expect("run qs");
+        assert!(output.status.success());
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        ["260101-AAA", "260102-BBB", "260103-CCC", "260104-DDD"]
+            .into_iter()
+            .filter(|id| stdout.contains(id))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        list(&["--label", "bug", "--label", "urgent"]),
+        ["260101-AAA", "260103-CCC"]
+    );
+    assert_eq!(
+        list(&["--label", "bug", "urgent", "--not-label", "wontfix"]),
+        ["260101-AAA"]
+    );
+    assert_eq!(
+        list(&["--any-label", "urgent", "--any-label", "feature"]),
+        ["260101-AAA", "260103-CCC", "260104-DDD"]
+    );
+    assert_eq!(list(&["--not-label", "bug"]), ["260104-DDD"]);
+}
+
+#[test]
+fn test_list_author_filter_uses_identities() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .identity("Dominic Rodemer", &["Dom", "dom@example.com"])
+            .build(),
+    );
+    commands::init().expect("init should succeed");
+
+    for (id, author) in [
+        ("260101-AAA", "Dom"),
+        ("260102-BBB", "Dominic Rodemer"),
+        ("260103-CCC", "Alice"),
+    ] {
+        let path = create_test_item(&env, id, "Task", "open", &[], None);
+        let content = std::fs::read_to_string(&path).expect("read item");
+        std::fs::write(&path, content.replace("Test User", author)).expect("write item");
+    }
+
+    for filter in ["dom@example.com", "Rodemer", "dom"] {
+        let output = qs_cmd(&env)
+            .args(["list", "--author", filter])
+            .output()
+            .expect("run qs");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("260101-AAA"), "{filter}: {stdout}");
+        assert!(stdout.contains("260102-BBB"), "{filter}: {stdout}");
+        assert!(!stdout.contains("260103-CCC"), "{filter}: {stdout}");
+    }
+}
+
+#[test]
+fn test_list_tree() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    create_test_item(&env, "260101-AAA", "Root item", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Crash", "open", &[], Some("bugs"));
+    let nested = create_test_item(&env, "260103-CCC", "Token leak", "open", &[], Some("bugs"));
+    let cve_dir = nested.parent().unwrap().join("security");
+    std::fs::create_dir_all(&cve_dir).unwrap();
+    std::fs::rename(&nested, cve_dir.join(nested.file_name().unwrap())).unwrap();
+    create_test_item(
+        &env,
+        "260104-DDD",
+        "Dark mode",
+        "open",
+        &[],
+        Some("features"),
+    );
+
+    qs_cmd(&env)
+        .args(["list", "--tree"])
+        .assert()
+        .success()
+        .stdout(
+            "queuestack/\n\
+             ├── bugs/\n\
+             │   ├── security/\n\
+             │   │   └── 260103-CCC  Token leak\n\
+             │   └── 260102-BBB  Crash\n\
+             ├── features/\n\
+             │   └── 260104-DDD  Dark mode\n\
+             └── 260101-AAA  Root item\n",
+        );
+}
+
+#[test]
+fn test_list_group_by_label() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    create_test_item(&env, "260101-AAA", "Crash", "open", &["bug", "ui"], None);
+    create_test_item(&env, "260102-BBB", "Typo", "open", &["ui"], None);
+    create_test_item(&env, "260103-CCC", "Idea", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["list", "--group-by", "label"])
+        .assert()
+        .success()
+        .stdout(
+            "bug (1)\n  queuestack/260101-AAA-crash.md\n\n\
+             ui (2)\n  queuestack/260101-AAA-crash.md\n  queuestack/260102-BBB-typo.md\n\n\
+             (no label) (1)\n  queuestack/260103-CCC-idea.md\n",
+        );
+}
+
+#[test]
+fn test_list_group_by_assignee_uses_identities() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .identity("Alice Smith", &["alice"])
+            .build(),
+    );
+    commands::init().expect("init should succeed");
+
+    for (id, title, assignee) in [
+        ("260101-AAA", "Crash", Some("alice")),
+        ("260102-BBB", "Typo", Some("Alice Smith")),
+        ("260103-CCC", "Idea", None),
+    ] {
+        let path = create_test_item(&env, id, title, "open", &[], None);
+        if let Some(assignee) = assignee {
+            let content = std::fs::read_to_string(&path).unwrap().replace(
+                "status: open",
+                &format!("status: open\nassignee: {assignee}"),
+            );
+            std::fs::write(&path, content).unwrap();
+        }
+    }
+
+    qs_cmd(&env)
+        .args(["list", "--group-by", "assignee"])
+        .assert()
+        .success()
+        .stdout(
+            "Alice Smith (2)\n  queuestack/260101-AAA-crash.md\n  queuestack/260102-BBB-typo.md\n\n\
+             (unassigned) (1)\n  queuestack/260103-CCC-idea.md\n",
+        );
+}
+
+#[test]
+fn test_list_group_by_conflicts_with_tree() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    qs_cmd(&env)
+        .args(["list", "--tree", "--group-by", "status"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_list_summary_footer() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(true).build());
+    commands::init().expect("init should succeed");
+
+    create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Typo", "in-progress", &[], None);
+    let path = create_test_item(&env, "260103-CCC", "Leak", "open", &[], None);
+    let content = std::fs::read_to_string(&path)
+        .unwrap()
+        .replace("status: open", "status: open\npriority: high");
+    std::fs::write(&path, content).unwrap();
+
+    // --summary implies non-interactive output
+    qs_cmd(&env)
+        .args(["list", "--summary"])
+        .assert()
+        .success()
+        .stdout(
+            "queuestack/260101-AAA-crash.md\n\
+             queuestack/260102-BBB-typo.md\n\
+             queuestack/260103-CCC-leak.md\n\
+             \n\
+             3 items: 2 open, 1 in-progress; priority: 1 high, 2 unset\n",
+        );
+}
+
+#[test]
+fn test_list_summary_without_priorities() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["list", "--tree", "--summary"])
+        .assert()
+        .success()
+        .stdout("queuestack/\n└── 260101-AAA  Crash\n\n1 item: 1 open\n");
+}
+
+#[test]
+fn test_list_paths_read_frontmatter_only() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    let zebra = create_test_item(&env, "260101-AAA", "Zebra", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "alpha", "open", &[], None);
+    // Bodies with rules and text that looks like frontmatter don't matter
+    let content = std::fs::read_to_string(&zebra).unwrap();
+    std::fs::write(&zebra, format!("{content}\n---\nid: 999\n---\n")).unwrap();
+    // Unparseable files are skipped
+    std::fs::write(
+        env.stack_path().join("260103-CCC-broken.md"),
+        "no frontmatter",
+    )
+    .unwrap();
+
+    qs_cmd(&env)
+        .args(["list", "--sort", "title"])
+        .assert()
+        .success()
+        .stdout("queuestack/260102-BBB-alpha.md\nqueuestack/260101-AAA-zebra.md\n");
+}
+
+#[test]
+fn test_list_format_json() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().build());
+    commands::init().expect("init should succeed");
+    create_test_item(
+        &env,
+        "260101-AAA",
+        "Login fails",
+        "open",
+        &["bug"],
+        Some("bugs"),
+    );
+    create_test_item(&env, "260102-BBB", "Add export", "open", &[], None);
+
+    let output = qs_cmd(&env)
+        .args(["list", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    let items = json.as_array().expect("array of items");
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0]["id"], "260101-AAA");
+    assert_eq!(items[0]["title"], "Login fails");
+    assert_eq!(items[0]["status"], "open");
+    assert_eq!(items[0]["labels"][0], "bug");
+    assert_eq!(items[0]["category"], "bugs");
+    assert_eq!(
+        items[0]["path"],
+        "queuestack/bugs/260101-AAA-login-fails.md"
+    );
+    assert!(items[0]["created_at"].is_string());
+    assert!(items[0]["attachments"].as_array().unwrap().is_empty());
+    assert_eq!(items[1]["category"], serde_json::Value::Null);
+}
+
+#[test]
+fn test_list_format_json_empty() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().build());
+    commands::init().expect("init should succeed");
+
+    qs_cmd(&env)
+        .args(["list", "--format", "json"])
+        .assert()
+        .success()
+        .stdout("[]\n");
+}
+
+#[test]
+fn test_list_meta_format_json() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().build());
+    commands::init().expect("init should succeed");
+    create_test_item(
+        &env,
+        "260101-AAA",
+        "Login fails",
+        "open",
+        &["bug"],
+        Some("bugs"),
+    );
+
+    let output = qs_cmd(&env)
+        .args(["list", "--meta", "--id", "260101", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(json["id"], "260101-AAA");
+    assert_eq!(json["category"], "bugs");
+    assert_eq!(json["labels"][0], "bug");
+}
+
+#[test]
+fn test_list_format_json_conflicts_with_tree() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().build());
+    commands::init().expect("init should succeed");
+
+    qs_cmd(&env)
+        .args(["list", "--format", "json", "--tree"])
+        .assert()
+        .failure();
+}
@@ -0,0 +1,133 @@
+//! # Merge Driver Tests
+//!
+//! Tests for `qs merge-driver` as a git merge driver.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+mod common;
+
+use std::{fs, process::Command as StdCommand};
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands::{self, InitArgs};
+
+/// Runs git in the project directory with the qs binary on the PATH; returns
+/// false if git is unavailable or fails.
+fn git(env: &TestEnv, args: &[&str]) -> bool {
+    let bin_dir = assert_cmd::cargo::cargo_bin("qs")
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    let path = std::env::join_paths(std::iter::once(bin_dir).chain(std::env::split_paths(
+        &std::env::var_os("PATH").unwrap_or_default(),
+    )))
+    .unwrap();
+    StdCommand::new("git")
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .current_dir(env.project_path())
+        .env("PATH", path)
+        .env("HOME", env.home_dir.path())
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+fn commit_all(env: &TestEnv, message: &str) -> bool {
+    git(env, &["add", "-A"]) && git(env, &["commit", "-q", "-m", message])
+}
+
+/// Edits the item file with `f`.
+fn edit(env: &TestEnv, f: impl Fn(String) -> String) {
+    let path = env.find_item_by_id("260101-AAA").unwrap();
+    let content = fs::read_to_string(&path).unwrap();
+    fs::write(&path, f(content)).unwrap();
+}
+
+/// Sets up a git project with the merge driver and one committed item on a
+/// `feature` branch and the initial branch; returns `None` if git is unavailable.
+fn setup() -> Option<TestEnv> {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    if !git(&env, &["init", "-q", "-b", "main"]) {
+        return None;
+    }
+    commands::init_with(&InitArgs {
+        gitattributes: true,
+        ..InitArgs::default()
+    })
+    .expect("init");
+    qs_cmd(&env)
+        .args(["merge-driver", "--install"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Registered the queuestack merge driver",
+        ));
+
+    let path = create_test_item(&env, "260101-AAA", "Fix login", "open", &["bug"], None);
+    let content = fs::read_to_string(&path).unwrap();
+    fs::write(
+        &path,
+        format!("{content}\nStep one\nStep two\nStep three\n"),
+    )
+    .unwrap();
+    if !commit_all(&env, "Initial") || !git(&env, &["branch", "feature"]) {
+        return None;
+    }
+    Some(env)
+}
+
+#[test]
+fn test_merge_combines_frontmatter_and_body() {
+    let Some(env) = setup() else {
+        return; // git not available
+    };
+    edit(&env, |c| {
+        c.replace("  - bug\n", "  - bug\n  - ui\n")
+            .replace("Step one", "Step 1")
+    });
+    assert!(commit_all(&env, "Ours"));
+
+    assert!(git(&env, &["checkout", "-q", "feature"]));
+    edit(&env, |c| {
+        c.replace("  - bug\n", "  - bug\n  - urgent\n")
+            .replace("status: open", "status: in-progress")
+            .replace("Step three", "Step 3")
+    });
+    assert!(commit_all(&env, "Theirs"));
+    assert!(git(&env, &["checkout", "-q", "main"]));
+
+    assert!(git(&env, &["merge", "-q", "--no-edit", "feature"]));
+    let content = fs::read_to_string(env.find_item_by_id("260101-AAA").unwrap()).unwrap();
+    assert!(content.contains("status: in-progress\n"));
+    assert!(content.contains("  - bug\n  - ui\n  - urgent\n"));
+    assert!(content.contains("Step 1\nStep two\nStep 3\n"));
+}
+
+#[test]
+fn test_body_conflict_gets_markers() {
+    let Some(env) = setup() else {
+        return;
+    };
+    edit(&env, |c| {
+        c.replace("title: Fix login", "title: Fix login page")
+            .replace("Step two", "Step 2")
+    });
+    assert!(commit_all(&env, "Ours"));
+
+    assert!(git(&env, &["checkout", "-q", "feature"]));
+    edit(&env, |c| {
+        c.replace("title: Fix login", "title: Fix sign-in")
+            .replace("Step two", "Step II")
+    });
+    assert!(commit_all(&env, "Theirs"));
+    assert!(git(&env, &["checkout", "-q", "main"]));
+
+    assert!(!git(&env, &["merge", "-q", "--no-edit", "feature"]));
+    let content = fs::read_to_string(env.find_item_by_id("260101-AAA").unwrap()).unwrap();
+    assert!(content.contains("title: Fix login page\n"));
+    assert!(content.contains("<<<<<<< ours\nStep 2\n=======\nStep II\n>>>>>>> theirs\n"));
+    assert_eq!(content.matches("<<<<<<<").count(), 1);
+}
@@ -0,0 +1,271 @@
+//! # Mirror Tests
+//!
+//! Tests for read-only mirrors: their items in `qs list` and `qs search`,
+//! refusal to change them, and `qs mirror list|fetch`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::{fs, path::Path, process::Command as StdCommand};
+
+use common::{
+    create_test_item, make_item_content, qs_cmd, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv,
+};
+use predicates::prelude::*;
+use queuestack::commands;
+use tempfile::TempDir;
+
+/// Writes an item into another project's stack.
+fn write_mirrored_item(root: &Path, id: &str, title: &str, category: Option<&str>) {
+    let dir = category.map_or_else(
+        || root.join("queuestack"),
+        |category| root.join("queuestack").join(category),
+    );
+    fs::create_dir_all(&dir).unwrap();
+    let content = make_item_content(id, title, "open", &[], category, None);
+    fs::write(dir.join(format!("{id}.md")), content).unwrap();
+}
+
+/// Sets up a project with one item and a path mirror holding two.
+fn setup() -> (TestEnv, TempDir) {
+    let upstream = TempDir::new().unwrap();
+    write_mirrored_item(
+        upstream.path(),
+        "251201-UP001",
+        "Upstream crash",
+        Some("bugs"),
+    );
+    write_mirrored_item(upstream.path(), "251202-UP002", "Upstream docs", None);
+
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .mirror("upstream", &upstream.path().display().to_string())
+            .build(),
+    );
+    create_test_item(&env, "260101-LOCAL", "Local crash", "open", &[], None);
+    (env, upstream)
+}
+
+#[test]
+fn test_list_shows_mirrored_items_with_marker() {
+    let (env, _upstream) = setup();
+
+    let output = qs_cmd(&env).arg("list").output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(
+        lines,
+        [
+            "queuestack/260101-LOCAL-local-crash.md",
+            "[upstream] queuestack/bugs/251201-UP001.md",
+            "[upstream] queuestack/251202-UP002.md",
+        ]
+    );
+
+    // Filters apply with the mirror's own categories
+    qs_cmd(&env)
+        .args(["list", "--category", "bugs"])
+        .assert()
+        .success()
+        .stdout("[upstream] queuestack/bugs/251201-UP001.md\n");
+
+    qs_cmd(&env)
+        .args(["list", "--no-mirrors"])
+        .assert()
+        .success()
+        .stdout("queuestack/260101-LOCAL-local-crash.md\n");
+}
+
+#[test]
+fn test_search_includes_mirrored_items() {
+    let (env, _upstream) = setup();
+
+    qs_cmd(&env)
+        .args(["search", "crash"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "queuestack/260101-LOCAL-local-crash.md",
+        ))
+        .stdout(predicate::str::contains(
+            "[upstream] queuestack/bugs/251201-UP001.md",
+        ));
+
+    qs_cmd(&env)
+        .args(["search", "crash", "--no-mirrors"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[upstream]").not());
+
+    qs_cmd(&env)
+        .args(["search", "docs", "--verbose"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("upstream:251202-UP002"))
+        .stdout(predicate::str::contains("Upstream docs"));
+}
+
+#[test]
+fn test_mirrored_items_cannot_be_changed() {
+    let (env, upstream) = setup();
+    let mirrored = upstream.path().join("queuestack/bugs/251201-UP001.md");
+    let original = fs::read_to_string(&mirrored).unwrap();
+
+    qs_cmd(&env)
+        .args(["close", "--id", "251201-UP001"])
+        .assert()
+        .failure();
+    qs_cmd(&env)
+        .args(["update", "--id", "251201", "--title", "Hijacked"])
+        .assert()
+        .failure();
+
+    qs_cmd(&env)
+        .args(["close", "--id", "upstream:251201"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Item 'upstream:251201-UP001' comes from the read-only mirror 'upstream'",
+        ));
+
+    assert_eq!(fs::read_to_string(&mirrored).unwrap(), original);
+}
+
+#[test]
+fn test_prefixed_ids_select_the_project() {
+    let (env, _upstream) = setup();
+
+    // A mirror prefix views the mirrored item
+    qs_cmd(&env)
+        .args(["show", "--id", "upstream:251201"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("upstream:251201-UP001"))
+        .stdout(predicate::str::contains("Upstream crash"));
+
+    // The project's own alias is its directory name
+    let alias = env
+        .project_dir
+        .path()
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .into_owned();
+    qs_cmd(&env)
+        .args([
+            "update",
+            "--id",
+            &format!("{alias}:260101"),
+            "--title",
+            "Renamed",
+        ])
+        .assert()
+        .success();
+    qs_cmd(&env)
+        .args(["show", "--id", "260101"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Renamed"));
+
+    qs_cmd(&env)
+        .args(["show", "--id", "elsewhere:260101"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown project 'elsewhere'"));
+}
+
+#[test]
+fn test_mirror_list_and_missing_fetch() {
+    let (env, upstream) = setup();
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .mirror("upstream", &upstream.path().display().to_string())
+            .mirror("library", "file:///nonexistent/library.git")
+            .build(),
+    );
+
+    let output = qs_cmd(&env).args(["mirror", "list"]).output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("library  file:///nonexistent/library.git  not fetched\n"));
+    assert!(stdout.contains("upstream  "));
+    assert!(stdout.contains("  2 open\n"));
+
+    // Unfetched git mirrors are skipped with a hint
+    qs_cmd(&env)
+        .arg("list")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Mirror 'library' hasn't been fetched yet; run 'qs mirror fetch library'",
+        ));
+
+    qs_cmd(&env)
+        .args(["mirror", "fetch", "upstream"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is a local path"));
+}
+
+/// Runs git in a directory; returns false if git is unavailable.
+fn git(dir: &Path, args: &[&str]) -> bool {
+    StdCommand::new("git")
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+#[test]
+fn test_mirror_fetch_clones_git_url() {
+    let remote = TempDir::new().unwrap();
+    write_mirrored_item(remote.path(), "251203-GIT01", "Remote task", None);
+    if !(git(remote.path(), &["init", "-q"])
+        && git(remote.path(), &["add", "-A"])
+        && git(remote.path(), &["commit", "-q", "-m", "Add item"]))
+    {
+        return; // git unavailable
+    }
+
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    let url = format!("file://{}", remote.path().display());
+    env.write_project_config(&ProjectConfigBuilder::new().mirror("library", &url).build());
+
+    qs_cmd(&env)
+        .args(["mirror", "fetch"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Fetched library"));
+    qs_cmd(&env)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout("[library] queuestack/251203-GIT01.md\n");
+
+    // Fetching again picks up new items
+    write_mirrored_item(remote.path(), "251204-GIT02", "Another task", None);
+    assert!(git(remote.path(), &["add", "-A"]));
+    assert!(git(remote.path(), &["commit", "-q", "-m", "Add another"]));
+    qs_cmd(&env)
+        .args(["mirror", "fetch", "library"])
+        .assert()
+        .success();
+    qs_cmd(&env)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "[library] queuestack/251204-GIT02.md",
+        ));
+}
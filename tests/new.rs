@@ -5,10 +5,17 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
 mod common;
 
 use common::{GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
-use queuestack::commands::{self, InteractiveArgs, NewArgs};
+use queuestack::{
+    commands::{self, InteractiveArgs, NewArgs},
+    config::Config,
+    item::{Frontmatter, Item},
+    storage,
+};
 
 #[test]
 fn test_new_creates_item() {
@@ -20,6 +27,8 @@ fn test_new_creates_item() {
         title: Some("Test Item".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -27,6 +36,7 @@ fn test_new_creates_item() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -53,6 +63,8 @@ fn test_new_with_labels() {
         title: Some("Bug Report".to_string()),
         labels: vec!["bug".to_string(), "urgent".to_string()],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -60,6 +72,7 @@ fn test_new_with_labels() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -80,6 +93,8 @@ fn test_new_with_category() {
         title: Some("Bug in Login".to_string()),
         labels: vec![],
         category: Some("bugs".to_string()),
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -87,6 +102,7 @@ fn test_new_with_category() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -105,6 +121,8 @@ fn test_new_uses_custom_id_pattern() {
         title: Some("Custom ID Item".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -112,6 +130,7 @@ fn test_new_uses_custom_id_pattern() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -138,6 +157,8 @@ fn test_new_project_id_pattern_overrides_global() {
         title: Some("Project Pattern".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -145,6 +166,7 @@ fn test_new_project_id_pattern_overrides_global() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -167,6 +189,8 @@ fn test_new_with_labels_and_category() {
         title: Some("Critical Bug".to_string()),
         labels: vec!["bug".to_string(), "urgent".to_string(), "p0".to_string()],
         category: Some("bugs".to_string()),
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -174,6 +198,7 @@ fn test_new_with_labels_and_category() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -195,16 +220,18 @@ fn test_new_with_attachments() {
     commands::init().expect("init should succeed");
 
     // Create test files
-    let file1 = env.create_test_file("screenshot.png", "fake png");
-    let file2 = env.create_test_file("debug.log", "log content");
+    let screenshot = env.create_test_file("screenshot.png", "fake png");
+    let log = env.create_test_file("debug.log", "log content");
 
     let args = NewArgs {
         title: Some("Bug with attachments".to_string()),
         labels: vec!["bug".to_string()],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![
-            file1.to_string_lossy().to_string(),
-            file2.to_string_lossy().to_string(),
+            screenshot.to_string_lossy().to_string(),
+            log.to_string_lossy().to_string(),
             "https://github.com/issue/42".to_string(),
         ],
         interactive: InteractiveArgs {
@@ -213,6 +240,7 @@ fn test_new_with_attachments() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -269,6 +297,8 @@ fn test_new_with_empty_labels() {
         title: Some("No Labels".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -276,6 +306,7 @@ fn test_new_with_empty_labels() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -308,6 +339,8 @@ fn test_new_multiple_items_unique_ids() {
             title: Some(format!("Task {i}")),
             labels: vec![],
             category: None,
+            due: None,
+            assignee: None,
             attachments: vec![],
             interactive: InteractiveArgs {
                 interactive: false,
@@ -315,6 +348,7 @@ fn test_new_multiple_items_unique_ids() {
             },
             as_template: false,
             from_template: None,
+            vars: vec![],
         };
         commands::new(args).expect("new should succeed");
     }
@@ -351,6 +385,8 @@ fn test_new_category_with_slash_normalized() {
         title: Some("Nested Task".to_string()),
         labels: vec![],
         category: Some("level1/level2".to_string()), // slash normalized to hyphen
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -358,6 +394,7 @@ fn test_new_category_with_slash_normalized() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -380,6 +417,8 @@ fn test_new_normalizes_spaces_in_labels_and_category() {
         title: Some("Test Item".to_string()),
         labels: vec!["my label".to_string(), "another one".to_string()],
         category: Some("my category".to_string()),
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -387,6 +426,7 @@ fn test_new_normalizes_spaces_in_labels_and_category() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -427,6 +467,8 @@ fn test_new_without_init() {
         title: Some("Task".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -434,8 +476,76 @@ fn test_new_without_init() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
 
     let result = commands::new(args);
     assert!(result.is_err(), "new without init should fail");
 }
+
+/// Returns arguments for a non-interactive `qs new` with the given title.
+fn new_args(title: &str) -> NewArgs {
+    NewArgs {
+        title: Some(title.to_string()),
+        labels: vec![],
+        category: None,
+        due: None,
+        assignee: None,
+        attachments: vec![],
+        interactive: InteractiveArgs {
+            interactive: false,
+            no_interactive: true,
+        },
+        as_template: false,
+        from_template: None,
+        vars: vec![],
+    }
+}
+
+#[test]
+fn test_new_never_overwrites_existing_file() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().id_pattern("FIXED").build());
+    commands::init().expect("init should succeed");
+
+    let existing = env.stack_path().join("FIXED-task.md");
+    std::fs::write(&existing, "---\nid: FIXED\ntitle: Task\n---\nkeep me\n").unwrap();
+
+    let err = commands::new(new_args("Task")).unwrap_err();
+    assert!(err.to_string().starts_with("ID 'FIXED' already exists: "));
+
+    assert!(env.read_item(&existing).contains("keep me"));
+    assert_eq!(
+        env.list_stack_files().len(),
+        1,
+        "Should not add a duplicate ID"
+    );
+}
+
+#[test]
+fn test_new_regenerates_colliding_id() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().build());
+    commands::init().expect("init should succeed");
+    let config = Config::load().unwrap();
+
+    let existing = env.stack_path().join("260101-AAAAA-task.md");
+    std::fs::write(
+        &existing,
+        "---\nid: 260101-AAAAA\ntitle: Task\n---\nkeep me\n",
+    )
+    .unwrap();
+
+    let mut item = Item::new(Frontmatter {
+        id: "260101-AAAAA".to_string(),
+        title: "Task".to_string(),
+        ..Frontmatter::default()
+    });
+    let path = storage::create_item(&config, &mut item, None).expect("create should succeed");
+
+    assert_ne!(path, existing);
+    assert_ne!(item.id(), "260101-AAAAA", "Should get a fresh ID");
+    assert!(path.ends_with(item.filename()));
+    assert!(env.read_item(&existing).contains("keep me"));
+    assert_eq!(env.list_stack_files().len(), 2);
+}
@@ -6,12 +6,15 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
 mod common;
 
-use std::fs;
+use std::{fs, path::Path};
 
-use assert_cmd::Command;
-use common::{create_test_item, create_test_item_with_attachments, GlobalConfigBuilder, TestEnv};
+use common::{
+    create_test_item, create_test_item_with_attachments, qs_cmd, GlobalConfigBuilder, TestEnv,
+};
 use predicates::prelude::*;
 use queuestack::commands;
 
@@ -19,14 +22,6 @@ use queuestack::commands;
 // Helper Functions
 // =============================================================================
 
-/// Creates a qs command configured to run in the test environment.
-fn qs_cmd(env: &TestEnv) -> Command {
-    let mut cmd = Command::cargo_bin("qs").unwrap();
-    cmd.current_dir(env.project_dir.path());
-    cmd.env("HOME", env.home_dir.path());
-    cmd
-}
-
 // =============================================================================
 // list --no-interactive Output Tests
 // =============================================================================
@@ -90,19 +85,17 @@ fn test_list_items_one_per_line() {
     let lines: Vec<&str> = stdout.lines().collect();
 
     // Should be exactly 3 lines (one per item)
-    assert_eq!(lines.len(), 3, "Expected 3 lines, got: {:?}", lines);
+    assert_eq!(lines.len(), 3, "Expected 3 lines, got: {lines:?}");
 
     // Each line should be a path
     for line in &lines {
         assert!(
-            line.ends_with(".md"),
-            "Each line should be a .md file path, got: {}",
-            line
+            Path::new(line).extension().is_some_and(|ext| ext == "md"),
+            "Each line should be a .md file path, got: {line}"
         );
         assert!(
             !line.contains("  "),
-            "Lines should not have double spaces (no table formatting): {}",
-            line
+            "Lines should not have double spaces (no table formatting): {line}"
         );
     }
 }
@@ -190,8 +183,7 @@ fn test_list_labels_one_per_line() {
     for line in &lines {
         assert!(
             line.contains(" (") && line.ends_with(')'),
-            "Each line should be 'label (count)', got: {}",
-            line
+            "Each line should be 'label (count)', got: {line}"
         );
     }
 }
@@ -373,8 +365,7 @@ fn test_list_categories_one_per_line() {
     for line in &lines {
         assert!(
             line.contains(" (") && line.ends_with(')'),
-            "Each line should be 'category (count)', got: {}",
-            line
+            "Each line should be 'category (count)', got: {line}"
         );
     }
 }
@@ -450,9 +441,11 @@ fn test_list_attachments_one_per_line() {
         .clone();
 
     let stdout = String::from_utf8_lossy(&output);
-    let lines: Vec<&str> = stdout.lines().collect();
-
-    assert_eq!(lines.len(), 3, "Expected 3 lines (one per attachment)");
+    assert_eq!(
+        stdout.lines().count(),
+        3,
+        "Expected 3 lines (one per attachment)"
+    );
 }
 
 #[test]
@@ -669,8 +662,7 @@ fn test_output_ends_with_newline() {
 
         assert!(
             output.ends_with(b"\n"),
-            "{:?} output should end with newline",
-            args
+            "{args:?} output should end with newline"
         );
     }
 }
@@ -0,0 +1,149 @@
+//! # Patch Command Tests
+//!
+//! Tests for the `qs patch` command.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{create_test_item, GlobalConfigBuilder, TestEnv};
+use queuestack::commands::{self, PatchArgs};
+
+fn patch_args(id: &str, set: &[&str]) -> PatchArgs {
+    PatchArgs {
+        id: Some(id.to_string()),
+        file: None,
+        set: set.iter().map(|s| (*s).to_string()).collect(),
+        patch_file: None,
+        force: false,
+    }
+}
+
+#[test]
+fn test_patch_labels_and_custom_field() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    create_test_item(&env, "260101-AAA", "Task", "open", &["bug"], None);
+
+    commands::patch(&patch_args("260101", &["labels+=urgent", "priority=high"]))
+        .expect("patch should succeed");
+
+    let item = env.find_item_by_id("260101").expect("item should exist");
+    let content = env.read_item(&item);
+    assert!(content.contains("- bug"), "Existing label should remain");
+    assert!(content.contains("- urgent"), "Label should be added");
+    assert!(
+        content.contains("priority: high"),
+        "Custom field should be set"
+    );
+}
+
+#[test]
+fn test_patch_is_deterministic() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Task", "open", &[], None);
+
+    // Same operations in a different order produce the same frontmatter layout
+    commands::patch(&patch_args("260101", &["zeta=1", "alpha=2"])).unwrap();
+    commands::patch(&patch_args("260102", &["alpha=2", "zeta=1"])).unwrap();
+
+    let a = env.read_item(&env.find_item_by_id("260101").unwrap());
+    let b = env.read_item(&env.find_item_by_id("260102").unwrap());
+    let tail = |s: &str| s[s.find("status:").unwrap()..].to_string();
+    assert_eq!(tail(&a), tail(&b));
+    assert!(a.find("alpha").unwrap() < a.find("zeta").unwrap());
+}
+
+#[test]
+fn test_patch_title_renames_file() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    create_test_item(&env, "260101-AAA", "Old Title", "open", &[], None);
+
+    commands::patch(&patch_args("260101", &["title=New Title"])).unwrap();
+
+    let item = env.find_item_by_id("260101").expect("item should exist");
+    assert!(item.to_string_lossy().ends_with("260101-AAA-new-title.md"));
+}
+
+#[test]
+fn test_patch_from_file() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    create_test_item(&env, "260101-AAA", "Task", "open", &["wontfix"], None);
+    let patch_file = env.create_test_file(
+        "changes.patch",
+        "# triage\nlabels-=wontfix\nlabels+=accepted\n",
+    );
+
+    commands::patch(&PatchArgs {
+        id: Some("260101".to_string()),
+        file: None,
+        set: vec![],
+        patch_file: Some(patch_file),
+        force: false,
+    })
+    .unwrap();
+
+    let content = env.read_item(&env.find_item_by_id("260101").unwrap());
+    assert!(!content.contains("wontfix"));
+    assert!(content.contains("- accepted"));
+}
+
+#[test]
+fn test_patch_rejects_protected_field() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    let result = commands::patch(&patch_args("260101", &["id=OTHER"]));
+    assert!(result.is_err());
+
+    let content = env.read_item(&env.find_item_by_id("260101").unwrap());
+    assert!(content.contains("id: 260101-AAA"));
+}
+
+#[test]
+fn test_patch_requires_operations() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    assert!(commands::patch(&patch_args("260101", &[])).is_err());
+}
+
+#[test]
+fn test_patch_assignee_uses_canonical_identity() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .identity("Dominic Rodemer", &["Dom", "dom@example.com"])
+            .build(),
+    );
+    commands::init().expect("init should succeed");
+
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    commands::patch(&patch_args("260101", &["assignee=dom"])).expect("patch should succeed");
+
+    let item = env.find_item_by_id("260101").expect("item should exist");
+    assert!(env.read_item(&item).contains("assignee: Dominic Rodemer"));
+}
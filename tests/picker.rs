@@ -0,0 +1,90 @@
+//! # External Picker Tests
+//!
+//! Tests for delegating item selection to an external picker command
+//! (`picker` config option and `--picker`).
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![cfg(unix)]
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    create_test_item(&env, "260101-AAA", "Login bug", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Login slow", "open", &[], None);
+    env
+}
+
+#[test]
+fn test_picker_flag_selects_item() {
+    let env = setup();
+
+    // The picker gets one line per item and prints the chosen one
+    qs_cmd(&env)
+        .args(["list", "--print-selection", "--picker", "grep BBB"])
+        .assert()
+        .success()
+        .stdout("queuestack/260102-BBB-login-slow.md\n");
+
+    // Placeholders are replaced before the command runs
+    qs_cmd(&env)
+        .args(["search", "login", "--print-selection", "id"])
+        .args(["--picker", "echo {prompt} >&2; grep AAA"])
+        .assert()
+        .success()
+        .stdout("260101-AAA\n")
+        .stderr(predicate::str::contains("Select an item>"));
+}
+
+#[test]
+fn test_picker_from_config() {
+    let env = setup();
+    env.write_project_config(&ProjectConfigBuilder::new().picker("tail -n 1").build());
+
+    qs_cmd(&env)
+        .args(["search", "login", "--print-selection", "id"])
+        .assert()
+        .success()
+        .stdout("260102-BBB\n");
+
+    // --picker overrides the config
+    qs_cmd(&env)
+        .args([
+            "search",
+            "login",
+            "--print-selection",
+            "id",
+            "--picker",
+            "head -n 1",
+        ])
+        .assert()
+        .success()
+        .stdout("260101-AAA\n");
+}
+
+#[test]
+fn test_picker_cancel_and_missing_command() {
+    let env = setup();
+
+    // Non-zero exit (fzf on Esc) cancels the selection
+    qs_cmd(&env)
+        .args(["list", "--print-selection", "--picker", "false"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Selection cancelled"));
+
+    qs_cmd(&env)
+        .args(["list", "--print-selection", "--picker", "no-such-picker"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Picker command not found"));
+}
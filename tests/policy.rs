@@ -0,0 +1,114 @@
+//! # Policy Hook Tests
+//!
+//! Tests for the `pre_create` and `pre_close` policy scripts.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![cfg(unix)]
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::os::unix::fs::PermissionsExt;
+
+use common::{create_test_item, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
+use queuestack::commands::{self, InteractiveArgs, NewArgs};
+
+/// Rejects items without a `verified` label.
+const REQUIRE_VERIFIED: &str = "sh -c 'case ,$QS_LABELS, in *,verified,*) exit 0 ;; esac; echo $QS_ID needs the verified label >&2; exit 1'";
+
+fn new_args(title: &str) -> NewArgs {
+    NewArgs {
+        title: Some(title.to_string()),
+        labels: vec![],
+        category: None,
+        due: None,
+        assignee: None,
+        attachments: vec![],
+        interactive: InteractiveArgs {
+            interactive: false,
+            no_interactive: true,
+        },
+        as_template: false,
+        from_template: None,
+        vars: vec![],
+    }
+}
+
+#[test]
+fn test_pre_close_blocks_and_shows_stderr() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .pre_close(REQUIRE_VERIFIED)
+            .build(),
+    );
+    commands::init().expect("init should succeed");
+    create_test_item(&env, "260101-AAA", "Bug", "open", &["bug"], None);
+    create_test_item(
+        &env,
+        "260102-BBB",
+        "Fixed",
+        "open",
+        &["bug", "verified"],
+        None,
+    );
+
+    let err = commands::execute_close(Some("260101".to_string()), None, None).unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("pre_close policy rejected '260101-AAA'"));
+    assert!(message.contains("260101-AAA needs the verified label"));
+    assert!(env.list_archive_files().is_empty());
+    assert!(env
+        .read_item(&env.find_item_by_id("260101").unwrap())
+        .contains("status: open"));
+
+    commands::execute_close(Some("260102".to_string()), None, None)
+        .expect("close should pass policy");
+    assert_eq!(env.list_archive_files().len(), 1);
+}
+
+#[test]
+fn test_pre_create_blocks_creation() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .pre_create("sh -c 'case $QS_TITLE in WIP) exit 1 ;; esac'")
+            .build(),
+    );
+    commands::init().expect("init should succeed");
+
+    let err = commands::new(new_args("WIP")).unwrap_err();
+    assert!(err.to_string().contains("pre_create policy rejected"));
+    assert!(env.list_stack_files().is_empty());
+
+    commands::new(new_args("Real work")).expect("new should pass policy");
+    assert_eq!(env.list_stack_files().len(), 1);
+}
+
+#[test]
+fn test_project_policy_runs_relative_script() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .pre_close("./check-done.sh")
+            .build(),
+    );
+
+    let script = env.project_path().join("check-done.sh");
+    std::fs::write(
+        &script,
+        "#!/bin/sh\necho \"closing $QS_ID from $QS_FILE\" >&2\nexit 3\n",
+    )
+    .unwrap();
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    create_test_item(&env, "260101-AAA", "Bug", "open", &[], None);
+    let err = commands::execute_close(Some("260101".to_string()), None, None).unwrap_err();
+    assert!(err.to_string().contains("closing 260101-AAA from"));
+}
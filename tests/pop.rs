@@ -0,0 +1,129 @@
+//! # Pop Command Tests
+//!
+//! Tests for `qs pop`: queue order, filters, and concurrent claims.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::{collections::BTreeSet, fs, process::Stdio};
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env
+}
+
+/// Creates an open item created on the given day of January 2026.
+fn create_item_on(env: &TestEnv, id: &str, day: u32, labels: &[&str]) {
+    let path = create_test_item(env, id, &format!("Item {id}"), "open", labels, None);
+    let content = fs::read_to_string(&path).unwrap().replace(
+        "created_at: 2026-01-09T12:00:00Z",
+        &format!("created_at: 2026-01-{day:02}T12:00:00Z"),
+    );
+    fs::write(path, content).unwrap();
+}
+
+fn status_of(env: &TestEnv, id: &str) -> String {
+    let content = fs::read_to_string(env.find_item_by_id(id).unwrap()).unwrap();
+    content
+        .lines()
+        .find_map(|line| line.strip_prefix("status: "))
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn test_pop_fifo_and_lifo() {
+    let env = setup();
+    // IDs deliberately out of creation order
+    create_item_on(&env, "260101-CCCCC", 1, &[]);
+    create_item_on(&env, "260101-AAAAA", 2, &[]);
+    create_item_on(&env, "260101-BBBBB", 3, &[]);
+
+    qs_cmd(&env)
+        .arg("pop")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Claimed item:"))
+        .stdout(predicate::str::contains("260101-CCCCC"));
+    assert_eq!(status_of(&env, "260101-CCCCC"), "in-progress");
+
+    qs_cmd(&env)
+        .args(["pop", "--lifo"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260101-BBBBB"));
+
+    qs_cmd(&env)
+        .args(["pop", "--fifo", "--format", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"id\": \"260101-AAAAA\""))
+        .stdout(predicate::str::contains("\"status\": \"in-progress\""));
+
+    qs_cmd(&env)
+        .arg("pop")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No open items to pop"));
+    assert!(!env.stack_path().join(".cache/claim.lock").exists());
+}
+
+#[test]
+fn test_pop_filters_by_label() {
+    let env = setup();
+    create_item_on(&env, "260101-AAAAA", 1, &["docs"]);
+    create_item_on(&env, "260101-BBBBB", 2, &["bug", "auto"]);
+
+    qs_cmd(&env)
+        .args(["pop", "--label", "bug", "--label", "auto"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260101-BBBBB"));
+    assert_eq!(status_of(&env, "260101-AAAAA"), "open");
+}
+
+#[test]
+fn test_concurrent_pops_claim_distinct_items() {
+    let env = setup();
+    let ids: Vec<String> = (0..6).map(|i| format!("260101-ITEM{i}")).collect();
+    for id in &ids {
+        create_item_on(&env, id, 1, &[]);
+    }
+
+    #[allow(clippy::needless_collect)] // Spawn every pop before waiting on any
+    let children: Vec<_> = ids
+        .iter()
+        .map(|_| {
+            std::process::Command::new(assert_cmd::cargo::cargo_bin("qs"))
+                .args(["pop", "--format", "json"])
+                .current_dir(env.project_dir.path())
+                .env("HOME", env.home_dir.path())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .spawn()
+                .unwrap()
+        })
+        .collect();
+
+    let popped: BTreeSet<String> = children
+        .into_iter()
+        .map(|child| {
+            let output = child.wait_with_output().unwrap();
+            assert!(output.status.success());
+            let record: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+            record["id"].as_str().unwrap().to_string()
+        })
+        .collect();
+
+    assert_eq!(popped, ids.into_iter().collect());
+}
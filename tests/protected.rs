@@ -0,0 +1,122 @@
+//! # Protected Item Tests
+//!
+//! Tests for protected categories and labels guarding destructive operations.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{
+    create_test_item, create_test_item_with_attachments, GlobalConfigBuilder, ProjectConfigBuilder,
+    TestEnv,
+};
+use queuestack::{
+    commands::{self, AttachRemoveArgs, ExportArgs, ExportFormat, StatusFilter},
+    config::GlobalConfig,
+};
+
+fn setup(global: GlobalConfigBuilder) -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&global.interactive(false).build());
+    commands::init().expect("init");
+    env
+}
+
+fn export(env: &TestEnv, force: bool) -> anyhow::Result<()> {
+    commands::export(&ExportArgs {
+        format: ExportFormat::Org,
+        status: StatusFilter::Open,
+        output: Some(env.project_path().join("export.org")),
+        force,
+    })
+}
+
+fn remove_attachment(force: bool) -> anyhow::Result<()> {
+    commands::attach_remove(&AttachRemoveArgs {
+        id: Some("260101-AAA".to_string()),
+        file: None,
+        indices: vec![1],
+        force,
+    })
+}
+
+#[test]
+fn test_export_of_protected_category_needs_force() {
+    let env = setup(GlobalConfigBuilder::new().protected(&["security/"], &[], &[]));
+    create_test_item(&env, "260101-AAA", "Plain", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "CVE", "open", &[], Some("security"));
+
+    let err = export(&env, false).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Cannot export '260102-BBB': category 'security' is protected. Use --force to export it anyway."
+    );
+    assert!(!env.project_path().join("export.org").exists());
+
+    export(&env, true).expect("forced export should succeed");
+    assert!(env.project_path().join("export.org").exists());
+}
+
+#[test]
+fn test_attachment_removal_from_protected_label_needs_force() {
+    let env = setup(GlobalConfigBuilder::new().protected(&[], &["legal-hold"], &[]));
+    let path = create_test_item_with_attachments(
+        &env,
+        "260101-AAA",
+        "Contract",
+        "open",
+        &["1-contract.pdf"],
+        None,
+    );
+    let content = env
+        .read_item(&path)
+        .replace("labels: []", "labels:\n  - legal-hold");
+    std::fs::write(&path, content).unwrap();
+
+    let err = remove_attachment(false).unwrap_err();
+    assert!(err.to_string().contains("label 'legal-hold' is protected"));
+    assert_eq!(env.list_attachment_files("260101-AAA").len(), 1);
+
+    remove_attachment(true).expect("forced removal should succeed");
+    assert!(env.list_attachment_files("260101-AAA").is_empty());
+}
+
+#[test]
+fn test_allowed_user_needs_no_force() {
+    let env = setup(
+        GlobalConfigBuilder::new()
+            .user_name("Dana")
+            .identity("Dana", &["dana@example.com"])
+            .protected(&["security"], &[], &["dana@example.com"]),
+    );
+    create_test_item(&env, "260101-AAA", "CVE", "open", &[], Some("security"));
+
+    export(&env, false).expect("allowed user should export");
+}
+
+#[test]
+fn test_project_protected_table_replaces_global() {
+    let env = setup(GlobalConfigBuilder::new().protected(&["security"], &[], &[]));
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .protected(&["legal"], &[], &[])
+            .build(),
+    );
+    create_test_item(&env, "260101-AAA", "CVE", "open", &[], Some("security"));
+    create_test_item(&env, "260102-BBB", "NDA", "open", &[], Some("legal"));
+
+    let err = export(&env, false).unwrap_err();
+    assert!(err.to_string().contains("'260102-BBB': category 'legal'"));
+}
+
+#[test]
+fn test_protected_written_to_global_config() {
+    let _env = TestEnv::new();
+    GlobalConfig::create_default_if_missing().expect("create config");
+
+    let content = std::fs::read_to_string(GlobalConfig::path().unwrap()).unwrap();
+    assert!(content.contains("# [protected]\n# categories = [\"security\"]"));
+}
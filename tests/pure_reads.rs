@@ -0,0 +1,165 @@
+//! # Pure Reads Tests
+//!
+//! Tests that read-only commands leave every file and directory of the
+//! project untouched (`pure_reads`), including git's index.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    process::Command as StdCommand,
+    time::SystemTime,
+};
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::{commands, config::Config, storage};
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .pure_reads(true)
+            .trash_days(7)
+            .build(),
+    );
+    commands::init().expect("init");
+    env
+}
+
+/// Moves an item into the trash and backdates its deletion past `trash_days`.
+fn trash_expired(env: &TestEnv, path: &Path) {
+    let config = Config::load().unwrap();
+    storage::delete_item(&config, path).expect("delete should succeed");
+    let trash = env.stack_path().join(".trash");
+    let stamp = fs::read_dir(&trash)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+    fs::rename(&stamp, trash.join("20200101-000000")).unwrap();
+}
+
+/// Records the modification time and size of every file and directory below
+/// `root`, skipping git's object database.
+fn snapshot(root: &Path) -> BTreeMap<PathBuf, (SystemTime, u64)> {
+    let mut entries = BTreeMap::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.ends_with(".git/objects") {
+                continue;
+            }
+            let metadata = fs::symlink_metadata(&path).unwrap();
+            if metadata.is_dir() {
+                pending.push(path.clone());
+            }
+            entries.insert(path, (metadata.modified().unwrap(), metadata.len()));
+        }
+    }
+    entries
+}
+
+#[test]
+fn test_read_only_commands_modify_nothing() {
+    let env = setup();
+    let git = |args: &[&str]| {
+        StdCommand::new("git")
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(env.project_path())
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+    };
+    if git(&["init", "-q"]).is_none() {
+        return; // git not available
+    }
+    create_test_item(&env, "260101-AAA", "Crash", "open", &["bug"], None);
+    create_test_item(&env, "260101-BBB", "Typo", "closed", &[], None);
+    let old = create_test_item(&env, "260101-CCC", "Old", "open", &[], None);
+    trash_expired(&env, &old);
+    if git(&["add", "-A"]).is_none() || git(&["commit", "-q", "-m", "Initial"]).is_none() {
+        return;
+    }
+
+    let before = snapshot(env.project_path());
+    let commands: &[&[&str]] = &[
+        &["list"],
+        &["list", "--scope", "all", "--format", "json"],
+        &["list", "--labels"],
+        &["list", "--tree"],
+        &["search", "crash"],
+        &["show", "--id", "260101-AAA"],
+        &["info"],
+        &["stats"],
+        &["activity"],
+        &["trash", "list"],
+        &["which"],
+        &["graph"],
+        &["validate"],
+        &["du"],
+        &["export", "org"],
+    ];
+    for args in commands {
+        qs_cmd(&env).args(*args).assert().success();
+        assert_eq!(
+            snapshot(env.project_path()),
+            before,
+            "`qs {}` modified the project",
+            args.join(" ")
+        );
+    }
+}
+
+#[test]
+fn test_trash_is_purged_by_writes_only() {
+    let env = setup();
+    let old = create_test_item(&env, "260101-AAA", "Old", "open", &[], None);
+    trash_expired(&env, &old);
+
+    qs_cmd(&env)
+        .args(["trash", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260101-AAA"));
+
+    create_test_item(&env, "260102-BBB", "New", "open", &[], None);
+    qs_cmd(&env)
+        .args(["delete", "--id", "260102-BBB", "--force"])
+        .assert()
+        .success();
+    qs_cmd(&env)
+        .args(["trash", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260101-AAA").not())
+        .stdout(predicate::str::contains("260102-BBB"));
+}
+
+#[test]
+fn test_claim_lock_lives_in_cache_dir() {
+    let _env = setup();
+    let config = Config::load().unwrap();
+
+    let lock = storage::lock::ProjectLock::acquire(&config).unwrap();
+    assert!(config.cache_path().join("claim.lock").exists());
+    drop(lock);
+    assert!(!config.cache_path().join("claim.lock").exists());
+    // Ignored by its own .gitignore, even without `qs init --gitignore`
+    assert_eq!(
+        fs::read_to_string(config.cache_path().join(".gitignore")).unwrap(),
+        "*\n"
+    );
+    assert!(storage::walk_all(&config).next().is_none());
+}
@@ -0,0 +1,57 @@
+//! # Push Command Tests
+//!
+//! Tests for `qs push` and its inline `+label @category` syntax.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+#[test]
+fn test_push_parses_labels_and_category() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+
+    qs_cmd(&env)
+        .args(["push", "Fix login crash", "+bug", "+urgent", "@bugs"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("queuestack/bugs/"))
+        .stdout(predicate::str::contains("fix-login-crash.md"));
+
+    let files = env.list_category_files("bugs");
+    assert_eq!(files.len(), 1);
+    let content = env.read_item(&files[0]);
+    assert!(content.contains("title: Fix login crash"));
+    assert!(content.contains("  - bug\n  - urgent\n"));
+}
+
+#[test]
+fn test_push_unquoted_title_and_literal_words() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+
+    qs_cmd(&env)
+        .args(["push", "Email", "\\@support", "today", "+ops"])
+        .assert()
+        .success();
+
+    let files = env.list_stack_files();
+    let content = env.read_item(&files[0]);
+    assert!(content.contains("title: Email @support today"));
+    assert!(content.contains("  - ops\n"));
+
+    qs_cmd(&env)
+        .args(["push", "+bug", "@bugs"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Title cannot be empty"));
+}
@@ -0,0 +1,147 @@
+//! # Rules Tests
+//!
+//! Tests for auto-labeling rules on `qs new` and `qs rules apply`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{create_test_item, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
+use queuestack::commands::{self, InteractiveArgs, NewArgs, RulesApplyArgs};
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .rule("panic|crash", &["bug"], &[("priority", "high")])
+            .build(),
+    );
+    commands::init().expect("init should succeed");
+    env
+}
+
+fn new_item(title: &str) {
+    commands::new(NewArgs {
+        title: Some(title.to_string()),
+        labels: vec![],
+        category: None,
+        due: None,
+        assignee: None,
+        attachments: vec![],
+        interactive: InteractiveArgs {
+            interactive: false,
+            no_interactive: true,
+        },
+        as_template: false,
+        from_template: None,
+        vars: vec![],
+    })
+    .expect("new should succeed");
+}
+
+#[test]
+fn test_rules_applied_on_new() {
+    let env = setup();
+    new_item("App crashes on startup");
+    new_item("Add dark mode");
+
+    let files = env.list_stack_files();
+    let crash = files
+        .iter()
+        .map(|p| env.read_item(p))
+        .find(|c| c.contains("crashes"))
+        .unwrap();
+    assert!(crash.contains("- bug"));
+    assert!(crash.contains("priority: high"));
+
+    let other = files
+        .iter()
+        .map(|p| env.read_item(p))
+        .find(|c| c.contains("dark mode"))
+        .unwrap();
+    assert!(!other.contains("- bug"));
+    assert!(!other.contains("priority"));
+}
+
+#[test]
+fn test_rules_apply_all_retroactively() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Panic in parser", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Docs", "open", &[], None);
+
+    commands::rules_apply(&RulesApplyArgs {
+        id: None,
+        file: None,
+        all: true,
+        force: false,
+    })
+    .expect("apply should succeed");
+
+    let panic = env.read_item(&env.find_item_by_id("260101").unwrap());
+    assert!(panic.contains("- bug"));
+    assert!(panic.contains("priority: high"));
+    let docs = env.read_item(&env.find_item_by_id("260102").unwrap());
+    assert!(!docs.contains("- bug"));
+
+    // Applying again changes nothing
+    commands::rules_apply(&RulesApplyArgs {
+        id: Some("260101".to_string()),
+        file: None,
+        all: false,
+        force: false,
+    })
+    .expect("apply should succeed");
+    assert_eq!(
+        env.read_item(&env.find_item_by_id("260101").unwrap()),
+        panic
+    );
+}
+
+#[test]
+fn test_rules_apply_refuses_archived_items() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Panic in parser", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Crash on exit", "closed", &[], None);
+    let apply = |id: Option<&str>, all: bool, force: bool| {
+        commands::rules_apply(&RulesApplyArgs {
+            id: id.map(str::to_string),
+            file: None,
+            all,
+            force,
+        })
+    };
+
+    let err = apply(Some("260102"), false, false).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Cannot apply rules to '260102-BBB'"));
+
+    apply(None, true, false).expect("apply should succeed");
+    let closed = env.find_item_by_id("260102").unwrap();
+    assert!(!env.read_item(&closed).contains("- bug"));
+    assert!(env
+        .read_item(&env.find_item_by_id("260101").unwrap())
+        .contains("- bug"));
+
+    apply(Some("260102"), false, true).expect("apply should succeed");
+    assert!(env.read_item(&closed).contains("- bug"));
+}
+
+#[test]
+fn test_project_rules_replace_global() {
+    let env = setup();
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .rule("docs", &["documentation"], &[])
+            .build(),
+    );
+    new_item("Crash in docs");
+
+    let content = env.read_item(&env.list_stack_files()[0]);
+    assert!(content.contains("- documentation"));
+    assert!(!content.contains("- bug"));
+}
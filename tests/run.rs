@@ -0,0 +1,130 @@
+//! # Run Command Tests
+//!
+//! Tests for item-level `actions` and `qs run`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![cfg(unix)]
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::{fmt::Write as _, fs, path::PathBuf};
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands::{self, RunArgs};
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env
+}
+
+/// Creates an item whose frontmatter declares the given actions.
+fn create_item_with_actions(env: &TestEnv, actions: &[(&str, &str)]) -> PathBuf {
+    let path = create_test_item(env, "260101-AAA", "Flaky test", "open", &[], None);
+    let yaml = actions
+        .iter()
+        .fold(String::new(), |mut yaml, (name, command)| {
+            let _ = writeln!(yaml, "  {name}: \"{command}\"");
+            yaml
+        });
+    let content = fs::read_to_string(&path).unwrap().replacen(
+        "\n---\n",
+        &format!("\nactions:\n{yaml}---\n"),
+        1,
+    );
+    fs::write(&path, content).unwrap();
+    path
+}
+
+fn run_args(action: Option<&str>) -> RunArgs {
+    RunArgs {
+        id: Some("260101".to_string()),
+        file: None,
+        action: action.map(str::to_string),
+    }
+}
+
+#[test]
+#[allow(clippy::literal_string_with_formatting_args)] // `{id}` is an action placeholder
+fn test_run_action_expands_placeholders() {
+    let env = setup();
+    let path = create_item_with_actions(
+        &env,
+        &[("record", "echo {id} $QS_ID > out.txt; cat {path} > copy.md")],
+    );
+
+    commands::run(&run_args(Some("record"))).expect("action should run");
+
+    // Runs from the project root
+    let out = fs::read_to_string(env.project_path().join("out.txt")).unwrap();
+    assert_eq!(out.trim(), "260101-AAA 260101-AAA");
+    let copy = fs::read_to_string(env.project_path().join("copy.md")).unwrap();
+    assert_eq!(copy, fs::read_to_string(&path).unwrap());
+}
+
+#[test]
+fn test_run_failing_action() {
+    let env = setup();
+    create_item_with_actions(&env, &[("reproduce", "exit 3")]);
+
+    let err = commands::run(&run_args(Some("reproduce"))).unwrap_err();
+    assert!(err.to_string().contains("Action 'reproduce' failed"));
+}
+
+#[test]
+fn test_run_unknown_action() {
+    let env = setup();
+    create_item_with_actions(&env, &[("reproduce", "true"), ("bisect", "true")]);
+
+    let err = commands::run(&run_args(Some("deploy"))).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Item '260101-AAA' has no action 'deploy' (available: bisect, reproduce)"
+    );
+}
+
+#[test]
+fn test_run_without_actions() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Plain", "open", &[], None);
+
+    let err = commands::run(&run_args(Some("reproduce"))).unwrap_err();
+    assert_eq!(err.to_string(), "Item '260101-AAA' has no actions");
+}
+
+#[test]
+fn test_run_lists_actions() {
+    let env = setup();
+    create_item_with_actions(&env, &[("reproduce", "cargo test flaky")]);
+
+    qs_cmd(&env)
+        .args(["run", "--id", "260101"])
+        .assert()
+        .success()
+        .stdout("reproduce: cargo test flaky\n");
+}
+
+#[test]
+fn test_actions_survive_updates() {
+    let env = setup();
+    let path = create_item_with_actions(&env, &[("reproduce", "true")]);
+
+    qs_cmd(&env)
+        .args(["patch", "--id", "260101", "--set", "labels+=flaky"])
+        .assert()
+        .success();
+
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(content.contains("actions:\n  reproduce: \"true\""));
+
+    qs_cmd(&env)
+        .args(["patch", "--id", "260101", "--set", "actions=x"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("actions"));
+}
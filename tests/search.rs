@@ -5,10 +5,12 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
 mod common;
 
-use common::{create_test_item, GlobalConfigBuilder, TestEnv};
-use queuestack::commands::{self, InteractiveArgs, SearchArgs};
+use common::{create_test_item, make_item_content, qs_cmd, GlobalConfigBuilder, TestEnv};
+use queuestack::commands::{self, InteractiveArgs, OutputFormat, Scope, SearchArgs};
 
 #[test]
 fn test_search_by_title() {
@@ -22,11 +24,19 @@ fn test_search_by_title() {
     let args = SearchArgs {
         query: "login".to_string(),
         full_text: false,
+        include_large: false,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
-        closed: false,
+        scope: Scope::Open,
+        labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
+        verbose: false,
+        print_selection: None,
+        format: OutputFormat::Text,
+        mirrors: true,
     };
 
     let result = commands::search(&args);
@@ -44,11 +54,19 @@ fn test_search_by_id() {
     let args = SearchArgs {
         query: "260101".to_string(),
         full_text: false,
+        include_large: false,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
-        closed: false,
+        scope: Scope::Open,
+        labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
+        verbose: false,
+        print_selection: None,
+        format: OutputFormat::Text,
+        mirrors: true,
     };
 
     let result = commands::search(&args);
@@ -66,11 +84,19 @@ fn test_search_case_insensitive() {
     let args = SearchArgs {
         query: "IMPORTANT".to_string(),
         full_text: false,
+        include_large: false,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
-        closed: false,
+        scope: Scope::Open,
+        labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
+        verbose: false,
+        print_selection: None,
+        format: OutputFormat::Text,
+        mirrors: true,
     };
 
     let result = commands::search(&args);
@@ -88,11 +114,19 @@ fn test_search_no_results() {
     let args = SearchArgs {
         query: "nonexistent".to_string(),
         full_text: false,
+        include_large: false,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
-        closed: false,
+        scope: Scope::Open,
+        labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
+        verbose: false,
+        print_selection: None,
+        format: OutputFormat::Text,
+        mirrors: true,
     };
 
     let result = commands::search(&args);
@@ -106,7 +140,7 @@ fn test_search_full_text() {
     commands::init().expect("init should succeed");
 
     // Create item with specific body content
-    let content = r#"---
+    let content = r"---
 id: 260101-AAA
 title: Generic Title
 author: Test User
@@ -117,7 +151,7 @@ category: ~
 ---
 
 This is the body with unique keyword: SEARCHTERM123
-"#;
+";
     std::fs::write(
         env.stack_path().join("260101-AAA-generic-title.md"),
         content,
@@ -127,11 +161,19 @@ This is the body with unique keyword: SEARCHTERM123
     let args = SearchArgs {
         query: "SEARCHTERM123".to_string(),
         full_text: true,
+        include_large: false,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
-        closed: false,
+        scope: Scope::Open,
+        labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
+        verbose: false,
+        print_selection: None,
+        format: OutputFormat::Text,
+        mirrors: true,
     };
 
     let result = commands::search(&args);
@@ -145,7 +187,7 @@ fn test_search_full_text_no_match_without_flag() {
     commands::init().expect("init should succeed");
 
     // Create item with body content but not in title
-    let content = r#"---
+    let content = r"---
 id: 260101-AAA
 title: Generic Title
 author: Test User
@@ -156,7 +198,7 @@ category: ~
 ---
 
 Body with keyword: ONLYINBODY
-"#;
+";
     std::fs::write(
         env.stack_path().join("260101-AAA-generic-title.md"),
         content,
@@ -166,11 +208,19 @@ Body with keyword: ONLYINBODY
     let args = SearchArgs {
         query: "ONLYINBODY".to_string(),
         full_text: false, // Not searching body
+        include_large: false,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
-        closed: false,
+        scope: Scope::Open,
+        labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
+        verbose: false,
+        print_selection: None,
+        format: OutputFormat::Text,
+        mirrors: true,
     };
 
     let result = commands::search(&args);
@@ -196,15 +246,26 @@ fn test_search_closed_items() {
     let args = SearchArgs {
         query: "archived".to_string(),
         full_text: false,
+        include_large: false,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
-        closed: true,
+        scope: Scope::Archive,
+        labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
+        verbose: false,
+        print_selection: None,
+        format: OutputFormat::Text,
+        mirrors: true,
     };
 
     let result = commands::search(&args);
-    assert!(result.is_ok(), "search --closed should find archived items");
+    assert!(
+        result.is_ok(),
+        "search --scope archive should find archived items"
+    );
 }
 
 #[test]
@@ -214,7 +275,7 @@ fn test_search_full_text_and_closed_combined() {
     commands::init().expect("init should succeed");
 
     // Create a closed item with searchable body
-    let content = r#"---
+    let content = r"---
 id: 260101-AAA
 title: Old Task
 author: Test User
@@ -225,17 +286,25 @@ category: ~
 ---
 
 Body contains: ARCHIVEDCONTENT
-"#;
+";
     std::fs::write(env.archive_path().join("260101-AAA-old-task.md"), content).expect("write item");
 
     let args = SearchArgs {
         query: "ARCHIVEDCONTENT".to_string(),
         full_text: true,
+        include_large: false,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
-        closed: true,
+        scope: Scope::Archive,
+        labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
+        verbose: false,
+        print_selection: None,
+        format: OutputFormat::Text,
+        mirrors: true,
     };
 
     let result = commands::search(&args);
@@ -258,11 +327,19 @@ fn test_search_multiple_matches() {
     let args = SearchArgs {
         query: "login".to_string(),
         full_text: false,
+        include_large: false,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
-        closed: false,
+        scope: Scope::Open,
+        labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
+        verbose: false,
+        print_selection: None,
+        format: OutputFormat::Text,
+        mirrors: true,
     };
 
     let result = commands::search(&args);
@@ -281,11 +358,19 @@ fn test_search_without_init() {
     let args = SearchArgs {
         query: "test".to_string(),
         full_text: false,
+        include_large: false,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
-        closed: false,
+        scope: Scope::Open,
+        labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
+        verbose: false,
+        print_selection: None,
+        format: OutputFormat::Text,
+        mirrors: true,
     };
 
     let result = commands::search(&args);
@@ -310,11 +395,19 @@ fn test_search_partial_word() {
     let args = SearchArgs {
         query: "auth".to_string(),
         full_text: false,
+        include_large: false,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
-        closed: false,
+        scope: Scope::Open,
+        labels: Vec::new(),
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
+        verbose: false,
+        print_selection: None,
+        format: OutputFormat::Text,
+        mirrors: true,
     };
 
     let result = commands::search(&args);
@@ -333,11 +426,19 @@ fn test_search_interactive_combinations() {
         let args = SearchArgs {
             query: "login".to_string(),
             full_text: false,
+            include_large: false,
             interactive: InteractiveArgs {
                 interactive: false,
                 no_interactive: true,
             },
-            closed: false,
+            scope: Scope::Open,
+            labels: Vec::new(),
+            any_labels: Vec::new(),
+            not_labels: Vec::new(),
+            verbose: false,
+            print_selection: None,
+            format: OutputFormat::Text,
+            mirrors: true,
         };
 
         commands::search(&args).expect("search should succeed");
@@ -353,13 +454,222 @@ fn test_search_interactive_combinations() {
         let args = SearchArgs {
             query: "login".to_string(),
             full_text: false,
+            include_large: false,
             interactive: InteractiveArgs {
                 interactive: false,
                 no_interactive: false,
             },
-            closed: false,
+            scope: Scope::Open,
+            labels: Vec::new(),
+            any_labels: Vec::new(),
+            not_labels: Vec::new(),
+            verbose: false,
+            print_selection: None,
+            format: OutputFormat::Text,
+            mirrors: true,
         };
 
         commands::search(&args).expect("search should succeed");
     }
 }
+
+#[test]
+fn test_search_ranks_title_matches_before_body_matches() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    let body = create_test_item(&env, "260101-AAA", "Crash report", "open", &[], None);
+    std::fs::write(
+        &body,
+        std::fs::read_to_string(&body).unwrap() + "Seen in the nightly build.\n",
+    )
+    .unwrap();
+    create_test_item(
+        &env,
+        "260102-BBB",
+        "Nightly build broken",
+        "open",
+        &[],
+        None,
+    );
+    create_test_item(&env, "260103-CCC", "Build fails on CI", "open", &[], None);
+    create_test_item(&env, "260104-DDD", "Unrelated", "open", &[], None);
+
+    // Title prefix match, then title match, then body-only match (not ID order)
+    qs_cmd(&env)
+        .args(["search", "build", "--full-text", "--no-interactive"])
+        .assert()
+        .success()
+        .stdout(
+            "queuestack/260103-CCC-build-fails-on-ci.md\n\
+             queuestack/260102-BBB-nightly-build-broken.md\n\
+             queuestack/260101-AAA-crash-report.md\n",
+        );
+}
+
+#[test]
+fn test_search_label_filters() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    create_test_item(
+        &env,
+        "260101-AAA",
+        "Login crash",
+        "open",
+        &["bug", "urgent"],
+        None,
+    );
+    create_test_item(
+        &env,
+        "260102-BBB",
+        "Login slow",
+        "open",
+        &["bug", "wontfix"],
+        None,
+    );
+    create_test_item(&env, "260103-CCC", "Login page", "open", &["feature"], None);
+
+    let output = qs_cmd(&env)
+        .args(["search", "login", "--no-interactive", "--label", "bug"])
+        .args(["--not-label", "wontfix"])
+        .output()
+        .expect("run qs");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("260101-AAA"));
+    assert!(!stdout.contains("260102-BBB"));
+    assert!(!stdout.contains("260103-CCC"));
+
+    let output = qs_cmd(&env)
+        .args(["search", "login", "--no-interactive"])
+        .args(["--any-label", "feature", "--any-label", "wontfix"])
+        .output()
+        .expect("run qs");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("260101-AAA"));
+    assert!(stdout.contains("260102-BBB"));
+    assert!(stdout.contains("260103-CCC"));
+}
+
+#[test]
+fn test_search_print_selection_single_match() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+
+    create_test_item(&env, "260101-AAA", "Login Bug", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Feature Request", "open", &[], None);
+
+    // A single match is printed without showing the selector
+    qs_cmd(&env)
+        .args(["search", "login", "--print-selection"])
+        .assert()
+        .success()
+        .stdout("queuestack/260101-AAA-login-bug.md\n");
+
+    qs_cmd(&env)
+        .args(["search", "login", "--print-selection", "id"])
+        .assert()
+        .success()
+        .stdout("260101-AAA\n");
+
+    qs_cmd(&env)
+        .args(["search", "login", "--print-selection", "--no-interactive"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_search_format_json() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().build());
+    commands::init().expect("init should succeed");
+    create_test_item(&env, "260101-AAA", "Login fails", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Login page", "open", &[], Some("ui"));
+    create_test_item(&env, "260103-CCC", "Export", "open", &[], None);
+
+    let output = qs_cmd(&env)
+        .args(["search", "login", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    let ids: Vec<&str> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids.len(), 2);
+    assert!(ids.contains(&"260101-AAA") && ids.contains(&"260102-BBB"));
+    let page = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["id"] == "260102-BBB")
+        .unwrap();
+    assert_eq!(page["category"], "ui");
+}
+
+#[test]
+fn test_search_scope() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().build());
+    commands::init().expect("init should succeed");
+    create_test_item(&env, "260101-AAA", "Deploy fails", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Deploy docs", "closed", &[], None);
+    std::fs::rename(
+        env.stack_path().join("260102-BBB-deploy-docs.md"),
+        env.archive_path().join("260102-BBB-deploy-docs.md"),
+    )
+    .expect("move to archive");
+    std::fs::create_dir_all(env.template_path()).unwrap();
+    std::fs::write(
+        env.template_path().join("260103-CCC-deploy-checklist.md"),
+        make_item_content(
+            "260103-CCC",
+            "Deploy checklist",
+            "template",
+            &[],
+            None,
+            None,
+        ),
+    )
+    .unwrap();
+
+    let ids = |scope: &[&str]| {
+        let output = qs_cmd(&env)
+            .args(["search", "deploy", "--format", "json"])
+            .args(scope)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let mut ids: Vec<String> = json
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| item["id"].as_str().unwrap().to_string())
+            .collect();
+        ids.sort();
+        ids
+    };
+
+    assert_eq!(ids(&[]), ["260101-AAA"]);
+    assert_eq!(ids(&["--scope", "archive"]), ["260102-BBB"]);
+    assert_eq!(ids(&["--closed"]), ["260102-BBB"]);
+    assert_eq!(ids(&["--scope", "templates"]), ["260103-CCC"]);
+    assert_eq!(
+        ids(&["--scope", "all"]),
+        ["260101-AAA", "260102-BBB", "260103-CCC"]
+    );
+
+    qs_cmd(&env)
+        .args(["search", "deploy", "--closed", "--scope", "open"])
+        .assert()
+        .failure();
+}
@@ -0,0 +1,108 @@
+//! # Setup Command Tests
+//!
+//! Tests for installing shell completions with `qs setup` and printing the
+//! paths it uses (`--print-paths`).
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![cfg(unix)]
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::{fs, os::unix::fs::PermissionsExt};
+
+use assert_cmd::Command;
+use common::{qs_cmd, TestEnv};
+use predicates::prelude::*;
+
+/// Puts fake shell executables on an otherwise empty PATH.
+fn with_shells(env: &TestEnv, cmd: &mut Command, shells: &[&str]) {
+    let bin = env.home_dir.path().join("bin");
+    fs::create_dir_all(&bin).unwrap();
+    for shell in shells {
+        let path = bin.join(shell);
+        fs::write(&path, "#!/bin/sh\n").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    cmd.env("PATH", &bin);
+}
+
+#[test]
+fn test_setup_single_shell() {
+    let env = TestEnv::new();
+
+    qs_cmd(&env)
+        .args(["setup", "--shell", "fish"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Installed fish completions"));
+
+    let home = env.home_dir.path();
+    assert!(home.join(".config/fish/completions/qs.fish").exists());
+    assert!(!home.join(".zfunc/_qs").exists());
+}
+
+#[test]
+fn test_setup_all_installs_for_shells_on_path() {
+    let env = TestEnv::new();
+
+    let mut cmd = qs_cmd(&env);
+    with_shells(&env, &mut cmd, &["bash", "zsh"]);
+    cmd.args(["setup", "--shell", "all"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Installed completions:"))
+        .stderr(predicate::str::contains("bash"))
+        .stderr(predicate::str::contains("zsh"));
+
+    let home = env.home_dir.path();
+    assert!(home.join(".zfunc/_qs").exists());
+    assert!(home
+        .join(".local/share/bash-completion/completions/qs")
+        .exists());
+    assert!(!home.join(".config/fish/completions/qs.fish").exists());
+}
+
+#[test]
+fn test_setup_all_without_shells() {
+    let env = TestEnv::new();
+
+    let mut cmd = qs_cmd(&env);
+    with_shells(&env, &mut cmd, &[]);
+    cmd.args(["setup", "--shell", "all"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("No supported shells found"));
+}
+
+#[test]
+fn test_setup_print_paths_writes_nothing() {
+    let env = TestEnv::new();
+    let home = env.home_dir.path().display().to_string();
+
+    qs_cmd(&env)
+        .args(["setup", "--print-paths"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "config\t{home}/.config/queuestack/config\n"
+        )))
+        .stdout(predicate::str::contains(format!(
+            "zsh\t{home}/.zfunc/_qs\n"
+        )))
+        .stdout(predicate::str::contains(format!(
+            "fish\t{home}/.config/fish/completions/qs.fish\n"
+        )));
+    assert!(!env.home_dir.path().join(".zfunc").exists());
+
+    qs_cmd(&env)
+        .args(["setup", "--print-paths", "--system", "--shell", "bash"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "bash\t/usr/local/share/bash-completion/completions/qs\n",
+        ))
+        .stdout(predicate::str::contains("zsh").not());
+}
@@ -0,0 +1,171 @@
+//! # Show Command Tests
+//!
+//! Tests for `qs show` and the summary cards shared with `qs search --verbose`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::fs;
+
+use common::{
+    create_test_item, create_test_item_with_attachments, qs_cmd, GlobalConfigBuilder, TestEnv,
+};
+use predicates::prelude::*;
+use queuestack::commands;
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+
+    let path = create_test_item_with_attachments(
+        &env,
+        "260101-AAA",
+        "Login fails",
+        "open",
+        &["1-shot.png"],
+        Some("bugs"),
+    );
+    let content = fs::read_to_string(&path)
+        .unwrap()
+        .replace("status: open", "status: open\nassignee: alice")
+        .replace(
+            "Test item body.",
+            "## Steps\n\nOpen the page\nand log in.\n\nSecond paragraph.",
+        );
+    fs::write(&path, content).unwrap();
+    env
+}
+
+#[test]
+fn test_show_brief_prints_card() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["show", "--id", "260101", "--brief"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "260101-AAA      open         Login fails\n",
+        ))
+        .stdout(predicate::str::contains("  bugs · @alice · "))
+        .stdout(predicate::str::contains(
+            " · 1 attachment · 9 words · 1 min read\n",
+        ))
+        .stdout(predicate::str::ends_with("  Open the page and log in.\n"))
+        .stdout(predicate::str::contains("\x1b[").not());
+}
+
+#[test]
+fn test_show_prints_card_and_body() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["show", "--id", "260101"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Open the page and log in.").not())
+        .stdout(predicate::str::contains(
+            "## Steps\n\nOpen the page\nand log in.\n\nSecond paragraph.\n",
+        ));
+}
+
+#[test]
+fn test_search_verbose_prints_cards() {
+    let env = setup();
+    create_test_item(&env, "260102-BBB", "Login page typo", "open", &["ui"], None);
+
+    qs_cmd(&env)
+        .args(["search", "login", "--verbose"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "260101-AAA      open         Login fails\n",
+        ))
+        .stdout(predicate::str::contains(
+            "\n\n260102-BBB      open         Login page typo\n  ui · ",
+        ));
+}
+
+#[test]
+fn test_show_unknown_id_suggests_candidates() {
+    let env = setup();
+    create_test_item(&env, "260102-BBB", "Export to CSV", "open", &[], None);
+
+    // A mistyped ID and a title word both point at the closest items
+    qs_cmd(&env)
+        .args(["show", "--id", "260101-AAB"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No item found matching '260101-AAB'\nDid you mean:\n  260101-AAA  Login fails\n",
+        ));
+    qs_cmd(&env)
+        .args(["show", "--id", "exprt"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("  260102-BBB  Export to CSV"))
+        .stderr(predicate::str::contains("Login fails").not());
+
+    qs_cmd(&env)
+        .args(["show", "--id", "2601"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Multiple items match '2601':\n  260101-AAA  Login fails\n  260102-BBB  Export to CSV\n",
+        ));
+}
+
+#[test]
+fn test_show_raw_prints_file_unchanged() {
+    let env = setup();
+    let content = fs::read_to_string(
+        env.stack_path()
+            .join("bugs")
+            .join("260101-AAA-login-fails.md"),
+    )
+    .unwrap();
+
+    qs_cmd(&env)
+        .args(["show", "--id", "260101", "--raw"])
+        .assert()
+        .success()
+        .stdout(content);
+}
+
+#[test]
+fn test_show_json_includes_fields_and_body() {
+    let env = setup();
+
+    let output = qs_cmd(&env)
+        .args(["show", "--id", "260101", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(json["id"], "260101-AAA");
+    assert_eq!(json["title"], "Login fails");
+    assert_eq!(json["category"], "bugs");
+    assert_eq!(json["path"], "queuestack/bugs/260101-AAA-login-fails.md");
+    assert_eq!(json["assignee"], "alice");
+    assert_eq!(json["attachments"][0], "1-shot.png");
+    assert!(json["body"]
+        .as_str()
+        .unwrap()
+        .starts_with("## Steps\n\nOpen the page"));
+}
+
+#[test]
+fn test_show_raw_conflicts_with_brief() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["show", "--id", "260101", "--raw", "--brief"])
+        .assert()
+        .failure();
+}
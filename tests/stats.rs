@@ -0,0 +1,284 @@
+//! # Stats Command Tests
+//!
+//! Tests for `qs stats` item counts, first-response metrics, the
+//! per-author breakdown, and Markdown output.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::{fs, process::Command as StdCommand};
+
+use common::{create_test_item, make_item_content, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+/// Commits all changes as `author` at `date`; returns false if git is unavailable.
+fn commit_as(env: &TestEnv, author: &str, date: &str) -> bool {
+    let git = |args: &[&str]| {
+        StdCommand::new("git")
+            .args(["-c", &format!("user.name={author}")])
+            .args(["-c", "user.email=test@example.com"])
+            .args(args)
+            .env("GIT_AUTHOR_DATE", date)
+            .env("GIT_COMMITTER_DATE", date)
+            .current_dir(env.project_path())
+            .output()
+            .is_ok_and(|output| output.status.success())
+    };
+    git(&["add", "-A"]) && git(&["commit", "-q", "-m", "update"])
+}
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env
+}
+
+#[test]
+fn test_stats_counts_without_git() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "One", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Two", "in-progress", &[], None);
+    create_test_item(&env, "260103-CCC", "Three", "open", &[], None);
+
+    qs_cmd(&env)
+        .arg("stats")
+        .assert()
+        .success()
+        .stdout("Items: 2 open, 1 in progress, 0 closed\nFirst response: no git history\n");
+}
+
+#[test]
+fn test_stats_first_response_from_git_history() {
+    let env = setup();
+    if !StdCommand::new("git")
+        .args(["init", "-q"])
+        .current_dir(env.project_path())
+        .status()
+        .is_ok_and(|s| s.success())
+    {
+        return; // git not available
+    }
+
+    // Both items are created (2026-01-09 12:00) and committed by their author
+    let responded = create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Typo", "open", &[], None);
+    if !commit_as(&env, "Test User", "2026-01-09T12:30:00Z") {
+        return;
+    }
+
+    // The author's own follow-up does not count as a response
+    fs::write(
+        &responded,
+        fs::read_to_string(&responded).unwrap() + "More\n",
+    )
+    .unwrap();
+    assert!(commit_as(&env, "Test User", "2026-01-09T18:00:00Z"));
+
+    // A maintainer touches the first item a day and four hours later
+    fs::write(
+        &responded,
+        fs::read_to_string(&responded).unwrap() + "Ack\n",
+    )
+    .unwrap();
+    assert!(commit_as(&env, "Maintainer", "2026-01-10T16:00:00Z"));
+
+    qs_cmd(&env)
+        .arg("stats")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Responded: 1 of 2 items\n"))
+        .stdout(predicate::str::contains("Median:    1d 4h\n"))
+        .stdout(predicate::str::contains("Average:   1d 4h\n"))
+        .stdout(predicate::str::contains("Awaiting:  1 open item(s)\n"));
+}
+
+#[test]
+fn test_stats_identities_unify_commit_authors() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .identity("Test User", &["TU", "test@example.com"])
+            .build(),
+    );
+    commands::init().expect("init");
+    if !StdCommand::new("git")
+        .args(["init", "-q"])
+        .current_dir(env.project_path())
+        .status()
+        .is_ok_and(|s| s.success())
+    {
+        return; // git not available
+    }
+
+    let item = create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+    if !commit_as(&env, "Test User", "2026-01-09T12:30:00Z") {
+        return;
+    }
+
+    // The author under another spelling is not a response
+    fs::write(&item, fs::read_to_string(&item).unwrap() + "More\n").unwrap();
+    assert!(commit_as(&env, "TU", "2026-01-09T18:00:00Z"));
+
+    qs_cmd(&env)
+        .arg("stats")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Responded: 0 of 1 items\n"))
+        .stdout(predicate::str::contains("Awaiting:  1 open item(s)\n"));
+}
+
+/// Writes an item with the given author, status, and optional assignee;
+/// closed items go to the archive, closed `days` after creation.
+fn write_item(
+    env: &TestEnv,
+    id: &str,
+    author: &str,
+    status: &str,
+    assignee: Option<&str>,
+    days: u32,
+) {
+    let mut content = make_item_content(id, "Task", status, &[], None, None)
+        .replace("author: Test User", &format!("author: {author}"));
+    if let Some(assignee) = assignee {
+        content = content.replace("status:", &format!("assignee: {assignee}\nstatus:"));
+    }
+    let dir = if status == "closed" {
+        content = content.replace(
+            "status: closed",
+            &format!(
+                "status: closed\nclosed_at: 2026-01-{:02}T12:00:00Z",
+                9 + days
+            ),
+        );
+        env.archive_path()
+    } else {
+        env.stack_path()
+    };
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(format!("{id}-task.md")), content).unwrap();
+}
+
+#[test]
+fn test_stats_by_author() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .identity("Alice Smith", &["alice"])
+            .build(),
+    );
+    commands::init().expect("init");
+
+    write_item(&env, "260101-AAA", "Test User", "open", Some("alice"), 0);
+    write_item(
+        &env,
+        "260102-BBB",
+        "Test User",
+        "in-progress",
+        Some("Alice Smith"),
+        0,
+    );
+    write_item(&env, "260103-CCC", "Bob", "closed", Some("alice"), 2);
+    write_item(&env, "260104-DDD", "Bob", "closed", None, 1);
+
+    qs_cmd(&env)
+        .args(["stats", "--author"])
+        .assert()
+        .success()
+        .stdout(
+            "Author       Created  Closed  Assigned  Avg cycle\n\
+             Alice Smith        0       1         2  2d 0h\n\
+             Bob                2       1         0  1d 0h\n\
+             Test User          2       0         0  -\n",
+        );
+
+    let output = qs_cmd(&env)
+        .args(["stats", "--author", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json[0]["author"], "Alice Smith");
+    assert_eq!(json[0]["assigned"], 2);
+    assert_eq!(json[0]["average_cycle_minutes"], 2 * 24 * 60);
+    assert_eq!(json[2]["author"], "Test User");
+    assert!(json[2]["average_cycle_minutes"].is_null());
+}
+
+#[test]
+fn test_stats_json_summary() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "One", "open", &[], None);
+
+    let output = qs_cmd(&env)
+        .args(["stats", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["open"], 1);
+    assert_eq!(json["closed"], 0);
+    assert!(json["first_response"].is_null());
+}
+
+#[test]
+fn test_stats_markdown_summary() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "One", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Two", "open", &[], None);
+    create_test_item(&env, "260103-CCC", "Three", "in-progress", &[], None);
+
+    qs_cmd(&env)
+        .args(["stats", "--format", "markdown"])
+        .assert()
+        .success()
+        .stdout(
+            "## Items\n\n\
+             | Status | Items |\n\
+             | --- | ---: |\n\
+             | Open | 2 |\n\
+             | In progress | 1 |\n\
+             | Closed | 0 |\n\
+             \n\
+             ```mermaid\n\
+             pie title Items by status\n    \
+             \"Open\" : 2\n    \
+             \"In progress\" : 1\n\
+             ```\n\
+             \n\
+             ## First response\n\n\
+             No git history.\n",
+        );
+}
+
+#[test]
+fn test_stats_markdown_by_author() {
+    let env = setup();
+    write_item(&env, "260101-AAA", "Test User", "open", Some("Alice"), 0);
+    write_item(&env, "260102-BBB", "Bob", "closed", None, 1);
+
+    qs_cmd(&env)
+        .args(["stats", "--author", "--format", "markdown"])
+        .assert()
+        .success()
+        .stdout(
+            "## Authors\n\n\
+             | Author | Created | Closed | Assigned | Avg cycle |\n\
+             | --- | ---: | ---: | ---: | ---: |\n\
+             | Alice | 0 | 0 | 1 | - |\n\
+             | Bob | 1 | 1 | 0 | 1d 0h |\n\
+             | Test User | 1 | 0 | 0 | - |\n\
+             \n\
+             ```mermaid\n\
+             pie title Assigned open items\n    \
+             \"Alice\" : 1\n\
+             ```\n",
+        );
+}
@@ -0,0 +1,177 @@
+//! # Status Directory Tests
+//!
+//! Tests for keeping open and in-progress items in their own directories
+//! (`[status_dirs]`) and moving them when their status changes.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::fs;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::{commands, config::Config, storage};
+
+/// Sets up a project with open items in `todo/` and in-progress items in `doing/`.
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .archive_dir(".archive")
+            .status_dir("open", "todo")
+            .status_dir("in-progress", "doing")
+            .build(),
+    );
+    env
+}
+
+/// Creates an open item in `todo/<category>/`.
+fn create_todo_item(env: &TestEnv, id: &str, title: &str, category: &str) {
+    let path = create_test_item(env, id, title, "open", &[], Some(category));
+    let dir = env.stack_path().join("todo").join(category);
+    fs::create_dir_all(&dir).unwrap();
+    fs::rename(&path, dir.join(path.file_name().unwrap())).unwrap();
+    fs::remove_dir(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn test_new_item_goes_to_open_directory() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["new", "Crash", "--category", "bugs"])
+        .assert()
+        .success();
+
+    let files: Vec<_> = fs::read_dir(env.stack_path().join("todo").join("bugs"))
+        .unwrap()
+        .collect();
+    assert_eq!(files.len(), 1);
+    assert!(!env.stack_path().join("bugs").exists());
+}
+
+#[test]
+fn test_status_changes_move_between_directories() {
+    let env = setup();
+    create_todo_item(&env, "260101-AAA", "Task", "bugs");
+    let config = Config::load().unwrap();
+
+    qs_cmd(&env)
+        .args(["patch", "--id", "260101", "--set", "status=in-progress"])
+        .assert()
+        .success();
+    let doing = env
+        .stack_path()
+        .join("doing")
+        .join("bugs")
+        .join("260101-AAA-task.md");
+    assert!(doing.exists());
+    assert!(!env.stack_path().join("todo").exists());
+    assert_eq!(
+        storage::derive_category(&config, &doing).as_deref(),
+        Some("bugs")
+    );
+
+    qs_cmd(&env)
+        .args(["close", "--id", "260101"])
+        .assert()
+        .success();
+    assert!(env
+        .archive_path()
+        .join("bugs")
+        .join("260101-AAA-task.md")
+        .exists());
+    assert!(!env.stack_path().join("doing").exists());
+
+    qs_cmd(&env)
+        .args(["reopen", "--id", "260101"])
+        .assert()
+        .success();
+    assert!(env
+        .stack_path()
+        .join("todo")
+        .join("bugs")
+        .join("260101-AAA-task.md")
+        .exists());
+}
+
+#[test]
+fn test_pop_moves_claimed_item_to_in_progress_directory() {
+    let env = setup();
+    create_todo_item(&env, "260101-AAA", "Task", "bugs");
+
+    qs_cmd(&env)
+        .args(["pop", "--category", "bugs"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("doing/bugs/260101-AAA-task.md"));
+
+    assert!(env
+        .stack_path()
+        .join("doing")
+        .join("bugs")
+        .join("260101-AAA-task.md")
+        .exists());
+}
+
+#[test]
+fn test_items_in_status_directories_are_listed() {
+    let env = setup();
+    create_todo_item(&env, "260101-AAA", "Task", "bugs");
+
+    qs_cmd(&env)
+        .args(["list", "--category", "bugs"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260101-AAA"));
+}
+
+#[test]
+fn test_info_reports_item_outside_its_status_directory() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Task", "in-progress", &[], None);
+
+    qs_cmd(&env)
+        .arg("info")
+        .assert()
+        .stdout(predicate::str::contains(
+            "260101-AAA-task.md is in-progress but not in queuestack/doing/",
+        ));
+}
+
+#[test]
+fn test_category_named_like_status_directory_is_rejected() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["new", "Crash", "--category", "doing"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("it is the directory of a status"));
+}
+
+#[test]
+fn test_closed_status_cannot_be_mapped() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .status_dir("closed", "done")
+            .build(),
+    );
+
+    qs_cmd(&env)
+        .arg("list")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid status 'closed' in [status_dirs]",
+        ));
+}
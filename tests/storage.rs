@@ -0,0 +1,119 @@
+//! # Storage Backend Tests
+//!
+//! Tests the version control system selection and that item files are moved
+//! and removed with plain file operations when the system is missing or the
+//! project isn't in its repository.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use assert_cmd::Command;
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+/// Creates a qs command that can't find git.
+fn qs_cmd_without_git(env: &TestEnv) -> Command {
+    let mut cmd = qs_cmd(env);
+    cmd.env("PATH", env.home_dir.path());
+    cmd
+}
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+    env
+}
+
+#[test]
+fn test_without_git_moves_files_and_notices_once() {
+    let env = setup();
+
+    qs_cmd_without_git(&env)
+        .args(["close", "--id", "260101-AAA"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("note: git is not installed"));
+    assert!(env.archive_path().join("260101-AAA-crash.md").exists());
+
+    // The notice is shown once per project
+    qs_cmd_without_git(&env)
+        .args(["reopen", "--id", "260101-AAA"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("note:").not());
+    assert!(env.stack_path().join("260101-AAA-crash.md").exists());
+}
+
+#[test]
+fn test_info_reports_missing_git() {
+    let env = setup();
+
+    qs_cmd_without_git(&env)
+        .arg("info")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Storage: filesystem (git is not installed)",
+        ));
+}
+
+#[test]
+fn test_info_reports_filesystem_outside_repository() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .arg("info")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Storage: filesystem ("))
+        .stdout(predicate::str::contains("Git: ").not());
+}
+
+#[test]
+fn test_jj_repository_is_detected() {
+    let env = setup();
+    std::fs::create_dir_all(env.project_path().join(".jj")).unwrap();
+
+    qs_cmd(&env)
+        .arg("info")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Storage: jj"));
+
+    // jj snapshots the working copy, so files are moved directly
+    qs_cmd(&env)
+        .args(["close", "--id", "260101-AAA"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("note:").not());
+    assert!(env.archive_path().join("260101-AAA-crash.md").exists());
+}
+
+#[test]
+fn test_configured_vcs_without_its_command() {
+    let env = setup();
+    env.write_project_config(&ProjectConfigBuilder::new().vcs("hg").build());
+
+    qs_cmd_without_git(&env)
+        .arg("info")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Storage: filesystem (hg is not installed)",
+        ));
+
+    qs_cmd_without_git(&env)
+        .args(["close", "--id", "260101-AAA"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "note: hg is not installed; item files are moved and removed without hg",
+        ));
+}
@@ -6,10 +6,14 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
 mod common;
 
 use common::{GlobalConfigBuilder, TestEnv};
-use queuestack::commands::{self, InteractiveArgs, ListMode, ListOptions, NewArgs, StatusFilter};
+use queuestack::commands::{
+    self, InteractiveArgs, ListMode, ListOptions, NewArgs, OutputFormat, Scope,
+};
 
 // =============================================================================
 // Creating Templates
@@ -25,6 +29,8 @@ fn test_new_as_template() {
         title: Some("Bug Report Template".to_string()),
         labels: vec!["bug".to_string()],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -32,6 +38,7 @@ fn test_new_as_template() {
         },
         as_template: true,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -62,6 +69,8 @@ fn test_new_as_template_with_category() {
         title: Some("Feature Request Template".to_string()),
         labels: vec!["feature".to_string()],
         category: Some("features".to_string()),
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -69,6 +78,7 @@ fn test_new_as_template_with_category() {
         },
         as_template: true,
         from_template: None,
+        vars: vec![],
     };
 
     commands::new(args).expect("new should succeed");
@@ -103,6 +113,8 @@ fn test_list_templates() {
             title: Some(title.to_string()),
             labels: vec![],
             category: None,
+            due: None,
+            assignee: None,
             attachments: vec![],
             interactive: InteractiveArgs {
                 interactive: false,
@@ -110,6 +122,7 @@ fn test_list_templates() {
             },
             as_template: true,
             from_template: None,
+            vars: vec![],
         };
         commands::new(args).expect("new should succeed");
     }
@@ -117,17 +130,33 @@ fn test_list_templates() {
     // List templates
     let options = ListOptions {
         mode: ListMode::Templates,
-        status: StatusFilter::Open,
+        scope: Scope::Open,
         labels: vec![],
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: commands::SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     // Should not error
@@ -153,6 +182,8 @@ fn test_new_from_template() {
         title: Some("Bug Report".to_string()),
         labels: vec!["bug".to_string()],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -160,6 +191,7 @@ fn test_new_from_template() {
         },
         as_template: true,
         from_template: None,
+        vars: vec![],
     };
     commands::new(template_args).expect("create template should succeed");
 
@@ -170,7 +202,7 @@ fn test_new_from_template() {
         .lines()
         .find(|l| l.starts_with("id:"))
         .and_then(|l| l.strip_prefix("id: "))
-        .map(|s| s.trim_matches('\'')) // Remove YAML quotes
+        .map(|s| s.trim_matches(['\'', '"'])) // Remove YAML quotes
         .expect("Should have ID");
 
     // Create item from template
@@ -178,6 +210,8 @@ fn test_new_from_template() {
         title: Some("Login Bug".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -185,6 +219,7 @@ fn test_new_from_template() {
         },
         as_template: false,
         from_template: Some(Some(template_id.to_string())),
+        vars: vec![],
     };
     commands::new(item_args).expect("create from template should succeed");
 
@@ -211,6 +246,8 @@ fn test_new_from_template_inherits_labels() {
         title: Some("Bug Template".to_string()),
         labels: vec!["bug".to_string(), "needs-triage".to_string()],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -218,6 +255,7 @@ fn test_new_from_template_inherits_labels() {
         },
         as_template: true,
         from_template: None,
+        vars: vec![],
     };
     commands::new(template_args).expect("create template should succeed");
 
@@ -227,7 +265,7 @@ fn test_new_from_template_inherits_labels() {
         .lines()
         .find(|l| l.starts_with("id:"))
         .and_then(|l| l.strip_prefix("id: "))
-        .map(|s| s.trim_matches('\'')) // Remove YAML quotes
+        .map(|s| s.trim_matches(['\'', '"'])) // Remove YAML quotes
         .expect("Should have ID");
 
     // Create item from template with additional label
@@ -235,6 +273,8 @@ fn test_new_from_template_inherits_labels() {
         title: Some("Crash Bug".to_string()),
         labels: vec!["critical".to_string()],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -242,6 +282,7 @@ fn test_new_from_template_inherits_labels() {
         },
         as_template: false,
         from_template: Some(Some(template_id.to_string())),
+        vars: vec![],
     };
     commands::new(item_args).expect("create from template should succeed");
 
@@ -267,6 +308,8 @@ fn test_new_from_template_inherits_category() {
         title: Some("Bug Template".to_string()),
         labels: vec![],
         category: Some("bugs".to_string()),
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -274,6 +317,7 @@ fn test_new_from_template_inherits_category() {
         },
         as_template: true,
         from_template: None,
+        vars: vec![],
     };
     commands::new(template_args).expect("create template should succeed");
 
@@ -283,7 +327,7 @@ fn test_new_from_template_inherits_category() {
         .lines()
         .find(|l| l.starts_with("id:"))
         .and_then(|l| l.strip_prefix("id: "))
-        .map(|s| s.trim_matches('\'')) // Remove YAML quotes
+        .map(|s| s.trim_matches(['\'', '"'])) // Remove YAML quotes
         .expect("Should have ID");
 
     // Create item from template without specifying category
@@ -291,6 +335,8 @@ fn test_new_from_template_inherits_category() {
         title: Some("New Bug".to_string()),
         labels: vec![],
         category: None, // Should inherit from template
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -298,6 +344,7 @@ fn test_new_from_template_inherits_category() {
         },
         as_template: false,
         from_template: Some(Some(template_id.to_string())),
+        vars: vec![],
     };
     commands::new(item_args).expect("create from template should succeed");
 
@@ -321,6 +368,8 @@ fn test_templates_excluded_from_list() {
         title: Some("Regular Item".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -328,6 +377,7 @@ fn test_templates_excluded_from_list() {
         },
         as_template: false,
         from_template: None,
+        vars: vec![],
     };
     commands::new(item_args).expect("create item should succeed");
 
@@ -336,6 +386,8 @@ fn test_templates_excluded_from_list() {
         title: Some("Bug Template".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -343,23 +395,40 @@ fn test_templates_excluded_from_list() {
         },
         as_template: true,
         from_template: None,
+        vars: vec![],
     };
     commands::new(template_args).expect("create template should succeed");
 
     // List regular items - should only show 1
     let options = ListOptions {
         mode: ListMode::Items,
-        status: StatusFilter::Open,
+        scope: Scope::Open,
         labels: vec![],
+        any_labels: Vec::new(),
+        not_labels: Vec::new(),
         author: None,
+        assignee: None,
+        mine: false,
         category: None,
+        since: None,
+        until: None,
+        due_after: None,
+        due_before: None,
+        overdue: false,
+        blocked: None,
         sort: commands::SortBy::Id,
+        group_by: None,
+        summary: false,
+        verbose: false,
+        print_selection: None,
         interactive: InteractiveArgs {
             interactive: false,
             no_interactive: true,
         },
         id: None,
         file: None,
+        mirrors: true,
+        format: OutputFormat::Text,
     };
 
     // Verify counts
@@ -383,6 +452,8 @@ fn test_find_template_by_title() {
         title: Some("Bug Report".to_string()),
         labels: vec!["bug".to_string()],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -390,6 +461,7 @@ fn test_find_template_by_title() {
         },
         as_template: true,
         from_template: None,
+        vars: vec![],
     };
     commands::new(template_args).expect("create template should succeed");
 
@@ -398,6 +470,8 @@ fn test_find_template_by_title() {
         title: Some("My Bug".to_string()),
         labels: vec![],
         category: None,
+        due: None,
+        assignee: None,
         attachments: vec![],
         interactive: InteractiveArgs {
             interactive: false,
@@ -405,6 +479,7 @@ fn test_find_template_by_title() {
         },
         as_template: false,
         from_template: Some(Some("bug report".to_string())), // Case-insensitive title match
+        vars: vec![],
     };
     commands::new(item_args).expect("create from template by title should succeed");
 
@@ -413,3 +488,155 @@ fn test_find_template_by_title() {
     let item_content = env.read_item(&items[0]);
     assert!(item_content.contains("- bug"), "Should inherit bug label");
 }
+
+#[test]
+fn test_new_from_template_expands_variables() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().build());
+    commands::init().expect("init should succeed");
+
+    let template_args = NewArgs {
+        title: Some("Bug Report".to_string()),
+        labels: vec!["bug".to_string()],
+        category: None,
+        due: None,
+        assignee: None,
+        attachments: vec![],
+        interactive: InteractiveArgs {
+            interactive: false,
+            no_interactive: true,
+        },
+        as_template: true,
+        from_template: None,
+        vars: vec![],
+    };
+    commands::new(template_args).expect("create template should succeed");
+
+    // Give the template a custom field and a body with variables
+    let template_path = &env.list_template_files()[0];
+    let content = env
+        .read_item(template_path)
+        .replace("status: template", "status: template\npriority: high");
+    std::fs::write(
+        template_path,
+        format!("{content}# {{{{title}}}}\n\nReported by {{{{author}}}} in {{{{category}}}} ({{{{priority}}}}, {{{{unknown}}}})\n"),
+    )
+    .unwrap();
+
+    let item_args = NewArgs {
+        title: Some("Login Bug".to_string()),
+        labels: vec![],
+        category: Some("backend".to_string()),
+        due: None,
+        assignee: None,
+        attachments: vec![],
+        interactive: InteractiveArgs {
+            interactive: false,
+            no_interactive: true,
+        },
+        as_template: false,
+        from_template: Some(Some("bug report".to_string())),
+        vars: vec![],
+    };
+    commands::new(item_args).expect("create from template should succeed");
+
+    let items = env.list_category_files("backend");
+    let item_content = env.read_item(&items[0]);
+    assert!(item_content.contains("# Login Bug\n"));
+    assert!(item_content.contains("Reported by Test User in backend (high, {{unknown}})"));
+    assert!(
+        item_content.contains("priority: high"),
+        "Should inherit custom fields"
+    );
+}
+
+/// Creates the "Bug Report" template with the given body.
+fn create_template_with_body(env: &TestEnv, body: &str) {
+    let template_args = NewArgs {
+        title: Some("Bug Report".to_string()),
+        labels: vec![],
+        category: None,
+        due: None,
+        assignee: None,
+        attachments: vec![],
+        interactive: InteractiveArgs {
+            interactive: false,
+            no_interactive: true,
+        },
+        as_template: true,
+        from_template: None,
+        vars: vec![],
+    };
+    commands::new(template_args).expect("create template should succeed");
+
+    let template_path = &env.list_template_files()[0];
+    let content = env.read_item(template_path);
+    std::fs::write(template_path, format!("{content}{body}")).unwrap();
+}
+
+fn new_from_template(vars: &[(&str, &str)]) -> anyhow::Result<()> {
+    commands::new(NewArgs {
+        title: Some("Login Bug".to_string()),
+        labels: vec![],
+        category: None,
+        due: None,
+        assignee: None,
+        attachments: vec![],
+        interactive: InteractiveArgs {
+            interactive: false,
+            no_interactive: true,
+        },
+        as_template: false,
+        from_template: Some(Some("bug report".to_string())),
+        vars: vars
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+            .collect(),
+    })
+}
+
+#[test]
+fn test_new_from_template_fills_prompts() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().build());
+    commands::init().expect("init should succeed");
+    create_template_with_body(
+        &env,
+        "## Steps\n{{prompt:Steps to reproduce}}\n\n## Expected\n{{prompt:Expected}}\n",
+    );
+
+    new_from_template(&[
+        ("steps-to-reproduce", "Open the app"),
+        ("Expected", "{{title}} works"),
+    ])
+    .expect("create from template should succeed");
+
+    let items = env.list_stack_files();
+    let item_content = env.read_item(&items[0]);
+    assert!(
+        item_content.contains("## Steps\nOpen the app\n\n## Expected\nLogin Bug works\n"),
+        "{item_content}"
+    );
+}
+
+#[test]
+fn test_new_from_template_requires_prompt_values() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().build());
+    commands::init().expect("init should succeed");
+    create_template_with_body(&env, "{{prompt:Steps to reproduce}}\n{{prompt:Expected}}\n");
+
+    let err = new_from_template(&[("expected", "It works")]).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Missing values for template prompts: Steps to reproduce"),
+        "{err}"
+    );
+
+    let err = new_from_template(&[("actual", "Crash")]).unwrap_err();
+    assert!(
+        err.to_string().contains("Unknown variable 'actual'"),
+        "{err}"
+    );
+    assert!(env.list_stack_files().is_empty());
+}
@@ -0,0 +1,252 @@
+//! # Trash Tests
+//!
+//! Tests for deleting items into `.trash/` (`qs delete`), `qs trash list`,
+//! `qs trash restore`, and purging after `trash_days`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::{fs, process::Command as StdCommand};
+
+use common::{
+    create_test_item, create_test_item_with_attachments, qs_cmd, GlobalConfigBuilder, TestEnv,
+};
+use predicates::prelude::*;
+use queuestack::{
+    commands::{self, TrashRestoreArgs},
+    config::Config,
+    storage,
+};
+
+fn setup(global: GlobalConfigBuilder) -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&global.interactive(false).build());
+    commands::init().expect("init");
+    env
+}
+
+fn delete(path: &std::path::Path) {
+    let config = Config::load().unwrap();
+    storage::delete_item(&config, path).expect("delete should succeed");
+}
+
+fn restore(id: &str) -> anyhow::Result<()> {
+    commands::trash_restore(&TrashRestoreArgs { id: id.to_string() })
+}
+
+#[test]
+fn test_delete_and_restore_round_trip() {
+    let env = setup(GlobalConfigBuilder::new());
+    let path = create_test_item_with_attachments(
+        &env,
+        "260101-AAA",
+        "Crash",
+        "open",
+        &["1-log.txt"],
+        Some("bugs"),
+    );
+
+    delete(&path);
+    assert!(!path.exists());
+    let config = Config::load().unwrap();
+    assert!(storage::find_by_id(&config, "260101-AAA").is_err());
+    assert!(env.stack_path().join(".trash").is_dir());
+
+    qs_cmd(&env)
+        .args(["trash", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260101-AAA  "))
+        .stdout(predicate::str::contains(
+            "  bugs/260101-AAA-crash.md  Crash\n",
+        ));
+
+    restore("260101").expect("restore should succeed");
+    assert!(path.exists());
+    assert_eq!(env.list_attachment_files("260101-AAA").len(), 1);
+    assert!(!env.stack_path().join(".trash").exists());
+}
+
+#[test]
+fn test_restore_archived_item_returns_to_archive() {
+    let env = setup(GlobalConfigBuilder::new());
+    let path = create_test_item(&env, "260101-AAA", "Done", "closed", &[], None);
+    let archived = env.stack_path().join(".archive").join("260101-AAA-done.md");
+    fs::create_dir_all(archived.parent().unwrap()).unwrap();
+    fs::rename(&path, &archived).unwrap();
+
+    delete(&archived);
+    restore("260101-AAA").expect("restore should succeed");
+    assert!(archived.exists());
+}
+
+#[test]
+fn test_restore_refuses_existing_id() {
+    let env = setup(GlobalConfigBuilder::new());
+    let path = create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+    delete(&path);
+    create_test_item(&env, "260101-AAA", "Again", "open", &[], None);
+
+    let err = restore("260101-AAA").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Cannot restore '260101-AAA': an item with this ID already exists (queuestack/260101-AAA-again.md)"
+    );
+}
+
+#[test]
+fn test_restore_unknown_item() {
+    let _env = setup(GlobalConfigBuilder::new());
+
+    let err = restore("NOPE").unwrap_err();
+    assert_eq!(err.to_string(), "No trashed item found matching 'NOPE'");
+}
+
+#[test]
+fn test_expired_deletions_are_purged() {
+    let env = setup(GlobalConfigBuilder::new().trash_days(7));
+    let path = create_test_item(&env, "260101-AAA", "Old", "open", &[], None);
+    delete(&path);
+
+    // Backdate the deletion
+    let trash = env.stack_path().join(".trash");
+    let stamp = fs::read_dir(&trash)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+    fs::rename(&stamp, trash.join("20200101-000000")).unwrap();
+
+    qs_cmd(&env)
+        .args(["trash", "list"])
+        .assert()
+        .success()
+        .stdout("Trash is empty.\n");
+    assert!(!trash.exists());
+}
+
+#[test]
+fn test_zero_trash_days_keeps_deletions() {
+    let env = setup(GlobalConfigBuilder::new().trash_days(0));
+    let path = create_test_item(&env, "260101-AAA", "Old", "open", &[], None);
+    delete(&path);
+
+    let trash = env.stack_path().join(".trash");
+    let stamp = fs::read_dir(&trash)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+    fs::rename(&stamp, trash.join("20200101-000000")).unwrap();
+
+    qs_cmd(&env)
+        .args(["trash", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "2020-01-01 00:00  260101-AAA-old.md  Old",
+        ));
+}
+
+#[test]
+fn test_delete_is_a_git_rename() {
+    let env = setup(GlobalConfigBuilder::new());
+    let git = |args: &[&str]| {
+        StdCommand::new("git")
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(env.project_path())
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+    };
+    if git(&["init", "-q"]).is_none() {
+        return; // git not available
+    }
+    let path = create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+    if git(&["add", "-A"]).is_none() || git(&["commit", "-q", "-m", "Initial"]).is_none() {
+        return;
+    }
+
+    delete(&path);
+
+    let status = git(&["status", "--porcelain"]).unwrap();
+    let status = String::from_utf8_lossy(&status.stdout);
+    assert!(
+        status.starts_with("R  queuestack/260101-AAA-crash.md -> queuestack/.trash/"),
+        "unexpected status: {status}"
+    );
+}
+
+#[test]
+fn test_delete_command_moves_item_to_trash() {
+    let env = setup(GlobalConfigBuilder::new());
+    let path = create_test_item_with_attachments(
+        &env,
+        "260101-AAA",
+        "Crash",
+        "open",
+        &["1-log.txt"],
+        Some("bugs"),
+    );
+
+    qs_cmd(&env)
+        .args(["delete", "--id", "260101", "--force"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Moved to trash: queuestack/bugs/260101-AAA-crash.md (restore with 'qs trash restore 260101-AAA')",
+        ));
+
+    assert!(!path.exists());
+    assert!(!env.stack_path().join("bugs").exists());
+    restore("260101-AAA").expect("restore should succeed");
+    assert!(path.exists());
+    assert_eq!(env.list_attachment_files("260101-AAA").len(), 1);
+}
+
+#[test]
+fn test_delete_command_requires_confirmation() {
+    let env = setup(GlobalConfigBuilder::new());
+    let path = create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["delete", "--id", "260101"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("pass --force"));
+
+    assert!(path.exists());
+
+    // --yes is an alias for --force
+    qs_cmd(&env)
+        .args(["delete", "--id", "260101", "--yes"])
+        .assert()
+        .success();
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_delete_command_protected_item() {
+    let env = setup(GlobalConfigBuilder::new().protected(&["legal"], &[], &[]));
+    let path = create_test_item(&env, "260101-AAA", "Contract", "open", &[], Some("legal"));
+
+    qs_cmd(&env)
+        .args(["delete", "--file", path.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Cannot delete '260101-AAA'"));
+    assert!(path.exists());
+
+    qs_cmd(&env)
+        .args(["delete", "--file", path.to_str().unwrap(), "--force"])
+        .assert()
+        .success();
+    assert!(!path.exists());
+}
@@ -0,0 +1,154 @@
+//! # Triage Command Tests
+//!
+//! Tests for printing triage lists with `qs triage` and applying edited ones
+//! with `qs triage --apply`.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::fs;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::{
+    commands,
+    item::{Item, Status},
+};
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env.write_project_config(&ProjectConfigBuilder::new().archive_dir(".archive").build());
+    env
+}
+
+/// Prints the triage list for `args`.
+fn list(env: &TestEnv, args: &[&str]) -> String {
+    let output = qs_cmd(env).arg("triage").args(args).output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Writes an edited list to `list.txt` and applies it.
+fn apply(env: &TestEnv, text: &str) -> assert_cmd::assert::Assert {
+    fs::write(env.project_dir.path().join("list.txt"), text).unwrap();
+    qs_cmd(env).args(["triage", "--apply", "list.txt"]).assert()
+}
+
+#[test]
+fn test_lists_active_items_one_per_line() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Crash", "open", &["bug"], Some("bugs"));
+    create_test_item(&env, "260102-BBB", "Docs", "in-progress", &[], None);
+    create_test_item(&env, "260103-CCC", "Done", "closed", &[], None);
+
+    let text = list(&env, &[]);
+
+    assert!(text.starts_with("# Triage:"));
+    assert!(text.contains("260101-AAA | open        | bugs |  | bug | Crash"));
+    assert!(text.contains("260102-BBB | in-progress |      |  |     | Docs"));
+    assert!(!text.contains("260103-CCC"));
+}
+
+#[test]
+fn test_list_filters() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Crash", "open", &["bug"], None);
+    create_test_item(&env, "260102-BBB", "Docs", "open", &[], None);
+
+    let text = list(&env, &["--label", "bug"]);
+
+    assert!(text.contains("260101-AAA"));
+    assert!(!text.contains("260102-BBB"));
+}
+
+#[test]
+fn test_apply_changes_fields_and_moves_items() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Crash", "open", &["bug"], None);
+    let untouched = create_test_item(&env, "260102-BBB", "Docs", "open", &[], None);
+    let before = fs::read_to_string(&untouched).unwrap();
+
+    let text: String = list(&env, &[])
+        .lines()
+        .map(|line| {
+            if line.starts_with("260101-AAA") {
+                "260101-AAA | in-progress | bugs | Alice | bug, Needs Review | Crash\n".to_string()
+            } else {
+                format!("{line}\n")
+            }
+        })
+        .collect();
+    apply(&env, &text)
+        .success()
+        .stdout(predicate::str::contains(
+            "Updated item: queuestack/bugs/260101-AAA-crash.md",
+        ));
+
+    let item = Item::load(&env.stack_path().join("bugs").join("260101-AAA-crash.md")).unwrap();
+    assert_eq!(item.status(), Status::InProgress);
+    assert_eq!(item.labels(), ["bug", "needs-review"]);
+    assert_eq!(item.assignee().as_deref(), Some("Alice"));
+    assert_eq!(fs::read_to_string(&untouched).unwrap(), before);
+}
+
+#[test]
+fn test_apply_closes_items() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+
+    apply(&env, "260101-AAA | closed | | | | Crash\n")
+        .success()
+        .stdout(predicate::str::contains("Closed item:"));
+
+    let item = Item::load(&env.archive_path().join("260101-AAA-crash.md")).unwrap();
+    assert_eq!(item.status(), Status::Closed);
+}
+
+#[test]
+fn test_apply_unchanged_list_does_nothing() {
+    let env = setup();
+    create_test_item(&env, "260101-AAA", "Crash", "open", &["bug"], None);
+
+    apply(&env, &list(&env, &[]))
+        .success()
+        .stdout(predicate::str::contains("No changes to apply."));
+}
+
+#[test]
+fn test_apply_invalid_list_changes_nothing() {
+    let env = setup();
+    let path = create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+    create_test_item(&env, "260102-BBB", "Done", "closed", &[], None);
+    let before = fs::read_to_string(&path).unwrap();
+
+    apply(
+        &env,
+        "260101-AAA | in-progress | | | | Crash\n\
+         260102-BBB | open | | | | Done\n\
+         260109-ZZZ | open | | | | Missing\n",
+    )
+    .failure()
+    .stderr(predicate::str::contains("260109-ZZZ: no item with this id"))
+    .stderr(predicate::str::contains(
+        "260102-BBB: only open and in-progress items can be triaged",
+    ));
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), before);
+}
+
+#[test]
+fn test_edit_requires_terminal() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["triage", "--edit"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--edit needs a terminal"));
+}
@@ -5,9 +5,12 @@
 //! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
 //! Licensed under the MIT License.
 
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
 mod common;
 
-use common::{create_test_item, GlobalConfigBuilder, TestEnv};
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use predicates::prelude::*;
 use queuestack::commands::{self, UpdateArgs};
 
 #[test]
@@ -26,6 +29,13 @@ fn test_update_title() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
     };
 
     commands::update(args).expect("update should succeed");
@@ -55,6 +65,13 @@ fn test_update_add_labels() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
     };
 
     commands::update(args).expect("update should succeed");
@@ -84,6 +101,13 @@ fn test_update_move_to_category() {
         remove_labels: vec![],
         category: Some("bugs".to_string()),
         remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
     };
 
     commands::update(args).expect("update should succeed");
@@ -108,6 +132,13 @@ fn test_update_clear_category() {
         remove_labels: vec![],
         category: None,
         remove_category: true,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
     };
 
     commands::update(args).expect("update should succeed");
@@ -133,6 +164,13 @@ fn test_update_nonexistent_id() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
     };
 
     let result = commands::update(args);
@@ -159,6 +197,13 @@ fn test_update_multiple_labels_at_once() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
     };
 
     commands::update(args).expect("update should succeed");
@@ -186,6 +231,13 @@ fn test_update_title_and_category_combined() {
         remove_labels: vec![],
         category: Some("bugs".to_string()),
         remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
     };
 
     commands::update(args).expect("update should succeed");
@@ -223,6 +275,13 @@ fn test_update_title_and_labels_combined() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
     };
 
     commands::update(args).expect("update should succeed");
@@ -256,6 +315,13 @@ fn test_update_all_fields_combined() {
         remove_labels: vec![],
         category: Some("features".to_string()),
         remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
     };
 
     commands::update(args).expect("update should succeed");
@@ -286,6 +352,13 @@ fn test_update_category_then_clear() {
         remove_labels: vec![],
         category: Some("bugs".to_string()),
         remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
     };
     commands::update(args).expect("update should succeed");
 
@@ -298,6 +371,13 @@ fn test_update_category_then_clear() {
         remove_labels: vec![],
         category: None,
         remove_category: true,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
     };
     commands::update(args).expect("clear category should succeed");
 
@@ -324,6 +404,13 @@ fn test_update_with_full_id() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
     };
 
     let result = commands::update(args);
@@ -344,8 +431,86 @@ fn test_update_without_init() {
         remove_labels: vec![],
         category: None,
         remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
     };
 
     let result = commands::update(args);
     assert!(result.is_err(), "update without init should fail");
 }
+
+#[test]
+fn test_update_prints_change_summary() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+    create_test_item(&env, "260101-AAA", "Old Title", "open", &["wontfix"], None);
+
+    let output = qs_cmd(&env)
+        .args(["update", "--id", "260101", "--title", "New Title"])
+        .args(["--label", "urgent", "--remove-label", "wontfix"])
+        .args(["--category", "bugs"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // Strip colors
+    let ansi = regex::Regex::new("\x1b\\[[0-9;]*m").unwrap();
+    let stdout = ansi.replace_all(std::str::from_utf8(&output.stdout).unwrap(), "");
+    assert!(
+        stdout.contains("title     Old Title → New Title"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("labels    +urgent -wontfix"), "{stdout}");
+    assert!(stdout.contains("category  (none) → bugs"), "{stdout}");
+    assert!(
+        stdout.contains(
+            "file      queuestack/260101-AAA-old-title.md → queuestack/bugs/260101-AAA-new-title.md"
+        ),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn test_update_summary_json_and_quiet() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init should succeed");
+    create_test_item(&env, "260101-AAA", "Old Title", "open", &[], None);
+
+    let output = qs_cmd(&env)
+        .args([
+            "update", "--id", "260101", "--label", "ui", "--format", "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["changed"], true);
+    assert_eq!(json["id"], "260101-AAA");
+    assert_eq!(json["labels_added"], serde_json::json!(["ui"]));
+    assert!(json.get("title").is_none());
+    assert!(json.get("file").is_none());
+
+    // Nothing to change
+    let output = qs_cmd(&env)
+        .args([
+            "update", "--id", "260101", "--label", "ui", "--format", "json",
+        ])
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json, serde_json::json!({ "changed": false }));
+
+    qs_cmd(&env)
+        .args(["update", "--id", "260101", "--title", "Quiet", "--quiet"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+    assert!(env.stack_path().join("260101-AAA-quiet.md").exists());
+}
@@ -0,0 +1,128 @@
+//! # URL and Resolve Command Tests
+//!
+//! Tests for shareable item links (`qs url`) and resolving them (`qs resolve`).
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::process::Command as StdCommand;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+/// Runs git in the project; returns false if git is unavailable or fails.
+fn git(env: &TestEnv, args: &[&str]) -> bool {
+    StdCommand::new("git")
+        .args(args)
+        .current_dir(env.project_path())
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    create_test_item(&env, "260101-AAA", "Crash", "open", &[], Some("bugs"));
+    env
+}
+
+#[test]
+fn test_url_without_remote_uses_scheme() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["url", "--id", "260101"])
+        .assert()
+        .success()
+        .stdout("qs://260101-AAA\n");
+}
+
+#[test]
+fn test_url_points_to_remote_web_page() {
+    let env = setup();
+    if !git(&env, &["init", "-q"])
+        || !git(&env, &["symbolic-ref", "HEAD", "refs/heads/main"])
+        || !git(
+            &env,
+            &["remote", "add", "origin", "git@github.com:org/repo.git"],
+        )
+    {
+        return; // git not available
+    }
+
+    qs_cmd(&env)
+        .args(["url", "--id", "260101-AAA"])
+        .assert()
+        .success()
+        .stdout("https://github.com/org/repo/blob/main/queuestack/bugs/260101-AAA-crash.md\n");
+}
+
+#[test]
+fn test_url_with_configured_base() {
+    let env = setup();
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .link_base("https://tracker.example/items/{id}")
+            .build(),
+    );
+
+    qs_cmd(&env)
+        .args(["url", "--id", "260101"])
+        .assert()
+        .success()
+        .stdout("https://tracker.example/items/260101-AAA\n");
+}
+
+#[test]
+fn test_resolve_prints_item_path() {
+    let env = setup();
+    create_test_item(&env, "260102-BBB", "Slow start", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["resolve", "qs://260101-AAA"])
+        .assert()
+        .success()
+        .stdout("queuestack/bugs/260101-AAA-crash.md\n");
+
+    // Links made with another base or before a rename still resolve
+    qs_cmd(&env)
+        .args([
+            "resolve",
+            "https://github.com/org/repo/blob/main/queuestack/260102-BBB-old-title.md#L5",
+        ])
+        .assert()
+        .success()
+        .stdout("queuestack/260102-BBB-slow-start.md\n");
+}
+
+#[test]
+fn test_resolve_finds_closed_items() {
+    let env = setup();
+    qs_cmd(&env)
+        .args(["close", "--id", "260101-AAA"])
+        .assert()
+        .success();
+
+    qs_cmd(&env)
+        .args(["resolve", "qs://260101-AAA"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("260101-AAA-crash.md"));
+}
+
+#[test]
+fn test_resolve_unknown_link_fails() {
+    let env = setup();
+
+    qs_cmd(&env)
+        .args(["resolve", "https://example.com/nothing/here"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No item found for link"));
+}
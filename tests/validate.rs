@@ -0,0 +1,224 @@
+//! # Label Policy Tests
+//!
+//! Tests for the `[labels]` policy: normalization and rejection when labels
+//! are written, defined labels and `strict`, and `qs validate [--fix]` for
+//! existing items.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use std::fs;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, ProjectConfigBuilder, TestEnv};
+use predicates::prelude::*;
+use queuestack::commands;
+
+fn setup() -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .label_policy("lower", Some("a-z0-9-"), Some(12))
+            .build(),
+    );
+    env
+}
+
+#[test]
+fn test_patch_normalizes_and_rejects_labels() {
+    let env = setup();
+    let path = create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["patch", "--id", "AAA", "--set", "labels+=Bug"])
+        .assert()
+        .success();
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(content.contains("- bug"), "{content}");
+    assert!(!content.contains("Bug"));
+
+    qs_cmd(&env)
+        .args(["patch", "--id", "AAA", "--set", "labels+=needs_qa"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Label 'needs_qa' contains characters outside [a-z0-9-]",
+        ));
+    qs_cmd(&env)
+        .args([
+            "patch",
+            "--id",
+            "AAA",
+            "--set",
+            "labels+=performance-regression",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is longer than 12 characters"));
+    assert!(!fs::read_to_string(&path).unwrap().contains("needs_qa"));
+}
+
+#[test]
+fn test_validate_fix_normalizes_case() {
+    let env = setup();
+    let path = create_test_item(
+        &env,
+        "260101-AAA",
+        "Crash",
+        "open",
+        &["Bug", "bug", "UI"],
+        None,
+    );
+    create_test_item(&env, "260101-BBB", "Docs", "open", &["docs"], None);
+
+    qs_cmd(&env)
+        .arg("validate")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Bug → bug, UI → ui"))
+        .stderr(predicate::str::contains("qs validate --fix"));
+
+    qs_cmd(&env)
+        .args(["validate", "--fix"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Fixed"));
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(
+        !content.contains("Bug") && !content.contains("UI"),
+        "{content}"
+    );
+    assert_eq!(content.matches("bug").count(), 1, "{content}");
+
+    qs_cmd(&env)
+        .arg("validate")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "2 item(s) follow the label policy",
+        ));
+}
+
+#[test]
+fn test_validate_reports_unfixable_labels() {
+    let env = setup();
+    let path = create_test_item(&env, "260101-AAA", "Crash", "open", &["needs qa"], None);
+
+    qs_cmd(&env)
+        .args(["validate", "--fix"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "label 'needs qa' contains characters outside [a-z0-9-]",
+        ))
+        .stderr(predicate::str::contains("qs patch"));
+    assert!(fs::read_to_string(&path).unwrap().contains("needs qa"));
+
+    // Existing labels don't block unrelated edits
+    qs_cmd(&env)
+        .args(["patch", "--id", "AAA", "--set", "labels+=crash"])
+        .assert()
+        .success();
+}
+
+fn setup_defined(strict: bool) -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    let mut config = ProjectConfigBuilder::new()
+        .define_label("bug", "Something isn't working", "#d73a4a")
+        .define_label("docs", "Documentation", "blue");
+    if strict {
+        config = config.strict_labels();
+    }
+    env.write_project_config(&config.build());
+    env
+}
+
+#[test]
+fn test_strict_labels_reject_undefined() {
+    let env = setup_defined(true);
+    let path = create_test_item(&env, "260101-AAA", "Crash", "open", &[], None);
+
+    qs_cmd(&env)
+        .args(["patch", "--id", "AAA", "--set", "labels+=BUG"])
+        .assert()
+        .success();
+    assert!(fs::read_to_string(&path).unwrap().contains("- bug"));
+
+    qs_cmd(&env)
+        .args(["patch", "--id", "AAA", "--set", "labels+=wontfix"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Label 'wontfix' is not a defined label",
+        ));
+}
+
+#[test]
+fn test_validate_reports_undefined_labels() {
+    let env = setup_defined(true);
+    create_test_item(
+        &env,
+        "260101-AAA",
+        "Crash",
+        "open",
+        &["Bug", "wontfix"],
+        None,
+    );
+
+    qs_cmd(&env)
+        .arg("validate")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Bug → bug"))
+        .stdout(predicate::str::contains(
+            "label 'wontfix' is not a defined label",
+        ));
+}
+
+#[test]
+fn test_list_labels_shows_definitions() {
+    let env = setup_defined(false);
+    create_test_item(&env, "260101-AAA", "Crash", "open", &["bug", "ui"], None);
+
+    let output = qs_cmd(&env)
+        .args(["list", "--labels", "--no-interactive"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("bug (1)  Something isn't working"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("ui (1)\n"), "{stdout}");
+    // Defined labels are listed before they are used
+    assert!(stdout.contains("docs (0)  Documentation"), "{stdout}");
+    assert!(!stdout.contains('\x1b'));
+}
+
+#[test]
+fn test_invalid_label_color_is_rejected() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().expect("init");
+    env.write_project_config(
+        &ProjectConfigBuilder::new()
+            .define_label("bug", "Broken", "rouge")
+            .build(),
+    );
+
+    qs_cmd(&env)
+        .args(["list", "--no-interactive"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid color 'rouge' for label 'bug'",
+        ));
+}
@@ -0,0 +1,139 @@
+//! # Vault Mode Tests
+//!
+//! Tests for Obsidian vault mode (`obsidian = true`).
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{create_test_item, qs_cmd, GlobalConfigBuilder, TestEnv};
+use queuestack::{
+    commands::{self, PatchArgs},
+    config::Config,
+    storage,
+};
+
+fn setup(obsidian: bool) -> TestEnv {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .interactive(false)
+            .obsidian(obsidian)
+            .build(),
+    );
+    commands::init().expect("init should succeed");
+    env
+}
+
+fn patch(id: &str, set: &str) {
+    commands::patch(&PatchArgs {
+        id: Some(id.to_string()),
+        file: None,
+        set: vec![set.to_string()],
+        patch_file: None,
+        force: false,
+    })
+    .expect("patch should succeed");
+}
+
+#[test]
+fn test_vault_footer_tracks_labels() {
+    let env = setup(true);
+    create_test_item(&env, "260101-AAA", "Login bug", "open", &["bug"], None);
+
+    patch("260101", "labels+=ui");
+    let path = env.find_item_by_id("260101").unwrap();
+    let content = env.read_item(&path);
+    assert!(content.contains("<!-- queuestack:vault -->\n#bug #ui\n"));
+
+    patch("260101", "labels-=bug");
+    let content = env.read_item(&env.find_item_by_id("260101").unwrap());
+    assert!(content.contains("<!-- queuestack:vault -->\n#ui\n"));
+    assert!(!content.contains("#bug"));
+
+    // The footer doesn't break parsing
+    let item = queuestack::Item::load(&path).unwrap();
+    assert_eq!(item.labels(), ["ui"]);
+}
+
+#[test]
+fn test_vault_index_notes() {
+    let env = setup(true);
+    create_test_item(
+        &env,
+        "260101-AAA",
+        "Login bug",
+        "open",
+        &[],
+        Some("backend"),
+    );
+    create_test_item(&env, "260102-BBB", "Write docs", "open", &[], None);
+
+    patch("260102", "priority=low");
+    let root = std::fs::read_to_string(env.stack_path().join("_index.md")).unwrap();
+    assert!(root.contains("- [[backend/_index|backend]]"));
+    assert!(root.contains("- [[260102-BBB-write-docs|Write docs]]"));
+    let backend =
+        std::fs::read_to_string(env.stack_path().join("backend").join("_index.md")).unwrap();
+    assert!(backend.contains("- [[260101-AAA-login-bug|Login bug]]"));
+
+    // Index notes are not items
+    let config = Config::load().unwrap();
+    assert_eq!(storage::walk_items(&config).count(), 2);
+
+    // Emptied categories lose their index note and directory
+    commands::execute_close(Some("260101".to_string()), None, None).unwrap();
+    assert!(!env.stack_path().join("backend").exists());
+    let root = std::fs::read_to_string(env.stack_path().join("_index.md")).unwrap();
+    assert!(!root.contains("backend"));
+}
+
+#[test]
+fn test_vault_index_notes_follow_status_dirs() {
+    let env = TestEnv::new();
+    env.write_global_config(
+        &GlobalConfigBuilder::new()
+            .user_name("Test User")
+            .interactive(false)
+            .obsidian(true)
+            .status_dir("in-progress", "doing")
+            .build(),
+    );
+    commands::init().expect("init should succeed");
+
+    qs_cmd(&env)
+        .args(["new", "Bug one", "--category", "bugs"])
+        .assert()
+        .success();
+    assert!(env.stack_path().join("bugs").join("_index.md").exists());
+
+    // Claiming the only item empties bugs/ into doing/bugs/, and the next
+    // write regenerates the notes there
+    qs_cmd(&env).arg("pop").assert().success();
+    qs_cmd(&env)
+        .args(["new", "Feature", "--category", "features"])
+        .assert()
+        .success();
+
+    assert!(!env.stack_path().join("bugs").exists());
+    let note = std::fs::read_to_string(env.stack_path().join("doing/bugs/_index.md")).unwrap();
+    assert!(note.starts_with("# doing/bugs\n\n- [["));
+    assert!(note.contains("|Bug one]]"));
+    let root = std::fs::read_to_string(env.stack_path().join("_index.md")).unwrap();
+    assert!(root.contains("- [[doing/bugs/_index|doing/bugs]]"));
+    assert!(!root.contains("[[bugs/_index"));
+}
+
+#[test]
+fn test_vault_disabled_by_default() {
+    let env = setup(false);
+    create_test_item(&env, "260101-AAA", "Login bug", "open", &["bug"], None);
+
+    patch("260101", "labels+=ui");
+    let content = env.read_item(&env.find_item_by_id("260101").unwrap());
+    assert!(!content.contains("queuestack:vault"));
+    assert!(!env.stack_path().join("_index.md").exists());
+}
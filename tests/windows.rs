@@ -0,0 +1,195 @@
+//! # Windows Path Tests
+//!
+//! Tests for Windows path handling: reserved device names, case-insensitive
+//! IDs and extensions, drive-relative attachment sources, and long paths.
+//! Tests marked `#[cfg(windows)]` only run on Windows.
+//!
+//! Copyright (c) 2025 Dominic Rodemer. All rights reserved.
+//! Licensed under the MIT License.
+
+#![allow(clippy::significant_drop_tightening)] // TestEnv holds the test lock on purpose
+
+mod common;
+
+use common::{create_test_item, GlobalConfigBuilder, TestEnv};
+use queuestack::{
+    commands::{self, InteractiveArgs, NewArgs, UpdateArgs},
+    config::Config,
+    storage,
+};
+
+fn new_args(title: &str, category: Option<&str>) -> NewArgs {
+    NewArgs {
+        title: Some(title.to_string()),
+        labels: vec![],
+        category: category.map(String::from),
+        due: None,
+        assignee: None,
+        attachments: vec![],
+        interactive: InteractiveArgs {
+            interactive: false,
+            no_interactive: true,
+        },
+        as_template: false,
+        from_template: None,
+        vars: vec![],
+    }
+}
+
+// =============================================================================
+// Reserved Names
+// =============================================================================
+
+#[test]
+fn test_new_rejects_reserved_category() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+
+    let err = commands::new(new_args("Task", Some("CON"))).unwrap_err();
+    assert!(format!("{err:#}").contains("reserved"), "{err:#}");
+    assert!(env.list_stack_files().is_empty());
+}
+
+#[test]
+fn test_update_rejects_reserved_category_before_changes() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+
+    let path = create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+
+    let result = commands::update(UpdateArgs {
+        id: Some("260101-AAA".to_string()),
+        file: None,
+        title: Some("Renamed".to_string()),
+        labels: vec![],
+        remove_labels: vec![],
+        category: Some("nul".to_string()),
+        remove_category: false,
+        due: None,
+        remove_due: false,
+        assignee: None,
+        remove_assignee: false,
+        format: commands::OutputFormat::Text,
+        quiet: false,
+        force: false,
+    });
+
+    assert!(result.is_err());
+    assert!(path.exists(), "Item should not be renamed");
+}
+
+#[test]
+fn test_reserved_title_gets_safe_filename() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+
+    commands::new(new_args("AUX", None)).unwrap();
+
+    let files = env.list_stack_files();
+    assert_eq!(files.len(), 1);
+    let name = files[0].file_name().unwrap().to_string_lossy().to_string();
+    assert!(name.ends_with("-aux_.md"), "{name}");
+}
+
+// =============================================================================
+// Case-Insensitive Matching
+// =============================================================================
+
+#[test]
+fn test_find_by_id_case_insensitive() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+
+    let path = create_test_item(&env, "260101-ABC", "Task", "open", &[], None);
+    let config = Config::load().unwrap();
+
+    let found = storage::find_by_id(&config, "260101-abc").unwrap();
+    assert_eq!(found.file_name(), path.file_name());
+}
+
+#[test]
+fn test_find_by_id_prefers_exact_match() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+
+    let path = create_test_item(&env, "260101-ABC", "Short", "open", &[], None);
+    create_test_item(&env, "260101-ABCD", "Longer", "open", &[], None);
+    let config = Config::load().unwrap();
+
+    let found = storage::find_by_id(&config, "260101-abc").unwrap();
+    assert_eq!(found.file_name(), path.file_name());
+    assert!(storage::find_by_id(&config, "260101-AB").is_err());
+}
+
+#[test]
+fn test_uppercase_extension_is_listed() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+
+    let path = create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+    std::fs::rename(&path, path.with_extension("MD")).unwrap();
+    let config = Config::load().unwrap();
+
+    assert_eq!(storage::walk_items(&config).count(), 1);
+}
+
+// =============================================================================
+// Windows Only
+// =============================================================================
+
+#[cfg(windows)]
+#[test]
+fn test_attach_drive_relative_source() {
+    use queuestack::commands::AttachAddArgs;
+
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+
+    create_test_item(&env, "260101-AAA", "Task", "open", &[], None);
+    env.create_test_file("notes.txt", "content");
+
+    let cwd = std::env::current_dir().unwrap();
+    let drive = &cwd.to_string_lossy()[..2];
+
+    commands::attach_add(&AttachAddArgs {
+        id: Some("260101-AAA".to_string()),
+        file: None,
+        sources: vec![format!("{drive}notes.txt")],
+        force: false,
+    })
+    .unwrap();
+
+    assert_eq!(env.list_attachment_files("260101-AAA").len(), 1);
+}
+
+#[cfg(windows)]
+#[test]
+fn test_move_long_path() {
+    let env = TestEnv::new();
+    env.write_global_config(&GlobalConfigBuilder::new().interactive(false).build());
+    commands::init().unwrap();
+
+    // Category directories nested beyond MAX_PATH (260 characters)
+    let category = "c".repeat(120);
+    let deep = env.stack_path().join(&category).join(&category);
+    std::fs::create_dir_all(&deep).unwrap();
+    let from = deep.join("260101-AAA-task.md");
+    std::fs::write(&from, "---\nid: 260101-AAA\ntitle: Task\n---\n").unwrap();
+
+    let to = env
+        .archive_path()
+        .join(&category)
+        .join(&category)
+        .join("260101-AAA-task.md");
+    storage::git::move_file(&from, &to).unwrap();
+
+    assert!(!from.exists());
+    assert!(to.exists());
+}